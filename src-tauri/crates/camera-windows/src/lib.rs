@@ -8,27 +8,39 @@
 
 use parking_lot::Mutex;
 use std::{
+    collections::HashSet,
     ffi::{OsStr, OsString},
     fmt::{Debug, Display},
-    mem::MaybeUninit,
+    mem::{ManuallyDrop, MaybeUninit},
     ops::Deref,
     os::windows::ffi::OsStringExt,
     ptr::null_mut,
     slice::from_raw_parts,
-    sync::mpsc::{channel, Receiver, Sender},
+    sync::atomic::{AtomicI64, Ordering},
+    sync::mpsc::{channel, sync_channel, Receiver, Sender},
+    thread::JoinHandle,
     time::Duration,
 };
 use tracing::{debug, error};
 use windows::Win32::{
-    Foundation::S_FALSE,
+    Foundation::{HANDLE, HWND, LPARAM, LRESULT, S_FALSE, WPARAM},
     Media::MediaFoundation::*,
     System::{
-        Com::{CoCreateInstance, CoInitialize, CLSCTX_INPROC_SERVER},
+        Com::{CoCreateInstance, CoInitialize, CoTaskMemFree, CLSCTX_INPROC_SERVER},
+        LibraryLoader::GetModuleHandleW,
         Performance::QueryPerformanceCounter,
         WinRT::{RoInitialize, RO_INIT_MULTITHREADED},
     },
+    UI::WindowsAndMessaging::{
+        CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, GetMessageW, KillTimer,
+        PostMessageW, PostQuitMessage, RegisterClassW, RegisterDeviceNotificationW, SetTimer,
+        UnregisterDeviceNotification, DBT_DEVICEARRIVAL, DBT_DEVICEREMOVECOMPLETE,
+        DBT_DEVTYP_DEVICEINTERFACE, DEVICE_NOTIFY_WINDOW_HANDLE, DEV_BROADCAST_DEVICEINTERFACE_W,
+        HDEVNOTIFY, HWND_MESSAGE, MSG, WINDOW_EX_STYLE, WINDOW_STYLE, WM_CLOSE, WM_DESTROY,
+        WM_DEVICECHANGE, WM_TIMER, WNDCLASSW,
+    },
 };
-use windows_core::{implement, ComObjectInner, Interface, GUID, PWSTR};
+use windows_core::{implement, w, ComObjectInner, Interface, GUID, PWSTR};
 
 /// MF version for Win7+ (same as Cap uses)
 const MF_VERSION: u32 = 131184;
@@ -46,6 +58,10 @@ const MEDIASUBTYPE_NV21: GUID = GUID::from_u128(0x3132564e_0000_0010_8000_00aa00
 const MF_VIDEO_FORMAT_RGB565: GUID = GUID::from_u128(0x00000017_0000_0010_8000_00aa00389b71);
 const MF_VIDEO_FORMAT_P010: GUID = GUID::from_u128(0x30313050_0000_0010_8000_00aa00389b71);
 
+// Device interface classes that video capture devices register under
+const KSCATEGORY_VIDEO_CAMERA: GUID = GUID::from_u128(0xe5323777_f976_4f5b_9b55_b94699c46e44);
+const KSCATEGORY_CAPTURE: GUID = GUID::from_u128(0x65e8773d_8f56_11d0_a3b9_00a0c9223196);
+
 // ============================================================================
 // Device Categories
 // ============================================================================
@@ -207,6 +223,10 @@ pub struct FormatPreference {
     pub height: u32,
     pub frame_rate: f32,
     pub format_priority: Vec<PixelFormat>,
+    /// Frame rate to deliver to the capture callback, if lower than the device's
+    pub output_fps: Option<f32>,
+    /// Decode MJPEG formats to NV12 before delivering frames
+    pub decode_mjpeg: bool,
 }
 
 impl FormatPreference {
@@ -223,6 +243,8 @@ impl FormatPreference {
                 PixelFormat::MJPEG,
                 PixelFormat::RGB32,
             ],
+            output_fps: None,
+            decode_mjpeg: false,
         }
     }
 
@@ -231,6 +253,20 @@ impl FormatPreference {
         self
     }
 
+    /// Throttle delivered frames to `fps`, e.g. when a 120fps capture card
+    /// feeds a 30fps recording. Pass to [`VideoDevice::start_capturing_with`].
+    pub fn with_output_fps(mut self, fps: f32) -> Self {
+        self.output_fps = Some(fps);
+        self
+    }
+
+    /// Deliver MJPEG formats as decoded NV12 frames instead of raw JPEG bytes.
+    /// Leave off to keep MJPEG for passthrough recording.
+    pub fn decode_mjpeg(mut self, decode: bool) -> Self {
+        self.decode_mjpeg = decode;
+        self
+    }
+
     /// Preference optimized for hardware encoding (prefers NV12)
     pub fn for_hardware_encoding() -> Self {
         Self::new(1920, 1080, 30.0).with_format_priority(vec![
@@ -267,7 +303,8 @@ impl Default for FormatPreference {
 pub struct VideoFormat {
     width: u32,
     height: u32,
-    frame_rate: f32,
+    min_frame_rate: f32,
+    max_frame_rate: f32,
     pixel_format: PixelFormat,
     is_bottom_up: bool,
     pub(crate) media_type: IMFMediaType,
@@ -282,8 +319,24 @@ impl VideoFormat {
         self.height
     }
 
+    /// Highest supported frame rate (same as [`max_frame_rate`](Self::max_frame_rate))
     pub fn frame_rate(&self) -> f32 {
-        self.frame_rate
+        self.max_frame_rate
+    }
+
+    /// Lowest supported frame rate. Equals the max unless the device supports
+    /// a continuous range of rates for this format.
+    pub fn min_frame_rate(&self) -> f32 {
+        self.min_frame_rate
+    }
+
+    pub fn max_frame_rate(&self) -> f32 {
+        self.max_frame_rate
+    }
+
+    /// How far `fps` is from the supported range (0 if inside it)
+    pub fn frame_rate_distance(&self, fps: f32) -> f32 {
+        distance_to_range(fps, self.min_frame_rate, self.max_frame_rate)
     }
 
     pub fn pixel_format(&self) -> PixelFormat {
@@ -303,13 +356,21 @@ impl VideoFormat {
         let width = (size >> 32) as u32;
         let height = (size & 0xFFFFFFFF) as u32;
 
-        let frame_rate_ratio = {
-            let frame_rate = unsafe { media_type.GetUINT64(&MF_MT_FRAME_RATE)? };
-            let numerator = (frame_rate >> 32) as u32;
-            let denominator = frame_rate as u32;
-            (numerator, denominator)
+        let frame_rate = frame_rate_from_ratio(unsafe { media_type.GetUINT64(&MF_MT_FRAME_RATE)? });
+
+        // Devices with continuous rates advertise a range alongside the nominal rate
+        let range_rate = |key: &GUID| {
+            unsafe { media_type.GetUINT64(key) }
+                .ok()
+                .map(frame_rate_from_ratio)
+                .filter(|rate| rate.is_finite() && *rate > 0.0)
         };
-        let frame_rate = frame_rate_ratio.0 as f32 / frame_rate_ratio.1 as f32;
+        let min_frame_rate = range_rate(&MF_MT_FRAME_RATE_RANGE_MIN)
+            .unwrap_or(frame_rate)
+            .min(frame_rate);
+        let max_frame_rate = range_rate(&MF_MT_FRAME_RATE_RANGE_MAX)
+            .unwrap_or(frame_rate)
+            .max(frame_rate);
 
         let subtype = unsafe { media_type.GetGUID(&MF_MT_SUBTYPE)? };
 
@@ -323,7 +384,8 @@ impl VideoFormat {
         Ok(Self {
             width,
             height,
-            frame_rate,
+            min_frame_rate,
+            max_frame_rate,
             pixel_format,
             is_bottom_up,
             media_type,
@@ -333,11 +395,41 @@ impl VideoFormat {
 
 impl Display for VideoFormat {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}x{} {:.1}fps {:?}",
-            self.width, self.height, self.frame_rate, self.pixel_format
-        )
+        if self.min_frame_rate < self.max_frame_rate {
+            write!(
+                f,
+                "{}x{} {:.1}-{:.1}fps {:?}",
+                self.width,
+                self.height,
+                self.min_frame_rate,
+                self.max_frame_rate,
+                self.pixel_format
+            )
+        } else {
+            write!(
+                f,
+                "{}x{} {:.1}fps {:?}",
+                self.width, self.height, self.max_frame_rate, self.pixel_format
+            )
+        }
+    }
+}
+
+/// Convert an MF packed numerator/denominator ratio to frames per second
+fn frame_rate_from_ratio(packed: u64) -> f32 {
+    let numerator = (packed >> 32) as u32;
+    let denominator = packed as u32;
+    numerator as f32 / denominator as f32
+}
+
+/// How far `value` is from `min..=max` (0 if inside it)
+fn distance_to_range(value: f32, min: f32, max: f32) -> f32 {
+    if value < min {
+        min - value
+    } else if value > max {
+        value - max
+    } else {
+        0.0
     }
 }
 
@@ -536,7 +628,8 @@ impl VideoDevice {
                 300 - ((target_pixels - pixels) / 10000).min(200) as i32
             };
 
-            let fps_diff = (f.frame_rate() - preference.frame_rate).abs();
+            // A requested rate inside a continuous range is a perfect match
+            let fps_diff = f.frame_rate_distance(preference.frame_rate);
             let fps_score = 100 - (fps_diff * 10.0).min(100.0) as i32;
 
             format_priority + resolution_score + fps_score
@@ -569,7 +662,7 @@ impl VideoDevice {
                     let res_score = (f.width() as i32).min(preference.width as i32)
                         + (f.height() as i32).min(preference.height as i32);
                     let fps_score =
-                        (100.0 - (f.frame_rate() - preference.frame_rate).abs().min(100.0)) as i32;
+                        (100.0 - f.frame_rate_distance(preference.frame_rate).min(100.0)) as i32;
                     res_score + fps_score
                 })
             {
@@ -586,6 +679,37 @@ impl VideoDevice {
         format: &VideoFormat,
         callback: impl FnMut(Frame) + Send + 'static,
     ) -> Result<CaptureHandle, StartCapturingError> {
+        self.start_capturing_with(format, &FormatPreference::default(), callback)
+    }
+
+    /// Start capturing frames with the delivery options from `preference`.
+    ///
+    /// With `output_fps` set, samples are dropped before their buffers are
+    /// touched, based on sample timestamps, so the throttle holds even when the
+    /// callback falls behind. With `decode_mjpeg` set and an MJPEG `format`,
+    /// frames are delivered as NV12.
+    pub fn start_capturing_with(
+        &self,
+        format: &VideoFormat,
+        preference: &FormatPreference,
+        callback: impl FnMut(Frame) + Send + 'static,
+    ) -> Result<CaptureHandle, StartCapturingError> {
+        let decoder = if preference.decode_mjpeg && format.pixel_format() == PixelFormat::MJPEG {
+            let decoder = MjpegDecoder::new(format.width(), format.height())
+                .map_err(StartCapturingError::CreateDecoder)?;
+            debug!("Decoding MJPEG capture to NV12");
+            Some(Mutex::new(decoder))
+        } else {
+            None
+        };
+
+        // Decoded frames are always top-down NV12
+        let (pixel_format, is_bottom_up) = if decoder.is_some() {
+            (PixelFormat::NV12, false)
+        } else {
+            (format.pixel_format(), format.is_bottom_up())
+        };
+
         unsafe {
             // Initialize COM on this thread using MTA (same as Cap)
             let _ = RoInitialize(RO_INIT_MULTITHREADED);
@@ -610,9 +734,11 @@ impl VideoDevice {
                 format_info: FormatInfo {
                     width: format.width() as usize,
                     height: format.height() as usize,
-                    pixel_format: format.pixel_format(),
-                    is_bottom_up: format.is_bottom_up(),
+                    pixel_format,
+                    is_bottom_up,
                 },
+                throttle: preference.output_fps.and_then(FrameThrottle::new),
+                decoder,
             }
             .into_object();
 
@@ -787,8 +913,90 @@ impl VideoDevice {
             })
         }
     }
+
+    /// Capture a single frame without starting the capture engine.
+    ///
+    /// Reads one sample through a source reader and releases the camera
+    /// before returning, e.g. for a still thumbnail in a device picker.
+    /// Returns [`StartCapturingError::Timeout`] if no frame arrives in time.
+    pub fn grab_frame(
+        &self,
+        format: &VideoFormat,
+        timeout: Duration,
+    ) -> Result<Frame, StartCapturingError> {
+        unsafe {
+            let _ = RoInitialize(RO_INIT_MULTITHREADED);
+            MFStartup(MF_VERSION, MFSTARTUP_FULL).map_err(StartCapturingError::GrabFrame)?;
+
+            // Async mode so the wait below can time out instead of blocking in ReadSample
+            let (sample_tx, sample_rx) = channel();
+            let callback: IMFSourceReaderCallback = ReaderCallback { sample_tx }.into();
+
+            let mut attributes = None;
+            MFCreateAttributes(&mut attributes, 2).map_err(StartCapturingError::GrabFrame)?;
+            let attributes = attributes.ok_or_else(|| {
+                StartCapturingError::GrabFrame(windows_core::Error::from_hresult(S_FALSE))
+            })?;
+            // Media source shuts down on drop if this isn't specified
+            attributes
+                .SetUINT32(&MF_SOURCE_READER_DISCONNECT_MEDIASOURCE_ON_SHUTDOWN, 1)
+                .map_err(StartCapturingError::GrabFrame)?;
+            attributes
+                .SetUnknown(&MF_SOURCE_READER_ASYNC_CALLBACK, &callback)
+                .map_err(StartCapturingError::GrabFrame)?;
+
+            let reader = MFCreateSourceReaderFromMediaSource(&self.media_source, &attributes)
+                .map_err(StartCapturingError::GrabFrame)?;
+
+            let stream = MF_SOURCE_READER_FIRST_VIDEO_STREAM.0 as u32;
+            reader
+                .SetCurrentMediaType(stream, None, &format.media_type)
+                .map_err(StartCapturingError::GrabFrame)?;
+
+            let deadline = std::time::Instant::now() + timeout;
+            let result = loop {
+                if let Err(e) = reader.ReadSample(stream, 0, None, None, None, None) {
+                    break Err(StartCapturingError::GrabFrame(e));
+                }
+
+                let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+                match sample_rx.recv_timeout(remaining) {
+                    Ok(Ok(Some((sample, timestamp)))) => break Ok((sample, timestamp)),
+                    // Stream tick or gap without a sample; read again
+                    Ok(Ok(None)) => continue,
+                    Ok(Err(e)) => break Err(StartCapturingError::GrabFrame(e)),
+                    Err(_) => {
+                        let _ = reader.Flush(MF_SOURCE_READER_ALL_STREAMS.0 as u32);
+                        break Err(StartCapturingError::Timeout(timeout));
+                    },
+                }
+            };
+
+            let (sample, timestamp) = result?;
+            let buffer = sample
+                .ConvertToContiguousBuffer()
+                .map_err(StartCapturingError::GrabFrame)?;
+
+            let mut perf_counter = 0;
+            let _ = QueryPerformanceCounter(&mut perf_counter);
+
+            Ok(Frame {
+                pixel_format: format.pixel_format(),
+                width: format.width() as usize,
+                height: format.height() as usize,
+                is_bottom_up: format.is_bottom_up(),
+                timestamp: Duration::from_micros(timestamp as u64 / 10),
+                perf_counter,
+                buffer,
+            })
+        }
+    }
 }
 
+// SAFETY: Media Foundation activation objects and device media sources are
+// free-threaded, so a device can be enumerated on one thread and used on another
+unsafe impl Send for VideoDevice {}
+
 impl Debug for VideoDevice {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("VideoDevice")
@@ -849,6 +1057,12 @@ pub enum StartCapturingError {
     ConfigureSink(windows_core::Error),
     #[error("StartPreview: {0}")]
     StartPreview(windows_core::Error),
+    #[error("CreateDecoder: {0}")]
+    CreateDecoder(windows_core::Error),
+    #[error("GrabFrame: {0}")]
+    GrabFrame(windows_core::Error),
+    #[error("No frame received within {0:?}")]
+    Timeout(Duration),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -859,6 +1073,8 @@ pub enum GetDevicesError {
     ComInitFailed(windows_core::Error),
     #[error("Media Foundation initialization failed: {0}")]
     MfInitFailed(windows_core::Error),
+    #[error("Failed to register for device notifications: {0}")]
+    WatchFailed(windows_core::Error),
 }
 
 // ============================================================================
@@ -877,6 +1093,16 @@ pub fn initialize() -> Result<(), GetDevicesError> {
 
 /// Get all available video capture devices
 pub fn get_devices() -> Result<Vec<VideoDevice>, GetDevicesError> {
+    Ok(enumerate_activates()?
+        .iter()
+        .filter_map(device_from_activate)
+        .collect())
+}
+
+/// Enumerate the activation objects of all video capture devices.
+///
+/// Cheap compared to [`get_devices`] since no media source is activated.
+fn enumerate_activates() -> Result<Vec<IMFActivate>, GetDevicesError> {
     // Ensure MF is initialized
     let _ = initialize();
 
@@ -906,48 +1132,42 @@ pub fn get_devices() -> Result<Vec<VideoDevice>, GetDevicesError> {
 
     let devices_ptr = unsafe { devices_ptr.assume_init() };
 
-    let mut devices = Vec::new();
-
-    for i in 0..count {
-        let Some(activate) = (unsafe { &(*devices_ptr.add(i as usize)) }) else {
-            continue;
-        };
-
-        let media_source = match unsafe { activate.ActivateObject::<IMFMediaSource>() } {
-            Ok(v) => v,
-            Err(e) => {
-                error!("Failed to activate IMFMediaSource: {}", e);
-                continue;
-            },
-        };
+    Ok((0..count)
+        .filter_map(|i| unsafe { (*devices_ptr.add(i as usize)).clone() })
+        .collect())
+}
 
-        let name = match get_device_string(activate, &MF_DEVSOURCE_ATTRIBUTE_FRIENDLY_NAME) {
-            Ok(n) => n,
-            Err(_) => continue,
-        };
+fn device_from_activate(activate: &IMFActivate) -> Option<VideoDevice> {
+    let media_source = match unsafe { activate.ActivateObject::<IMFMediaSource>() } {
+        Ok(v) => v,
+        Err(e) => {
+            error!("Failed to activate IMFMediaSource: {}", e);
+            return None;
+        },
+    };
 
-        let id = match get_device_string(
-            activate,
-            &MF_DEVSOURCE_ATTRIBUTE_SOURCE_TYPE_VIDCAP_SYMBOLIC_LINK,
-        ) {
-            Ok(i) => i,
-            Err(_) => continue,
-        };
+    let name = get_device_string(activate, &MF_DEVSOURCE_ATTRIBUTE_FRIENDLY_NAME).ok()?;
+    let id = device_id(activate)?;
 
-        let model_id = get_device_model_id(&id.to_string_lossy());
-        let category = detect_device_category(&name, model_id.as_deref());
+    let model_id = get_device_model_id(&id.to_string_lossy());
+    let category = detect_device_category(&name, model_id.as_deref());
 
-        devices.push(VideoDevice {
-            id,
-            name,
-            model_id,
-            category,
-            activate: activate.clone(),
-            media_source,
-        });
-    }
+    Some(VideoDevice {
+        id,
+        name,
+        model_id,
+        category,
+        activate: activate.clone(),
+        media_source,
+    })
+}
 
-    Ok(devices)
+fn device_id(activate: &IMFActivate) -> Option<OsString> {
+    get_device_string(
+        activate,
+        &MF_DEVSOURCE_ATTRIBUTE_SOURCE_TYPE_VIDCAP_SYMBOLIC_LINK,
+    )
+    .ok()
 }
 
 fn get_device_string(activate: &IMFActivate, key: &GUID) -> windows_core::Result<OsString> {
@@ -976,6 +1196,435 @@ fn get_device_model_id(device_id: &str) -> Option<String> {
     Some(format!("{id_vendor}:{id_product}"))
 }
 
+// ============================================================================
+// Device Change Notifications
+// ============================================================================
+
+/// Windows fires several arrival/removal messages while a composite USB
+/// device enumerates, so the device list is only rescanned once they settle.
+const DEVICE_CHANGE_DEBOUNCE: Duration = Duration::from_millis(500);
+
+const DEVICE_CHANGE_TIMER_ID: usize = 1;
+
+/// A video capture device was plugged in or removed
+#[derive(Debug)]
+pub enum DeviceEvent {
+    Added(VideoDevice),
+    /// The id (symbolic link) of the removed device
+    Removed(OsString),
+}
+
+/// Watches for video capture devices being plugged in or removed.
+///
+/// Runs its own message loop on a background thread; events are delivered
+/// through [`events`](Self::events). Stops when dropped.
+pub struct DeviceChangeWatcher {
+    events: Receiver<DeviceEvent>,
+    hwnd: isize,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl DeviceChangeWatcher {
+    /// Receiver for device added/removed events
+    pub fn events(&self) -> &Receiver<DeviceEvent> {
+        &self.events
+    }
+}
+
+impl Drop for DeviceChangeWatcher {
+    fn drop(&mut self) {
+        let _ = unsafe {
+            PostMessageW(
+                Some(HWND(self.hwnd as *mut _)),
+                WM_CLOSE,
+                WPARAM(0),
+                LPARAM(0),
+            )
+        };
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Start watching for video capture devices being added or removed.
+///
+/// Only changes after this call are reported, so take the initial
+/// [`get_devices`] snapshot after starting the watcher.
+pub fn watch_devices() -> Result<DeviceChangeWatcher, GetDevicesError> {
+    let (event_tx, event_rx) = channel();
+    let (ready_tx, ready_rx) = sync_channel(1);
+
+    let thread = std::thread::Builder::new()
+        .name("camera-device-watcher".to_string())
+        .spawn(move || {
+            let (hwnd, notifications) = match create_device_change_window() {
+                Ok(v) => v,
+                Err(e) => {
+                    let _ = ready_tx.send(Err(e));
+                    return;
+                },
+            };
+
+            let mut known_ids = match enumerate_activates() {
+                Ok(activates) => activates.iter().filter_map(device_id).collect(),
+                Err(e) => {
+                    let _ = ready_tx.send(Err(e));
+                    unsafe { destroy_device_change_window(hwnd, notifications) };
+                    return;
+                },
+            };
+
+            let _ = ready_tx.send(Ok(hwnd.0 as isize));
+            run_device_change_loop(&event_tx, &mut known_ids);
+            unsafe { destroy_device_change_window(hwnd, notifications) };
+            debug!("Device change watcher stopped");
+        })
+        .map_err(|_| GetDevicesError::WatchFailed(windows_core::Error::from_win32()))?;
+
+    match ready_rx.recv() {
+        Ok(Ok(hwnd)) => Ok(DeviceChangeWatcher {
+            events: event_rx,
+            hwnd,
+            thread: Some(thread),
+        }),
+        Ok(Err(e)) => {
+            let _ = thread.join();
+            Err(e)
+        },
+        Err(_) => {
+            let _ = thread.join();
+            Err(GetDevicesError::WatchFailed(
+                windows_core::Error::from_hresult(S_FALSE),
+            ))
+        },
+    }
+}
+
+fn create_device_change_window() -> Result<(HWND, Vec<HDEVNOTIFY>), GetDevicesError> {
+    unsafe {
+        let hinstance = GetModuleHandleW(None).map_err(GetDevicesError::WatchFailed)?;
+        let class_name = w!("SnapItCameraDeviceWatcher");
+
+        let wc = WNDCLASSW {
+            lpfnWndProc: Some(device_change_wnd_proc),
+            hInstance: hinstance.into(),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        // Fails harmlessly if another watcher already registered the class
+        RegisterClassW(&wc);
+
+        // Message-only window: never shown, but receives device notifications
+        let hwnd = CreateWindowExW(
+            WINDOW_EX_STYLE::default(),
+            class_name,
+            w!(""),
+            WINDOW_STYLE::default(),
+            0,
+            0,
+            0,
+            0,
+            Some(HWND_MESSAGE),
+            None,
+            Some(hinstance.into()),
+            None,
+        )
+        .map_err(GetDevicesError::WatchFailed)?;
+
+        let mut notifications = Vec::new();
+        for class_guid in [KSCATEGORY_VIDEO_CAMERA, KSCATEGORY_CAPTURE] {
+            let filter = DEV_BROADCAST_DEVICEINTERFACE_W {
+                dbcc_size: std::mem::size_of::<DEV_BROADCAST_DEVICEINTERFACE_W>() as u32,
+                dbcc_devicetype: DBT_DEVTYP_DEVICEINTERFACE.0,
+                dbcc_classguid: class_guid,
+                ..Default::default()
+            };
+            match RegisterDeviceNotificationW(
+                HANDLE(hwnd.0),
+                &filter as *const _ as *const _,
+                DEVICE_NOTIFY_WINDOW_HANDLE,
+            ) {
+                Ok(notification) => notifications.push(notification),
+                Err(e) => {
+                    destroy_device_change_window(hwnd, notifications);
+                    return Err(GetDevicesError::WatchFailed(e));
+                },
+            }
+        }
+
+        Ok((hwnd, notifications))
+    }
+}
+
+unsafe fn destroy_device_change_window(hwnd: HWND, notifications: Vec<HDEVNOTIFY>) {
+    for notification in notifications {
+        let _ = UnregisterDeviceNotification(notification);
+    }
+    let _ = DestroyWindow(hwnd);
+}
+
+/// Pump messages until the window is closed, rescanning devices each time
+/// the debounce timer fires.
+fn run_device_change_loop(event_tx: &Sender<DeviceEvent>, known_ids: &mut HashSet<OsString>) {
+    let mut msg = MSG::default();
+    while unsafe { GetMessageW(&mut msg, None, 0, 0) }.as_bool() {
+        if msg.message == WM_TIMER && msg.wParam.0 == DEVICE_CHANGE_TIMER_ID {
+            let _ = unsafe { KillTimer(Some(msg.hwnd), DEVICE_CHANGE_TIMER_ID) };
+            if !rescan_devices(event_tx, known_ids) {
+                // Receiver dropped; nobody is listening anymore
+                let _ = unsafe { DestroyWindow(msg.hwnd) };
+            }
+            continue;
+        }
+        unsafe { DispatchMessageW(&msg) };
+    }
+}
+
+/// Diff the current device list against `known_ids` and send events.
+///
+/// Returns false if the event receiver has been dropped.
+fn rescan_devices(event_tx: &Sender<DeviceEvent>, known_ids: &mut HashSet<OsString>) -> bool {
+    let activates = match enumerate_activates() {
+        Ok(v) => v,
+        Err(e) => {
+            error!("Failed to enumerate devices after device change: {}", e);
+            return true;
+        },
+    };
+
+    let current: Vec<(OsString, &IMFActivate)> = activates
+        .iter()
+        .filter_map(|activate| Some((device_id(activate)?, activate)))
+        .collect();
+
+    let removed: Vec<OsString> = known_ids
+        .iter()
+        .filter(|id| !current.iter().any(|(current_id, _)| current_id == *id))
+        .cloned()
+        .collect();
+    for id in removed {
+        debug!("Video device removed: {:?}", id);
+        known_ids.remove(&id);
+        if event_tx.send(DeviceEvent::Removed(id)).is_err() {
+            return false;
+        }
+    }
+
+    for (id, activate) in current {
+        if known_ids.contains(&id) {
+            continue;
+        }
+        // Left out of known_ids on failure so the next change retries it
+        let Some(device) = device_from_activate(activate) else {
+            continue;
+        };
+        debug!("Video device added: {}", device);
+        known_ids.insert(id);
+        if event_tx.send(DeviceEvent::Added(device)).is_err() {
+            return false;
+        }
+    }
+
+    true
+}
+
+unsafe extern "system" fn device_change_wnd_proc(
+    hwnd: HWND,
+    msg: u32,
+    w_param: WPARAM,
+    l_param: LPARAM,
+) -> LRESULT {
+    match msg {
+        WM_DEVICECHANGE => {
+            let event = w_param.0 as u32;
+            if event == DBT_DEVICEARRIVAL || event == DBT_DEVICEREMOVECOMPLETE {
+                // Restart the debounce timer on every message in a burst
+                SetTimer(
+                    Some(hwnd),
+                    DEVICE_CHANGE_TIMER_ID,
+                    DEVICE_CHANGE_DEBOUNCE.as_millis() as u32,
+                    None,
+                );
+            }
+            LRESULT(1)
+        },
+        WM_DESTROY => {
+            PostQuitMessage(0);
+            LRESULT(0)
+        },
+        _ => DefWindowProcW(hwnd, msg, w_param, l_param),
+    }
+}
+
+// ============================================================================
+// MJPEG Decoding
+// ============================================================================
+
+/// Synchronous Media Foundation MJPEG decoder producing NV12 buffers
+struct MjpegDecoder {
+    transform: IMFTransform,
+    /// Output buffer size to allocate when the MFT doesn't provide samples
+    output_buffer_size: u32,
+    provides_samples: bool,
+}
+
+impl MjpegDecoder {
+    fn new(width: u32, height: u32) -> windows_core::Result<Self> {
+        let transform = find_mjpeg_decoder()?;
+
+        unsafe {
+            let input_type = MFCreateMediaType()?;
+            input_type.SetGUID(&MF_MT_MAJOR_TYPE, &MFMediaType_Video)?;
+            input_type.SetGUID(&MF_MT_SUBTYPE, &MFVideoFormat_MJPG)?;
+            input_type.SetUINT64(&MF_MT_FRAME_SIZE, ((width as u64) << 32) | height as u64)?;
+            transform.SetInputType(0, &input_type, 0)?;
+        }
+
+        let mut decoder = Self {
+            transform,
+            output_buffer_size: 0,
+            provides_samples: false,
+        };
+        decoder.configure_output()?;
+
+        unsafe {
+            decoder
+                .transform
+                .ProcessMessage(MFT_MESSAGE_NOTIFY_BEGIN_STREAMING, 0)?;
+            decoder
+                .transform
+                .ProcessMessage(MFT_MESSAGE_NOTIFY_START_OF_STREAM, 0)?;
+        }
+
+        Ok(decoder)
+    }
+
+    /// Select the NV12 output type. Called again if the stream format changes.
+    fn configure_output(&mut self) -> windows_core::Result<()> {
+        unsafe {
+            let mut index = 0;
+            loop {
+                // Errors with MF_E_NO_MORE_TYPES once the list is exhausted
+                let output_type = self.transform.GetOutputAvailableType(0, index)?;
+                if output_type.GetGUID(&MF_MT_SUBTYPE)? == MFVideoFormat_NV12 {
+                    self.transform.SetOutputType(0, &output_type, 0)?;
+                    break;
+                }
+                index += 1;
+            }
+
+            let info = self.transform.GetOutputStreamInfo(0)?;
+            self.output_buffer_size = info.cbSize;
+            self.provides_samples = info.dwFlags
+                & (MFT_OUTPUT_STREAM_PROVIDES_SAMPLES.0 | MFT_OUTPUT_STREAM_CAN_PROVIDE_SAMPLES.0)
+                    as u32
+                != 0;
+        }
+        Ok(())
+    }
+
+    /// Decode one MJPEG sample. Returns `None` if the decoder needs more input.
+    fn decode(&mut self, sample: &IMFSample) -> windows_core::Result<Option<IMFMediaBuffer>> {
+        unsafe {
+            self.transform.ProcessInput(0, sample, 0)?;
+
+            loop {
+                let output_sample = if self.provides_samples {
+                    None
+                } else {
+                    let output_sample = MFCreateSample()?;
+                    output_sample.AddBuffer(&MFCreateMemoryBuffer(self.output_buffer_size)?)?;
+                    Some(output_sample)
+                };
+
+                let mut output = [MFT_OUTPUT_DATA_BUFFER {
+                    dwStreamID: 0,
+                    pSample: ManuallyDrop::new(output_sample),
+                    dwStatus: 0,
+                    pEvents: ManuallyDrop::new(None),
+                }];
+                let mut status = 0;
+                let result = self.transform.ProcessOutput(0, &mut output, &mut status);
+
+                let [output] = output;
+                let output_sample = ManuallyDrop::into_inner(output.pSample);
+                drop(ManuallyDrop::into_inner(output.pEvents));
+
+                match result {
+                    Ok(()) => {
+                        return output_sample
+                            .map(|sample| sample.ConvertToContiguousBuffer())
+                            .transpose();
+                    },
+                    Err(e) if e.code() == MF_E_TRANSFORM_NEED_MORE_INPUT => return Ok(None),
+                    Err(e) if e.code() == MF_E_TRANSFORM_STREAM_CHANGE => {
+                        self.configure_output()?;
+                    },
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+    }
+}
+
+impl Drop for MjpegDecoder {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = self
+                .transform
+                .ProcessMessage(MFT_MESSAGE_NOTIFY_END_OF_STREAM, 0);
+            let _ = self
+                .transform
+                .ProcessMessage(MFT_MESSAGE_NOTIFY_END_STREAMING, 0);
+        }
+    }
+}
+
+/// Find and activate a synchronous MFT that decodes MJPEG to NV12
+fn find_mjpeg_decoder() -> windows_core::Result<IMFTransform> {
+    let input = MFT_REGISTER_TYPE_INFO {
+        guidMajorType: MFMediaType_Video,
+        guidSubtype: MFVideoFormat_MJPG,
+    };
+    let output = MFT_REGISTER_TYPE_INFO {
+        guidMajorType: MFMediaType_Video,
+        guidSubtype: MFVideoFormat_NV12,
+    };
+
+    let mut activates = null_mut();
+    let mut count = 0;
+    unsafe {
+        MFTEnumEx(
+            MFT_CATEGORY_VIDEO_DECODER,
+            MFT_ENUM_FLAG_SYNCMFT | MFT_ENUM_FLAG_LOCALMFT | MFT_ENUM_FLAG_SORTANDFILTER,
+            Some(&input),
+            Some(&output),
+            &mut activates,
+            &mut count,
+        )?;
+    }
+    if activates.is_null() {
+        return Err(MF_E_TOPO_CODEC_NOT_FOUND.into());
+    }
+
+    let result = unsafe { from_raw_parts(activates, count as usize) }
+        .iter()
+        .flatten()
+        .find_map(|activate| unsafe { activate.ActivateObject::<IMFTransform>() }.ok())
+        .ok_or_else(|| MF_E_TOPO_CODEC_NOT_FOUND.into());
+
+    // The array owns a reference to each activation object
+    unsafe {
+        for i in 0..count as usize {
+            std::ptr::drop_in_place(activates.add(i));
+        }
+        CoTaskMemFree(Some(activates as *const _));
+    }
+
+    result
+}
+
 // ============================================================================
 // Capture Engine Internals
 // ============================================================================
@@ -987,11 +1636,57 @@ struct FormatInfo {
     is_bottom_up: bool,
 }
 
+/// Drops samples that land in the same output frame interval as the last
+/// delivered sample.
+struct FrameThrottle {
+    /// Output frame interval in 100ns units (the unit of sample times)
+    interval: i64,
+    /// Interval index of the last delivered sample
+    last_slot: AtomicI64,
+}
+
+impl FrameThrottle {
+    fn new(output_fps: f32) -> Option<Self> {
+        if output_fps.is_nan() || output_fps <= 0.0 {
+            return None;
+        }
+        Some(Self {
+            interval: ((10_000_000.0 / output_fps as f64) as i64).max(1),
+            last_slot: AtomicI64::new(i64::MIN),
+        })
+    }
+
+    fn should_deliver(&self, sample_time: i64) -> bool {
+        let slot = sample_time.div_euclid(self.interval);
+        // Timestamps going backwards (e.g. a stream restart) start over
+        self.last_slot.swap(slot, Ordering::Relaxed) != slot
+    }
+}
+
 #[implement(IMFCaptureEngineOnSampleCallback, IMFCaptureEngineOnEventCallback)]
 struct VideoCallback {
     event_tx: Sender<CaptureEngineEvent>,
     sample_callback: Mutex<Box<dyn FnMut(Frame) + Send>>,
     format_info: FormatInfo,
+    throttle: Option<FrameThrottle>,
+    decoder: Option<Mutex<MjpegDecoder>>,
+}
+
+impl VideoCallback {
+    fn deliver(&self, buffer: IMFMediaBuffer, sample_time: i64, perf_counter: i64) {
+        let frame = Frame {
+            buffer,
+            width: self.format_info.width,
+            height: self.format_info.height,
+            is_bottom_up: self.format_info.is_bottom_up,
+            pixel_format: self.format_info.pixel_format,
+            timestamp: Duration::from_micros(sample_time as u64 / 10),
+            perf_counter,
+        };
+
+        let mut callback = self.sample_callback.lock();
+        (callback)(frame);
+    }
 }
 
 // Static counter for OnSample calls
@@ -1018,6 +1713,21 @@ impl IMFCaptureEngineOnSampleCallback_Impl for VideoCallback_Impl {
 
         let sample_time = unsafe { sample.GetSampleTime() }?;
 
+        if let Some(throttle) = &self.throttle {
+            if !throttle.should_deliver(sample_time) {
+                return Ok(());
+            }
+        }
+
+        if let Some(decoder) = &self.decoder {
+            match decoder.lock().decode(sample) {
+                Ok(Some(buffer)) => self.deliver(buffer, sample_time, perf_counter),
+                Ok(None) => {},
+                Err(e) => error!("MJPEG decode failed: {}", e),
+            }
+            return Ok(());
+        }
+
         let buffer_count = unsafe { sample.GetBufferCount() }?;
         if count <= 3 {
             eprintln!(
@@ -1035,21 +1745,10 @@ impl IMFCaptureEngineOnSampleCallback_Impl for VideoCallback_Impl {
                 continue;
             };
 
-            let frame = Frame {
-                buffer,
-                width: self.format_info.width,
-                height: self.format_info.height,
-                is_bottom_up: self.format_info.is_bottom_up,
-                pixel_format: self.format_info.pixel_format,
-                timestamp: Duration::from_micros(sample_time as u64 / 10),
-                perf_counter,
-            };
-
             if count <= 3 {
                 eprintln!("[MF_CALLBACK] OnSample #{}: calling user callback", count);
             }
-            let mut callback = self.sample_callback.lock();
-            (callback)(frame);
+            self.deliver(buffer, sample_time, perf_counter);
             if count <= 3 {
                 eprintln!("[MF_CALLBACK] OnSample #{}: user callback returned", count);
             }
@@ -1076,6 +1775,44 @@ impl IMFCaptureEngineOnEventCallback_Impl for VideoCallback_Impl {
     }
 }
 
+/// Sample delivered to a single-frame grab, with its timestamp (100ns units).
+/// `None` for stream ticks that carry no sample.
+type ReadSampleResult = windows_core::Result<Option<(IMFSample, i64)>>;
+
+#[implement(IMFSourceReaderCallback)]
+struct ReaderCallback {
+    sample_tx: Sender<ReadSampleResult>,
+}
+
+impl IMFSourceReaderCallback_Impl for ReaderCallback_Impl {
+    fn OnReadSample(
+        &self,
+        hrstatus: windows_core::HRESULT,
+        _dwstreamindex: u32,
+        _dwstreamflags: u32,
+        lltimestamp: i64,
+        psample: windows_core::Ref<'_, IMFSample>,
+    ) -> windows_core::Result<()> {
+        let result = hrstatus
+            .ok()
+            .map(|_| psample.as_ref().map(|sample| (sample.clone(), lltimestamp)));
+        let _ = self.sample_tx.send(result);
+        Ok(())
+    }
+
+    fn OnFlush(&self, _dwstreamindex: u32) -> windows_core::Result<()> {
+        Ok(())
+    }
+
+    fn OnEvent(
+        &self,
+        _dwstreamindex: u32,
+        _pevent: windows_core::Ref<'_, IMFMediaEvent>,
+    ) -> windows_core::Result<()> {
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct CaptureEngineEvent(IMFMediaEvent);
 
@@ -1173,3 +1910,76 @@ fn retry_on_invalid_request<T>(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sample times (100ns units) of `count` frames at `fps`.
+    fn sample_times(fps: f64, count: i64) -> impl Iterator<Item = i64> {
+        (0..count).map(move |i| (i as f64 * 10_000_000.0 / fps) as i64)
+    }
+
+    fn delivered(throttle: &FrameThrottle, times: impl Iterator<Item = i64>) -> usize {
+        times.filter(|&time| throttle.should_deliver(time)).count()
+    }
+
+    #[test]
+    fn test_frame_rate_distance() {
+        // Continuous 15-60fps range
+        assert_eq!(distance_to_range(30.0, 15.0, 60.0), 0.0);
+        assert_eq!(distance_to_range(15.0, 15.0, 60.0), 0.0);
+        assert_eq!(distance_to_range(60.0, 15.0, 60.0), 0.0);
+        assert_eq!(distance_to_range(120.0, 15.0, 60.0), 60.0);
+        assert_eq!(distance_to_range(5.0, 15.0, 60.0), 10.0);
+
+        // Fixed rate
+        assert_eq!(distance_to_range(24.0, 30.0, 30.0), 6.0);
+    }
+
+    #[test]
+    fn test_frame_rate_from_ratio() {
+        let ratio = |numerator: u64, denominator: u64| (numerator << 32) | denominator;
+
+        assert_eq!(frame_rate_from_ratio(ratio(30, 1)), 30.0);
+        assert!((frame_rate_from_ratio(ratio(30_000, 1001)) - 29.97).abs() < 0.01);
+
+        // Zero denominators aren't usable rates (range rates filter these out)
+        assert!(!frame_rate_from_ratio(ratio(30, 0)).is_finite());
+        assert!(frame_rate_from_ratio(ratio(0, 0)).is_nan());
+    }
+
+    #[test]
+    fn test_frame_throttle_drops_to_output_rate() {
+        let throttle = FrameThrottle::new(30.0).unwrap();
+        // One second of 120fps keeps every fourth frame
+        assert_eq!(delivered(&throttle, sample_times(120.0, 120)), 30);
+    }
+
+    #[test]
+    fn test_frame_throttle_passes_slower_sources() {
+        let throttle = FrameThrottle::new(30.0).unwrap();
+        assert_eq!(delivered(&throttle, sample_times(30.0, 30)), 30);
+
+        let throttle = FrameThrottle::new(30.0).unwrap();
+        assert_eq!(delivered(&throttle, sample_times(24.0, 24)), 24);
+    }
+
+    #[test]
+    fn test_frame_throttle_restarts_on_backwards_timestamps() {
+        let throttle = FrameThrottle::new(30.0).unwrap();
+        assert!(throttle.should_deliver(10_000_000));
+        assert!(!throttle.should_deliver(10_000_001));
+
+        // Stream restarted from zero
+        assert!(throttle.should_deliver(0));
+        assert!(!throttle.should_deliver(1));
+    }
+
+    #[test]
+    fn test_frame_throttle_rejects_invalid_rates() {
+        assert!(FrameThrottle::new(0.0).is_none());
+        assert!(FrameThrottle::new(-30.0).is_none());
+        assert!(FrameThrottle::new(f32::NAN).is_none());
+    }
+}
@@ -38,6 +38,27 @@ impl PhysicalBounds {
     pub fn size(&self) -> PhysicalSize {
         self.size
     }
+
+    /// Smallest bounds enclosing both `self` and `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        let left = self.position.x.min(other.position.x);
+        let top = self.position.y.min(other.position.y);
+        let right = (self.position.x + self.size.width).max(other.position.x + other.size.width);
+        let bottom = (self.position.y + self.size.height).max(other.position.y + other.size.height);
+
+        Self::new(
+            PhysicalPosition::new(left, top),
+            PhysicalSize::new(right - left, bottom - top),
+        )
+    }
+
+    /// Whether the point lies inside the bounds (right and bottom edges excluded).
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        x >= self.position.x
+            && y >= self.position.y
+            && x < self.position.x + self.size.width
+            && y < self.position.y + self.size.height
+    }
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
@@ -119,3 +140,58 @@ impl PhysicalPosition {
         self.y
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds(x: f64, y: f64, width: f64, height: f64) -> PhysicalBounds {
+        PhysicalBounds::new(
+            PhysicalPosition::new(x, y),
+            PhysicalSize::new(width, height),
+        )
+    }
+
+    fn edges(bounds: &PhysicalBounds) -> (f64, f64, f64, f64) {
+        (
+            bounds.position.x,
+            bounds.position.y,
+            bounds.size.width,
+            bounds.size.height,
+        )
+    }
+
+    #[test]
+    fn test_union_with_negative_origin() {
+        // Secondary monitor left of and above the primary
+        let primary = bounds(0.0, 0.0, 1920.0, 1080.0);
+        let secondary = bounds(-1280.0, -200.0, 1280.0, 1024.0);
+
+        let desktop = primary.union(&secondary);
+        assert_eq!(edges(&desktop), (-1280.0, -200.0, 3200.0, 1280.0));
+        assert_eq!(edges(&secondary.union(&primary)), edges(&desktop));
+    }
+
+    #[test]
+    fn test_union_of_disjoint_bounds() {
+        // Gap between the monitors is covered too
+        let left = bounds(0.0, 0.0, 1920.0, 1080.0);
+        let right = bounds(2920.0, 500.0, 1000.0, 800.0);
+
+        let desktop = left.union(&right);
+        assert_eq!(edges(&desktop), (0.0, 0.0, 3920.0, 1300.0));
+        assert!(desktop.contains(2500.0, 100.0));
+    }
+
+    #[test]
+    fn test_contains_excludes_right_and_bottom_edges() {
+        let monitor = bounds(-1920.0, 0.0, 1920.0, 1080.0);
+
+        assert!(monitor.contains(-1920.0, 0.0));
+        assert!(monitor.contains(-1.0, 1079.0));
+        assert!(!monitor.contains(0.0, 500.0));
+        assert!(!monitor.contains(-500.0, 1080.0));
+        assert!(!monitor.contains(-1921.0, 500.0));
+        assert!(!monitor.contains(-500.0, -1.0));
+    }
+}
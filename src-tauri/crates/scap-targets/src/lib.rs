@@ -54,9 +54,35 @@ impl Display {
         self.0.refresh_rate()
     }
 
+    /// Ratio of physical pixels to logical units (e.g. 1.5 at 150% scaling).
+    ///
+    /// Returns 1.0 if the scale can't be queried.
+    pub fn scale_factor(&self) -> f64 {
+        self.0.scale_factor()
+    }
+
     pub fn physical_bounds(&self) -> Option<PhysicalBounds> {
         self.0.physical_bounds()
     }
+
+    /// Bounds of all displays combined (the virtual screen).
+    ///
+    /// Empty bounds at the origin if no display reports its bounds.
+    pub fn virtual_bounds() -> PhysicalBounds {
+        Self::list()
+            .iter()
+            .filter_map(Self::physical_bounds)
+            .reduce(|all, bounds| all.union(&bounds))
+            .unwrap_or_else(|| {
+                PhysicalBounds::new(PhysicalPosition::new(0.0, 0.0), PhysicalSize::new(0.0, 0.0))
+            })
+    }
+
+    /// Whether the physical screen point lies on this display.
+    pub fn contains_point(&self, x: i32, y: i32) -> bool {
+        self.physical_bounds()
+            .is_some_and(|bounds| bounds.contains(x as f64, y as f64))
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
@@ -92,6 +118,16 @@ impl Window {
         WindowImpl::list().into_iter().map(Self).collect()
     }
 
+    /// Capturable windows ordered front to back, so the topmost window comes first.
+    pub fn list_by_zorder() -> Vec<Self> {
+        WindowImpl::list_by_zorder().into_iter().map(Self).collect()
+    }
+
+    /// Position in the desktop Z-order (0 = topmost), or None if the window no longer exists.
+    pub fn z_index(&self) -> Option<u32> {
+        self.0.z_index()
+    }
+
     pub fn id(&self) -> WindowId {
         WindowId(self.0.id())
     }
@@ -100,6 +136,14 @@ impl Window {
         Self::list().into_iter().find(|d| &d.id() == id)
     }
 
+    /// Look up a window without enumerating all windows.
+    ///
+    /// Unlike `from_id` this also finds minimized and hidden windows, and is cheap
+    /// enough to call every frame. Returns None once the window has been closed.
+    pub fn from_id_unfiltered(id: &WindowId) -> Option<Self> {
+        WindowImpl::from_raw_id(id.0).map(Self)
+    }
+
     pub fn physical_size(&self) -> Option<PhysicalSize> {
         self.0.physical_size()
     }
@@ -127,6 +171,22 @@ impl Window {
     pub fn physical_bounds(&self) -> Option<PhysicalBounds> {
         self.0.physical_bounds()
     }
+
+    /// Client area in physical screen coordinates, excluding title bar and borders.
+    pub fn physical_client_bounds(&self) -> Option<PhysicalBounds> {
+        self.0.physical_client_bounds()
+    }
+
+    pub fn is_minimized(&self) -> bool {
+        self.0.is_minimized()
+    }
+
+    /// The window's application icon, PNG-encoded.
+    ///
+    /// Returns the largest icon the window provides, which may be as small as 16x16.
+    pub fn icon(&self) -> Option<Vec<u8>> {
+        self.0.icon()
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
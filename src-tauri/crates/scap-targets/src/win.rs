@@ -5,14 +5,16 @@ use windows::{
     core::PCWSTR,
     Graphics::Capture::GraphicsCaptureItem,
     Win32::{
-        Foundation::{CloseHandle, BOOL, HWND, LPARAM, POINT, RECT, TRUE},
+        Foundation::{CloseHandle, BOOL, HWND, LPARAM, POINT, RECT, TRUE, WPARAM},
         Graphics::{
             Dwm::{DwmGetWindowAttribute, DWMWA_EXTENDED_FRAME_BOUNDS},
             Gdi::{
-                EnumDisplayDevicesW, EnumDisplayMonitors, EnumDisplaySettingsW, GetMonitorInfoW,
-                MonitorFromPoint, MonitorFromWindow, DEVMODEW, DISPLAY_DEVICEW,
-                ENUM_CURRENT_SETTINGS, HDC, HMONITOR, MONITORINFOEXW, MONITOR_DEFAULTTONEAREST,
-                MONITOR_DEFAULTTONULL,
+                ClientToScreen, DeleteObject, EnumDisplayDevicesW, EnumDisplayMonitors,
+                EnumDisplaySettingsW, GetDC, GetDIBits, GetMonitorInfoW, GetObjectW,
+                MonitorFromPoint, MonitorFromWindow, ReleaseDC, BITMAP, BITMAPINFO,
+                BITMAPINFOHEADER, BI_RGB, DEVMODEW, DIB_RGB_COLORS, DISPLAY_DEVICEW,
+                ENUM_CURRENT_SETTINGS, HBITMAP, HDC, HMONITOR, MONITORINFOEXW,
+                MONITOR_DEFAULTTONEAREST, MONITOR_DEFAULTTONULL,
             },
         },
         System::{
@@ -25,9 +27,12 @@ use windows::{
         UI::{
             HiDpi::{GetDpiForMonitor, GetDpiForWindow, MDT_EFFECTIVE_DPI},
             WindowsAndMessaging::{
-                EnumChildWindows, GetCursorPos, GetDesktopWindow, GetWindowLongPtrW, GetWindowRect,
-                GetWindowTextLengthW, GetWindowTextW, GetWindowThreadProcessId, IsIconic,
-                IsWindowVisible, GWL_EXSTYLE, GWL_STYLE, WS_CHILD, WS_EX_TOOLWINDOW,
+                EnumChildWindows, GetClassLongPtrW, GetClientRect, GetCursorPos, GetDesktopWindow,
+                GetIconInfo, GetTopWindow, GetWindow, GetWindowLongPtrW, GetWindowRect,
+                GetWindowTextLengthW, GetWindowTextW, GetWindowThreadProcessId, IsIconic, IsWindow,
+                IsWindowVisible, SendMessageTimeoutW, GCLP_HICON, GCLP_HICONSM, GWL_EXSTYLE,
+                GWL_STYLE, GW_HWNDNEXT, HICON, ICONINFO, ICON_BIG, ICON_SMALL, ICON_SMALL2,
+                SMTO_ABORTIFHUNG, WM_GETICON, WS_CHILD, WS_EX_TOOLWINDOW,
             },
         },
     },
@@ -35,6 +40,12 @@ use windows::{
 
 use crate::bounds::{LogicalSize, PhysicalBounds, PhysicalPosition, PhysicalSize};
 
+/// DPI at 100% scaling.
+const BASE_DPI: f64 = 96.0;
+
+/// How long to wait for a window to answer `WM_GETICON` before giving up.
+const ICON_QUERY_TIMEOUT_MS: u32 = 100;
+
 #[derive(Clone, Copy)]
 pub struct DisplayImpl(pub HMONITOR);
 
@@ -91,16 +102,22 @@ impl DisplayImpl {
         self.0 .0 as u64
     }
 
+    fn effective_dpi(&self) -> Option<u32> {
+        let mut dpi_x = 0;
+        unsafe { GetDpiForMonitor(self.0, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut 0) }.ok()?;
+        (dpi_x != 0).then_some(dpi_x)
+    }
+
+    /// DPI scale of the monitor (1.5 at 150%), or 1.0 if it can't be queried.
+    pub fn scale_factor(&self) -> f64 {
+        self.effective_dpi()
+            .map_or(1.0, |dpi| dpi as f64 / BASE_DPI)
+    }
+
     pub fn logical_size(&self) -> Option<LogicalSize> {
         let physical_size = self.physical_size()?;
 
-        let dpi = unsafe {
-            let mut dpi_x = 0;
-            GetDpiForMonitor(self.0, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut 0).ok()?;
-            dpi_x
-        };
-
-        let scale = dpi as f64 / 96.0;
+        let scale = self.effective_dpi()? as f64 / BASE_DPI;
 
         Some(LogicalSize::new(
             physical_size.width() / scale,
@@ -256,10 +273,33 @@ impl WindowImpl {
         context.list
     }
 
+    /// Capturable windows ordered front to back by Z-order.
+    pub fn list_by_zorder() -> Vec<Self> {
+        let current_process_id = unsafe { GetCurrentProcessId() };
+
+        top_level_windows()
+            .filter(|hwnd| is_window_valid(*hwnd, current_process_id))
+            .map(Self)
+            .collect()
+    }
+
+    /// Position in the top-level Z-order (0 = topmost), or None if the window is gone.
+    pub fn z_index(&self) -> Option<u32> {
+        top_level_windows()
+            .position(|hwnd| hwnd == self.0)
+            .map(|index| index as u32)
+    }
+
     pub fn id(&self) -> u64 {
         self.0 .0 as u64
     }
 
+    /// Wrap a window handle without enumerating, as long as the window still exists.
+    pub fn from_raw_id(id: u64) -> Option<Self> {
+        let hwnd = HWND(id as isize as *mut std::ffi::c_void);
+        unsafe { IsWindow(hwnd) }.as_bool().then_some(Self(hwnd))
+    }
+
     pub fn owner_name(&self) -> Option<String> {
         unsafe {
             let mut process_id = 0u32;
@@ -307,7 +347,6 @@ impl WindowImpl {
             )
             .ok()?;
 
-            const BASE_DPI: f64 = 96.0;
             let dpi = match GetDpiForWindow(self.0) {
                 0 => BASE_DPI as u32,
                 dpi => dpi,
@@ -345,10 +384,37 @@ impl WindowImpl {
         }
     }
 
+    /// Client area in screen coordinates (excludes the title bar and borders).
+    pub fn physical_client_bounds(&self) -> Option<PhysicalBounds> {
+        let mut rect = RECT::default();
+        let mut origin = POINT::default();
+        unsafe {
+            GetClientRect(self.0, &mut rect).ok()?;
+            if !ClientToScreen(self.0, &mut origin).as_bool() {
+                return None;
+            }
+        }
+
+        Some(PhysicalBounds {
+            position: PhysicalPosition {
+                x: origin.x as f64,
+                y: origin.y as f64,
+            },
+            size: PhysicalSize {
+                width: (rect.right - rect.left) as f64,
+                height: (rect.bottom - rect.top) as f64,
+            },
+        })
+    }
+
     pub fn physical_size(&self) -> Option<PhysicalSize> {
         Some(self.physical_bounds()?.size())
     }
 
+    pub fn is_minimized(&self) -> bool {
+        unsafe { IsIconic(self.0).as_bool() }
+    }
+
     pub fn display(&self) -> Option<DisplayImpl> {
         let hwmonitor = unsafe { MonitorFromWindow(self.0, MONITOR_DEFAULTTONULL) };
         if hwmonitor.is_invalid() {
@@ -380,12 +446,145 @@ impl WindowImpl {
         .ok()
     }
 
+    /// The window's application icon as PNG, at the largest size it provides.
+    pub fn icon(&self) -> Option<Vec<u8>> {
+        let (width, height, rgba) = unsafe { icon_to_rgba(self.hicon()?) }?;
+        let image = image::RgbaImage::from_raw(width, height, rgba)?;
+
+        let mut png = Vec::new();
+        image
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .ok()?;
+        Some(png)
+    }
+
+    /// Icon set on the window, falling back to its class icon. Prefers large over small.
+    fn hicon(&self) -> Option<HICON> {
+        unsafe {
+            for icon_type in [ICON_BIG, ICON_SMALL2, ICON_SMALL] {
+                let mut result = 0usize;
+                // Timeout so a hung window can't stall the picker
+                let sent = SendMessageTimeoutW(
+                    self.0,
+                    WM_GETICON,
+                    WPARAM(icon_type as usize),
+                    LPARAM(0),
+                    SMTO_ABORTIFHUNG,
+                    ICON_QUERY_TIMEOUT_MS,
+                    Some(&mut result),
+                );
+                if sent.0 != 0 && result != 0 {
+                    return Some(HICON(result as *mut _));
+                }
+            }
+
+            [GCLP_HICON, GCLP_HICONSM]
+                .into_iter()
+                .map(|index| GetClassLongPtrW(self.0, index))
+                .find(|handle| *handle != 0)
+                .map(|handle| HICON(handle as *mut _))
+        }
+    }
+
     pub fn try_as_capture_item(&self) -> windows::core::Result<GraphicsCaptureItem> {
         let interop = windows::core::factory::<GraphicsCaptureItem, IGraphicsCaptureItemInterop>()?;
         unsafe { interop.CreateForWindow(self.0) }
     }
 }
 
+/// Convert a color icon to (width, height, RGBA pixels).
+///
+/// Icons without an alpha channel get their transparency from the icon mask.
+/// Monochrome icons are not supported.
+unsafe fn icon_to_rgba(hicon: HICON) -> Option<(u32, u32, Vec<u8>)> {
+    let mut info = ICONINFO::default();
+    GetIconInfo(hicon, &mut info).ok()?;
+
+    let color = (!info.hbmColor.is_invalid())
+        .then(|| bitmap_to_bgra(info.hbmColor))
+        .flatten();
+    let mask = (!info.hbmMask.is_invalid())
+        .then(|| bitmap_to_bgra(info.hbmMask))
+        .flatten();
+
+    if !info.hbmColor.is_invalid() {
+        let _ = DeleteObject(info.hbmColor);
+    }
+    if !info.hbmMask.is_invalid() {
+        let _ = DeleteObject(info.hbmMask);
+    }
+
+    let (width, height, mut pixels) = color?;
+
+    if !pixels.chunks_exact(4).any(|p| p[3] != 0) {
+        // No alpha channel: mask pixels are white where the icon is transparent
+        let mask = mask.filter(|(w, h, _)| *w == width && *h >= height);
+        for (i, pixel) in pixels.chunks_exact_mut(4).enumerate() {
+            let transparent = mask.as_ref().is_some_and(|(_, _, m)| m[i * 4] != 0);
+            pixel[3] = if transparent { 0 } else { 255 };
+        }
+    }
+
+    // BGRA -> RGBA
+    for pixel in pixels.chunks_exact_mut(4) {
+        pixel.swap(0, 2);
+    }
+
+    Some((width, height, pixels))
+}
+
+/// Read a bitmap as top-down 32-bit BGRA.
+unsafe fn bitmap_to_bgra(bitmap: HBITMAP) -> Option<(u32, u32, Vec<u8>)> {
+    let mut bm = BITMAP::default();
+    if GetObjectW(
+        bitmap,
+        mem::size_of::<BITMAP>() as i32,
+        Some(&mut bm as *mut _ as *mut _),
+    ) == 0
+    {
+        return None;
+    }
+
+    let width = u32::try_from(bm.bmWidth).ok().filter(|w| *w > 0)?;
+    let height = u32::try_from(bm.bmHeight).ok().filter(|h| *h > 0)?;
+
+    let mut bmi = BITMAPINFO {
+        bmiHeader: BITMAPINFOHEADER {
+            biSize: mem::size_of::<BITMAPINFOHEADER>() as u32,
+            biWidth: width as i32,
+            biHeight: -(height as i32), // Top-down DIB
+            biPlanes: 1,
+            biBitCount: 32,
+            biCompression: BI_RGB.0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut pixels = vec![0u8; (width * height * 4) as usize];
+
+    let screen_dc = GetDC(None);
+    let lines = GetDIBits(
+        screen_dc,
+        bitmap,
+        0,
+        height,
+        Some(pixels.as_mut_ptr() as *mut _),
+        &mut bmi,
+        DIB_RGB_COLORS,
+    );
+    ReleaseDC(None, screen_dc);
+
+    (lines != 0).then_some((width, height, pixels))
+}
+
+/// All top-level windows, front to back.
+fn top_level_windows() -> impl Iterator<Item = HWND> {
+    let topmost = unsafe { GetTopWindow(None) }.ok();
+    std::iter::successors(topmost, |hwnd| {
+        unsafe { GetWindow(*hwnd, GW_HWNDNEXT) }.ok()
+    })
+}
+
 fn is_window_valid(hwnd: HWND, current_process_id: u32) -> bool {
     unsafe {
         if !IsWindowVisible(hwnd).as_bool() || IsIconic(hwnd).as_bool() {
@@ -112,11 +112,9 @@ pub fn get_windows() -> Result<Vec<WindowInfo>, CaptureError> {
     Ok(infos)
 }
 
-/// Capture a window using full monitor capture + crop.
-/// This properly captures WebView2/DWM-composited windows that PrintWindow misses.
-/// Reuses capture_screen_region_raw which handles DXGI capture properly.
+/// Get a window's on-screen bounds using DWM (excludes the drop shadow).
 #[cfg(target_os = "windows")]
-pub fn capture_window_xcap(hwnd_value: isize) -> Result<(Vec<u8>, u32, u32), CaptureError> {
+pub fn window_screen_bounds(hwnd_value: isize) -> Result<ScreenRegionSelection, CaptureError> {
     use windows::Win32::Foundation::{HWND, RECT};
     use windows::Win32::Graphics::Dwm::{DwmGetWindowAttribute, DWMWA_EXTENDED_FRAME_BOUNDS};
 
@@ -140,8 +138,6 @@ pub fn capture_window_xcap(hwnd_value: isize) -> Result<(Vec<u8>, u32, u32), Cap
         )));
     }
 
-    let x = dwm_rect.left;
-    let y = dwm_rect.top;
     let width = (dwm_rect.right - dwm_rect.left) as u32;
     let height = (dwm_rect.bottom - dwm_rect.top) as u32;
 
@@ -151,18 +147,28 @@ pub fn capture_window_xcap(hwnd_value: isize) -> Result<(Vec<u8>, u32, u32), Cap
         ));
     }
 
+    Ok(ScreenRegionSelection {
+        x: dwm_rect.left,
+        y: dwm_rect.top,
+        width,
+        height,
+    })
+}
+
+/// Capture a window using full monitor capture + crop.
+/// This properly captures WebView2/DWM-composited windows that PrintWindow misses.
+/// Reuses capture_screen_region_raw which handles DXGI capture properly.
+#[cfg(target_os = "windows")]
+pub fn capture_window_xcap(hwnd_value: isize) -> Result<(Vec<u8>, u32, u32), CaptureError> {
+    let bounds = window_screen_bounds(hwnd_value)?;
+
     println!(
         "[CAPTURE] Window capture via screen region: hwnd={} (0x{:X}), bounds=({},{}) {}x{}",
-        hwnd_value, hwnd_value, x, y, width, height
+        hwnd_value, hwnd_value, bounds.x, bounds.y, bounds.width, bounds.height
     );
 
     // Use the same capture path as Area mode (which works)
-    capture_screen_region_raw(ScreenRegionSelection {
-        x,
-        y,
-        width,
-        height,
-    })
+    capture_screen_region_raw(bounds)
 }
 
 #[cfg(not(target_os = "windows"))]
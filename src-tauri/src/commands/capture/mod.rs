@@ -3,9 +3,12 @@
 //! Uses xcap (BitBlt) for all captures:
 //! - Fullscreen: Direct monitor capture
 //! - Region: Screen region capture
+//! - Multi-region: Each region captured and composited over their bounding box
 //! - Window: Screen capture at DWM bounds with border inset
+//! - Scrolling: Repeated region capture while scrolling, stitched vertically
 
 pub mod fallback;
+pub mod scrolling;
 pub mod types;
 
 pub use types::{
@@ -15,7 +18,7 @@ pub use types::{
 
 use std::io::Write;
 use std::sync::atomic::{AtomicI32, Ordering};
-use tauri::{command, Emitter};
+use tauri::{command, AppHandle, Emitter};
 
 // Track which monitor is currently detecting windows to prevent duplicate borders
 // When a monitor calls get_window_at_point, it becomes the "active" monitor
@@ -32,29 +35,17 @@ pub async fn get_monitors() -> Result<Vec<MonitorInfo>, String> {
 /// This is useful for capturing all monitors at once.
 #[command]
 pub async fn get_virtual_screen_bounds() -> Result<VirtualScreenBounds, String> {
-    let monitors = fallback::get_monitors().map_err(|e| e.to_string())?;
+    let bounds = scap_targets::Display::virtual_bounds();
 
-    if monitors.is_empty() {
+    if bounds.size().width() <= 0.0 || bounds.size().height() <= 0.0 {
         return Err("No monitors found".to_string());
     }
 
-    let mut min_x = i32::MAX;
-    let mut min_y = i32::MAX;
-    let mut max_x = i32::MIN;
-    let mut max_y = i32::MIN;
-
-    for mon in &monitors {
-        min_x = min_x.min(mon.x);
-        min_y = min_y.min(mon.y);
-        max_x = max_x.max(mon.x + mon.width as i32);
-        max_y = max_y.max(mon.y + mon.height as i32);
-    }
-
     Ok(VirtualScreenBounds {
-        x: min_x,
-        y: min_y,
-        width: (max_x - min_x) as u32,
-        height: (max_y - min_y) as u32,
+        x: bounds.position().x() as i32,
+        y: bounds.position().y() as i32,
+        width: bounds.size().width() as u32,
+        height: bounds.size().height() as u32,
     })
 }
 
@@ -502,6 +493,64 @@ pub async fn capture_screen_region_fast(
     })
 }
 
+/// Fast capture of several screen regions (a multi-region selection) into one image.
+///
+/// Each region is captured on its own and placed at its position within the
+/// regions' bounding box. Uncovered space between them stays transparent.
+pub async fn capture_screen_regions_fast(
+    regions: Vec<ScreenRegionSelection>,
+) -> Result<FastCaptureResult, String> {
+    let bounds = regions_bounding_box(&regions).ok_or("No regions to capture")?;
+
+    let mut captures = Vec::with_capacity(regions.len());
+    for region in regions {
+        let (rgba_data, width, height) = capture_region_dxgi(&region)?;
+        let image = image::RgbaImage::from_raw(width, height, rgba_data)
+            .ok_or("Region capture returned a truncated image")?;
+        captures.push((region, image));
+    }
+
+    let image = composite_regions(&bounds, &captures);
+    let file_path = write_rgba_to_temp_file(image.as_raw(), bounds.width, bounds.height)?;
+    Ok(FastCaptureResult {
+        file_path,
+        width: bounds.width,
+        height: bounds.height,
+        has_transparency: true,
+    })
+}
+
+/// Smallest selection covering every region, or `None` if there are none.
+fn regions_bounding_box(regions: &[ScreenRegionSelection]) -> Option<ScreenRegionSelection> {
+    let left = regions.iter().map(|r| r.x).min()?;
+    let top = regions.iter().map(|r| r.y).min()?;
+    let right = regions.iter().map(|r| r.x + r.width as i32).max()?;
+    let bottom = regions.iter().map(|r| r.y + r.height as i32).max()?;
+    Some(ScreenRegionSelection {
+        x: left,
+        y: top,
+        width: (right - left) as u32,
+        height: (bottom - top) as u32,
+    })
+}
+
+/// Place region captures on a transparent canvas covering `bounds`.
+fn composite_regions(
+    bounds: &ScreenRegionSelection,
+    captures: &[(ScreenRegionSelection, image::RgbaImage)],
+) -> image::RgbaImage {
+    let mut canvas = image::RgbaImage::new(bounds.width, bounds.height);
+    for (region, image) in captures {
+        image::imageops::replace(
+            &mut canvas,
+            image,
+            i64::from(region.x - bounds.x),
+            i64::from(region.y - bounds.y),
+        );
+    }
+    canvas
+}
+
 /// Fast capture of fullscreen - returns file path instead of base64.
 /// Uses DXGI Desktop Duplication.
 #[command]
@@ -516,6 +565,45 @@ pub async fn capture_fullscreen_fast() -> Result<FastCaptureResult, String> {
     })
 }
 
+/// Scrolling capture of a window - scrolls it and stitches the frames into one
+/// tall image. Captures `region` (screen coordinates) if given, otherwise the
+/// window bounds. Emits `scrolling-capture-progress` after each frame.
+#[command]
+pub async fn capture_scrolling(
+    app: AppHandle,
+    window_id: isize,
+    region: Option<ScreenRegionSelection>,
+) -> Result<FastCaptureResult, String> {
+    let region = match region {
+        Some(region) => region,
+        None => fallback::window_screen_bounds(window_id)?,
+    };
+    println!(
+        "[CAPTURE] Scrolling capture for hwnd={}, region=({},{}) {}x{}",
+        window_id, region.x, region.y, region.width, region.height
+    );
+
+    let image = tokio::task::spawn_blocking(move || {
+        scrolling::capture_scrolling(window_id, &region, |frame, height| {
+            let _ = app.emit(
+                "scrolling-capture-progress",
+                serde_json::json!({ "frame": frame, "height": height }),
+            );
+        })
+    })
+    .await
+    .map_err(|e| format!("Scrolling capture task failed: {}", e))??;
+
+    let (width, height) = image.dimensions();
+    let file_path = write_rgba_to_temp_file(image.as_raw(), width, height)?;
+    Ok(FastCaptureResult {
+        file_path,
+        width,
+        height,
+        has_transparency: false,
+    })
+}
+
 /// Read raw RGBA data from a temp file (for converting to PNG when saving).
 #[command]
 pub async fn read_rgba_file(file_path: String) -> Result<CaptureResult, String> {
@@ -571,3 +659,45 @@ pub async fn read_rgba_file(file_path: String) -> Result<CaptureResult, String>
 pub async fn cleanup_rgba_file(file_path: String) -> Result<(), String> {
     std::fs::remove_file(&file_path).map_err(|e| format!("Failed to delete temp file: {}", e))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    fn region(x: i32, y: i32, width: u32, height: u32) -> ScreenRegionSelection {
+        ScreenRegionSelection {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_composite_regions() {
+        let red = Rgba([255, 0, 0, 255]);
+        let blue = Rgba([0, 0, 255, 255]);
+        let regions = vec![region(-100, 50, 20, 10), region(-60, 80, 10, 30)];
+        let bounds = regions_bounding_box(&regions).unwrap();
+        assert_eq!(
+            (bounds.x, bounds.y, bounds.width, bounds.height),
+            (-100, 50, 50, 60)
+        );
+
+        let captures = vec![
+            (regions[0].clone(), RgbaImage::from_pixel(20, 10, red)),
+            (regions[1].clone(), RgbaImage::from_pixel(10, 30, blue)),
+        ];
+        let image = composite_regions(&bounds, &captures);
+
+        assert_eq!(image.dimensions(), (50, 60));
+        assert_eq!(*image.get_pixel(0, 0), red);
+        assert_eq!(*image.get_pixel(19, 9), red);
+        assert_eq!(*image.get_pixel(40, 30), blue);
+        assert_eq!(*image.get_pixel(49, 59), blue);
+        // Between the regions stays transparent
+        assert_eq!(image.get_pixel(30, 20)[3], 0);
+        assert!(regions_bounding_box(&[]).is_none());
+    }
+}
@@ -0,0 +1,267 @@
+//! Scrolling capture: stitches successive screenshots of a scrolling window
+//! into one tall image.
+//!
+//! Each step captures the region, scrolls the target window with the mouse
+//! wheel, captures again, and finds how far the content moved by matching a
+//! strip of the new frame against the previous one. Only the newly revealed
+//! rows are appended. Capture stops when a scroll reveals nothing new.
+
+use std::time::Duration;
+
+use image::{imageops, GrayImage, RgbaImage};
+
+use super::fallback;
+use super::types::ScreenRegionSelection;
+
+/// Mouse wheel notches sent per scroll step
+const SCROLL_NOTCHES: i32 = 3;
+
+/// Time for the target to finish (smooth) scrolling before the next capture
+const SCROLL_SETTLE_MS: u64 = 350;
+
+/// Upper bound on captured frames
+const MAX_FRAMES: usize = 60;
+
+/// Upper bound on the stitched image height
+const MAX_HEIGHT: u32 = 30_000;
+
+/// Rows in the strip matched against the previous frame
+const TEMPLATE_ROWS: u32 = 48;
+
+/// Mean absolute luma difference below which two strips are considered equal
+const MATCH_TOLERANCE: f64 = 3.0;
+
+/// Only every Nth column is compared (speed vs. accuracy)
+const COLUMN_STEP: usize = 4;
+
+/// How a new frame relates to the previous one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollOffset {
+    /// Frames are identical - the end of the content was reached
+    Unchanged,
+    /// Content moved up by this many rows
+    Scrolled(u32),
+    /// No overlap with the previous frame was found
+    Lost,
+}
+
+/// Find how far the content of `next` scrolled relative to `prev`.
+///
+/// The template strip is taken a quarter of the way down the new frame so a
+/// sticky header at the top doesn't match itself at offset zero.
+pub fn find_scroll_offset(prev: &GrayImage, next: &GrayImage) -> ScrollOffset {
+    let (_, height) = prev.dimensions();
+    if next.dimensions() != prev.dimensions() || height == 0 {
+        return ScrollOffset::Lost;
+    }
+
+    if strip_diff(prev, 0, next, 0, height) <= MATCH_TOLERANCE {
+        return ScrollOffset::Unchanged;
+    }
+
+    let rows = TEMPLATE_ROWS.min(height / 4);
+    if rows == 0 {
+        return ScrollOffset::Lost;
+    }
+    let anchor = height / 4;
+
+    // The template at `anchor` in `next` sits at `anchor + offset` in `prev`
+    let best = (1..=height - anchor - rows)
+        .map(|offset| {
+            (
+                offset,
+                strip_diff(prev, anchor + offset, next, anchor, rows),
+            )
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+
+    match best {
+        Some((offset, diff)) if diff <= MATCH_TOLERANCE => ScrollOffset::Scrolled(offset),
+        _ => ScrollOffset::Lost,
+    }
+}
+
+/// Mean absolute difference between `rows` rows of two images.
+fn strip_diff(a: &GrayImage, a_top: u32, b: &GrayImage, b_top: u32, rows: u32) -> f64 {
+    let width = a.width() as usize;
+    let mut total = 0u64;
+    let mut count = 0u64;
+
+    for row in 0..rows {
+        let a_start = (a_top + row) as usize * width;
+        let b_start = (b_top + row) as usize * width;
+        let a_row = &a.as_raw()[a_start..a_start + width];
+        let b_row = &b.as_raw()[b_start..b_start + width];
+        for (pa, pb) in a_row
+            .iter()
+            .step_by(COLUMN_STEP)
+            .zip(b_row.iter().step_by(COLUMN_STEP))
+        {
+            total += pa.abs_diff(*pb) as u64;
+            count += 1;
+        }
+    }
+
+    if count == 0 {
+        return f64::MAX;
+    }
+    total as f64 / count as f64
+}
+
+/// Append the bottom `rows` rows of `frame` to the raw RGBA `canvas`.
+///
+/// The canvas has the frame's width and grows in place, so stitching stays
+/// linear in the final image size.
+pub fn append_rows(canvas: &mut Vec<u8>, frame: &RgbaImage, rows: u32) {
+    let rows = rows.min(frame.height()) as usize;
+    let raw = frame.as_raw();
+    let row_bytes = frame.width() as usize * 4;
+    canvas.extend_from_slice(&raw[raw.len() - rows * row_bytes..]);
+}
+
+/// Capture `region` while scrolling `window_id`, stitching the frames.
+///
+/// `on_frame` is called after each stitched frame with the frame index and
+/// the current image height.
+pub fn capture_scrolling(
+    window_id: isize,
+    region: &ScreenRegionSelection,
+    mut on_frame: impl FnMut(usize, u32),
+) -> Result<RgbaImage, String> {
+    focus_window(window_id);
+    std::thread::sleep(Duration::from_millis(SCROLL_SETTLE_MS));
+
+    let first = capture_frame(region)?;
+    let mut prev = imageops::grayscale(&first);
+    let width = first.width();
+    let row_bytes = (width as usize * 4).max(1);
+    let mut canvas = first.into_raw();
+    let canvas_height = |canvas: &Vec<u8>| (canvas.len() / row_bytes) as u32;
+
+    for frame_idx in 1..MAX_FRAMES {
+        scroll_down(region);
+        std::thread::sleep(Duration::from_millis(SCROLL_SETTLE_MS));
+
+        let frame = capture_frame(region)?;
+        let gray = imageops::grayscale(&frame);
+
+        match find_scroll_offset(&prev, &gray) {
+            ScrollOffset::Unchanged => {
+                log::info!("[SCROLL] Reached end of content after {} frames", frame_idx);
+                break;
+            },
+            ScrollOffset::Lost => {
+                log::warn!("[SCROLL] Lost overlap at frame {}, stopping", frame_idx);
+                break;
+            },
+            ScrollOffset::Scrolled(rows) => {
+                append_rows(&mut canvas, &frame, rows);
+            },
+        }
+
+        let height = canvas_height(&canvas);
+        on_frame(frame_idx, height);
+        if height >= MAX_HEIGHT {
+            log::warn!("[SCROLL] Reached maximum height {}, stopping", MAX_HEIGHT);
+            break;
+        }
+        prev = gray;
+    }
+
+    let height = canvas_height(&canvas);
+    RgbaImage::from_raw(width, height, canvas)
+        .ok_or_else(|| "Failed to create stitched image".to_string())
+}
+
+/// Capture a screen region as an RGBA image.
+fn capture_frame(region: &ScreenRegionSelection) -> Result<RgbaImage, String> {
+    let (rgba, width, height) =
+        fallback::capture_screen_region_raw(region.clone()).map_err(|e| e.to_string())?;
+    RgbaImage::from_raw(width, height, rgba)
+        .ok_or_else(|| "Failed to create image from buffer".to_string())
+}
+
+/// Bring the target window to the foreground so it receives wheel input.
+fn focus_window(window_id: isize) {
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::UI::WindowsAndMessaging::SetForegroundWindow;
+
+    unsafe {
+        let _ = SetForegroundWindow(HWND(window_id as *mut std::ffi::c_void));
+    }
+}
+
+/// Scroll down with the mouse wheel over the center of the region.
+fn scroll_down(region: &ScreenRegionSelection) {
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        SendInput, INPUT, INPUT_0, INPUT_MOUSE, MOUSEEVENTF_WHEEL, MOUSEINPUT,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{SetCursorPos, WHEEL_DELTA};
+
+    let input = INPUT {
+        r#type: INPUT_MOUSE,
+        Anonymous: INPUT_0 {
+            mi: MOUSEINPUT {
+                mouseData: -(WHEEL_DELTA as i32) * SCROLL_NOTCHES,
+                dwFlags: MOUSEEVENTF_WHEEL,
+                ..Default::default()
+            },
+        },
+    };
+
+    unsafe {
+        let _ = SetCursorPos(
+            region.x + region.width as i32 / 2,
+            region.y + region.height as i32 / 2,
+        );
+        SendInput(&[input], std::mem::size_of::<INPUT>() as i32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Luma;
+
+    /// A "page" with a distinct value on every row
+    fn page(height: u32) -> GrayImage {
+        GrayImage::from_fn(16, height, |x, y| Luma([((y * 7 + x) % 251) as u8]))
+    }
+
+    /// A viewport of `height` rows starting at `top` of the page
+    fn viewport(page: &GrayImage, top: u32, height: u32) -> GrayImage {
+        imageops::crop_imm(page, 0, top, page.width(), height).to_image()
+    }
+
+    #[test]
+    fn test_find_scroll_offset() {
+        let page = page(1000);
+        let prev = viewport(&page, 0, 400);
+
+        assert_eq!(
+            find_scroll_offset(&prev, &viewport(&page, 120, 400)),
+            ScrollOffset::Scrolled(120)
+        );
+        assert_eq!(find_scroll_offset(&prev, &prev), ScrollOffset::Unchanged);
+        assert_eq!(
+            find_scroll_offset(&prev, &GrayImage::new(16, 400)),
+            ScrollOffset::Lost
+        );
+    }
+
+    #[test]
+    fn test_append_rows_keeps_new_content_only() {
+        let mut canvas = RgbaImage::from_pixel(4, 10, image::Rgba([1, 1, 1, 255])).into_raw();
+        let mut frame = RgbaImage::from_pixel(4, 10, image::Rgba([1, 1, 1, 255]));
+        for y in 7..10 {
+            for x in 0..4 {
+                frame.put_pixel(x, y, image::Rgba([9, 9, 9, 255]));
+            }
+        }
+
+        append_rows(&mut canvas, &frame, 3);
+        let stitched = RgbaImage::from_raw(4, 13, canvas).unwrap();
+        assert_eq!(stitched.get_pixel(0, 9), &image::Rgba([1, 1, 1, 255]));
+        assert_eq!(stitched.get_pixel(0, 10), &image::Rgba([9, 9, 9, 255]));
+    }
+}
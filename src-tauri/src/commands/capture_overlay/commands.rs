@@ -4,8 +4,15 @@
 //! the overlay (confirm selection, cancel, reselect).
 //!
 //! Communication uses an atomic pending command that the overlay polls.
+//! Highlight changes additionally signal an event so the preview overlay can
+//! sleep until there is something to redraw.
 
 use std::sync::atomic::{AtomicI32, AtomicIsize, AtomicU32, AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::HANDLE;
+use windows::Win32::System::Threading::{CreateEventW, SetEvent};
 
 use super::types::OverlayCommand;
 
@@ -15,6 +22,12 @@ static HIGHLIGHTED_MONITOR: AtomicI32 = AtomicI32::new(-1);
 /// Highlighted window HWND (0 = none, use cursor position)
 static HIGHLIGHTED_WINDOW: AtomicIsize = AtomicIsize::new(0);
 
+/// Auto-reset event signaled whenever the highlight changes.
+///
+/// Stored as the raw handle value because `HANDLE` is not `Sync`.
+/// 0 means the event could not be created.
+static HIGHLIGHT_CHANGED_EVENT: OnceLock<isize> = OnceLock::new();
+
 /// Global pending command for the overlay.
 ///
 /// The overlay polls this in its message loop to check for commands
@@ -25,6 +38,11 @@ static PENDING_COMMAND: AtomicU8 = AtomicU8::new(0);
 static PENDING_WIDTH: AtomicU32 = AtomicU32::new(0);
 static PENDING_HEIGHT: AtomicU32 = AtomicU32::new(0);
 
+/// Aspect ratio Shift-drag locks to (0 = unset, lock to a square).
+/// Kept across overlay sessions so the choice sticks for the next drag.
+static LOCKED_ASPECT_WIDTH: AtomicU32 = AtomicU32::new(0);
+static LOCKED_ASPECT_HEIGHT: AtomicU32 = AtomicU32::new(0);
+
 /// Get and clear the pending command.
 ///
 /// Returns the current pending command and resets it to None.
@@ -111,6 +129,28 @@ pub async fn capture_overlay_set_dimensions(width: u32, height: u32) -> Result<(
     Ok(())
 }
 
+/// Set the aspect ratio that holding Shift locks a region drag to.
+///
+/// Called from the toolbar when the user picks a ratio (16:9, 4:3, 1:1).
+#[tauri::command]
+pub async fn capture_overlay_set_aspect_ratio(width: u32, height: u32) -> Result<(), String> {
+    if width == 0 || height == 0 {
+        return Err("Aspect ratio must be non-zero".to_string());
+    }
+    LOCKED_ASPECT_WIDTH.store(width, Ordering::SeqCst);
+    LOCKED_ASPECT_HEIGHT.store(height, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Get the aspect ratio Shift-drag locks to.
+///
+/// Returns None if the toolbar hasn't picked one (lock to a square).
+pub fn get_locked_aspect_ratio() -> Option<(u32, u32)> {
+    let width = LOCKED_ASPECT_WIDTH.load(Ordering::SeqCst);
+    let height = LOCKED_ASPECT_HEIGHT.load(Ordering::SeqCst);
+    (width > 0 && height > 0).then_some((width, height))
+}
+
 /// Highlight a specific monitor in the overlay.
 ///
 /// Called from the display picker panel when the user hovers over a monitor item.
@@ -118,6 +158,7 @@ pub async fn capture_overlay_set_dimensions(width: u32, height: u32) -> Result<(
 #[tauri::command]
 pub async fn capture_overlay_highlight_monitor(monitor_index: i32) -> Result<(), String> {
     HIGHLIGHTED_MONITOR.store(monitor_index, Ordering::SeqCst);
+    notify_highlight_changed();
     Ok(())
 }
 
@@ -128,6 +169,7 @@ pub async fn capture_overlay_highlight_monitor(monitor_index: i32) -> Result<(),
 #[tauri::command]
 pub async fn capture_overlay_highlight_window(hwnd: isize) -> Result<(), String> {
     HIGHLIGHTED_WINDOW.store(hwnd, Ordering::SeqCst);
+    notify_highlight_changed();
     Ok(())
 }
 
@@ -149,6 +191,35 @@ pub fn get_highlighted_window() -> isize {
 pub fn clear_highlights() {
     HIGHLIGHTED_MONITOR.store(-1, Ordering::SeqCst);
     HIGHLIGHTED_WINDOW.store(0, Ordering::SeqCst);
+    notify_highlight_changed();
+}
+
+/// Get the event that is signaled when the highlight changes.
+///
+/// The preview overlay waits on this (together with its message queue)
+/// instead of polling the highlight atomics. Returns an invalid handle
+/// if the event could not be created.
+pub fn highlight_changed_event() -> HANDLE {
+    let raw = *HIGHLIGHT_CHANGED_EVENT.get_or_init(|| unsafe {
+        match CreateEventW(None, false, false, PCWSTR::null()) {
+            Ok(event) => event.0 as isize,
+            Err(e) => {
+                log::warn!("Failed to create highlight change event: {:?}", e);
+                0
+            },
+        }
+    });
+    HANDLE(raw as *mut std::ffi::c_void)
+}
+
+/// Wake anything waiting on the highlight change event.
+pub fn notify_highlight_changed() {
+    let event = highlight_changed_event();
+    if !event.is_invalid() {
+        unsafe {
+            let _ = SetEvent(event);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -178,4 +249,21 @@ mod tests {
 
         assert_eq!(take_pending_command(), OverlayCommand::Cancel);
     }
+
+    #[test]
+    fn test_highlight_change_signals_event() {
+        use windows::Win32::Foundation::WAIT_OBJECT_0;
+        use windows::Win32::System::Threading::WaitForSingleObject;
+
+        let event = highlight_changed_event();
+        assert!(!event.is_invalid());
+
+        clear_highlights();
+
+        // Auto-reset: signaled once, then cleared by the successful wait
+        unsafe {
+            assert_eq!(WaitForSingleObject(event, 0), WAIT_OBJECT_0);
+            assert_ne!(WaitForSingleObject(event, 0), WAIT_OBJECT_0);
+        }
+    }
 }
@@ -12,15 +12,15 @@
 use windows::core::{Interface, Result, PCWSTR};
 use windows::Foundation::Numerics::Matrix3x2;
 use windows::Win32::Graphics::Direct2D::Common::{
-    D2D1_ALPHA_MODE_PREMULTIPLIED, D2D1_COLOR_F, D2D1_PIXEL_FORMAT,
+    D2D1_ALPHA_MODE_PREMULTIPLIED, D2D1_COLOR_F, D2D1_PIXEL_FORMAT, D2D_SIZE_U,
 };
 use windows::Win32::Graphics::Direct2D::{
     D2D1CreateFactory, ID2D1Bitmap1, ID2D1Device, ID2D1DeviceContext, ID2D1Factory1,
     ID2D1RenderTarget, ID2D1SolidColorBrush, ID2D1StrokeStyle1, D2D1_BITMAP_OPTIONS_CANNOT_DRAW,
-    D2D1_BITMAP_OPTIONS_TARGET, D2D1_BITMAP_PROPERTIES1, D2D1_BRUSH_PROPERTIES,
-    D2D1_CAP_STYLE_FLAT, D2D1_DASH_STYLE_CUSTOM, D2D1_DEVICE_CONTEXT_OPTIONS_NONE,
-    D2D1_FACTORY_TYPE_SINGLE_THREADED, D2D1_LINE_JOIN_MITER, D2D1_STROKE_STYLE_PROPERTIES1,
-    D2D1_STROKE_TRANSFORM_TYPE_NORMAL,
+    D2D1_BITMAP_OPTIONS_NONE, D2D1_BITMAP_OPTIONS_TARGET, D2D1_BITMAP_PROPERTIES1,
+    D2D1_BRUSH_PROPERTIES, D2D1_CAP_STYLE_FLAT, D2D1_DASH_STYLE_CUSTOM,
+    D2D1_DEVICE_CONTEXT_OPTIONS_NONE, D2D1_FACTORY_TYPE_SINGLE_THREADED, D2D1_LINE_JOIN_MITER,
+    D2D1_STROKE_STYLE_PROPERTIES1, D2D1_STROKE_TRANSFORM_TYPE_NORMAL,
 };
 use windows::Win32::Graphics::Direct3D11::ID3D11Device;
 use windows::Win32::Graphics::DirectWrite::{
@@ -268,3 +268,33 @@ pub fn create_target_bitmap(
 
     unsafe { context.CreateBitmapFromDxgiSurface(surface, Some(&bitmap_props)) }
 }
+
+/// Create a drawable bitmap from opaque BGRA pixels (no row padding).
+///
+/// Used for the magnifier loupe, which uploads a small region each frame.
+pub fn create_pixel_bitmap(
+    context: &ID2D1DeviceContext,
+    width: u32,
+    height: u32,
+    bgra: &[u8],
+) -> Result<ID2D1Bitmap1> {
+    let bitmap_props = D2D1_BITMAP_PROPERTIES1 {
+        pixelFormat: D2D1_PIXEL_FORMAT {
+            format: DXGI_FORMAT_B8G8R8A8_UNORM,
+            alphaMode: D2D1_ALPHA_MODE_PREMULTIPLIED,
+        },
+        dpiX: 96.0,
+        dpiY: 96.0,
+        bitmapOptions: D2D1_BITMAP_OPTIONS_NONE,
+        colorContext: std::mem::ManuallyDrop::new(None),
+    };
+
+    unsafe {
+        context.CreateBitmap(
+            D2D_SIZE_U { width, height },
+            Some(bgra.as_ptr() as *const std::ffi::c_void),
+            width * 4,
+            &bitmap_props,
+        )
+    }
+}
@@ -9,9 +9,12 @@
 //! - Window detection and highlighting (click to select window)
 //! - Region selection with drag (drag to select custom region)
 //! - Crosshair cursor display
+//! - Magnifier loupe with pixel color and selection size
 //! - Semi-transparent overlay with clear selection area
 //! - Resize handles for adjusting selection
 //! - Multi-monitor support
+//! - Color picker (eyedropper) mode
+//! - Multi-region screenshots (Ctrl+drag to add regions, Enter to finish)
 //!
 //! # Architecture
 //!
@@ -22,6 +25,7 @@
 //!   +-- state.rs (overlay state management)
 //!   +-- commands.rs (Tauri commands)
 //!   +-- render.rs (Direct2D rendering)
+//!   +-- snapshot.rs (desktop snapshot for the loupe)
 //!   +-- wndproc.rs (Win32 message handling)
 //!   +-- graphics/ (D3D11, D2D, DirectComposition)
 //!   +-- input/ (hit-testing, window detection)
@@ -31,6 +35,7 @@ pub mod commands;
 mod graphics;
 mod input;
 mod render;
+mod snapshot;
 mod state;
 pub mod types;
 mod wndproc;
@@ -39,6 +44,7 @@ mod wndproc;
 mod tests;
 
 // Re-exports for public API
+pub(crate) use input::get_window_at_point;
 pub use types::{CaptureType, OverlayAction, OverlayMode, OverlayResult, SelectionEvent};
 
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -48,16 +54,19 @@ use tauri::{command, AppHandle, Emitter, Manager};
 use windows::core::PCWSTR;
 use windows::Win32::Foundation::{HWND, POINT};
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
-use windows::Win32::UI::Input::KeyboardAndMouse::GetAsyncKeyState;
+use windows::Win32::System::Threading::INFINITE;
+use windows::Win32::UI::Input::KeyboardAndMouse::{GetAsyncKeyState, VK_SHIFT};
 use windows::Win32::UI::WindowsAndMessaging::{
-    CreateWindowExW, DestroyWindow, DispatchMessageW, GetCursorPos, GetSystemMetrics, LoadCursorW,
-    PeekMessageW, RegisterClassW, SetWindowLongPtrW, ShowWindow, CS_HREDRAW, CS_VREDRAW,
-    GWLP_USERDATA, IDC_CROSS, MSG, PM_REMOVE, SM_CXVIRTUALSCREEN, SM_CYVIRTUALSCREEN,
-    SM_XVIRTUALSCREEN, SM_YVIRTUALSCREEN, SW_SHOW, WINDOW_EX_STYLE, WNDCLASSW, WS_EX_NOACTIVATE,
-    WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_POPUP,
+    CreateWindowExW, DestroyWindow, DispatchMessageW, GetCursorPos, LoadCursorW,
+    MsgWaitForMultipleObjects, PeekMessageW, RegisterClassW, SetWindowLongPtrW, ShowWindow,
+    CS_HREDRAW, CS_VREDRAW, GWLP_USERDATA, IDC_CROSS, MSG, PM_REMOVE, QS_ALLINPUT, SW_HIDE,
+    SW_SHOW, WINDOW_EX_STYLE, WNDCLASSW, WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW, WS_EX_TOPMOST,
+    WS_POPUP,
 };
 
-use commands::{clear_pending_command, take_pending_command, take_pending_dimensions};
+use commands::{
+    clear_pending_command, get_locked_aspect_ratio, take_pending_command, take_pending_dimensions,
+};
 use graphics::{compositor, d2d, d3d};
 use state::{GraphicsState, MonitorInfo, OverlayState};
 use types::*;
@@ -86,12 +95,15 @@ static PREVIEW_SHOULD_STOP: AtomicBool = AtomicBool::new(false);
 /// * `capture_type` - "screenshot", "video", or "gif"
 ///   - Screenshot: immediately captures after selection (no toolbar)
 ///   - Video/GIF: shows toolbar for recording controls
-/// * `source_mode` - "display", "window", or "region"
+/// * `source_mode` - "display", "window", "region", or "color"
 ///   - Display: click on monitor to select entire display
 ///   - Window: click on window to select it
 ///   - Region: drag to select custom region (default)
+///   - Color: click to pick the pixel color under the cursor
 /// * `preselect_monitor` - Optional monitor index to pre-select (skips interactive selection)
 /// * `preselect_window` - Optional window HWND to pre-select (skips interactive selection)
+/// * `capture_delay_secs` - Optional delay between confirming a screenshot and capturing it.
+///   The overlay becomes click-through and shows only a countdown meanwhile.
 ///
 /// # Returns
 /// The selection result if confirmed, or None if cancelled
@@ -103,9 +115,10 @@ pub async fn show_capture_overlay(
     source_mode: Option<String>,
     preselect_monitor: Option<usize>,
     preselect_window: Option<isize>,
+    capture_delay_secs: Option<u32>,
 ) -> Result<Option<OverlayResult>, String> {
-    log::info!("[show_capture_overlay] Called with capture_type: {:?}, source_mode: {:?}, preselect_monitor: {:?}, preselect_window: {:?}", 
-        capture_type, source_mode, preselect_monitor, preselect_window);
+    log::info!("[show_capture_overlay] Called with capture_type: {:?}, source_mode: {:?}, preselect_monitor: {:?}, preselect_window: {:?}, delay: {:?}", 
+        capture_type, source_mode, preselect_monitor, preselect_window, capture_delay_secs);
 
     let app_clone = app.clone();
     let ct = CaptureType::from_str(capture_type.as_deref().unwrap_or("video"));
@@ -162,6 +175,7 @@ pub async fn show_capture_overlay(
             preselect_window_title,
             preselect_monitor,
             preselect_monitor_name,
+            capture_delay_secs.unwrap_or(0),
         )
     })
     .await
@@ -203,6 +217,19 @@ fn bring_window_to_front(hwnd: isize) {
     log::info!("[Overlay] Brought window to foreground");
 }
 
+/// Make the overlay window click-through (mouse input goes to windows below).
+fn make_click_through(hwnd: HWND) {
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetWindowLongW, SetWindowLongW, GWL_EXSTYLE, WS_EX_LAYERED, WS_EX_TRANSPARENT,
+    };
+
+    unsafe {
+        let ex_style = GetWindowLongW(hwnd, GWL_EXSTYLE);
+        let new_style = ex_style | WS_EX_TRANSPARENT.0 as i32 | WS_EX_LAYERED.0 as i32;
+        SetWindowLongW(hwnd, GWL_EXSTYLE, new_style);
+    }
+}
+
 /// Get a window's title by HWND.
 fn get_window_title(hwnd: isize) -> Option<String> {
     use windows::Win32::Foundation::HWND;
@@ -223,14 +250,13 @@ fn get_window_title(hwnd: isize) -> Option<String> {
 
 /// Get the virtual screen bounds (all monitors combined)
 fn get_virtual_screen_bounds() -> (i32, i32, u32, u32) {
-    unsafe {
-        (
-            GetSystemMetrics(SM_XVIRTUALSCREEN),
-            GetSystemMetrics(SM_YVIRTUALSCREEN),
-            GetSystemMetrics(SM_CXVIRTUALSCREEN) as u32,
-            GetSystemMetrics(SM_CYVIRTUALSCREEN) as u32,
-        )
-    }
+    let bounds = scap_targets::Display::virtual_bounds();
+    (
+        bounds.position().x() as i32,
+        bounds.position().y() as i32,
+        bounds.size().width() as u32,
+        bounds.size().height() as u32,
+    )
 }
 
 /// Run the overlay window and message loop.
@@ -244,6 +270,7 @@ fn run_overlay(
     preselect_window_title: Option<String>,
     preselect_monitor_index: Option<usize>,
     preselect_monitor_name: Option<String>,
+    capture_delay_secs: u32,
 ) -> Result<Option<OverlayResult>, String> {
     log::info!("[run_overlay] Starting overlay for {:?} with mode {:?}, preselect: {:?}, window: {:?}/{:?}, monitor: {:?}/{:?}",
         capture_type, overlay_mode, preselect_bounds,
@@ -349,6 +376,38 @@ fn run_overlay(
             Default::default()
         };
 
+        // Snap guides from every monitor's bounds, scaled for the overlay's DPI
+        let monitor_rects = xcap::Monitor::all()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|m| {
+                Some(monitor_info.screen_rect_to_local(Rect::from_xywh(
+                    m.x().ok()?,
+                    m.y().ok()?,
+                    m.width().ok()?,
+                    m.height().ok()?,
+                )))
+            })
+            .collect();
+        let snap = state::SnapGuides::new(
+            monitor_rects,
+            bounds.width(),
+            bounds.height(),
+            windows::Win32::UI::HiDpi::GetDpiForWindow(hwnd),
+        );
+
+        // Snapshot the desktop for the magnifier loupe (the overlay is excluded
+        // from capture, so it's safe to take now)
+        let wants_loupe = matches!(
+            overlay_mode,
+            OverlayMode::RegionSelect | OverlayMode::ColorPick
+        );
+        let snapshot = if wants_loupe && preselect_bounds.is_none() {
+            snapshot::DesktopSnapshot::capture(&monitor_info)
+        } else {
+            None
+        };
+
         let mut state = Box::new(OverlayState {
             app_handle: app,
             capture_type,
@@ -361,6 +420,9 @@ fn run_overlay(
                 position: types::Point::new(initial_cursor_x, initial_cursor_y),
                 hovered_window: None,
             },
+            snap,
+            snapshot,
+            regions: Vec::new(),
             preselected_window_id: preselect_window_id,
             preselected_window_title: preselect_window_title,
             preselected_monitor_index: preselect_monitor_index,
@@ -372,6 +434,7 @@ fn run_overlay(
                 d2d: d2d_resources,
             }),
             should_close: false,
+            capture_delay: state::CaptureDelay::new(capture_delay_secs),
             last_emit_time: Instant::now(),
             result: Default::default(),
         });
@@ -422,12 +485,7 @@ fn run_overlay(
             }
             // Make overlay click-through for locked preselection
             // This is bulletproof - no Z-order fighting with toolbar
-            use windows::Win32::UI::WindowsAndMessaging::{
-                GetWindowLongW, SetWindowLongW, GWL_EXSTYLE, WS_EX_LAYERED, WS_EX_TRANSPARENT,
-            };
-            let ex_style = GetWindowLongW(hwnd, GWL_EXSTYLE);
-            let new_style = ex_style | WS_EX_TRANSPARENT.0 as i32 | WS_EX_LAYERED.0 as i32;
-            SetWindowLongW(hwnd, GWL_EXSTYLE, new_style);
+            make_click_through(hwnd);
             log::info!("[run_overlay] Made overlay click-through for locked preselection");
         }
 
@@ -440,10 +498,37 @@ fn run_overlay(
         // Message loop
         let mut msg = MSG::default();
         let mut esc_was_pressed = false;
+        let mut fill_was_pressed = false;
+        let mut confirm_was_pressed = false;
+        let mut countdown_shown: Option<u32> = None;
 
         loop {
             if state.should_close {
-                break;
+                if !state.begin_capture_delay() {
+                    break;
+                }
+                // Screenshot delay: let clicks reach the desktop so transient UI
+                // can be opened while only the countdown is drawn
+                log::info!(
+                    "[Overlay] Delaying screenshot by {}s",
+                    state.capture_delay.secs
+                );
+                make_click_through(hwnd);
+            }
+
+            if state.capture_delay.deadline.is_some() {
+                let now = Instant::now();
+                if state.capture_delay.is_elapsed(now) {
+                    // Fully hide before handing the result back for capture
+                    let _ = ShowWindow(hwnd, SW_HIDE);
+                    state.should_close = true;
+                    continue;
+                }
+                let remaining = state.capture_delay.remaining_secs(now);
+                if remaining != countdown_shown {
+                    countdown_shown = remaining;
+                    let _ = render::render(&state);
+                }
             }
 
             // Poll ESC key using GetAsyncKeyState
@@ -455,6 +540,46 @@ fn run_overlay(
             }
             esc_was_pressed = esc_pressed;
 
+            // Poll 'M' to expand the selection to the monitor under the cursor
+            let fill_pressed = (GetAsyncKeyState(VK_FILL_MONITOR) as u16 & 0x8000) != 0;
+            if fill_pressed && !fill_was_pressed && state.fill_monitor_under_cursor() {
+                if state.adjustment.is_active {
+                    let screen_sel = state.monitor.local_rect_to_screen(state.adjustment.bounds);
+                    let _ = state
+                        .app_handle
+                        .emit("selection-updated", SelectionEvent::from(screen_sel));
+                }
+                let _ = render::render(&state);
+            }
+            fill_was_pressed = fill_pressed;
+
+            // Poll Enter to finish a multi-region selection (between drags)
+            let confirm_pressed = (GetAsyncKeyState(VK_CONFIRM_REGIONS) as u16 & 0x8000) != 0;
+            if confirm_pressed
+                && !confirm_was_pressed
+                && !state.regions.is_empty()
+                && !state.drag.is_active
+                && !state.adjustment.is_active
+            {
+                log::info!(
+                    "[Overlay] Enter pressed, confirming {} regions",
+                    state.regions.len()
+                );
+                state.confirm_regions(None, OverlayAction::CaptureScreenshot);
+            }
+            confirm_was_pressed = confirm_pressed;
+
+            // Poll Shift to lock the drag to the toolbar's aspect ratio,
+            // re-rendering whenever the constraint toggles mid-drag
+            state.drag.aspect_ratio = get_locked_aspect_ratio();
+            let shift_held = (GetAsyncKeyState(VK_SHIFT.0 as i32) as u16 & 0x8000) != 0;
+            if shift_held != state.drag.shift_held {
+                state.drag.shift_held = shift_held;
+                if state.drag.is_dragging {
+                    let _ = render::render(&state);
+                }
+            }
+
             // Check for pending commands from toolbar
             if state.adjustment.is_active {
                 match take_pending_command() {
@@ -532,6 +657,13 @@ fn run_overlay(
                 height: sel.height(),
                 action: state.result.action,
                 window_id: state.result.window_id,
+                color: state.result.color,
+                regions: state
+                    .result
+                    .regions
+                    .iter()
+                    .map(|&r| SelectionEvent::from(r))
+                    .collect(),
             })
         } else {
             None
@@ -608,6 +740,7 @@ fn run_overlay(
 ///
 /// This overlay is click-through (WS_EX_TRANSPARENT) and only renders
 /// highlights based on HIGHLIGHTED_MONITOR/HIGHLIGHTED_WINDOW atomics.
+/// It sleeps between highlight changes, so it costs no CPU while idle.
 /// Used by picker panels to show visual feedback while hovering items.
 #[command]
 pub async fn start_highlight_preview() -> Result<(), String> {
@@ -760,6 +893,15 @@ fn run_preview_overlay(bounds: Rect) -> Result<(), String> {
         let mut last_monitor = -2i32; // Use -2 as "uninitialized" vs -1 as "no highlight"
         let mut last_window = -1isize;
 
+        // Sleep until the highlight changes or a window message arrives.
+        // Fall back to a ~60fps poll if the change event couldn't be created.
+        let highlight_event = commands::highlight_changed_event();
+        let (wait_handles, wait_timeout) = if highlight_event.is_invalid() {
+            (Vec::new(), 16)
+        } else {
+            (vec![highlight_event], INFINITE)
+        };
+
         while !PREVIEW_SHOULD_STOP.load(Ordering::SeqCst) {
             // Check current highlight state
             let current_monitor = commands::get_highlighted_monitor();
@@ -793,8 +935,9 @@ fn run_preview_overlay(bounds: Rect) -> Result<(), String> {
                 DispatchMessageW(&msg);
             }
 
-            // Small sleep to avoid burning CPU
-            std::thread::sleep(std::time::Duration::from_millis(16)); // ~60fps
+            // Block until there's something to do (stop_highlight_preview clears the
+            // highlights, which also signals the event)
+            MsgWaitForMultipleObjects(Some(&wait_handles), false, wait_timeout, QS_ALLINPUT);
         }
 
         // Cleanup
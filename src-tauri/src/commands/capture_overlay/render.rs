@@ -1,22 +1,26 @@
 //! Direct2D rendering for the overlay.
 //!
 //! This module handles all rendering operations:
-//! - Dimmed overlay around the selection
+//! - Dimmed overlay around the selection (and committed multi-region selections)
 //! - Selection border
 //! - Crosshair cursor
 //! - Size indicator text
 //! - Resize handles
+//! - Magnifier loupe
+//! - Screenshot delay countdown
 
 use windows::core::Result;
 use windows::Win32::Graphics::Direct2D::Common::{D2D1_COLOR_F, D2D_POINT_2F, D2D_RECT_F};
 use windows::Win32::Graphics::Direct2D::{
-    ID2D1DeviceContext, D2D1_DRAW_TEXT_OPTIONS_NONE, D2D1_ROUNDED_RECT,
+    ID2D1DeviceContext, D2D1_ANTIALIAS_MODE_ALIASED, D2D1_DRAW_TEXT_OPTIONS_NONE,
+    D2D1_INTERPOLATION_MODE_NEAREST_NEIGHBOR, D2D1_ROUNDED_RECT,
 };
 use windows::Win32::Graphics::DirectWrite::DWRITE_MEASURING_MODE_NATURAL;
 use windows::Win32::Graphics::Dxgi::{IDXGISurface, DXGI_PRESENT};
 
 use super::commands::{get_highlighted_monitor, get_highlighted_window};
-use super::graphics::d2d::{create_target_bitmap, Brushes, D2DResources};
+use super::graphics::d2d::{create_pixel_bitmap, create_target_bitmap, Brushes, D2DResources};
+use super::snapshot::DesktopSnapshot;
 use super::state::OverlayState;
 use super::types::*;
 
@@ -43,19 +47,39 @@ pub fn render(state: &OverlayState) -> Result<()> {
             a: 0.0,
         }));
 
+        // Screenshot delay: only the countdown is drawn
+        if let Some(secs) = state
+            .capture_delay
+            .remaining_secs(std::time::Instant::now())
+        {
+            draw_countdown(&d2d.context, d2d, secs, state);
+            d2d.context.EndDraw(None, None)?;
+            graphics.swap_chain.Present(1, DXGI_PRESENT(0)).ok()?;
+            graphics.comp_device.Commit()?;
+            return Ok(());
+        }
+
         // Determine what to render
         let render_info = determine_render_info(state);
 
         // Draw dimmed overlay around the clear area
         draw_dim_overlay(&d2d.context, &d2d.brushes, render_info.clear_rect, state);
 
+        // Cut committed multi-region selections out of the dim layer
+        draw_committed_regions(&d2d.context, &d2d.brushes, &state.regions);
+
         // Draw selection border
         if render_info.draw_border {
             draw_selection_border(&d2d.context, &d2d.brushes, render_info.clear_rect);
         }
 
-        // Draw crosshair (only when not adjusting and only in RegionSelect mode)
-        if !state.adjustment.is_active && state.overlay_mode == OverlayMode::RegionSelect {
+        // Draw crosshair (only when not adjusting, in RegionSelect or ColorPick mode)
+        if !state.adjustment.is_active
+            && matches!(
+                state.overlay_mode,
+                OverlayMode::RegionSelect | OverlayMode::ColorPick
+            )
+        {
             draw_crosshair(&d2d.context, d2d, state.cursor.position, state);
         }
 
@@ -82,6 +106,24 @@ pub fn render(state: &OverlayState) -> Result<()> {
             draw_resize_handles(&d2d.context, &d2d.brushes, render_info.clear_rect);
         }
 
+        // Draw magnifier loupe (region/color selection, or while dragging an adjustment)
+        if let Some(ref snapshot) = state.snapshot {
+            let selecting = matches!(
+                state.overlay_mode,
+                OverlayMode::RegionSelect | OverlayMode::ColorPick
+            ) && !state.adjustment.is_active;
+            if selecting || state.adjustment.is_dragging {
+                let selection = if state.adjustment.is_active {
+                    Some(state.adjustment.bounds)
+                } else if state.drag.is_dragging {
+                    Some(state.drag.selection_rect())
+                } else {
+                    None
+                };
+                draw_loupe(&d2d.context, d2d, snapshot, selection, state);
+            }
+        }
+
         d2d.context.EndDraw(None, None)?;
 
         // Present the frame
@@ -121,6 +163,17 @@ fn determine_render_info(state: &OverlayState) -> RenderInfo {
         OverlayMode::DisplaySelect => determine_display_mode_render(state, width, height),
         OverlayMode::WindowSelect => determine_window_mode_render(state, width, height),
         OverlayMode::RegionSelect => determine_region_mode_render(state, width, height),
+        OverlayMode::ColorPick => RenderInfo {
+            // Nothing dimmed so picked colors look exactly as on screen
+            clear_rect: D2D_RECT_F {
+                left: 0.0,
+                top: 0.0,
+                right: width,
+                bottom: height,
+            },
+            draw_border: false,
+            draw_handles: false,
+        },
     }
 }
 
@@ -417,6 +470,24 @@ fn draw_dim_overlay(
     }
 }
 
+/// Clear committed multi-region selections back to transparent and outline them.
+fn draw_committed_regions(context: &ID2D1DeviceContext, brushes: &Brushes, regions: &[Rect]) {
+    for region in regions {
+        let rect = region.to_d2d_rect();
+        unsafe {
+            context.PushAxisAlignedClip(&rect, D2D1_ANTIALIAS_MODE_ALIASED);
+            context.Clear(Some(&D2D1_COLOR_F {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 0.0,
+            }));
+            context.PopAxisAlignedClip();
+        }
+        draw_selection_border(context, brushes, rect);
+    }
+}
+
 /// Draw the selection border.
 fn draw_selection_border(context: &ID2D1DeviceContext, brushes: &Brushes, rect: D2D_RECT_F) {
     unsafe {
@@ -591,6 +662,195 @@ fn draw_size_indicator(
     }
 }
 
+/// Draw the magnifier loupe next to the cursor.
+///
+/// Shows a zoomed copy of the pixels under the cursor with the center pixel
+/// marked, and an info box with its RGB value and the selection's size
+/// (plus its hex value in color pick mode).
+fn draw_loupe(
+    context: &ID2D1DeviceContext,
+    d2d: &D2DResources,
+    snapshot: &DesktopSnapshot,
+    selection: Option<Rect>,
+    state: &OverlayState,
+) {
+    let cursor = state.cursor.position;
+    let Some((r, g, b)) = snapshot.rgb_at(cursor) else {
+        return;
+    };
+
+    let samples = (2 * LOUPE_RADIUS + 1) as u32;
+    let size = samples as f32 * LOUPE_ZOOM;
+    let mut info_lines = Vec::new();
+    if let Some(sel) = selection {
+        info_lines.push(format!("{} × {}", sel.width(), sel.height()));
+    }
+    if state.overlay_mode == OverlayMode::ColorPick {
+        info_lines.push(PickedColor::opaque(r, g, b).hex());
+    }
+    info_lines.push(format!("RGB({}, {}, {})", r, g, b));
+    let info_height = 8.0 + 16.0 * info_lines.len() as f32;
+    let width = state.monitor.width as f32;
+    let height = state.monitor.height as f32;
+
+    // Below-right of the cursor, flipped to the other side near screen edges
+    let mut x = cursor.x as f32 + LOUPE_OFFSET;
+    if x + size > width {
+        x = cursor.x as f32 - LOUPE_OFFSET - size;
+    }
+    let mut y = cursor.y as f32 + LOUPE_OFFSET;
+    if y + size + info_height > height {
+        y = cursor.y as f32 - LOUPE_OFFSET - size - info_height;
+    }
+
+    let loupe_rect = D2D_RECT_F {
+        left: x,
+        top: y,
+        right: x + size,
+        bottom: y + size,
+    };
+
+    let pixels = snapshot.region(cursor, LOUPE_RADIUS);
+    let Ok(bitmap) = create_pixel_bitmap(context, samples, samples, &pixels) else {
+        return;
+    };
+
+    // Center pixel cell
+    let cell = D2D_RECT_F {
+        left: x + LOUPE_RADIUS as f32 * LOUPE_ZOOM,
+        top: y + LOUPE_RADIUS as f32 * LOUPE_ZOOM,
+        right: x + (LOUPE_RADIUS + 1) as f32 * LOUPE_ZOOM,
+        bottom: y + (LOUPE_RADIUS + 1) as f32 * LOUPE_ZOOM,
+    };
+    let mid_x = (cell.left + cell.right) / 2.0;
+    let mid_y = (cell.top + cell.bottom) / 2.0;
+
+    let info_wide: Vec<u16> = info_lines.join("\n").encode_utf16().collect();
+    let info_rect = D2D_RECT_F {
+        left: x,
+        top: y + size,
+        right: x + size,
+        bottom: y + size + info_height,
+    };
+
+    unsafe {
+        context.DrawBitmap(
+            &bitmap,
+            Some(&loupe_rect),
+            1.0,
+            D2D1_INTERPOLATION_MODE_NEAREST_NEIGHBOR,
+            None,
+            None,
+        );
+
+        // Crosshair through the center pixel
+        context.DrawLine(
+            D2D_POINT_2F { x, y: mid_y },
+            D2D_POINT_2F {
+                x: cell.left,
+                y: mid_y,
+            },
+            &d2d.brushes.crosshair,
+            1.0,
+            None,
+        );
+        context.DrawLine(
+            D2D_POINT_2F {
+                x: cell.right,
+                y: mid_y,
+            },
+            D2D_POINT_2F {
+                x: x + size,
+                y: mid_y,
+            },
+            &d2d.brushes.crosshair,
+            1.0,
+            None,
+        );
+        context.DrawLine(
+            D2D_POINT_2F { x: mid_x, y },
+            D2D_POINT_2F {
+                x: mid_x,
+                y: cell.top,
+            },
+            &d2d.brushes.crosshair,
+            1.0,
+            None,
+        );
+        context.DrawLine(
+            D2D_POINT_2F {
+                x: mid_x,
+                y: cell.bottom,
+            },
+            D2D_POINT_2F {
+                x: mid_x,
+                y: y + size,
+            },
+            &d2d.brushes.crosshair,
+            1.0,
+            None,
+        );
+        context.DrawRectangle(&cell, &d2d.brushes.text, 1.0, None);
+        context.DrawRectangle(&loupe_rect, &d2d.brushes.border, 2.0, None);
+
+        // Info box
+        context.FillRectangle(&info_rect, &d2d.brushes.text_bg);
+        context.DrawText(
+            &info_wide,
+            &d2d.text_format,
+            &info_rect,
+            &d2d.brushes.text,
+            D2D1_DRAW_TEXT_OPTIONS_NONE,
+            DWRITE_MEASURING_MODE_NATURAL,
+        );
+    }
+}
+
+/// Draw the screenshot delay countdown badge at the center of the selection.
+fn draw_countdown(
+    context: &ID2D1DeviceContext,
+    d2d: &D2DResources,
+    secs: u32,
+    state: &OverlayState,
+) {
+    let badge = 64.0_f32;
+    let (cx, cy) = state
+        .result
+        .selection
+        .map(|sel| state.monitor.screen_rect_to_local(sel).center())
+        .unwrap_or((
+            state.monitor.width as i32 / 2,
+            state.monitor.height as i32 / 2,
+        ));
+
+    let rect = D2D_RECT_F {
+        left: cx as f32 - badge / 2.0,
+        top: cy as f32 - badge / 2.0,
+        right: cx as f32 + badge / 2.0,
+        bottom: cy as f32 + badge / 2.0,
+    };
+    let text: Vec<u16> = secs.to_string().encode_utf16().collect();
+
+    unsafe {
+        context.FillRoundedRectangle(
+            &D2D1_ROUNDED_RECT {
+                rect,
+                radiusX: badge / 2.0,
+                radiusY: badge / 2.0,
+            },
+            &d2d.brushes.text_bg,
+        );
+        context.DrawText(
+            &text,
+            &d2d.text_format_large,
+            &rect,
+            &d2d.brushes.text,
+            D2D1_DRAW_TEXT_OPTIONS_NONE,
+            DWRITE_MEASURING_MODE_NATURAL,
+        );
+    }
+}
+
 /// Draw the 8 resize handles.
 fn draw_resize_handles(context: &ID2D1DeviceContext, brushes: &Brushes, rect: D2D_RECT_F) {
     let hh = HANDLE_HALF as f32;
@@ -0,0 +1,135 @@
+//! One-time desktop snapshot backing the magnifier loupe.
+//!
+//! Taken with Desktop Duplication when the overlay opens so the loupe can show
+//! the real pixels under the cursor without re-capturing on every mouse move.
+//! The overlay itself is excluded from capture, so the snapshot never contains
+//! the dim layer or crosshair.
+
+use scap_targets::Display;
+use windows::Win32::Graphics::Gdi::{GetMonitorInfoW, HMONITOR, MONITORINFO};
+
+use super::state::MonitorInfo;
+use super::types::*;
+use crate::commands::video_recording::dxgi_capture::DxgiDuplicationCapture;
+
+/// How long to wait for each display's first duplicated frame
+const SNAPSHOT_TIMEOUT_MS: u64 = 500;
+
+/// BGRA copy of the desktop covering the overlay, in local coordinates.
+pub struct DesktopSnapshot {
+    width: u32,
+    height: u32,
+    /// BGRA pixels, no row padding
+    pixels: Vec<u8>,
+}
+
+impl DesktopSnapshot {
+    /// Create an empty (black) snapshot of the given size.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![0; width as usize * height as usize * 4],
+        }
+    }
+
+    /// Capture every display intersecting the overlay.
+    ///
+    /// Displays that can't be duplicated are left black; returns None if
+    /// nothing could be captured at all.
+    pub fn capture(monitor: &MonitorInfo) -> Option<Self> {
+        let mut snapshot = Self::new(monitor.width, monitor.height);
+        let mut captured = false;
+
+        for (index, display) in Display::list().iter().enumerate() {
+            let Some(bounds) = monitor_rect(display.raw_handle().0) else {
+                continue;
+            };
+            let local = monitor.screen_rect_to_local(bounds);
+
+            let frame = DxgiDuplicationCapture::new(index, None).and_then(|mut capture| {
+                capture.start()?;
+                capture
+                    .wait_for_first_frame(SNAPSHOT_TIMEOUT_MS)
+                    .ok_or_else(|| "No frame".to_string())
+            });
+
+            match frame {
+                Ok((width, height, frame)) => {
+                    snapshot.blit(local.left, local.top, width, height, &frame.data);
+                    captured = true;
+                },
+                Err(e) => log::warn!(
+                    "[Overlay] Loupe snapshot of display {} failed: {}",
+                    index,
+                    e
+                ),
+            }
+        }
+
+        captured.then_some(snapshot)
+    }
+
+    /// Copy a BGRA image into the snapshot at (x, y), clipping to bounds.
+    pub fn blit(&mut self, x: i32, y: i32, width: u32, height: u32, data: &[u8]) {
+        for row in 0..height as i32 {
+            let dst_y = y + row;
+            if dst_y < 0 || dst_y >= self.height as i32 {
+                continue;
+            }
+            let src_x = (-x).max(0);
+            let dst_x = x.max(0);
+            let len = (width as i32 - src_x).min(self.width as i32 - dst_x);
+            if len <= 0 {
+                continue;
+            }
+
+            let src = (row as usize * width as usize + src_x as usize) * 4;
+            let dst = (dst_y as usize * self.width as usize + dst_x as usize) * 4;
+            let bytes = len as usize * 4;
+            if let Some(src) = data.get(src..src + bytes) {
+                self.pixels[dst..dst + bytes].copy_from_slice(src);
+            }
+        }
+    }
+
+    /// RGB value of the pixel at a local point.
+    pub fn rgb_at(&self, p: Point) -> Option<(u8, u8, u8)> {
+        if p.x < 0 || p.y < 0 || p.x >= self.width as i32 || p.y >= self.height as i32 {
+            return None;
+        }
+        let i = (p.y as usize * self.width as usize + p.x as usize) * 4;
+        Some((self.pixels[i + 2], self.pixels[i + 1], self.pixels[i]))
+    }
+
+    /// Opaque BGRA square of `2 * radius + 1` pixels centered on `center`.
+    ///
+    /// Pixels outside the snapshot are black.
+    pub fn region(&self, center: Point, radius: i32) -> Vec<u8> {
+        let size = (2 * radius + 1) as usize;
+        let mut out = Vec::with_capacity(size * size * 4);
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                let (r, g, b) = self
+                    .rgb_at(Point::new(center.x + dx, center.y + dy))
+                    .unwrap_or((0, 0, 0));
+                out.extend_from_slice(&[b, g, r, 255]);
+            }
+        }
+        out
+    }
+}
+
+/// Screen bounds of a monitor handle.
+fn monitor_rect(hmonitor: HMONITOR) -> Option<Rect> {
+    let mut info = MONITORINFO {
+        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+        ..Default::default()
+    };
+    unsafe { GetMonitorInfoW(hmonitor, &mut info) }
+        .as_bool()
+        .then(|| {
+            let rc = info.rcMonitor;
+            Rect::new(rc.left, rc.top, rc.right, rc.bottom)
+        })
+}
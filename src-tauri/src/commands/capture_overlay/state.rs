@@ -8,6 +8,8 @@
 //! - `MonitorInfo` - Virtual screen bounds and coordinate conversion
 //! - `DragState` - Initial region selection (mouse drag)
 //! - `AdjustmentState` - Post-selection resize/move
+//! - `SnapGuides` - Monitor edges and midlines selections snap to
+//! - `CaptureDelay` - Countdown between screenshot confirm and capture
 
 // Allow unused utility methods - may be useful for future features
 #![allow(dead_code)]
@@ -16,7 +18,7 @@
 //! - `GraphicsState` - All graphics resources (boxed)
 //! - `OverlayState` - Complete overlay state
 
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use tauri::AppHandle;
 use windows::Win32::Foundation::HWND;
@@ -24,6 +26,7 @@ use windows::Win32::Graphics::DirectComposition::IDCompositionDevice;
 use windows::Win32::Graphics::Dxgi::IDXGISwapChain1;
 
 use super::graphics::{CompositorResources, D2DResources};
+use super::snapshot::DesktopSnapshot;
 use super::types::*;
 
 // ============================================================================
@@ -95,15 +98,18 @@ pub struct DragState {
     pub start: Point,
     /// Current drag position (local coords)
     pub current: Point,
-    /// Shift key held (for square constraint)
+    /// Shift key held (for aspect ratio constraint)
     pub shift_held: bool,
+    /// Width:height ratio Shift locks to (square if unset)
+    pub aspect_ratio: Option<(u32, u32)>,
 }
 
 impl DragState {
     /// Get the selection rectangle from current drag state.
     ///
     /// Normalizes coordinates so left < right and top < bottom.
-    /// If shift is held, constrains to a square.
+    /// If shift is held, constrains to the locked aspect ratio (square by
+    /// default), driven by whichever dimension has grown further.
     pub fn selection_rect(&self) -> Rect {
         let mut r = Rect {
             left: self.start.x.min(self.current.x),
@@ -113,17 +119,24 @@ impl DragState {
         };
 
         if self.shift_held {
-            let size = (r.width() as i32).max(r.height() as i32);
+            let (ratio_w, ratio_h) = self.aspect_ratio.unwrap_or((1, 1));
+            let (ratio_w, ratio_h) = (ratio_w.max(1) as i64, ratio_h.max(1) as i64);
+            let (w, h) = (r.width() as i64, r.height() as i64);
+            let (width, height) = if w * ratio_h >= h * ratio_w {
+                (w, w * ratio_h / ratio_w)
+            } else {
+                (h * ratio_w / ratio_h, h)
+            };
             // Expand in the direction of the drag
             if self.current.x >= self.start.x {
-                r.right = r.left + size;
+                r.right = r.left + width as i32;
             } else {
-                r.left = r.right - size;
+                r.left = r.right - width as i32;
             }
             if self.current.y >= self.start.y {
-                r.bottom = r.top + size;
+                r.bottom = r.top + height as i32;
             } else {
-                r.top = r.bottom - size;
+                r.top = r.bottom - height as i32;
             }
         }
 
@@ -210,6 +223,44 @@ impl AdjustmentState {
         self.bounds = self.bounds.ensure_min_size(MIN_SELECTION_SIZE);
     }
 
+    /// Snap the edges moved by the current handle to the guides.
+    pub fn snap_to(&mut self, guides: &SnapGuides) {
+        let b = &mut self.bounds;
+        match self.handle {
+            HandlePosition::Interior => {
+                *b = guides.snap_rect_position(*b);
+                return;
+            },
+            HandlePosition::None => return,
+            _ => {},
+        }
+        if matches!(
+            self.handle,
+            HandlePosition::TopLeft | HandlePosition::Left | HandlePosition::BottomLeft
+        ) {
+            b.left = guides.snap_x(b.left);
+        }
+        if matches!(
+            self.handle,
+            HandlePosition::TopRight | HandlePosition::Right | HandlePosition::BottomRight
+        ) {
+            b.right = guides.snap_x(b.right);
+        }
+        if matches!(
+            self.handle,
+            HandlePosition::TopLeft | HandlePosition::Top | HandlePosition::TopRight
+        ) {
+            b.top = guides.snap_y(b.top);
+        }
+        if matches!(
+            self.handle,
+            HandlePosition::BottomLeft | HandlePosition::Bottom | HandlePosition::BottomRight
+        ) {
+            b.bottom = guides.snap_y(b.bottom);
+        }
+        self.bounds = self.bounds.ensure_min_size(MIN_SELECTION_SIZE);
+    }
+
     /// Start dragging a handle.
     /// Does nothing if the selection is locked (display/window mode).
     pub fn start_drag(&mut self, handle: HandlePosition, mouse: Point) {
@@ -261,6 +312,139 @@ impl AdjustmentState {
     }
 }
 
+// ============================================================================
+// Snap Guides
+// ============================================================================
+
+/// Lines that selection edges snap to while dragging: every monitor edge and
+/// the virtual-screen midlines, all in local coordinates.
+#[derive(Debug, Clone, Default)]
+pub struct SnapGuides {
+    /// Monitor bounds in local coordinates
+    pub monitors: Vec<Rect>,
+    /// Vertical guide lines (x)
+    pub xs: Vec<i32>,
+    /// Horizontal guide lines (y)
+    pub ys: Vec<i32>,
+    /// Snap distance in pixels
+    pub threshold: i32,
+}
+
+impl SnapGuides {
+    /// Build guides for `monitors` (local coords) on a virtual screen of the
+    /// given size, with the snap distance scaled for `dpi`.
+    pub fn new(monitors: Vec<Rect>, width: u32, height: u32, dpi: u32) -> Self {
+        let mut xs = vec![width as i32 / 2];
+        let mut ys = vec![height as i32 / 2];
+        for m in &monitors {
+            xs.extend([m.left, m.right]);
+            ys.extend([m.top, m.bottom]);
+        }
+        let dpi = if dpi > 0 { dpi } else { 96 };
+
+        Self {
+            monitors,
+            xs,
+            ys,
+            threshold: (SNAP_THRESHOLD * dpi as i32 + 48) / 96,
+        }
+    }
+
+    /// Nearest line within the threshold, as an offset from `value`.
+    fn nearest(&self, value: i32, lines: &[i32]) -> Option<i32> {
+        lines
+            .iter()
+            .map(|line| line - value)
+            .filter(|offset| offset.abs() <= self.threshold)
+            .min_by_key(|offset| offset.abs())
+    }
+
+    /// Snap an x coordinate to the nearest vertical guide.
+    pub fn snap_x(&self, x: i32) -> i32 {
+        x + self.nearest(x, &self.xs).unwrap_or(0)
+    }
+
+    /// Snap a y coordinate to the nearest horizontal guide.
+    pub fn snap_y(&self, y: i32) -> i32 {
+        y + self.nearest(y, &self.ys).unwrap_or(0)
+    }
+
+    /// Snap a point to the nearest guides.
+    pub fn snap_point(&self, p: Point) -> Point {
+        Point::new(self.snap_x(p.x), self.snap_y(p.y))
+    }
+
+    /// Move a rect (keeping its size) so its closest edge lands on a guide.
+    pub fn snap_rect_position(&self, r: Rect) -> Rect {
+        let dx = [
+            self.nearest(r.left, &self.xs),
+            self.nearest(r.right, &self.xs),
+        ]
+        .into_iter()
+        .flatten()
+        .min_by_key(|offset| offset.abs())
+        .unwrap_or(0);
+        let dy = [
+            self.nearest(r.top, &self.ys),
+            self.nearest(r.bottom, &self.ys),
+        ]
+        .into_iter()
+        .flatten()
+        .min_by_key(|offset| offset.abs())
+        .unwrap_or(0);
+        r.offset(dx, dy)
+    }
+
+    /// Bounds of the monitor containing `p`.
+    pub fn monitor_at(&self, p: Point) -> Option<Rect> {
+        self.monitors.iter().copied().find(|m| m.contains(p.x, p.y))
+    }
+}
+
+// ============================================================================
+// Capture Delay
+// ============================================================================
+
+/// Countdown between confirming a screenshot and returning the result, so
+/// transient UI (open menus, tooltips) can be brought up first.
+#[derive(Debug, Clone, Default)]
+pub struct CaptureDelay {
+    /// Delay in seconds (0 = capture immediately)
+    pub secs: u32,
+    /// When the countdown ends, once started
+    pub deadline: Option<Instant>,
+}
+
+impl CaptureDelay {
+    /// Create a delay of the given length
+    pub fn new(secs: u32) -> Self {
+        Self {
+            secs,
+            deadline: None,
+        }
+    }
+
+    /// Start the countdown. Returns false if there is no delay or it already ran.
+    pub fn start(&mut self, now: Instant) -> bool {
+        if self.secs == 0 || self.deadline.is_some() {
+            return false;
+        }
+        self.deadline = Some(now + Duration::from_secs(self.secs as u64));
+        true
+    }
+
+    /// Whole seconds left (rounded up), or None if not counting down.
+    pub fn remaining_secs(&self, now: Instant) -> Option<u32> {
+        let left = self.deadline?.saturating_duration_since(now);
+        Some(left.as_millis().div_ceil(1000) as u32)
+    }
+
+    /// True once a started countdown has run out
+    pub fn is_elapsed(&self, now: Instant) -> bool {
+        self.deadline.is_some_and(|deadline| now >= deadline)
+    }
+}
+
 // ============================================================================
 // Cursor State
 // ============================================================================
@@ -301,6 +485,10 @@ pub struct ResultState {
     pub selection: Option<Rect>,
     /// Window ID (HWND) if a window was selected
     pub window_id: Option<isize>,
+    /// Color picked in color pick mode
+    pub color: Option<PickedColor>,
+    /// Every region of a multi-region selection (screen coords)
+    pub regions: Vec<Rect>,
 }
 
 impl ResultState {
@@ -310,6 +498,30 @@ impl ResultState {
         self.action = action;
         self.selection = Some(bounds);
         self.window_id = None;
+        self.color = None;
+        self.regions.clear();
+    }
+
+    /// Set the result to confirmed with a picked color at a screen point
+    pub fn confirm_color(&mut self, point: Point, color: PickedColor) {
+        self.confirmed = true;
+        self.action = OverlayAction::PickColor;
+        self.selection = Some(Rect::from_xywh(point.x, point.y, 1, 1));
+        self.window_id = None;
+        self.color = Some(color);
+        self.regions.clear();
+    }
+
+    /// Set the result to confirmed with several regions (screen coords).
+    ///
+    /// The selection is their bounding box.
+    pub fn confirm_regions(&mut self, regions: Vec<Rect>, action: OverlayAction) {
+        self.confirmed = true;
+        self.action = action;
+        self.selection = regions.iter().copied().reduce(|a, b| a.union(&b));
+        self.window_id = None;
+        self.color = None;
+        self.regions = regions;
     }
 
     /// Set the result to confirmed with window capture
@@ -318,6 +530,8 @@ impl ResultState {
         self.action = action;
         self.selection = Some(bounds);
         self.window_id = Some(window_id);
+        self.color = None;
+        self.regions.clear();
     }
 
     /// Set the result to cancelled
@@ -326,6 +540,8 @@ impl ResultState {
         self.action = OverlayAction::Cancelled;
         self.selection = None;
         self.window_id = None;
+        self.color = None;
+        self.regions.clear();
     }
 }
 
@@ -380,6 +596,12 @@ pub struct OverlayState {
     pub adjustment: AdjustmentState,
     /// Cursor position and hovered window
     pub cursor: CursorState,
+    /// Edges and midlines the selection snaps to
+    pub snap: SnapGuides,
+    /// Desktop pixels for the magnifier loupe (region selection only)
+    pub snapshot: Option<DesktopSnapshot>,
+    /// Regions committed so far in a multi-region selection (local coords)
+    pub regions: Vec<Rect>,
     /// Preselected window HWND (for window capture mode)
     pub preselected_window_id: Option<isize>,
     /// Preselected window title (for window capture mode)
@@ -396,6 +618,8 @@ pub struct OverlayState {
     // Control flags
     /// True when overlay should close
     pub should_close: bool,
+    /// Countdown before a confirmed screenshot is returned
+    pub capture_delay: CaptureDelay,
     /// Last time an event was emitted (for throttling)
     pub last_emit_time: Instant,
 
@@ -469,6 +693,69 @@ impl OverlayState {
         self.should_close = true;
     }
 
+    /// Expand the current selection (drag or unlocked adjustment) to fill the
+    /// monitor under the cursor. Returns true if the selection changed.
+    pub fn fill_monitor_under_cursor(&mut self) -> bool {
+        let Some(monitor) = self.snap.monitor_at(self.cursor.position) else {
+            return false;
+        };
+
+        if self.adjustment.is_active {
+            if self.adjustment.is_locked || self.adjustment.is_dragging {
+                return false;
+            }
+            self.adjustment.bounds = monitor;
+            true
+        } else if self.drag.is_dragging {
+            self.drag.start = Point::new(monitor.left, monitor.top);
+            self.drag.current = Point::new(monitor.right, monitor.bottom);
+            self.drag.shift_held = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Commit a region and reset the drag so another can be selected.
+    pub fn add_region(&mut self, local_bounds: Rect) {
+        self.regions.push(local_bounds);
+        self.drag.reset();
+    }
+
+    /// Confirm every committed region plus an optional final one.
+    ///
+    /// Returns false if there is nothing to confirm.
+    pub fn confirm_regions(&mut self, last: Option<Rect>, action: OverlayAction) -> bool {
+        let regions: Vec<Rect> = self
+            .regions
+            .iter()
+            .copied()
+            .chain(last)
+            .map(|r| self.monitor.local_rect_to_screen(r))
+            .collect();
+        if regions.is_empty() {
+            return false;
+        }
+        self.result.confirm_regions(regions, action);
+        self.should_close = true;
+        true
+    }
+
+    /// Pick the color under the cursor from the desktop snapshot and close.
+    ///
+    /// Returns false if there is no snapshot pixel under the cursor.
+    pub fn pick_color(&mut self) -> bool {
+        let cursor = self.cursor.position;
+        let Some((r, g, b)) = self.snapshot.as_ref().and_then(|s| s.rgb_at(cursor)) else {
+            return false;
+        };
+        let screen_point = self.monitor.local_to_screen(cursor);
+        self.result
+            .confirm_color(screen_point, PickedColor::opaque(r, g, b));
+        self.should_close = true;
+        true
+    }
+
     /// Go back to selection mode (reselect).
     pub fn reselect(&mut self) {
         self.adjustment.reset();
@@ -476,6 +763,20 @@ impl OverlayState {
         self.cursor.clear_hovered();
     }
 
+    /// Hold a confirmed screenshot for the capture delay instead of closing.
+    ///
+    /// Returns true if the countdown started (and the overlay stays open).
+    pub fn begin_capture_delay(&mut self) -> bool {
+        if !self.result.confirmed || self.result.action != OverlayAction::CaptureScreenshot {
+            return false;
+        }
+        if !self.capture_delay.start(Instant::now()) {
+            return false;
+        }
+        self.should_close = false;
+        true
+    }
+
     /// Check if event emission should be throttled.
     ///
     /// Returns true if enough time has passed since the last emission.
@@ -531,6 +832,25 @@ mod tests {
         assert_eq!(r.width(), 100);
     }
 
+    #[test]
+    fn test_drag_state_locked_aspect_ratio() {
+        let mut state = DragState::default();
+        state.start = Point::new(100, 100);
+        state.current = Point::new(500, 150);
+        state.shift_held = true;
+        state.aspect_ratio = Some((16, 9));
+
+        // Width grew further, so height follows it
+        let r = state.selection_rect();
+        assert_eq!((r.width(), r.height()), (400, 225));
+
+        // Height grew further, dragging up-left
+        state.current = Point::new(80, -300);
+        let r = state.selection_rect();
+        assert_eq!((r.width(), r.height()), (711, 400));
+        assert_eq!((r.right, r.bottom), (100, 100));
+    }
+
     #[test]
     fn test_adjustment_apply_delta_interior() {
         let mut state = AdjustmentState::default();
@@ -567,6 +887,79 @@ mod tests {
         assert!(state.bounds.width() >= MIN_SELECTION_SIZE as u32);
     }
 
+    #[test]
+    fn test_snap_guides_scale_with_dpi() {
+        let monitors = vec![Rect::new(0, 0, 1920, 1080)];
+        assert_eq!(
+            SnapGuides::new(monitors.clone(), 1920, 1080, 96).threshold,
+            SNAP_THRESHOLD
+        );
+        assert_eq!(
+            SnapGuides::new(monitors, 1920, 1080, 192).threshold,
+            SNAP_THRESHOLD * 2
+        );
+    }
+
+    #[test]
+    fn test_snap_guides_edges_and_midlines() {
+        let guides = SnapGuides::new(
+            vec![Rect::new(0, 0, 1920, 1080), Rect::new(1920, 0, 3840, 1080)],
+            3840,
+            1080,
+            96,
+        );
+
+        assert_eq!(guides.snap_x(4), 0);
+        assert_eq!(guides.snap_x(1915), 1920);
+        assert_eq!(guides.snap_x(1000), 1000);
+        assert_eq!(guides.snap_y(536), 540);
+        assert_eq!(
+            guides.snap_point(Point::new(3836, 1077)),
+            Point::new(3840, 1080)
+        );
+
+        // Moving keeps the size and snaps the closest edge
+        let moved = guides.snap_rect_position(Rect::new(5, 100, 505, 400));
+        assert_eq!(moved, Rect::new(0, 100, 500, 400));
+
+        assert_eq!(
+            guides.monitor_at(Point::new(2000, 10)),
+            Some(Rect::new(1920, 0, 3840, 1080))
+        );
+    }
+
+    #[test]
+    fn test_adjustment_snap_moves_only_dragged_edges() {
+        let guides = SnapGuides::new(vec![Rect::new(0, 0, 1920, 1080)], 1920, 1080, 96);
+        let mut state = AdjustmentState::default();
+        state.enter(Rect::new(4, 4, 1914, 500));
+        state.start_drag(HandlePosition::Right, Point::new(1914, 250));
+        state.snap_to(&guides);
+
+        assert_eq!(state.bounds, Rect::new(4, 4, 1920, 500));
+    }
+
+    #[test]
+    fn test_capture_delay_countdown() {
+        let now = Instant::now();
+        let mut delay = CaptureDelay::new(3);
+        assert_eq!(delay.remaining_secs(now), None);
+        assert!(!delay.is_elapsed(now));
+
+        assert!(delay.start(now));
+        assert!(!delay.start(now), "countdown only runs once");
+        assert_eq!(delay.remaining_secs(now), Some(3));
+        assert_eq!(
+            delay.remaining_secs(now + Duration::from_millis(1500)),
+            Some(2)
+        );
+        assert!(delay.is_elapsed(now + Duration::from_secs(3)));
+        assert_eq!(delay.remaining_secs(now + Duration::from_secs(4)), Some(0));
+
+        // No delay configured
+        assert!(!CaptureDelay::new(0).start(now));
+    }
+
     #[test]
     fn test_monitor_coordinate_conversion() {
         let monitor = MonitorInfo::new(-1920, 0, 1920, 1080);
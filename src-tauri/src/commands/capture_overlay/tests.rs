@@ -6,6 +6,9 @@
 //! - State management
 //! - Hit-testing
 //! - Commands
+//! - Loupe snapshot
+//! - Color picking
+//! - Multi-region results
 
 #[cfg(test)]
 mod geometry_tests {
@@ -210,6 +213,41 @@ mod overlay_result_tests {
         assert_eq!(result.width, 0);
         assert_eq!(result.height, 0);
         assert_eq!(result.action, OverlayAction::Cancelled);
+        assert_eq!(result.color, None);
+    }
+}
+
+#[cfg(test)]
+mod color_pick_tests {
+    use crate::commands::capture_overlay::state::ResultState;
+    use crate::commands::capture_overlay::types::*;
+
+    #[test]
+    fn overlay_mode_from_str() {
+        assert_eq!(OverlayMode::from_str("color"), OverlayMode::ColorPick);
+        assert_eq!(OverlayMode::from_str("Eyedropper"), OverlayMode::ColorPick);
+    }
+
+    #[test]
+    fn picked_color_hex() {
+        let color = PickedColor::opaque(0x1a, 0x2b, 0xff);
+        assert_eq!(color.a, 255);
+        assert_eq!(color.hex(), "#1A2BFF");
+    }
+
+    #[test]
+    fn confirm_color_selects_single_pixel() {
+        let mut result = ResultState::default();
+        let color = PickedColor::opaque(1, 2, 3);
+        result.confirm_color(Point::new(-50, 20), color);
+
+        assert!(result.confirmed);
+        assert_eq!(result.action, OverlayAction::PickColor);
+        assert_eq!(result.selection, Some(Rect::new(-50, 20, -49, 21)));
+        assert_eq!(result.color, Some(color));
+
+        result.cancel();
+        assert_eq!(result.color, None);
     }
 }
 
@@ -228,3 +266,66 @@ mod selection_event_tests {
         assert_eq!(event.height, 300);
     }
 }
+
+#[cfg(test)]
+mod snapshot_tests {
+    use crate::commands::capture_overlay::snapshot::DesktopSnapshot;
+    use crate::commands::capture_overlay::types::*;
+
+    /// Solid BGRA image of the given color
+    fn solid(width: u32, height: u32, rgb: (u8, u8, u8)) -> Vec<u8> {
+        [rgb.2, rgb.1, rgb.0, 255].repeat((width * height) as usize)
+    }
+
+    #[test]
+    fn blit_clips_to_bounds() {
+        let mut snapshot = DesktopSnapshot::new(4, 4);
+        snapshot.blit(-2, 2, 4, 4, &solid(4, 4, (10, 20, 30)));
+
+        assert_eq!(snapshot.rgb_at(Point::new(0, 2)), Some((10, 20, 30)));
+        assert_eq!(snapshot.rgb_at(Point::new(1, 3)), Some((10, 20, 30)));
+        assert_eq!(snapshot.rgb_at(Point::new(2, 2)), Some((0, 0, 0)));
+        assert_eq!(snapshot.rgb_at(Point::new(0, 1)), Some((0, 0, 0)));
+        assert_eq!(snapshot.rgb_at(Point::new(4, 0)), None);
+    }
+
+    #[test]
+    fn region_pads_outside_with_black() {
+        let mut snapshot = DesktopSnapshot::new(2, 2);
+        snapshot.blit(0, 0, 2, 2, &solid(2, 2, (255, 0, 0)));
+
+        let region = snapshot.region(Point::new(0, 0), 1);
+        assert_eq!(region.len(), 3 * 3 * 4);
+        // Top-left is outside the snapshot, center is the red pixel
+        assert_eq!(&region[0..4], &[0, 0, 0, 255]);
+        assert_eq!(&region[16..20], &[0, 0, 255, 255]);
+    }
+}
+
+#[cfg(test)]
+mod multi_region_tests {
+    use crate::commands::capture_overlay::state::ResultState;
+    use crate::commands::capture_overlay::types::*;
+
+    #[test]
+    fn rect_union() {
+        let a = Rect::new(0, 0, 10, 10);
+        let b = Rect::new(-5, 20, 5, 30);
+        assert_eq!(a.union(&b), Rect::new(-5, 0, 10, 30));
+    }
+
+    #[test]
+    fn confirm_regions_uses_bounding_box() {
+        let mut result = ResultState::default();
+        let regions = vec![Rect::new(0, 0, 100, 50), Rect::new(200, 100, 300, 400)];
+        result.confirm_regions(regions.clone(), OverlayAction::CaptureScreenshot);
+
+        assert!(result.confirmed);
+        assert_eq!(result.selection, Some(Rect::new(0, 0, 300, 400)));
+        assert_eq!(result.regions, regions);
+
+        // Single selections don't carry regions
+        result.confirm(Rect::new(0, 0, 10, 10), OverlayAction::CaptureScreenshot);
+        assert!(result.regions.is_empty());
+    }
+}
@@ -43,9 +43,31 @@ pub const HANDLE_HALF: i32 = HANDLE_SIZE / 2;
 /// Minimum selection size in pixels
 pub const MIN_SELECTION_SIZE: i32 = 20;
 
+/// Distance at 96 DPI within which selection edges snap to monitor edges
+/// and virtual-screen midlines (scaled with the overlay's DPI)
+pub const SNAP_THRESHOLD: i32 = 8;
+
+/// Virtual key polled to confirm accumulated multi-region selections (Enter)
+pub const VK_CONFIRM_REGIONS: i32 = 0x0D;
+
+/// Virtual key polled to keep selecting after a region drag (Ctrl)
+pub const VK_ADD_REGION: i32 = 0x11;
+
+/// Virtual key polled to expand the selection to the monitor under the cursor ('M')
+pub const VK_FILL_MONITOR: i32 = 0x4D;
+
 /// Gap radius around cursor center for crosshair
 pub const CROSSHAIR_GAP: f32 = 10.0;
 
+/// Pixels sampled on each side of the cursor by the magnifier loupe
+pub const LOUPE_RADIUS: i32 = 8;
+
+/// Zoom factor of the magnifier loupe (screen pixels per sampled pixel)
+pub const LOUPE_ZOOM: f32 = 10.0;
+
+/// Distance between the cursor and the magnifier loupe
+pub const LOUPE_OFFSET: f32 = 24.0;
+
 /// Extended window style for DirectComposition (no redirection bitmap)
 pub const WS_EX_NOREDIRECTIONBITMAP: u32 = 0x00200000;
 
@@ -132,6 +154,16 @@ impl Rect {
         r
     }
 
+    /// Smallest rectangle containing both rectangles
+    pub fn union(&self, other: &Rect) -> Self {
+        Self {
+            left: self.left.min(other.left),
+            top: self.top.min(other.top),
+            right: self.right.max(other.right),
+            bottom: self.bottom.max(other.bottom),
+        }
+    }
+
     /// Offset the rectangle by dx, dy
     pub fn offset(&self, dx: i32, dy: i32) -> Self {
         Self {
@@ -189,6 +221,8 @@ pub enum OverlayMode {
     /// Region selection - drag to select custom region (default behavior)
     #[default]
     RegionSelect,
+    /// Color pick - click to pick the color of the pixel under the cursor
+    ColorPick,
 }
 
 impl OverlayMode {
@@ -198,6 +232,7 @@ impl OverlayMode {
             "display" => Self::DisplaySelect,
             "window" => Self::WindowSelect,
             "area" | "region" => Self::RegionSelect,
+            "color" | "eyedropper" => Self::ColorPick,
             _ => Self::RegionSelect,
         }
     }
@@ -231,6 +266,29 @@ pub enum OverlayAction {
     Cancelled,
     StartRecording,
     CaptureScreenshot,
+    PickColor,
+}
+
+/// Color picked in `OverlayMode::ColorPick`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PickedColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl PickedColor {
+    /// Opaque color from RGB components
+    pub fn opaque(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 255 }
+    }
+
+    /// Hex string in `#RRGGBB` form
+    pub fn hex(&self) -> String {
+        format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
 }
 
 /// Result from overlay selection
@@ -245,6 +303,13 @@ pub struct OverlayResult {
     /// Window ID (HWND) if a window was selected (for window capture mode)
     #[serde(serialize_with = "serialize_hwnd_option")]
     pub window_id: Option<isize>,
+    /// Picked color (for color pick mode; x/y is the picked pixel)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<PickedColor>,
+    /// Every region of a multi-region selection (x/y/width/height is their
+    /// bounding box). Empty for single selections.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub regions: Vec<SelectionEvent>,
 }
 
 impl OverlayResult {
@@ -257,6 +322,8 @@ impl OverlayResult {
             height: bounds.height(),
             action,
             window_id: None,
+            color: None,
+            regions: Vec::new(),
         }
     }
 
@@ -269,6 +336,8 @@ impl OverlayResult {
             height: bounds.height(),
             action,
             window_id: Some(window_id),
+            color: None,
+            regions: Vec::new(),
         }
     }
 
@@ -281,6 +350,8 @@ impl OverlayResult {
             height: 0,
             action: OverlayAction::Cancelled,
             window_id: None,
+            color: None,
+            regions: Vec::new(),
         }
     }
 }
@@ -6,7 +6,7 @@
 use tauri::{Emitter, Manager};
 use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, WPARAM};
 use windows::Win32::Graphics::Gdi::{BeginPaint, EndPaint, PAINTSTRUCT};
-use windows::Win32::UI::Input::KeyboardAndMouse::VK_SHIFT;
+use windows::Win32::UI::Input::KeyboardAndMouse::{GetAsyncKeyState, VK_SHIFT};
 use windows::Win32::UI::WindowsAndMessaging::{
     DefWindowProcW, GetWindowLongPtrW, LoadCursorW, SetCursor, SetWindowPos, GWLP_USERDATA,
     HTCLIENT, HWND_TOPMOST, IDC_CROSS, SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE, WM_CREATE,
@@ -122,8 +122,8 @@ fn handle_mouse_down(state_ptr: *mut OverlayState, lparam: LPARAM) -> LRESULT {
                     // Display mode: click immediately selects the monitor under cursor
                     // No drag needed
                 },
-                OverlayMode::WindowSelect => {
-                    // Window mode: click selects the hovered window
+                OverlayMode::WindowSelect | OverlayMode::ColorPick => {
+                    // Window/color mode: click picks on mouse up
                     // No drag needed
                 },
                 OverlayMode::RegionSelect => {
@@ -157,6 +157,7 @@ fn handle_mouse_move(state_ptr: *mut OverlayState, lparam: LPARAM) -> LRESULT {
                 let dx = x - state.adjustment.drag_start.x;
                 let dy = y - state.adjustment.drag_start.y;
                 state.adjustment.apply_delta(dx, dy);
+                state.adjustment.snap_to(&state.snap);
 
                 // Emit dimension updates to toolbar (throttled)
                 if state.should_emit(50) {
@@ -167,9 +168,9 @@ fn handle_mouse_move(state_ptr: *mut OverlayState, lparam: LPARAM) -> LRESULT {
         } else {
             // Mode-specific mouse move behavior
             match state.overlay_mode {
-                OverlayMode::DisplaySelect => {
-                    // Display mode: just update cursor, no window detection
-                    // Monitor highlight is based purely on cursor position (handled in render)
+                OverlayMode::DisplaySelect | OverlayMode::ColorPick => {
+                    // Display/color mode: just update cursor, no window detection
+                    // Monitor highlight and loupe follow the cursor (handled in render)
                 },
                 OverlayMode::WindowSelect => {
                     // Window mode: detect window under cursor
@@ -186,8 +187,14 @@ fn handle_mouse_move(state_ptr: *mut OverlayState, lparam: LPARAM) -> LRESULT {
                         // Check if we've dragged enough to enter region selection mode
                         if !state.drag.is_dragging && state.drag.exceeds_threshold() {
                             state.drag.is_dragging = true;
+                            state.drag.start = state.snap.snap_point(state.drag.start);
                             state.cursor.clear_hovered(); // Clear window detection when dragging
                         }
+
+                        // Snap the dragged corner to monitor edges and midlines
+                        if state.drag.is_dragging {
+                            state.drag.current = state.snap.snap_point(state.drag.current);
+                        }
                     } else {
                         // Window detection mode - find window under cursor
                         let screen_x = state.monitor.x + x;
@@ -227,6 +234,12 @@ fn handle_mouse_up(state_ptr: *mut OverlayState) -> LRESULT {
                     // Display mode: select the monitor under cursor
                     handle_monitor_selection(state);
                 },
+                OverlayMode::ColorPick => {
+                    // Color mode: pick the pixel under the cursor
+                    if !state.pick_color() {
+                        log::warn!("[Overlay] No snapshot pixel under cursor to pick");
+                    }
+                },
                 OverlayMode::WindowSelect => {
                     // Window mode: select the hovered window
                     if let Some(ref win) = state.cursor.hovered_window {
@@ -270,11 +283,20 @@ fn handle_region_selection_complete(state: &mut OverlayState) {
         let screen_bounds = state.monitor.local_rect_to_screen(local_bounds);
 
         if state.capture_type == CaptureType::Screenshot {
-            // For screenshots, capture immediately without adjustment mode
-            state
-                .result
-                .confirm(screen_bounds, OverlayAction::CaptureScreenshot);
-            state.should_close = true;
+            let add_region = unsafe { GetAsyncKeyState(VK_ADD_REGION) as u16 & 0x8000 } != 0;
+            if add_region {
+                // Ctrl held: keep the region and start another (Enter finishes)
+                state.add_region(local_bounds);
+            } else if !state.regions.is_empty() {
+                // Final region of a multi-region selection
+                state.confirm_regions(Some(local_bounds), OverlayAction::CaptureScreenshot);
+            } else {
+                // For screenshots, capture immediately without adjustment mode
+                state
+                    .result
+                    .confirm(screen_bounds, OverlayAction::CaptureScreenshot);
+                state.should_close = true;
+            }
         } else {
             // For video/gif, enter adjustment mode
             state.enter_adjustment_mode(local_bounds);
@@ -12,7 +12,7 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use super::video_recording::GifQualityPreset;
+use super::video_recording::{GifQualityPreset, MicChannelMode, RateControl, VideoCodec};
 
 // ============================================================================
 // Screenshot Settings
@@ -115,6 +115,41 @@ pub struct VideoSettings {
     /// When true, cursor is baked into video based on include_cursor setting.
     /// When false, cursor is captured separately for editor flexibility.
     pub quick_capture: bool,
+    /// Video codec. H.265 falls back to H.264 without a hardware HEVC encoder.
+    #[serde(default)]
+    pub codec: VideoCodec,
+    /// Encoder rate control (constant bitrate, variable bitrate or constant quality).
+    #[serde(default)]
+    pub rate_control: RateControl,
+    /// Seconds between keyframes (0.5-10). Shorter seeks faster in the editor.
+    #[serde(default = "default_keyframe_interval_secs")]
+    pub keyframe_interval_secs: f32,
+    /// Split long recordings into parts of this many seconds (None = single file).
+    #[serde(default)]
+    pub segment_duration_secs: Option<u32>,
+    /// Microphone gain in dB (-20 to +20).
+    #[serde(default)]
+    pub microphone_gain_db: f32,
+    /// Silence the microphone below `noise_gate_threshold_db`.
+    #[serde(default)]
+    pub noise_gate: bool,
+    /// Noise gate threshold in dBFS.
+    #[serde(default = "default_noise_gate_threshold_db")]
+    pub noise_gate_threshold_db: f32,
+    /// How microphone channels are mixed into the recorded track.
+    #[serde(default)]
+    pub mic_channel_mode: MicChannelMode,
+    /// Record key presses for the exported keystroke overlay.
+    #[serde(default)]
+    pub capture_keystrokes: bool,
+}
+
+fn default_keyframe_interval_secs() -> f32 {
+    2.0
+}
+
+fn default_noise_gate_threshold_db() -> f32 {
+    -45.0
 }
 
 impl Default for VideoSettings {
@@ -131,6 +166,15 @@ impl Default for VideoSettings {
             countdown_secs: 3,
             hide_desktop_icons: false,
             quick_capture: false, // Default to editor flow
+            codec: VideoCodec::default(),
+            rate_control: RateControl::default(),
+            keyframe_interval_secs: default_keyframe_interval_secs(),
+            segment_duration_secs: None,
+            microphone_gain_db: 0.0,
+            noise_gate: false,
+            noise_gate_threshold_db: default_noise_gate_threshold_db(),
+            mic_channel_mode: MicChannelMode::default(),
+            capture_keystrokes: false,
         }
     }
 }
@@ -4,11 +4,35 @@
 //! - Windows: DirectWrite
 //! - macOS: Core Text
 //! - Linux: Fontconfig
+//!
+//! Per-family weight/style variants come from fontdb, which reads face
+//! metadata without loading every font.
 
 use font_kit::family_name::FamilyName;
 use font_kit::properties::{Properties, Style, Weight};
 use font_kit::source::SystemSource;
-use std::collections::HashSet;
+use glyphon::cosmic_text::fontdb;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use ts_rs::TS;
+
+/// A weight/style a font family is installed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, TS)]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct FontVariant {
+    /// CSS weight, rounded to the nearest 100.
+    pub weight: u16,
+    pub italic: bool,
+}
+
+/// An installed font family and the variants it has.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, TS)]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct SystemFontFamily {
+    pub family: String,
+    /// Sorted by weight, upright before italic.
+    pub variants: Vec<FontVariant>,
+}
 
 /// Get list of installed system fonts (only fonts that can actually be loaded)
 #[tauri::command]
@@ -44,6 +68,63 @@ pub fn get_system_fonts() -> Result<Vec<String>, String> {
     Ok(valid_fonts)
 }
 
+/// Get installed font families with the weights/styles each has.
+///
+/// Lets the text tools offer only real variants, rather than ones the
+/// renderer would fake (e.g. synthesized bold).
+#[tauri::command]
+pub fn get_system_font_families() -> Result<Vec<SystemFontFamily>, String> {
+    let mut db = fontdb::Database::new();
+    db.load_system_fonts();
+
+    let faces = db.faces().filter_map(|face| {
+        // The first name is the English one
+        let (family, _) = face.families.first()?;
+        Some((
+            family.as_str(),
+            face.weight.0,
+            face.style != fontdb::Style::Normal,
+        ))
+    });
+    Ok(group_font_variants(faces))
+}
+
+/// Group `(family, weight, italic)` faces into families sorted by name.
+fn group_font_variants<'a>(
+    faces: impl IntoIterator<Item = (&'a str, u16, bool)>,
+) -> Vec<SystemFontFamily> {
+    let mut families: BTreeMap<String, (String, BTreeSet<FontVariant>)> = BTreeMap::new();
+
+    for (family, weight, italic) in faces {
+        // Skip internal/symbol fonts
+        if family.starts_with('@') || family.starts_with('.') || family.is_empty() {
+            continue;
+        }
+
+        families
+            .entry(family.to_lowercase())
+            .or_insert_with(|| (family.to_string(), BTreeSet::new()))
+            .1
+            .insert(FontVariant {
+                weight: round_weight(weight as f32) as u16,
+                italic,
+            });
+    }
+
+    families
+        .into_values()
+        .map(|(family, variants)| SystemFontFamily {
+            family,
+            variants: variants.into_iter().collect(),
+        })
+        .collect()
+}
+
+/// Round a font weight to the nearest 100 (100-900).
+fn round_weight(weight: f32) -> u32 {
+    (((weight as u32 + 50) / 100) * 100).clamp(100, 900)
+}
+
 /// Get font file data for a given font family name, weight, and style
 #[tauri::command]
 pub fn get_font_data(
@@ -110,9 +191,7 @@ pub fn get_font_weights(family: String) -> Result<Vec<u32>, String> {
     for handle in fonts {
         if let Ok(font) = handle.load() {
             let props = font.properties();
-            // Round weight to nearest 100
-            let weight = ((props.weight.0 as u32 + 50) / 100) * 100;
-            weights.insert(weight.clamp(100, 900));
+            weights.insert(round_weight(props.weight.0));
         }
     }
 
@@ -125,3 +204,59 @@ pub fn get_font_weights(family: String) -> Result<Vec<u32>, String> {
     result.sort();
     Ok(result)
 }
+
+/// Load a font file for video text overlays and register it under `family`.
+///
+/// `source` is a file path or `file://` URL. Returns once the font is loaded;
+/// segments using the family render with the fallback font until then.
+#[tauri::command]
+pub async fn load_text_font(family: String, source: String) -> Result<(), String> {
+    crate::rendering::fonts::load_font(family, source).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_font_variants() {
+        let families = group_font_variants([
+            ("Segoe UI", 700, false),
+            ("Segoe UI", 400, true),
+            ("Segoe UI", 350, false),
+            ("segoe ui", 400, false),
+            ("@MS Gothic", 400, false),
+            ("Arial", 400, false),
+        ]);
+
+        assert_eq!(
+            families,
+            vec![
+                SystemFontFamily {
+                    family: "Arial".to_string(),
+                    variants: vec![FontVariant {
+                        weight: 400,
+                        italic: false
+                    }],
+                },
+                SystemFontFamily {
+                    family: "Segoe UI".to_string(),
+                    variants: vec![
+                        FontVariant {
+                            weight: 400,
+                            italic: false
+                        },
+                        FontVariant {
+                            weight: 400,
+                            italic: true
+                        },
+                        FontVariant {
+                            weight: 700,
+                            italic: false
+                        },
+                    ],
+                },
+            ]
+        );
+    }
+}
@@ -2,6 +2,9 @@ use image::GenericImageView;
 use tauri::{command, image::Image as TauriImage, AppHandle};
 use tauri_plugin_clipboard_manager::ClipboardExt;
 
+use super::storage::types::CaptureProject;
+use super::storage::{get_app_data_dir, resolve_original_image};
+
 /// Copy image from file path to clipboard
 #[command]
 pub async fn copy_image_to_clipboard(app: AppHandle, path: String) -> Result<(), String> {
@@ -21,3 +24,95 @@ pub async fn copy_image_to_clipboard(app: AppHandle, path: String) -> Result<(),
 
     Ok(())
 }
+
+/// Copy a capture to clipboard as a file, so it pastes into Explorer, file
+/// dialogs and email clients as an attachment rather than as a bitmap.
+#[command]
+pub async fn copy_capture_as_file(app: AppHandle, project_id: String) -> Result<(), String> {
+    let base_dir = get_app_data_dir(&app)?;
+    let project_file = base_dir
+        .join("projects")
+        .join(&project_id)
+        .join("project.json");
+    if !project_file.exists() {
+        return Err("Project not found".to_string());
+    }
+
+    let content = std::fs::read_to_string(&project_file)
+        .map_err(|e| format!("Failed to read project: {}", e))?;
+    let project: CaptureProject =
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse project: {}", e))?;
+
+    let path = resolve_original_image(&base_dir, &project.original_image);
+    if !path.exists() {
+        return Err(format!("Capture file not found: {}", path.display()));
+    }
+
+    set_clipboard_file(&path)
+}
+
+/// Put `path` on the clipboard as a CF_HDROP file list.
+#[cfg(target_os = "windows")]
+fn set_clipboard_file(path: &std::path::Path) -> Result<(), String> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows::Win32::Foundation::{GlobalFree, BOOL, HANDLE, HWND, POINT};
+    use windows::Win32::System::DataExchange::{
+        CloseClipboard, EmptyClipboard, OpenClipboard, SetClipboardData,
+    };
+    use windows::Win32::System::Memory::{GlobalAlloc, GlobalLock, GlobalUnlock, GMEM_MOVEABLE};
+    use windows::Win32::System::Ole::CF_HDROP;
+    use windows::Win32::UI::Shell::DROPFILES;
+
+    // DROPFILES header followed by a double-NUL terminated wide path list
+    let wide_path: Vec<u16> = path.as_os_str().encode_wide().chain([0, 0]).collect();
+    let header_size = std::mem::size_of::<DROPFILES>();
+    let size = header_size + wide_path.len() * std::mem::size_of::<u16>();
+
+    unsafe {
+        let memory = GlobalAlloc(GMEM_MOVEABLE, size)
+            .map_err(|e| format!("Failed to allocate clipboard memory: {}", e))?;
+
+        let data = GlobalLock(memory) as *mut u8;
+        if data.is_null() {
+            let _ = GlobalFree(memory);
+            return Err("Failed to lock clipboard memory".to_string());
+        }
+        std::ptr::write_unaligned(
+            data as *mut DROPFILES,
+            DROPFILES {
+                pFiles: header_size as u32,
+                pt: POINT::default(),
+                fNC: BOOL(0),
+                fWide: BOOL(1),
+            },
+        );
+        std::ptr::copy_nonoverlapping(
+            wide_path.as_ptr() as *const u8,
+            data.add(header_size),
+            wide_path.len() * std::mem::size_of::<u16>(),
+        );
+        let _ = GlobalUnlock(memory);
+
+        if let Err(e) = OpenClipboard(HWND::default()) {
+            let _ = GlobalFree(memory);
+            return Err(format!("Failed to open clipboard: {}", e));
+        }
+
+        let result = EmptyClipboard()
+            .and_then(|_| SetClipboardData(CF_HDROP.0 as u32, HANDLE(memory.0)).map(|_| ()));
+        let _ = CloseClipboard();
+
+        // The clipboard owns the memory only once SetClipboardData succeeds
+        if let Err(e) = result {
+            let _ = GlobalFree(memory);
+            return Err(format!("Failed to copy file to clipboard: {}", e));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(not(target_os = "windows"))]
+fn set_clipboard_file(_path: &std::path::Path) -> Result<(), String> {
+    Err("Copying captures as files is only supported on Windows".to_string())
+}
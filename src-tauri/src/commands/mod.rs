@@ -13,8 +13,11 @@
 //! | [`image`] | Clipboard operations |
 //! | [`keyboard_hook`] | Windows low-level keyboard hook for global shortcuts |
 //! | [`logging`] | Frontend logging bridge |
+//! | [`ocr`] | Text extraction from captures (Windows.Media.Ocr) |
+//! | [`qr`] | QR code generation for annotations |
 //! | [`settings`] | App settings (autostart, close-to-tray, etc.) |
 //! | [`storage`] | Project persistence and library management |
+//! | [`upload`] | Capture uploads to sharing backends (S3-compatible) |
 //! | [`video_recording`] | Video/GIF recording and editing |
 //! | [`window`] | Window management (toolbar, overlay, editor) |
 //!
@@ -31,9 +34,12 @@ pub mod fonts;
 pub mod image;
 pub mod keyboard_hook;
 pub mod logging;
+pub mod ocr;
 pub mod preview;
+pub mod qr;
 pub mod settings;
 pub mod storage;
+pub mod upload;
 pub mod video_recording;
 pub mod win_utils;
 pub mod window;
@@ -0,0 +1,157 @@
+//! Text extraction (OCR) from captures.
+//!
+//! Uses the on-device Windows.Media.Ocr engine in the user's profile
+//! languages, so nothing is bundled or sent off the machine. Other platforms
+//! report OCR as unsupported.
+
+use std::path::PathBuf;
+
+use image::DynamicImage;
+use tauri::{command, AppHandle};
+
+use super::storage::types::CaptureProject;
+use super::storage::{get_app_data_dir, resolve_original_image};
+
+/// Recognize the text in a capture.
+///
+/// `project_id_or_path` is a library project ID or an image file path.
+/// Returns the recognized lines joined with newlines (empty if no text was
+/// found), ready for the clipboard.
+#[command]
+pub async fn extract_text(app: AppHandle, project_id_or_path: String) -> Result<String, String> {
+    let path = resolve_image_path(&app, &project_id_or_path)?;
+
+    tokio::task::spawn_blocking(move || {
+        let image = image::open(&path).map_err(|e| format!("Failed to open image: {}", e))?;
+        recognize_text(&image)
+    })
+    .await
+    .map_err(|e| format!("OCR task failed: {}", e))?
+}
+
+/// Image file of a project ID, or the path itself when it is a file.
+fn resolve_image_path(app: &AppHandle, project_id_or_path: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(project_id_or_path);
+    if path.is_file() {
+        return Ok(path);
+    }
+
+    let base_dir = get_app_data_dir(app)?;
+    let project_file = base_dir
+        .join("projects")
+        .join(project_id_or_path)
+        .join("project.json");
+    if !project_file.exists() {
+        return Err("Project not found".to_string());
+    }
+
+    let content = std::fs::read_to_string(&project_file)
+        .map_err(|e| format!("Failed to read project: {}", e))?;
+    let project: CaptureProject =
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse project: {}", e))?;
+    Ok(resolve_original_image(&base_dir, &project.original_image))
+}
+
+/// Size that fits `width`x`height` within `max_dimension` on both sides,
+/// keeping the aspect ratio. Unchanged when it already fits.
+fn fit_within(width: u32, height: u32, max_dimension: u32) -> (u32, u32) {
+    let longest = width.max(height);
+    if longest <= max_dimension || longest == 0 {
+        return (width, height);
+    }
+
+    let scale = max_dimension as f64 / longest as f64;
+    (
+        ((width as f64 * scale).round() as u32).clamp(1, max_dimension),
+        ((height as f64 * scale).round() as u32).clamp(1, max_dimension),
+    )
+}
+
+#[cfg(target_os = "windows")]
+fn recognize_text(image: &DynamicImage) -> Result<String, String> {
+    use image::imageops::FilterType;
+    use windows::Graphics::Imaging::{BitmapPixelFormat, SoftwareBitmap};
+    use windows::Media::Ocr::OcrEngine;
+    use windows::Storage::Streams::DataWriter;
+    use windows::Win32::System::Com::{CoInitializeEx, CoUninitialize, COINIT_MULTITHREADED};
+
+    // WinRT needs COM on this (blocking pool) thread
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+    }
+    struct ComGuard;
+    impl Drop for ComGuard {
+        fn drop(&mut self) {
+            unsafe {
+                CoUninitialize();
+            }
+        }
+    }
+    let _guard = ComGuard;
+
+    let engine = OcrEngine::TryCreateFromUserProfileLanguages()
+        .map_err(|e| format!("No OCR language available: {}", e))?;
+
+    // The engine rejects images larger than its maximum dimension
+    let max_dimension = OcrEngine::MaxImageDimension().map_err(|e| e.to_string())?;
+    let (width, height) = fit_within(image.width(), image.height(), max_dimension);
+    let image = if (width, height) == (image.width(), image.height()) {
+        image.clone()
+    } else {
+        image.resize_exact(width, height, FilterType::Triangle)
+    };
+
+    // SoftwareBitmap takes BGRA
+    let mut bgra = image.to_rgba8().into_raw();
+    for pixel in bgra.chunks_exact_mut(4) {
+        pixel.swap(0, 2);
+    }
+
+    let bitmap = (|| -> windows::core::Result<SoftwareBitmap> {
+        let writer = DataWriter::new()?;
+        writer.WriteBytes(&bgra)?;
+        let buffer = writer.DetachBuffer()?;
+        SoftwareBitmap::CreateCopyFromBuffer(
+            &buffer,
+            BitmapPixelFormat::Bgra8,
+            width as i32,
+            height as i32,
+        )
+    })()
+    .map_err(|e| format!("Failed to prepare image for OCR: {}", e))?;
+
+    let result = engine
+        .RecognizeAsync(&bitmap)
+        .and_then(|operation| operation.get())
+        .map_err(|e| format!("OCR failed: {}", e))?;
+
+    let lines = result
+        .Lines()
+        .map_err(|e| format!("OCR failed: {}", e))?
+        .into_iter()
+        .filter_map(|line| line.Text().ok())
+        .map(|text| text.to_string_lossy())
+        .collect::<Vec<_>>();
+
+    log::info!("[OCR] Recognized {} line(s)", lines.len());
+    Ok(lines.join("\n"))
+}
+
+#[cfg(not(target_os = "windows"))]
+fn recognize_text(_image: &DynamicImage) -> Result<String, String> {
+    Err("Text extraction is only supported on Windows".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_within_keeps_aspect_ratio() {
+        assert_eq!(fit_within(1920, 1080, 10_000), (1920, 1080));
+        assert_eq!(fit_within(20_000, 5_000, 10_000), (10_000, 2_500));
+        assert_eq!(fit_within(3_000, 12_000, 6_000), (1_500, 6_000));
+        // Extreme strips keep at least one pixel
+        assert_eq!(fit_within(100_000, 1, 1_000), (1_000, 1));
+    }
+}
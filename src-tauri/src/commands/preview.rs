@@ -5,7 +5,7 @@ use crate::preview::{
     create_frame_ws, get_preview_instance, remove_preview_instance, PreviewRenderer,
     ShutdownSignal, WSFrame,
 };
-use crate::rendering::RendererState;
+use crate::rendering::{RendererState, TextMeasurement};
 use parking_lot::Mutex as ParkingMutex;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -177,6 +177,24 @@ pub async fn render_text_overlay(
         .await
 }
 
+/// Measure the pixel size of a text segment as it would render at `width` x `height`.
+///
+/// Lets the UI position selection handles around the real text bounds.
+#[command]
+pub async fn measure_text_segment(
+    state: State<'_, PreviewState>,
+    width: u32,
+    height: u32,
+    segment: TextSegment,
+) -> Result<TextMeasurement, String> {
+    let renderer = state.renderer.read().await;
+    let renderer = renderer
+        .as_ref()
+        .ok_or_else(|| "Preview not initialized".to_string())?;
+
+    Ok(renderer.measure_text_segment(width, height, &segment).await)
+}
+
 // =============================================================================
 // Native Text Preview Commands (zero-latency surface rendering)
 // =============================================================================
@@ -0,0 +1,88 @@
+//! QR code generation for QR annotations.
+//!
+//! Codes are rendered as black-on-white PNGs with whole-pixel modules and the
+//! standard four-module quiet zone, so they stay scannable when the editor or
+//! exporter places them on top of a capture.
+
+use std::io::Cursor;
+
+use image::{GrayImage, ImageFormat, Luma};
+use qrcode::{Color, QrCode};
+use tauri::command;
+
+/// Light modules around the code that scanners need to find it.
+const QUIET_ZONE_MODULES: u32 = 4;
+
+/// Largest image size accepted, so a bad request can't allocate a huge image.
+const MAX_QR_SIZE: u32 = 4096;
+
+/// Render `data` as a QR code PNG at most `size` pixels square.
+///
+/// Modules are a whole number of pixels, so the image is the largest multiple
+/// of the module count that fits in `size` (and never smaller than one pixel
+/// per module). `size` is capped at `MAX_QR_SIZE`.
+#[command]
+pub fn generate_qr(data: String, size: u32) -> Result<Vec<u8>, String> {
+    let code = QrCode::new(data.as_bytes()).map_err(|e| format!("Failed to encode QR: {}", e))?;
+    let image = render_qr(&code, size.min(MAX_QR_SIZE));
+
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|e| format!("Failed to encode QR image: {}", e))?;
+    Ok(png)
+}
+
+/// Draw `code` with its quiet zone, scaled to fit `size`.
+fn render_qr(code: &QrCode, size: u32) -> GrayImage {
+    let modules = code.width() as u32;
+    let total_modules = modules + 2 * QUIET_ZONE_MODULES;
+    let scale = (size / total_modules).max(1);
+    let colors = code.to_colors();
+
+    GrayImage::from_fn(total_modules * scale, total_modules * scale, |x, y| {
+        let (mx, my) = (x / scale, y / scale);
+        let inside = (QUIET_ZONE_MODULES..QUIET_ZONE_MODULES + modules).contains(&mx)
+            && (QUIET_ZONE_MODULES..QUIET_ZONE_MODULES + modules).contains(&my);
+        let dark = inside
+            && colors[((my - QUIET_ZONE_MODULES) * modules + (mx - QUIET_ZONE_MODULES)) as usize]
+                == Color::Dark;
+        Luma([if dark { 0 } else { 255 }])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_qr_fits_size_with_quiet_zone() {
+        let png = generate_qr("https://example.com".to_string(), 300).unwrap();
+        let image = image::load_from_memory(&png).unwrap().to_luma8();
+
+        // Version 2 (25 modules) + quiet zone = 33 modules, 9px each
+        assert_eq!(image.dimensions(), (297, 297));
+        // Quiet zone is light, the finder pattern's corner is dark
+        assert_eq!(image.get_pixel(0, 0).0, [255]);
+        assert_eq!(image.get_pixel(4 * 9, 4 * 9).0, [0]);
+    }
+
+    #[test]
+    fn test_generate_qr_keeps_one_pixel_per_module() {
+        let png = generate_qr("snapit".to_string(), 1).unwrap();
+        let image = image::load_from_memory(&png).unwrap();
+
+        // Version 1 (21 modules) + quiet zone
+        assert_eq!((image.width(), image.height()), (29, 29));
+    }
+
+    #[test]
+    fn test_generate_qr_caps_size() {
+        let png = generate_qr("snapit".to_string(), u32::MAX).unwrap();
+        let image = image::load_from_memory(&png).unwrap();
+
+        // 29 modules at the largest whole scale within MAX_QR_SIZE
+        assert_eq!(image.width(), MAX_QR_SIZE / 29 * 29);
+        assert!(image.width() <= MAX_QR_SIZE);
+    }
+}
@@ -0,0 +1,397 @@
+//! Self-contained `.snapitproj` bundles for sharing editable captures.
+//!
+//! A bundle is a zip with a `manifest.json` naming its kind:
+//! - screenshot: `project.json` plus the original image
+//! - video: every file in the video project folder (screen, webcam, cursor,
+//!   audio tracks and `project.json`), with paths relative to the folder
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{Read, Seek, Write};
+use std::path::Path;
+use tauri::{command, AppHandle};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use super::ffmpeg::generate_thumbnail;
+use super::operations::index_content_hash;
+use super::types::CaptureProject;
+use super::{
+    ensure_directories, generate_id, get_app_data_dir, get_captures_dir, resolve_original_image,
+};
+
+/// Bump when the bundle layout changes incompatibly.
+const BUNDLE_VERSION: u32 = 1;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const PROJECT_FILE: &str = "project.json";
+
+/// Video sources that hold paths into the project folder.
+const VIDEO_SOURCE_KEYS: [&str; 6] = [
+    "screenVideo",
+    "webcamVideo",
+    "cursorData",
+    "audioFile",
+    "systemAudio",
+    "microphoneAudio",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum BundleKind {
+    Screenshot,
+    Video,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct BundleManifest {
+    pub version: u32,
+    pub kind: BundleKind,
+}
+
+/// Media is already compressed; only deflate the small text files.
+fn entry_options(name: &str) -> SimpleFileOptions {
+    let method = if name.ends_with(".json") {
+        CompressionMethod::Deflated
+    } else {
+        CompressionMethod::Stored
+    };
+    SimpleFileOptions::default()
+        .compression_method(method)
+        .large_file(true)
+}
+
+fn add_bytes<W: Write + Seek>(
+    zip: &mut ZipWriter<W>,
+    name: &str,
+    data: &[u8],
+) -> Result<(), String> {
+    zip.start_file(name, entry_options(name))
+        .map_err(|e| format!("Failed to add {} to bundle: {}", name, e))?;
+    zip.write_all(data)
+        .map_err(|e| format!("Failed to add {} to bundle: {}", name, e))
+}
+
+fn add_file<W: Write + Seek>(
+    zip: &mut ZipWriter<W>,
+    name: &str,
+    path: &Path,
+) -> Result<(), String> {
+    let mut file = fs::File::open(path).map_err(|e| format!("Failed to open {:?}: {}", path, e))?;
+    zip.start_file(name, entry_options(name))
+        .map_err(|e| format!("Failed to add {} to bundle: {}", name, e))?;
+    std::io::copy(&mut file, zip)
+        .map_err(|e| format!("Failed to add {} to bundle: {}", name, e))?;
+    Ok(())
+}
+
+/// Add every file under `dir`, named by its path relative to `root`.
+fn add_dir<W: Write + Seek>(zip: &mut ZipWriter<W>, root: &Path, dir: &Path) -> Result<(), String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("Failed to read {:?}: {}", dir, e))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            add_dir(zip, root, &path)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            // Zip entry names always use forward slashes
+            let name = relative.to_string_lossy().replace('\\', "/");
+            add_file(zip, &name, &path)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_manifest<W: Write + Seek>(zip: &mut ZipWriter<W>, kind: BundleKind) -> Result<(), String> {
+    let manifest = serde_json::to_vec_pretty(&BundleManifest {
+        version: BUNDLE_VERSION,
+        kind,
+    })
+    .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+    add_bytes(zip, MANIFEST_FILE, &manifest)
+}
+
+fn read_entry<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str) -> Result<Vec<u8>, String> {
+    let mut entry = archive
+        .by_name(name)
+        .map_err(|_| format!("Bundle is missing {}", name))?;
+    let mut data = Vec::new();
+    entry
+        .read_to_end(&mut data)
+        .map_err(|e| format!("Failed to read {} from bundle: {}", name, e))?;
+    Ok(data)
+}
+
+/// Extract every entry except the manifest into `dest`.
+/// Entries with paths escaping `dest` are rejected.
+fn extract_all<R: Read + Seek>(archive: &mut ZipArchive<R>, dest: &Path) -> Result<(), String> {
+    for i in 0..archive.len() {
+        let mut entry = archive
+            .by_index(i)
+            .map_err(|e| format!("Failed to read bundle: {}", e))?;
+        if entry.name() == MANIFEST_FILE || entry.is_dir() {
+            continue;
+        }
+
+        let relative = entry
+            .enclosed_name()
+            .ok_or_else(|| format!("Unsafe path in bundle: {}", entry.name()))?;
+        let out_path = dest.join(relative);
+        if let Some(parent) = out_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {:?}: {}", parent, e))?;
+        }
+
+        let mut out = fs::File::create(&out_path)
+            .map_err(|e| format!("Failed to create {:?}: {}", out_path, e))?;
+        std::io::copy(&mut entry, &mut out)
+            .map_err(|e| format!("Failed to extract {:?}: {}", out_path, e))?;
+    }
+    Ok(())
+}
+
+/// Point absolute source paths at the bundled copy inside the new folder.
+///
+/// Recordings normally store paths relative to their folder, but older ones
+/// may hold absolute paths from the machine that made them.
+pub(crate) fn relink_video_sources(project: &mut serde_json::Value, folder: &Path) {
+    let Some(sources) = project.get_mut("sources").and_then(|s| s.as_object_mut()) else {
+        return;
+    };
+
+    for key in VIDEO_SOURCE_KEYS {
+        let Some(path) = sources.get(key).and_then(|v| v.as_str()) else {
+            continue;
+        };
+        let path = Path::new(path);
+        if !path.is_absolute() {
+            continue;
+        }
+        if let Some(file_name) = path.file_name() {
+            if folder.join(file_name).exists() {
+                sources.insert(
+                    key.to_string(),
+                    serde_json::Value::String(file_name.to_string_lossy().to_string()),
+                );
+            }
+        }
+    }
+}
+
+pub(crate) fn export_bundle(
+    base_dir: &Path,
+    captures_dir: &Path,
+    project_id: &str,
+    output_path: &Path,
+) -> Result<(), String> {
+    let file =
+        fs::File::create(output_path).map_err(|e| format!("Failed to create bundle: {}", e))?;
+
+    let result = write_bundle(ZipWriter::new(file), base_dir, captures_dir, project_id);
+    if result.is_err() {
+        // Don't leave a truncated bundle behind
+        let _ = fs::remove_file(output_path);
+    }
+    result
+}
+
+fn write_bundle<W: Write + Seek>(
+    mut zip: ZipWriter<W>,
+    base_dir: &Path,
+    captures_dir: &Path,
+    project_id: &str,
+) -> Result<(), String> {
+    let project_file = base_dir
+        .join("projects")
+        .join(project_id)
+        .join(PROJECT_FILE);
+    let video_folder = captures_dir.join(project_id);
+
+    if project_file.exists() {
+        let content = fs::read_to_string(&project_file)
+            .map_err(|e| format!("Failed to read project: {}", e))?;
+        let project: CaptureProject = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse project: {}", e))?;
+
+        let image_path = resolve_original_image(base_dir, &project.original_image);
+        let image_name = image_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .ok_or_else(|| "Invalid image path".to_string())?;
+
+        write_manifest(&mut zip, BundleKind::Screenshot)?;
+        add_bytes(&mut zip, PROJECT_FILE, content.as_bytes())?;
+        add_file(&mut zip, &image_name, &image_path)?;
+    } else if video_folder.join("screen.mp4").exists() {
+        write_manifest(&mut zip, BundleKind::Video)?;
+        add_dir(&mut zip, &video_folder, &video_folder)?;
+    } else {
+        return Err("Only screenshot and video projects can be bundled".to_string());
+    }
+
+    zip.finish()
+        .map_err(|e| format!("Failed to write bundle: {}", e))?;
+    Ok(())
+}
+
+fn import_screenshot<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    base_dir: &Path,
+    captures_dir: &Path,
+) -> Result<String, String> {
+    let mut project: CaptureProject = serde_json::from_slice(&read_entry(archive, PROJECT_FILE)?)
+        .map_err(|e| format!("Failed to parse project: {}", e))?;
+
+    let image_name = Path::new(&project.original_image)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .ok_or_else(|| "Bundle project has no image".to_string())?;
+    let image_data = read_entry(archive, &image_name)?;
+
+    let id = generate_id();
+    let now = Utc::now();
+    let extension = Path::new(&image_name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("png");
+
+    // Decode before writing, so a corrupt image leaves nothing behind
+    let image =
+        image::load_from_memory(&image_data).map_err(|e| format!("Failed to load image: {}", e))?;
+
+    let original_path = captures_dir.join(format!(
+        "{}_{}.{}",
+        now.format("%Y-%m-%d_%H%M%S"),
+        &id,
+        extension
+    ));
+    fs::write(&original_path, &image_data).map_err(|e| format!("Failed to save image: {}", e))?;
+    let thumbnail_path = base_dir
+        .join("thumbnails")
+        .join(format!("{}_thumb.png", &id));
+    generate_thumbnail(&image)?
+        .save(&thumbnail_path)
+        .map_err(|e| format!("Failed to save thumbnail: {}", e))?;
+
+    project.id = id.clone();
+    project.original_image = original_path.to_string_lossy().to_string();
+    project.updated_at = now;
+
+    let project_dir = base_dir.join("projects").join(&id);
+    fs::create_dir_all(&project_dir).map_err(|e| format!("Failed to create project dir: {}", e))?;
+    let project_json = serde_json::to_string_pretty(&project)
+        .map_err(|e| format!("Failed to serialize project: {}", e))?;
+    fs::write(project_dir.join(PROJECT_FILE), project_json)
+        .map_err(|e| format!("Failed to write project file: {}", e))?;
+
+    if let Some(hash) = &project.content_hash {
+        index_content_hash(hash, &id);
+    }
+
+    Ok(id)
+}
+
+fn import_video<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    captures_dir: &Path,
+) -> Result<String, String> {
+    // Same naming as fresh recordings
+    let folder_name = format!(
+        "recording_{}_{}",
+        chrono::Local::now().format("%Y%m%d_%H%M%S"),
+        rand::random::<u16>()
+    );
+    let folder = captures_dir.join(&folder_name);
+    fs::create_dir_all(&folder).map_err(|e| format!("Failed to create recording folder: {}", e))?;
+
+    let result = extract_all(archive, &folder).and_then(|()| {
+        if !folder.join("screen.mp4").exists() {
+            return Err("Bundle has no screen recording".to_string());
+        }
+
+        let project_file = folder.join(PROJECT_FILE);
+        let Ok(content) = fs::read_to_string(&project_file) else {
+            // Recordings without a project.json still load from screen.mp4
+            return Ok(());
+        };
+        let mut project: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse video project: {}", e))?;
+
+        relink_video_sources(&mut project, &folder);
+        if let Some(object) = project.as_object_mut() {
+            object.insert("id".to_string(), serde_json::Value::String(generate_id()));
+            object.insert(
+                "updatedAt".to_string(),
+                serde_json::Value::String(Utc::now().to_rfc3339()),
+            );
+        }
+
+        let project_json = serde_json::to_string_pretty(&project)
+            .map_err(|e| format!("Failed to serialize video project: {}", e))?;
+        fs::write(&project_file, project_json)
+            .map_err(|e| format!("Failed to write video project: {}", e))
+    });
+
+    if let Err(e) = result {
+        let _ = fs::remove_dir_all(&folder);
+        return Err(e);
+    }
+
+    Ok(folder_name)
+}
+
+/// Export a screenshot or video project as a `.snapitproj` bundle.
+#[command]
+pub async fn export_project_bundle(
+    app: AppHandle,
+    project_id: String,
+    output_path: String,
+) -> Result<(), String> {
+    let base_dir = get_app_data_dir(&app)?;
+    let captures_dir = get_captures_dir(&app)?;
+
+    tokio::task::spawn_blocking(move || {
+        export_bundle(
+            &base_dir,
+            &captures_dir,
+            &project_id,
+            Path::new(&output_path),
+        )
+    })
+    .await
+    .map_err(|e| format!("Bundle export task failed: {}", e))?
+}
+
+/// Import a `.snapitproj` bundle as a new project. Returns the new library ID.
+#[command]
+pub async fn import_project_bundle(app: AppHandle, bundle_path: String) -> Result<String, String> {
+    let base_dir = ensure_directories(&app)?;
+    let captures_dir = get_captures_dir(&app)?;
+
+    tokio::task::spawn_blocking(move || {
+        let file =
+            fs::File::open(&bundle_path).map_err(|e| format!("Failed to open bundle: {}", e))?;
+        let mut archive =
+            ZipArchive::new(file).map_err(|e| format!("Not a valid bundle: {}", e))?;
+
+        let manifest: BundleManifest =
+            serde_json::from_slice(&read_entry(&mut archive, MANIFEST_FILE)?)
+                .map_err(|e| format!("Invalid bundle manifest: {}", e))?;
+        if manifest.version > BUNDLE_VERSION {
+            return Err(format!(
+                "Bundle version {} is newer than this app supports",
+                manifest.version
+            ));
+        }
+
+        let id = match manifest.kind {
+            BundleKind::Screenshot => import_screenshot(&mut archive, &base_dir, &captures_dir)?,
+            BundleKind::Video => import_video(&mut archive, &captures_dir)?,
+        };
+
+        log::info!("[BUNDLE] Imported {:?} bundle as {}", manifest.kind, id);
+        Ok(id)
+    })
+    .await
+    .map_err(|e| format!("Bundle import task failed: {}", e))?
+}
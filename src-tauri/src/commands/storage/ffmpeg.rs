@@ -244,6 +244,86 @@ pub fn generate_video_thumbnail(
     }
 }
 
+/// Number of frames sampled across a video for its animated thumbnail.
+const ANIMATED_THUMBNAIL_FRAMES: u32 = 10;
+
+/// Playback rate of animated thumbnails, in frames per second.
+const ANIMATED_THUMBNAIL_FPS: u32 = 2;
+
+/// Get video duration in seconds using bundled ffprobe.
+fn get_video_duration_secs(video_path: &PathBuf) -> Option<f64> {
+    let ffprobe_path = find_ffprobe()?;
+
+    let output = create_hidden_command(&ffprobe_path)
+        .args([
+            "-v",
+            "error",
+            "-show_entries",
+            "format=duration",
+            "-of",
+            "default=noprint_wrappers=1:nokey=1",
+        ])
+        .arg(video_path)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|d| *d > 0.0)
+}
+
+/// Generate an animated WebP thumbnail from a video using bundled ffmpeg.
+/// Samples frames evenly across the whole duration and loops them.
+pub fn generate_animated_thumbnail(video_path: &PathBuf, out_path: &PathBuf) -> Result<(), String> {
+    let ffmpeg_path = find_ffmpeg().ok_or_else(|| "ffmpeg not found".to_string())?;
+
+    // Without a duration, fall back to one frame per second from the start
+    let sample_rate = get_video_duration_secs(video_path)
+        .map(|duration| ANIMATED_THUMBNAIL_FRAMES as f64 / duration)
+        .unwrap_or(1.0);
+
+    // Sample frames, then retime them so the preview plays at a fixed rate
+    let filter = format!(
+        "fps={:.6},scale={}:-1,setpts=N/{}/TB",
+        sample_rate, THUMBNAIL_SIZE, ANIMATED_THUMBNAIL_FPS
+    );
+
+    let result = create_hidden_command(&ffmpeg_path)
+        .args(["-y", "-i", &video_path.to_string_lossy().to_string()])
+        .args([
+            "-vf",
+            &filter,
+            "-frames:v",
+            &ANIMATED_THUMBNAIL_FRAMES.to_string(),
+            "-r",
+            &ANIMATED_THUMBNAIL_FPS.to_string(),
+            "-an",
+            "-c:v",
+            "libwebp",
+            "-quality",
+            "75",
+            "-loop",
+            "0",
+            &out_path.to_string_lossy().to_string(),
+        ])
+        .output()
+        .map_err(|e| format!("Failed to run ffmpeg: {}", e))?;
+
+    if result.status.success() {
+        Ok(())
+    } else {
+        let _ = fs::remove_file(out_path);
+        let stderr = String::from_utf8_lossy(&result.stderr);
+        Err(format!("ffmpeg failed: {}", stderr))
+    }
+}
+
 /// Generate thumbnail from GIF using pure Rust (image crate).
 /// Extracts the first frame and resizes it.
 pub fn generate_gif_thumbnail(gif_path: &PathBuf, thumbnail_path: &PathBuf) -> Result<(), String> {
@@ -0,0 +1,196 @@
+//! User-configurable output filenames (`general.filenameTemplate`).
+//!
+//! Templates are plain text with these tokens:
+//! - `{date}` - local date, `2025-01-31`
+//! - `{time}` - local time, `143005`
+//! - `{source}` - monitor name, window title, `Region` or `All Displays`
+//! - `{n}` - per-day counter, starting at 1
+//!
+//! An empty template, an unknown token or an unclosed brace falls back to the
+//! built-in naming scheme of the caller.
+
+use chrono::{DateTime, Local};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use tauri::AppHandle;
+
+use super::get_app_data_dir;
+use super::types::CaptureSource;
+
+/// Keeps templated names well under the Windows MAX_PATH limit.
+const MAX_FILENAME_LEN: usize = 120;
+
+/// Give up looking for a free name after this many collisions.
+const MAX_NAME_ATTEMPTS: u32 = 1000;
+
+/// Characters Windows does not allow in file names.
+const ILLEGAL_FILENAME_CHARS: [char; 9] = ['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
+
+/// The user's filename template, if one is configured.
+pub(crate) fn filename_template(app: &AppHandle) -> Option<String> {
+    let app_data_dir = get_app_data_dir(app).ok()?;
+    let content = fs::read_to_string(app_data_dir.join("settings.json")).ok()?;
+    let settings = serde_json::from_str::<serde_json::Value>(&content).ok()?;
+    let template = settings
+        .get("general")?
+        .get("filenameTemplate")?
+        .as_str()?
+        .trim();
+
+    (!template.is_empty()).then(|| template.to_string())
+}
+
+/// Replace characters that are illegal in file names and trim what Windows strips.
+pub(crate) fn sanitize_filename(name: &str) -> String {
+    let sanitized: String = name
+        .chars()
+        .map(|c| {
+            if c.is_control() || ILLEGAL_FILENAME_CHARS.contains(&c) {
+                '_'
+            } else {
+                c
+            }
+        })
+        .take(MAX_FILENAME_LEN)
+        .collect();
+
+    sanitized
+        .trim_start()
+        .trim_end_matches(|c: char| c == '.' || c.is_whitespace())
+        .to_string()
+}
+
+/// Expand a template into a file stem. Returns None if the template is invalid
+/// or expands to nothing usable.
+pub(crate) fn expand_filename_template(
+    template: &str,
+    now: DateTime<Local>,
+    source: &str,
+    n: u32,
+) -> Option<String> {
+    let mut expanded = String::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        expanded.push_str(&rest[..start]);
+        let end = rest[start..].find('}')? + start;
+
+        match &rest[start + 1..end] {
+            "date" => expanded.push_str(&now.format("%Y-%m-%d").to_string()),
+            "time" => expanded.push_str(&now.format("%H%M%S").to_string()),
+            "source" => expanded.push_str(source),
+            "n" => expanded.push_str(&n.to_string()),
+            _ => return None,
+        }
+        rest = &rest[end + 1..];
+    }
+    if rest.contains('}') {
+        return None;
+    }
+    expanded.push_str(rest);
+
+    let sanitized = sanitize_filename(&expanded);
+    (!sanitized.is_empty()).then_some(sanitized)
+}
+
+/// Number of entries in `dir` last modified today, used to seed `{n}`.
+fn files_created_today(dir: &Path, today: chrono::NaiveDate) -> u32 {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+        .filter(|modified| DateTime::<Local>::from(*modified).date_naive() == today)
+        .count() as u32
+}
+
+/// Build a unique file name in `dir` from the user's template.
+///
+/// `extension` is None for folders. Returns None if no template is configured
+/// or it is invalid, so callers can fall back to their default naming.
+pub(crate) fn templated_filename(
+    app: &AppHandle,
+    dir: &Path,
+    source: &str,
+    extension: Option<&str>,
+) -> Option<String> {
+    let template = filename_template(app)?;
+    let now = Local::now();
+    let counter_start = files_created_today(dir, now.date_naive()) + 1;
+    let has_counter = template.contains("{n}");
+
+    let with_extension = |stem: String| match extension {
+        Some(ext) => format!("{}.{}", stem, ext),
+        None => stem,
+    };
+
+    let first = expand_filename_template(&template, now, source, counter_start);
+    let Some(first) = first else {
+        log::warn!("[FILENAME] Invalid filename template: {:?}", template);
+        return None;
+    };
+
+    for attempt in 0..MAX_NAME_ATTEMPTS {
+        let stem = if attempt == 0 {
+            first.clone()
+        } else if has_counter {
+            expand_filename_template(&template, now, source, counter_start + attempt)?
+        } else {
+            format!("{}_{}", first, attempt + 1)
+        };
+
+        let name = with_extension(stem);
+        if !dir.join(&name).exists() {
+            return Some(name);
+        }
+    }
+
+    None
+}
+
+/// Display name of a monitor for `{source}`, e.g. `DELL U2720Q`.
+pub(crate) fn monitor_source_name(monitor_index: usize) -> String {
+    scap_targets::Display::list()
+        .get(monitor_index)
+        .and_then(|display| display.name())
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| format!("Display {}", monitor_index + 1))
+}
+
+/// Title of a window for `{source}`, falling back to its owning app.
+pub(crate) fn window_source_name(window_id: u32) -> String {
+    scap_targets::WindowId::from_str(&window_id.to_string())
+        .ok()
+        .and_then(|id| scap_targets::Window::from_id(&id))
+        .and_then(|window| {
+            window
+                .name()
+                .filter(|name| !name.trim().is_empty())
+                .or_else(|| window.owner_name())
+        })
+        .unwrap_or_else(|| "Window".to_string())
+}
+
+/// `{source}` for a screenshot.
+pub(crate) fn capture_source_name(source: &CaptureSource) -> String {
+    if let Some(title) = source
+        .window_title
+        .as_deref()
+        .filter(|title| !title.trim().is_empty())
+    {
+        return title.to_string();
+    }
+
+    if let Some(window_id) = source.window_id {
+        window_source_name(window_id)
+    } else if source.region.is_some() {
+        "Region".to_string()
+    } else if let Some(monitor) = source.monitor {
+        monitor_source_name(monitor as usize)
+    } else {
+        "Screen".to_string()
+    }
+}
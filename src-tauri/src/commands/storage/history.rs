@@ -0,0 +1,119 @@
+//! Persistent annotation history for undo/redo across editor sessions.
+//!
+//! Every saved annotation set is appended to `annotations_history.json` next to
+//! the project's `project.json`, with a pointer at the set the project holds.
+//! Undo and redo move the pointer; saving a new set drops the sets ahead of it
+//! (like an editor's redo stack). The oldest sets are dropped beyond
+//! `MAX_HISTORY_ENTRIES`.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::types::Annotation;
+
+/// History file name inside a project folder.
+pub const ANNOTATION_HISTORY_FILE: &str = "annotations_history.json";
+
+/// Annotation sets kept per project.
+pub const MAX_HISTORY_ENTRIES: usize = 100;
+
+/// Saved annotation sets of one project, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnotationHistory {
+    pub entries: Vec<Vec<Annotation>>,
+    /// Index of the entry the project currently holds.
+    pub position: usize,
+}
+
+impl AnnotationHistory {
+    /// History file of the project in `project_dir`.
+    pub fn path(project_dir: &Path) -> PathBuf {
+        project_dir.join(ANNOTATION_HISTORY_FILE)
+    }
+
+    /// Load the history of the project in `project_dir` (empty if none yet).
+    pub fn load(project_dir: &Path) -> Result<Self, String> {
+        let path = Self::path(project_dir);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read annotation history: {}", e))?;
+        let mut history: Self = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse annotation history: {}", e))?;
+        history.position = history
+            .position
+            .min(history.entries.len().saturating_sub(1));
+        Ok(history)
+    }
+
+    /// Write the history to the project in `project_dir`.
+    pub fn save(&self, project_dir: &Path) -> Result<(), String> {
+        let json = serde_json::to_string(self)
+            .map_err(|e| format!("Failed to serialize annotation history: {}", e))?;
+        fs::write(Self::path(project_dir), json)
+            .map_err(|e| format!("Failed to write annotation history: {}", e))
+    }
+
+    /// Record a newly saved annotation set.
+    ///
+    /// `previous` is the set the project held before, used as the first entry
+    /// of a history that doesn't have one yet. Saving the current set again is
+    /// not recorded, and saving a neighbouring set (the editor undid or redid
+    /// in memory) moves the pointer to it instead.
+    pub fn record(&mut self, previous: &[Annotation], annotations: &[Annotation]) {
+        if self.entries.is_empty() {
+            self.entries.push(previous.to_vec());
+            self.position = 0;
+        }
+        if same_annotations(&self.entries[self.position], annotations) {
+            return;
+        }
+        if self.position > 0 && same_annotations(&self.entries[self.position - 1], annotations) {
+            self.position -= 1;
+            return;
+        }
+        if self
+            .entries
+            .get(self.position + 1)
+            .is_some_and(|next| same_annotations(next, annotations))
+        {
+            self.position += 1;
+            return;
+        }
+
+        self.entries.truncate(self.position + 1);
+        self.entries.push(annotations.to_vec());
+
+        let excess = self.entries.len().saturating_sub(MAX_HISTORY_ENTRIES);
+        self.entries.drain(..excess);
+        self.position = self.entries.len() - 1;
+    }
+
+    /// Step back to the previous set, if any.
+    pub fn undo(&mut self) -> Option<&[Annotation]> {
+        if self.position == 0 || self.entries.is_empty() {
+            return None;
+        }
+        self.position -= 1;
+        self.entries.get(self.position).map(Vec::as_slice)
+    }
+
+    /// Step forward to the next set, if any.
+    pub fn redo(&mut self) -> Option<&[Annotation]> {
+        if self.position + 1 >= self.entries.len() {
+            return None;
+        }
+        self.position += 1;
+        self.entries.get(self.position).map(Vec::as_slice)
+    }
+}
+
+/// Annotation has no PartialEq (its properties are free-form), so compare the
+/// sets as JSON.
+fn same_annotations(a: &[Annotation], b: &[Annotation]) -> bool {
+    a.len() == b.len() && serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
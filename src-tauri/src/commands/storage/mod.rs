@@ -6,13 +6,21 @@
 //! mod.rs (public API + shared helpers)
 //!   |
 //!   +-- types.rs (type definitions)
+//!   +-- bundle.rs (.snapitproj export/import)
+//!   +-- filename.rs (user filename templates)
+//!   +-- history.rs (persistent annotation undo/redo)
+//!   +-- redact.rs (irreversible blur/pixelate of image regions)
 //!   +-- ffmpeg.rs (FFmpeg utilities, thumbnail generation)
 //!   +-- operations.rs (Tauri command handlers)
 //!   +-- tests.rs (unit tests)
 //! ```
 
+pub mod bundle;
 pub mod ffmpeg;
+pub mod filename;
+pub mod history;
 pub mod operations;
+pub mod redact;
 #[cfg(test)]
 mod tests;
 pub mod types;
@@ -22,9 +30,10 @@ pub use ffmpeg::{find_ffmpeg, find_ffprobe};
 
 // Types are available via `storage::types::*` for external use
 
+use image::{metadata::Orientation, DynamicImage, ImageDecoder, ImageReader};
 use rand::Rng;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Manager};
 
@@ -32,6 +41,25 @@ use tauri::{AppHandle, Manager};
 // Shared Helper Functions
 // ============================================================================
 
+/// Whether the user opted into animated video thumbnails (`general.animatedThumbnails`).
+/// Off by default since generating them is much slower than a single frame.
+pub(crate) fn animated_thumbnails_enabled(app: &AppHandle) -> bool {
+    let Ok(app_data_dir) = get_app_data_dir(app) else {
+        return false;
+    };
+
+    fs::read_to_string(app_data_dir.join("settings.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|settings| {
+            settings
+                .get("general")?
+                .get("animatedThumbnails")?
+                .as_bool()
+        })
+        .unwrap_or(false)
+}
+
 /// Get the user's configured save directory from settings, falling back to Pictures/SnapIt
 pub(crate) fn get_captures_dir(app: &AppHandle) -> Result<PathBuf, String> {
     let app_data_dir = get_app_data_dir(app)?;
@@ -95,6 +123,18 @@ pub(crate) fn ensure_directories(app: &AppHandle) -> Result<PathBuf, String> {
     Ok(base_dir)
 }
 
+/// Path of a project's original image.
+///
+/// Handles both old format (filename only, under `captures`) and new format (full path).
+pub(crate) fn resolve_original_image(base_dir: &Path, original_image: &str) -> PathBuf {
+    let original_path = PathBuf::from(original_image);
+    if original_path.is_absolute() {
+        original_path
+    } else {
+        base_dir.join("captures").join(original_image)
+    }
+}
+
 /// Generate a unique ID for a capture.
 pub(crate) fn generate_id() -> String {
     let timestamp = SystemTime::now()
@@ -106,7 +146,7 @@ pub(crate) fn generate_id() -> String {
 }
 
 /// Calculate the total size of a directory recursively.
-pub(crate) fn calculate_dir_size(path: &PathBuf) -> u64 {
+pub(crate) fn calculate_dir_size(path: &Path) -> u64 {
     let mut size: u64 = 0;
     if let Ok(entries) = fs::read_dir(path) {
         for entry in entries.flatten() {
@@ -122,3 +162,25 @@ pub(crate) fn calculate_dir_size(path: &PathBuf) -> u64 {
     }
     size
 }
+
+/// Load an image from disk with its EXIF orientation applied.
+///
+/// Phone/camera photos store pixels in sensor order and rely on the EXIF
+/// orientation tag for display. The returned image is upright; since our PNG
+/// encoder writes no EXIF, saved copies carry no orientation tag and won't be
+/// rotated twice by downstream viewers.
+pub(crate) fn load_image_oriented(path: &Path) -> Result<DynamicImage, String> {
+    let mut decoder = ImageReader::open(path)
+        .and_then(|r| r.with_guessed_format())
+        .map_err(|e| format!("Failed to open image: {}", e))?
+        .into_decoder()
+        .map_err(|e| format!("Failed to load image: {}", e))?;
+
+    let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+
+    let mut image =
+        DynamicImage::from_decoder(decoder).map_err(|e| format!("Failed to load image: {}", e))?;
+    image.apply_orientation(orientation);
+
+    Ok(image)
+}
@@ -2,22 +2,123 @@
 
 use base64::{engine::general_purpose::STANDARD, Engine};
 use chrono::{DateTime, Utc};
-use image::{DynamicImage, GenericImageView};
+use image::{DynamicImage, GenericImageView, RgbaImage};
+use parking_lot::Mutex;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::io::Cursor;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tauri::{command, AppHandle, Emitter};
+use tauri_plugin_clipboard_manager::ClipboardExt;
 use tokio::fs as async_fs;
 
 use super::ffmpeg::{
-    find_ffmpeg, find_ffprobe, generate_gif_thumbnail, generate_thumbnail,
-    generate_video_thumbnail, get_video_metadata_for_migration,
+    find_ffmpeg, find_ffprobe, generate_animated_thumbnail, generate_gif_thumbnail,
+    generate_thumbnail, generate_video_thumbnail, get_video_metadata_for_migration,
 };
+use super::filename::{capture_source_name, templated_filename};
+use super::history::AnnotationHistory;
+use super::redact::apply_redactions as redact_image;
 use super::types::*;
 use super::{
-    calculate_dir_size, ensure_directories, generate_id, get_app_data_dir, get_captures_dir,
+    animated_thumbnails_enabled, calculate_dir_size, ensure_directories, generate_id,
+    get_app_data_dir, get_captures_dir, load_image_oriented, resolve_original_image,
 };
 
+// ============================================================================
+// Content Hash Deduplication
+// ============================================================================
+
+/// Content hash -> project ID, built from project.json files on first use.
+static CONTENT_HASH_INDEX: Mutex<Option<HashMap<String, String>>> = Mutex::new(None);
+
+/// SHA-256 of an image's decoded RGBA pixels and dimensions, as hex.
+pub(crate) fn content_hash(image: &DynamicImage) -> String {
+    let rgba = image.to_rgba8();
+    let mut hasher = Sha256::new();
+    hasher.update(rgba.width().to_le_bytes());
+    hasher.update(rgba.height().to_le_bytes());
+    hasher.update(rgba.as_raw());
+    format!("{:x}", hasher.finalize())
+}
+
+fn build_content_hash_index(base_dir: &Path) -> HashMap<String, String> {
+    let mut index = HashMap::new();
+
+    let Ok(entries) = fs::read_dir(base_dir.join("projects")) else {
+        return index;
+    };
+
+    for entry in entries.flatten() {
+        let Ok(content) = fs::read_to_string(entry.path().join("project.json")) else {
+            continue;
+        };
+        let Ok(project) = serde_json::from_str::<CaptureProject>(&content) else {
+            continue;
+        };
+        if let Some(hash) = project.content_hash {
+            index.entry(hash).or_insert(project.id);
+        }
+    }
+
+    log::debug!("[DEDUP] Indexed {} content hashes", index.len());
+    index
+}
+
+/// Record a newly saved project. A no-op until the index is first built, since
+/// building it picks the project up from disk.
+pub(crate) fn index_content_hash(hash: &str, project_id: &str) {
+    if let Some(index) = CONTENT_HASH_INDEX.lock().as_mut() {
+        index
+            .entry(hash.to_string())
+            .or_insert_with(|| project_id.to_string());
+    }
+}
+
+/// Find an existing project whose image has the given content hash.
+///
+/// Stale index entries (project deleted, or its image missing) are dropped so
+/// the caller saves a fresh copy.
+fn find_duplicate_capture(base_dir: &Path, hash: &str) -> Option<SaveCaptureResponse> {
+    let id = CONTENT_HASH_INDEX
+        .lock()
+        .get_or_insert_with(|| build_content_hash_index(base_dir))
+        .get(hash)
+        .cloned()?;
+
+    let project_file = base_dir.join("projects").join(&id).join("project.json");
+    let project = fs::read_to_string(&project_file)
+        .ok()
+        .and_then(|content| serde_json::from_str::<CaptureProject>(&content).ok());
+
+    let image_path = project
+        .as_ref()
+        .map(|project| resolve_original_image(base_dir, &project.original_image));
+
+    match (project, image_path) {
+        (Some(project), Some(image_path)) if image_path.exists() => {
+            log::info!("[DEDUP] Image matches existing project {}", id);
+            Some(SaveCaptureResponse {
+                id: project.id.clone(),
+                project,
+                thumbnail_path: base_dir
+                    .join("thumbnails")
+                    .join(format!("{}_thumb.png", id))
+                    .to_string_lossy()
+                    .to_string(),
+                image_path: image_path.to_string_lossy().to_string(),
+            })
+        },
+        _ => {
+            if let Some(index) = CONTENT_HASH_INDEX.lock().as_mut() {
+                index.remove(hash);
+            }
+            None
+        },
+    }
+}
+
 // ============================================================================
 // Save Operations
 // ============================================================================
@@ -39,10 +140,23 @@ pub async fn save_capture(
     let image =
         image::load_from_memory(&decoded).map_err(|e| format!("Failed to load image: {}", e))?;
 
+    let hash = content_hash(&image);
+    if !request.allow_duplicates.unwrap_or(false) {
+        if let Some(existing) = find_duplicate_capture(&base_dir, &hash) {
+            return Ok(existing);
+        }
+    }
+
     let (width, height) = image.dimensions();
 
     let date_str = now.format("%Y-%m-%d_%H%M%S").to_string();
-    let original_filename = format!("{}_{}.png", date_str, &id);
+    let original_filename = templated_filename(
+        &app,
+        &captures_dir,
+        &capture_source_name(&request.source),
+        Some("png"),
+    )
+    .unwrap_or_else(|| format!("{}_{}.png", date_str, &id));
     let thumbnail_filename = format!("{}_thumb.png", &id);
 
     // Save original image to user's configured directory
@@ -71,6 +185,8 @@ pub async fn save_capture(
         annotations: Vec::new(),
         tags: Vec::new(),
         favorite: false,
+        content_hash: Some(hash.clone()),
+        name: None,
     };
 
     // Save project file
@@ -83,6 +199,7 @@ pub async fn save_capture(
         .map_err(|e| format!("Failed to serialize project: {}", e))?;
     fs::write(&project_file, project_json)
         .map_err(|e| format!("Failed to write project file: {}", e))?;
+    index_content_hash(&hash, &id);
 
     Ok(SaveCaptureResponse {
         id,
@@ -127,9 +244,16 @@ pub async fn save_capture_from_file(
     let image: DynamicImage = image::RgbaImage::from_raw(width, height, rgba_data)
         .ok_or_else(|| "Failed to create image from RGBA data".to_string())?
         .into();
+    let hash = content_hash(&image);
 
     let date_str = now.format("%Y-%m-%d_%H%M%S").to_string();
-    let original_filename = format!("{}_{}.png", date_str, &id);
+    let original_filename = templated_filename(
+        &app,
+        &captures_dir,
+        &capture_source_name(&source),
+        Some("png"),
+    )
+    .unwrap_or_else(|| format!("{}_{}.png", date_str, &id));
     let thumbnail_filename = format!("{}_thumb.png", &id);
 
     // Save original image to user's configured directory
@@ -158,6 +282,8 @@ pub async fn save_capture_from_file(
         annotations: Vec::new(),
         tags: Vec::new(),
         favorite: false,
+        content_hash: Some(hash.clone()),
+        name: None,
     };
 
     // Save project file
@@ -170,6 +296,7 @@ pub async fn save_capture_from_file(
         .map_err(|e| format!("Failed to serialize project: {}", e))?;
     fs::write(&project_file, project_json)
         .map_err(|e| format!("Failed to write project file: {}", e))?;
+    index_content_hash(&hash, &id);
 
     Ok(SaveCaptureResponse {
         id,
@@ -184,6 +311,7 @@ pub async fn save_capture_from_file(
 pub async fn import_image_from_path(
     app: AppHandle,
     file_path: String,
+    allow_duplicates: Option<bool>,
 ) -> Result<SaveCaptureResponse, String> {
     let path = PathBuf::from(&file_path);
 
@@ -203,13 +331,50 @@ pub async fn import_image_from_path(
         return Err(format!("Unsupported image format: {}", extension));
     }
 
-    // Load image directly from file
-    let image = image::open(&path).map_err(|e| format!("Failed to load image: {}", e))?;
+    // Load image with EXIF orientation applied so phone photos import upright
+    let image = load_image_oriented(&path)?;
+
+    import_image(&app, &image, "import", allow_duplicates.unwrap_or(false))
+}
+
+/// Import the image on the clipboard (e.g. copied from a browser) as a capture.
+#[command]
+pub async fn import_image_from_clipboard(app: AppHandle) -> Result<SaveCaptureResponse, String> {
+    let clipboard_image = app
+        .clipboard()
+        .read_image()
+        .map_err(|_| "The clipboard doesn't contain an image".to_string())?;
+
+    let (width, height) = (clipboard_image.width(), clipboard_image.height());
+    let rgba = RgbaImage::from_raw(width, height, clipboard_image.rgba().to_vec())
+        .ok_or_else(|| "Failed to decode clipboard image".to_string())?;
+
+    import_image(&app, &DynamicImage::ImageRgba8(rgba), "clipboard", false)
+}
 
+/// Save an imported image as a new library capture of `capture_type`.
+///
+/// Unless `allow_duplicates`, an image already in the library opens the
+/// existing project instead.
+fn import_image(
+    app: &AppHandle,
+    image: &DynamicImage,
+    capture_type: &str,
+    allow_duplicates: bool,
+) -> Result<SaveCaptureResponse, String> {
     let (width, height) = image.dimensions();
 
-    let base_dir = ensure_directories(&app)?;
-    let captures_dir = get_captures_dir(&app)?;
+    let base_dir = ensure_directories(app)?;
+
+    // Importing the same image again opens the existing project
+    let hash = content_hash(image);
+    if !allow_duplicates {
+        if let Some(existing) = find_duplicate_capture(&base_dir, &hash) {
+            return Ok(existing);
+        }
+    }
+
+    let captures_dir = get_captures_dir(app)?;
     let id = generate_id();
     let now = Utc::now();
 
@@ -224,7 +389,7 @@ pub async fn import_image_from_path(
         .map_err(|e| format!("Failed to save image: {}", e))?;
 
     // Generate and save thumbnail
-    let thumbnail = generate_thumbnail(&image)?;
+    let thumbnail = generate_thumbnail(image)?;
     let thumbnails_dir = base_dir.join("thumbnails");
     let thumbnail_path = thumbnails_dir.join(&thumbnail_filename);
     thumbnail
@@ -236,7 +401,7 @@ pub async fn import_image_from_path(
         id: id.clone(),
         created_at: now,
         updated_at: now,
-        capture_type: "import".to_string(),
+        capture_type: capture_type.to_string(),
         source: CaptureSource {
             monitor: None,
             window_id: None,
@@ -248,6 +413,8 @@ pub async fn import_image_from_path(
         annotations: Vec::new(),
         tags: Vec::new(),
         favorite: false,
+        content_hash: Some(hash.clone()),
+        name: None,
     };
 
     // Save project file
@@ -260,6 +427,7 @@ pub async fn import_image_from_path(
         .map_err(|e| format!("Failed to serialize project: {}", e))?;
     fs::write(&project_file, project_json)
         .map_err(|e| format!("Failed to write project file: {}", e))?;
+    index_content_hash(&hash, &id);
 
     Ok(SaveCaptureResponse {
         id,
@@ -295,7 +463,7 @@ pub async fn update_project_annotations(
     let mut project: CaptureProject =
         serde_json::from_str(&content).map_err(|e| format!("Failed to parse project: {}", e))?;
 
-    project.annotations = annotations;
+    let previous = std::mem::replace(&mut project.annotations, annotations);
     project.updated_at = Utc::now();
 
     let project_json = serde_json::to_string_pretty(&project)
@@ -304,9 +472,181 @@ pub async fn update_project_annotations(
     fs::write(&project_file, project_json)
         .map_err(|e| format!("Failed to write project: {}", e))?;
 
+    // History is best-effort: a failure must not lose the saved annotations
+    if let Some(project_dir) = project_file.parent() {
+        let recorded = AnnotationHistory::load(project_dir).and_then(|mut history| {
+            history.record(&previous, &project.annotations);
+            history.save(project_dir)
+        });
+        if let Err(e) = recorded {
+            log::warn!(
+                "[HISTORY] Failed to record annotations of {}: {}",
+                project_id,
+                e
+            );
+        }
+    }
+
     Ok(project)
 }
 
+/// Step back to the previously saved annotation set of a project.
+///
+/// Writes the set to the project and returns it, or None when there is
+/// nothing to undo. The history persists, so this works after reopening.
+#[command]
+pub async fn undo_annotations(
+    app: AppHandle,
+    project_id: String,
+) -> Result<Option<Vec<Annotation>>, String> {
+    step_annotation_history(&app, &project_id, AnnotationHistory::undo)
+}
+
+/// Step forward to the next saved annotation set of a project.
+///
+/// Writes the set to the project and returns it, or None when there is
+/// nothing to redo.
+#[command]
+pub async fn redo_annotations(
+    app: AppHandle,
+    project_id: String,
+) -> Result<Option<Vec<Annotation>>, String> {
+    step_annotation_history(&app, &project_id, AnnotationHistory::redo)
+}
+
+/// Move the annotation history pointer of a project with `step` and write the
+/// annotation set it lands on to project.json.
+fn step_annotation_history(
+    app: &AppHandle,
+    project_id: &str,
+    step: fn(&mut AnnotationHistory) -> Option<&[Annotation]>,
+) -> Result<Option<Vec<Annotation>>, String> {
+    let project_dir = get_app_data_dir(app)?.join("projects").join(project_id);
+    let project_file = project_dir.join("project.json");
+
+    if !project_file.exists() {
+        return Err("Project not found".to_string());
+    }
+
+    let mut history = AnnotationHistory::load(&project_dir)?;
+    let Some(annotations) = step(&mut history).map(<[Annotation]>::to_vec) else {
+        return Ok(None);
+    };
+
+    let content =
+        fs::read_to_string(&project_file).map_err(|e| format!("Failed to read project: {}", e))?;
+    let mut project: CaptureProject =
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse project: {}", e))?;
+
+    project.annotations = annotations.clone();
+    project.updated_at = Utc::now();
+
+    let project_json = serde_json::to_string_pretty(&project)
+        .map_err(|e| format!("Failed to serialize project: {}", e))?;
+    fs::write(&project_file, project_json)
+        .map_err(|e| format!("Failed to write project: {}", e))?;
+    history.save(&project_dir)?;
+
+    Ok(Some(annotations))
+}
+
+/// Save a copy of a capture with `regions` irreversibly blurred or pixelated.
+///
+/// The redaction is applied to the image pixels (not drawn over them like the
+/// editor's blur annotations), so the copy can be shared without the hidden
+/// content being recoverable. The copy is a new project in the library with
+/// the original's annotations; the original is left untouched.
+#[command]
+pub async fn apply_redactions(
+    app: AppHandle,
+    project_id: String,
+    regions: Vec<RedactRegion>,
+    mode: RedactMode,
+) -> Result<SaveCaptureResponse, String> {
+    let base_dir = ensure_directories(&app)?;
+    let project_file = base_dir
+        .join("projects")
+        .join(&project_id)
+        .join("project.json");
+
+    if !project_file.exists() {
+        return Err("Project not found".to_string());
+    }
+
+    let content =
+        fs::read_to_string(&project_file).map_err(|e| format!("Failed to read project: {}", e))?;
+    let original: CaptureProject =
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse project: {}", e))?;
+
+    let mut pixels =
+        load_image_oriented(&resolve_original_image(&base_dir, &original.original_image))?
+            .to_rgba8();
+    redact_image(&mut pixels, &regions, mode);
+    let image = DynamicImage::from(pixels);
+    let (width, height) = image.dimensions();
+    let hash = content_hash(&image);
+
+    let captures_dir = get_captures_dir(&app)?;
+    let id = generate_id();
+    let now = Utc::now();
+
+    let date_str = now.format("%Y-%m-%d_%H%M%S").to_string();
+    let original_filename = format!("{}_{}_redacted.png", date_str, &id);
+    let thumbnail_filename = format!("{}_thumb.png", &id);
+
+    // Save the redacted image to user's configured directory
+    let original_path = captures_dir.join(&original_filename);
+    image
+        .save(&original_path)
+        .map_err(|e| format!("Failed to save image: {}", e))?;
+
+    // Generate and save thumbnail
+    let thumbnail = generate_thumbnail(&image)?;
+    let thumbnail_path = base_dir.join("thumbnails").join(&thumbnail_filename);
+    thumbnail
+        .save(&thumbnail_path)
+        .map_err(|e| format!("Failed to save thumbnail: {}", e))?;
+
+    let project = CaptureProject {
+        id: id.clone(),
+        created_at: now,
+        updated_at: now,
+        original_image: original_path.to_string_lossy().to_string(),
+        dimensions: Dimensions { width, height },
+        favorite: false,
+        content_hash: Some(hash.clone()),
+        name: original
+            .name
+            .as_ref()
+            .map(|name| format!("{} (redacted)", name)),
+        ..original
+    };
+
+    // Save project file
+    let project_dir = base_dir.join("projects").join(&id);
+    fs::create_dir_all(&project_dir).map_err(|e| format!("Failed to create project dir: {}", e))?;
+
+    let project_json = serde_json::to_string_pretty(&project)
+        .map_err(|e| format!("Failed to serialize project: {}", e))?;
+    fs::write(project_dir.join("project.json"), project_json)
+        .map_err(|e| format!("Failed to write project file: {}", e))?;
+    index_content_hash(&hash, &id);
+
+    log::info!(
+        "[REDACT] Redacted {} region(s) of {} into {}",
+        regions.len(),
+        project_id,
+        id
+    );
+
+    Ok(SaveCaptureResponse {
+        id,
+        project,
+        thumbnail_path: thumbnail_path.to_string_lossy().to_string(),
+        image_path: original_path.to_string_lossy().to_string(),
+    })
+}
+
 #[command]
 pub async fn update_project_metadata(
     app: AppHandle,
@@ -346,34 +686,163 @@ pub async fn update_project_metadata(
     Ok(project)
 }
 
+/// Rename a capture. An empty or missing name clears it.
+///
+/// Screenshot projects store the name in their project.json. Video projects
+/// update the `name` field of their own project.json, falling back to the
+/// folder name when cleared since video projects always have a name.
+#[command]
+pub async fn update_project_name(
+    app: AppHandle,
+    project_id: String,
+    name: Option<String>,
+) -> Result<(), String> {
+    let name = name.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+
+    let (capture_type, file_path) = determine_capture_type(&app, &project_id)?;
+
+    match capture_type.as_str() {
+        "project" => {
+            let base_dir = get_app_data_dir(&app)?;
+            let project_file = base_dir
+                .join("projects")
+                .join(&project_id)
+                .join("project.json");
+
+            let content = fs::read_to_string(&project_file)
+                .map_err(|e| format!("Failed to read project: {}", e))?;
+            let mut project: CaptureProject = serde_json::from_str(&content)
+                .map_err(|e| format!("Failed to parse project: {}", e))?;
+
+            project.name = name;
+            project.updated_at = Utc::now();
+
+            let project_json = serde_json::to_string_pretty(&project)
+                .map_err(|e| format!("Failed to serialize project: {}", e))?;
+            fs::write(&project_file, project_json)
+                .map_err(|e| format!("Failed to write project: {}", e))?;
+        },
+        "video_folder" => {
+            let project_file = file_path
+                .ok_or_else(|| "Project not found".to_string())?
+                .join("project.json");
+
+            // Edit as JSON so fields this module doesn't know about survive
+            let content = fs::read_to_string(&project_file)
+                .map_err(|e| format!("Failed to read video project: {}", e))?;
+            let mut project: serde_json::Value = serde_json::from_str(&content)
+                .map_err(|e| format!("Failed to parse video project: {}", e))?;
+            let object = project
+                .as_object_mut()
+                .ok_or_else(|| "Invalid video project file".to_string())?;
+
+            object.insert(
+                "name".to_string(),
+                serde_json::Value::String(name.unwrap_or_else(|| project_id.clone())),
+            );
+            object.insert(
+                "updatedAt".to_string(),
+                serde_json::Value::String(Utc::now().to_rfc3339()),
+            );
+
+            let project_json = serde_json::to_string_pretty(&project)
+                .map_err(|e| format!("Failed to serialize video project: {}", e))?;
+            fs::write(&project_file, project_json)
+                .map_err(|e| format!("Failed to write video project: {}", e))?;
+        },
+        "unknown" => return Err("Project not found".to_string()),
+        _ => return Err(format!("Cannot rename {} captures", capture_type)),
+    }
+
+    Ok(())
+}
+
 // ============================================================================
 // Query Operations
 // ============================================================================
 
+/// Library search filter, applied while loading so non-matching items skip
+/// thumbnail and metadata work.
+#[derive(Debug, Default)]
+pub(crate) struct CaptureFilter {
+    /// Lowercased search text, matched against name, window title, tags and type.
+    query: Option<String>,
+    /// Items must have at least one of these tags.
+    tags: HashSet<String>,
+    /// Exact capture type (region, window, video, gif, ...).
+    capture_type: Option<String>,
+}
+
+impl CaptureFilter {
+    pub(crate) fn new(query: &str, tags: Vec<String>, capture_type: Option<String>) -> Self {
+        let query = query.trim().to_lowercase();
+        Self {
+            query: (!query.is_empty()).then_some(query),
+            tags: tags.into_iter().collect(),
+            capture_type: capture_type.filter(|t| !t.is_empty()),
+        }
+    }
+
+    pub(crate) fn matches(
+        &self,
+        capture_type: &str,
+        name: Option<&str>,
+        window_title: Option<&str>,
+        tags: &[String],
+    ) -> bool {
+        if self
+            .capture_type
+            .as_ref()
+            .is_some_and(|t| !t.eq_ignore_ascii_case(capture_type))
+        {
+            return false;
+        }
+
+        if !self.tags.is_empty() && !tags.iter().any(|tag| self.tags.contains(tag)) {
+            return false;
+        }
+
+        let Some(query) = &self.query else {
+            return true;
+        };
+        let contains = |text: &str| text.to_lowercase().contains(query.as_str());
+
+        contains(capture_type)
+            || name.is_some_and(contains)
+            || window_title.is_some_and(contains)
+            || tags.iter().any(|tag| contains(tag))
+    }
+}
+
 /// Process a single project directory into a CaptureListItem.
 /// Returns None if the project can't be loaded.
 async fn load_project_item(
     project_dir: PathBuf,
     base_dir: PathBuf,
     thumbnails_dir: PathBuf,
+    filter: Option<&CaptureFilter>,
 ) -> Option<CaptureListItem> {
     let project_file = project_dir.join("project.json");
     let content = async_fs::read_to_string(&project_file).await.ok()?;
     let project: CaptureProject = serde_json::from_str(&content).ok()?;
 
+    if filter.is_some_and(|f| {
+        !f.matches(
+            &project.capture_type,
+            project.name.as_deref(),
+            project.source.window_title.as_deref(),
+            &project.tags,
+        )
+    }) {
+        return None;
+    }
+
     let thumbnail_path = thumbnails_dir
         .join(format!("{}_thumb.png", &project.id))
         .to_string_lossy()
         .to_string();
 
-    // Handle both old format (filename only) and new format (full path)
-    let original_path = PathBuf::from(&project.original_image);
-    let image_path_buf = if original_path.is_absolute() {
-        original_path
-    } else {
-        // Legacy: construct path from app data dir
-        base_dir.join("captures").join(&project.original_image)
-    };
+    let image_path_buf = resolve_original_image(&base_dir, &project.original_image);
     let image_path = image_path_buf.to_string_lossy().to_string();
 
     // Check if the original image file exists
@@ -391,9 +860,53 @@ async fn load_project_item(
         tags: project.tags,
         favorite: project.favorite,
         is_missing,
+        duration_ms: None,
+        name: project.name,
     })
 }
 
+/// Sidecar file caching ffprobe results inside a video project folder.
+const MEDIA_META_FILE: &str = "meta.json";
+
+/// Read cached media metadata from `meta_path`.
+///
+/// On a cache miss the media is probed with ffprobe in the background and the
+/// result written for the next list load, so listing never waits on ffprobe.
+async fn cached_media_meta(media_path: &Path, meta_path: PathBuf) -> Option<MediaMeta> {
+    if let Ok(content) = async_fs::read_to_string(&meta_path).await {
+        if let Ok(meta) = serde_json::from_str::<MediaMeta>(&content) {
+            return Some(meta);
+        }
+    }
+
+    let media_path = media_path.to_path_buf();
+    std::thread::spawn(move || {
+        let Some(ffprobe_path) = find_ffprobe() else {
+            return;
+        };
+        match get_video_metadata_for_migration(&ffprobe_path, &media_path) {
+            Ok((width, height, duration_ms, _fps)) => {
+                let meta = MediaMeta {
+                    width,
+                    height,
+                    duration_ms,
+                };
+                match serde_json::to_string_pretty(&meta) {
+                    Ok(json) => {
+                        if let Err(e) = fs::write(&meta_path, json) {
+                            log::warn!("[META] Failed to write {:?}: {}", meta_path, e);
+                        }
+                    },
+                    Err(e) => log::warn!("[META] Failed to serialize metadata: {}", e),
+                }
+            },
+            Err(e) => log::warn!("[META] Failed to probe {:?}: {}", media_path, e),
+        }
+    });
+
+    None
+}
+
 /// Process a video project folder into a CaptureListItem.
 ///
 /// Video project folders contain:
@@ -407,6 +920,7 @@ async fn load_video_project_folder(
     folder_path: PathBuf,
     thumbnails_dir: PathBuf,
     app: AppHandle,
+    filter: Option<&CaptureFilter>,
 ) -> Option<CaptureListItem> {
     // Check if this is a video project folder
     let project_json = folder_path.join("project.json");
@@ -425,7 +939,7 @@ async fn load_video_project_folder(
         .to_string();
 
     // Try to read metadata from project.json, fall back to file metadata
-    let (created_at, updated_at, dimensions) =
+    let (created_at, updated_at, dimensions, name) =
         if async_fs::try_exists(&project_json).await.unwrap_or(false) {
             if let Ok(content) = async_fs::read_to_string(&project_json).await {
                 if let Ok(project) = serde_json::from_str::<serde_json::Value>(&content) {
@@ -455,7 +969,11 @@ async fn load_video_project_folder(
                             width: 0,
                             height: 0,
                         });
-                    (created, updated, dims)
+                    let name = project
+                        .get("name")
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string);
+                    (created, updated, dims, name)
                 } else {
                     (
                         Utc::now(),
@@ -464,6 +982,7 @@ async fn load_video_project_folder(
                             width: 0,
                             height: 0,
                         },
+                        None,
                     )
                 }
             } else {
@@ -474,6 +993,7 @@ async fn load_video_project_folder(
                         width: 0,
                         height: 0,
                     },
+                    None,
                 )
             }
         } else {
@@ -495,29 +1015,56 @@ async fn load_video_project_folder(
                     width: 0,
                     height: 0,
                 },
+                None,
             )
         };
 
-    // Check/generate thumbnail
-    let thumbnail_filename = format!("{}_thumb.png", &id);
-    let thumbnail_path = thumbnails_dir.join(&thumbnail_filename);
+    if filter.is_some_and(|f| !f.matches("video", name.as_deref(), None, &[])) {
+        return None;
+    }
+
+    // Check/generate thumbnail. Animated WebP thumbnails are opt-in; until one
+    // exists the static PNG (if any) is shown.
+    let animated = animated_thumbnails_enabled(&app);
+    let thumbnail_path = thumbnails_dir.join(format!("{}_thumb.png", &id));
+    let animated_path = thumbnails_dir.join(format!("{}_thumb.webp", &id));
     let thumb_exists = async_fs::try_exists(&thumbnail_path).await.unwrap_or(false);
+    let animated_exists = animated && async_fs::try_exists(&animated_path).await.unwrap_or(false);
 
-    if !thumb_exists {
+    if (animated && !animated_exists) || (!animated && !thumb_exists) {
         let video_path = screen_mp4.clone();
         let thumb_path = thumbnail_path.clone();
+        let animated_thumb_path = animated_path.clone();
         let capture_id = id.clone();
         let app_clone = app.clone();
         std::thread::spawn(move || {
-            match generate_video_thumbnail(&video_path, &thumb_path) {
-                Ok(()) => {
-                    log::debug!("[THUMB] Video project OK: {:?}", thumb_path);
+            let result = if animated {
+                generate_animated_thumbnail(&video_path, &animated_thumb_path)
+                    .map(|()| animated_thumb_path)
+                    .or_else(|e| {
+                        log::warn!(
+                            "[THUMB] Animated video thumbnail FAILED, using static: {}",
+                            e
+                        );
+                        if thumb_exists {
+                            Err(e)
+                        } else {
+                            generate_video_thumbnail(&video_path, &thumb_path).map(|()| thumb_path)
+                        }
+                    })
+            } else {
+                generate_video_thumbnail(&video_path, &thumb_path).map(|()| thumb_path)
+            };
+
+            match result {
+                Ok(path) => {
+                    log::debug!("[THUMB] Video project OK: {:?}", path);
                     // Emit event to notify frontend
                     let _ = app_clone.emit(
                         "thumbnail-ready",
                         ThumbnailReadyEvent {
                             capture_id,
-                            thumbnail_path: thumb_path.to_string_lossy().to_string(),
+                            thumbnail_path: path.to_string_lossy().to_string(),
                         },
                     );
                 },
@@ -526,12 +1073,24 @@ async fn load_video_project_folder(
         });
     }
 
-    let thumbnail_path_str = if thumb_exists {
+    let thumbnail_path_str = if animated_exists {
+        animated_path.to_string_lossy().to_string()
+    } else if thumb_exists {
         thumbnail_path.to_string_lossy().to_string()
     } else {
         String::new()
     };
 
+    // Prefer probed values; project.json dimensions may be missing on old recordings
+    let meta = cached_media_meta(&screen_mp4, folder_path.join(MEDIA_META_FILE)).await;
+    let dimensions = match meta {
+        Some(meta) if dimensions.width == 0 => Dimensions {
+            width: meta.width,
+            height: meta.height,
+        },
+        _ => dimensions,
+    };
+
     Some(CaptureListItem {
         id,
         created_at,
@@ -545,6 +1104,8 @@ async fn load_video_project_folder(
         tags: Vec::new(),
         favorite: false,
         is_missing: false,
+        duration_ms: meta.map(|meta| meta.duration_ms),
+        name,
     })
 }
 
@@ -557,6 +1118,7 @@ async fn load_media_item(
     path: PathBuf,
     thumbnails_dir: PathBuf,
     app: AppHandle,
+    filter: Option<&CaptureFilter>,
 ) -> Option<CaptureListItem> {
     let metadata = async_fs::metadata(&path).await.ok()?;
     if !metadata.is_file() {
@@ -606,6 +1168,10 @@ async fn load_media_item(
 
     let capture_type = if extension == "gif" { "gif" } else { "video" };
 
+    if filter.is_some_and(|f| !f.matches(capture_type, None, None, &[])) {
+        return None;
+    }
+
     // Check thumbnail
     let thumbnail_filename = format!("{}_thumb.png", &id);
     let thumbnail_path = thumbnails_dir.join(&thumbnail_filename);
@@ -655,10 +1221,12 @@ async fn load_media_item(
         String::new()
     };
 
-    // Skip video dimension fetching on startup for faster load
+    // Flat media files have no project folder, so their metadata is cached next
+    // to the thumbnail. Zero dimensions until the first probe finishes.
+    let meta = cached_media_meta(&path, thumbnails_dir.join(format!("{}_meta.json", &id))).await;
     let dimensions = Dimensions {
-        width: 0,
-        height: 0,
+        width: meta.map_or(0, |meta| meta.width),
+        height: meta.map_or(0, |meta| meta.height),
     };
 
     Some(CaptureListItem {
@@ -673,17 +1241,43 @@ async fn load_media_item(
         tags: Vec::new(),
         favorite: false,
         is_missing: false,
+        duration_ms: meta.map(|meta| meta.duration_ms),
+        name: None,
     })
 }
 
 #[command]
 pub async fn get_capture_list(app: AppHandle) -> Result<Vec<CaptureListItem>, String> {
+    load_captures(&app, None).await
+}
+
+/// Search the library on the backend so only matches cross IPC.
+///
+/// `query` matches (case-insensitively) against name, window title, tags and
+/// capture type; `tags` keeps items with any of the given tags; `capture_type`
+/// keeps only that type. Empty filters match everything.
+#[command]
+pub async fn search_captures(
+    app: AppHandle,
+    query: String,
+    tags: Vec<String>,
+    capture_type: Option<String>,
+) -> Result<Vec<CaptureListItem>, String> {
+    let filter = CaptureFilter::new(&query, tags, capture_type);
+    load_captures(&app, Some(&filter)).await
+}
+
+/// Load library items, newest first, keeping only those matching `filter`.
+async fn load_captures(
+    app: &AppHandle,
+    filter: Option<&CaptureFilter>,
+) -> Result<Vec<CaptureListItem>, String> {
     use futures::future::join_all;
 
-    let base_dir = get_app_data_dir(&app)?;
+    let base_dir = get_app_data_dir(app)?;
     let projects_dir = base_dir.join("projects");
     let thumbnails_dir = base_dir.join("thumbnails");
-    let captures_dir = get_captures_dir(&app)?;
+    let captures_dir = get_captures_dir(app)?;
 
     let mut captures: Vec<CaptureListItem> = Vec::new();
 
@@ -712,7 +1306,7 @@ pub async fn get_capture_list(app: AppHandle) -> Result<Vec<CaptureListItem>, St
             .map(|dir| {
                 let base = base_dir.clone();
                 let thumbs = thumbnails_dir.clone();
-                load_project_item(dir, base, thumbs)
+                load_project_item(dir, base, thumbs, filter)
             })
             .collect();
 
@@ -746,7 +1340,7 @@ pub async fn get_capture_list(app: AppHandle) -> Result<Vec<CaptureListItem>, St
             .map(|path| {
                 let thumbs = thumbnails_dir.clone();
                 let app_clone = app.clone();
-                load_video_project_folder(path, thumbs, app_clone)
+                load_video_project_folder(path, thumbs, app_clone, filter)
             })
             .collect();
 
@@ -756,7 +1350,7 @@ pub async fn get_capture_list(app: AppHandle) -> Result<Vec<CaptureListItem>, St
             .map(|path| {
                 let thumbs = thumbnails_dir.clone();
                 let app_clone = app.clone();
-                load_media_item(path, thumbs, app_clone)
+                load_media_item(path, thumbs, app_clone, filter)
             })
             .collect();
 
@@ -811,13 +1405,7 @@ pub async fn get_project_image(app: AppHandle, project_id: String) -> Result<Str
     let project: CaptureProject =
         serde_json::from_str(&content).map_err(|e| format!("Failed to parse project: {}", e))?;
 
-    // Handle both old format (filename only) and new format (full path)
-    let original_path = PathBuf::from(&project.original_image);
-    let image_path = if original_path.is_absolute() {
-        original_path
-    } else {
-        base_dir.join("captures").join(&project.original_image)
-    };
+    let image_path = resolve_original_image(&base_dir, &project.original_image);
 
     let image_data = fs::read(&image_path).map_err(|e| format!("Failed to read image: {}", e))?;
 
@@ -841,7 +1429,7 @@ pub fn get_library_folder(app: AppHandle) -> Result<String, String> {
 ///   - "video": Legacy flat MP4 file (in captures_dir/)
 ///   - "gif": GIF file (in captures_dir/)
 ///   - "unknown": Not found
-fn determine_capture_type(
+pub(crate) fn determine_capture_type(
     app: &AppHandle,
     project_id: &str,
 ) -> Result<(String, Option<PathBuf>), String> {
@@ -855,12 +1443,7 @@ fn determine_capture_type(
         // It's a screenshot project - get the image path from project.json
         if let Ok(content) = fs::read_to_string(&project_file) {
             if let Ok(project) = serde_json::from_str::<CaptureProject>(&content) {
-                let original_path = PathBuf::from(&project.original_image);
-                let image_path = if original_path.is_absolute() {
-                    original_path
-                } else {
-                    base_dir.join("captures").join(&project.original_image)
-                };
+                let image_path = resolve_original_image(&base_dir, &project.original_image);
                 return Ok(("project".to_string(), Some(image_path)));
             }
         }
@@ -890,8 +1473,41 @@ fn determine_capture_type(
     Ok(("unknown".to_string(), None))
 }
 
+/// Move a file or folder to the OS trash, or delete it outright when `permanent`.
+///
+/// Falls back to permanent deletion if the trash is unavailable (e.g. network
+/// drives without a recycle bin).
+fn remove_path(path: &Path, permanent: bool) -> std::io::Result<()> {
+    let metadata = fs::symlink_metadata(path)?;
+
+    if !permanent {
+        match trash::delete(path) {
+            Ok(()) => return Ok(()),
+            Err(e) => log::warn!(
+                "[DELETE] Failed to move {:?} to trash, deleting permanently: {}",
+                path,
+                e
+            ),
+        }
+    }
+
+    if metadata.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+/// Delete a capture and its thumbnail.
+///
+/// Files go to the OS trash so a misclick can be undone, unless `permanent`.
 #[command]
-pub async fn delete_project(app: AppHandle, project_id: String) -> Result<(), String> {
+pub async fn delete_project(
+    app: AppHandle,
+    project_id: String,
+    permanent: Option<bool>,
+) -> Result<(), String> {
+    let permanent = permanent.unwrap_or(false);
     let base_dir = get_app_data_dir(&app)?;
     let captures_dir = get_captures_dir(&app)?;
 
@@ -902,12 +1518,12 @@ pub async fn delete_project(app: AppHandle, project_id: String) -> Result<(), St
         "project" => {
             // Screenshot project - delete original image, project dir, and thumbnail
             if let Some(image_path) = file_path {
-                let _ = fs::remove_file(image_path);
+                let _ = remove_path(&image_path, permanent);
             }
 
             let project_dir = base_dir.join("projects").join(&project_id);
             if project_dir.exists() {
-                fs::remove_dir_all(&project_dir)
+                remove_path(&project_dir, permanent)
                     .map_err(|e| format!("Failed to delete project: {}", e))?;
             }
         },
@@ -916,7 +1532,7 @@ pub async fn delete_project(app: AppHandle, project_id: String) -> Result<(), St
             // This removes screen.mp4, webcam.mp4, cursor.json, project.json, etc.
             if let Some(folder_path) = file_path {
                 if folder_path.exists() {
-                    fs::remove_dir_all(&folder_path)
+                    remove_path(&folder_path, permanent)
                         .map_err(|e| format!("Failed to delete video project folder: {}", e))?;
                     log::info!("[DELETE] Removed video project folder: {:?}", folder_path);
                 }
@@ -925,7 +1541,7 @@ pub async fn delete_project(app: AppHandle, project_id: String) -> Result<(), St
         "video" => {
             // Legacy flat MP4 file - delete main file and any associated files
             if let Some(video_path) = file_path {
-                fs::remove_file(&video_path)
+                remove_path(&video_path, permanent)
                     .map_err(|e| format!("Failed to delete video file: {}", e))?;
 
                 // Also try to delete associated legacy files (_webcam.mp4, _cursor.json, etc.)
@@ -936,16 +1552,16 @@ pub async fn delete_project(app: AppHandle, project_id: String) -> Result<(), St
                 let parent = video_path.parent().unwrap_or(&captures_dir);
 
                 // Try to delete associated files (don't error if they don't exist)
-                let _ = fs::remove_file(parent.join(format!("{}_webcam.mp4", stem)));
-                let _ = fs::remove_file(parent.join(format!("{}_cursor.json", stem)));
-                let _ = fs::remove_file(parent.join(format!("{}_system.wav", stem)));
-                let _ = fs::remove_file(parent.join(format!("{}_mic.wav", stem)));
+                let _ = remove_path(&parent.join(format!("{}_webcam.mp4", stem)), permanent);
+                let _ = remove_path(&parent.join(format!("{}_cursor.json", stem)), permanent);
+                let _ = remove_path(&parent.join(format!("{}_system.wav", stem)), permanent);
+                let _ = remove_path(&parent.join(format!("{}_mic.wav", stem)), permanent);
             }
         },
         "gif" => {
             // GIF file - just delete the file
             if let Some(gif_path) = file_path {
-                fs::remove_file(&gif_path)
+                remove_path(&gif_path, permanent)
                     .map_err(|e| format!("Failed to delete GIF file: {}", e))?;
             }
         },
@@ -959,7 +1575,15 @@ pub async fn delete_project(app: AppHandle, project_id: String) -> Result<(), St
     let thumbnail_path = base_dir
         .join("thumbnails")
         .join(format!("{}_thumb.png", &project_id));
-    let _ = fs::remove_file(thumbnail_path);
+    let _ = remove_path(&thumbnail_path, permanent);
+    let animated_thumbnail_path = base_dir
+        .join("thumbnails")
+        .join(format!("{}_thumb.webp", &project_id));
+    let _ = remove_path(&animated_thumbnail_path, permanent);
+    let meta_path = base_dir
+        .join("thumbnails")
+        .join(format!("{}_meta.json", &project_id));
+    let _ = remove_path(&meta_path, permanent);
 
     // Clean up any empty directories in captures folder
     cleanup_empty_directories(&captures_dir);
@@ -990,9 +1614,13 @@ fn cleanup_empty_directories(dir: &std::path::Path) {
 }
 
 #[command]
-pub async fn delete_projects(app: AppHandle, project_ids: Vec<String>) -> Result<(), String> {
+pub async fn delete_projects(
+    app: AppHandle,
+    project_ids: Vec<String>,
+    permanent: Option<bool>,
+) -> Result<(), String> {
     for id in project_ids {
-        delete_project(app.clone(), id).await?;
+        delete_project(app.clone(), id, permanent).await?;
     }
 
     // Final cleanup pass after bulk deletion
@@ -1007,6 +1635,114 @@ pub async fn delete_projects(app: AppHandle, project_ids: Vec<String>) -> Result
 // Export Operations
 // ============================================================================
 
+/// Quality used for lossy export formats when the caller doesn't pass one.
+const DEFAULT_EXPORT_QUALITY: u8 = 90;
+
+/// AVIF encoder speed (1 = slowest/smallest, 10 = fastest).
+const AVIF_EXPORT_SPEED: u8 = 6;
+
+/// Encode an image for export.
+///
+/// `quality` (1-100) applies to the lossy formats (JPEG, WebP, AVIF); PNG is
+/// always lossless. Unknown formats fall back to PNG.
+pub(crate) fn encode_export_image(
+    image: &DynamicImage,
+    format: &str,
+    quality: u8,
+) -> Result<Vec<u8>, String> {
+    let quality = quality.clamp(1, 100);
+    let mut buffer = Cursor::new(Vec::new());
+
+    match format.to_lowercase().as_str() {
+        "jpg" | "jpeg" => {
+            // JPEG has no alpha channel
+            let rgb = DynamicImage::ImageRgb8(image.to_rgb8());
+            rgb.write_with_encoder(image::codecs::jpeg::JpegEncoder::new_with_quality(
+                &mut buffer,
+                quality,
+            ))
+            .map_err(|e| format!("Failed to encode JPEG: {}", e))?;
+        },
+        "webp" => {
+            // image's WebP encoder is lossless-only
+            let rgba = image.to_rgba8();
+            let encoded =
+                webp::Encoder::from_rgba(&rgba, rgba.width(), rgba.height()).encode(quality as f32);
+            return Ok(encoded.to_vec());
+        },
+        "avif" => {
+            image
+                .write_with_encoder(image::codecs::avif::AvifEncoder::new_with_speed_quality(
+                    &mut buffer,
+                    AVIF_EXPORT_SPEED,
+                    quality,
+                ))
+                .map_err(|e| format!("Failed to encode AVIF: {}", e))?;
+        },
+        _ => {
+            image
+                .write_to(&mut buffer, image::ImageFormat::Png)
+                .map_err(|e| format!("Failed to encode PNG: {}", e))?;
+        },
+    }
+
+    Ok(buffer.into_inner())
+}
+
+/// PNG text chunks recording where a capture came from.
+pub(crate) fn capture_provenance(project: &CaptureProject) -> Vec<(&'static str, String)> {
+    let mut text = vec![
+        ("Software", "SnapIt".to_string()),
+        ("Creation Time", project.created_at.to_rfc3339()),
+        ("Capture Type", project.capture_type.clone()),
+    ];
+    if let Some(title) = &project.source.window_title {
+        text.push(("Source Window", title.clone()));
+    }
+    text
+}
+
+/// Encode an image as PNG with text chunks.
+///
+/// ASCII values go into tEXt chunks; anything else (e.g. non-Latin window
+/// titles) into UTF-8 iTXt chunks.
+pub(crate) fn encode_png_with_text(
+    image: &DynamicImage,
+    text: &[(&str, String)],
+) -> Result<Vec<u8>, String> {
+    let rgba = image.to_rgba8();
+    let mut buffer = Vec::new();
+
+    let mut encoder = png::Encoder::new(&mut buffer, rgba.width(), rgba.height());
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    for (keyword, value) in text {
+        let result = if value.is_ascii() {
+            encoder.add_text_chunk(keyword.to_string(), value.clone())
+        } else {
+            encoder.add_itxt_chunk(keyword.to_string(), value.clone())
+        };
+        result.map_err(|e| format!("Failed to add PNG text chunk: {}", e))?;
+    }
+
+    let mut writer = encoder
+        .write_header()
+        .map_err(|e| format!("Failed to encode PNG: {}", e))?;
+    writer
+        .write_image_data(&rgba)
+        .map_err(|e| format!("Failed to encode PNG: {}", e))?;
+    writer
+        .finish()
+        .map_err(|e| format!("Failed to encode PNG: {}", e))?;
+
+    Ok(buffer)
+}
+
+/// Export a rendered image to `file_path`.
+///
+/// `format` is png, jpg/jpeg, webp or avif. `quality` (1-100) applies to the
+/// lossy formats and defaults to 90. With `embed_metadata`, PNG exports carry
+/// the capture's creation time, type and source window title as text chunks.
 #[command]
 pub async fn export_project(
     app: AppHandle,
@@ -1014,6 +1750,8 @@ pub async fn export_project(
     rendered_image_data: String,
     file_path: String,
     format: String,
+    quality: Option<u8>,
+    embed_metadata: Option<bool>,
 ) -> Result<(), String> {
     let decoded = STANDARD
         .decode(&rendered_image_data)
@@ -1022,19 +1760,25 @@ pub async fn export_project(
     let image =
         image::load_from_memory(&decoded).map_err(|e| format!("Failed to load image: {}", e))?;
 
-    let img_format = match format.to_lowercase().as_str() {
-        "png" => image::ImageFormat::Png,
-        "jpg" | "jpeg" => image::ImageFormat::Jpeg,
-        "webp" => image::ImageFormat::WebP,
-        _ => image::ImageFormat::Png,
-    };
+    let base_dir = get_app_data_dir(&app)?;
 
-    image
-        .save_with_format(&file_path, img_format)
-        .map_err(|e| format!("Failed to save image: {}", e))?;
+    let encoded = if embed_metadata.unwrap_or(false) && format.eq_ignore_ascii_case("png") {
+        let project_file = base_dir
+            .join("projects")
+            .join(&project_id)
+            .join("project.json");
+        let content = fs::read_to_string(&project_file)
+            .map_err(|e| format!("Failed to read project: {}", e))?;
+        let project: CaptureProject = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse project: {}", e))?;
+
+        encode_png_with_text(&image, &capture_provenance(&project))?
+    } else {
+        encode_export_image(&image, &format, quality.unwrap_or(DEFAULT_EXPORT_QUALITY))?
+    };
+    fs::write(&file_path, encoded).map_err(|e| format!("Failed to save image: {}", e))?;
 
     // Save a copy in the project folder
-    let base_dir = get_app_data_dir(&app)?;
     let edited_path = base_dir
         .join("projects")
         .join(&project_id)
@@ -1050,13 +1794,178 @@ pub async fn export_project(
     Ok(())
 }
 
+/// File extension for an export format (matches `encode_export_image`'s fallback to PNG).
+pub(crate) fn export_extension(format: &str) -> &'static str {
+    match format.to_lowercase().as_str() {
+        "jpg" | "jpeg" => "jpg",
+        "webp" => "webp",
+        "avif" => "avif",
+        _ => "png",
+    }
+}
+
+/// Export one project into `output_dir` as `{id}.{ext}`.
+///
+/// Uses the annotated render saved by the last `export_project` when there is
+/// one, otherwise the original capture.
+fn export_project_to_dir(
+    base_dir: &Path,
+    project_id: &str,
+    output_dir: &Path,
+    format: &str,
+) -> Result<String, String> {
+    let project_dir = base_dir.join("projects").join(project_id);
+    let content = fs::read_to_string(project_dir.join("project.json"))
+        .map_err(|e| format!("Failed to read project: {}", e))?;
+    let project: CaptureProject =
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse project: {}", e))?;
+
+    let edited_path = project_dir.join("edited.png");
+    let source_path = if !project.annotations.is_empty() && edited_path.exists() {
+        edited_path
+    } else {
+        resolve_original_image(base_dir, &project.original_image)
+    };
+
+    let image = load_image_oriented(&source_path)?;
+    let encoded = encode_export_image(&image, format, DEFAULT_EXPORT_QUALITY)?;
+
+    let output_path = output_dir.join(format!("{}.{}", project.id, export_extension(format)));
+    fs::write(&output_path, encoded).map_err(|e| format!("Failed to save image: {}", e))?;
+
+    Ok(output_path.to_string_lossy().to_string())
+}
+
+/// Export several projects into `output_dir`, one `{id}.{ext}` file each.
+///
+/// Keeps going past individual failures, which are logged; returns the paths
+/// that were written. Fails only if no project could be exported.
+#[command]
+pub async fn export_projects(
+    app: AppHandle,
+    project_ids: Vec<String>,
+    output_dir: String,
+    format: String,
+) -> Result<Vec<String>, String> {
+    let base_dir = get_app_data_dir(&app)?;
+    let output_dir = PathBuf::from(output_dir);
+    fs::create_dir_all(&output_dir)
+        .map_err(|e| format!("Failed to create output folder: {}", e))?;
+
+    let mut written = Vec::new();
+    let mut failed = Vec::new();
+
+    for id in &project_ids {
+        match export_project_to_dir(&base_dir, id, &output_dir, &format) {
+            Ok(path) => written.push(path),
+            Err(e) => {
+                log::warn!("[EXPORT] Failed to export project {}: {}", id, e);
+                failed.push(format!("{}: {}", id, e));
+            },
+        }
+    }
+
+    if written.is_empty() && !failed.is_empty() {
+        return Err(format!(
+            "Failed to export {} projects: {}",
+            failed.len(),
+            failed.join("; ")
+        ));
+    }
+
+    if !failed.is_empty() {
+        log::warn!(
+            "[EXPORT] Exported {} of {} projects",
+            written.len(),
+            project_ids.len()
+        );
+    }
+
+    Ok(written)
+}
+
 // ============================================================================
 // Stats and Utility Operations
 // ============================================================================
 
+/// Add up disk usage per capture type.
+///
+/// Image projects count their original image plus project folder, video
+/// projects their whole folder (screen, webcam and audio tracks), and GIF and
+/// legacy MP4 captures their file.
+pub(crate) fn storage_usage_by_type(base_dir: &Path, captures_dir: &Path) -> Vec<TypeUsage> {
+    let mut usage: HashMap<String, TypeUsage> = HashMap::new();
+    let mut add = |capture_type: &str, size_bytes: u64| {
+        let entry = usage
+            .entry(capture_type.to_string())
+            .or_insert_with(|| TypeUsage {
+                capture_type: capture_type.to_string(),
+                count: 0,
+                size_bytes: 0,
+            });
+        entry.count += 1;
+        entry.size_bytes += size_bytes;
+    };
+
+    // Image projects
+    if let Ok(entries) = fs::read_dir(base_dir.join("projects")) {
+        for entry in entries.flatten() {
+            let project_dir = entry.path();
+            let Ok(content) = fs::read_to_string(project_dir.join("project.json")) else {
+                continue;
+            };
+            let Ok(project) = serde_json::from_str::<CaptureProject>(&content) else {
+                continue;
+            };
+
+            let image_path = resolve_original_image(base_dir, &project.original_image);
+            let image_size = fs::metadata(&image_path).map(|m| m.len()).unwrap_or(0);
+
+            add(
+                &project.capture_type,
+                image_size + calculate_dir_size(&project_dir),
+            );
+        }
+    }
+
+    // Video project folders, GIFs and legacy MP4s
+    if let Ok(entries) = fs::read_dir(captures_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                if path.join("screen.mp4").exists() {
+                    add("video", calculate_dir_size(&path));
+                }
+                continue;
+            }
+
+            let extension = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .map(|e| e.to_lowercase())
+                .unwrap_or_default();
+            let capture_type = match extension.as_str() {
+                "gif" => "gif",
+                "mp4" => "video",
+                _ => continue,
+            };
+            add(capture_type, entry.metadata().map(|m| m.len()).unwrap_or(0));
+        }
+    }
+
+    let mut by_type: Vec<TypeUsage> = usage.into_values().collect();
+    by_type.sort_by(|a, b| {
+        b.size_bytes
+            .cmp(&a.size_bytes)
+            .then_with(|| a.capture_type.cmp(&b.capture_type))
+    });
+    by_type
+}
+
 #[command]
 pub async fn get_storage_stats(app: AppHandle) -> Result<StorageStats, String> {
     let base_dir = get_app_data_dir(&app)?;
+    let captures_dir = get_captures_dir(&app)?;
 
     let mut total_size: u64 = 0;
     let mut capture_count: u32 = 0;
@@ -1084,6 +1993,7 @@ pub async fn get_storage_stats(app: AppHandle) -> Result<StorageStats, String> {
         total_size_mb: total_size as f64 / (1024.0 * 1024.0),
         capture_count,
         storage_path: base_dir.to_string_lossy().to_string(),
+        by_type: storage_usage_by_type(&base_dir, &captures_dir),
     })
 }
 
@@ -1111,6 +2021,308 @@ pub async fn ensure_ffmpeg() -> Result<bool, String> {
     }
 }
 
+// ============================================================================
+// Library Verification and Repair
+// ============================================================================
+
+/// Name of the temp file left behind by an interrupted project.json write.
+const PROJECT_TEMP_FILE: &str = "project.json.tmp";
+
+/// Source media a missing thumbnail can be regenerated from.
+enum ThumbnailSource {
+    Image(PathBuf),
+    Video(PathBuf),
+    Gif(PathBuf),
+}
+
+/// Full scan result: the user-facing report plus what repair needs.
+struct LibraryScan {
+    report: LibraryReport,
+    /// (source, thumbnail path) for every item missing its thumbnail.
+    missing_thumbnails: Vec<(ThumbnailSource, PathBuf)>,
+}
+
+/// Extract the capture ID from a thumbnail file name (`{id}_thumb.png`).
+pub(crate) fn thumbnail_capture_id(file_name: &str) -> Option<&str> {
+    file_name
+        .strip_suffix("_thumb.png")
+        .filter(|id| !id.is_empty())
+}
+
+/// Read and parse a screenshot project.json.
+fn read_capture_project(path: &Path) -> Option<CaptureProject> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Check that a file contains valid JSON.
+///
+/// Used for video project.json files, which `load_video_project_folder` reads leniently.
+fn is_valid_json(path: &Path) -> bool {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .is_some()
+}
+
+/// Scan projects, media and thumbnails without modifying anything.
+fn scan_library(base_dir: &Path, captures_dir: &Path) -> LibraryScan {
+    let projects_dir = base_dir.join("projects");
+    let thumbnails_dir = base_dir.join("thumbnails");
+    let mut report = LibraryReport::default();
+    let mut missing_thumbnails = Vec::new();
+    let mut known_ids: HashSet<String> = HashSet::new();
+
+    let mut check_thumbnail = |id: &str, source: ThumbnailSource, report: &mut LibraryReport| {
+        let thumbnail_path = thumbnails_dir.join(format!("{}_thumb.png", id));
+        if !thumbnail_path.exists() {
+            report.missing_thumbnails.push(id.to_string());
+            missing_thumbnails.push((source, thumbnail_path));
+        }
+    };
+
+    // 1. Screenshot projects
+    for entry in fs::read_dir(&projects_dir).into_iter().flatten().flatten() {
+        let project_dir = entry.path();
+        if !project_dir.is_dir() {
+            continue;
+        }
+        let Some(id) = project_dir.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        known_ids.insert(id.to_string());
+        report.items_scanned += 1;
+
+        let temp_file = project_dir.join(PROJECT_TEMP_FILE);
+        if temp_file.exists() {
+            report
+                .temp_project_files
+                .push(temp_file.to_string_lossy().to_string());
+        }
+
+        let project_file = project_dir.join("project.json");
+        match read_capture_project(&project_file) {
+            Some(project) => {
+                let image_path = resolve_original_image(base_dir, &project.original_image);
+
+                if image_path.exists() {
+                    check_thumbnail(id, ThumbnailSource::Image(image_path), &mut report);
+                } else {
+                    report.missing_originals.push(id.to_string());
+                }
+            },
+            None => report
+                .unparseable_projects
+                .push(project_file.to_string_lossy().to_string()),
+        }
+    }
+
+    // 2. Video project folders and GIF/legacy MP4 files
+    for entry in fs::read_dir(captures_dir).into_iter().flatten().flatten() {
+        let path = entry.path();
+        let (Some(name), Some(stem)) = (
+            path.file_name().and_then(|n| n.to_str()),
+            path.file_stem().and_then(|n| n.to_str()),
+        ) else {
+            continue;
+        };
+
+        if path.is_dir() {
+            let project_json = path.join("project.json");
+            let screen_mp4 = path.join("screen.mp4");
+            let has_project_json = project_json.exists();
+
+            // Only folders that look like recordings; users may keep other folders here
+            if !has_project_json && !screen_mp4.exists() {
+                continue;
+            }
+            known_ids.insert(name.to_string());
+
+            let temp_file = path.join(PROJECT_TEMP_FILE);
+            if temp_file.exists() {
+                report
+                    .temp_project_files
+                    .push(temp_file.to_string_lossy().to_string());
+            }
+
+            if has_project_json && !is_valid_json(&project_json) {
+                report
+                    .unparseable_projects
+                    .push(project_json.to_string_lossy().to_string());
+            }
+
+            if screen_mp4.exists() {
+                report.items_scanned += 1;
+                check_thumbnail(name, ThumbnailSource::Video(screen_mp4), &mut report);
+            } else {
+                report
+                    .missing_screen_videos
+                    .push(path.to_string_lossy().to_string());
+            }
+        } else {
+            let extension = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .map(|e| e.to_lowercase())
+                .unwrap_or_default();
+            if extension != "mp4" && extension != "gif" {
+                continue;
+            }
+            // Skip auxiliary files (webcam, cursor) stored next to legacy recordings
+            if stem.ends_with("_webcam") || stem.ends_with("_cursor") {
+                continue;
+            }
+            known_ids.insert(stem.to_string());
+            report.items_scanned += 1;
+
+            let source = if extension == "gif" {
+                ThumbnailSource::Gif(path.clone())
+            } else {
+                ThumbnailSource::Video(path.clone())
+            };
+            check_thumbnail(stem, source, &mut report);
+        }
+    }
+
+    // 3. Thumbnails that no longer belong to anything
+    for entry in fs::read_dir(&thumbnails_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+    {
+        let path = entry.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if let Some(id) = thumbnail_capture_id(name) {
+            if !known_ids.contains(id) {
+                report
+                    .orphaned_thumbnails
+                    .push(path.to_string_lossy().to_string());
+            }
+        }
+    }
+
+    LibraryScan {
+        report,
+        missing_thumbnails,
+    }
+}
+
+/// Scan the library for inconsistencies left by crashes or manual file changes.
+///
+/// Read-only: reports orphaned thumbnails, projects with missing originals,
+/// unparseable project files, video folders without screen.mp4, leftover temp
+/// files and missing thumbnails. Use `repair_library` to fix what can be fixed.
+#[command]
+pub async fn verify_library(app: AppHandle) -> Result<LibraryReport, String> {
+    let base_dir = get_app_data_dir(&app)?;
+    let captures_dir = get_captures_dir(&app)?;
+
+    let report = scan_library(&base_dir, &captures_dir).report;
+
+    log::info!(
+        "[LIBRARY] Verified {} items: {} orphaned thumbnails, {} missing originals, {} unparseable projects, {} folders without screen.mp4, {} temp files, {} missing thumbnails",
+        report.items_scanned,
+        report.orphaned_thumbnails.len(),
+        report.missing_originals.len(),
+        report.unparseable_projects.len(),
+        report.missing_screen_videos.len(),
+        report.temp_project_files.len(),
+        report.missing_thumbnails.len()
+    );
+
+    Ok(report)
+}
+
+/// Put each `project.json.tmp` in place of a missing or unparseable
+/// project.json, and delete the ones that aren't needed.
+pub(crate) fn restore_temp_project_files(
+    base_dir: &Path,
+    captures_dir: &Path,
+    result: &mut LibraryRepairResult,
+) {
+    for temp_path in scan_library(base_dir, captures_dir)
+        .report
+        .temp_project_files
+    {
+        let temp_path = PathBuf::from(temp_path);
+        let project_file = temp_path.with_file_name("project.json");
+        let target_valid = is_valid_json(&project_file);
+        let temp_valid = is_valid_json(&temp_path);
+
+        if !target_valid && temp_valid {
+            match fs::rename(&temp_path, &project_file) {
+                Ok(()) => {
+                    log::info!("[LIBRARY] Restored {:?}", project_file);
+                    result.projects_restored += 1;
+                },
+                Err(e) => log::warn!("[LIBRARY] Failed to restore {:?}: {}", temp_path, e),
+            }
+        } else if fs::remove_file(&temp_path).is_ok() {
+            result.temp_files_removed += 1;
+        }
+    }
+}
+
+/// Repair what `verify_library` reports.
+///
+/// - Restores `project.json.tmp` when project.json is missing or unparseable
+///   (otherwise the stale temp file is removed)
+/// - Removes orphaned thumbnails
+/// - Regenerates missing thumbnails from the original media
+///
+/// Projects with missing originals or unrecoverable project files are left
+/// untouched so no user data is deleted.
+#[command]
+pub async fn repair_library(app: AppHandle) -> Result<LibraryRepairResult, String> {
+    let base_dir = ensure_directories(&app)?;
+    let captures_dir = get_captures_dir(&app)?;
+    let mut result = LibraryRepairResult::default();
+
+    // 1. Restore or discard temp project files (before scanning thumbnails,
+    //    since a restored project changes what's missing)
+    restore_temp_project_files(&base_dir, &captures_dir, &mut result);
+
+    let scan = scan_library(&base_dir, &captures_dir);
+
+    // 2. Remove orphaned thumbnails
+    for thumbnail in &scan.report.orphaned_thumbnails {
+        if fs::remove_file(thumbnail).is_ok() {
+            result.thumbnails_removed += 1;
+        }
+    }
+
+    // 3. Regenerate missing thumbnails
+    for (source, thumbnail_path) in scan.missing_thumbnails {
+        let generated = match &source {
+            ThumbnailSource::Image(path) => load_image_oriented(path)
+                .and_then(|image| generate_thumbnail(&image))
+                .and_then(|thumbnail| {
+                    thumbnail
+                        .save(&thumbnail_path)
+                        .map_err(|e| format!("Failed to save thumbnail: {}", e))
+                }),
+            ThumbnailSource::Video(path) => generate_video_thumbnail(path, &thumbnail_path),
+            ThumbnailSource::Gif(path) => generate_gif_thumbnail(path, &thumbnail_path),
+        };
+        match generated {
+            Ok(()) => result.thumbnails_regenerated += 1,
+            Err(e) => log::warn!("[LIBRARY] Failed to regenerate {:?}: {}", thumbnail_path, e),
+        }
+    }
+
+    log::info!(
+        "[LIBRARY] Repair complete: {} projects restored, {} temp files removed, {} orphaned thumbnails removed, {} thumbnails regenerated",
+        result.projects_restored,
+        result.temp_files_removed,
+        result.thumbnails_removed,
+        result.thumbnails_regenerated
+    );
+
+    Ok(result)
+}
+
 // ============================================================================
 // Migration and Cleanup Operations
 // ============================================================================
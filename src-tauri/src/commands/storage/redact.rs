@@ -0,0 +1,153 @@
+//! Irreversible redaction of image regions.
+//!
+//! Unlike the editor's blur annotations, which are drawn over the image in the
+//! WebView, these rewrite the pixels themselves, so nothing of the original
+//! content survives in the saved file. Blur and mosaic strength scale with the
+//! region so small regions are still unreadable.
+
+use image::{Rgba, RgbaImage};
+
+use super::types::{RedactMode, RedactRegion};
+
+/// Mosaic block size floor, in pixels.
+const MIN_PIXELATE_BLOCK: u32 = 8;
+
+/// Mosaic block size as a fraction of the region's shorter side.
+const PIXELATE_BLOCK_DIVISOR: u32 = 8;
+
+/// Box blur radius floor, in pixels.
+const MIN_BLUR_RADIUS: u32 = 8;
+
+/// Box blur radius as a fraction of the region's shorter side.
+const BLUR_RADIUS_DIVISOR: u32 = 6;
+
+/// Repeated box blurs approximate a gaussian and wipe out fine detail.
+const BLUR_PASSES: usize = 3;
+
+/// Redact every region of `image` in place.
+pub fn apply_redactions(image: &mut RgbaImage, regions: &[RedactRegion], mode: RedactMode) {
+    for region in regions {
+        let Some((x, y, width, height)) = clamp_region(region, image.width(), image.height())
+        else {
+            continue;
+        };
+
+        match mode {
+            RedactMode::Blur => box_blur_region(image, x, y, width, height),
+            RedactMode::Pixelate => pixelate_region(image, x, y, width, height),
+        }
+    }
+}
+
+/// Clip `region` to the image. None when nothing of it is inside.
+fn clamp_region(region: &RedactRegion, width: u32, height: u32) -> Option<(u32, u32, u32, u32)> {
+    let left = region.x.max(0) as i64;
+    let top = region.y.max(0) as i64;
+    let right = (region.x as i64 + region.width as i64).min(width as i64);
+    let bottom = (region.y as i64 + region.height as i64).min(height as i64);
+
+    (right > left && bottom > top).then(|| {
+        (
+            left as u32,
+            top as u32,
+            (right - left) as u32,
+            (bottom - top) as u32,
+        )
+    })
+}
+
+/// Fill each block of the region with its average color.
+fn pixelate_region(image: &mut RgbaImage, x: u32, y: u32, width: u32, height: u32) {
+    let block = (width.min(height) / PIXELATE_BLOCK_DIVISOR).max(MIN_PIXELATE_BLOCK);
+
+    for block_y in (y..y + height).step_by(block as usize) {
+        for block_x in (x..x + width).step_by(block as usize) {
+            let block_w = block.min(x + width - block_x);
+            let block_h = block.min(y + height - block_y);
+
+            let mut sum = [0u64; 4];
+            for py in block_y..block_y + block_h {
+                for px in block_x..block_x + block_w {
+                    let pixel = image.get_pixel(px, py);
+                    for (total, &channel) in sum.iter_mut().zip(pixel.0.iter()) {
+                        *total += channel as u64;
+                    }
+                }
+            }
+
+            let count = (block_w * block_h) as u64;
+            let average = Rgba(sum.map(|total| (total / count) as u8));
+            for py in block_y..block_y + block_h {
+                for px in block_x..block_x + block_w {
+                    image.put_pixel(px, py, average);
+                }
+            }
+        }
+    }
+}
+
+/// Blur the region with repeated separable box blurs, using only pixels
+/// inside it so the redacted content doesn't bleed out.
+fn box_blur_region(image: &mut RgbaImage, x: u32, y: u32, width: u32, height: u32) {
+    let radius = (width.min(height) / BLUR_RADIUS_DIVISOR).max(MIN_BLUR_RADIUS) as usize;
+    let (w, h) = (width as usize, height as usize);
+
+    let mut pixels: Vec<[f32; 4]> = Vec::with_capacity(w * h);
+    for py in y..y + height {
+        for px in x..x + width {
+            pixels.push(image.get_pixel(px, py).0.map(f32::from));
+        }
+    }
+
+    for _ in 0..BLUR_PASSES {
+        pixels = box_blur_1d(&pixels, w, h, radius, 1, w);
+        pixels = box_blur_1d(&pixels, h, w, radius, w, 1);
+    }
+
+    for (i, pixel) in pixels.iter().enumerate() {
+        let (px, py) = ((i % w) as u32, (i / w) as u32);
+        image.put_pixel(
+            x + px,
+            y + py,
+            Rgba(pixel.map(|channel| channel.round().clamp(0.0, 255.0) as u8)),
+        );
+    }
+}
+
+/// Moving average over `lines` lines of `len` pixels, clamping at the line
+/// ends. Pixel `i` of line `l` sits at `l * line_stride + i * step`.
+fn box_blur_1d(
+    pixels: &[[f32; 4]],
+    len: usize,
+    lines: usize,
+    radius: usize,
+    step: usize,
+    line_stride: usize,
+) -> Vec<[f32; 4]> {
+    let mut out = vec![[0.0; 4]; pixels.len()];
+    let window = (2 * radius + 1) as f32;
+    let at = |line: usize, i: isize| {
+        let i = i.clamp(0, len as isize - 1) as usize;
+        pixels[line * line_stride + i * step]
+    };
+
+    for line in 0..lines {
+        let mut sum = [0.0f32; 4];
+        for i in -(radius as isize)..=radius as isize {
+            for (total, channel) in sum.iter_mut().zip(at(line, i)) {
+                *total += channel;
+            }
+        }
+
+        for i in 0..len {
+            out[line * line_stride + i * step] = sum.map(|total| total / window);
+
+            let leaving = at(line, i as isize - radius as isize);
+            let entering = at(line, i as isize + radius as isize + 1);
+            for ((total, enter), leave) in sum.iter_mut().zip(entering).zip(leaving) {
+                *total += enter - leave;
+            }
+        }
+    }
+    out
+}
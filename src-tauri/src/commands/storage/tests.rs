@@ -3,10 +3,20 @@
 //! These tests focus on pure logic and serialization without I/O dependencies.
 
 use chrono::Utc;
+use image::codecs::png::PngEncoder;
+use image::{DynamicImage, GenericImageView, ImageEncoder, Rgba, RgbaImage};
 
+use super::bundle::{export_bundle, relink_video_sources, BundleKind, BundleManifest};
 use super::ffmpeg::THUMBNAIL_SIZE;
-use super::generate_id;
+use super::filename::{expand_filename_template, sanitize_filename};
+use super::history::{AnnotationHistory, MAX_HISTORY_ENTRIES};
+use super::operations::{
+    capture_provenance, content_hash, encode_export_image, encode_png_with_text, export_extension,
+    restore_temp_project_files, storage_usage_by_type, thumbnail_capture_id, CaptureFilter,
+};
+use super::redact::apply_redactions;
 use super::types::*;
+use super::{generate_id, load_image_oriented, resolve_original_image};
 
 #[test]
 fn test_generate_id_format() {
@@ -156,6 +166,8 @@ fn test_capture_project_serialization() {
         annotations: vec![],
         tags: vec!["screenshot".to_string(), "test".to_string()],
         favorite: true,
+        content_hash: None,
+        name: None,
     };
 
     let json = serde_json::to_string_pretty(&project).expect("Failed to serialize");
@@ -197,6 +209,8 @@ fn test_capture_list_item_serialization() {
         tags: vec![],
         favorite: false,
         is_missing: false,
+        duration_ms: None,
+        name: Some("Bug repro".to_string()),
     };
 
     let json = serde_json::to_string(&item).expect("Failed to serialize");
@@ -207,6 +221,7 @@ fn test_capture_list_item_serialization() {
     assert_eq!(restored.dimensions.width, 2560);
     assert!(!restored.has_annotations);
     assert!(!restored.is_missing);
+    assert_eq!(restored.name.as_deref(), Some("Bug repro"));
 }
 
 #[test]
@@ -216,6 +231,11 @@ fn test_storage_stats_serialization() {
         total_size_mb: 1024.0,
         capture_count: 150,
         storage_path: "/Users/test/Pictures/SnapIt".to_string(),
+        by_type: vec![TypeUsage {
+            capture_type: "video".to_string(),
+            count: 3,
+            size_bytes: 900_000_000,
+        }],
     };
 
     let json = serde_json::to_string(&stats).expect("Failed to serialize");
@@ -223,6 +243,9 @@ fn test_storage_stats_serialization() {
     assert!(json.contains("1073741824"));
     assert!(json.contains("1024"));
     assert!(json.contains("150"));
+    assert!(
+        json.contains(r#""by_type":[{"capture_type":"video","count":3,"size_bytes":900000000}]"#)
+    );
 }
 
 #[test]
@@ -263,3 +286,656 @@ fn test_region_boundary_values() {
     assert_eq!(restored.width, 0);
     assert_eq!(restored.height, u32::MAX);
 }
+
+/// Build a 3x2 image where each pixel's red channel is its index (row-major).
+fn orientation_test_image() -> DynamicImage {
+    let mut img = RgbaImage::new(3, 2);
+    for (x, y, pixel) in img.enumerate_pixels_mut() {
+        *pixel = Rgba([(y * 3 + x) as u8, 0, 0, 255]);
+    }
+    DynamicImage::ImageRgba8(img)
+}
+
+/// Minimal little-endian EXIF block holding only an orientation tag.
+fn exif_with_orientation(exif_value: u8) -> Vec<u8> {
+    let mut exif = b"II*\0".to_vec();
+    exif.extend_from_slice(&8u32.to_le_bytes()); // IFD offset
+    exif.extend_from_slice(&1u16.to_le_bytes()); // entry count
+    exif.extend_from_slice(&0x0112u16.to_le_bytes()); // Orientation tag
+    exif.extend_from_slice(&3u16.to_le_bytes()); // SHORT
+    exif.extend_from_slice(&1u32.to_le_bytes()); // value count
+    exif.extend_from_slice(&[exif_value, 0, 0, 0]);
+    exif.extend_from_slice(&0u32.to_le_bytes()); // no next IFD
+    exif
+}
+
+/// Return (width, height, [red of (0,0), red of (1,0)]) after importing a PNG
+/// tagged with an EXIF orientation.
+fn oriented(exif_value: u8) -> (u32, u32, [u8; 2]) {
+    let source = orientation_test_image().to_rgba8();
+    let mut png = Vec::new();
+    let mut encoder = PngEncoder::new(&mut png);
+    encoder
+        .set_exif_metadata(exif_with_orientation(exif_value))
+        .unwrap();
+    encoder
+        .write_image(source.as_raw(), 3, 2, image::ExtendedColorType::Rgba8)
+        .unwrap();
+
+    let path = std::env::temp_dir().join(format!("snapit_orientation_test_{}.png", generate_id()));
+    std::fs::write(&path, png).unwrap();
+    let img = load_image_oriented(&path);
+    let _ = std::fs::remove_file(&path);
+
+    let img = img.unwrap();
+    let (w, h) = img.dimensions();
+    (w, h, [img.get_pixel(0, 0)[0], img.get_pixel(1, 0)[0]])
+}
+
+// Source layout (red values):
+//   0 1 2
+//   3 4 5
+
+#[test]
+fn test_exif_orientation_1_identity() {
+    assert_eq!(oriented(1), (3, 2, [0, 1]));
+}
+
+#[test]
+fn test_exif_orientation_2_flip_horizontal() {
+    assert_eq!(oriented(2), (3, 2, [2, 1]));
+}
+
+#[test]
+fn test_exif_orientation_3_rotate_180() {
+    assert_eq!(oriented(3), (3, 2, [5, 4]));
+}
+
+#[test]
+fn test_exif_orientation_4_flip_vertical() {
+    assert_eq!(oriented(4), (3, 2, [3, 4]));
+}
+
+#[test]
+fn test_exif_orientation_5_transpose() {
+    assert_eq!(oriented(5), (2, 3, [0, 3]));
+}
+
+#[test]
+fn test_exif_orientation_6_rotate_90_cw() {
+    assert_eq!(oriented(6), (2, 3, [3, 0]));
+}
+
+#[test]
+fn test_exif_orientation_7_transverse() {
+    assert_eq!(oriented(7), (2, 3, [5, 2]));
+}
+
+#[test]
+fn test_exif_orientation_8_rotate_270_cw() {
+    assert_eq!(oriented(8), (2, 3, [2, 5]));
+}
+
+#[test]
+fn test_exif_orientation_invalid_value_is_noop() {
+    assert_eq!(oriented(0), (3, 2, [0, 1]));
+    assert_eq!(oriented(9), (3, 2, [0, 1]));
+}
+
+#[test]
+fn test_thumbnail_capture_id() {
+    assert_eq!(thumbnail_capture_id("abc123_thumb.png"), Some("abc123"));
+    assert_eq!(
+        thumbnail_capture_id("recording_2024_thumb.png"),
+        Some("recording_2024")
+    );
+    assert_eq!(thumbnail_capture_id("_thumb.png"), None);
+    assert_eq!(thumbnail_capture_id("abc123.png"), None);
+    assert_eq!(thumbnail_capture_id("abc123_thumb.jpg"), None);
+}
+
+#[test]
+fn test_resolve_original_image() {
+    let base_dir = std::env::temp_dir().join("snapit");
+
+    // Old format: filename only, stored under captures
+    assert_eq!(
+        resolve_original_image(&base_dir, "capture.png"),
+        base_dir.join("captures").join("capture.png")
+    );
+
+    // New format: full path used as is
+    let full_path = base_dir.join("elsewhere").join("capture.png");
+    assert_eq!(
+        resolve_original_image(&base_dir, &full_path.to_string_lossy()),
+        full_path
+    );
+}
+
+/// Write a minimal `project.json` into `project_dir`, creating it.
+fn write_test_project(project_dir: &std::path::Path, capture_type: &str, original_image: &str) {
+    let now = Utc::now();
+    let project = CaptureProject {
+        id: generate_id(),
+        created_at: now,
+        updated_at: now,
+        capture_type: capture_type.to_string(),
+        source: CaptureSource {
+            monitor: Some(0),
+            window_id: None,
+            window_title: None,
+            region: None,
+        },
+        original_image: original_image.to_string(),
+        dimensions: Dimensions {
+            width: 100,
+            height: 100,
+        },
+        annotations: vec![],
+        tags: vec![],
+        favorite: false,
+        content_hash: None,
+        name: None,
+    };
+    std::fs::create_dir_all(project_dir).unwrap();
+    std::fs::write(
+        project_dir.join("project.json"),
+        serde_json::to_string(&project).unwrap(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_storage_usage_by_type() {
+    let base_dir = std::env::temp_dir().join(format!("snapit_library_test_{}", generate_id()));
+    let captures_dir = base_dir.join("captures");
+    let recording = captures_dir.join("recording_1");
+    std::fs::create_dir_all(&recording).unwrap();
+
+    // Image project in the old format (filename only, under captures)
+    let project_dir = base_dir.join("projects").join("shot");
+    write_test_project(&project_dir, "region", "shot.png");
+    std::fs::write(captures_dir.join("shot.png"), vec![0u8; 10_000]).unwrap();
+    let project_json_size = std::fs::metadata(project_dir.join("project.json"))
+        .unwrap()
+        .len();
+
+    // Video project folder, GIF and legacy MP4
+    std::fs::write(recording.join("screen.mp4"), vec![0u8; 5_000]).unwrap();
+    std::fs::write(recording.join("webcam.mp4"), vec![0u8; 2_000]).unwrap();
+    std::fs::write(captures_dir.join("clip.gif"), vec![0u8; 300]).unwrap();
+    std::fs::write(captures_dir.join("legacy.MP4"), vec![0u8; 400]).unwrap();
+
+    let usage = storage_usage_by_type(&base_dir, &captures_dir);
+    let _ = std::fs::remove_dir_all(&base_dir);
+
+    // Largest first
+    assert_eq!(
+        usage,
+        vec![
+            TypeUsage {
+                capture_type: "region".to_string(),
+                count: 1,
+                size_bytes: 10_000 + project_json_size,
+            },
+            TypeUsage {
+                capture_type: "video".to_string(),
+                count: 2,
+                size_bytes: 7_400,
+            },
+            TypeUsage {
+                capture_type: "gif".to_string(),
+                count: 1,
+                size_bytes: 300,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_restore_temp_project_files() {
+    let base_dir = std::env::temp_dir().join(format!("snapit_library_test_{}", generate_id()));
+    let captures_dir = base_dir.join("captures");
+    let crashed = base_dir.join("projects").join("crashed");
+    let saved = base_dir.join("projects").join("saved");
+    std::fs::create_dir_all(&captures_dir).unwrap();
+    std::fs::create_dir_all(&crashed).unwrap();
+    std::fs::create_dir_all(&saved).unwrap();
+
+    // Interrupted write: only the temp file made it to disk
+    std::fs::write(crashed.join("project.json.tmp"), r#"{"id":"crashed"}"#).unwrap();
+    // Finished write with a stale temp file next to it
+    std::fs::write(saved.join("project.json"), r#"{"id":"saved"}"#).unwrap();
+    std::fs::write(saved.join("project.json.tmp"), r#"{"id":"old"}"#).unwrap();
+
+    let mut result = LibraryRepairResult::default();
+    restore_temp_project_files(&base_dir, &captures_dir, &mut result);
+
+    assert_eq!(result.projects_restored, 1);
+    assert_eq!(result.temp_files_removed, 1);
+    assert_eq!(
+        std::fs::read_to_string(crashed.join("project.json")).unwrap(),
+        r#"{"id":"crashed"}"#
+    );
+    assert_eq!(
+        std::fs::read_to_string(saved.join("project.json")).unwrap(),
+        r#"{"id":"saved"}"#
+    );
+    assert!(!crashed.join("project.json.tmp").exists());
+    assert!(!saved.join("project.json.tmp").exists());
+
+    let _ = std::fs::remove_dir_all(&base_dir);
+}
+
+#[test]
+fn test_library_report_serialization() {
+    let report = LibraryReport {
+        items_scanned: 3,
+        missing_originals: vec!["abc".to_string()],
+        ..Default::default()
+    };
+
+    let json = serde_json::to_string(&report).expect("Failed to serialize");
+
+    assert!(json.contains("\"items_scanned\":3"));
+    assert!(json.contains("\"missing_originals\":[\"abc\"]"));
+    assert!(json.contains("\"orphaned_thumbnails\":[]"));
+}
+
+#[test]
+fn test_encode_export_image_formats() {
+    let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(16, 16, Rgba([255, 0, 0, 128])));
+
+    let png = encode_export_image(&image, "png", 90).unwrap();
+    assert!(png.starts_with(b"\x89PNG"));
+
+    let jpeg = encode_export_image(&image, "JPG", 80).unwrap();
+    assert!(jpeg.starts_with(&[0xFF, 0xD8]));
+
+    let webp = encode_export_image(&image, "webp", 80).unwrap();
+    assert!(webp.starts_with(b"RIFF"));
+    assert_eq!(&webp[8..12], b"WEBP");
+
+    let avif = encode_export_image(&image, "avif", 80).unwrap();
+    assert_eq!(&avif[4..8], b"ftyp");
+
+    // Unknown formats fall back to PNG
+    let fallback = encode_export_image(&image, "tiff", 90).unwrap();
+    assert!(fallback.starts_with(b"\x89PNG"));
+}
+
+#[test]
+fn test_png_export_embeds_capture_provenance() {
+    use chrono::TimeZone;
+
+    let created_at = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap();
+    let project = CaptureProject {
+        id: "test-project-1".to_string(),
+        created_at,
+        updated_at: created_at,
+        capture_type: "window".to_string(),
+        source: CaptureSource {
+            monitor: None,
+            window_id: Some(42),
+            window_title: Some("Café — Notes".to_string()),
+            region: None,
+        },
+        original_image: "image.png".to_string(),
+        dimensions: Dimensions {
+            width: 4,
+            height: 4,
+        },
+        annotations: vec![],
+        tags: vec![],
+        favorite: false,
+        content_hash: None,
+        name: None,
+    };
+
+    let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(4, 4, Rgba([0, 0, 255, 255])));
+    let encoded = encode_png_with_text(&image, &capture_provenance(&project)).unwrap();
+
+    let reader = png::Decoder::new(std::io::Cursor::new(encoded))
+        .read_info()
+        .unwrap();
+    let info = reader.info();
+
+    let text: Vec<(&str, &str)> = info
+        .uncompressed_latin1_text
+        .iter()
+        .map(|chunk| (chunk.keyword.as_str(), chunk.text.as_str()))
+        .collect();
+    assert!(text.contains(&("Creation Time", "2024-01-15T10:30:00+00:00")));
+    assert!(text.contains(&("Capture Type", "window")));
+
+    // Non-ASCII window titles are stored as UTF-8
+    let title = info
+        .utf8_text
+        .iter()
+        .find(|chunk| chunk.keyword == "Source Window")
+        .unwrap();
+    assert_eq!(title.get_text().unwrap(), "Café — Notes");
+}
+
+#[test]
+fn test_export_extension() {
+    assert_eq!(export_extension("PNG"), "png");
+    assert_eq!(export_extension("jpeg"), "jpg");
+    assert_eq!(export_extension("webp"), "webp");
+    assert_eq!(export_extension("avif"), "avif");
+    assert_eq!(export_extension("tiff"), "png");
+}
+
+#[test]
+fn test_content_hash() {
+    let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(4, 4, Rgba([10, 20, 30, 255])));
+    let same = DynamicImage::ImageRgba8(RgbaImage::from_pixel(4, 4, Rgba([10, 20, 30, 255])));
+    let hash = content_hash(&image);
+
+    assert_eq!(hash.len(), 64);
+    assert_eq!(hash, content_hash(&same));
+
+    // Same pixel bytes in a different shape are different images
+    let reshaped = DynamicImage::ImageRgba8(RgbaImage::from_pixel(2, 8, Rgba([10, 20, 30, 255])));
+    assert_ne!(hash, content_hash(&reshaped));
+}
+
+#[test]
+fn test_capture_project_without_content_hash() {
+    let json = r#"{
+        "id": "legacy",
+        "created_at": "2024-01-15T10:30:00Z",
+        "updated_at": "2024-01-15T10:30:00Z",
+        "capture_type": "region",
+        "source": {"monitor": 0, "window_id": null, "window_title": null, "region": null},
+        "original_image": "image.png",
+        "dimensions": {"width": 10, "height": 10},
+        "annotations": [],
+        "tags": [],
+        "favorite": false
+    }"#;
+
+    let project: CaptureProject = serde_json::from_str(json).expect("Failed to deserialize");
+    assert_eq!(project.content_hash, None);
+    assert!(!serde_json::to_string(&project)
+        .unwrap()
+        .contains("content_hash"));
+}
+
+#[test]
+fn test_media_meta_serialization() {
+    let meta = MediaMeta {
+        width: 1280,
+        height: 720,
+        duration_ms: 42_000,
+    };
+
+    let json = serde_json::to_string(&meta).expect("Failed to serialize");
+    assert!(json.contains("durationMs"));
+
+    let restored: MediaMeta = serde_json::from_str(&json).expect("Failed to deserialize");
+    assert_eq!(restored, meta);
+}
+
+#[test]
+fn test_capture_filter() {
+    let tags = vec!["bug".to_string(), "ui".to_string()];
+
+    let everything = CaptureFilter::new("  ", vec![], None);
+    assert!(everything.matches("region", None, None, &[]));
+
+    let by_query = CaptureFilter::new("LOGIN", vec![], None);
+    assert!(by_query.matches("region", Some("Login page"), None, &[]));
+    assert!(by_query.matches("window", None, Some("Login - Chrome"), &[]));
+    assert!(!by_query.matches("region", Some("Dashboard"), None, &tags));
+
+    let by_tag = CaptureFilter::new("", vec!["ui".to_string()], None);
+    assert!(by_tag.matches("region", None, None, &tags));
+    assert!(!by_tag.matches("video", None, None, &[]));
+
+    let by_type = CaptureFilter::new("", vec![], Some("gif".to_string()));
+    assert!(by_type.matches("gif", None, None, &[]));
+    assert!(!by_type.matches("video", None, None, &[]));
+}
+
+#[test]
+fn test_bundle_manifest_serialization() {
+    let manifest = BundleManifest {
+        version: 1,
+        kind: BundleKind::Video,
+    };
+
+    let json = serde_json::to_string(&manifest).expect("Failed to serialize");
+    assert!(json.contains("\"video\""));
+
+    let restored: BundleManifest = serde_json::from_str(&json).expect("Failed to deserialize");
+    assert_eq!(restored.kind, BundleKind::Video);
+}
+
+#[test]
+fn test_relink_video_sources() {
+    let folder = std::env::temp_dir().join(format!("snapit_bundle_test_{}", generate_id()));
+    std::fs::create_dir_all(&folder).unwrap();
+    std::fs::write(folder.join("screen.mp4"), b"").unwrap();
+
+    let absolute = if cfg!(windows) {
+        "C:\\Old\\recording_1\\screen.mp4"
+    } else {
+        "/old/recording_1/screen.mp4"
+    };
+    let mut project = serde_json::json!({
+        "sources": {
+            "screenVideo": absolute,
+            "webcamVideo": "webcam.mp4",
+            "cursorData": "/old/recording_1/cursor.json",
+        }
+    });
+
+    relink_video_sources(&mut project, &folder);
+    let _ = std::fs::remove_dir_all(&folder);
+
+    assert_eq!(project["sources"]["screenVideo"], "screen.mp4");
+    assert_eq!(project["sources"]["webcamVideo"], "webcam.mp4");
+    // Files missing from the bundle keep their original path
+    assert_eq!(
+        project["sources"]["cursorData"],
+        "/old/recording_1/cursor.json"
+    );
+}
+
+#[test]
+fn test_export_bundle_removes_partial_file() {
+    let base_dir = std::env::temp_dir().join(format!("snapit_bundle_test_{}", generate_id()));
+    let captures_dir = base_dir.join("captures");
+    let project_dir = base_dir.join("projects").join("shot");
+    std::fs::create_dir_all(&captures_dir).unwrap();
+
+    // Project whose image is missing, so adding it to the zip fails
+    write_test_project(&project_dir, "region", "missing.png");
+
+    let output_path = base_dir.join("shot.snapitproj");
+    let result = export_bundle(&base_dir, &captures_dir, "shot", &output_path);
+    let partial_left = output_path.exists();
+    let _ = std::fs::remove_dir_all(&base_dir);
+
+    assert!(result.is_err());
+    assert!(!partial_left);
+}
+
+#[test]
+fn test_expand_filename_template() {
+    use chrono::TimeZone;
+
+    let now = chrono::Local
+        .with_ymd_and_hms(2025, 1, 31, 14, 30, 5)
+        .unwrap();
+
+    assert_eq!(
+        expand_filename_template("{date}_{source}_{n}", now, "DELL U2720Q", 3).as_deref(),
+        Some("2025-01-31_DELL U2720Q_3")
+    );
+    assert_eq!(
+        expand_filename_template("shot-{time}", now, "", 1).as_deref(),
+        Some("shot-143005")
+    );
+
+    // Window titles can contain characters Windows rejects
+    assert_eq!(
+        expand_filename_template("{source}", now, "C:\\temp | Notes?", 1).as_deref(),
+        Some("C__temp _ Notes_")
+    );
+
+    // Unknown tokens, unbalanced braces and empty results fall back
+    assert_eq!(expand_filename_template("{user}_{n}", now, "", 1), None);
+    assert_eq!(expand_filename_template("{date", now, "", 1), None);
+    assert_eq!(expand_filename_template("date}", now, "", 1), None);
+    assert_eq!(expand_filename_template("{source}", now, "", 1), None);
+}
+
+#[test]
+fn test_sanitize_filename() {
+    assert_eq!(sanitize_filename("a/b\\c:d"), "a_b_c_d");
+    assert_eq!(sanitize_filename("  trailing dots... "), "trailing dots");
+    assert_eq!(sanitize_filename(&"x".repeat(500)).len(), 120);
+}
+
+fn annotation_set(ids: &[&str]) -> Vec<Annotation> {
+    ids.iter()
+        .map(|id| Annotation {
+            id: id.to_string(),
+            annotation_type: "rectangle".to_string(),
+            properties: serde_json::json!({ "x": 10 }),
+        })
+        .collect()
+}
+
+#[test]
+fn test_annotation_history_undo_redo() {
+    let mut history = AnnotationHistory::default();
+    assert!(history.undo().is_none());
+
+    // First save records the set the project started with
+    history.record(&[], &annotation_set(&["a"]));
+    history.record(&annotation_set(&["a"]), &annotation_set(&["a", "b"]));
+    // Saving the same set again isn't a new step
+    history.record(&annotation_set(&["a", "b"]), &annotation_set(&["a", "b"]));
+    assert_eq!(history.entries.len(), 3);
+
+    assert_eq!(history.undo().map(<[Annotation]>::len), Some(1));
+    assert_eq!(history.undo().map(<[Annotation]>::len), Some(0));
+    assert!(history.undo().is_none());
+    assert_eq!(history.redo().map(<[Annotation]>::len), Some(1));
+
+    // Saving a neighbouring set follows the editor's in-memory undo/redo
+    history.record(&annotation_set(&["a"]), &annotation_set(&["a", "b"]));
+    assert_eq!(history.position, 2);
+    history.record(&annotation_set(&["a", "b"]), &annotation_set(&["a"]));
+    assert_eq!(history.position, 1);
+    assert_eq!(history.entries.len(), 3);
+
+    // A new save after undoing drops the redo steps
+    history.record(&annotation_set(&["a"]), &annotation_set(&["c"]));
+    assert!(history.redo().is_none());
+    assert_eq!(history.entries.len(), 3);
+    assert_eq!(history.entries[2][0].id, "c");
+
+    // Survives a round trip through JSON
+    let json = serde_json::to_string(&history).expect("Failed to serialize");
+    let mut restored: AnnotationHistory =
+        serde_json::from_str(&json).expect("Failed to deserialize");
+    assert_eq!(restored.position, 2);
+    assert_eq!(
+        restored.undo().map(|set| set[0].id.clone()),
+        Some("a".to_string())
+    );
+}
+
+#[test]
+fn test_annotation_history_capped() {
+    let mut history = AnnotationHistory::default();
+    for i in 0..MAX_HISTORY_ENTRIES + 10 {
+        let id = i.to_string();
+        history.record(&[], &annotation_set(&[id.as_str()]));
+    }
+
+    assert_eq!(history.entries.len(), MAX_HISTORY_ENTRIES);
+    assert_eq!(history.position, MAX_HISTORY_ENTRIES - 1);
+    // The oldest sets were dropped
+    assert_eq!(history.entries[0][0].id, "10");
+}
+
+/// Black/white checkerboard of 1px squares.
+fn checkerboard(width: u32, height: u32) -> RgbaImage {
+    RgbaImage::from_fn(width, height, |x, y| {
+        if (x + y) % 2 == 0 {
+            Rgba([0, 0, 0, 255])
+        } else {
+            Rgba([255, 255, 255, 255])
+        }
+    })
+}
+
+fn redact_region(x: i32, y: i32, width: u32, height: u32) -> RedactRegion {
+    RedactRegion {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+#[test]
+fn test_pixelate_flattens_blocks_inside_region_only() {
+    let mut image = checkerboard(64, 64);
+    apply_redactions(
+        &mut image,
+        &[redact_region(16, 16, 32, 32)],
+        RedactMode::Pixelate,
+    );
+
+    // Each 8px block inside is one averaged gray
+    let block = *image.get_pixel(16, 16);
+    assert!((126..=128).contains(&block[0]));
+    assert_eq!(image.get_pixel(23, 23), &block);
+    // Outside the region is untouched
+    assert_eq!(image.get_pixel(15, 16), &Rgba([255, 255, 255, 255]));
+    assert_eq!(image.get_pixel(48, 48), &Rgba([0, 0, 0, 255]));
+}
+
+#[test]
+fn test_blur_removes_detail_and_clips_to_image() {
+    let mut image = checkerboard(40, 40);
+    // Hangs off the top-left corner; the 0/255 checker blurs to mid gray
+    apply_redactions(
+        &mut image,
+        &[redact_region(-10, -10, 30, 30)],
+        RedactMode::Blur,
+    );
+
+    for y in 0..20 {
+        for x in 0..20 {
+            let value = image.get_pixel(x, y)[0];
+            assert!((100..=155).contains(&value), "({}, {}) = {}", x, y, value);
+        }
+    }
+    assert_eq!(image.get_pixel(20, 0), &Rgba([0, 0, 0, 255]));
+
+    // Regions entirely outside the image are ignored
+    let before = image.clone();
+    apply_redactions(
+        &mut image,
+        &[redact_region(100, 100, 10, 10)],
+        RedactMode::Blur,
+    );
+    assert_eq!(image, before);
+}
+
+#[test]
+fn test_redact_mode_serialization() {
+    assert_eq!(
+        serde_json::to_string(&RedactMode::Pixelate).unwrap(),
+        "\"pixelate\""
+    );
+    let mode: RedactMode = serde_json::from_str("\"blur\"").unwrap();
+    assert_eq!(mode, RedactMode::Blur);
+}
@@ -22,6 +22,27 @@ pub struct Region {
     pub height: u32,
 }
 
+/// Image region to redact, in image pixels.
+#[derive(Debug, Serialize, Deserialize, Clone, TS)]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct RedactRegion {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// How redacted regions are obscured.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub enum RedactMode {
+    /// Heavy box blur.
+    Blur,
+    /// Mosaic of averaged blocks.
+    Pixelate,
+}
+
 /// Source information for a capture.
 #[derive(Debug, Serialize, Deserialize, Clone, TS)]
 #[ts(export, export_to = "../../src/types/generated/")]
@@ -67,6 +88,13 @@ pub struct CaptureProject {
     pub annotations: Vec<Annotation>,
     pub tags: Vec<String>,
     pub favorite: bool,
+    /// SHA-256 of the decoded image, used to detect duplicate imports.
+    /// None for projects saved before hashing was added.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+    /// User-chosen title, or None to show the capture by date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
 /// Lightweight capture item for list display.
@@ -87,6 +115,21 @@ pub struct CaptureListItem {
     pub favorite: bool,
     /// True if the original image file is missing from disk
     pub is_missing: bool,
+    /// Duration of video and GIF captures in milliseconds.
+    /// None for images, and for media whose metadata hasn't been probed yet.
+    #[ts(type = "number | null")]
+    pub duration_ms: Option<u64>,
+    /// User-chosen title (video projects always have one).
+    pub name: Option<String>,
+}
+
+/// Video/GIF metadata cached next to the media so list loads skip ffprobe.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaMeta {
+    pub width: u32,
+    pub height: u32,
+    pub duration_ms: u64,
 }
 
 /// Request to save a new capture.
@@ -96,6 +139,10 @@ pub struct SaveCaptureRequest {
     pub image_data: String,
     pub capture_type: String,
     pub source: CaptureSource,
+    /// Save even if a project with identical image content already exists.
+    #[serde(default)]
+    #[ts(optional)]
+    pub allow_duplicates: Option<bool>,
 }
 
 /// Response after saving a capture.
@@ -119,6 +166,20 @@ pub struct StorageStats {
     #[ts(type = "number")]
     pub capture_count: u32,
     pub storage_path: String,
+    /// Disk usage per capture type, largest first.
+    pub by_type: Vec<TypeUsage>,
+}
+
+/// Disk usage of one capture type.
+#[derive(Debug, Clone, Serialize, PartialEq, TS)]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct TypeUsage {
+    /// Capture type as shown in the library (region, window, video, gif, ...).
+    pub capture_type: String,
+    #[ts(type = "number")]
+    pub count: u32,
+    #[ts(type = "number")]
+    pub size_bytes: u64,
 }
 
 /// Result of startup cleanup operation.
@@ -127,3 +188,33 @@ pub struct StartupCleanupResult {
     pub temp_files_cleaned: u32,
     pub thumbnails_regenerated: u32,
 }
+
+/// Library health report from `verify_library`.
+#[derive(Debug, Default, Serialize, TS)]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct LibraryReport {
+    /// Number of projects and media files scanned.
+    pub items_scanned: u32,
+    /// Thumbnail files with no matching project or media file.
+    pub orphaned_thumbnails: Vec<String>,
+    /// IDs of screenshot projects whose original image is missing.
+    pub missing_originals: Vec<String>,
+    /// project.json files that could not be read or parsed.
+    pub unparseable_projects: Vec<String>,
+    /// Video project folders that have a project.json but no screen.mp4.
+    pub missing_screen_videos: Vec<String>,
+    /// Leftover project.json.tmp files from interrupted writes.
+    pub temp_project_files: Vec<String>,
+    /// IDs of library items without a thumbnail.
+    pub missing_thumbnails: Vec<String>,
+}
+
+/// Result of `repair_library`.
+#[derive(Debug, Default, Serialize, TS)]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct LibraryRepairResult {
+    pub thumbnails_removed: u32,
+    pub projects_restored: u32,
+    pub temp_files_removed: u32,
+    pub thumbnails_regenerated: u32,
+}
@@ -0,0 +1,198 @@
+//! Uploading captures to a sharing backend.
+//!
+//! Each backend implements [`UploadBackend`]; [`UploadProvider`] picks one and
+//! loads its configuration from the user's settings. The file is streamed to
+//! the backend with `upload-progress` events, and the URL it can be shared at
+//! is copied to the clipboard and returned.
+//!
+//! To add a backend, add an `UploadProvider` variant and a module implementing
+//! `UploadBackend` for its settings.
+
+mod s3;
+
+use std::path::PathBuf;
+
+use futures::future::BoxFuture;
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Emitter};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tokio_util::io::ReaderStream;
+use ts_rs::TS;
+
+use super::storage::operations::determine_capture_type;
+
+/// Largest chunk the file is streamed in (one progress event each).
+const UPLOAD_CHUNK_SIZE: usize = 256 * 1024;
+
+/// Backend a capture is uploaded to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub enum UploadProvider {
+    /// Amazon S3 or any S3-compatible store (`general.s3Upload` settings).
+    S3,
+}
+
+impl UploadProvider {
+    /// The backend with its configuration from settings.
+    fn backend(self, app: &AppHandle) -> Result<Box<dyn UploadBackend>, String> {
+        match self {
+            Self::S3 => Ok(Box::new(s3::S3Config::from_settings(app)?)),
+        }
+    }
+}
+
+/// Progress event sent while a capture uploads.
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct UploadProgress {
+    pub project_id: String,
+    #[ts(type = "number")]
+    pub uploaded_bytes: u64,
+    #[ts(type = "number")]
+    pub total_bytes: u64,
+}
+
+/// File being uploaded.
+pub struct UploadFile {
+    /// File name, unique enough to be used as the object name.
+    pub name: String,
+    pub content_type: &'static str,
+    pub len: u64,
+}
+
+/// A place captures can be shared from.
+pub trait UploadBackend: Send + Sync {
+    /// Upload `body` as `file` and return the URL it can be shared at.
+    fn upload<'a>(
+        &'a self,
+        client: &'a reqwest::Client,
+        file: &'a UploadFile,
+        body: reqwest::Body,
+    ) -> BoxFuture<'a, Result<String, String>>;
+}
+
+/// Upload a capture and copy its shareable URL to the clipboard.
+///
+/// `file_path` is the rendered export to upload (e.g. an annotated image);
+/// without it the capture's own file is uploaded. Returns the URL.
+#[command]
+pub async fn upload_capture(
+    app: AppHandle,
+    project_id: String,
+    provider: UploadProvider,
+    file_path: Option<String>,
+) -> Result<String, String> {
+    let path = match file_path {
+        Some(path) => PathBuf::from(path),
+        None => match determine_capture_type(&app, &project_id)? {
+            (kind, _) if kind == "video_folder" => {
+                return Err("Export the video before uploading it".to_string())
+            },
+            (_, Some(path)) => path,
+            (_, None) => return Err("Capture not found".to_string()),
+        },
+    };
+    let backend = provider.backend(&app)?;
+
+    let reader = tokio::fs::File::open(&path)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let len = reader
+        .metadata()
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?
+        .len();
+    let file = UploadFile {
+        name: upload_file_name(&project_id, &path),
+        content_type: content_type(&path),
+        len,
+    };
+    log::info!(
+        "[UPLOAD] Uploading {} ({} bytes) to {:?}",
+        file.name,
+        file.len,
+        provider
+    );
+
+    let total_bytes = file.len;
+    let progress_app = app.clone();
+    let progress_id = project_id.clone();
+    let mut uploaded_bytes = 0u64;
+    // Read from disk as the request is sent, so large videos aren't held in memory
+    let stream = ReaderStream::with_capacity(reader, UPLOAD_CHUNK_SIZE).inspect(move |chunk| {
+        if let Ok(chunk) = chunk {
+            uploaded_bytes += chunk.len() as u64;
+            let _ = progress_app.emit(
+                "upload-progress",
+                UploadProgress {
+                    project_id: progress_id.clone(),
+                    uploaded_bytes,
+                    total_bytes,
+                },
+            );
+        }
+    });
+
+    let client = reqwest::Client::new();
+    let url = backend
+        .upload(&client, &file, reqwest::Body::wrap_stream(stream))
+        .await?;
+
+    if let Err(e) = app.clipboard().write_text(url.clone()) {
+        log::warn!("[UPLOAD] Failed to copy URL to clipboard: {}", e);
+    }
+    log::info!("[UPLOAD] Uploaded {} to {}", file.name, url);
+    Ok(url)
+}
+
+/// Object name for an upload: the project ID keeps names unique, the file
+/// name keeps the extension (and so the type) recognisable.
+fn upload_file_name(project_id: &str, path: &std::path::Path) -> String {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().replace(char::is_whitespace, "_"))
+        .unwrap_or_default();
+    format!("{}-{}", project_id, file_name)
+}
+
+/// MIME type of a capture file, by extension.
+fn content_type(path: &std::path::Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "webp" => "image/webp",
+        "gif" => "image/gif",
+        "bmp" => "image/bmp",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn test_upload_file_name_keeps_extension() {
+        assert_eq!(
+            upload_file_name("abc123", Path::new("/captures/2024-01-01 shot.png")),
+            "abc123-2024-01-01_shot.png"
+        );
+        assert_eq!(content_type(Path::new("/captures/clip.MP4")), "video/mp4");
+        assert_eq!(
+            content_type(Path::new("/captures/data.bin")),
+            "application/octet-stream"
+        );
+    }
+}
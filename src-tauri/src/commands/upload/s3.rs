@@ -0,0 +1,276 @@
+//! S3-compatible upload backend.
+//!
+//! Objects are PUT path-style (`{endpoint}/{bucket}/{key}`), which Amazon S3,
+//! Cloudflare R2, MinIO and Backblaze B2 all accept, and signed with AWS
+//! Signature Version 4. The payload is streamed, so it is sent unsigned
+//! (`UNSIGNED-PAYLOAD`) rather than hashed up front.
+
+use std::fs;
+
+use chrono::Utc;
+use futures::future::BoxFuture;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tauri::AppHandle;
+
+use super::{UploadBackend, UploadFile};
+use crate::commands::storage::get_app_data_dir;
+
+/// Payload hash used when the body isn't signed.
+const UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+
+/// Region used when none is configured (also what R2 and MinIO expect).
+const DEFAULT_REGION: &str = "us-east-1";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// S3 upload settings (`general.s3Upload`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct S3Config {
+    /// e.g. `https://s3.us-east-1.amazonaws.com` or an R2/MinIO endpoint.
+    pub endpoint: String,
+    pub region: String,
+    pub bucket: String,
+    pub access_key_id: String,
+    /// Stored in plain text in settings.json; the settings UI says so.
+    pub secret_access_key: String,
+    /// Public base URL of the bucket (e.g. a CDN or custom domain). Empty
+    /// shares the endpoint URL, which needs a publicly readable bucket.
+    pub public_url: String,
+}
+
+impl S3Config {
+    /// Load the settings, failing if the required fields are missing.
+    pub fn from_settings(app: &AppHandle) -> Result<Self, String> {
+        let content = fs::read_to_string(get_app_data_dir(app)?.join("settings.json"))
+            .map_err(|_| "S3 upload is not configured".to_string())?;
+        let settings = serde_json::from_str::<serde_json::Value>(&content)
+            .map_err(|e| format!("Failed to parse settings: {}", e))?;
+
+        let config = settings
+            .get("general")
+            .and_then(|general| general.get("s3Upload"))
+            .cloned()
+            .map(serde_json::from_value::<Self>)
+            .transpose()
+            .map_err(|e| format!("Invalid S3 upload settings: {}", e))?
+            .unwrap_or_default();
+
+        if config.endpoint.trim().is_empty()
+            || config.bucket.trim().is_empty()
+            || config.access_key_id.trim().is_empty()
+            || config.secret_access_key.is_empty()
+        {
+            return Err("S3 upload is not configured".to_string());
+        }
+        Ok(config)
+    }
+
+    fn region(&self) -> &str {
+        match self.region.trim() {
+            "" => DEFAULT_REGION,
+            region => region,
+        }
+    }
+
+    /// Path of `key` in the bucket, URI-encoded as SigV4 expects.
+    fn object_path(&self, key: &str) -> String {
+        format!(
+            "/{}/{}",
+            uri_encode(self.bucket.trim()),
+            key.split('/').map(uri_encode).collect::<Vec<_>>().join("/")
+        )
+    }
+
+    /// Path of `key` on the server: `object_path` under the endpoint's own
+    /// path, if any (e.g. a reverse-proxied MinIO at `https://host/minio`).
+    fn request_path(&self, endpoint: &reqwest::Url, key: &str) -> String {
+        format!(
+            "{}{}",
+            endpoint.path().trim_end_matches('/'),
+            self.object_path(key)
+        )
+    }
+
+    /// URL the uploaded object is shared at.
+    fn public_object_url(&self, key: &str) -> String {
+        match self.public_url.trim().trim_end_matches('/') {
+            "" => format!(
+                "{}{}",
+                self.endpoint.trim().trim_end_matches('/'),
+                self.object_path(key)
+            ),
+            base => format!("{}/{}", base, uri_encode(key)),
+        }
+    }
+
+    /// `Authorization` header for a PUT of `path` on `host` at `amz_date`.
+    fn authorization(&self, host: &str, path: &str, amz_date: &str) -> String {
+        let date = &amz_date[..8];
+        let scope = format!("{}/{}/s3/aws4_request", date, self.region());
+        let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+
+        let canonical_request = format!(
+            "PUT\n{}\n\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+            path, host, UNSIGNED_PAYLOAD, amz_date, signed_headers, UNSIGNED_PAYLOAD
+        );
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{:x}",
+            amz_date,
+            scope,
+            Sha256::digest(canonical_request.as_bytes())
+        );
+
+        let key = signing_key(&self.secret_access_key, date, self.region(), "s3");
+        let signature = to_hex(&hmac_sha256(&key, string_to_sign.as_bytes()));
+
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.access_key_id.trim(),
+            scope,
+            signed_headers,
+            signature
+        )
+    }
+}
+
+impl UploadBackend for S3Config {
+    fn upload<'a>(
+        &'a self,
+        client: &'a reqwest::Client,
+        file: &'a UploadFile,
+        body: reqwest::Body,
+    ) -> BoxFuture<'a, Result<String, String>> {
+        Box::pin(async move {
+            let endpoint = reqwest::Url::parse(self.endpoint.trim())
+                .map_err(|e| format!("Invalid S3 endpoint: {}", e))?;
+            let host = match (endpoint.host_str(), endpoint.port()) {
+                (Some(host), Some(port)) => format!("{}:{}", host, port),
+                (Some(host), None) => host.to_string(),
+                (None, _) => return Err("Invalid S3 endpoint: missing host".to_string()),
+            };
+
+            let path = self.request_path(&endpoint, &file.name);
+            let amz_date = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+            let url = format!("{}://{}{}", endpoint.scheme(), host, path);
+
+            let response = client
+                .put(&url)
+                .header("authorization", self.authorization(&host, &path, &amz_date))
+                .header("x-amz-content-sha256", UNSIGNED_PAYLOAD)
+                .header("x-amz-date", &amz_date)
+                .header("content-type", file.content_type)
+                .header("content-length", file.len)
+                .body(body)
+                .send()
+                .await
+                .map_err(|e| format!("Upload failed: {}", e))?;
+
+            let status = response.status();
+            if !status.is_success() {
+                let detail = response.text().await.unwrap_or_default();
+                return Err(format!("Upload failed ({}): {}", status, detail.trim()));
+            }
+
+            Ok(self.public_object_url(&file.name))
+        })
+    }
+}
+
+/// SigV4 key for `date` (YYYYMMDD), `region` and `service`.
+fn signing_key(secret: &str, date: &str, region: &str, service: &str) -> [u8; 32] {
+    let key = hmac_sha256(format!("AWS4{}", secret).as_bytes(), date.as_bytes());
+    let key = hmac_sha256(&key, region.as_bytes());
+    let key = hmac_sha256(&key, service.as_bytes());
+    hmac_sha256(&key, b"aws4_request")
+}
+
+/// HMAC-SHA256 (RFC 2104).
+fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().into()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Percent-encode everything but RFC 3986 unreserved characters.
+fn uri_encode(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            },
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hmac_sha256_rfc4231() {
+        assert_eq!(
+            to_hex(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_signing_key_matches_aws_example() {
+        assert_eq!(
+            to_hex(&signing_key(
+                "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+                "20120215",
+                "us-east-1",
+                "iam"
+            )),
+            "f4780e2d9f65fa895f9c67b32ce1baf0b0d8a43505a000a1a9e090d414db404d"
+        );
+    }
+
+    #[test]
+    fn test_public_object_url() {
+        let mut config = S3Config {
+            endpoint: "https://minio.local:9000/".to_string(),
+            bucket: "shots".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.public_object_url("a b.png"),
+            "https://minio.local:9000/shots/a%20b.png"
+        );
+
+        config.public_url = "https://cdn.example.com/".to_string();
+        assert_eq!(
+            config.public_object_url("a b.png"),
+            "https://cdn.example.com/a%20b.png"
+        );
+    }
+
+    #[test]
+    fn test_endpoint_path_prefix_is_kept() {
+        let config = S3Config {
+            endpoint: "https://example.com/minio/".to_string(),
+            bucket: "shots".to_string(),
+            ..Default::default()
+        };
+        let endpoint = reqwest::Url::parse(&config.endpoint).unwrap();
+
+        assert_eq!(
+            config.request_path(&endpoint, "a b.png"),
+            "/minio/shots/a%20b.png"
+        );
+        assert_eq!(
+            config.public_object_url("a b.png"),
+            "https://example.com/minio/shots/a%20b.png"
+        );
+    }
+}
@@ -0,0 +1,179 @@
+//! Sample processing for captured microphone audio.
+//!
+//! Applied to interleaved f32 samples in the WASAPI capture threads, before
+//! they reach the WAV writer or the level meters.
+
+use super::types::MicChannelMode;
+
+/// Convert decibels to a linear amplitude factor.
+pub fn db_to_linear(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
+
+/// Rewrite interleaved stereo frames according to `mode`, so a mic that only
+/// delivers on one side doesn't play back half-silent. Non-stereo input is
+/// left untouched.
+pub fn apply_channel_mode(samples: &mut [f32], channels: u16, mode: MicChannelMode) {
+    if channels != 2 {
+        return;
+    }
+
+    for frame in samples.chunks_exact_mut(2) {
+        let value = match mode {
+            MicChannelMode::Stereo => continue,
+            MicChannelMode::MonoLeft => frame[0],
+            MicChannelMode::MonoRight => frame[1],
+            MicChannelMode::MonoMix => (frame[0] + frame[1]) * 0.5,
+        };
+        frame[0] = value;
+        frame[1] = value;
+    }
+}
+
+/// Scale samples by `gain_db`, hard-clipping to [-1.0, 1.0] so boosted peaks
+/// don't wrap or exceed full scale in the WAV file.
+pub fn apply_gain(samples: &mut [f32], gain_db: f32) {
+    if gain_db == 0.0 {
+        return;
+    }
+
+    let gain = db_to_linear(gain_db);
+    for sample in samples.iter_mut() {
+        *sample = (*sample * gain).clamp(-1.0, 1.0);
+    }
+}
+
+/// How fast the gate opens once speech crosses the threshold.
+const NOISE_GATE_ATTACK_MS: f32 = 5.0;
+
+/// How long the gate takes to close after the signal drops below the threshold.
+/// Long enough to keep word endings and short pauses from sounding choppy.
+const NOISE_GATE_RELEASE_MS: f32 = 150.0;
+
+/// Silences audio whose RMS level is below a threshold.
+///
+/// The open/closed decision is made per buffer from its RMS, then applied
+/// through a per-frame gain envelope so the gate fades in and out instead of
+/// clicking.
+pub struct NoiseGate {
+    /// Linear RMS level the buffer must reach to open the gate.
+    threshold: f32,
+    /// Per-frame smoothing factor while opening.
+    attack_coeff: f32,
+    /// Per-frame smoothing factor while closing.
+    release_coeff: f32,
+    /// Interleaved channels per frame.
+    channels: usize,
+    /// Current gain applied to samples (0 = closed, 1 = open).
+    gain: f32,
+}
+
+impl NoiseGate {
+    pub fn new(threshold_db: f32, sample_rate: u32, channels: u16) -> Self {
+        Self {
+            threshold: db_to_linear(threshold_db),
+            attack_coeff: envelope_coeff(NOISE_GATE_ATTACK_MS, sample_rate),
+            release_coeff: envelope_coeff(NOISE_GATE_RELEASE_MS, sample_rate),
+            channels: channels.max(1) as usize,
+            gain: 0.0,
+        }
+    }
+
+    /// Gate a buffer of interleaved samples in place.
+    pub fn process(&mut self, samples: &mut [f32]) {
+        if samples.is_empty() {
+            return;
+        }
+
+        let rms = (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt();
+        let (target, coeff) = if rms >= self.threshold {
+            (1.0, self.attack_coeff)
+        } else {
+            (0.0, self.release_coeff)
+        };
+
+        for frame in samples.chunks_mut(self.channels) {
+            self.gain += (target - self.gain) * coeff;
+            for sample in frame {
+                *sample *= self.gain;
+            }
+        }
+    }
+}
+
+/// One-pole smoothing factor that covers ~63% of a step in `time_ms`.
+fn envelope_coeff(time_ms: f32, sample_rate: u32) -> f32 {
+    let frames = (time_ms / 1000.0 * sample_rate as f32).max(1.0);
+    1.0 - (-1.0 / frames).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_db_to_linear() {
+        assert!((db_to_linear(0.0) - 1.0).abs() < 1e-6);
+        assert!((db_to_linear(20.0) - 10.0).abs() < 1e-4);
+        assert!((db_to_linear(-20.0) - 0.1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_apply_gain_clips() {
+        let mut samples = [0.05, -0.05, 0.5, -0.5];
+        apply_gain(&mut samples, 20.0);
+        assert!((samples[0] - 0.5).abs() < 1e-4);
+        assert!((samples[1] + 0.5).abs() < 1e-4);
+        assert_eq!(samples[2], 1.0);
+        assert_eq!(samples[3], -1.0);
+    }
+
+    #[test]
+    fn test_apply_channel_mode() {
+        // Left-only interface input: L carries signal, R is silent
+        let input = [0.4, 0.0, -0.2, 0.0];
+
+        let mut stereo = input;
+        apply_channel_mode(&mut stereo, 2, MicChannelMode::Stereo);
+        assert_eq!(stereo, input);
+
+        let mut left = input;
+        apply_channel_mode(&mut left, 2, MicChannelMode::MonoLeft);
+        assert_eq!(left, [0.4, 0.4, -0.2, -0.2]);
+
+        let mut right = input;
+        apply_channel_mode(&mut right, 2, MicChannelMode::MonoRight);
+        assert_eq!(right, [0.0, 0.0, 0.0, 0.0]);
+
+        let mut mix = input;
+        apply_channel_mode(&mut mix, 2, MicChannelMode::MonoMix);
+        assert_eq!(mix, [0.2, 0.2, -0.1, -0.1]);
+    }
+
+    #[test]
+    fn test_noise_gate_silences_quiet_input() {
+        let mut gate = NoiseGate::new(-40.0, 48000, 2);
+
+        // -60 dB hiss stays closed
+        let mut hiss = vec![0.001; 4800];
+        gate.process(&mut hiss);
+        assert!(hiss.iter().all(|s| s.abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_noise_gate_fades_in_and_out() {
+        let mut gate = NoiseGate::new(-40.0, 48000, 2);
+
+        // Speech opens the gate gradually, then passes through
+        let mut speech = vec![0.5; 4800];
+        gate.process(&mut speech);
+        assert!(speech[0] < 0.01);
+        assert!((speech[4799] - 0.5).abs() < 1e-3);
+
+        // After speech stops, the tail fades out rather than cutting off
+        let mut tail = vec![0.001; 4800];
+        gate.process(&mut tail);
+        assert!(tail[0] > 0.0009);
+        assert!(tail[4799] < tail[0]);
+    }
+}
@@ -32,6 +32,9 @@ use tauri::{AppHandle, Emitter};
 use ts_rs::TS;
 use wasapi::*;
 
+use super::audio_dsp::{apply_gain, NoiseGate};
+use crate::config::recording::get_microphone_gain_db;
+
 /// Audio levels payload emitted to frontend.
 #[derive(Debug, Clone, Serialize, TS)]
 #[serde(rename_all = "camelCase")]
@@ -120,6 +123,7 @@ fn monitor_microphone(
     should_stop: Arc<AtomicBool>,
     level: Arc<Mutex<f32>>,
     is_active: Arc<AtomicBool>,
+    noise_gate_threshold_db: Option<f32>,
 ) {
     // Initialize COM for this thread (HRESULT.ok() converts to Result)
     if let Err(e) = initialize_mta().ok() {
@@ -228,6 +232,8 @@ fn monitor_microphone(
 
     // Capture buffer
     let mut sample_queue: VecDeque<u8> = VecDeque::with_capacity(48000 * 4);
+    let mut noise_gate =
+        noise_gate_threshold_db.map(|threshold_db| NoiseGate::new(threshold_db, 48000, 2));
 
     // Monitoring loop
     while !should_stop.load(Ordering::Relaxed) {
@@ -239,7 +245,12 @@ fn monitor_microphone(
         // Read audio data
         if let Ok(_) = capture_client.read_from_device_to_deque(&mut sample_queue) {
             if sample_queue.len() >= 4 {
-                let samples = bytes_to_f32_samples(&sample_queue);
+                // Meter the post-gain, post-gate signal so it matches what gets recorded
+                let mut samples = bytes_to_f32_samples(&sample_queue);
+                apply_gain(&mut samples, get_microphone_gain_db());
+                if let Some(gate) = noise_gate.as_mut() {
+                    gate.process(&mut samples);
+                }
                 let rms = calculate_rms(&samples);
 
                 if let Ok(mut lvl) = level.lock() {
@@ -407,6 +418,7 @@ pub fn start_monitoring(
     app: AppHandle,
     mic_device_index: Option<usize>,
     enable_system_audio: bool,
+    noise_gate_threshold_db: Option<f32>,
 ) -> Result<(), String> {
     let mut state = AUDIO_MONITOR.lock().map_err(|e| e.to_string())?;
 
@@ -441,7 +453,7 @@ pub fn start_monitoring(
         let active = Arc::clone(&mic_active);
 
         state.mic_thread = Some(thread::spawn(move || {
-            monitor_microphone(device_index, stop, level, active);
+            monitor_microphone(device_index, stop, level, active, noise_gate_threshold_db);
         }));
     }
 
@@ -44,6 +44,10 @@ use crossbeam_channel::{bounded, Sender};
 use hound::{WavSpec, WavWriter};
 use wasapi::*;
 
+use super::audio_dsp::{apply_channel_mode, apply_gain, NoiseGate};
+use super::types::MicChannelMode;
+use crate::config::recording::get_microphone_gain_db;
+
 /// Audio format configuration.
 const SAMPLE_RATE: u32 = 48000;
 const CHANNELS: u16 = 2;
@@ -71,6 +75,10 @@ pub struct MultiTrackAudioRecorder {
     system_audio_path: Option<PathBuf>,
     /// Path to microphone WAV file.
     mic_audio_path: Option<PathBuf>,
+    /// Noise gate threshold (dBFS) for the microphone. None = gate off.
+    noise_gate_threshold_db: Option<f32>,
+    /// How microphone channels are mixed into the track.
+    mic_channel_mode: MicChannelMode,
 }
 
 impl MultiTrackAudioRecorder {
@@ -83,6 +91,8 @@ impl MultiTrackAudioRecorder {
             is_paused: Arc::new(AtomicBool::new(false)),
             system_audio_path: None,
             mic_audio_path: None,
+            noise_gate_threshold_db: None,
+            mic_channel_mode: MicChannelMode::default(),
         }
     }
 
@@ -97,9 +107,23 @@ impl MultiTrackAudioRecorder {
             is_paused,
             system_audio_path: None,
             mic_audio_path: None,
+            noise_gate_threshold_db: None,
+            mic_channel_mode: MicChannelMode::default(),
         }
     }
 
+    /// Gate the microphone track below `threshold_db` (None to record it ungated).
+    pub fn with_noise_gate(mut self, threshold_db: Option<f32>) -> Self {
+        self.noise_gate_threshold_db = threshold_db;
+        self
+    }
+
+    /// Mix microphone channels according to `mode` before writing the track.
+    pub fn with_mic_channel_mode(mut self, mode: MicChannelMode) -> Self {
+        self.mic_channel_mode = mode;
+        self
+    }
+
     /// Start recording audio to the specified files.
     ///
     /// # Arguments
@@ -142,9 +166,18 @@ impl MultiTrackAudioRecorder {
             let should_stop = Arc::clone(&self.should_stop);
             let is_paused = Arc::clone(&self.is_paused);
             let path_clone = path.clone();
+            let noise_gate_threshold_db = self.noise_gate_threshold_db;
+            let mic_channel_mode = self.mic_channel_mode;
 
             let handle = thread::spawn(move || {
-                record_microphone(&path_clone, should_stop, is_paused, start_time)
+                record_microphone(
+                    &path_clone,
+                    should_stop,
+                    is_paused,
+                    start_time,
+                    noise_gate_threshold_db,
+                    mic_channel_mode,
+                )
             });
 
             self.mic_thread = Some(handle);
@@ -449,11 +482,16 @@ fn record_microphone(
     should_stop: Arc<AtomicBool>,
     is_paused: Arc<AtomicBool>,
     _start_time: Instant,
+    noise_gate_threshold_db: Option<f32>,
+    mic_channel_mode: MicChannelMode,
 ) -> Result<(), String> {
     // Spawn async writer thread first
     let (sample_tx, writer_handle) =
         spawn_wav_writer(output_path.clone(), Arc::clone(&should_stop), "microphone")?;
 
+    let mut noise_gate = noise_gate_threshold_db
+        .map(|threshold_db| NoiseGate::new(threshold_db, SAMPLE_RATE, CHANNELS));
+
     // Initialize COM for this thread
     initialize_mta()
         .ok()
@@ -546,8 +584,13 @@ fn record_microphone(
             .is_ok()
             && sample_queue.len() >= 4
         {
-            // Convert to f32 samples
-            let samples = bytes_to_f32_samples(&sample_queue);
+            // Convert to f32 samples, fix up channels, boost by the user's mic gain, then gate
+            let mut samples = bytes_to_f32_samples(&sample_queue);
+            apply_channel_mode(&mut samples, CHANNELS, mic_channel_mode);
+            apply_gain(&mut samples, get_microphone_gain_db());
+            if let Some(gate) = noise_gate.as_mut() {
+                gate.process(&mut samples);
+            }
             captured_samples += samples.len() as u64;
             sample_queue.clear();
 
@@ -0,0 +1,97 @@
+//! Waveform peaks for the editor timeline's audio lane.
+//!
+//! Reads the recorded WAV tracks (`system.wav`, `mic.wav`) sample by sample, so
+//! long recordings never have to fit in memory.
+
+use std::path::Path;
+
+use hound::{SampleFormat, WavReader};
+
+/// Read a WAV file and reduce it to `buckets` (min, max) pairs.
+///
+/// Returns `2 * buckets` values laid out as `[min0, max0, min1, max1, ...]`,
+/// each in -1.0..=1.0 with all channels folded together. Buckets past the end
+/// of a short file stay at 0.0.
+pub fn wav_peaks(path: &Path, buckets: usize) -> Result<Vec<f32>, String> {
+    let mut reader =
+        WavReader::open(path).map_err(|e| format!("Failed to open WAV {:?}: {}", path, e))?;
+    let spec = reader.spec();
+    let channels = spec.channels.max(1) as u64;
+    let total_frames = reader.duration() as u64;
+
+    let mut peaks = vec![0.0f32; buckets * 2];
+    if buckets == 0 || total_frames == 0 {
+        return Ok(peaks);
+    }
+
+    let mut fold = |index: u64, sample: f32| {
+        let frame = index / channels;
+        let bucket = ((frame * buckets as u64) / total_frames).min(buckets as u64 - 1) as usize;
+        peaks[bucket * 2] = peaks[bucket * 2].min(sample);
+        peaks[bucket * 2 + 1] = peaks[bucket * 2 + 1].max(sample);
+    };
+
+    match spec.sample_format {
+        SampleFormat::Float => {
+            for (index, sample) in reader.samples::<f32>().enumerate() {
+                let sample = sample.map_err(|e| format!("Failed to read WAV: {}", e))?;
+                fold(index as u64, sample.clamp(-1.0, 1.0));
+            }
+        },
+        SampleFormat::Int => {
+            let full_scale = (1i64 << (spec.bits_per_sample.max(1) - 1)) as f32;
+            for (index, sample) in reader.samples::<i32>().enumerate() {
+                let sample = sample.map_err(|e| format!("Failed to read WAV: {}", e))?;
+                fold(index as u64, (sample as f32 / full_scale).clamp(-1.0, 1.0));
+            }
+        },
+    }
+
+    Ok(peaks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hound::{WavSpec, WavWriter};
+
+    fn write_wav(name: &str, samples: &[f32]) -> std::path::PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("snapit_peaks_{}_{}.wav", name, std::process::id()));
+        let spec = WavSpec {
+            channels: 2,
+            sample_rate: 48000,
+            bits_per_sample: 32,
+            sample_format: SampleFormat::Float,
+        };
+        let mut writer = WavWriter::create(&path, spec).unwrap();
+        for &sample in samples {
+            writer.write_sample(sample).unwrap();
+        }
+        writer.finalize().unwrap();
+        path
+    }
+
+    #[test]
+    fn test_wav_peaks_min_max_per_bucket() {
+        // 4 stereo frames: loud first half, quiet second half
+        let path = write_wav("buckets", &[0.5, -0.8, 0.2, 0.1, 0.05, -0.05, 0.0, 0.02]);
+
+        let peaks = wav_peaks(&path, 2).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(peaks, vec![-0.8, 0.5, -0.05, 0.05]);
+    }
+
+    #[test]
+    fn test_wav_peaks_more_buckets_than_frames() {
+        let path = write_wav("short", &[0.5, 0.5]);
+
+        let peaks = wav_peaks(&path, 4).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(peaks.len(), 8);
+        assert_eq!(peaks[1], 0.5);
+        assert!(peaks[2..].iter().all(|&p| p == 0.0));
+    }
+}
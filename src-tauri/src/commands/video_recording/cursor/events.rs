@@ -14,7 +14,7 @@
 use device_query::{DeviceQuery, DeviceState};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
 use std::thread::{self, JoinHandle};
 use std::time::{Duration, Instant};
@@ -344,6 +344,38 @@ impl CaptureRegion {
     }
 }
 
+/// Capture region shared with the capture threads.
+///
+/// The origin can move mid-recording, for window-region recordings that follow
+/// a window.
+#[derive(Debug)]
+struct SharedCaptureRegion {
+    x: AtomicI32,
+    y: AtomicI32,
+    width: u32,
+    height: u32,
+}
+
+impl SharedCaptureRegion {
+    fn new(region: CaptureRegion) -> Self {
+        Self {
+            x: AtomicI32::new(region.x),
+            y: AtomicI32::new(region.y),
+            width: region.width,
+            height: region.height,
+        }
+    }
+
+    fn get(&self) -> CaptureRegion {
+        CaptureRegion {
+            x: self.x.load(Ordering::Relaxed),
+            y: self.y.load(Ordering::Relaxed),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
 /// Manages cursor event capture in a background thread.
 ///
 /// Captures:
@@ -365,7 +397,7 @@ pub struct CursorEventCapture {
     /// Mouse hook thread handle.
     hook_thread: Option<JoinHandle<()>>,
     /// Capture region for coordinate normalization.
-    capture_region: CaptureRegion,
+    capture_region: Arc<SharedCaptureRegion>,
     /// Video start offset in ms (time of first video frame relative to start_time).
     /// Used to sync cursor timestamps with video during playback.
     video_start_offset_ms: u64,
@@ -386,12 +418,12 @@ impl CursorEventCapture {
             start_time: None,
             position_thread: None,
             hook_thread: None,
-            capture_region: CaptureRegion {
+            capture_region: Arc::new(SharedCaptureRegion::new(CaptureRegion {
                 x: 0,
                 y: 0,
                 width: 1920,
                 height: 1080,
-            },
+            })),
             video_start_offset_ms: 0,
         }
     }
@@ -403,6 +435,15 @@ impl CursorEventCapture {
         log::debug!("[CURSOR_EVENTS] Video start offset set to {}ms", offset_ms);
     }
 
+    /// Move the capture region's top-left to new screen coordinates.
+    ///
+    /// Events recorded after this are normalized against the moved region, so
+    /// cursor data follows a window-region recording as the window moves.
+    pub fn move_region(&self, x: i32, y: i32) {
+        self.capture_region.x.store(x, Ordering::Relaxed);
+        self.capture_region.y.store(y, Ordering::Relaxed);
+    }
+
     /// Start capturing cursor events.
     ///
     /// # Arguments
@@ -433,7 +474,7 @@ impl CursorEventCapture {
         self.start_time = Some(start_time);
 
         // Set capture region (use fullscreen if not specified)
-        self.capture_region = if let Some((x, y, w, h)) = region {
+        let capture_region = if let Some((x, y, w, h)) = region {
             log::info!(
                 "[CURSOR_EVENTS] Using provided region: origin=({}, {}) size={}x{} - cursor positions will be normalized relative to this",
                 x,
@@ -465,6 +506,7 @@ impl CursorEventCapture {
                 height: h,
             }
         };
+        self.capture_region = Arc::new(SharedCaptureRegion::new(capture_region));
 
         // Clear previous data
         if let Ok(mut data) = self.data.lock() {
@@ -478,7 +520,7 @@ impl CursorEventCapture {
         // Start position capture thread (100Hz polling) - also captures cursor images
         let data_clone = Arc::clone(&self.data);
         let should_stop_clone = Arc::clone(&self.should_stop);
-        let capture_region = self.capture_region;
+        let capture_region = Arc::clone(&self.capture_region);
         let start_time = self.start_time.unwrap();
 
         self.position_thread = Some(
@@ -498,7 +540,7 @@ impl CursorEventCapture {
         // Start mouse hook thread (for click events)
         let data_clone = Arc::clone(&self.data);
         let should_stop_clone = Arc::clone(&self.should_stop);
-        let capture_region = self.capture_region;
+        let capture_region = Arc::clone(&self.capture_region);
         let start_time = self.start_time.unwrap();
 
         self.hook_thread = Some(
@@ -512,10 +554,10 @@ impl CursorEventCapture {
 
         log::info!(
             "[CURSOR_EVENTS] Started capture (region: {}x{} at ({}, {}))",
-            self.capture_region.width,
-            self.capture_region.height,
-            self.capture_region.x,
-            self.capture_region.y
+            capture_region.width,
+            capture_region.height,
+            capture_region.x,
+            capture_region.y
         );
 
         Ok(())
@@ -540,22 +582,23 @@ impl CursorEventCapture {
             .map(|d| (d.events.clone(), d.cursor_images.clone()))
             .unwrap_or_default();
 
+        let capture_region = self.capture_region.get();
         log::info!(
             "[CURSOR_EVENTS] Stopped capture, collected {} events, {} cursor images, region=({}, {}) {}x{}",
             events.len(),
             cursor_images.len(),
-            self.capture_region.x,
-            self.capture_region.y,
-            self.capture_region.width,
-            self.capture_region.height
+            capture_region.x,
+            capture_region.y,
+            capture_region.width,
+            capture_region.height
         );
 
         CursorRecording {
             sample_rate: 100,
-            width: self.capture_region.width,
-            height: self.capture_region.height,
-            region_x: self.capture_region.x,
-            region_y: self.capture_region.y,
+            width: capture_region.width,
+            height: capture_region.height,
+            region_x: capture_region.x,
+            region_y: capture_region.y,
             video_start_offset_ms: self.video_start_offset_ms,
             events,
             cursor_images,
@@ -1045,7 +1088,7 @@ fn run_position_capture_loop(
     data: Arc<Mutex<SharedCursorData>>,
     should_stop: Arc<AtomicBool>,
     start_time: Instant,
-    region: CaptureRegion,
+    shared_region: Arc<SharedCaptureRegion>,
 ) {
     let interval = Duration::from_millis(10); // 100Hz
 
@@ -1077,6 +1120,7 @@ fn run_position_capture_loop(
     let mut current_cursor_id: Option<String> = initial_cursor_id;
 
     // Record initial position with cursor_id (normalized)
+    let region = shared_region.get();
     let (norm_x, norm_y) = region.normalize(init_x, init_y);
 
     log::info!(
@@ -1133,6 +1177,7 @@ fn run_position_capture_loop(
             let timestamp_ms = start_time.elapsed().as_millis() as u64;
 
             // Normalize to 0.0-1.0 relative to capture region
+            let region = shared_region.get();
             let (norm_x, norm_y) = region.normalize(x, y);
 
             // Debug: Log first few cursor events to verify normalization
@@ -1178,7 +1223,7 @@ fn run_mouse_hook_loop(
     data: Arc<Mutex<SharedCursorData>>,
     should_stop: Arc<AtomicBool>,
     start_time: Instant,
-    region: CaptureRegion,
+    region: Arc<SharedCaptureRegion>,
 ) {
     use std::cell::RefCell;
     use windows::Win32::Foundation::{LPARAM, LRESULT, WPARAM};
@@ -1190,7 +1235,7 @@ fn run_mouse_hook_loop(
 
     // Thread-local storage for hook callback data (includes region for normalization)
     thread_local! {
-        static HOOK_DATA: RefCell<Option<(Arc<Mutex<SharedCursorData>>, Instant, CaptureRegion)>> = RefCell::new(None);
+        static HOOK_DATA: RefCell<Option<(Arc<Mutex<SharedCursorData>>, Instant, Arc<SharedCaptureRegion>)>> = RefCell::new(None);
     }
 
     // Set up thread-local data
@@ -1231,7 +1276,7 @@ fn run_mouse_hook_loop(
                         let timestamp_ms = start_time.elapsed().as_millis() as u64;
                         // Normalize click position
                         let (norm_x, norm_y) =
-                            region.normalize(mouse_struct.pt.x, mouse_struct.pt.y);
+                            region.get().normalize(mouse_struct.pt.x, mouse_struct.pt.y);
 
                         if let Ok(mut data_guard) = data.lock() {
                             data_guard.events.push(CursorEvent {
@@ -1293,7 +1338,7 @@ fn run_mouse_hook_loop(
     _data: Arc<Mutex<SharedCursorData>>,
     should_stop: Arc<AtomicBool>,
     _start_time: Instant,
-    _region: CaptureRegion,
+    _region: Arc<SharedCaptureRegion>,
 ) {
     // Non-Windows stub - just wait until stopped
     while !should_stop.load(Ordering::SeqCst) {
@@ -1404,4 +1449,16 @@ mod tests {
         let (x, _) = region.normalize(50, 50);
         assert!(x < 0.0);
     }
+
+    #[test]
+    fn test_move_region_renormalizes() {
+        let capture = CursorEventCapture::new();
+        capture.move_region(100, 50);
+
+        // Same screen point is at the region origin after the move
+        let (x, y) = capture.capture_region.get().normalize(100, 50);
+        assert!(x.abs() < 0.001);
+        assert!(y.abs() < 0.001);
+        assert_eq!(capture.capture_region.get().width, 1920);
+    }
 }
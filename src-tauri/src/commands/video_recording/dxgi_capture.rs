@@ -0,0 +1,460 @@
+//! DXGI Desktop Duplication capture for fullscreen-exclusive applications.
+//!
+//! Windows Graphics Capture (and GDI) return black frames while a DirectX game
+//! owns the output in fullscreen-exclusive mode. Desktop Duplication reads the
+//! scanout surface directly and keeps working in that case, so it is used as an
+//! alternate backend when exclusive fullscreen is detected on the target display.
+//!
+//! Frames are produced in the same `D3DFrame` format (BGRA, no stride) as the
+//! WGC path so the recorders don't need to know which backend is active.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    mpsc::{self, Receiver, SyncSender},
+    Arc,
+};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use scap_targets::Display;
+use windows::core::Interface;
+use windows::Win32::Foundation::{HMODULE, HWND};
+use windows::Win32::Graphics::Direct3D::D3D_DRIVER_TYPE_UNKNOWN;
+use windows::Win32::Graphics::Direct3D11::{
+    D3D11CreateDevice, ID3D11Device, ID3D11DeviceContext, ID3D11Texture2D, D3D11_CPU_ACCESS_READ,
+    D3D11_CREATE_DEVICE_BGRA_SUPPORT, D3D11_MAPPED_SUBRESOURCE, D3D11_MAP_READ, D3D11_SDK_VERSION,
+    D3D11_TEXTURE2D_DESC, D3D11_USAGE_STAGING,
+};
+use windows::Win32::Graphics::Dxgi::{
+    CreateDXGIFactory1, IDXGIAdapter1, IDXGIFactory1, IDXGIOutput1, IDXGIOutputDuplication,
+    IDXGIResource, DXGI_ERROR_ACCESS_LOST, DXGI_ERROR_NOT_FOUND, DXGI_ERROR_WAIT_TIMEOUT,
+    DXGI_OUTDUPL_FRAME_INFO,
+};
+use windows::Win32::Graphics::Gdi::{MonitorFromWindow, HMONITOR, MONITOR_DEFAULTTONULL};
+use windows::Win32::UI::Shell::{SHQueryUserNotificationState, QUNS_RUNNING_D3D_FULL_SCREEN};
+use windows::Win32::UI::WindowsAndMessaging::GetForegroundWindow;
+
+use super::d3d_capture::D3DFrame;
+use super::timestamp::PerformanceCounterTimestamp;
+
+/// How long a single `AcquireNextFrame` call blocks before re-checking the stop flag.
+const ACQUIRE_TIMEOUT_MS: u32 = 100;
+
+/// Maximum number of times the duplication is re-created after `ACCESS_LOST`
+/// (mode switches, alt-tab in/out of the game) before giving up.
+const MAX_REACQUIRE_ATTEMPTS: u32 = 10;
+
+/// Check whether a fullscreen-exclusive Direct3D application is running.
+///
+/// Uses the shell's notification state, which reports `QUNS_RUNNING_D3D_FULL_SCREEN`
+/// only for true exclusive mode (borderless-windowed games are reported as busy
+/// or normal and capture fine through WGC).
+pub fn is_fullscreen_exclusive_active() -> bool {
+    unsafe { SHQueryUserNotificationState() }
+        .map(|state| state == QUNS_RUNNING_D3D_FULL_SCREEN)
+        .unwrap_or(false)
+}
+
+/// Check whether a fullscreen-exclusive application owns the given display.
+///
+/// The shell state is global, so the foreground window's monitor is used to
+/// decide which display the exclusive application is on.
+pub fn is_fullscreen_exclusive_on_display(display_index: usize) -> bool {
+    if !is_fullscreen_exclusive_active() {
+        return false;
+    }
+
+    let displays = Display::list();
+    let Some(display) = displays.get(display_index) else {
+        return false;
+    };
+
+    let foreground_monitor = unsafe {
+        let hwnd: HWND = GetForegroundWindow();
+        if hwnd.0.is_null() {
+            return false;
+        }
+        MonitorFromWindow(hwnd, MONITOR_DEFAULTTONULL)
+    };
+
+    !foreground_monitor.is_invalid() && foreground_monitor == display.raw_handle().0
+}
+
+/// Desktop Duplication capture of a single display, with optional crop.
+///
+/// Mirrors the `D3DVideoCapture` interface so `CaptureSource` can switch
+/// between the two transparently.
+pub struct DxgiDuplicationCapture {
+    frame_rx: Receiver<D3DFrame>,
+    error_rx: Receiver<String>,
+    stop_flag: Arc<AtomicBool>,
+    width: u32,
+    height: u32,
+    pending: Option<DuplicationWorker>,
+    thread: Option<JoinHandle<()>>,
+}
+
+/// Resources moved onto the capture thread when `start()` is called.
+struct DuplicationWorker {
+    device: ID3D11Device,
+    context: ID3D11DeviceContext,
+    output: IDXGIOutput1,
+    duplication: IDXGIOutputDuplication,
+    crop: Option<(u32, u32, u32, u32)>,
+    frame_tx: SyncSender<D3DFrame>,
+    error_tx: SyncSender<String>,
+    stop_flag: Arc<AtomicBool>,
+}
+
+// SAFETY: D3D11 devices are free-threaded and the duplication/output objects are
+// only ever touched by the capture thread once `start()` hands them over.
+unsafe impl Send for DuplicationWorker {}
+
+impl DxgiDuplicationCapture {
+    /// Create a Desktop Duplication capture for a display.
+    ///
+    /// `crop` is (x, y, width, height) relative to the display.
+    pub fn new(display_index: usize, crop: Option<(u32, u32, u32, u32)>) -> Result<Self, String> {
+        let displays = Display::list();
+        let display = displays
+            .get(display_index)
+            .ok_or_else(|| format!("Display {} not found", display_index))?;
+
+        let (adapter, output) = find_output_for_monitor(display.raw_handle().0)?;
+        let (device, context) = create_device_for_adapter(&adapter)?;
+
+        let duplication = unsafe { output.DuplicateOutput(&device) }.map_err(|e| {
+            format!(
+                "Desktop duplication unavailable for display {}: {}",
+                display_index, e
+            )
+        })?;
+
+        let (width, height) = if let Some((_, _, w, h)) = crop {
+            (w, h)
+        } else {
+            let size = display
+                .physical_size()
+                .ok_or("Failed to get display size")?;
+            (size.width() as u32, size.height() as u32)
+        };
+
+        let (frame_tx, frame_rx) = mpsc::sync_channel::<D3DFrame>(4);
+        let (error_tx, error_rx) = mpsc::sync_channel::<String>(1);
+        let stop_flag = Arc::new(AtomicBool::new(false));
+
+        log::info!(
+            "[DXGI] Created desktop duplication for display {} ({}x{}, crop={:?})",
+            display_index,
+            width,
+            height,
+            crop
+        );
+
+        Ok(Self {
+            frame_rx,
+            error_rx,
+            stop_flag: stop_flag.clone(),
+            width,
+            height,
+            pending: Some(DuplicationWorker {
+                device,
+                context,
+                output,
+                duplication,
+                crop,
+                frame_tx,
+                error_tx,
+                stop_flag,
+            }),
+            thread: None,
+        })
+    }
+
+    /// Start capturing on a background thread.
+    pub fn start(&mut self) -> Result<(), String> {
+        let Some(worker) = self.pending.take() else {
+            return Ok(());
+        };
+
+        let handle = std::thread::Builder::new()
+            .name("dxgi-duplication".to_string())
+            .spawn(move || worker.run())
+            .map_err(|e| format!("Failed to spawn duplication thread: {}", e))?;
+
+        self.thread = Some(handle);
+        Ok(())
+    }
+
+    /// Get the next frame with timeout.
+    pub fn get_frame(&self, timeout_ms: u64) -> Option<D3DFrame> {
+        self.frame_rx
+            .recv_timeout(Duration::from_millis(timeout_ms))
+            .ok()
+    }
+
+    /// Wait for first frame to get actual dimensions.
+    pub fn wait_for_first_frame(&self, timeout_ms: u64) -> Option<(u32, u32, D3DFrame)> {
+        let frame = self.get_frame(timeout_ms)?;
+        Some((frame.width, frame.height, frame))
+    }
+
+    /// Take the fatal error reported by the capture thread, if any.
+    pub fn take_error(&self) -> Option<String> {
+        self.error_rx.try_recv().ok()
+    }
+
+    /// Get capture width.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Get capture height.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Stop capturing.
+    pub fn stop(&mut self) {
+        self.stop_flag.store(true, Ordering::SeqCst);
+        self.pending = None;
+        if let Some(handle) = self.thread.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for DxgiDuplicationCapture {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+impl DuplicationWorker {
+    fn run(mut self) {
+        let mut staging: Option<(ID3D11Texture2D, u32, u32)> = None;
+        let mut reacquire_attempts = 0;
+
+        while !self.stop_flag.load(Ordering::Relaxed) {
+            let mut frame_info = DXGI_OUTDUPL_FRAME_INFO::default();
+            let mut resource: Option<IDXGIResource> = None;
+
+            let acquired = unsafe {
+                self.duplication.AcquireNextFrame(
+                    ACQUIRE_TIMEOUT_MS,
+                    &mut frame_info,
+                    &mut resource,
+                )
+            };
+
+            match acquired {
+                Ok(()) => {},
+                Err(e) if e.code() == DXGI_ERROR_WAIT_TIMEOUT => continue,
+                Err(e) if e.code() == DXGI_ERROR_ACCESS_LOST => {
+                    // Display mode changed (e.g. game switched resolution) - recreate.
+                    reacquire_attempts += 1;
+                    if reacquire_attempts > MAX_REACQUIRE_ATTEMPTS {
+                        self.fail(format!("Desktop duplication access lost: {}", e));
+                        return;
+                    }
+                    log::warn!(
+                        "[DXGI] Access lost, recreating duplication (attempt {})",
+                        reacquire_attempts
+                    );
+                    std::thread::sleep(Duration::from_millis(100));
+                    match unsafe { self.output.DuplicateOutput(&self.device) } {
+                        Ok(duplication) => self.duplication = duplication,
+                        Err(e) => log::warn!("[DXGI] Failed to recreate duplication: {}", e),
+                    }
+                    continue;
+                },
+                Err(e) => {
+                    self.fail(format!("Desktop duplication failed: {}", e));
+                    return;
+                },
+            }
+            reacquire_attempts = 0;
+
+            // LastPresentTime is zero when only the pointer moved; skip those.
+            let result = if frame_info.LastPresentTime != 0 {
+                resource
+                    .as_ref()
+                    .map(|r| self.read_frame(r, &mut staging, frame_info.LastPresentTime))
+                    .transpose()
+            } else {
+                Ok(None)
+            };
+
+            let _ = unsafe { self.duplication.ReleaseFrame() };
+
+            match result {
+                Ok(Some(frame)) => {
+                    // Non-blocking send - drop frame if channel is full
+                    let _ = self.frame_tx.try_send(frame);
+                },
+                Ok(None) => {},
+                Err(e) => log::warn!("[DXGI] Failed to read frame: {}", e),
+            }
+        }
+
+        log::debug!("[DXGI] Duplication thread stopped");
+    }
+
+    /// Copy the acquired desktop texture into a CPU-readable BGRA buffer.
+    fn read_frame(
+        &self,
+        resource: &IDXGIResource,
+        staging: &mut Option<(ID3D11Texture2D, u32, u32)>,
+        present_time_qpc: i64,
+    ) -> windows::core::Result<D3DFrame> {
+        let texture: ID3D11Texture2D = resource.cast()?;
+
+        let mut desc = D3D11_TEXTURE2D_DESC::default();
+        unsafe { texture.GetDesc(&mut desc) };
+
+        let needs_new =
+            !matches!(staging, Some((_, w, h)) if *w == desc.Width && *h == desc.Height);
+        if needs_new {
+            let staging_desc = D3D11_TEXTURE2D_DESC {
+                Usage: D3D11_USAGE_STAGING,
+                BindFlags: 0,
+                CPUAccessFlags: D3D11_CPU_ACCESS_READ.0 as u32,
+                MiscFlags: 0,
+                MipLevels: 1,
+                ArraySize: 1,
+                ..desc
+            };
+            let mut texture = None;
+            unsafe {
+                self.device
+                    .CreateTexture2D(&staging_desc, None, Some(&mut texture))?
+            };
+            *staging = texture.map(|t| (t, desc.Width, desc.Height));
+        }
+        let Some((staging_texture, _, _)) = staging.as_ref() else {
+            return Err(windows::core::Error::from(DXGI_ERROR_NOT_FOUND));
+        };
+
+        unsafe { self.context.CopyResource(staging_texture, &texture) };
+
+        let mut mapped = D3D11_MAPPED_SUBRESOURCE::default();
+        unsafe {
+            self.context
+                .Map(staging_texture, 0, D3D11_MAP_READ, 0, Some(&mut mapped))?
+        };
+
+        let (x, y, width, height) = clamp_crop(self.crop, desc.Width, desc.Height);
+        let row_bytes = (width * 4) as usize;
+        let mut data = Vec::with_capacity(row_bytes * height as usize);
+        unsafe {
+            let base = mapped.pData as *const u8;
+            for row in y..y + height {
+                let offset = row as usize * mapped.RowPitch as usize + x as usize * 4;
+                data.extend_from_slice(std::slice::from_raw_parts(base.add(offset), row_bytes));
+            }
+            self.context.Unmap(staging_texture, 0);
+        }
+
+        Ok(D3DFrame {
+            data,
+            width,
+            height,
+            timestamp_100ns: PerformanceCounterTimestamp::new(present_time_qpc).to_wgc_time(),
+        })
+    }
+
+    fn fail(&self, message: String) {
+        log::error!("[DXGI] {}", message);
+        let _ = self.error_tx.try_send(message);
+    }
+}
+
+/// Clamp a crop rectangle to the texture bounds, defaulting to the full texture.
+fn clamp_crop(crop: Option<(u32, u32, u32, u32)>, tex_w: u32, tex_h: u32) -> (u32, u32, u32, u32) {
+    match crop {
+        Some((x, y, w, h)) => {
+            let x = x.min(tex_w.saturating_sub(1));
+            let y = y.min(tex_h.saturating_sub(1));
+            (x, y, w.min(tex_w - x), h.min(tex_h - y))
+        },
+        None => (0, 0, tex_w, tex_h),
+    }
+}
+
+/// Find the DXGI adapter/output pair driving a monitor.
+fn find_output_for_monitor(monitor: HMONITOR) -> Result<(IDXGIAdapter1, IDXGIOutput1), String> {
+    let factory: IDXGIFactory1 = unsafe { CreateDXGIFactory1() }
+        .map_err(|e| format!("Failed to create DXGI factory: {}", e))?;
+
+    let mut adapter_index = 0;
+    while let Ok(adapter) = unsafe { factory.EnumAdapters1(adapter_index) } {
+        let mut output_index = 0;
+        while let Ok(output) = unsafe { adapter.EnumOutputs(output_index) } {
+            if let Ok(desc) = unsafe { output.GetDesc() } {
+                if desc.Monitor == monitor {
+                    let output1: IDXGIOutput1 = output
+                        .cast()
+                        .map_err(|e| format!("Output does not support duplication: {}", e))?;
+                    return Ok((adapter, output1));
+                }
+            }
+            output_index += 1;
+        }
+        adapter_index += 1;
+    }
+
+    Err("No DXGI output found for display".to_string())
+}
+
+/// Create a D3D11 device on the adapter that owns the output (required by DuplicateOutput).
+fn create_device_for_adapter(
+    adapter: &IDXGIAdapter1,
+) -> Result<(ID3D11Device, ID3D11DeviceContext), String> {
+    let mut device = None;
+    let mut context = None;
+    unsafe {
+        D3D11CreateDevice(
+            adapter,
+            D3D_DRIVER_TYPE_UNKNOWN,
+            HMODULE::default(),
+            D3D11_CREATE_DEVICE_BGRA_SUPPORT,
+            None,
+            D3D11_SDK_VERSION,
+            Some(&mut device),
+            None,
+            Some(&mut context),
+        )
+    }
+    .map_err(|e| format!("Failed to create D3D11 device for duplication: {}", e))?;
+
+    match (device, context) {
+        (Some(device), Some(context)) => Ok((device, context)),
+        _ => Err("D3D11 device creation returned no device".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_crop_full_texture() {
+        assert_eq!(clamp_crop(None, 1920, 1080), (0, 0, 1920, 1080));
+    }
+
+    #[test]
+    fn test_clamp_crop_within_bounds() {
+        assert_eq!(
+            clamp_crop(Some((100, 50, 640, 480)), 1920, 1080),
+            (100, 50, 640, 480)
+        );
+    }
+
+    #[test]
+    fn test_clamp_crop_overflowing_edges() {
+        assert_eq!(
+            clamp_crop(Some((1800, 1000, 400, 200)), 1920, 1080),
+            (1800, 1000, 120, 80)
+        );
+    }
+}
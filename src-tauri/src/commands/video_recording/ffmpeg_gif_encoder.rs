@@ -12,6 +12,9 @@ use ts_rs::TS;
 
 use super::gif_encoder::GifFrame;
 
+/// Frame rate cap for GIF output, keeping file sizes reasonable.
+pub const MAX_GIF_FPS: u32 = 30;
+
 /// Quality preset for GIF encoding.
 ///
 /// All presets use:
@@ -39,7 +42,7 @@ pub enum GifQualityPreset {
 
 impl GifQualityPreset {
     /// Get the FFmpeg filter string for this preset.
-    fn to_filter(&self) -> &'static str {
+    pub fn to_filter(&self) -> &'static str {
         // All presets use:
         // - stats_mode=full: global palette from all frames
         // - diff_mode=rectangle: only encode changed regions
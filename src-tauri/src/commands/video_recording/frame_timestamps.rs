@@ -0,0 +1,75 @@
+//! Capture timestamps of recorded screen frames.
+//!
+//! The recorder writes the master clock time of every encoded frame to a
+//! sidecar. These are the same times the encoder stamps the frames with (and
+//! that cursor events use), so the video itself is already in sync; the
+//! sidecar keeps a per-frame record of capture timing without demuxing.
+
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Sidecar file name inside a project folder.
+pub const FRAME_TIMESTAMPS_FILE: &str = "frames.json";
+
+/// Master clock time of each frame in a screen recording.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameTimestamps {
+    /// Nominal frame rate the video was encoded at.
+    pub fps: u32,
+    /// Capture time of each frame in ms since recording start (the cursor clock).
+    pub timestamps_ms: Vec<f64>,
+}
+
+impl FrameTimestamps {
+    /// Empty timestamp list for a recording at `fps`.
+    pub fn new(fps: u32) -> Self {
+        Self {
+            fps,
+            timestamps_ms: Vec::new(),
+        }
+    }
+
+    /// Record the capture time of the next encoded frame.
+    pub fn push(&mut self, elapsed: Duration) {
+        self.timestamps_ms.push(elapsed.as_secs_f64() * 1000.0);
+    }
+
+    /// Write the sidecar to `path`.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string(self)
+            .map_err(|e| format!("Failed to serialize frame timestamps: {}", e))?;
+        std::fs::write(path, json).map_err(|e| format!("Failed to write frame timestamps: {}", e))
+    }
+
+    /// Read a sidecar written by [`FrameTimestamps::save`].
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read frame timestamps: {}", e))?;
+        serde_json::from_str(&json).map_err(|e| format!("Failed to parse frame timestamps: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "snapit_frame_timestamps_{}.json",
+            std::process::id()
+        ));
+        let mut timestamps = FrameTimestamps::new(30);
+        timestamps.push(Duration::from_millis(40));
+        timestamps.push(Duration::from_millis(90));
+        timestamps.save(&path).unwrap();
+
+        let loaded = FrameTimestamps::load(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(loaded.fps, 30);
+        assert_eq!(loaded.timestamps_ms, vec![40.0, 90.0]);
+    }
+}
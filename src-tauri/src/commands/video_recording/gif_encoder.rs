@@ -24,15 +24,17 @@ pub struct GifFrame {
     pub timestamp: f64,
 }
 
+/// Average encoded size per pixel of a GIF frame. Palettized, LZW-compressed
+/// screen content with frame differencing typically lands around a quarter byte.
+const GIF_BYTES_PER_PIXEL_ESTIMATE: f64 = 0.25;
+
 /// GIF recorder that buffers frames and encodes them.
 pub struct GifRecorder {
     /// Buffered frames.
     frames: Vec<GifFrame>,
     /// Target width.
-    #[allow(dead_code)]
     width: u32,
     /// Target height.
-    #[allow(dead_code)]
     height: u32,
     /// Target FPS.
     fps: u32,
@@ -75,6 +77,13 @@ impl GifRecorder {
         });
     }
 
+    /// Estimated size of the encoded GIF, for live progress while frames are
+    /// still buffered in memory.
+    pub fn estimated_size_bytes(&self) -> u64 {
+        let frame_bytes = self.width as f64 * self.height as f64 * GIF_BYTES_PER_PIXEL_ESTIMATE;
+        (self.frames.len() as f64 * frame_bytes) as u64
+    }
+
     /// Get the number of buffered frames.
     pub fn frame_count(&self) -> usize {
         self.frames.len()
@@ -234,6 +243,17 @@ mod tests {
         assert!((recorder.duration() - 10.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_estimated_size_grows_with_frames() {
+        let mut recorder = GifRecorder::new(100, 100, 30, GifQualityPreset::Balanced, 100);
+        assert_eq!(recorder.estimated_size_bytes(), 0);
+
+        let rgba = vec![0u8; 100 * 100 * 4];
+        recorder.add_frame(rgba.clone(), 100, 100, 0.0);
+        recorder.add_frame(rgba, 100, 100, 0.033);
+        assert_eq!(recorder.estimated_size_bytes(), 5000);
+    }
+
     #[test]
     fn test_take_frames() {
         let mut recorder = GifRecorder::new(2, 2, 30, GifQualityPreset::Balanced, 100);
@@ -112,15 +112,33 @@ pub async fn editor_pause(
 }
 
 /// Seek to a specific timestamp.
+///
+/// `frame_exact` decodes the exact frame at the timestamp instead of the fast
+/// approximate seek. Use it when paused; leave it off for live scrubbing.
 #[tauri::command]
 pub async fn editor_seek(
     instance_id: String,
     timestamp_ms: u64,
+    frame_exact: Option<bool>,
     state: State<'_, EditorState>,
 ) -> Result<(), String> {
     let instance = get_instance(&instance_id, &state)?;
     let inst = instance.lock().await;
-    inst.seek(timestamp_ms).await
+    inst.seek(timestamp_ms, frame_exact.unwrap_or(false)).await
+}
+
+/// Step forward or backward by `delta` frames with a frame-exact seek.
+///
+/// Pauses playback. Returns the timestamp of the new frame.
+#[tauri::command]
+pub async fn editor_step_frame(
+    instance_id: String,
+    delta: i32,
+    state: State<'_, EditorState>,
+) -> Result<u64, String> {
+    let instance = get_instance(&instance_id, &state)?;
+    let inst = instance.lock().await;
+    inst.step_frame(delta).await
 }
 
 /// Set playback speed.
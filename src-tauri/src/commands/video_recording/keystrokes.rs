@@ -0,0 +1,386 @@
+//! Keystroke capture for the exported keystroke overlay.
+//!
+//! A low-level keyboard hook records every key press during an editor-flow
+//! recording (when enabled in the recording settings). Modifiers held at the
+//! time of a press are folded into it, so Ctrl+C is one event rather than two.
+//! Timestamps use the same start time as cursor events.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+/// Sidecar file name inside a project folder.
+pub const KEYSTROKE_FILE: &str = "keystroke.json";
+
+/// Modifier key held during a key press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum KeyModifier {
+    Ctrl,
+    Alt,
+    Shift,
+    Win,
+}
+
+impl KeyModifier {
+    /// Label shown on the keycap.
+    pub fn label(self) -> &'static str {
+        match self {
+            KeyModifier::Ctrl => "Ctrl",
+            KeyModifier::Alt => "Alt",
+            KeyModifier::Shift => "Shift",
+            KeyModifier::Win => "Win",
+        }
+    }
+
+    /// Modifier a virtual key code belongs to, if it is one.
+    pub fn from_vk(vk: u32) -> Option<Self> {
+        match vk {
+            0x11 | 0xA2 | 0xA3 => Some(KeyModifier::Ctrl),
+            0x12 | 0xA4 | 0xA5 => Some(KeyModifier::Alt),
+            0x10 | 0xA0 | 0xA1 => Some(KeyModifier::Shift),
+            0x5B | 0x5C => Some(KeyModifier::Win),
+            _ => None,
+        }
+    }
+}
+
+/// One key press.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeystrokeEvent {
+    /// Milliseconds since recording start (same clock as cursor events).
+    pub timestamp_ms: u64,
+    /// Display name of the pressed key (e.g. "C", "Enter").
+    pub key: String,
+    /// Modifiers held at the time, in display order.
+    #[serde(default)]
+    pub modifiers: Vec<KeyModifier>,
+}
+
+impl KeystrokeEvent {
+    /// Combined keycap label, e.g. "Ctrl+Shift+C".
+    pub fn label(&self) -> String {
+        self.modifiers
+            .iter()
+            .map(|modifier| modifier.label())
+            .chain([self.key.as_str()])
+            .collect::<Vec<_>>()
+            .join("+")
+    }
+}
+
+/// Key presses of one recording, saved as [`KEYSTROKE_FILE`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeystrokeRecording {
+    /// Key presses sorted by timestamp.
+    pub events: Vec<KeystrokeEvent>,
+}
+
+impl KeystrokeRecording {
+    /// Write the sidecar to `path`.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string(self)
+            .map_err(|e| format!("Failed to serialize keystrokes: {}", e))?;
+        std::fs::write(path, json).map_err(|e| format!("Failed to write keystrokes: {}", e))
+    }
+
+    /// Read a sidecar written by [`KeystrokeRecording::save`].
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read keystrokes: {}", e))?;
+        serde_json::from_str(&json).map_err(|e| format!("Failed to parse keystrokes: {}", e))
+    }
+}
+
+/// Display name of a virtual key code, or None for keys that aren't shown.
+pub fn key_name(vk: u32) -> Option<String> {
+    let name = match vk {
+        0x30..=0x39 | 0x41..=0x5A => return char::from_u32(vk).map(String::from),
+        0x60..=0x69 => return Some(format!("Num{}", vk - 0x60)),
+        0x70..=0x87 => return Some(format!("F{}", vk - 0x6F)),
+        0x08 => "Backspace",
+        0x09 => "Tab",
+        0x0D => "Enter",
+        0x1B => "Esc",
+        0x20 => "Space",
+        0x21 => "PgUp",
+        0x22 => "PgDn",
+        0x23 => "End",
+        0x24 => "Home",
+        0x25 => "Left",
+        0x26 => "Up",
+        0x27 => "Right",
+        0x28 => "Down",
+        0x2C => "PrtSc",
+        0x2D => "Ins",
+        0x2E => "Del",
+        0x6A => "*",
+        0x6B => "+",
+        0x6D => "-",
+        0x6E => ".",
+        0x6F => "/",
+        0xBA => ";",
+        0xBB => "=",
+        0xBC => ",",
+        0xBD => "-",
+        0xBE => ".",
+        0xBF => "/",
+        0xC0 => "`",
+        0xDB => "[",
+        0xDC => "\\",
+        0xDD => "]",
+        0xDE => "'",
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+/// Turns raw key down/up transitions into key press events.
+///
+/// Tracks held keys so auto-repeat doesn't produce repeated presses and
+/// modifiers can be attached to the key pressed while they are held.
+#[derive(Debug, Default)]
+pub struct KeystrokeTracker {
+    held: HashSet<u32>,
+}
+
+impl KeystrokeTracker {
+    /// Handle a key going down; returns the press to record, if any.
+    pub fn key_down(&mut self, vk: u32, timestamp_ms: u64) -> Option<KeystrokeEvent> {
+        if !self.held.insert(vk) || KeyModifier::from_vk(vk).is_some() {
+            return None;
+        }
+
+        let mut modifiers: Vec<KeyModifier> = self
+            .held
+            .iter()
+            .filter_map(|&held| KeyModifier::from_vk(held))
+            .collect();
+        modifiers.sort();
+        modifiers.dedup();
+
+        Some(KeystrokeEvent {
+            timestamp_ms,
+            key: key_name(vk)?,
+            modifiers,
+        })
+    }
+
+    /// Handle a key being released.
+    pub fn key_up(&mut self, vk: u32) {
+        self.held.remove(&vk);
+    }
+}
+
+/// Keyboard hook capture running for the length of a recording.
+pub struct KeystrokeCapture {
+    events: Arc<Mutex<Vec<KeystrokeEvent>>>,
+    should_stop: Arc<AtomicBool>,
+    hook_thread: Option<JoinHandle<()>>,
+}
+
+impl KeystrokeCapture {
+    /// Create a capture (not yet started).
+    pub fn new() -> Self {
+        Self {
+            events: Arc::new(Mutex::new(Vec::new())),
+            should_stop: Arc::new(AtomicBool::new(false)),
+            hook_thread: None,
+        }
+    }
+
+    /// Start capturing, timestamping presses relative to `start_time`.
+    pub fn start_with_time(&mut self, start_time: Instant) -> Result<(), String> {
+        if self.hook_thread.is_some() {
+            return Err("Keystroke capture already running".to_string());
+        }
+        self.should_stop.store(false, Ordering::SeqCst);
+
+        let events = Arc::clone(&self.events);
+        let should_stop = Arc::clone(&self.should_stop);
+        self.hook_thread = Some(
+            thread::Builder::new()
+                .name("keystroke-hook-capture".to_string())
+                .spawn(move || run_keyboard_hook_loop(events, should_stop, start_time))
+                .map_err(|e| format!("Failed to spawn keyboard hook thread: {}", e))?,
+        );
+
+        log::info!("[KEYSTROKES] Started capture");
+        Ok(())
+    }
+
+    /// Stop capturing and return the recorded presses.
+    pub fn stop(&mut self) -> KeystrokeRecording {
+        self.should_stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.hook_thread.take() {
+            let _ = handle.join();
+        }
+
+        let events = self
+            .events
+            .lock()
+            .map(|events| events.clone())
+            .unwrap_or_default();
+        log::info!(
+            "[KEYSTROKES] Stopped capture, collected {} presses",
+            events.len()
+        );
+
+        KeystrokeRecording { events }
+    }
+}
+
+impl Default for KeystrokeCapture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for KeystrokeCapture {
+    fn drop(&mut self) {
+        self.should_stop.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Keyboard hook loop - records key presses via Windows low-level hook.
+#[cfg(target_os = "windows")]
+fn run_keyboard_hook_loop(
+    events: Arc<Mutex<Vec<KeystrokeEvent>>>,
+    should_stop: Arc<AtomicBool>,
+    start_time: Instant,
+) {
+    use std::cell::RefCell;
+    use windows::Win32::Foundation::{LPARAM, LRESULT, WPARAM};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        CallNextHookEx, DispatchMessageW, PeekMessageW, SetWindowsHookExW, TranslateMessage,
+        UnhookWindowsHookEx, HHOOK, KBDLLHOOKSTRUCT, MSG, PM_REMOVE, WH_KEYBOARD_LL, WM_KEYDOWN,
+        WM_KEYUP, WM_SYSKEYDOWN, WM_SYSKEYUP,
+    };
+
+    // Thread-local storage for hook callback data
+    thread_local! {
+        static HOOK_DATA: RefCell<Option<(Arc<Mutex<Vec<KeystrokeEvent>>>, Instant, KeystrokeTracker)>> = RefCell::new(None);
+    }
+
+    HOOK_DATA.with(|hook_data| {
+        *hook_data.borrow_mut() =
+            Some((Arc::clone(&events), start_time, KeystrokeTracker::default()));
+    });
+
+    // Low-level keyboard hook callback
+    unsafe extern "system" fn keyboard_hook_proc(
+        code: i32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        if code >= 0 {
+            let key_struct = &*(lparam.0 as *const KBDLLHOOKSTRUCT);
+            let message = wparam.0 as u32;
+
+            HOOK_DATA.with(|hook_data| {
+                if let Some((events, start_time, tracker)) = hook_data.borrow_mut().as_mut() {
+                    if message == WM_KEYDOWN || message == WM_SYSKEYDOWN {
+                        let timestamp_ms = start_time.elapsed().as_millis() as u64;
+                        if let Some(event) = tracker.key_down(key_struct.vkCode, timestamp_ms) {
+                            if let Ok(mut events) = events.lock() {
+                                events.push(event);
+                            }
+                        }
+                    } else if message == WM_KEYUP || message == WM_SYSKEYUP {
+                        tracker.key_up(key_struct.vkCode);
+                    }
+                }
+            });
+        }
+
+        CallNextHookEx(HHOOK::default(), code, wparam, lparam)
+    }
+
+    unsafe {
+        let hook = match SetWindowsHookExW(WH_KEYBOARD_LL, Some(keyboard_hook_proc), None, 0) {
+            Ok(hook) => hook,
+            Err(e) => {
+                log::error!("[KEYSTROKES] Failed to install keyboard hook: {}", e);
+                return;
+            },
+        };
+        log::debug!("[KEYSTROKES] Keyboard hook installed");
+
+        // Message loop (required for low-level hooks to work), non-blocking so
+        // the stop flag is checked
+        let mut msg = MSG::default();
+        while !should_stop.load(Ordering::SeqCst) {
+            while PeekMessageW(&mut msg, None, 0, 0, PM_REMOVE).as_bool() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        let _ = UnhookWindowsHookEx(hook);
+        log::debug!("[KEYSTROKES] Keyboard hook removed");
+    }
+
+    HOOK_DATA.with(|hook_data| {
+        *hook_data.borrow_mut() = None;
+    });
+}
+
+#[cfg(not(target_os = "windows"))]
+fn run_keyboard_hook_loop(
+    _events: Arc<Mutex<Vec<KeystrokeEvent>>>,
+    should_stop: Arc<AtomicBool>,
+    _start_time: Instant,
+) {
+    // Non-Windows stub - just wait until stopped
+    while !should_stop.load(Ordering::SeqCst) {
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modifier_combo_is_one_press() {
+        let mut tracker = KeystrokeTracker::default();
+
+        // Ctrl down, C down (plus auto-repeat), C up, Ctrl up
+        assert_eq!(tracker.key_down(0xA2, 100), None);
+        let press = tracker.key_down(0x43, 120).unwrap();
+        assert_eq!(press.label(), "Ctrl+C");
+        assert_eq!(press.timestamp_ms, 120);
+        assert_eq!(tracker.key_down(0x43, 150), None);
+        tracker.key_up(0x43);
+        tracker.key_up(0xA2);
+
+        // Plain key afterwards carries no modifiers
+        assert_eq!(tracker.key_down(0x0D, 200).unwrap().label(), "Enter");
+
+        // Both shifts count as one modifier, listed after Ctrl
+        tracker.key_down(0xA1, 300);
+        tracker.key_down(0xA0, 301);
+        tracker.key_down(0xA3, 302);
+        assert_eq!(
+            tracker.key_down(0x70, 310).unwrap().label(),
+            "Ctrl+Shift+F1"
+        );
+    }
+
+    #[test]
+    fn test_key_names() {
+        assert_eq!(key_name(0x41).as_deref(), Some("A"));
+        assert_eq!(key_name(0x39).as_deref(), Some("9"));
+        assert_eq!(key_name(0x7B).as_deref(), Some("F12"));
+        assert_eq!(key_name(0x2E).as_deref(), Some("Del"));
+        assert_eq!(key_name(0xFF), None);
+    }
+}
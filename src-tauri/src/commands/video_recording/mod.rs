@@ -29,17 +29,22 @@
 //! ```
 
 pub mod audio;
+pub mod audio_dsp;
 pub mod audio_monitor;
 pub mod audio_multitrack;
+pub mod audio_peaks;
 pub mod audio_sync;
 pub mod audio_wasapi;
 pub mod cursor;
 pub mod d3d_capture;
 pub mod desktop_icons;
+pub mod dxgi_capture;
 pub mod ffmpeg_gif_encoder;
 pub mod fragmentation;
+pub mod frame_timestamps;
 pub mod gif_encoder;
 pub mod gpu_editor;
+pub mod keystrokes;
 pub mod master_clock;
 pub mod recorder;
 pub mod state;
@@ -60,8 +65,9 @@ use tauri::{command, AppHandle, Emitter, Manager};
 
 // Types (from types.rs)
 pub use types::{
-    find_monitor_for_point, get_scap_display_bounds, AudioInputDevice, RecordingFormat,
-    RecordingMode, RecordingSettings, RecordingState, RecordingStatus, StartRecordingResult,
+    find_monitor_for_point, get_scap_display_bounds, AudioInputDevice, MicChannelMode, RateControl,
+    RecordingFormat, RecordingMode, RecordingSettings, RecordingState, RecordingStatus,
+    StartRecordingResult, VideoCodec,
 };
 // StopRecordingResult is available via types:: but not re-exported (unused)
 
@@ -74,7 +80,7 @@ pub use crate::config::webcam::{get_webcam_settings, WebcamShape, WebcamSize, WE
 
 // Video editor types
 pub use cursor::CursorRecording;
-pub use video_export::ExportResult;
+pub use video_export::{ExportOverrides, ExportResult, ReexportProgress, ReexportResult};
 pub use video_project::{
     apply_auto_zoom_to_project, clear_frame_cache, get_video_frame_cached,
     load_video_project_from_file, AudioWaveform, AutoZoomConfig, VideoProject,
@@ -170,7 +176,7 @@ pub fn prewarm_capture() -> Result<(), String> {
 /// NOTE: This function is idempotent - if called multiple times (e.g., due to React
 /// double-render or StrictMode), it cancels any previous preparation before starting new.
 #[command]
-pub fn prepare_recording(format: RecordingFormat) -> Result<(), String> {
+pub fn prepare_recording(app: AppHandle, format: RecordingFormat) -> Result<(), String> {
     log::info!("[PREPARE] Preparing recording resources (background)...");
 
     // IMPORTANT: Cancel any existing prepared resources FIRST to avoid race conditions.
@@ -198,7 +204,7 @@ pub fn prepare_recording(format: RecordingFormat) -> Result<(), String> {
         format,
         ..Default::default()
     };
-    let output_path = generate_output_path(&settings)?;
+    let output_path = generate_output_path(&app, &settings)?;
     log::info!("[PREPARE] Output path: {:?}", output_path);
 
     // Store for later use
@@ -711,6 +717,10 @@ pub async fn clamp_webcam_to_selection(
 
 /// Exclude the webcam preview window from screen capture.
 /// Uses Windows SetWindowDisplayAffinity API to make the window invisible to capture.
+///
+/// Returns an error if the OS doesn't support `WDA_EXCLUDEFROMCAPTURE`
+/// (Windows 10 before version 2004), where the call can silently fall back to
+/// blacking the window out or do nothing at all.
 #[command]
 pub async fn exclude_webcam_from_capture(app: tauri::AppHandle) -> Result<(), String> {
     #[cfg(target_os = "windows")]
@@ -724,16 +734,58 @@ pub async fn exclude_webcam_from_capture(app: tauri::AppHandle) -> Result<(), St
             let hwnd = window
                 .hwnd()
                 .map_err(|e| format!("Failed to get HWND: {}", e))?;
+            let hwnd = HWND(hwnd.0);
             unsafe {
-                SetWindowDisplayAffinity(HWND(hwnd.0), WDA_EXCLUDEFROMCAPTURE)
+                SetWindowDisplayAffinity(hwnd, WDA_EXCLUDEFROMCAPTURE)
                     .map_err(|e| format!("Failed to exclude from capture: {:?}", e))?;
             }
+
+            // Older Windows accepts the call but doesn't apply the exclusion
+            if !is_excluded_from_capture(hwnd) {
+                return Err(
+                    "Excluding the webcam preview from capture requires Windows 10 version 2004 or later. The preview will appear in recordings."
+                        .to_string(),
+                );
+            }
             log::debug!("[WEBCAM] Preview window excluded from screen capture");
         }
     }
     Ok(())
 }
 
+/// Check whether the webcam preview window is actually excluded from screen capture.
+///
+/// Reads back the window's display affinity, so it reflects what the OS applied
+/// rather than what was requested. Returns false if the preview window isn't open.
+#[command]
+pub async fn is_webcam_excluded_from_capture(app: tauri::AppHandle) -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::Foundation::HWND;
+
+        if let Some(window) = app.get_webview_window("webcam-preview") {
+            if let Ok(hwnd) = window.hwnd() {
+                return is_excluded_from_capture(HWND(hwnd.0));
+            }
+        }
+    }
+    #[cfg(not(target_os = "windows"))]
+    let _ = app;
+    false
+}
+
+/// Read back whether `WDA_EXCLUDEFROMCAPTURE` is in effect for a window.
+#[cfg(target_os = "windows")]
+fn is_excluded_from_capture(hwnd: windows::Win32::Foundation::HWND) -> bool {
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetWindowDisplayAffinity, WDA_EXCLUDEFROMCAPTURE,
+    };
+
+    let mut affinity = 0u32;
+    unsafe { GetWindowDisplayAffinity(hwnd, &mut affinity) }.is_ok()
+        && affinity == WDA_EXCLUDEFROMCAPTURE.0
+}
+
 // ============================================================================
 // Native/MF Webcam Preview (REMOVED - browser handles preview now)
 // ============================================================================
@@ -905,16 +957,27 @@ pub async fn start_recording(
     let output_path = if settings.quick_capture {
         // Quick capture: always generate fresh path (flat file, not folder)
         let _ = take_prepared_output_path(); // Discard any prepared path
-        generate_output_path(&settings).unwrap_or_else(|_| {
+        generate_output_path(&app, &settings).unwrap_or_else(|_| {
             std::env::temp_dir().join(format!(
                 "recording_{}.mp4",
                 chrono::Local::now().format("%Y%m%d_%H%M%S")
             ))
         })
     } else {
-        // Editor flow: use prepared path if available
-        take_prepared_output_path().unwrap_or_else(|| {
-            generate_output_path(&settings).unwrap_or_else(|_| {
+        // Editor flow: use prepared path if available. A templated name may depend on
+        // the capture target, which isn't known when the path is prepared.
+        let prepared = take_prepared_output_path();
+        let prepared = if crate::commands::storage::filename::filename_template(&app).is_some() {
+            if let Some(path) = prepared {
+                // Only removes the folder if nothing was written to it yet
+                let _ = std::fs::remove_dir(path);
+            }
+            None
+        } else {
+            prepared
+        };
+        prepared.unwrap_or_else(|| {
+            generate_output_path(&app, &settings).unwrap_or_else(|_| {
                 // Fallback to temp dir if generation fails - respect format
                 let ext = match settings.format {
                     RecordingFormat::Gif => "gif",
@@ -954,7 +1017,7 @@ pub async fn cancel_recording(app: AppHandle) -> Result<(), String> {
     recorder::cancel_recording(app).await
 }
 
-/// Pause the current recording (MP4 only).
+/// Pause the current recording.
 #[command]
 pub async fn pause_recording(app: AppHandle) -> Result<(), String> {
     recorder::pause_recording(app).await
@@ -973,6 +1036,10 @@ pub async fn get_recording_status() -> Result<RecordingStatus, String> {
     Ok(RecordingStatus {
         state: controller.state.clone(),
         settings: controller.settings.clone(),
+        current_segment: controller
+            .active
+            .as_ref()
+            .and_then(|active| active.progress.get_segment()),
     })
 }
 
@@ -987,22 +1054,64 @@ pub async fn get_recording_status() -> Result<RecordingStatus, String> {
 /// 2. Detects associated files (webcam: `_webcam.mp4`, cursor: `_cursor.json`)
 /// 3. Creates a VideoProject with default configurations
 ///
+/// If an autosave newer than the saved project exists (see
+/// `find_video_project_autosave`), `recover_autosave` decides what happens to
+/// it: `true` loads it instead, `false` discards it, `None` leaves it alone.
+///
 /// # Arguments
 /// * `video_path` - Path to the screen recording MP4 file
+/// * `recover_autosave` - Whether to recover unsaved changes
 ///
 /// # Returns
 /// A VideoProject ready for editing in the video editor UI.
 #[command]
-pub async fn load_video_project(video_path: String) -> Result<VideoProject, String> {
+pub async fn load_video_project(
+    video_path: String,
+    recover_autosave: Option<bool>,
+) -> Result<VideoProject, String> {
     let path = std::path::Path::new(&video_path);
 
     if !path.exists() {
         return Err(format!("Video file not found: {}", video_path));
     }
 
+    let project_path = video_project::metadata::project_file_path(path);
+    if let Some(autosave_path) = video_project::autosave::newer_autosave(&project_path) {
+        match recover_autosave {
+            Some(true) => {
+                log::info!("[PROJECT] Recovering autosave {:?}", autosave_path);
+                return video_project::metadata::load_video_project_autosave(path, &autosave_path);
+            },
+            Some(false) => video_project::autosave::discard_autosave(&project_path),
+            None => {},
+        }
+    }
+
     load_video_project_from_file(path)
 }
 
+/// Find unsaved changes of the project of `video_path`.
+///
+/// Returns when the autosave was written (RFC 3339) if it is newer than the
+/// saved project, i.e. the editor closed without saving.
+#[command]
+pub async fn find_video_project_autosave(video_path: String) -> Result<Option<String>, String> {
+    let path = std::path::Path::new(&video_path);
+    let project_path = video_project::metadata::project_file_path(path);
+
+    let Some(autosave_path) = video_project::autosave::newer_autosave(&project_path) else {
+        return Ok(None);
+    };
+    match video_project::metadata::load_video_project_autosave(path, &autosave_path) {
+        Ok(project) => Ok(Some(project.updated_at)),
+        Err(e) => {
+            // An unreadable autosave (e.g. cut short by the crash) has nothing to recover
+            log::warn!("[PROJECT] Ignoring autosave {:?}: {}", autosave_path, e);
+            Ok(None)
+        },
+    }
+}
+
 /// Save a video project to a JSON file.
 ///
 /// For folder-based projects (screen_video is inside a folder):
@@ -1010,59 +1119,81 @@ pub async fn load_video_project(video_path: String) -> Result<VideoProject, Stri
 ///
 /// For legacy flat file projects:
 ///   Saves alongside the video with `.snapit` extension.
+///
+/// Removes the project's autosave, which the saved file supersedes.
 #[command]
-pub async fn save_video_project(mut project: VideoProject) -> Result<(), String> {
+pub async fn save_video_project(project: VideoProject) -> Result<(), String> {
     let video_path = std::path::Path::new(&project.sources.screen_video);
+    let project_path = video_project::metadata::project_file_path(video_path);
 
-    // Check if this is a folder-based project (video is inside a project folder)
-    if let Some(parent) = video_path.parent() {
-        // If the parent folder looks like a project folder (video is screen.mp4)
-        if video_path.file_name().and_then(|n| n.to_str()) == Some("screen.mp4") {
-            // Save to project.json in the folder, with relative paths
-            let project_path = parent.join("project.json");
-
-            // Convert absolute paths back to relative paths for storage
-            let to_relative = |abs_path: &str| -> String {
-                let path = std::path::Path::new(abs_path);
-                path.file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or(abs_path)
-                    .to_string()
-            };
-
-            // Create a copy with relative paths for saving
-            let mut save_project = project.clone();
-            save_project.sources.screen_video = to_relative(&project.sources.screen_video);
-            if let Some(ref p) = project.sources.webcam_video {
-                save_project.sources.webcam_video = Some(to_relative(p));
-            }
-            if let Some(ref p) = project.sources.cursor_data {
-                save_project.sources.cursor_data = Some(to_relative(p));
-            }
-            if let Some(ref p) = project.sources.audio_file {
-                save_project.sources.audio_file = Some(to_relative(p));
-            }
-            if let Some(ref p) = project.sources.system_audio {
-                save_project.sources.system_audio = Some(to_relative(p));
-            }
-            if let Some(ref p) = project.sources.microphone_audio {
-                save_project.sources.microphone_audio = Some(to_relative(p));
-            }
-            if let Some(ref p) = project.sources.background_music {
-                save_project.sources.background_music = Some(to_relative(p));
-            }
+    let mut save_project = project_for_storage(&project);
+    save_project.updated_at = chrono::Utc::now().to_rfc3339();
+    save_project.save(&project_path)?;
 
-            // Update timestamp
-            save_project.updated_at = chrono::Utc::now().to_rfc3339();
+    video_project::autosave::discard_autosave(&project_path);
+    Ok(())
+}
 
-            return save_project.save(&project_path);
-        }
+/// Autosave the video project being edited.
+///
+/// Writes to the project's `.autosave.snapit` sidecar once edits pause, so
+/// the editor can call this on every change.
+#[command]
+pub async fn autosave_project(project: VideoProject) -> Result<(), String> {
+    let video_path = std::path::Path::new(&project.sources.screen_video);
+    let project_path = video_project::metadata::project_file_path(video_path);
+
+    let mut autosave = project_for_storage(&project);
+    autosave.updated_at = chrono::Utc::now().to_rfc3339();
+    video_project::autosave::schedule_autosave(&project_path, autosave);
+    Ok(())
+}
+
+/// Copy of `project` as stored on disk. Folder-based projects store paths
+/// relative to the folder; legacy projects keep absolute paths.
+fn project_for_storage(project: &VideoProject) -> VideoProject {
+    let video_path = std::path::Path::new(&project.sources.screen_video);
+    let mut save_project = project.clone();
+    if video_path.file_name().and_then(|n| n.to_str()) != Some("screen.mp4") {
+        return save_project;
+    }
+
+    // Convert absolute paths back to relative paths for storage
+    let to_relative = |abs_path: &str| -> String {
+        let path = std::path::Path::new(abs_path);
+        path.file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(abs_path)
+            .to_string()
+    };
+
+    save_project.sources.screen_video = to_relative(&project.sources.screen_video);
+    if let Some(ref p) = project.sources.webcam_video {
+        save_project.sources.webcam_video = Some(to_relative(p));
+    }
+    if let Some(ref p) = project.sources.cursor_data {
+        save_project.sources.cursor_data = Some(to_relative(p));
+    }
+    if let Some(ref p) = project.sources.frame_timestamps {
+        save_project.sources.frame_timestamps = Some(to_relative(p));
+    }
+    if let Some(ref p) = project.sources.keystrokes {
+        save_project.sources.keystrokes = Some(to_relative(p));
+    }
+    if let Some(ref p) = project.sources.audio_file {
+        save_project.sources.audio_file = Some(to_relative(p));
+    }
+    if let Some(ref p) = project.sources.system_audio {
+        save_project.sources.system_audio = Some(to_relative(p));
+    }
+    if let Some(ref p) = project.sources.microphone_audio {
+        save_project.sources.microphone_audio = Some(to_relative(p));
+    }
+    if let Some(ref p) = project.sources.background_music {
+        save_project.sources.background_music = Some(to_relative(p));
     }
 
-    // Legacy: save alongside video with .snapit extension
-    let project_path = video_path.with_extension("snapit");
-    project.updated_at = chrono::Utc::now().to_rfc3339();
-    project.save(&project_path)
+    save_project
 }
 
 /// Load cursor recording data from a JSON file.
@@ -1218,6 +1349,23 @@ pub async fn extract_audio_waveform(
     })
 }
 
+/// Get min/max waveform peaks from a recorded WAV track.
+///
+/// Returns `buckets` (min, max) pairs flattened into one list, for drawing the
+/// audio lane of the editor timeline. The file is streamed, not loaded whole.
+#[command]
+pub async fn get_audio_peaks(audio_path: String, buckets: usize) -> Result<Vec<f32>, String> {
+    let path = std::path::PathBuf::from(&audio_path);
+
+    if !path.exists() {
+        return Err(format!("Audio file not found: {}", audio_path));
+    }
+
+    tokio::task::spawn_blocking(move || audio_peaks::wav_peaks(&path, buckets))
+        .await
+        .map_err(|e| format!("Peak extraction task failed: {}", e))?
+}
+
 /// Generate auto-zoom regions from cursor data.
 ///
 /// Analyzes the cursor recording to find click events and creates zoom regions
@@ -1304,6 +1452,125 @@ pub async fn export_video(
     Ok(result)
 }
 
+/// Re-export several saved video projects with common setting overrides.
+///
+/// Each project is loaded from its library folder, the overrides are merged into
+/// its saved `ExportConfig`, and it is exported through the same GPU path as
+/// `export_video`. Projects run one at a time (the GPU renderer is shared);
+/// a failure is recorded for that project and the batch continues.
+///
+/// Per-project progress is reported via `export-progress` events and overall
+/// progress via `reexport-progress` events.
+///
+/// # Arguments
+/// * `app` - Tauri app handle for progress events
+/// * `project_ids` - Library IDs (project folder names) to re-export
+/// * `overrides` - Settings applied to every project's export config
+///
+/// # Returns
+/// One ReexportResult per requested project, in order
+#[command]
+pub async fn reexport_projects(
+    app: AppHandle,
+    project_ids: Vec<String>,
+    overrides: ExportOverrides,
+) -> Result<Vec<ReexportResult>, String> {
+    if crate::commands::storage::find_ffmpeg().is_none() {
+        return Err(
+            "FFmpeg is not available. Please wait for it to download or restart the app."
+                .to_string(),
+        );
+    }
+
+    let captures_dir = crate::commands::storage::get_captures_dir(&app)?;
+    let total = project_ids.len() as u32;
+    let mut results = Vec::with_capacity(project_ids.len());
+    let mut failed = 0;
+
+    log::info!("[EXPORT] Re-exporting {} projects", total);
+
+    for (index, project_id) in project_ids.into_iter().enumerate() {
+        let _ = app.emit(
+            "reexport-progress",
+            ReexportProgress {
+                project_id: project_id.clone(),
+                completed: index as u32,
+                failed,
+                total,
+            },
+        );
+
+        let outcome = reexport_single_project(&app, &captures_dir, &project_id, &overrides).await;
+        let result = match outcome {
+            Ok(result) => ReexportResult {
+                project_id: project_id.clone(),
+                result: Some(result),
+                error: None,
+            },
+            Err(e) => {
+                log::warn!("[EXPORT] Re-export of '{}' failed: {}", project_id, e);
+                failed += 1;
+                ReexportResult {
+                    project_id: project_id.clone(),
+                    result: None,
+                    error: Some(e),
+                }
+            },
+        };
+        results.push(result);
+
+        let _ = app.emit(
+            "reexport-progress",
+            ReexportProgress {
+                project_id,
+                completed: index as u32 + 1,
+                failed,
+                total,
+            },
+        );
+    }
+
+    log::info!(
+        "[EXPORT] Re-export finished: {} succeeded, {} failed",
+        total - failed,
+        failed
+    );
+
+    Ok(results)
+}
+
+/// Load one library project, apply overrides, and export it next to its source.
+async fn reexport_single_project(
+    app: &AppHandle,
+    captures_dir: &std::path::Path,
+    project_id: &str,
+    overrides: &ExportOverrides,
+) -> Result<ExportResult, String> {
+    let project_dir = captures_dir.join(project_id);
+    let screen_video = project_dir.join("screen.mp4");
+    if !screen_video.exists() {
+        return Err(format!("Video project not found: {}", project_id));
+    }
+
+    let mut project = load_video_project_from_file(&screen_video)?;
+    overrides.apply(&mut project.export);
+
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let output_path = captures_dir.join(format!(
+        "{}_export_{}.{}",
+        project_id,
+        timestamp,
+        project.export.format.extension()
+    ));
+
+    export_video(
+        app.clone(),
+        project,
+        output_path.to_string_lossy().to_string(),
+    )
+    .await
+}
+
 /// Check if NVENC hardware encoding is available.
 ///
 /// This tests if the system has an NVIDIA GPU with NVENC support
@@ -1317,6 +1584,21 @@ pub async fn check_nvenc_available() -> Result<bool, String> {
     Ok(crate::rendering::exporter::is_nvenc_available(&ffmpeg_path))
 }
 
+/// Report which hardware video encoders (NVENC, QSV, AMF) are usable.
+///
+/// Probes FFmpeg's encoder list and test-encodes with each one present,
+/// so it can take a moment; the frontend should cache the result.
+#[command]
+pub async fn get_encoder_capabilities(
+) -> Result<crate::rendering::exporter::EncoderCapabilities, String> {
+    let ffmpeg_path = crate::commands::storage::find_ffmpeg().ok_or("FFmpeg not found")?;
+    tokio::task::spawn_blocking(move || {
+        crate::rendering::exporter::detect_encoder_capabilities(&ffmpeg_path)
+    })
+    .await
+    .map_err(|e| format!("Encoder detection task failed: {}", e))
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -1336,7 +1618,13 @@ pub async fn check_nvenc_available() -> Result<bool, String> {
 ///   - webcam.mp4 (optional)
 ///   - cursor.json (optional)
 ///   - project.json (video project metadata)
-pub fn generate_output_path(settings: &RecordingSettings) -> Result<PathBuf, String> {
+///
+/// If the user configured a filename template (`general.filenameTemplate`), it names
+/// the file or folder instead of `recording_{timestamp}_{rand}`.
+pub fn generate_output_path(
+    app: &AppHandle,
+    settings: &RecordingSettings,
+) -> Result<PathBuf, String> {
     // Get the default save directory from settings
     let save_dir = crate::commands::settings::get_default_save_dir_sync().unwrap_or_else(|_| {
         dirs::video_dir()
@@ -1350,16 +1638,24 @@ pub fn generate_output_path(settings: &RecordingSettings) -> Result<PathBuf, Str
 
     // Generate name with timestamp
     let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let templated = |extension: Option<&str>| {
+        let source = recording_source_name(&settings.mode);
+        crate::commands::storage::filename::templated_filename(app, &save_dir, &source, extension)
+    };
 
     match settings.format {
         RecordingFormat::Mp4 => {
             if settings.quick_capture {
                 // Quick capture: flat file, skip editor
-                let filename = format!("recording_{}_{}.mp4", timestamp, rand::random::<u16>());
+                let filename = templated(Some("mp4")).unwrap_or_else(|| {
+                    format!("recording_{}_{}.mp4", timestamp, rand::random::<u16>())
+                });
                 Ok(save_dir.join(filename))
             } else {
                 // Editor flow: create a project folder
-                let folder_name = format!("recording_{}_{}", timestamp, rand::random::<u16>());
+                let folder_name = templated(None).unwrap_or_else(|| {
+                    format!("recording_{}_{}", timestamp, rand::random::<u16>())
+                });
                 let folder_path = save_dir.join(&folder_name);
                 std::fs::create_dir_all(&folder_path)
                     .map_err(|e| format!("Failed to create recording folder: {}", e))?;
@@ -1368,12 +1664,28 @@ pub fn generate_output_path(settings: &RecordingSettings) -> Result<PathBuf, Str
         },
         RecordingFormat::Gif => {
             // For GIF, use flat file (no complex artifacts)
-            let filename = format!("recording_{}_{}.gif", timestamp, rand::random::<u16>());
+            let filename = templated(Some("gif")).unwrap_or_else(|| {
+                format!("recording_{}_{}.gif", timestamp, rand::random::<u16>())
+            });
             Ok(save_dir.join(filename))
         },
     }
 }
 
+/// `{source}` for a recording's filename template.
+fn recording_source_name(mode: &RecordingMode) -> String {
+    match mode {
+        RecordingMode::Region { .. } => "Region".to_string(),
+        RecordingMode::Window { window_id } | RecordingMode::WindowRegion { window_id, .. } => {
+            crate::commands::storage::filename::window_source_name(*window_id)
+        },
+        RecordingMode::Monitor { monitor_index } => {
+            crate::commands::storage::filename::monitor_source_name(*monitor_index)
+        },
+        RecordingMode::AllMonitors => "All Displays".to_string(),
+    }
+}
+
 /// Emit a recording state change event to the frontend.
 pub fn emit_state_change(app: &AppHandle, state: &RecordingState) {
     // Debug: log the serialized JSON to verify field names
@@ -1394,13 +1706,20 @@ pub fn emit_state_change(app: &AppHandle, state: &RecordingState) {
 /// # Arguments
 /// * `mic_device_index` - Optional microphone device index to monitor
 /// * `monitor_system_audio` - Whether to monitor system audio (loopback)
+/// * `noise_gate_threshold_db` - Meter the microphone through a noise gate at this threshold
 #[command]
 pub fn start_audio_monitoring(
     app: AppHandle,
     mic_device_index: Option<usize>,
     monitor_system_audio: bool,
+    noise_gate_threshold_db: Option<f32>,
 ) -> Result<(), String> {
-    audio_monitor::start_monitoring(app, mic_device_index, monitor_system_audio)
+    audio_monitor::start_monitoring(
+        app,
+        mic_device_index,
+        monitor_system_audio,
+        noise_gate_threshold_db,
+    )
 }
 
 /// Stop audio level monitoring.
@@ -1,9 +1,15 @@
 //! Unified capture source abstraction for video recording.
 //!
 //! Uses D3D capture (scap-direct3d) for all capture types for reliable frame capture.
+//! When a fullscreen-exclusive game owns the target display, WGC only produces
+//! black frames, so DXGI Desktop Duplication is selected instead.
+
+use parking_lot::Mutex;
 
 use super::super::d3d_capture::{D3DCaptureConfig, D3DFrame, D3DVideoCapture};
+use super::super::dxgi_capture::{is_fullscreen_exclusive_on_display, DxgiDuplicationCapture};
 use super::super::timestamp::PerformanceCounterTimestamp;
+use super::helpers::get_window_region_rect;
 
 /// A captured video frame.
 pub struct CapturedFrame {
@@ -26,12 +32,163 @@ impl From<D3DFrame> for CapturedFrame {
     }
 }
 
+/// Message shown when neither backend can capture a fullscreen-exclusive game.
+const EXCLUSIVE_FULLSCREEN_HINT: &str =
+    "A fullscreen-exclusive game is running on this display and \
+     cannot be captured. Switch the game to borderless windowed mode and try again.";
+
+/// Capture backend selected for a source.
+enum CaptureBackend {
+    /// Windows Graphics Capture (default for everything)
+    Wgc(D3DVideoCapture),
+    /// DXGI Desktop Duplication (fullscreen-exclusive fallback)
+    Dxgi(DxgiDuplicationCapture),
+}
+
+/// Crop that follows a window, for `WindowRegion` recordings.
+///
+/// The whole monitor is captured and the region is cut out in software, since
+/// the backend crop is fixed once capture starts.
+struct WindowRegionTracker {
+    window_id: u32,
+    /// (x, y, width, height) relative to the window's client area.
+    region: (i32, i32, u32, u32),
+    /// Top-left of the captured monitor in screen space.
+    monitor_origin: (i32, i32),
+    /// Top-left of the region in screen space, as of the last frame.
+    screen_origin: Mutex<(i32, i32)>,
+    /// Set once the window is closed or minimized.
+    error: Mutex<Option<String>>,
+}
+
+impl WindowRegionTracker {
+    /// Current screen-space origin of the region, recording an error once the
+    /// window is closed or minimized.
+    fn locate(&self) -> Option<(i32, i32)> {
+        let (x, y, width, height) = self.region;
+        match get_window_region_rect(self.window_id, x, y, width, height) {
+            Ok((screen_x, screen_y, _, _)) => {
+                *self.screen_origin.lock() = (screen_x, screen_y);
+                Some((screen_x, screen_y))
+            },
+            Err(e) => {
+                *self.error.lock() = Some(e);
+                None
+            },
+        }
+    }
+
+    /// Re-query the window bounds and cut the region out of a full-monitor frame.
+    fn crop(&self, frame: CapturedFrame) -> Option<CapturedFrame> {
+        let (_, _, width, height) = self.region;
+        let (screen_x, screen_y) = self.locate()?;
+        Some(CapturedFrame {
+            data: crop_bgra_padded(
+                &frame.data,
+                frame.width,
+                frame.height,
+                (
+                    screen_x - self.monitor_origin.0,
+                    screen_y - self.monitor_origin.1,
+                ),
+                width,
+                height,
+            ),
+            width,
+            height,
+            timestamp_100ns: frame.timestamp_100ns,
+        })
+    }
+}
+
+/// Copy a `width`x`height` BGRA rectangle at `origin` out of a frame.
+///
+/// Parts of the rectangle outside the frame (window dragged partly off the
+/// monitor) are left black so the output size never changes mid-recording.
+fn crop_bgra_padded(
+    data: &[u8],
+    frame_width: u32,
+    frame_height: u32,
+    origin: (i32, i32),
+    width: u32,
+    height: u32,
+) -> Vec<u8> {
+    let mut out = vec![0u8; width as usize * height as usize * 4];
+
+    let src_left = origin.0.max(0);
+    let src_right = (origin.0 + width as i32).min(frame_width as i32);
+    if src_left >= src_right {
+        return out;
+    }
+    let row_bytes = (src_right - src_left) as usize * 4;
+    let dst_col = (src_left - origin.0) as usize * 4;
+
+    for row in 0..height as i32 {
+        let src_row = origin.1 + row;
+        if src_row < 0 || src_row >= frame_height as i32 {
+            continue;
+        }
+        let src_start = (src_row as usize * frame_width as usize + src_left as usize) * 4;
+        let dst_start = row as usize * width as usize * 4 + dst_col;
+        if let Some(src) = data.get(src_start..src_start + row_bytes) {
+            out[dst_start..dst_start + row_bytes].copy_from_slice(src);
+        }
+    }
+
+    out
+}
+
+/// Index and screen-space origin of the scap display containing a point.
+fn find_scap_display_containing(x: i32, y: i32) -> Option<(usize, i32, i32)> {
+    scap_targets::Display::list()
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, d)| {
+            let bounds = d.physical_bounds()?;
+            let pos_x = bounds.position().x() as i32;
+            let pos_y = bounds.position().y() as i32;
+            let size_w = bounds.size().width() as i32;
+            let size_h = bounds.size().height() as i32;
+            if x >= pos_x && x < pos_x + size_w && y >= pos_y && y < pos_y + size_h {
+                Some((idx, pos_x, pos_y))
+            } else {
+                None
+            }
+        })
+        .next()
+}
+
 /// Unified capture source using D3D capture for all types.
 pub struct CaptureSource {
-    d3d: D3DVideoCapture,
+    backend: CaptureBackend,
+    /// Per-frame crop for `WindowRegion` recordings.
+    window_region: Option<WindowRegionTracker>,
 }
 
 impl CaptureSource {
+    /// Create the backend for a display, falling back to Desktop Duplication
+    /// when a fullscreen-exclusive application owns it.
+    fn create_backend(config: D3DCaptureConfig) -> Result<CaptureBackend, String> {
+        if is_fullscreen_exclusive_on_display(config.display_index) {
+            log::warn!(
+                "[CAPTURE] Fullscreen-exclusive application on monitor {}, using DXGI desktop duplication",
+                config.display_index
+            );
+
+            let mut dxgi = DxgiDuplicationCapture::new(config.display_index, config.crop)
+                .map_err(|e| format!("{} ({})", EXCLUSIVE_FULLSCREEN_HINT, e))?;
+            dxgi.start()
+                .map_err(|e| format!("{} ({})", EXCLUSIVE_FULLSCREEN_HINT, e))?;
+
+            return Ok(CaptureBackend::Dxgi(dxgi));
+        }
+
+        let mut d3d = D3DVideoCapture::new(config)?;
+        d3d.start()?;
+
+        Ok(CaptureBackend::Wgc(d3d))
+    }
+
     /// Create a capture source for a monitor (full monitor capture).
     pub fn new_monitor(monitor_index: usize, include_cursor: bool) -> Result<Self, String> {
         log::info!(
@@ -40,16 +197,17 @@ impl CaptureSource {
             include_cursor
         );
 
-        let mut d3d = D3DVideoCapture::new(D3DCaptureConfig {
+        let backend = Self::create_backend(D3DCaptureConfig {
             display_index: monitor_index,
             fps: 60,
             show_cursor: include_cursor,
             crop: None,
         })?;
 
-        d3d.start()?;
-
-        Ok(CaptureSource { d3d })
+        Ok(CaptureSource {
+            backend,
+            window_region: None,
+        })
     }
 
     /// Create a capture source for a window.
@@ -105,27 +263,7 @@ impl CaptureSource {
         let center_x = x + (width as i32 / 2);
         let center_y = y + (height as i32 / 2);
 
-        let displays = scap_targets::Display::list();
-        let (monitor_index, mon_x, mon_y) = displays
-            .iter()
-            .enumerate()
-            .filter_map(|(idx, d)| {
-                let bounds = d.physical_bounds()?;
-                let pos_x = bounds.position().x() as i32;
-                let pos_y = bounds.position().y() as i32;
-                let size_w = bounds.size().width() as i32;
-                let size_h = bounds.size().height() as i32;
-                if center_x >= pos_x
-                    && center_x < pos_x + size_w
-                    && center_y >= pos_y
-                    && center_y < pos_y + size_h
-                {
-                    Some((idx, pos_x, pos_y))
-                } else {
-                    None
-                }
-            })
-            .next()
+        let (monitor_index, mon_x, mon_y) = find_scap_display_containing(center_x, center_y)
             .ok_or_else(|| "Window not on any monitor".to_string())?;
 
         log::info!(
@@ -174,43 +312,154 @@ impl CaptureSource {
         let rel_x = (x - mon_x).max(0) as u32;
         let rel_y = (y - mon_y).max(0) as u32;
 
-        let mut d3d = D3DVideoCapture::new(D3DCaptureConfig {
+        let backend = Self::create_backend(D3DCaptureConfig {
             display_index: monitor_index,
             fps,
             show_cursor: include_cursor,
             crop: Some((rel_x, rel_y, w, h)),
         })?;
 
-        d3d.start()?;
+        Ok(CaptureSource {
+            backend,
+            window_region: None,
+        })
+    }
+
+    /// Create a capture source for an area of a window's client area.
+    ///
+    /// Captures the monitor the region starts on and re-crops every frame from the
+    /// window's current position, so the region follows the window. Once the window
+    /// is closed or minimized no more frames are produced and `check_error` fails.
+    pub fn new_window_region(
+        window_id: u32,
+        region: (i32, i32, u32, u32),
+        fps: u32,
+        include_cursor: bool,
+    ) -> Result<Self, String> {
+        let (x, y, width, height) = region;
+        let (screen_x, screen_y, _, _) = get_window_region_rect(window_id, x, y, width, height)?;
 
-        Ok(CaptureSource { d3d })
+        let center_x = screen_x + (width as i32 / 2);
+        let center_y = screen_y + (height as i32 / 2);
+        let (monitor_index, mon_x, mon_y) = find_scap_display_containing(center_x, center_y)
+            .ok_or_else(|| "Window region not on any monitor".to_string())?;
+
+        log::info!(
+            "[CAPTURE] Creating D3D capture for window {} region {:?} on monitor {} (cursor={})",
+            window_id,
+            region,
+            monitor_index,
+            include_cursor
+        );
+
+        let backend = Self::create_backend(D3DCaptureConfig {
+            display_index: monitor_index,
+            fps,
+            show_cursor: include_cursor,
+            crop: None,
+        })?;
+
+        Ok(CaptureSource {
+            backend,
+            window_region: Some(WindowRegionTracker {
+                window_id,
+                region,
+                monitor_origin: (mon_x, mon_y),
+                screen_origin: Mutex::new((screen_x, screen_y)),
+                error: Mutex::new(None),
+            }),
+        })
+    }
+
+    /// Apply the window-region crop, if any.
+    fn track_window_region(&self, frame: CapturedFrame) -> Option<CapturedFrame> {
+        match &self.window_region {
+            Some(tracker) => tracker.crop(frame),
+            None => Some(frame),
+        }
+    }
+
+    /// Screen-space top-left of a `WindowRegion` crop as of the latest frame.
+    ///
+    /// Lets cursor capture follow the region as the window moves.
+    pub fn window_region_origin(&self) -> Option<(i32, i32)> {
+        self.window_region
+            .as_ref()
+            .map(|tracker| *tracker.screen_origin.lock())
     }
 
     /// Get the capture width.
     pub fn width(&self) -> u32 {
-        self.d3d.width()
+        if let Some(tracker) = &self.window_region {
+            return tracker.region.2;
+        }
+        match &self.backend {
+            CaptureBackend::Wgc(d3d) => d3d.width(),
+            CaptureBackend::Dxgi(dxgi) => dxgi.width(),
+        }
     }
 
     /// Get the capture height.
     pub fn height(&self) -> u32 {
-        self.d3d.height()
+        if let Some(tracker) = &self.window_region {
+            return tracker.region.3;
+        }
+        match &self.backend {
+            CaptureBackend::Wgc(d3d) => d3d.height(),
+            CaptureBackend::Dxgi(dxgi) => dxgi.height(),
+        }
     }
 
     /// Wait for first frame and get actual dimensions.
     pub fn wait_for_first_frame(&self, timeout_ms: u64) -> Option<(u32, u32, CapturedFrame)> {
-        self.d3d
-            .wait_for_first_frame(timeout_ms)
-            .map(|(w, h, f)| (w, h, f.into()))
+        let first = match &self.backend {
+            CaptureBackend::Wgc(d3d) => d3d.wait_for_first_frame(timeout_ms),
+            CaptureBackend::Dxgi(dxgi) => dxgi.wait_for_first_frame(timeout_ms),
+        };
+        let frame = self.track_window_region(first?.2.into())?;
+        Some((frame.width, frame.height, frame))
     }
 
     /// Get next frame with timeout.
     pub fn get_frame(&self, timeout_ms: u64) -> Option<CapturedFrame> {
-        self.d3d.get_frame(timeout_ms).map(|f| f.into())
+        let frame = match &self.backend {
+            CaptureBackend::Wgc(d3d) => d3d.get_frame(timeout_ms),
+            CaptureBackend::Dxgi(dxgi) => dxgi.get_frame(timeout_ms),
+        };
+        self.track_window_region(frame?.into())
+    }
+
+    /// Return a user-facing error if the capture backend has failed.
+    ///
+    /// Only the Desktop Duplication fallback and window-region tracking report
+    /// fatal errors; WGC failures surface as missing frames.
+    pub fn check_error(&self) -> Result<(), String> {
+        if let Some(tracker) = &self.window_region {
+            // WGC sends no frames while the screen is static, so a closed or
+            // minimized window is also detected here, not only when cropping.
+            if tracker.error.lock().is_none() {
+                tracker.locate();
+            }
+            if let Some(e) = tracker.error.lock().clone() {
+                return Err(e);
+            }
+        }
+
+        match &self.backend {
+            CaptureBackend::Dxgi(dxgi) => match dxgi.take_error() {
+                Some(e) => Err(format!("{} ({})", EXCLUSIVE_FULLSCREEN_HINT, e)),
+                None => Ok(()),
+            },
+            CaptureBackend::Wgc(_) => Ok(()),
+        }
     }
 
     /// Stop the capture.
     pub fn stop(&mut self) {
-        self.d3d.stop()
+        match &mut self.backend {
+            CaptureBackend::Wgc(d3d) => d3d.stop(),
+            CaptureBackend::Dxgi(dxgi) => dxgi.stop(),
+        }
     }
 
     /// Drain any buffered frames to ensure the next frame is fresh.
@@ -239,3 +488,26 @@ impl CaptureSource {
         Some((frame, timestamp))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crop_bgra_padded_inside_frame() {
+        // 3x2 frame, each pixel's bytes are its index
+        let frame: Vec<u8> = (0..6u8).flat_map(|i| [i; 4]).collect();
+        let out = crop_bgra_padded(&frame, 3, 2, (1, 0), 2, 2);
+        assert_eq!(out, [[1; 4], [2; 4], [4; 4], [5; 4]].concat());
+    }
+
+    #[test]
+    fn test_crop_bgra_padded_off_frame_is_black() {
+        let frame: Vec<u8> = (0..6u8).flat_map(|i| [i; 4]).collect();
+        let out = crop_bgra_padded(&frame, 3, 2, (-1, 1), 2, 2);
+        assert_eq!(out, [[0; 4], [3; 4], [0; 4], [0; 4]].concat());
+
+        let outside = crop_bgra_padded(&frame, 3, 2, (10, 10), 2, 2);
+        assert!(outside.iter().all(|&b| b == 0));
+    }
+}
@@ -0,0 +1,249 @@
+//! Hardware MP4 encoder for screen frames.
+//!
+//! Uses a Media Foundation sink writer instead of windows-capture's `VideoEncoder`,
+//! which goes through `MediaTranscoder` and only takes a bitrate. The sink writer
+//! hands encoding parameters to the encoder's `ICodecAPI`, so the rate control
+//! mode and keyframe interval from `RecordingSettings` reach the encoder.
+
+use std::path::Path;
+
+use windows::core::HSTRING;
+use windows::Win32::Media::MediaFoundation::{
+    eAVEncCommonRateControlMode_CBR, eAVEncCommonRateControlMode_PeakConstrainedVBR,
+    eAVEncCommonRateControlMode_Quality, CODECAPI_AVEncCommonMaxBitRate,
+    CODECAPI_AVEncCommonMeanBitRate, CODECAPI_AVEncCommonQuality,
+    CODECAPI_AVEncCommonRateControlMode, CODECAPI_AVEncMPVGOPSize, IMFAttributes, IMFByteStream,
+    IMFSinkWriter, MFCreateAttributes, MFCreateMediaType, MFCreateMemoryBuffer, MFCreateSample,
+    MFCreateSinkWriterFromURL, MFMediaType_Video, MFShutdown, MFStartup,
+    MFTranscodeContainerType_MPEG4, MFVideoFormat_H264, MFVideoFormat_HEVC, MFVideoFormat_RGB32,
+    MFVideoInterlace_Progressive, MFSTARTUP_FULL, MF_MT_AVG_BITRATE, MF_MT_DEFAULT_STRIDE,
+    MF_MT_FRAME_RATE, MF_MT_FRAME_SIZE, MF_MT_INTERLACE_MODE, MF_MT_MAJOR_TYPE,
+    MF_MT_PIXEL_ASPECT_RATIO, MF_MT_SUBTYPE, MF_READWRITE_ENABLE_HARDWARE_TRANSFORMS,
+    MF_SINK_WRITER_DISABLE_THROTTLING, MF_TRANSCODE_CONTAINERTYPE, MF_VERSION,
+};
+use windows::Win32::System::Com::{CoInitializeEx, CoUninitialize, COINIT_MULTITHREADED};
+
+use super::super::types::common_quality_for_crf;
+use super::super::RateControl;
+
+/// 100ns units per second (Media Foundation time base).
+const HNS_PER_SECOND: i64 = 10_000_000;
+
+/// BGRA bytes per pixel.
+const BYTES_PER_PIXEL: u32 = 4;
+
+/// Encoder settings for one output file.
+#[derive(Debug, Clone, Copy)]
+pub struct EncoderConfig {
+    pub width: u32,
+    pub height: u32,
+    pub fps: u32,
+    /// Average bitrate in bits per second.
+    pub bitrate: u32,
+    pub rate_control: RateControl,
+    /// Encode H.265 instead of H.264.
+    pub hevc: bool,
+    /// Frames between keyframes.
+    pub gop_size: u32,
+}
+
+/// Encodes BGRA frames into an MP4 file.
+pub struct ScreenEncoder {
+    writer: IMFSinkWriter,
+    stream_index: u32,
+    frame_size: u32,
+    frame_duration: i64,
+    /// Timestamp of the first frame, which the file starts at.
+    first_timestamp: Option<i64>,
+    // Declared last so it shuts down after the writer is released
+    _runtime: MediaFoundation,
+}
+
+impl ScreenEncoder {
+    /// Create an encoder writing to `path` (replaced if it exists).
+    pub fn new(config: &EncoderConfig, path: &Path) -> Result<Self, String> {
+        let runtime = MediaFoundation::start()?;
+        let writer = unsafe { create_sink_writer(path) }
+            .map_err(|e| format!("Failed to create sink writer for {:?}: {}", path, e))?;
+        let stream_index = unsafe { configure_stream(&writer, config) }
+            .map_err(|e| format!("Failed to configure encoder: {}", e))?;
+        unsafe { writer.BeginWriting() }.map_err(|e| format!("Failed to start encoder: {}", e))?;
+
+        Ok(Self {
+            writer,
+            stream_index,
+            frame_size: config.width * config.height * BYTES_PER_PIXEL,
+            frame_duration: HNS_PER_SECOND / i64::from(config.fps.max(1)),
+            first_timestamp: None,
+            _runtime: runtime,
+        })
+    }
+
+    /// Encode one bottom-up BGRA frame at `timestamp` (100ns units).
+    ///
+    /// Timestamps are relative to the first frame, so the file starts at zero.
+    pub fn send_frame_buffer(&mut self, buffer: &[u8], timestamp: i64) -> Result<(), String> {
+        if buffer.len() != self.frame_size as usize {
+            return Err(format!(
+                "Frame is {} bytes, expected {}",
+                buffer.len(),
+                self.frame_size
+            ));
+        }
+        let timestamp = timestamp - *self.first_timestamp.get_or_insert(timestamp);
+
+        unsafe {
+            let media_buffer = MFCreateMemoryBuffer(self.frame_size)
+                .map_err(|e| format!("Failed to allocate frame buffer: {}", e))?;
+            let mut data = std::ptr::null_mut();
+            media_buffer
+                .Lock(&mut data, None, None)
+                .map_err(|e| format!("Failed to lock frame buffer: {}", e))?;
+            std::ptr::copy_nonoverlapping(buffer.as_ptr(), data, buffer.len());
+            media_buffer
+                .Unlock()
+                .map_err(|e| format!("Failed to unlock frame buffer: {}", e))?;
+            media_buffer
+                .SetCurrentLength(self.frame_size)
+                .map_err(|e| format!("Failed to set frame length: {}", e))?;
+
+            let sample = MFCreateSample().map_err(|e| format!("Failed to create sample: {}", e))?;
+            sample
+                .AddBuffer(&media_buffer)
+                .map_err(|e| format!("Failed to add frame buffer: {}", e))?;
+            sample
+                .SetSampleTime(timestamp)
+                .map_err(|e| format!("Failed to set frame time: {}", e))?;
+            sample
+                .SetSampleDuration(self.frame_duration)
+                .map_err(|e| format!("Failed to set frame duration: {}", e))?;
+
+            self.writer
+                .WriteSample(self.stream_index, &sample)
+                .map_err(|e| format!("Failed to encode frame: {}", e))
+        }
+    }
+
+    /// Flush the encoder and finish the MP4 file.
+    pub fn finish(self) -> Result<(), String> {
+        unsafe { self.writer.Finalize() }.map_err(|e| format!("Failed to finalize video: {}", e))
+    }
+}
+
+/// Keeps COM and Media Foundation started on the encoding thread.
+struct MediaFoundation {
+    com_initialized: bool,
+}
+
+impl MediaFoundation {
+    fn start() -> Result<Self, String> {
+        unsafe {
+            let com_initialized = CoInitializeEx(None, COINIT_MULTITHREADED).is_ok();
+            if let Err(e) = MFStartup(MF_VERSION, MFSTARTUP_FULL) {
+                if com_initialized {
+                    CoUninitialize();
+                }
+                return Err(format!("Failed to start Media Foundation: {}", e));
+            }
+            Ok(Self { com_initialized })
+        }
+    }
+}
+
+impl Drop for MediaFoundation {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = MFShutdown();
+            if self.com_initialized {
+                CoUninitialize();
+            }
+        }
+    }
+}
+
+/// Sink writer for an MP4 file, allowed to use hardware encoders.
+unsafe fn create_sink_writer(path: &Path) -> windows::core::Result<IMFSinkWriter> {
+    let mut attributes: Option<IMFAttributes> = None;
+    MFCreateAttributes(&mut attributes, 3)?;
+    let attributes = attributes.ok_or_else(windows::core::Error::empty)?;
+    attributes.SetUINT32(&MF_READWRITE_ENABLE_HARDWARE_TRANSFORMS, 1)?;
+    attributes.SetGUID(&MF_TRANSCODE_CONTAINERTYPE, &MFTranscodeContainerType_MPEG4)?;
+    // Frames come from the capture loop, which must not block on the encoder
+    attributes.SetUINT32(&MF_SINK_WRITER_DISABLE_THROTTLING, 1)?;
+
+    MFCreateSinkWriterFromURL(
+        &HSTRING::from(path.as_os_str()),
+        None::<&IMFByteStream>,
+        &attributes,
+    )
+}
+
+/// Add the video stream: encoded output, BGRA input and the encoder parameters.
+unsafe fn configure_stream(
+    writer: &IMFSinkWriter,
+    config: &EncoderConfig,
+) -> windows::core::Result<u32> {
+    let frame_size = (u64::from(config.width) << 32) | u64::from(config.height);
+    let frame_rate = (u64::from(config.fps) << 32) | 1;
+    let square_pixels = (1u64 << 32) | 1;
+
+    let output_type = MFCreateMediaType()?;
+    output_type.SetGUID(&MF_MT_MAJOR_TYPE, &MFMediaType_Video)?;
+    output_type.SetGUID(
+        &MF_MT_SUBTYPE,
+        if config.hevc {
+            &MFVideoFormat_HEVC
+        } else {
+            &MFVideoFormat_H264
+        },
+    )?;
+    output_type.SetUINT32(&MF_MT_AVG_BITRATE, config.bitrate)?;
+    output_type.SetUINT32(&MF_MT_INTERLACE_MODE, MFVideoInterlace_Progressive.0 as u32)?;
+    output_type.SetUINT64(&MF_MT_FRAME_SIZE, frame_size)?;
+    output_type.SetUINT64(&MF_MT_FRAME_RATE, frame_rate)?;
+    output_type.SetUINT64(&MF_MT_PIXEL_ASPECT_RATIO, square_pixels)?;
+    let stream_index = writer.AddStream(&output_type)?;
+
+    let input_type = MFCreateMediaType()?;
+    input_type.SetGUID(&MF_MT_MAJOR_TYPE, &MFMediaType_Video)?;
+    input_type.SetGUID(&MF_MT_SUBTYPE, &MFVideoFormat_RGB32)?;
+    input_type.SetUINT32(&MF_MT_INTERLACE_MODE, MFVideoInterlace_Progressive.0 as u32)?;
+    input_type.SetUINT64(&MF_MT_FRAME_SIZE, frame_size)?;
+    input_type.SetUINT64(&MF_MT_FRAME_RATE, frame_rate)?;
+    input_type.SetUINT64(&MF_MT_PIXEL_ASPECT_RATIO, square_pixels)?;
+    // Negative stride: rows are stored bottom-up
+    input_type.SetUINT32(
+        &MF_MT_DEFAULT_STRIDE,
+        (-((config.width * BYTES_PER_PIXEL) as i32)) as u32,
+    )?;
+
+    // Applied through the encoder's ICodecAPI
+    let mut parameters: Option<IMFAttributes> = None;
+    MFCreateAttributes(&mut parameters, 4)?;
+    let parameters = parameters.ok_or_else(windows::core::Error::empty)?;
+    parameters.SetUINT32(&CODECAPI_AVEncMPVGOPSize, config.gop_size.max(1))?;
+    match config.rate_control {
+        RateControl::Cbr => {
+            let mode = eAVEncCommonRateControlMode_CBR.0 as u32;
+            parameters.SetUINT32(&CODECAPI_AVEncCommonRateControlMode, mode)?;
+            parameters.SetUINT32(&CODECAPI_AVEncCommonMeanBitRate, config.bitrate)?;
+        },
+        RateControl::Vbr { max_bitrate } => {
+            let mode = eAVEncCommonRateControlMode_PeakConstrainedVBR.0 as u32;
+            parameters.SetUINT32(&CODECAPI_AVEncCommonRateControlMode, mode)?;
+            parameters.SetUINT32(&CODECAPI_AVEncCommonMeanBitRate, config.bitrate)?;
+            parameters.SetUINT32(
+                &CODECAPI_AVEncCommonMaxBitRate,
+                max_bitrate.max(config.bitrate),
+            )?;
+        },
+        RateControl::Quality { crf } => {
+            let mode = eAVEncCommonRateControlMode_Quality.0 as u32;
+            parameters.SetUINT32(&CODECAPI_AVEncCommonRateControlMode, mode)?;
+            parameters.SetUINT32(&CODECAPI_AVEncCommonQuality, common_quality_for_crf(crf))?;
+        },
+    }
+
+    writer.SetInputMediaType(stream_index, &input_type, &parameters)?;
+    Ok(stream_index)
+}
@@ -67,7 +67,30 @@ pub fn run_gif_capture(
     };
 
     // Create capture source based on mode (all use D3D now)
-    let (mut capture, first_frame_dims) = if let Some(wid) = window_id {
+    let (mut capture, first_frame_dims) = if let RecordingMode::WindowRegion {
+        window_id,
+        x,
+        y,
+        width,
+        height,
+    } = &settings.mode
+    {
+        log::debug!(
+            "[GIF] Using D3D window region capture for hwnd={}",
+            window_id
+        );
+        let capture = CaptureSource::new_window_region(
+            *window_id,
+            (*x, *y, *width, *height),
+            settings.fps,
+            settings.include_cursor,
+        )
+        .map_err(|e| format!("Failed to start window region capture: {}", e))?;
+
+        let first_frame = capture.wait_for_first_frame(1000);
+        let dims = first_frame.as_ref().map(|(w, h, _)| (*w, *h));
+        (capture, dims)
+    } else if let Some(wid) = window_id {
         log::debug!("[GIF] Using D3D window capture for hwnd={}", wid);
         let capture = CaptureSource::new_window(wid, settings.include_cursor)
             .map_err(|e| format!("Failed to start D3D window capture: {}", e))?;
@@ -107,6 +130,9 @@ pub fn run_gif_capture(
     };
 
     // Get capture dimensions from first frame
+    if first_frame_dims.is_none() {
+        capture.check_error()?;
+    }
     let (capture_width, capture_height) =
         first_frame_dims.unwrap_or_else(|| (capture.width(), capture.height()));
     let (width, height) = if let Some((_, _, w, h)) = crop_region {
@@ -137,20 +163,61 @@ pub fn run_gif_capture(
     let start_time = Instant::now();
     let mut last_frame_time = start_time;
 
+    // Pause tracking - paused time is cut from frame timestamps so the GIF
+    // continues seamlessly instead of freezing for the length of the pause
+    let mut paused = false;
+    let mut pause_time = Duration::ZERO;
+    let mut pause_start: Option<Instant> = None;
+
     loop {
-        // Check for commands
-        match command_rx.try_recv() {
-            Ok(RecorderCommand::Stop) | Ok(RecorderCommand::Cancel) => {
-                if matches!(command_rx.try_recv(), Ok(RecorderCommand::Cancel)) {
-                    progress.mark_cancelled();
-                }
+        // While paused, block on commands instead of capturing
+        let command = if paused {
+            match command_rx.recv_timeout(Duration::from_millis(100)) {
+                Ok(command) => Some(command),
+                Err(crossbeam_channel::RecvTimeoutError::Timeout) => None,
+                Err(crossbeam_channel::RecvTimeoutError::Disconnected) => break,
+            }
+        } else {
+            match command_rx.try_recv() {
+                Ok(command) => Some(command),
+                Err(crossbeam_channel::TryRecvError::Empty) => None,
+                Err(crossbeam_channel::TryRecvError::Disconnected) => break,
+            }
+        };
+
+        match command {
+            Some(RecorderCommand::Stop) => break,
+            Some(RecorderCommand::Cancel) => {
+                progress.mark_cancelled();
                 break;
             },
-            _ => {},
+            Some(RecorderCommand::Pause) => {
+                if !paused {
+                    paused = true;
+                    pause_start = Some(Instant::now());
+                    progress.set_paused(true);
+                }
+            },
+            Some(RecorderCommand::Resume) => {
+                if paused {
+                    if let Some(ps) = pause_start.take() {
+                        pause_time += ps.elapsed();
+                    }
+                    paused = false;
+                    progress.set_paused(false);
+                    // Don't wait out a frame interval that started before the pause
+                    last_frame_time = Instant::now() - frame_duration;
+                }
+            },
+            None => {},
+        }
+
+        if paused {
+            continue;
         }
 
-        // Check max duration
-        let elapsed = start_time.elapsed();
+        // Check max duration (recorded time, excluding pauses)
+        let elapsed = start_time.elapsed() - pause_time;
         if let Some(max_dur) = max_duration {
             if elapsed >= max_dur {
                 break;
@@ -168,13 +235,16 @@ pub fn run_gif_capture(
         // Get the most recent frame
         let frame = match capture.get_frame(frame_timeout_ms) {
             Some(f) => f,
-            None => continue,
+            None => {
+                capture.check_error()?;
+                continue;
+            },
         };
 
         last_frame_time = Instant::now();
 
         // D3D returns BGRA - keep it as BGRA, FFmpeg will handle it
-        // Note: D3D capture with crop already returns cropped frames, no manual crop needed
+        // Note: region and window-region frames are already cropped by CaptureSource
         let final_data = frame.data;
 
         // Add frame with actual elapsed timestamp
@@ -187,12 +257,18 @@ pub fn run_gif_capture(
 
         let frame_count = progress.get_frame_count();
         if frame_count % 30 == 0 {
+            let current_size_bytes = recorder
+                .lock()
+                .map(|rec| rec.estimated_size_bytes())
+                .unwrap_or(0);
+            progress.set_size_bytes(current_size_bytes);
             emit_state_change(
                 app,
                 &RecordingState::Recording {
                     started_at: started_at.to_string(),
                     elapsed_secs: elapsed.as_secs_f64(),
                     frame_count,
+                    current_size_bytes,
                 },
             );
         }
@@ -201,8 +277,11 @@ pub fn run_gif_capture(
     // Stop D3D capture
     capture.stop();
 
-    // Capture duration before any post-processing
-    let recording_duration = start_time.elapsed().as_secs_f64();
+    // Capture duration before any post-processing, excluding pauses
+    if let Some(ps) = pause_start.take() {
+        pause_time += ps.elapsed();
+    }
+    let recording_duration = (start_time.elapsed() - pause_time).as_secs_f64();
 
     // Check if cancelled
     if progress.was_cancelled() {
@@ -212,7 +291,7 @@ pub fn run_gif_capture(
     // Encode GIF
     emit_state_change(app, &RecordingState::Processing { progress: 0.0 });
 
-    let total_duration = start_time.elapsed();
+    let total_duration = start_time.elapsed() - pause_time;
     let recorder_guard = recorder.lock().map_err(|_| "Failed to lock recorder")?;
     let frame_count = recorder_guard.frame_count();
 
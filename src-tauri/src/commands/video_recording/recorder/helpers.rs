@@ -5,6 +5,8 @@
 
 use std::path::PathBuf;
 
+use super::super::frame_timestamps::FRAME_TIMESTAMPS_FILE;
+use super::super::keystrokes::KEYSTROKE_FILE;
 use super::super::video_project::VideoProject;
 use super::super::RecordingMode;
 
@@ -486,6 +488,38 @@ pub fn get_window_rect(_window_id: u32) -> Result<(i32, i32, u32, u32), String>
     Err("Window capture not supported on this platform".to_string())
 }
 
+/// Screen-space bounds of a `WindowRegion` capture: the window's client-area
+/// origin plus the region offset. Fails once the window is closed or minimized.
+pub fn get_window_region_rect(
+    window_id: u32,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+) -> Result<(i32, i32, u32, u32), String> {
+    use std::str::FromStr;
+
+    let window = scap_targets::WindowId::from_str(&window_id.to_string())
+        .ok()
+        .and_then(|id| scap_targets::Window::from_id_unfiltered(&id))
+        .ok_or_else(|| "The recorded window was closed".to_string())?;
+
+    if window.is_minimized() {
+        return Err("The recorded window was minimized".to_string());
+    }
+
+    let client = window
+        .physical_client_bounds()
+        .ok_or_else(|| "Failed to get window client area".to_string())?;
+
+    Ok((
+        client.position().x() as i32 + x,
+        client.position().y() as i32 + y,
+        width,
+        height,
+    ))
+}
+
 /// Convert Window mode to Region mode by getting window bounds.
 /// Only used for modes that don't support native window capture.
 #[allow(dead_code)]
@@ -512,6 +546,65 @@ pub fn is_window_mode(mode: &RecordingMode) -> Option<u32> {
     }
 }
 
+// ============================================================================
+// Encoder Helpers
+// ============================================================================
+
+/// Whether a hardware HEVC (H.265) encoder is installed. Checked once per process.
+#[cfg(target_os = "windows")]
+pub fn hevc_encoder_available() -> bool {
+    use std::sync::OnceLock;
+    use windows::Win32::{
+        Media::MediaFoundation::{
+            IMFActivate, MFMediaType_Video, MFTEnumEx, MFVideoFormat_HEVC,
+            MFT_CATEGORY_VIDEO_ENCODER, MFT_ENUM_FLAG_HARDWARE, MFT_ENUM_FLAG_SORTANDFILTER,
+            MFT_REGISTER_TYPE_INFO,
+        },
+        System::Com::CoTaskMemFree,
+    };
+
+    static AVAILABLE: OnceLock<bool> = OnceLock::new();
+
+    *AVAILABLE.get_or_init(|| {
+        let output_type = MFT_REGISTER_TYPE_INFO {
+            guidMajorType: MFMediaType_Video,
+            guidSubtype: MFVideoFormat_HEVC,
+        };
+        let mut activates: *mut Option<IMFActivate> = std::ptr::null_mut();
+        let mut count = 0u32;
+
+        let result = unsafe {
+            MFTEnumEx(
+                MFT_CATEGORY_VIDEO_ENCODER,
+                MFT_ENUM_FLAG_HARDWARE | MFT_ENUM_FLAG_SORTANDFILTER,
+                None,
+                Some(&output_type),
+                &mut activates,
+                &mut count,
+            )
+        };
+
+        if !activates.is_null() {
+            unsafe {
+                // Release the activation objects before freeing the array
+                for i in 0..count as usize {
+                    drop((*activates.add(i)).take());
+                }
+                CoTaskMemFree(Some(activates as *const std::ffi::c_void));
+            }
+        }
+
+        let available = result.is_ok() && count > 0;
+        log::info!("[ENCODER] Hardware HEVC encoder available: {}", available);
+        available
+    })
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn hevc_encoder_available() -> bool {
+    false
+}
+
 // ============================================================================
 // Video Project Creation
 // ============================================================================
@@ -528,6 +621,8 @@ pub fn create_video_project_file(
     fps: u32,
     has_webcam: bool,
     has_cursor_data: bool,
+    has_frame_timestamps: bool,
+    has_keystrokes: bool,
     has_system_audio: bool,
     has_mic_audio: bool,
 ) -> Result<(), String> {
@@ -551,6 +646,15 @@ pub fn create_video_project_file(
         project.sources.cursor_data = Some("cursor.json".to_string());
     }
 
+    if has_frame_timestamps {
+        project.sources.frame_timestamps = Some(FRAME_TIMESTAMPS_FILE.to_string());
+    }
+
+    if has_keystrokes {
+        project.sources.keystrokes = Some(KEYSTROKE_FILE.to_string());
+        project.keystrokes.enabled = true;
+    }
+
     // Add audio file paths (editor flow keeps separate audio files)
     if has_system_audio {
         project.sources.system_audio = Some("system.wav".to_string());
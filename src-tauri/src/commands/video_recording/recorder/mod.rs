@@ -1,17 +1,20 @@
 //! Core video recording implementation.
 //!
 //! Uses Windows Graphics Capture (WGC) for frame capture
-//! and VideoEncoder for hardware-accelerated MP4 encoding.
+//! and a Media Foundation sink writer for hardware-accelerated MP4 encoding.
 
 // Allow unused internal helpers - may be useful for future features
 #![allow(dead_code)]
 
 mod buffer;
 mod capture_source;
+mod encoder;
 mod gif;
 mod helpers;
 mod video;
 
+pub use helpers::hevc_encoder_available;
+
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
@@ -117,6 +120,7 @@ pub async fn start_recording(
                     started_at: started_at.clone(),
                     elapsed_secs: 0.0,
                     frame_count: 0,
+                    current_size_bytes: 0,
                 },
             );
 
@@ -348,12 +352,6 @@ pub async fn pause_recording(app: AppHandle) -> Result<(), String> {
         return Err("No active recording to pause".to_string());
     }
 
-    if let Some(ref settings) = controller.settings {
-        if settings.format == RecordingFormat::Gif {
-            return Err("GIF recording cannot be paused".to_string());
-        }
-    }
-
     controller.send_command(RecorderCommand::Pause)?;
     controller.set_paused(true);
     emit_state_change(&app, &controller.state);
@@ -1,38 +1,73 @@
 //! Video (MP4) capture implementation.
 //!
 //! Uses Scap for frame capture (with SystemTime-based timestamps)
-//! and VideoEncoder for hardware-accelerated MP4 encoding.
+//! and ScreenEncoder for hardware-accelerated MP4 encoding.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use crossbeam_channel::{Receiver, TryRecvError};
 use tauri::AppHandle;
-use windows_capture::encoder::{
-    AudioSettingsBuilder, ContainerSettingsBuilder, VideoEncoder, VideoSettingsBuilder,
-    VideoSettingsSubType,
-};
 
 use super::super::audio_multitrack::MultiTrackAudioRecorder;
 use super::super::cursor::{save_cursor_recording, CursorEventCapture};
+use super::super::fragmentation::{atomic_write_json, sync_file, FragmentManifest};
+use super::super::frame_timestamps::{FrameTimestamps, FRAME_TIMESTAMPS_FILE};
+use super::super::keystrokes::{KeystrokeCapture, KEYSTROKE_FILE};
 use super::super::state::{RecorderCommand, RecordingProgress};
 use super::super::timestamp::Timestamps;
 use super::super::webcam::{
-    global_feed_dimensions, start_global_feed, stop_capture_service, stop_global_feed,
-    FeedWebcamEncoder, WebcamEncoderPipe,
+    concatenate_segments, global_feed_dimensions, start_global_feed, stop_capture_service,
+    stop_global_feed, FeedWebcamEncoder, SegmentInfo, WebcamEncoderPipe,
 };
 use super::super::{
     emit_state_change, find_monitor_for_point, get_scap_display_bounds, get_webcam_settings,
-    RecordingMode, RecordingSettings, RecordingState,
+    RecordingMode, RecordingSettings, RecordingState, VideoCodec,
 };
 use super::buffer::FrameBufferPool;
 use super::capture_source::CaptureSource;
+use super::encoder::{EncoderConfig, ScreenEncoder};
 use super::helpers::{
     create_video_project_file, is_window_mode, make_video_faststart, mux_audio_to_video,
 };
 
+/// Manifest listing the screen segments of a split recording, for crash recovery.
+const SEGMENT_MANIFEST_FILE: &str = "segments.json";
+
+/// Combined size of the screen and webcam files written so far.
+/// The encoders stream to disk, so this tracks the recording as it grows.
+fn output_size_bytes<'a>(paths: impl IntoIterator<Item = &'a Path>) -> u64 {
+    paths
+        .into_iter()
+        .filter_map(|path| std::fs::metadata(path).ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+/// File name of a screen segment (`index` is 0-based, names start at part01).
+fn segment_file_name(index: u32) -> String {
+    format!("recording_part{:02}.mp4", index + 1)
+}
+
+/// Rewrite the segment manifest: finished segments plus the one being written.
+fn write_segment_manifest(path: &Path, finished: &[SegmentInfo], current: Option<(&Path, u32)>) {
+    let mut manifest = FragmentManifest::new();
+    for segment in finished {
+        manifest.add_completed_fragment(segment.path.clone(), segment.index, segment.duration);
+    }
+    match current {
+        Some((current_path, index)) => {
+            manifest.add_in_progress_fragment(current_path.to_path_buf(), index)
+        },
+        None => manifest.finalize(),
+    }
+    if let Err(e) = atomic_write_json(path, &manifest) {
+        log::warn!("[CAPTURE] Failed to write segment manifest: {}", e);
+    }
+}
+
 /// Run video (MP4) capture using Windows Graphics Capture (WGC).
 ///
 /// For MP4, `output_path` is a project folder containing:
@@ -128,7 +163,33 @@ pub fn run_video_capture(
     // NOTE: We always capture WITHOUT the baked-in cursor. The cursor is rendered
     // separately via the cursor overlay in the video editor, which allows for
     // customization (size, style, visibility) and proper zoom tracking.
-    let (capture_source, first_frame) = if let Some(wid) = window_id {
+    let (capture_source, first_frame) = if let RecordingMode::WindowRegion {
+        window_id,
+        x,
+        y,
+        width,
+        height,
+    } = &settings.mode
+    {
+        log::debug!(
+            "[CAPTURE] Using WGC window region capture for hwnd={}: ({}, {}) {}x{}",
+            window_id,
+            x,
+            y,
+            width,
+            height
+        );
+        let source = CaptureSource::new_window_region(
+            *window_id,
+            (*x, *y, *width, *height),
+            settings.fps,
+            false,
+        )
+        .map_err(|e| format!("Failed to create window region capture: {}", e))?;
+
+        let first_frame = source.wait_for_first_frame(1000);
+        (source, first_frame)
+    } else if let Some(wid) = window_id {
         log::debug!("[CAPTURE] Using Scap window capture for hwnd={}", wid);
         let source = CaptureSource::new_window(wid, false)
             .map_err(|e| format!("Failed to create Scap window capture: {}", e))?;
@@ -186,7 +247,19 @@ pub fn run_video_capture(
         (capture_source.width(), capture_source.height())
     };
 
-    let bitrate = settings.calculate_bitrate(width, height);
+    // Fail fast instead of recording a black screen when the fullscreen-exclusive
+    // fallback couldn't produce frames.
+    if first_frame.is_none() {
+        capture_source.check_error()?;
+    }
+
+    let bitrate = settings.encoder_bitrate(width, height);
+    log::debug!(
+        "[CAPTURE] Rate control {:?}, target bitrate {} bps, GOP {} frames",
+        settings.rate_control,
+        bitrate,
+        settings.gop_size()
+    );
     let max_duration = settings
         .max_duration_secs
         .map(|s| Duration::from_secs(s as u64));
@@ -195,25 +268,69 @@ pub fn run_video_capture(
     let _capture_audio =
         settings.audio.capture_system_audio || settings.audio.microphone_device_index.is_some();
 
-    // Create video encoder with audio enabled if needed
-    // Use H.264 codec for better browser/WebView compatibility (HEVC requires paid extension)
-    let video_settings = VideoSettingsBuilder::new(width, height)
-        .sub_type(VideoSettingsSubType::H264)
-        .bitrate(bitrate)
-        .frame_rate(settings.fps);
-
-    // ALWAYS disable audio in VideoEncoder - windows-capture's MediaTranscoder
-    // introduces audio jitter. Instead, we use MultiTrackAudioRecorder to capture
-    // perfect WAV files, then mux with FFmpeg post-recording.
-    let audio_settings = AudioSettingsBuilder::default().disabled(true);
-
-    let mut encoder = VideoEncoder::new(
-        video_settings,
-        audio_settings,
-        ContainerSettingsBuilder::default(),
-        &screen_video_path,
-    )
-    .map_err(|e| format!("Failed to create encoder: {:?}", e))?;
+    // Long recordings can be split into segments that are joined on stop, so a
+    // crash only loses the segment being written. Segment frame timestamps restart
+    // at zero, but are taken from the same master clock (`actual_elapsed`) as the
+    // cursor and audio, so the joined video stays in sync.
+    let segment_duration = settings
+        .segment_duration_secs
+        .map(|secs| Duration::from_secs(secs as u64));
+    let segment_manifest_path = output_path.join(SEGMENT_MANIFEST_FILE);
+    let mut finished_segments: Vec<SegmentInfo> = Vec::new();
+    let mut current_segment_path = if segment_duration.is_some() {
+        output_path.join(segment_file_name(0))
+    } else {
+        screen_video_path.clone()
+    };
+
+    // Create video encoder. H.264 is the default for browser/WebView compatibility
+    // (HEVC playback requires a paid extension); H.265 is opt-in for smaller files.
+    // The encoder is video-only: MultiTrackAudioRecorder captures WAV files,
+    // which FFmpeg muxes in post-recording.
+    let create_encoder = |hevc: bool, path: &Path| {
+        let config = EncoderConfig {
+            width,
+            height,
+            fps: settings.fps,
+            bitrate,
+            rate_control: settings.rate_control,
+            hevc,
+            gop_size: settings.gop_size(),
+        };
+        ScreenEncoder::new(&config, path)
+    };
+
+    let mut use_hevc = settings.video_codec == VideoCodec::H265;
+    let mut encoder = match create_encoder(use_hevc, &current_segment_path) {
+        Ok(encoder) => encoder,
+        Err(e) if use_hevc => {
+            // Some GPUs list an HEVC encoder but can't handle this size/rate
+            log::warn!(
+                "[CAPTURE] HEVC encoder failed, falling back to H.264: {}",
+                e
+            );
+            emit_state_change(
+                app,
+                &RecordingState::Warning {
+                    message: "HEVC isn't supported by this GPU, recording in H.264 instead"
+                        .to_string(),
+                },
+            );
+            use_hevc = false;
+            create_encoder(use_hevc, &current_segment_path)
+                .map_err(|e| format!("Failed to create encoder: {}", e))?
+        },
+        Err(e) => return Err(format!("Failed to create encoder: {}", e)),
+    };
+
+    if segment_duration.is_some() {
+        write_segment_manifest(
+            &segment_manifest_path,
+            &finished_segments,
+            Some((&current_segment_path, 0)),
+        );
+        progress.set_segment(1);
+    }
 
     // === SHARED CONTROL FLAGS ===
     let should_stop = Arc::new(AtomicBool::new(false));
@@ -266,7 +383,9 @@ pub fn run_video_capture(
     // This enables independent volume control in the video editor.
     // Use shared flags so pause/resume affects multi-track audio too.
     let mut multitrack_audio =
-        MultiTrackAudioRecorder::with_flags(Arc::clone(&should_stop), Arc::clone(&is_paused));
+        MultiTrackAudioRecorder::with_flags(Arc::clone(&should_stop), Arc::clone(&is_paused))
+            .with_noise_gate(settings.audio.noise_gate_threshold())
+            .with_mic_channel_mode(settings.audio.mic_channel_mode);
 
     // Audio files location depends on capture mode:
     // - Quick capture: output_path is a FILE (e.g., recording.mp4), so put audio as siblings
@@ -335,11 +454,15 @@ pub fn run_video_capture(
     // Recording loop variables
     let frame_duration = Duration::from_secs_f64(1.0 / settings.fps as f64);
     let mut frame_count: u64 = 0;
+    // Master clock time of each encoded frame (the PTS it is encoded with)
+    let mut frame_timestamps = FrameTimestamps::new(settings.fps);
     let mut paused = false;
     let mut pause_time = Duration::ZERO;
     let mut pause_start: Option<Instant> = None;
     let mut first_frame_captured = false;
     let mut first_frame_hw_timestamp: i64 = 0; // Hardware timestamp of first video frame
+    let mut segment_start = Duration::ZERO; // Master clock time the current segment began
+    let mut segment_frames: u64 = 0;
 
     // === START RECORDING ===
     // Recording state was already emitted before thread started (optimistic UI)
@@ -410,6 +533,13 @@ pub fn run_video_capture(
                 },
             }
         },
+        RecordingMode::WindowRegion { width, height, .. } => {
+            // Starts where the capture source last found the region; the origin
+            // is moved every frame as the window moves (see move_region below).
+            capture_source
+                .window_region_origin()
+                .map(|(x, y)| (x, y, *width, *height))
+        },
         RecordingMode::Monitor { monitor_index } => {
             // Get monitor bounds for cursor coordinate normalization.
             // CRITICAL: Use scap's display enumeration (same as video capture) to ensure
@@ -446,10 +576,19 @@ pub fn run_video_capture(
         }
     }
 
+    // Key presses for the keystroke overlay, on the same clock as cursor events
+    let mut keystroke_capture = KeystrokeCapture::new();
+    if settings.capture_keystrokes && !settings.quick_capture {
+        if let Err(e) = keystroke_capture.start_with_time(start_time) {
+            log::warn!("Failed to start keystroke capture: {}", e);
+        }
+    }
+
     // NOTE: Do NOT use the pre-captured first frame for recording!
     // It was captured BEFORE start_time, so cursor timestamps won't align.
     // We only use first_frame for dimension detection, then wait for a fresh frame.
     let mut pending_first_frame: Option<Vec<u8>> = None;
+    let mut capture_error: Option<String> = None;
 
     // Wait for a frame captured AFTER our start time.
     // Pre-buffered frames have timestamps before start_time, which would cause
@@ -599,6 +738,12 @@ pub fn run_video_capture(
 
         // Skip if no frame was acquired
         if !frame_data {
+            if let Err(e) = capture_source.check_error() {
+                log::error!("[CAPTURE] Capture backend failed: {}", e);
+                capture_error = Some(e);
+                should_stop.store(true, Ordering::SeqCst);
+                break;
+            }
             continue;
         }
 
@@ -623,6 +768,11 @@ pub fn run_video_capture(
 
         last_frame_time = Instant::now();
 
+        // Keep cursor coordinates relative to a window region that moves with its window
+        if let Some((x, y)) = capture_source.window_region_origin() {
+            cursor_event_capture.move_region(x, y);
+        }
+
         // NOTE: Cursor is NO LONGER composited onto frames!
         // Cursor events and images are captured separately (CursorEventCapture)
         // and rendered by the video editor/exporter for flexibility.
@@ -634,31 +784,89 @@ pub fn run_video_capture(
         // Flip vertically using pooled buffer (both DXGI and WGC return top-down, encoder expects bottom-up)
         let flipped_data = buffer_pool.flip_vertical(width, height);
 
+        // Start the next segment once this one is long enough
+        if let Some(segment_len) = segment_duration {
+            if segment_frames > 0 && actual_elapsed - segment_start >= segment_len {
+                let index = finished_segments.len() as u32;
+                let next_path = output_path.join(segment_file_name(index + 1));
+
+                match create_encoder(use_hevc, &next_path) {
+                    Ok(next_encoder) => {
+                        let finished = std::mem::replace(&mut encoder, next_encoder);
+                        if let Err(e) = finished.finish() {
+                            // Keep recording; only this segment is affected
+                            log::error!("[CAPTURE] Failed to finish segment {}: {}", index, e);
+                        }
+                        let _ = sync_file(&current_segment_path);
+
+                        finished_segments.push(SegmentInfo {
+                            path: std::mem::replace(&mut current_segment_path, next_path),
+                            index,
+                            duration: actual_elapsed - segment_start,
+                            frame_count: segment_frames,
+                        });
+                        write_segment_manifest(
+                            &segment_manifest_path,
+                            &finished_segments,
+                            Some((&current_segment_path, index + 1)),
+                        );
+
+                        segment_start = actual_elapsed;
+                        segment_frames = 0;
+                        progress.set_segment(index + 2);
+                        log::info!(
+                            "[CAPTURE] Started segment {} at {:.1}s",
+                            index + 2,
+                            actual_elapsed.as_secs_f64()
+                        );
+                    },
+                    Err(e) => {
+                        log::error!("[CAPTURE] Failed to start segment {}: {}", index + 2, e);
+                        capture_error = Some(format!("Failed to start new segment: {}", e));
+                        should_stop.store(true, Ordering::SeqCst);
+                        break;
+                    },
+                }
+            }
+        }
+
         // Get video timestamp using Instant-based timing (same as cursor)
         // This ensures video and cursor timestamps are in the same time domain,
         // eliminating any drift from mixing clock sources.
         // Hardware timestamps are captured above but used only for debugging.
-        let video_timestamp = (actual_elapsed.as_micros() * 10) as i64;
+        // Each segment starts at zero so the parts concatenate cleanly.
+        let video_timestamp = ((actual_elapsed - segment_start).as_micros() * 10) as i64;
 
         // Send video frame to encoder
         let _ = encoder.send_frame_buffer(flipped_data, video_timestamp);
+        frame_timestamps.push(actual_elapsed);
 
-        // Audio is NOT sent to encoder - see comment at audio_settings creation.
+        // Audio is NOT sent to encoder - see comment at create_encoder.
         // MultiTrackAudioRecorder handles WAV capture, FFmpeg muxes post-recording.
 
         // Webcam frames are captured automatically by FeedWebcamEncoder subscription
 
         frame_count += 1;
+        segment_frames += 1;
         progress.increment_frame();
 
         // Emit progress periodically
         if frame_count % 30 == 0 {
+            let current_size_bytes = output_size_bytes(
+                finished_segments
+                    .iter()
+                    .map(|segment| segment.path.as_path())
+                    .chain([current_segment_path.as_path()])
+                    .chain(webcam_output_path.as_deref()),
+            );
+            progress.set_size_bytes(current_size_bytes);
             emit_state_change(
                 app,
                 &RecordingState::Recording {
                     started_at: started_at.to_string(),
                     elapsed_secs: actual_elapsed.as_secs_f64(),
                     frame_count,
+                    current_size_bytes,
                 },
             );
         }
@@ -700,6 +908,7 @@ pub fn run_video_capture(
     stop_global_feed();
     let _ = multitrack_audio.stop();
     let cursor_recording = cursor_event_capture.stop();
+    let keystroke_recording = keystroke_capture.stop();
 
     // If cancelled, skip main encoder
     if was_cancelled {
@@ -707,19 +916,64 @@ pub fn run_video_capture(
         return Ok(recording_duration.as_secs_f64());
     }
 
-    // Save cursor data (editor flow only)
+    // Save cursor data and frame timestamps (editor flow only)
+    let mut has_frame_timestamps = false;
+    let mut has_keystrokes = false;
     if !settings.quick_capture {
         if let Some(ref path) = cursor_data_path {
             if !cursor_recording.events.is_empty() {
                 let _ = save_cursor_recording(&cursor_recording, path);
             }
         }
+        match frame_timestamps.save(&output_path.join(FRAME_TIMESTAMPS_FILE)) {
+            Ok(()) => has_frame_timestamps = true,
+            Err(e) => log::warn!("[CAPTURE] {}", e),
+        }
+        if !keystroke_recording.events.is_empty() {
+            match keystroke_recording.save(&output_path.join(KEYSTROKE_FILE)) {
+                Ok(()) => has_keystrokes = true,
+                Err(e) => log::warn!("[CAPTURE] {}", e),
+            }
+        }
     }
 
     // Finish main video encoder (video-only, no audio)
     encoder
         .finish()
-        .map_err(|e| format!("Failed to finish encoding: {:?}", e))?;
+        .map_err(|e| format!("Failed to finish encoding: {}", e))?;
+
+    if let Some(e) = capture_error {
+        return Err(e);
+    }
+
+    // Join the segments into screen.mp4. On failure the parts and manifest stay
+    // in the project folder so nothing recorded is lost.
+    if segment_duration.is_some() {
+        if segment_frames > 0 {
+            finished_segments.push(SegmentInfo {
+                path: current_segment_path.clone(),
+                index: finished_segments.len() as u32,
+                duration: recording_duration.saturating_sub(segment_start),
+                frame_count: segment_frames,
+            });
+        } else {
+            let _ = std::fs::remove_file(&current_segment_path);
+        }
+        write_segment_manifest(&segment_manifest_path, &finished_segments, None);
+
+        concatenate_segments(&finished_segments, &screen_video_path).map_err(|e| {
+            format!(
+                "Failed to join recording segments (parts kept in {}): {}",
+                output_path.to_string_lossy(),
+                e
+            )
+        })?;
+
+        for segment in &finished_segments {
+            let _ = std::fs::remove_file(&segment.path);
+        }
+        let _ = std::fs::remove_file(&segment_manifest_path);
+    }
 
     // Verify video file was created and has content
     let video_file_size = std::fs::metadata(&screen_video_path)
@@ -780,6 +1034,8 @@ pub fn run_video_capture(
                 .as_ref()
                 .map(|_| !cursor_recording.events.is_empty())
                 .unwrap_or(false),
+            has_frame_timestamps,
+            has_keystrokes,
             has_system_audio,
             has_mic_audio,
         )?;
@@ -5,7 +5,7 @@
 
 #![allow(dead_code)]
 
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
@@ -26,7 +26,7 @@ pub enum RecorderCommand {
     Stop,
     /// Cancel recording without saving.
     Cancel,
-    /// Pause recording (MP4 only).
+    /// Pause recording.
     Pause,
     /// Resume recording.
     Resume,
@@ -37,6 +37,10 @@ pub enum RecorderCommand {
 pub struct RecordingProgress {
     /// Number of frames captured.
     pub frame_count: AtomicU64,
+    /// Current output size in bytes (estimated for GIF).
+    pub size_bytes: AtomicU64,
+    /// Segment being written (1-based), 0 when not segmenting.
+    pub segment: AtomicU32,
     /// Whether recording is currently paused.
     pub is_paused: AtomicBool,
     /// Whether recording should stop.
@@ -49,6 +53,8 @@ impl RecordingProgress {
     pub fn new() -> Self {
         Self {
             frame_count: AtomicU64::new(0),
+            size_bytes: AtomicU64::new(0),
+            segment: AtomicU32::new(0),
             is_paused: AtomicBool::new(false),
             should_stop: AtomicBool::new(false),
             was_cancelled: AtomicBool::new(false),
@@ -63,6 +69,26 @@ impl RecordingProgress {
         self.frame_count.load(Ordering::Relaxed)
     }
 
+    pub fn set_size_bytes(&self, bytes: u64) {
+        self.size_bytes.store(bytes, Ordering::Relaxed);
+    }
+
+    pub fn get_size_bytes(&self) -> u64 {
+        self.size_bytes.load(Ordering::Relaxed)
+    }
+
+    pub fn set_segment(&self, segment: u32) {
+        self.segment.store(segment, Ordering::Relaxed);
+    }
+
+    /// Segment being written, or None when the recording isn't segmented.
+    pub fn get_segment(&self) -> Option<u32> {
+        match self.segment.load(Ordering::Relaxed) {
+            0 => None,
+            segment => Some(segment),
+        }
+    }
+
     pub fn set_paused(&self, paused: bool) {
         self.is_paused.store(paused, Ordering::Relaxed);
     }
@@ -163,6 +189,7 @@ impl RecordingController {
                 started_at: chrono::Local::now().to_rfc3339(),
                 elapsed_secs: 0.0,
                 frame_count: 0,
+                current_size_bytes: 0,
             }
         };
 
@@ -195,6 +222,7 @@ impl RecordingController {
             started_at: chrono::Local::now().to_rfc3339(),
             elapsed_secs: 0.0,
             frame_count: 0,
+            current_size_bytes: 0,
         };
 
         if let Some(ref mut active) = self.active {
@@ -203,12 +231,18 @@ impl RecordingController {
     }
 
     /// Update recording progress.
-    pub fn update_progress(&mut self, elapsed_secs: f64, frame_count: u64) {
+    pub fn update_progress(
+        &mut self,
+        elapsed_secs: f64,
+        frame_count: u64,
+        current_size_bytes: u64,
+    ) {
         if let RecordingState::Recording { started_at, .. } = &self.state {
             self.state = RecordingState::Recording {
                 started_at: started_at.clone(),
                 elapsed_secs,
                 frame_count,
+                current_size_bytes,
             };
         }
     }
@@ -236,6 +270,11 @@ impl RecordingController {
                 started_at: chrono::Local::now().to_rfc3339(),
                 elapsed_secs: *elapsed_secs,
                 frame_count: *frame_count,
+                current_size_bytes: self
+                    .active
+                    .as_ref()
+                    .map(|active| active.progress.get_size_bytes())
+                    .unwrap_or(0),
             };
         }
 
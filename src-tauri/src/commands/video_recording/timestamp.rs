@@ -63,6 +63,15 @@ impl PerformanceCounterTimestamp {
         Self(ticks)
     }
 
+    /// Convert to 100-nanosecond units, the inverse of `from_wgc_time`.
+    ///
+    /// Use this for raw QPC values from other APIs (e.g. DXGI's
+    /// `LastPresentTime`) so they share WGC's `SystemRelativeTime` clock.
+    #[cfg(target_os = "windows")]
+    pub fn to_wgc_time(&self) -> i64 {
+        ticks_to_100ns(self.0, perf_freq())
+    }
+
     /// Get the current performance counter value.
     #[cfg(target_os = "windows")]
     pub fn now() -> Self {
@@ -201,6 +210,21 @@ impl std::ops::Sub<Duration> for PerformanceCounterTimestamp {
     }
 }
 
+/// Convert counter ticks at `freq` ticks per second to 100ns units.
+///
+/// Whole seconds and the remainder are scaled separately so large tick counts
+/// don't overflow. Returns `ticks` unchanged for a non-positive frequency.
+#[cfg(any(target_os = "windows", test))]
+fn ticks_to_100ns(ticks: i64, freq: i64) -> i64 {
+    if freq <= 0 {
+        return ticks;
+    }
+    let secs = ticks / freq;
+    let rem = ticks % freq;
+    secs.saturating_mul(10_000_000)
+        .saturating_add(rem * 10_000_000 / freq)
+}
+
 /// Combined timestamps for synchronization.
 ///
 /// Captures:
@@ -287,6 +311,21 @@ impl Timestamps {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_ticks_to_100ns() {
+        // 10MHz counter: ticks are already 100ns units
+        assert_eq!(ticks_to_100ns(12_345, 10_000_000), 12_345);
+        // 3MHz counter: 1.5s
+        assert_eq!(ticks_to_100ns(4_500_000, 3_000_000), 15_000_000);
+        // Months of uptime at a 24MHz counter don't overflow
+        let ticks = 24_000_000i64 * 60 * 60 * 24 * 90;
+        assert_eq!(
+            ticks_to_100ns(ticks, 24_000_000),
+            10_000_000 * 60 * 60 * 24 * 90
+        );
+        assert_eq!(ticks_to_100ns(42, 0), 42);
+    }
+
     #[test]
     fn test_timestamps_now() {
         let ts = Timestamps::now();
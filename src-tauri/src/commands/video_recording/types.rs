@@ -3,7 +3,8 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use super::ffmpeg_gif_encoder::GifQualityPreset;
+use super::ffmpeg_gif_encoder::{GifQualityPreset, MAX_GIF_FPS};
+use super::recorder::hevc_encoder_available;
 
 // ============================================================================
 // Monitor Info (Windows API)
@@ -119,6 +120,49 @@ impl Default for RecordingFormat {
     }
 }
 
+/// Video codec for MP4 recordings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub enum VideoCodec {
+    /// H.264/AVC - plays everywhere
+    H264,
+    /// H.265/HEVC - roughly half the size, needs a hardware HEVC encoder
+    H265,
+}
+
+impl Default for VideoCodec {
+    fn default() -> Self {
+        Self::H264
+    }
+}
+
+/// How the MP4 encoder spends its bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(tag = "mode", rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub enum RateControl {
+    /// Constant bitrate from the resolution buckets in `calculate_bitrate`.
+    Cbr,
+    /// Variable bitrate that never exceeds `max_bitrate` (bits per second).
+    Vbr {
+        #[serde(rename = "maxBitrate")]
+        max_bitrate: u32,
+    },
+    /// Constant quality. `crf` follows the x264 scale (0-51, lower is better)
+    /// and is derived from the `quality` slider by `validate`.
+    Quality {
+        #[serde(default)]
+        crf: u8,
+    },
+}
+
+impl Default for RateControl {
+    fn default() -> Self {
+        Self::Cbr
+    }
+}
+
 /// What to capture.
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[serde(tag = "type", rename_all = "camelCase")]
@@ -143,6 +187,16 @@ pub enum RecordingMode {
     },
     /// Capture all monitors combined.
     AllMonitors,
+    /// Capture a fixed-size area of a window that follows the window as it moves.
+    /// `x`/`y` are relative to the window's client area.
+    WindowRegion {
+        #[serde(rename = "windowId")]
+        window_id: u32,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+    },
 }
 
 /// Information about an available audio input device.
@@ -159,6 +213,27 @@ pub struct AudioInputDevice {
     pub is_default: bool,
 }
 
+/// Which microphone channels end up in the recorded track.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub enum MicChannelMode {
+    /// Keep left and right as delivered.
+    Stereo,
+    /// Copy the left channel to both sides (interfaces with the mic on input 1).
+    MonoLeft,
+    /// Copy the right channel to both sides.
+    MonoRight,
+    /// Average both channels into both sides.
+    MonoMix,
+}
+
+impl Default for MicChannelMode {
+    fn default() -> Self {
+        Self::MonoMix
+    }
+}
+
 /// Audio capture settings.
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase")]
@@ -169,6 +244,26 @@ pub struct AudioSettings {
     /// Selected microphone device index. None = no microphone.
     #[ts(type = "number | null")]
     pub microphone_device_index: Option<usize>,
+    /// Silence the microphone while its level is below `noise_gate_threshold_db`.
+    #[serde(default)]
+    pub noise_gate: bool,
+    /// RMS level (dBFS) the microphone must reach to open the noise gate.
+    #[serde(default = "default_noise_gate_threshold_db")]
+    pub noise_gate_threshold_db: f32,
+    /// How microphone channels are mixed before writing the track.
+    #[serde(default)]
+    pub mic_channel_mode: MicChannelMode,
+}
+
+fn default_noise_gate_threshold_db() -> f32 {
+    -45.0
+}
+
+impl AudioSettings {
+    /// Noise gate threshold, or None if the gate is off.
+    pub fn noise_gate_threshold(&self) -> Option<f32> {
+        self.noise_gate.then_some(self.noise_gate_threshold_db)
+    }
 }
 
 impl Default for AudioSettings {
@@ -176,6 +271,9 @@ impl Default for AudioSettings {
         Self {
             capture_system_audio: true,
             microphone_device_index: None,
+            noise_gate: false,
+            noise_gate_threshold_db: default_noise_gate_threshold_db(),
+            mic_channel_mode: MicChannelMode::default(),
         }
     }
 }
@@ -207,6 +305,27 @@ pub struct RecordingSettings {
     /// When true, cursor is baked into video based on include_cursor setting.
     /// When false, cursor is captured separately for editor flexibility.
     pub quick_capture: bool,
+    /// Video codec for MP4 recordings. Ignored for GIF.
+    #[serde(default)]
+    pub video_codec: VideoCodec,
+    /// Encoder rate control for MP4 recordings. Ignored for GIF.
+    #[serde(default)]
+    pub rate_control: RateControl,
+    /// Seconds between keyframes (0.5-10). Shorter intervals make seeking in the
+    /// editor faster at the cost of file size.
+    #[serde(default = "default_keyframe_interval_secs")]
+    pub keyframe_interval_secs: f32,
+    /// Split the screen recording into `recording_partNN.mp4` files of this many
+    /// seconds, joined when recording stops. Editor-flow MP4 only.
+    #[serde(default)]
+    pub segment_duration_secs: Option<u32>,
+    /// Record key presses for the exported keystroke overlay. Editor-flow MP4 only.
+    #[serde(default)]
+    pub capture_keystrokes: bool,
+}
+
+fn default_keyframe_interval_secs() -> f32 {
+    2.0
 }
 
 impl Default for RecordingSettings {
@@ -224,6 +343,11 @@ impl Default for RecordingSettings {
             gif_quality_preset: GifQualityPreset::default(),
             countdown_secs: 3,
             quick_capture: false, // Default to editor flow
+            video_codec: VideoCodec::default(),
+            rate_control: RateControl::default(),
+            keyframe_interval_secs: default_keyframe_interval_secs(),
+            segment_duration_secs: None,
+            capture_keystrokes: false,
         }
     }
 }
@@ -240,24 +364,75 @@ impl RecordingSettings {
         // Clamp countdown to 0-10
         self.countdown_secs = self.countdown_secs.clamp(0, 10);
 
+        // Clamp keyframe interval to 0.5-10 seconds
+        self.keyframe_interval_secs = if self.keyframe_interval_secs.is_finite() {
+            self.keyframe_interval_secs.clamp(0.5, 10.0)
+        } else {
+            default_keyframe_interval_secs()
+        };
+
         // GIF-specific limits
         if self.format == RecordingFormat::Gif {
             // Cap GIF FPS at 30 for reasonable file sizes
-            self.fps = self.fps.min(30);
+            self.fps = self.fps.min(MAX_GIF_FPS);
 
             // GIF doesn't support audio
             self.audio.capture_system_audio = false;
             self.audio.microphone_device_index = None;
 
-            // Limit GIF duration to 60 seconds max
+            // Limit GIF duration to 60 seconds max (recorded time; pauses don't count)
             if let Some(duration) = self.max_duration_secs {
                 self.max_duration_secs = Some(duration.min(60));
             } else {
                 self.max_duration_secs = Some(30); // Default 30s for GIF
             }
+
+            self.video_codec = VideoCodec::H264;
+        }
+
+        // Segments live in the project folder, so quick capture and GIF don't split
+        if self.format == RecordingFormat::Gif || self.quick_capture {
+            self.segment_duration_secs = None;
+            // Keystrokes are only rendered by the editor export
+            self.capture_keystrokes = false;
+        }
+        self.segment_duration_secs = self
+            .segment_duration_secs
+            .filter(|&secs| secs > 0)
+            .map(|secs| secs.max(MIN_SEGMENT_DURATION_SECS));
+
+        // Quality mode takes its quantizer from the quality slider
+        if let RateControl::Quality { crf } = &mut self.rate_control {
+            *crf = crf_for_quality(self.quality);
+        }
+
+        // Only trust HEVC when a hardware encoder for it is installed
+        if self.video_codec == VideoCodec::H265 && !hevc_encoder_available() {
+            log::warn!("[RECORDING] No hardware HEVC encoder found, using H.264");
+            self.video_codec = VideoCodec::H264;
         }
     }
 
+    /// Average bitrate handed to the encoder for the configured rate control
+    /// (`CODECAPI_AVEncCommonMeanBitRate`). In quality mode the encoder follows the
+    /// quality level instead, and this is only an estimate.
+    pub fn encoder_bitrate(&self, width: u32, height: u32) -> u32 {
+        match self.rate_control {
+            RateControl::Cbr => self.calculate_bitrate(width, height),
+            RateControl::Vbr { max_bitrate } => {
+                // Screen content is mostly static, so the average sits well below the peak
+                let average = self.calculate_bitrate(width, height) as f64 * VBR_AVERAGE_RATIO;
+                (average as u32).min(max_bitrate)
+            },
+            RateControl::Quality { crf } => crf_bitrate(width, height, self.fps, crf),
+        }
+    }
+
+    /// Frames between keyframes (`CODECAPI_AVEncMPVGOPSize`), at least 1.
+    pub fn gop_size(&self) -> u32 {
+        ((self.keyframe_interval_secs * self.fps as f32).round() as u32).max(1)
+    }
+
     /// Calculate video bitrate based on quality and resolution.
     pub fn calculate_bitrate(&self, width: u32, height: u32) -> u32 {
         let pixels = width * height;
@@ -274,6 +449,41 @@ impl RecordingSettings {
     }
 }
 
+/// Shortest allowed recording segment. Each split costs an encoder restart.
+const MIN_SEGMENT_DURATION_SECS: u32 = 10;
+
+/// Share of the CBR bitrate a VBR recording averages.
+const VBR_AVERAGE_RATIO: f64 = 0.6;
+
+/// Worst quantizer on the x264 CRF scale.
+const MAX_CRF: u8 = 51;
+
+/// CRF range the quality slider maps onto (best, worst).
+const CRF_RANGE: (u8, u8) = (18, 40);
+
+/// Bits per pixel at CRF 23 for screen content. Each +6 CRF halves the bitrate.
+const BITS_PER_PIXEL_AT_CRF_23: f64 = 0.08;
+
+/// Map the 1-100 quality slider to a CRF-like quantizer (100 -> 18, 1 -> 40).
+pub fn crf_for_quality(quality: u32) -> u8 {
+    let (best, worst) = CRF_RANGE;
+    let t = (quality.clamp(1, 100) - 1) as f64 / 99.0;
+    (worst as f64 - t * (worst - best) as f64).round() as u8
+}
+
+/// `CODECAPI_AVEncCommonQuality` level (0-100, higher is better) for a CRF.
+pub fn common_quality_for_crf(crf: u8) -> u32 {
+    let steps_from_worst = u32::from(MAX_CRF - crf.min(MAX_CRF));
+    (steps_from_worst * 100 + u32::from(MAX_CRF) / 2) / u32::from(MAX_CRF)
+}
+
+/// Approximate bitrate needed to hold a CRF at the given size and frame rate.
+fn crf_bitrate(width: u32, height: u32, fps: u32, crf: u8) -> u32 {
+    let pixels_per_sec = width as f64 * height as f64 * fps as f64;
+    let bits_per_pixel = BITS_PER_PIXEL_AT_CRF_23 * 2f64.powf((23.0 - crf as f64) / 6.0);
+    (pixels_per_sec * bits_per_pixel) as u32
+}
+
 /// Current state of a recording session.
 ///
 /// NOTE: ts-rs generates TypeScript types from Rust - single source of truth.
@@ -298,8 +508,12 @@ pub enum RecordingState {
         #[serde(rename = "frameCount")]
         #[ts(type = "number")]
         frame_count: u64,
+        /// Bytes written to disk so far (estimated for GIF, which encodes at the end).
+        #[serde(rename = "currentSizeBytes", default)]
+        #[ts(type = "number")]
+        current_size_bytes: u64,
     },
-    /// Paused (MP4 only).
+    /// Paused. Time spent paused is left out of the output.
     Paused {
         #[serde(rename = "elapsedSecs")]
         elapsed_secs: f64,
@@ -321,6 +535,9 @@ pub enum RecordingState {
     },
     /// Recording failed.
     Error { message: String },
+    /// Non-fatal problem worth telling the user about. The recording carries on in
+    /// its previous state, so listeners should not treat this as a state change.
+    Warning { message: String },
 }
 
 impl Default for RecordingState {
@@ -336,6 +553,9 @@ impl Default for RecordingState {
 pub struct RecordingStatus {
     pub state: RecordingState,
     pub settings: Option<RecordingSettings>,
+    /// Segment being written (1-based, matching `recording_partNN.mp4`), or None
+    /// when the recording isn't split into segments.
+    pub current_segment: Option<u32>,
 }
 
 /// Result of starting a recording.
@@ -359,3 +579,155 @@ pub struct StopRecordingResult {
     pub file_size_bytes: u64,
     pub format: RecordingFormat,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_video_codec_defaults_to_h264() {
+        let settings: RecordingSettings = serde_json::from_str(
+            r#"{
+                "format": "mp4",
+                "mode": { "type": "allMonitors" },
+                "fps": 30,
+                "maxDurationSecs": null,
+                "includeCursor": false,
+                "audio": { "captureSystemAudio": false, "microphoneDeviceIndex": null },
+                "quality": 80,
+                "gifQualityPreset": "balanced",
+                "countdownSecs": 3,
+                "quickCapture": false
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(settings.video_codec, VideoCodec::H264);
+    }
+
+    #[test]
+    fn test_window_region_mode_serialization() {
+        let mode: RecordingMode = serde_json::from_str(
+            r#"{ "type": "windowRegion", "windowId": 42, "x": 10, "y": 20, "width": 640, "height": 480 }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            mode,
+            RecordingMode::WindowRegion {
+                window_id: 42,
+                x: 10,
+                y: 20,
+                width: 640,
+                height: 480
+            }
+        ));
+
+        let json = serde_json::to_string(&mode).unwrap();
+        assert!(json.contains(r#""type":"windowRegion""#));
+        assert!(json.contains(r#""windowId":42"#));
+    }
+
+    #[test]
+    fn test_noise_gate_defaults_off() {
+        let legacy: AudioSettings =
+            serde_json::from_str(r#"{"captureSystemAudio": true, "microphoneDeviceIndex": 0}"#)
+                .unwrap();
+        assert_eq!(legacy.noise_gate_threshold(), None);
+
+        let gated: AudioSettings = serde_json::from_str(
+            r#"{"captureSystemAudio": false, "microphoneDeviceIndex": 0, "noiseGate": true, "noiseGateThresholdDb": -50}"#,
+        )
+        .unwrap();
+        assert_eq!(gated.noise_gate_threshold(), Some(-50.0));
+    }
+
+    #[test]
+    fn test_segment_duration_validation() {
+        let mut settings = RecordingSettings {
+            segment_duration_secs: Some(3),
+            ..Default::default()
+        };
+        settings.validate();
+        assert_eq!(
+            settings.segment_duration_secs,
+            Some(MIN_SEGMENT_DURATION_SECS)
+        );
+
+        settings.segment_duration_secs = Some(0);
+        settings.validate();
+        assert_eq!(settings.segment_duration_secs, None);
+
+        let mut quick = RecordingSettings {
+            quick_capture: true,
+            segment_duration_secs: Some(600),
+            ..Default::default()
+        };
+        quick.validate();
+        assert_eq!(quick.segment_duration_secs, None);
+    }
+
+    #[test]
+    fn test_crf_for_quality() {
+        assert_eq!(crf_for_quality(100), 18);
+        assert_eq!(crf_for_quality(1), 40);
+        assert_eq!(crf_for_quality(0), 40);
+        assert!(crf_for_quality(80) < crf_for_quality(50));
+    }
+
+    #[test]
+    fn test_common_quality_for_crf() {
+        assert_eq!(common_quality_for_crf(0), 100);
+        assert_eq!(common_quality_for_crf(51), 0);
+        assert_eq!(common_quality_for_crf(60), 0);
+        assert!(common_quality_for_crf(18) > common_quality_for_crf(40));
+    }
+
+    #[test]
+    fn test_encoder_bitrate_by_rate_control() {
+        let mut settings = RecordingSettings::default();
+        let cbr = settings.encoder_bitrate(1920, 1080);
+        assert_eq!(cbr, settings.calculate_bitrate(1920, 1080));
+
+        settings.rate_control = RateControl::Vbr {
+            max_bitrate: 4_000_000,
+        };
+        assert_eq!(settings.encoder_bitrate(1920, 1080), 4_000_000);
+
+        settings.rate_control = RateControl::Quality { crf: 0 };
+        settings.validate();
+        assert_eq!(
+            settings.rate_control,
+            RateControl::Quality {
+                crf: crf_for_quality(settings.quality)
+            }
+        );
+        let high = settings.encoder_bitrate(1920, 1080);
+        settings.rate_control = RateControl::Quality { crf: 40 };
+        assert!(settings.encoder_bitrate(1920, 1080) < high);
+    }
+
+    #[test]
+    fn test_keyframe_interval() {
+        let mut settings = RecordingSettings::default();
+        assert_eq!(settings.gop_size(), 60);
+
+        settings.keyframe_interval_secs = 0.1;
+        settings.validate();
+        assert_eq!(settings.keyframe_interval_secs, 0.5);
+        assert_eq!(settings.gop_size(), 15);
+
+        settings.keyframe_interval_secs = f32::NAN;
+        settings.validate();
+        assert_eq!(settings.keyframe_interval_secs, 2.0);
+    }
+
+    #[test]
+    fn test_validate_forces_h264_for_gif() {
+        let mut settings = RecordingSettings {
+            format: RecordingFormat::Gif,
+            video_codec: VideoCodec::H265,
+            ..Default::default()
+        };
+        settings.validate();
+        assert_eq!(settings.video_codec, VideoCodec::H264);
+    }
+}
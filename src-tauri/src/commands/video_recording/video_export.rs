@@ -17,12 +17,13 @@ use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 use std::process::Stdio;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Emitter};
 use ts_rs::TS;
 
 use super::video_project::{
-    EasingFunction, ExportFormat, VideoProject, WebcamOverlayPosition, ZoomRegion,
+    EasingFunction, ExportConfig, ExportFormat, ExportPreset, ExportResolution, VideoProject,
+    WebcamOverlayPosition, ZoomRegion,
 };
 
 // ============================================================================
@@ -40,6 +41,14 @@ pub struct ExportProgress {
     pub stage: ExportStage,
     /// Human-readable status message.
     pub message: String,
+    /// Render throughput in frames per second (while rendering frames).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub fps: Option<f32>,
+    /// Estimated seconds until rendering finishes (while rendering frames).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub eta_secs: Option<f32>,
 }
 
 /// Stages of the export process.
@@ -73,6 +82,97 @@ pub struct ExportResult {
     pub file_size_bytes: u64,
     /// Output format.
     pub format: ExportFormat,
+    /// FFmpeg video encoder used (e.g. `h264_nvenc`), `None` for PNG sequences.
+    pub encoder: Option<String>,
+}
+
+impl ExportFormat {
+    /// File extension (without dot) for this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Mp4 => "mp4",
+            ExportFormat::Webm => "webm",
+            ExportFormat::Gif => "gif",
+            ExportFormat::PngSequence => "png",
+            ExportFormat::Mov => "mov",
+        }
+    }
+}
+
+// ============================================================================
+// Batch Re-export Types
+// ============================================================================
+
+/// Export settings applied on top of each project's saved `ExportConfig`
+/// when re-exporting several projects at once. `None` keeps the project's value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct ExportOverrides {
+    /// Export preset.
+    pub preset: Option<ExportPreset>,
+    /// Output format.
+    pub format: Option<ExportFormat>,
+    /// Output resolution.
+    pub resolution: Option<ExportResolution>,
+    /// Quality (1-100).
+    pub quality: Option<u32>,
+    /// Frames per second.
+    pub fps: Option<u32>,
+    /// Prefer hardware encoding when available.
+    pub prefer_hardware_encoding: Option<bool>,
+}
+
+impl ExportOverrides {
+    /// Merge the overrides into an export config.
+    pub fn apply(&self, config: &mut ExportConfig) {
+        if let Some(preset) = self.preset {
+            config.preset = preset;
+        }
+        if let Some(format) = self.format {
+            config.format = format;
+        }
+        if let Some(resolution) = self.resolution {
+            config.resolution = resolution;
+        }
+        if let Some(quality) = self.quality {
+            config.quality = quality.clamp(1, 100);
+        }
+        if let Some(fps) = self.fps {
+            config.fps = fps.max(1);
+        }
+        if let Some(prefer) = self.prefer_hardware_encoding {
+            config.prefer_hardware_encoding = Some(prefer);
+        }
+    }
+}
+
+/// Outcome of re-exporting one project in a batch.
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct ReexportResult {
+    /// Library ID of the project.
+    pub project_id: String,
+    /// Export result when the project exported successfully.
+    pub result: Option<ExportResult>,
+    /// Error message when the project failed to export.
+    pub error: Option<String>,
+}
+
+/// Aggregate progress event sent during a batch re-export.
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct ReexportProgress {
+    /// Project currently being exported.
+    pub project_id: String,
+    /// Number of projects finished so far (success or failure).
+    pub completed: u32,
+    /// Number of failed projects so far.
+    pub failed: u32,
+    /// Total number of projects in the batch.
+    pub total: u32,
 }
 
 // ============================================================================
@@ -204,6 +304,7 @@ impl VideoExporter {
             duration_secs,
             file_size_bytes: metadata.len(),
             format: self.project.export.format,
+            encoder: self.video_encoder().map(str::to_string),
         };
 
         self.emit_progress(app, 1.0, ExportStage::Complete, "Export complete!");
@@ -405,6 +506,12 @@ impl VideoExporter {
                 args.push("-loop".to_string());
                 args.push("0".to_string());
             },
+            ExportFormat::PngSequence | ExportFormat::Mov => {
+                return Err(format!(
+                    "{:?} export is only supported by the GPU exporter",
+                    self.project.export.format
+                ));
+            },
         }
 
         // FPS
@@ -421,6 +528,16 @@ impl VideoExporter {
         Ok(args)
     }
 
+    /// FFmpeg video encoder `build_ffmpeg_args` selects for the export format.
+    fn video_encoder(&self) -> Option<&'static str> {
+        match self.project.export.format {
+            ExportFormat::Mp4 => Some("libx264"),
+            ExportFormat::Webm => Some("libvpx-vp9"),
+            ExportFormat::Gif => Some("gif"),
+            ExportFormat::PngSequence | ExportFormat::Mov => None,
+        }
+    }
+
     /// Build audio filter for mixing multiple audio tracks.
     ///
     /// Applies volume control, fade effects, and optional loudnorm normalization.
@@ -440,8 +557,13 @@ impl VideoExporter {
         if let Some(idx) = system_audio_index {
             let label = format!("sys{}", input_count);
             filters.push(format!(
-                "[{}:a]volume={:.2}[{}]",
-                idx, audio_settings.system_volume, label
+                "[{}:a]{}[{}]",
+                idx,
+                audio_track_chain(
+                    audio_settings.system_offset_ms,
+                    audio_settings.system_volume
+                ),
+                label
             ));
             mix_inputs.push(format!("[{}]", label));
             input_count += 1;
@@ -451,8 +573,13 @@ impl VideoExporter {
         if let Some(idx) = mic_audio_index {
             let label = format!("mic{}", input_count);
             filters.push(format!(
-                "[{}:a]volume={:.2}[{}]",
-                idx, audio_settings.microphone_volume, label
+                "[{}:a]{}[{}]",
+                idx,
+                audio_track_chain(
+                    audio_settings.mic_offset_ms,
+                    audio_settings.microphone_volume
+                ),
+                label
             ));
             mix_inputs.push(format!("[{}]", label));
             input_count += 1;
@@ -485,7 +612,10 @@ impl VideoExporter {
             // Single track - just pass through with optional normalization
             let input_label = &mix_inputs[0];
             if audio_settings.normalize_output {
-                format!("{}loudnorm=I=-16:TP=-1.5:LRA=11[aout]", input_label)
+                format!(
+                    "{}loudnorm=I={:.1}:TP=-1.5:LRA=11[aout]",
+                    input_label, audio_settings.loudness_target_lufs
+                )
             } else {
                 format!("{}anull[aout]", input_label)
             }
@@ -494,8 +624,8 @@ impl VideoExporter {
             let inputs_str = mix_inputs.join("");
             if audio_settings.normalize_output {
                 format!(
-                    "{}amix=inputs={}:duration=longest:dropout_transition=2,loudnorm=I=-16:TP=-1.5:LRA=11[aout]",
-                    inputs_str, input_count
+                    "{}amix=inputs={}:duration=longest:dropout_transition=2,loudnorm=I={:.1}:TP=-1.5:LRA=11[aout]",
+                    inputs_str, input_count, audio_settings.loudness_target_lufs
                 )
             } else {
                 format!(
@@ -811,6 +941,8 @@ impl VideoExporter {
             progress,
             stage,
             message: message.to_string(),
+            fps: None,
+            eta_secs: None,
         };
         let _ = app.emit("export-progress", &event);
         log::debug!("[EXPORT] Progress: {:.0}% - {}", progress * 100.0, message);
@@ -820,31 +952,29 @@ impl VideoExporter {
 /// Apply easing function to a linear progress value (0-1).
 #[allow(dead_code)]
 fn apply_easing(t: f64, easing: EasingFunction) -> f64 {
-    match easing {
-        EasingFunction::Linear => t,
-        EasingFunction::EaseIn => t * t,
-        EasingFunction::EaseOut => 1.0 - (1.0 - t).powi(2),
-        EasingFunction::EaseInOut => {
-            if t < 0.5 {
-                2.0 * t * t
-            } else {
-                1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
-            }
-        },
-        EasingFunction::Smooth => {
-            // Smoothstep
-            t * t * (3.0 - 2.0 * t)
-        },
-        EasingFunction::Snappy => {
-            // Quick start, gradual end
-            1.0 - (1.0 - t).powi(3)
-        },
-        EasingFunction::Bouncy => {
-            // Slight overshoot
-            let c1 = 1.70158;
-            let c3 = c1 + 1.0;
-            1.0 + c3 * (t - 1.0).powi(3) + c1 * (t - 1.0).powi(2)
-        },
+    easing.apply(t)
+}
+
+/// FFmpeg filter that shifts an audio track by `offset_ms`.
+///
+/// Positive offsets pad the head with silence (the track plays later), negative
+/// offsets trim the head (the track plays earlier). Returns None for no offset.
+pub fn audio_offset_filter(offset_ms: i32) -> Option<String> {
+    match offset_ms {
+        0 => None,
+        ms if ms > 0 => Some(format!("adelay=delays={}:all=1", ms)),
+        ms => Some(format!(
+            "atrim=start={:.3},asetpts=PTS-STARTPTS",
+            ms.unsigned_abs() as f64 / 1000.0
+        )),
+    }
+}
+
+/// Filter chain for one audio track: optional offset followed by volume.
+pub fn audio_track_chain(offset_ms: i32, volume: f32) -> String {
+    match audio_offset_filter(offset_ms) {
+        Some(offset) => format!("{},volume={:.2}", offset, volume),
+        None => format!("volume={:.2}", volume),
     }
 }
 
@@ -877,6 +1007,36 @@ mod tests {
         assert!((apply_easing(1.0, EasingFunction::EaseOut) - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_audio_offset_filter() {
+        assert_eq!(audio_offset_filter(0), None);
+
+        // Delay pads the head with silence
+        assert_eq!(
+            audio_offset_filter(250).as_deref(),
+            Some("adelay=delays=250:all=1")
+        );
+
+        // Advance trims the head and restarts timestamps at zero
+        assert_eq!(
+            audio_offset_filter(-120).as_deref(),
+            Some("atrim=start=0.120,asetpts=PTS-STARTPTS")
+        );
+        assert_eq!(
+            audio_offset_filter(-1500).as_deref(),
+            Some("atrim=start=1.500,asetpts=PTS-STARTPTS")
+        );
+    }
+
+    #[test]
+    fn test_audio_track_chain() {
+        assert_eq!(audio_track_chain(0, 0.9), "volume=0.90");
+        assert_eq!(
+            audio_track_chain(80, 1.0),
+            "adelay=delays=80:all=1,volume=1.00"
+        );
+    }
+
     #[test]
     fn test_parse_time_str() {
         let exporter = VideoExporter {
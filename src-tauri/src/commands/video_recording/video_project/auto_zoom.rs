@@ -4,7 +4,16 @@ use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
 use super::types::{EasingFunction, VideoProject, ZoomRegion, ZoomRegionMode, ZoomTransition};
-use crate::commands::video_recording::cursor::{load_cursor_recording, CursorEventType};
+use crate::commands::video_recording::cursor::{
+    load_cursor_recording, CursorEvent, CursorEventType,
+};
+
+/// How far back to look when measuring cursor speed at a click (ms).
+const VELOCITY_WINDOW_MS: u64 = 100;
+
+/// Cursor speed (normalized units per second) above which a click counts as a
+/// pass-through and gets no zoom. 3.0 crosses the frame in about a third of a second.
+const MAX_CLICK_VELOCITY: f64 = 3.0;
 
 // ============================================================================
 // Auto-Zoom Configuration
@@ -29,6 +38,30 @@ pub struct AutoZoomConfig {
     pub easing: EasingFunction,
     /// Only include left clicks (ignore right/middle clicks).
     pub left_clicks_only: bool,
+    /// Clicks within this long of the previous click in the same spot are
+    /// merged into one zoom centered on their centroid (ms).
+    #[serde(default = "AutoZoomConfig::default_merge_window_ms")]
+    pub merge_window_ms: u32,
+    /// How close (normalized, 0-1) a click must be to the group's centroid to merge.
+    #[serde(default = "AutoZoomConfig::default_merge_radius")]
+    pub merge_radius: f32,
+    /// Clicks elsewhere sooner than this after the previous click are skipped (ms).
+    #[serde(default = "AutoZoomConfig::default_min_click_spacing_ms")]
+    pub min_click_spacing_ms: u32,
+}
+
+impl AutoZoomConfig {
+    fn default_merge_window_ms() -> u32 {
+        1000
+    }
+
+    fn default_merge_radius() -> f32 {
+        0.1
+    }
+
+    fn default_min_click_spacing_ms() -> u32 {
+        400
+    }
 }
 
 impl Default for AutoZoomConfig {
@@ -41,8 +74,114 @@ impl Default for AutoZoomConfig {
             transition_out_ms: 300,
             easing: EasingFunction::EaseInOut,
             left_clicks_only: true,
+            merge_window_ms: Self::default_merge_window_ms(),
+            merge_radius: Self::default_merge_radius(),
+            min_click_spacing_ms: Self::default_min_click_spacing_ms(),
+        }
+    }
+}
+
+/// A group of nearby clicks that gets a single zoom region.
+#[derive(Debug, Clone, PartialEq)]
+struct ClickCluster {
+    start_ms: u64,
+    end_ms: u64,
+    x: f64,
+    y: f64,
+    count: u32,
+}
+
+impl ClickCluster {
+    fn new(click: &CursorEvent) -> Self {
+        Self {
+            start_ms: click.timestamp_ms,
+            end_ms: click.timestamp_ms,
+            x: click.x,
+            y: click.y,
+            count: 1,
+        }
+    }
+
+    fn distance_to(&self, click: &CursorEvent) -> f64 {
+        (click.x - self.x).hypot(click.y - self.y)
+    }
+
+    /// Add a click, moving the center to the running centroid.
+    fn push(&mut self, click: &CursorEvent) {
+        let n = self.count as f64;
+        self.x = (self.x * n + click.x) / (n + 1.0);
+        self.y = (self.y * n + click.y) / (n + 1.0);
+        self.count += 1;
+        self.end_ms = click.timestamp_ms;
+    }
+}
+
+/// Cursor speed just before `timestamp_ms`, in normalized units per second.
+///
+/// Uses the positions recorded in the preceding [`VELOCITY_WINDOW_MS`].
+fn cursor_speed_at(events: &[CursorEvent], timestamp_ms: u64) -> f64 {
+    let window_start = timestamp_ms.saturating_sub(VELOCITY_WINDOW_MS);
+    let mut samples = events
+        .iter()
+        .filter(|e| e.timestamp_ms >= window_start && e.timestamp_ms <= timestamp_ms);
+
+    let Some(first) = samples.next() else {
+        return 0.0;
+    };
+    let last = samples.last().unwrap_or(first);
+    let elapsed_ms = last.timestamp_ms.saturating_sub(first.timestamp_ms);
+    if elapsed_ms == 0 {
+        return 0.0;
+    }
+
+    (last.x - first.x).hypot(last.y - first.y) / (elapsed_ms as f64 / 1000.0)
+}
+
+/// Group clicks into zoom targets.
+///
+/// Clicks made while the cursor is moving fast are dropped. A click within
+/// `merge_window_ms` of the previous click and `merge_radius` of the group's
+/// centroid joins that group; one elsewhere within `min_click_spacing_ms` is
+/// treated as a rapid pass-through and skipped.
+fn cluster_clicks(
+    events: &[CursorEvent],
+    clicks: &[&CursorEvent],
+    config: &AutoZoomConfig,
+) -> Vec<ClickCluster> {
+    let mut clusters: Vec<ClickCluster> = Vec::new();
+
+    for click in clicks {
+        if cursor_speed_at(events, click.timestamp_ms) > MAX_CLICK_VELOCITY {
+            log::debug!(
+                "[AUTO_ZOOM] Skipped click at {}ms (cursor moving fast)",
+                click.timestamp_ms
+            );
+            continue;
+        }
+
+        if let Some(cluster) = clusters.last_mut() {
+            let since_last = click.timestamp_ms.saturating_sub(cluster.end_ms);
+
+            if since_last <= config.merge_window_ms as u64
+                && cluster.distance_to(click) <= config.merge_radius as f64
+            {
+                cluster.push(click);
+                continue;
+            }
+
+            if since_last < config.min_click_spacing_ms as u64 {
+                log::debug!(
+                    "[AUTO_ZOOM] Skipped click at {}ms (too soon after previous click)",
+                    click.timestamp_ms
+                );
+                continue;
+            }
         }
+
+        clusters.push(ClickCluster::new(click));
     }
+
+    clusters
 }
 
 // ============================================================================
@@ -54,8 +193,8 @@ impl Default for AutoZoomConfig {
 /// This function:
 /// 1. Loads the cursor recording from the JSON file
 /// 2. Filters for click events (left clicks by default)
-/// 3. Creates ZoomRegion entries for each click
-/// 4. Merges clicks that are too close together
+/// 3. Groups nearby clicks and drops pass-through clicks (see [`cluster_clicks`])
+/// 4. Creates ZoomRegion entries for each group, merging ones that are too close together
 /// 5. Normalizes coordinates to 0-1 range using region dimensions from the recording
 ///
 /// # Arguments
@@ -95,21 +234,23 @@ pub fn generate_auto_zoom_regions(
         recording.height
     );
 
+    let clusters = cluster_clicks(&recording.events, &clicks, config);
+
     // Generate zoom regions
     let mut regions: Vec<ZoomRegion> = Vec::new();
 
-    for click in clicks {
+    for cluster in clusters {
         // Cursor events already have normalized (0-1) coordinates
-        let target_x = (click.x as f32).clamp(0.0, 1.0);
-        let target_y = (click.y as f32).clamp(0.0, 1.0);
+        let target_x = (cluster.x as f32).clamp(0.0, 1.0);
+        let target_y = (cluster.y as f32).clamp(0.0, 1.0);
 
-        // Check if this click is too close to the previous one
+        // Check if this group is too close to the previous one
         if let Some(last_region) = regions.last_mut() {
-            let gap = click.timestamp_ms.saturating_sub(last_region.end_ms);
+            let gap = cluster.start_ms.saturating_sub(last_region.end_ms);
 
             if gap < config.min_gap_ms as u64 {
                 // Extend the previous region instead of creating a new one
-                last_region.end_ms = click.timestamp_ms + config.hold_duration_ms as u64;
+                last_region.end_ms = cluster.end_ms + config.hold_duration_ms as u64;
                 log::debug!(
                     "[AUTO_ZOOM] Extended region {} to {}ms (merged close click)",
                     last_region.id,
@@ -122,14 +263,14 @@ pub fn generate_auto_zoom_regions(
         // Create new zoom region
         let region_id = format!(
             "auto_zoom_{}_{:08x}",
-            click.timestamp_ms,
+            cluster.start_ms,
             rand::random::<u32>()
         );
 
         let region = ZoomRegion {
             id: region_id,
-            start_ms: click.timestamp_ms,
-            end_ms: click.timestamp_ms + config.hold_duration_ms as u64,
+            start_ms: cluster.start_ms,
+            end_ms: cluster.end_ms + config.hold_duration_ms as u64,
             scale: config.scale,
             target_x,
             target_y,
@@ -140,6 +281,7 @@ pub fn generate_auto_zoom_regions(
                 duration_out_ms: config.transition_out_ms,
                 easing: config.easing,
             },
+            focus_padding: ZoomRegion::default_focus_padding(),
         };
 
         log::debug!(
@@ -200,3 +342,85 @@ pub fn apply_auto_zoom_to_project(
 
     Ok(project)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(timestamp_ms: u64, x: f64, y: f64, event_type: CursorEventType) -> CursorEvent {
+        CursorEvent {
+            timestamp_ms,
+            x,
+            y,
+            event_type,
+            cursor_id: None,
+        }
+    }
+
+    fn click(timestamp_ms: u64, x: f64, y: f64) -> CursorEvent {
+        event(
+            timestamp_ms,
+            x,
+            y,
+            CursorEventType::LeftClick { pressed: true },
+        )
+    }
+
+    fn clusters_for(events: &[CursorEvent]) -> Vec<ClickCluster> {
+        let clicks: Vec<_> = events
+            .iter()
+            .filter(|e| matches!(e.event_type, CursorEventType::LeftClick { .. }))
+            .collect();
+        cluster_clicks(events, &clicks, &AutoZoomConfig::default())
+    }
+
+    #[test]
+    fn test_double_click_merges_at_centroid() {
+        let events = [click(1000, 0.40, 0.50), click(1200, 0.44, 0.50)];
+
+        let clusters = clusters_for(&events);
+        assert_eq!(clusters.len(), 1);
+        assert_eq!((clusters[0].start_ms, clusters[0].end_ms), (1000, 1200));
+        assert!((clusters[0].x - 0.42).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rapid_clicks_elsewhere_are_skipped() {
+        let events = [
+            click(1000, 0.2, 0.2),
+            click(1200, 0.8, 0.8),
+            click(3000, 0.8, 0.8),
+        ];
+
+        let clusters = clusters_for(&events);
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[1].start_ms, 3000);
+    }
+
+    #[test]
+    fn test_click_while_moving_fast_is_skipped() {
+        let events = [
+            event(900, 0.0, 0.5, CursorEventType::Move),
+            event(950, 0.3, 0.5, CursorEventType::Move),
+            click(1000, 0.6, 0.5),
+            event(1500, 0.6, 0.5, CursorEventType::Move),
+            click(2000, 0.6, 0.5),
+        ];
+
+        assert!(cursor_speed_at(&events, 1000) > MAX_CLICK_VELOCITY);
+        let clusters = clusters_for(&events);
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].start_ms, 2000);
+    }
+
+    #[test]
+    fn test_config_defaults_for_legacy_json() {
+        let config: AutoZoomConfig = serde_json::from_str(
+            r#"{"scale": 2.0, "holdDurationMs": 1500, "minGapMs": 500, "transitionInMs": 300,
+                "transitionOutMs": 300, "easing": "easeInOut", "leftClicksOnly": true}"#,
+        )
+        .unwrap();
+        assert_eq!(config.merge_window_ms, 1000);
+        assert_eq!(config.min_click_spacing_ms, 400);
+    }
+}
@@ -0,0 +1,181 @@
+//! Editor autosave and crash recovery.
+//!
+//! Edits are written to a `.autosave.snapit` sidecar next to the project file
+//! (`project.json` -> `project.autosave.snapit`, `recording.snapit` ->
+//! `recording.autosave.snapit`). Writes are debounced so a burst of edits ends
+//! in a single write once the editor goes quiet. Saving the project removes the
+//! sidecar, so an autosave newer than the project file means the editor closed
+//! without saving and its changes can be recovered.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use super::types::VideoProject;
+
+/// Extension replacing the project file's own for its autosave sidecar.
+pub const AUTOSAVE_EXTENSION: &str = "autosave.snapit";
+
+/// Quiet time after the last edit before the autosave is written.
+const AUTOSAVE_DEBOUNCE: Duration = Duration::from_millis(1500);
+
+/// Latest unwritten autosave of one project.
+struct PendingAutosave {
+    /// Bumped by every edit; a write only goes ahead if no edit followed it.
+    generation: u64,
+    project: Option<VideoProject>,
+}
+
+fn pending() -> &'static Mutex<HashMap<PathBuf, PendingAutosave>> {
+    static PENDING: OnceLock<Mutex<HashMap<PathBuf, PendingAutosave>>> = OnceLock::new();
+    PENDING.get_or_init(Default::default)
+}
+
+/// Autosave sidecar of the project file at `project_path`.
+pub fn autosave_path(project_path: &Path) -> PathBuf {
+    project_path.with_extension(AUTOSAVE_EXTENSION)
+}
+
+/// Write `project` to the autosave of `project_path` once edits pause.
+///
+/// Each call replaces the pending project, so only the latest state is written.
+pub fn schedule_autosave(project_path: &Path, project: VideoProject) {
+    let path = autosave_path(project_path);
+    let generation = {
+        let mut pending = pending().lock();
+        let entry = pending.entry(path.clone()).or_insert(PendingAutosave {
+            generation: 0,
+            project: None,
+        });
+        entry.generation += 1;
+        entry.project = Some(project);
+        entry.generation
+    };
+
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(AUTOSAVE_DEBOUNCE).await;
+        write_if_latest(&path, generation);
+    });
+}
+
+/// Write the pending autosave at `path` unless an edit or discard followed
+/// `generation`.
+///
+/// The lock is held through the write, so a discard can't delete the sidecar
+/// between the check and the write and leave a stale autosave behind.
+fn write_if_latest(path: &Path, generation: u64) {
+    let mut pending = pending().lock();
+    let project = match pending.get(path) {
+        Some(entry) if entry.generation == generation => {
+            pending.remove(path).and_then(|entry| entry.project)
+        },
+        _ => None,
+    };
+
+    if let Some(project) = project {
+        if let Err(e) = project.save(path) {
+            log::warn!("[PROJECT] Autosave to {:?} failed: {}", path, e);
+        }
+    }
+}
+
+/// Drop any pending autosave of `project_path` and delete its sidecar.
+pub fn discard_autosave(project_path: &Path) {
+    let path = autosave_path(project_path);
+    let mut pending = pending().lock();
+    pending.remove(&path);
+
+    if path.exists() {
+        if let Err(e) = std::fs::remove_file(&path) {
+            log::warn!("[PROJECT] Failed to remove autosave {:?}: {}", path, e);
+        }
+    }
+}
+
+/// The autosave of `project_path` if it was written after the project file
+/// (or the project was never saved).
+pub fn newer_autosave(project_path: &Path) -> Option<PathBuf> {
+    let path = autosave_path(project_path);
+    let autosaved = std::fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+
+    match std::fs::metadata(project_path).and_then(|m| m.modified()) {
+        Ok(saved) if saved >= autosaved => None,
+        _ => Some(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_autosave_path_replaces_extension() {
+        assert_eq!(
+            autosave_path(Path::new("/rec/project.json")),
+            PathBuf::from("/rec/project.autosave.snapit")
+        );
+        assert_eq!(
+            autosave_path(Path::new("/rec/recording_1.snapit")),
+            PathBuf::from("/rec/recording_1.autosave.snapit")
+        );
+    }
+
+    #[test]
+    fn test_newer_autosave_compares_modified_times() {
+        let dir = std::env::temp_dir().join(format!("snapit_autosave_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let project_path = dir.join("project.json");
+        let sidecar = autosave_path(&project_path);
+
+        // No autosave
+        assert_eq!(newer_autosave(&project_path), None);
+
+        // Autosave of a project that was never saved
+        std::fs::write(&sidecar, "{}").unwrap();
+        assert_eq!(newer_autosave(&project_path), Some(sidecar.clone()));
+
+        // Saving afterwards makes the autosave stale
+        std::thread::sleep(Duration::from_millis(20));
+        std::fs::write(&project_path, "{}").unwrap();
+        assert_eq!(newer_autosave(&project_path), None);
+
+        discard_autosave(&project_path);
+        assert!(!sidecar.exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_discard_cancels_pending_write() {
+        let dir =
+            std::env::temp_dir().join(format!("snapit_autosave_discard_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let project_path = dir.join("project.json");
+        let sidecar = autosave_path(&project_path);
+        let queue = |generation| {
+            pending().lock().insert(
+                sidecar.clone(),
+                PendingAutosave {
+                    generation,
+                    project: Some(VideoProject::new("test.mp4", 1920, 1080, 60000, 30)),
+                },
+            );
+        };
+
+        // A write that is due goes ahead
+        queue(1);
+        write_if_latest(&sidecar, 1);
+        assert!(sidecar.exists());
+
+        // Saving the project discards the sidecar; the write that was due
+        // before the save must not bring it back
+        queue(2);
+        discard_autosave(&project_path);
+        write_if_latest(&sidecar, 2);
+        assert!(!sidecar.exists());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
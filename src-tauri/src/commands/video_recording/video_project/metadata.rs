@@ -202,16 +202,49 @@ pub fn load_video_project_from_file(video_path: &std::path::Path) -> Result<Vide
     load_video_project_legacy(video_path)
 }
 
+/// Path the project of `video_path` is saved to: `project.json` for a video
+/// inside a project folder (`screen.mp4`), else `<video>.snapit` next to it.
+pub fn project_file_path(video_path: &std::path::Path) -> PathBuf {
+    match video_path.parent() {
+        Some(parent) if video_path.file_name().and_then(|n| n.to_str()) == Some("screen.mp4") => {
+            parent.join("project.json")
+        },
+        _ => video_path.with_extension("snapit"),
+    }
+}
+
+/// Load the autosave `autosave_path` of the project of `video_path`.
+///
+/// Folder projects autosave with relative paths like project.json does.
+pub fn load_video_project_autosave(
+    video_path: &std::path::Path,
+    autosave_path: &std::path::Path,
+) -> Result<VideoProject, String> {
+    match video_path.parent() {
+        Some(parent) if video_path.file_name().and_then(|n| n.to_str()) == Some("screen.mp4") => {
+            load_folder_project_file(parent, autosave_path)
+        },
+        _ => VideoProject::load(autosave_path),
+    }
+}
+
 /// Load a VideoProject from a project folder containing project.json.
 /// Resolves relative paths in the project to absolute paths.
 fn load_video_project_from_folder(folder_path: &std::path::Path) -> Result<VideoProject, String> {
-    let project_json = folder_path.join("project.json");
+    load_folder_project_file(folder_path, &folder_path.join("project.json"))
+}
 
-    let content = std::fs::read_to_string(&project_json)
-        .map_err(|e| format!("Failed to read project.json: {}", e))?;
+/// Load a folder project saved at `project_file`, resolving its relative
+/// paths against `folder_path`.
+fn load_folder_project_file(
+    folder_path: &std::path::Path,
+    project_file: &std::path::Path,
+) -> Result<VideoProject, String> {
+    let content = std::fs::read_to_string(project_file)
+        .map_err(|e| format!("Failed to read {:?}: {}", project_file, e))?;
 
     let mut project: VideoProject = serde_json::from_str(&content)
-        .map_err(|e| format!("Failed to parse project.json: {}", e))?;
+        .map_err(|e| format!("Failed to parse {:?}: {}", project_file, e))?;
 
     // Resolve relative paths to absolute paths
     // The sources contain relative paths like "screen.mp4", "webcam.mp4", etc.
@@ -232,6 +265,12 @@ fn load_video_project_from_folder(folder_path: &std::path::Path) -> Result<Video
     if let Some(ref cursor) = project.sources.cursor_data {
         project.sources.cursor_data = Some(resolve_path(cursor));
     }
+    if let Some(ref timestamps) = project.sources.frame_timestamps {
+        project.sources.frame_timestamps = Some(resolve_path(timestamps));
+    }
+    if let Some(ref keystrokes) = project.sources.keystrokes {
+        project.sources.keystrokes = Some(resolve_path(keystrokes));
+    }
     if let Some(ref audio) = project.sources.audio_file {
         project.sources.audio_file = Some(resolve_path(audio));
     }
@@ -284,6 +323,7 @@ fn load_video_project_legacy(video_path: &std::path::Path) -> Result<VideoProjec
             start_ms: 0,
             end_ms: metadata.duration_ms,
             visible: true,
+            transition: None,
         });
     }
 
@@ -16,18 +16,23 @@
 //!   types.rs     - Type definitions (VideoProject, configs, etc.)
 //!   metadata.rs  - Video metadata extraction and project loading
 //!   frames.rs    - Video frame extraction and caching
+//!   timeline.rs  - Export time to source time mapping (cuts, speed ramps)
+//!   autosave.rs  - Debounced editor autosave and crash recovery
 //!   auto_zoom.rs - Auto-zoom generation from cursor data
 //! ```
 
 pub mod auto_zoom;
+pub mod autosave;
 pub mod frames;
 pub mod metadata;
+pub mod timeline;
 pub mod types;
 
 // Re-export all types for convenience
 pub use auto_zoom::{apply_auto_zoom_to_project, AutoZoomConfig};
 pub use frames::{clear_frame_cache, get_video_frame_cached};
 pub use metadata::{load_video_project_from_file, VideoMetadata};
+pub use timeline::TempoPiece;
 pub use types::*;
 
 #[cfg(test)]
@@ -57,6 +62,7 @@ mod tests {
             mode: ZoomRegionMode::Manual,
             is_auto: true,
             transition: ZoomTransition::default(),
+            focus_padding: ZoomRegion::default_focus_padding(),
         };
 
         let json = serde_json::to_string(&region).unwrap();
@@ -77,4 +83,118 @@ mod tests {
         assert_eq!(deserialized.scale, 2.0);
         assert_eq!(deserialized.hold_duration_ms, 1500);
     }
+
+    #[test]
+    fn test_easing_function_serialization() {
+        let json = serde_json::to_string(&EasingFunction::EaseOutBack).unwrap();
+        assert_eq!(json, r#""easeOutBack""#);
+
+        let custom: EasingFunction =
+            serde_json::from_str(r#"{"cubicBezier": [0.2, 0.0, 0.0, 1.2]}"#).unwrap();
+        assert_eq!(custom, EasingFunction::CubicBezier(0.2, 0.0, 0.0, 1.2));
+    }
+
+    #[test]
+    fn test_zoom_mode_follow_cursor_serialization() {
+        let mode: ZoomMode =
+            serde_json::from_str(r#"{"followCursor": {"scale": 2.0, "smoothing": 0.85}}"#).unwrap();
+        assert_eq!(
+            mode,
+            ZoomMode::FollowCursor {
+                scale: 2.0,
+                smoothing: 0.85
+            }
+        );
+
+        let manual: ZoomMode = serde_json::from_str(r#""manual""#).unwrap();
+        assert_eq!(manual, ZoomMode::Manual);
+    }
+
+    #[test]
+    fn test_background_blurred_source_deserialization() {
+        let mut json = serde_json::to_value(BackgroundConfig::default()).unwrap();
+        json["bgType"] = "blurredSource".into();
+        json.as_object_mut().unwrap().remove("imageBlurAmount");
+
+        let config: BackgroundConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(config.bg_type, BackgroundType::BlurredSource);
+        assert_eq!(config.image_blur_amount, 0.0);
+    }
+
+    #[test]
+    fn test_background_screen_style_aliases() {
+        let config: BackgroundConfig = serde_json::from_str(
+            r##"{
+                "bgType": "solid",
+                "solidColor": "#000000",
+                "gradientStart": "#000000",
+                "gradientEnd": "#000000",
+                "gradientAngle": 0,
+                "screenRounding": 16,
+                "screenShadow": {"enabled": true, "shadow": 80}
+            }"##,
+        )
+        .unwrap();
+
+        assert_eq!(config.rounding, 16.0);
+        assert!(config.shadow.enabled);
+        assert_eq!(config.shadow.shadow, 80.0);
+    }
+
+    #[test]
+    fn test_transparent_output_requires_alpha_format() {
+        let mut config = ExportConfig {
+            transparent_background: true,
+            ..Default::default()
+        };
+        assert!(!config.transparent_output());
+
+        config.format = ExportFormat::Mov;
+        assert!(config.transparent_output());
+        config.format = ExportFormat::PngSequence;
+        assert!(config.transparent_output());
+
+        config.transparent_background = false;
+        assert!(!config.transparent_output());
+    }
+
+    #[test]
+    fn test_text_segment_auto_fit_defaults_off() {
+        let legacy: TextSegment =
+            serde_json::from_str(r#"{"startMs": 0, "endMs": 2000, "text": "Hi"}"#).unwrap();
+        assert!(!legacy.auto_fit);
+
+        let fitted: TextSegment =
+            serde_json::from_str(r#"{"start": 0, "end": 2, "content": "Hi", "autoFit": true}"#)
+                .unwrap();
+        assert!(fitted.auto_fit);
+    }
+
+    #[test]
+    fn test_text_segment_spacing_defaults() {
+        let legacy: TextSegment =
+            serde_json::from_str(r#"{"startMs": 0, "endMs": 2000, "text": "Hi"}"#).unwrap();
+        assert_eq!(legacy.letter_spacing, 0.0);
+        assert_eq!(legacy.line_height, 1.2);
+
+        let spaced: TextSegment = serde_json::from_str(
+            r#"{"start": 0, "end": 2, "content": "Hi", "letterSpacing": 0.1, "lineHeight": 1.5}"#,
+        )
+        .unwrap();
+        assert_eq!(spaced.letter_spacing, 0.1);
+        assert_eq!(spaced.line_height, 1.5);
+    }
+
+    #[test]
+    fn test_text_segment_vertical_align() {
+        let legacy: TextSegment =
+            serde_json::from_str(r#"{"startMs": 0, "endMs": 2000, "text": "Hi"}"#).unwrap();
+        assert_eq!(legacy.vertical_align, TextVerticalAlign::Top);
+
+        let centered: TextSegment = serde_json::from_str(
+            r#"{"start": 0, "end": 2, "content": "Hi", "verticalAlign": "middle"}"#,
+        )
+        .unwrap();
+        assert_eq!(centered.vertical_align, TextVerticalAlign::Middle);
+    }
 }
@@ -0,0 +1,428 @@
+//! Mapping between export time and source time.
+//!
+//! The export plays the timeline's kept ranges back to back along the speed
+//! curve: speed keyframes on the source timeline, ramping linearly between
+//! keyframes. Output time is the integral of 1/speed over source time, so a 2x
+//! section takes half as long and consumes source frames twice as fast.
+//!
+//! Holds don't change the mapping: they only pick which source frame the
+//! screen shows while the timeline runs on.
+
+use super::types::{SpeedKeyframe, TimelineSegment, TimelineState};
+
+/// Slowest supported export speed.
+pub const MIN_SPEED: f64 = 0.25;
+
+/// Fastest supported export speed.
+pub const MAX_SPEED: f64 = 8.0;
+
+/// Speed ramps are cut into constant-tempo audio pieces of at most this length.
+const AUDIO_RAMP_STEP_MS: f64 = 250.0;
+
+/// Speed differences below this count as constant speed.
+const SPEED_EPSILON: f64 = 1e-6;
+
+/// Stretch of source time over which the speed changes linearly.
+#[derive(Debug, Clone, Copy)]
+struct SpeedPiece {
+    start_ms: f64,
+    end_ms: f64,
+    start_speed: f64,
+    end_speed: f64,
+}
+
+impl SpeedPiece {
+    /// How long the piece plays in the export.
+    fn output_ms(&self) -> f64 {
+        let length = self.end_ms - self.start_ms;
+        let ramp = self.end_speed - self.start_speed;
+        if ramp.abs() < SPEED_EPSILON {
+            length / self.start_speed
+        } else {
+            length / ramp * (self.end_speed / self.start_speed).ln()
+        }
+    }
+
+    /// Source time reached `output_ms` into the piece.
+    fn source_at(&self, output_ms: f64) -> f64 {
+        let length = self.end_ms - self.start_ms;
+        let ramp = self.end_speed - self.start_speed;
+        let offset = if ramp.abs() < SPEED_EPSILON || length <= 0.0 {
+            self.start_speed * output_ms
+        } else {
+            // Speed grows linearly with source time, so source time grows
+            // exponentially with output time
+            let rate = ramp / length;
+            self.start_speed * ((rate * output_ms).exp() - 1.0) / rate
+        };
+        (self.start_ms + offset).min(self.end_ms)
+    }
+}
+
+/// Source stretch played at one constant tempo, for retiming audio.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TempoPiece {
+    /// Start in source ms.
+    pub start_ms: f64,
+    /// End in source ms.
+    pub end_ms: f64,
+    /// Playback rate (source length / output length).
+    pub tempo: f64,
+}
+
+impl TimelineState {
+    /// Source ranges the export keeps, sorted, clamped to the recording and
+    /// with overlapping ranges merged.
+    pub fn kept_ranges(&self) -> Vec<TimelineSegment> {
+        if self.segments.is_empty() {
+            return vec![TimelineSegment {
+                start_ms: self.in_point,
+                end_ms: self.out_point.max(self.in_point),
+            }];
+        }
+
+        let mut ranges: Vec<TimelineSegment> = self
+            .segments
+            .iter()
+            .map(|segment| TimelineSegment {
+                start_ms: segment.start_ms.min(self.duration_ms),
+                end_ms: segment.end_ms.min(self.duration_ms),
+            })
+            .filter(|segment| segment.end_ms > segment.start_ms)
+            .collect();
+        ranges.sort_by_key(|segment| segment.start_ms);
+
+        let mut merged: Vec<TimelineSegment> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start_ms <= last.end_ms => {
+                    last.end_ms = last.end_ms.max(range.end_ms);
+                },
+                _ => merged.push(range),
+            }
+        }
+        merged
+    }
+
+    /// Kept ranges from `source_ms` on, the first starting there (for
+    /// decoding a resumed export).
+    pub fn ranges_from(&self, source_ms: u64) -> Vec<TimelineSegment> {
+        self.kept_ranges()
+            .into_iter()
+            .filter(|range| range.end_ms > source_ms)
+            .map(|range| TimelineSegment {
+                start_ms: range.start_ms.max(source_ms),
+                end_ms: range.end_ms,
+            })
+            .collect()
+    }
+
+    /// Whether any part of the export plays at other than 1x.
+    pub fn has_speed_changes(&self) -> bool {
+        self.speed_keyframes
+            .iter()
+            .any(|keyframe| (clamp_speed(keyframe.speed) - 1.0).abs() > SPEED_EPSILON)
+    }
+
+    /// Export speed at `source_ms`.
+    pub fn speed_at(&self, source_ms: f64) -> f64 {
+        speed_at(&self.sorted_speed_keyframes(), source_ms)
+    }
+
+    /// Length of the export in ms.
+    pub fn output_duration_ms(&self) -> f64 {
+        let keyframes = self.sorted_speed_keyframes();
+        self.kept_ranges()
+            .iter()
+            .flat_map(|range| speed_pieces(&keyframes, range))
+            .map(|piece| piece.output_ms())
+            .sum()
+    }
+
+    /// Source time of every export frame at `fps`, in playback order.
+    ///
+    /// Each kept range spans `ceil(output length * fps)` frames.
+    pub fn frame_source_times(&self, fps: u32) -> Vec<u64> {
+        let fps = fps.max(1) as f64;
+        let keyframes = self.sorted_speed_keyframes();
+        let mut times = Vec::new();
+
+        for range in self.kept_ranges() {
+            let pieces = speed_pieces(&keyframes, &range);
+            let range_output_ms: f64 = pieces.iter().map(SpeedPiece::output_ms).sum();
+            let frames = (range_output_ms / 1000.0 * fps).ceil() as u32;
+
+            let mut piece_idx = 0;
+            let mut piece_start_ms = 0.0;
+            for frame in 0..frames {
+                let output_ms = frame as f64 * 1000.0 / fps;
+                while piece_idx + 1 < pieces.len()
+                    && output_ms >= piece_start_ms + pieces[piece_idx].output_ms()
+                {
+                    piece_start_ms += pieces[piece_idx].output_ms();
+                    piece_idx += 1;
+                }
+                let source_ms = pieces[piece_idx].source_at(output_ms - piece_start_ms);
+                times.push(source_ms as u64);
+            }
+        }
+        times
+    }
+
+    /// Kept ranges cut into constant-tempo pieces for retiming audio.
+    ///
+    /// Ramps are split every `AUDIO_RAMP_STEP_MS` of source time; each piece's
+    /// tempo matches its exact output length, so audio stays in sync with the
+    /// frames at every piece boundary.
+    pub fn tempo_pieces(&self) -> Vec<TempoPiece> {
+        let keyframes = self.sorted_speed_keyframes();
+        let mut tempo_pieces = Vec::new();
+
+        for range in self.kept_ranges() {
+            for piece in speed_pieces(&keyframes, &range) {
+                let length = piece.end_ms - piece.start_ms;
+                let steps = if (piece.end_speed - piece.start_speed).abs() < SPEED_EPSILON {
+                    1
+                } else {
+                    (length / AUDIO_RAMP_STEP_MS).ceil().max(1.0) as usize
+                };
+
+                for step in 0..steps {
+                    let start_ms = piece.start_ms + length * step as f64 / steps as f64;
+                    let end_ms = piece.start_ms + length * (step + 1) as f64 / steps as f64;
+                    let sub_piece = SpeedPiece {
+                        start_ms,
+                        end_ms,
+                        start_speed: speed_at(&keyframes, start_ms),
+                        end_speed: speed_at(&keyframes, end_ms),
+                    };
+                    tempo_pieces.push(TempoPiece {
+                        start_ms,
+                        end_ms,
+                        tempo: (end_ms - start_ms) / sub_piece.output_ms(),
+                    });
+                }
+            }
+        }
+        tempo_pieces
+    }
+
+    /// Source time of the screen frame shown at `source_ms`: the held frame
+    /// while a hold covers it, otherwise `source_ms` itself.
+    pub fn held_source_ms(&self, source_ms: u64) -> u64 {
+        self.holds
+            .iter()
+            .filter(|hold| hold.contains(source_ms))
+            .map(|hold| hold.at_source_ms)
+            .min()
+            .unwrap_or(source_ms)
+    }
+
+    fn sorted_speed_keyframes(&self) -> Vec<SpeedKeyframe> {
+        let mut keyframes = self.speed_keyframes.clone();
+        keyframes.sort_by_key(|keyframe| keyframe.time_ms);
+        keyframes
+    }
+}
+
+/// Speed keyframes are clamped to the supported range (non-finite = 1x).
+fn clamp_speed(speed: f32) -> f64 {
+    if speed.is_finite() {
+        (speed as f64).clamp(MIN_SPEED, MAX_SPEED)
+    } else {
+        1.0
+    }
+}
+
+/// Speed at `source_ms` on the curve through `keyframes` (sorted by time).
+fn speed_at(keyframes: &[SpeedKeyframe], source_ms: f64) -> f64 {
+    let (Some(first), Some(last)) = (keyframes.first(), keyframes.last()) else {
+        return 1.0;
+    };
+    if source_ms <= first.time_ms as f64 {
+        return clamp_speed(first.speed);
+    }
+    if source_ms >= last.time_ms as f64 {
+        return clamp_speed(last.speed);
+    }
+
+    let next = keyframes.partition_point(|keyframe| keyframe.time_ms as f64 <= source_ms);
+    let (a, b) = (&keyframes[next - 1], &keyframes[next]);
+    let t = (source_ms - a.time_ms as f64) / (b.time_ms - a.time_ms) as f64;
+    let (speed_a, speed_b) = (clamp_speed(a.speed), clamp_speed(b.speed));
+    speed_a + (speed_b - speed_a) * t
+}
+
+/// Split `range` at the keyframes inside it into linear speed pieces.
+fn speed_pieces(keyframes: &[SpeedKeyframe], range: &TimelineSegment) -> Vec<SpeedPiece> {
+    let mut bounds = vec![range.start_ms as f64];
+    bounds.extend(
+        keyframes
+            .iter()
+            .map(|keyframe| keyframe.time_ms)
+            .filter(|&time_ms| time_ms > range.start_ms && time_ms < range.end_ms)
+            .map(|time_ms| time_ms as f64),
+    );
+    bounds.push(range.end_ms as f64);
+    bounds.dedup();
+
+    bounds
+        .windows(2)
+        .filter(|pair| pair[1] > pair[0])
+        .map(|pair| SpeedPiece {
+            start_ms: pair[0],
+            end_ms: pair[1],
+            start_speed: speed_at(keyframes, pair[0]),
+            end_speed: speed_at(keyframes, pair[1]),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::video_recording::video_project::{HoldAudio, HoldSegment};
+
+    fn segment(start_ms: u64, end_ms: u64) -> TimelineSegment {
+        TimelineSegment { start_ms, end_ms }
+    }
+
+    fn keyframe(time_ms: u64, speed: f32) -> SpeedKeyframe {
+        SpeedKeyframe { time_ms, speed }
+    }
+
+    #[test]
+    fn test_segments_map_through_cuts() {
+        let timeline = TimelineState {
+            duration_ms: 10_000,
+            in_point: 0,
+            out_point: 10_000,
+            // Out of order, overlapping and past the end of the recording
+            segments: vec![
+                segment(6000, 12_000),
+                segment(1000, 2000),
+                segment(1500, 3000),
+            ],
+            ..TimelineState::default()
+        };
+
+        assert_eq!(
+            timeline.kept_ranges(),
+            vec![segment(1000, 3000), segment(6000, 10_000)]
+        );
+        // 2s + 4s at 10fps
+        let times = timeline.frame_source_times(10);
+        assert_eq!(times.len(), 60);
+        assert_eq!(times[0], 1000);
+        assert_eq!(times[19], 2900);
+        // First frame after the cut jumps to the second range
+        assert_eq!(times[20], 6000);
+        assert_eq!(times[25], 6500);
+
+        // Resuming mid-way skips the first range and part of the second
+        assert_eq!(timeline.ranges_from(6500), vec![segment(6500, 10_000)]);
+        assert_eq!(timeline.ranges_from(0), timeline.kept_ranges());
+    }
+
+    #[test]
+    fn test_without_segments_keeps_in_out() {
+        let timeline = TimelineState {
+            duration_ms: 10_000,
+            in_point: 2000,
+            out_point: 5000,
+            ..TimelineState::default()
+        };
+
+        assert_eq!(timeline.kept_ranges(), vec![segment(2000, 5000)]);
+        let times = timeline.frame_source_times(30);
+        assert_eq!(times.len(), 90);
+        assert_eq!(times[30], 3000);
+        assert!(!timeline.has_speed_changes());
+        assert_eq!(timeline.tempo_pieces().len(), 1);
+    }
+
+    #[test]
+    fn test_constant_speed_consumes_source_faster() {
+        let timeline = TimelineState {
+            duration_ms: 4000,
+            in_point: 0,
+            out_point: 4000,
+            speed_keyframes: vec![keyframe(2000, 2.0), keyframe(0, 2.0)],
+            ..TimelineState::default()
+        };
+
+        assert!(timeline.has_speed_changes());
+        assert!((timeline.output_duration_ms() - 2000.0).abs() < 1e-6);
+        let times = timeline.frame_source_times(10);
+        assert_eq!(times.len(), 20);
+        assert_eq!(times[1], 200);
+        assert_eq!(times[10], 2000);
+
+        let pieces = timeline.tempo_pieces();
+        assert!(pieces.iter().all(|piece| (piece.tempo - 2.0).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_speed_ramp_integrates_curve() {
+        // 1x at 0s ramping to 3x at 2s, then held
+        let timeline = TimelineState {
+            duration_ms: 3000,
+            in_point: 0,
+            out_point: 3000,
+            speed_keyframes: vec![keyframe(0, 1.0), keyframe(2000, 3.0)],
+            ..TimelineState::default()
+        };
+
+        assert!((timeline.speed_at(1000.0) - 2.0).abs() < 1e-9);
+        // Ramp: 2000 / (3 - 1) * ln(3), then 1000ms at 3x
+        let expected = 1000.0 * 3f64.ln() + 1000.0 / 3.0;
+        assert!((timeline.output_duration_ms() - expected).abs() < 1e-6);
+
+        let times = timeline.frame_source_times(30);
+        assert_eq!(times.len(), (expected / 1000.0 * 30.0).ceil() as usize);
+        assert!(times.windows(2).all(|pair| pair[1] >= pair[0]));
+        // Source steps grow as the ramp speeds up
+        assert!(times[2] - times[1] < times[times.len() - 1] - times[times.len() - 2]);
+
+        // Audio pieces cover the range and add up to the same output length
+        let pieces = timeline.tempo_pieces();
+        let output_ms: f64 = pieces
+            .iter()
+            .map(|piece| (piece.end_ms - piece.start_ms) / piece.tempo)
+            .sum();
+        assert!((output_ms - expected).abs() < 1e-6);
+        assert_eq!(pieces.first().map(|piece| piece.start_ms), Some(0.0));
+        assert_eq!(pieces.last().map(|piece| piece.end_ms), Some(3000.0));
+    }
+
+    #[test]
+    fn test_holds_freeze_screen_frame() {
+        let timeline = TimelineState {
+            duration_ms: 5000,
+            in_point: 0,
+            out_point: 5000,
+            holds: vec![
+                HoldSegment {
+                    at_source_ms: 1000,
+                    duration_ms: 2000,
+                    audio: HoldAudio::Continue,
+                },
+                // Overlapping hold: the earlier frame wins
+                HoldSegment {
+                    at_source_ms: 2500,
+                    duration_ms: 1000,
+                    audio: HoldAudio::Silence,
+                },
+            ],
+            ..TimelineState::default()
+        };
+
+        assert_eq!(timeline.held_source_ms(999), 999);
+        assert_eq!(timeline.held_source_ms(1000), 1000);
+        assert_eq!(timeline.held_source_ms(2999), 1000);
+        assert_eq!(timeline.held_source_ms(3000), 2500);
+        assert_eq!(timeline.held_source_ms(3500), 3500);
+        // The timeline itself keeps running through the holds
+        assert_eq!(timeline.frame_source_times(10).len(), 50);
+    }
+}
@@ -9,6 +9,8 @@
 //! - Export settings
 
 use serde::{Deserialize, Serialize};
+
+use crate::commands::video_recording::GifQualityPreset;
 use std::path::PathBuf;
 use ts_rs::TS;
 
@@ -50,6 +52,12 @@ pub struct VideoProject {
     /// Mask/blur region configuration.
     #[serde(default)]
     pub mask: MaskConfig,
+    /// Cursor spring overrides for parts of the timeline.
+    #[serde(default)]
+    pub cursor_smoothing_segments: Vec<CursorSmoothingSegment>,
+    /// Keystroke overlay configuration.
+    #[serde(default)]
+    pub keystrokes: KeystrokeConfig,
 }
 
 /// Source files for a video project.
@@ -63,6 +71,14 @@ pub struct VideoSources {
     pub webcam_video: Option<String>,
     /// Path to cursor events JSON file.
     pub cursor_data: Option<String>,
+    /// Path to the per-frame capture timestamps sidecar (`frames.json`).
+    #[serde(default)]
+    #[ts(optional)]
+    pub frame_timestamps: Option<String>,
+    /// Path to the recorded key presses sidecar (`keystroke.json`).
+    #[serde(default)]
+    #[ts(optional)]
+    pub keystrokes: Option<String>,
     /// Path to audio file if recorded separately (legacy, use system_audio/microphone_audio instead).
     pub audio_file: Option<String>,
     /// Path to system audio recording (desktop/app audio).
@@ -101,6 +117,18 @@ pub struct TimelineState {
     pub out_point: u64,
     /// Playback speed multiplier (1.0 = normal).
     pub speed: f32,
+    /// Source ranges kept in the export, in order. Everything between them is
+    /// cut. Empty = keep the single `in_point`..`out_point` range.
+    #[serde(default)]
+    pub segments: Vec<TimelineSegment>,
+    /// Export speed curve over source time. Speed ramps linearly between
+    /// keyframes and holds before the first and after the last. Empty = 1x.
+    #[serde(default)]
+    pub speed_keyframes: Vec<SpeedKeyframe>,
+    /// Freeze-frames: the screen holds on one frame while the timeline
+    /// (audio, zoom, text) keeps running underneath.
+    #[serde(default)]
+    pub holds: Vec<HoldSegment>,
 }
 
 impl Default for TimelineState {
@@ -110,10 +138,81 @@ impl Default for TimelineState {
             in_point: 0,
             out_point: 0,
             speed: 1.0,
+            segments: Vec::new(),
+            speed_keyframes: Vec::new(),
+            holds: Vec::new(),
         }
     }
 }
 
+/// A kept range of the source recording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct TimelineSegment {
+    /// Start of the range in source ms.
+    #[ts(type = "number")]
+    pub start_ms: u64,
+    /// End of the range in source ms (exclusive).
+    #[ts(type = "number")]
+    pub end_ms: u64,
+}
+
+impl TimelineSegment {
+    /// Length of the range in milliseconds.
+    pub fn duration_ms(&self) -> u64 {
+        self.end_ms.saturating_sub(self.start_ms)
+    }
+}
+
+/// Export playback speed at a point of the source timeline.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct SpeedKeyframe {
+    /// Source time in ms.
+    #[ts(type = "number")]
+    pub time_ms: u64,
+    /// Speed multiplier (0.25-8, 2.0 = twice as fast).
+    pub speed: f32,
+}
+
+/// Freeze-frame on the source timeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct HoldSegment {
+    /// Source time of the frame to hold, in ms.
+    #[ts(type = "number")]
+    pub at_source_ms: u64,
+    /// How long the frame is held, in ms of source time.
+    #[ts(type = "number")]
+    pub duration_ms: u64,
+    /// What the audio does during the hold.
+    #[serde(default)]
+    pub audio: HoldAudio,
+}
+
+impl HoldSegment {
+    /// Whether the hold shows its frame at `source_ms`.
+    pub fn contains(&self, source_ms: u64) -> bool {
+        source_ms >= self.at_source_ms
+            && source_ms < self.at_source_ms.saturating_add(self.duration_ms)
+    }
+}
+
+/// Audio during a freeze-frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub enum HoldAudio {
+    /// Recorded tracks keep playing under the held frame (e.g. narration).
+    #[default]
+    Continue,
+    /// Recorded tracks are muted for the hold.
+    Silence,
+}
+
 // ============================================================================
 // Audio Track Settings
 // ============================================================================
@@ -133,14 +232,34 @@ pub struct AudioTrackSettings {
     pub music_fade_in_secs: f32,
     /// Fade out duration for background music (seconds).
     pub music_fade_out_secs: f32,
-    /// Normalize output audio to -16 LUFS.
+    /// Normalize output audio to `loudness_target_lufs`.
     pub normalize_output: bool,
+    /// Integrated loudness target for normalization (e.g. -14 streaming, -16 podcasts).
+    #[serde(default = "default_loudness_target_lufs")]
+    pub loudness_target_lufs: f32,
     /// Mute system audio track.
     pub system_muted: bool,
     /// Mute microphone track.
     pub microphone_muted: bool,
     /// Mute background music.
     pub music_muted: bool,
+    /// Microphone track offset in ms. Positive delays the track, negative advances it.
+    #[serde(default)]
+    pub mic_offset_ms: i32,
+    /// System audio track offset in ms. Positive delays the track, negative advances it.
+    #[serde(default)]
+    pub system_offset_ms: i32,
+    /// Click sound volume (0.0 - 1.0).
+    #[serde(default = "default_click_sound_volume")]
+    pub click_sound_volume: f32,
+}
+
+fn default_loudness_target_lufs() -> f32 {
+    -16.0
+}
+
+fn default_click_sound_volume() -> f32 {
+    0.5
 }
 
 impl Default for AudioTrackSettings {
@@ -152,9 +271,13 @@ impl Default for AudioTrackSettings {
             music_fade_in_secs: 2.0,
             music_fade_out_secs: 3.0,
             normalize_output: true,
+            loudness_target_lufs: default_loudness_target_lufs(),
             system_muted: false,
             microphone_muted: false,
             music_muted: false,
+            mic_offset_ms: 0,
+            system_offset_ms: 0,
+            click_sound_volume: default_click_sound_volume(),
         }
     }
 }
@@ -187,7 +310,7 @@ impl Default for ZoomConfig {
 }
 
 /// Zoom mode - controls how zooms are applied.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export, export_to = "../../src/types/generated/")]
 pub enum ZoomMode {
@@ -199,6 +322,30 @@ pub enum ZoomMode {
     Manual,
     /// Use both auto-generated and manual zooms.
     Both,
+    /// Stay zoomed in at `scale` and pan so the cursor stays centered.
+    /// `smoothing` (0-1) is how much of the distance to the cursor the view
+    /// keeps per 60fps frame; 0 snaps to the cursor, higher values lag behind.
+    FollowCursor { scale: f32, smoothing: f32 },
+    /// Follow an explicit zoom curve instead of the regions.
+    Keyframed { keyframes: Vec<ZoomKeyframe> },
+}
+
+/// A point on a keyframed zoom curve ([`ZoomMode::Keyframed`]).
+///
+/// The zoom holds the first keyframe before it and the last one after it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct ZoomKeyframe {
+    /// Timeline position in milliseconds.
+    #[ts(type = "number")]
+    pub time_ms: u64,
+    /// Zoom center (0-1 normalized).
+    pub center: XY<f64>,
+    /// Zoom scale (1.0 = no zoom).
+    pub scale: f32,
+    /// Easing of the transition from the previous keyframe into this one.
+    pub easing: EasingFunction,
 }
 
 /// Per-region zoom mode - controls whether a region follows the cursor or uses a fixed position.
@@ -247,6 +394,16 @@ pub struct ZoomRegion {
     pub is_auto: bool,
     /// Transition settings.
     pub transition: ZoomTransition,
+    /// Minimum distance (normalized 0-0.5) kept between the zoom target and the
+    /// output edges, so targets near a corner aren't clipped by the viewport.
+    #[serde(default = "ZoomRegion::default_focus_padding")]
+    pub focus_padding: f32,
+}
+
+impl ZoomRegion {
+    pub fn default_focus_padding() -> f32 {
+        0.05
+    }
 }
 
 /// Zoom transition settings.
@@ -273,7 +430,7 @@ impl Default for ZoomTransition {
 }
 
 /// Easing function for animations.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export, export_to = "../../src/types/generated/")]
 pub enum EasingFunction {
@@ -291,6 +448,56 @@ pub enum EasingFunction {
     Snappy,
     /// Slight overshoot at end.
     Bouncy,
+    /// Cubic slow start and end.
+    EaseInOutCubic,
+    /// Fast start that overshoots the target slightly, then settles.
+    EaseOutBack,
+    /// Custom curve with control points (x1, y1, x2, y2), like CSS `cubic-bezier()`.
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl EasingFunction {
+    /// Map linear progress (0-1) to eased progress.
+    ///
+    /// Overshooting curves (`Bouncy`, `EaseOutBack`, some `CubicBezier`s) can
+    /// return values outside 0-1 mid-curve; callers clamp what they render.
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t,
+            Self::EaseOut => 1.0 - (1.0 - t).powi(2),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            },
+            // Smoothstep
+            Self::Smooth => t * t * (3.0 - 2.0 * t),
+            Self::Snappy => 1.0 - (1.0 - t).powi(3),
+            Self::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            },
+            Self::Bouncy | Self::EaseOutBack => {
+                let c1 = 1.70158;
+                let c3 = c1 + 1.0;
+                1.0 + c3 * (t - 1.0).powi(3) + c1 * (t - 1.0).powi(2)
+            },
+            Self::CubicBezier(x1, y1, x2, y2) => {
+                // The x control points must stay within 0-1 for the curve to be a function
+                match bezier_easing::bezier_easing(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2) {
+                    Ok(curve) => curve(t as f32) as f64,
+                    Err(_) => t,
+                }
+            },
+        }
+    }
 }
 
 // ============================================================================
@@ -369,6 +576,9 @@ pub struct CursorConfig {
     pub motion_blur: f32,
     /// Click highlight settings.
     pub click_highlight: ClickHighlightConfig,
+    /// Fading trail behind the cursor, or None for no trail.
+    #[serde(default)]
+    pub cursor_trail: Option<CursorTrailConfig>,
     /// Hide cursor when idle.
     pub hide_when_idle: bool,
     /// Time before hiding idle cursor (milliseconds).
@@ -402,12 +612,61 @@ impl Default for CursorConfig {
             friction,
             motion_blur: 0.0,
             click_highlight: ClickHighlightConfig::default(),
+            cursor_trail: None,
             hide_when_idle: false,
             idle_timeout_ms: 3000,
         }
     }
 }
 
+/// Fading motion trail drawn behind the cursor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct CursorTrailConfig {
+    /// How far back the trail reaches (ms).
+    pub length_ms: u32,
+    /// Trail color (hex format, e.g., "#ffffff").
+    pub color: String,
+    /// Opacity at the cursor (0-1); the trail fades to transparent at its tail.
+    pub opacity: f32,
+    /// Fade curve exponent (1.0 = linear, higher = fades out sooner).
+    pub falloff: f32,
+    /// Line width in pixels (at 720p reference).
+    pub width: f32,
+}
+
+impl Default for CursorTrailConfig {
+    fn default() -> Self {
+        Self {
+            length_ms: 300,
+            color: "#ffffff".to_string(),
+            opacity: 0.6,
+            falloff: 1.5,
+            width: 4.0,
+        }
+    }
+}
+
+/// Cursor spring constants for a time range, overriding the ones in [`CursorConfig`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct CursorSmoothingSegment {
+    /// Start time in milliseconds.
+    #[ts(type = "number")]
+    pub start_ms: u64,
+    /// End time in milliseconds.
+    #[ts(type = "number")]
+    pub end_ms: u64,
+    /// Spring tension (higher = snappier).
+    pub tension: f32,
+    /// Mass (higher = more momentum).
+    pub mass: f32,
+    /// Friction (higher = more damping).
+    pub friction: f32,
+}
+
 /// Click highlight animation settings.
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase")]
@@ -423,6 +682,10 @@ pub struct ClickHighlightConfig {
     pub duration_ms: u32,
     /// Highlight style.
     pub style: ClickHighlightStyle,
+    /// Short WAV file played at every click in exports, or None for silent clicks.
+    /// Independent of `enabled`: the sound plays even with the highlight hidden.
+    #[serde(default)]
+    pub click_sound: Option<String>,
 }
 
 impl Default for ClickHighlightConfig {
@@ -433,6 +696,7 @@ impl Default for ClickHighlightConfig {
             radius: 30,
             duration_ms: 400,
             style: ClickHighlightStyle::Ripple,
+            click_sound: None,
         }
     }
 }
@@ -444,7 +708,7 @@ impl Default for ClickHighlightConfig {
 pub enum ClickHighlightStyle {
     /// Expanding circle animation.
     Ripple,
-    /// Static glow effect.
+    /// Dims everything except a circle around the click (ignores `color`).
     Spotlight,
     /// Hollow ring animation.
     Ring,
@@ -488,6 +752,95 @@ pub struct WebcamConfig {
     pub border: WebcamBorder,
     /// Visibility segments (for toggling on/off during video).
     pub visibility_segments: Vec<VisibilitySegment>,
+    /// Green-screen keying applied on export. Off when `None`.
+    #[serde(default)]
+    #[ts(optional)]
+    pub chroma_key: Option<ChromaKeyConfig>,
+    /// Animated position/size path. When empty, the static position is used.
+    #[serde(default)]
+    pub position_keyframes: Vec<WebcamPositionKeyframe>,
+    /// Overlay placements (e.g., picture-in-picture at several positions).
+    /// When empty, the position/size/shape/visibility above are the only one.
+    #[serde(default)]
+    pub instances: Vec<WebcamInstance>,
+}
+
+impl WebcamConfig {
+    /// The single placement described by the top-level fields.
+    pub fn base_instance(&self) -> WebcamInstance {
+        let mut instance = WebcamInstance {
+            position: self.position,
+            custom_x: self.custom_x,
+            custom_y: self.custom_y,
+            size: self.size,
+            shape: self.shape,
+            visibility_segments: self.visibility_segments.clone(),
+            position_keyframes: self.position_keyframes.clone(),
+        };
+        instance.sort_keyframes();
+        instance
+    }
+
+    /// Placements to composite, bottom to top.
+    pub fn overlay_instances(&self) -> Vec<WebcamInstance> {
+        if self.instances.is_empty() {
+            return vec![self.base_instance()];
+        }
+        let mut instances = self.instances.clone();
+        instances
+            .iter_mut()
+            .for_each(WebcamInstance::sort_keyframes);
+        instances
+    }
+}
+
+/// One on-screen placement of the webcam.
+///
+/// Shares the source and styling (border, shadow, mirror, keying) of its
+/// `WebcamConfig`; only placement and timing are per instance.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct WebcamInstance {
+    /// Position preset.
+    pub position: WebcamOverlayPosition,
+    /// Custom position (used when position is Custom).
+    pub custom_x: f32,
+    pub custom_y: f32,
+    /// Size as percentage of video width (e.g., 0.2 = 20%).
+    pub size: f32,
+    /// Shape of webcam overlay.
+    pub shape: WebcamOverlayShape,
+    /// Visibility segments (for toggling on/off during video).
+    #[serde(default)]
+    pub visibility_segments: Vec<VisibilitySegment>,
+    /// Animated position/size path. When empty, the static position is used.
+    #[serde(default)]
+    pub position_keyframes: Vec<WebcamPositionKeyframe>,
+}
+
+impl WebcamInstance {
+    fn sort_keyframes(&mut self) {
+        self.position_keyframes.sort_by_key(|k| k.time_ms);
+    }
+}
+
+/// A point on an animated webcam path.
+///
+/// The webcam holds the first keyframe before it and the last one after it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct WebcamPositionKeyframe {
+    /// Timeline position in milliseconds.
+    #[ts(type = "number")]
+    pub time_ms: u64,
+    /// Webcam center (0-1 normalized).
+    pub center: XY<f64>,
+    /// Size as percentage of video width (e.g., 0.2 = 20%).
+    pub size: f32,
+    /// Easing of the transition from the previous keyframe into this one.
+    pub easing: EasingFunction,
 }
 
 fn default_rounding() -> f32 {
@@ -514,6 +867,32 @@ impl Default for WebcamConfig {
             mirror: false,
             border: WebcamBorder::default(),
             visibility_segments: Vec::new(),
+            chroma_key: None,
+            position_keyframes: Vec::new(),
+            instances: Vec::new(),
+        }
+    }
+}
+
+/// Chroma key settings for removing a solid-color webcam background.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct ChromaKeyConfig {
+    /// Key color (hex format, e.g., "#00FF00").
+    pub key_color: String,
+    /// Chroma distance from the key color that is fully removed (0-1).
+    pub similarity: f32,
+    /// Width of the partially transparent band beyond `similarity` (0-1).
+    pub smoothness: f32,
+}
+
+impl Default for ChromaKeyConfig {
+    fn default() -> Self {
+        Self {
+            key_color: "#00FF00".to_string(),
+            similarity: 0.4,
+            smoothness: 0.08,
         }
     }
 }
@@ -592,6 +971,21 @@ pub struct WebcamBorder {
     pub width: u32,
     /// Border color (CSS color string).
     pub color: String,
+    /// Gradient end color. Solid `color` border when `None`.
+    #[serde(default)]
+    #[ts(optional)]
+    pub gradient_end: Option<String>,
+    /// Gradient angle in degrees (0-360).
+    #[serde(default = "default_webcam_border_gradient_angle")]
+    pub gradient_angle: f32,
+    /// Glow around the border. No glow when `None`.
+    #[serde(default)]
+    #[ts(optional)]
+    pub glow: Option<GlowConfig>,
+}
+
+fn default_webcam_border_gradient_angle() -> f32 {
+    135.0
 }
 
 impl Default for WebcamBorder {
@@ -600,6 +994,32 @@ impl Default for WebcamBorder {
             enabled: false,
             width: 3,
             color: "#FFFFFF".to_string(),
+            gradient_end: None,
+            gradient_angle: default_webcam_border_gradient_angle(),
+            glow: None,
+        }
+    }
+}
+
+/// Soft glow drawn outside the webcam border.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct GlowConfig {
+    /// Glow color (CSS color string).
+    pub color: String,
+    /// Glow radius in pixels.
+    pub radius: f32,
+    /// Glow opacity (0-1).
+    pub opacity: f32,
+}
+
+impl Default for GlowConfig {
+    fn default() -> Self {
+        Self {
+            color: "#FFFFFF".to_string(),
+            radius: 16.0,
+            opacity: 0.6,
         }
     }
 }
@@ -617,6 +1037,35 @@ pub struct VisibilitySegment {
     pub end_ms: u64,
     /// Whether visible during this segment.
     pub visible: bool,
+    /// Animation at the segment's boundaries. Instant when `None`.
+    #[serde(default)]
+    #[ts(optional)]
+    pub transition: Option<WebcamTransition>,
+}
+
+/// How the webcam animates in and out at a visibility boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub enum WebcamTransitionKind {
+    /// Opacity ramps in/out.
+    Fade,
+    /// Slides in from (and out to) the nearest horizontal edge.
+    SlideFromEdge,
+    /// Grows from (and shrinks to) its center.
+    ScaleIn,
+}
+
+/// Webcam entrance/exit transition.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct WebcamTransition {
+    /// Animation style.
+    pub kind: WebcamTransitionKind,
+    /// Duration of each of the entrance and exit in milliseconds.
+    #[ts(type = "number")]
+    pub duration_ms: u64,
 }
 
 // ============================================================================
@@ -648,16 +1097,52 @@ pub struct ExportConfig {
     /// Composition configuration (output canvas size/aspect).
     #[serde(default)]
     pub composition: CompositionConfig,
+    /// How screen content fills a composition taller than it (e.g. 9:16).
+    #[serde(default)]
+    pub portrait_framing: PortraitFraming,
+    /// Palette quality used when exporting to GIF.
+    #[serde(default)]
+    pub gif_quality_preset: GifQualityPreset,
+    /// Leave the background transparent instead of drawing it (alpha-capable
+    /// formats only: MOV/ProRes 4444 and PNG sequences).
+    #[serde(default)]
+    pub transparent_background: bool,
+    /// Encode in chunks next to the output so a failed export of the same
+    /// project resumes where it stopped (MP4, WebM and MOV).
+    #[serde(default)]
+    pub resumable: bool,
     /// Prefer hardware encoding (NVENC) when available.
     /// Defaults to true. Set to false to force software encoding.
     #[serde(default = "default_prefer_hardware")]
     pub prefer_hardware_encoding: Option<bool>,
+    /// Burned-in timecode/clock overlay. Off when `None`.
+    #[serde(default)]
+    #[ts(optional)]
+    pub timecode: Option<TimecodeConfig>,
 }
 
 fn default_prefer_hardware() -> Option<bool> {
     Some(false)
 }
 
+impl ExportConfig {
+    /// Whether exported frames keep a transparent background: requested and
+    /// supported by the format.
+    pub fn transparent_output(&self) -> bool {
+        self.transparent_background && self.format.supports_alpha()
+    }
+
+    /// Whether the export is encoded in resumable chunks: requested and the
+    /// format is a video that FFmpeg can concatenate.
+    pub fn resumable_output(&self) -> bool {
+        self.resumable
+            && matches!(
+                self.format,
+                ExportFormat::Mp4 | ExportFormat::Webm | ExportFormat::Mov
+            )
+    }
+}
+
 impl Default for ExportConfig {
     fn default() -> Self {
         Self {
@@ -670,19 +1155,142 @@ impl Default for ExportConfig {
             background: BackgroundConfig::default(),
             crop: CropConfig::default(),
             composition: CompositionConfig::default(),
+            portrait_framing: PortraitFraming::default(),
+            gif_quality_preset: GifQualityPreset::default(),
+            transparent_background: false,
+            resumable: false,
             prefer_hardware_encoding: Some(false),
+            timecode: None,
+        }
+    }
+}
+
+/// Burned-in timecode overlay rendered into exported frames.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct TimecodeConfig {
+    /// What time the overlay shows.
+    #[serde(default)]
+    pub mode: TimecodeMode,
+    /// strftime-style format string (e.g. "%H:%M:%S%.3f").
+    #[serde(default = "TimecodeConfig::default_format")]
+    pub format: String,
+    /// Frame corner the timecode is anchored to.
+    #[serde(default)]
+    pub position: TimecodePosition,
+    /// Distance from the corner in pixels (at 1080p reference).
+    #[serde(default = "TimecodeConfig::default_margin")]
+    pub margin: f32,
+    /// Font family.
+    #[serde(default = "TimecodeConfig::default_font_family")]
+    pub font_family: String,
+    /// Font size in pixels (at 1080p reference).
+    #[serde(default = "TimecodeConfig::default_font_size")]
+    pub font_size: f32,
+    /// Font weight (100-900).
+    #[serde(default = "TimecodeConfig::default_font_weight")]
+    pub font_weight: f32,
+    /// Text color (hex format, e.g., "#ffffff").
+    #[serde(default = "TimecodeConfig::default_color")]
+    pub color: String,
+}
+
+impl TimecodeConfig {
+    fn default_format() -> String {
+        "%H:%M:%S".to_string()
+    }
+
+    fn default_margin() -> f32 {
+        24.0
+    }
+
+    fn default_font_family() -> String {
+        "monospace".to_string()
+    }
+
+    fn default_font_size() -> f32 {
+        32.0
+    }
+
+    fn default_font_weight() -> f32 {
+        600.0
+    }
+
+    fn default_color() -> String {
+        "#ffffff".to_string()
+    }
+}
+
+impl Default for TimecodeConfig {
+    fn default() -> Self {
+        Self {
+            mode: TimecodeMode::default(),
+            format: Self::default_format(),
+            position: TimecodePosition::default(),
+            margin: Self::default_margin(),
+            font_family: Self::default_font_family(),
+            font_size: Self::default_font_size(),
+            font_weight: Self::default_font_weight(),
+            color: Self::default_color(),
         }
     }
 }
 
+/// Time source for the burned-in timecode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub enum TimecodeMode {
+    /// Running time since the start of the exported clip.
+    #[default]
+    Elapsed,
+    /// Local wall-clock time at which each frame was recorded.
+    WallClock,
+}
+
+/// Frame corner for the burned-in timecode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub enum TimecodePosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
 /// Export format.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export, export_to = "../../src/types/generated/")]
 pub enum ExportFormat {
+    /// H.264/AAC (NVENC when preferred and available). The default.
     Mp4,
+    /// VP9/Opus for web embedding. Always encoded in software with
+    /// libvpx-vp9 (no hardware VP9), so exports are CPU-bound.
     Webm,
+    /// Palette-based animated GIF, no audio.
     Gif,
+    /// Numbered RGBA PNG frames (`frame_00001.png`, ...) in a directory,
+    /// no audio. Written directly from the renderer, without FFmpeg.
+    PngSequence,
+    /// ProRes 4444 with alpha in a QuickTime container, PCM audio. For
+    /// compositing in editors and OBS.
+    Mov,
+}
+
+impl ExportFormat {
+    /// Whether the format carries an audio track.
+    pub fn has_audio(&self) -> bool {
+        !matches!(self, ExportFormat::Gif | ExportFormat::PngSequence)
+    }
+
+    /// Whether the format preserves an alpha channel.
+    pub fn supports_alpha(&self) -> bool {
+        matches!(self, ExportFormat::PngSequence | ExportFormat::Mov)
+    }
 }
 
 /// Export resolution.
@@ -750,6 +1358,8 @@ pub enum BackgroundType {
     Wallpaper,
     /// Custom image background.
     Image,
+    /// Blurred, scaled copy of the screen recording itself.
+    BlurredSource,
 }
 
 /// Shadow configuration for video frame background.
@@ -812,7 +1422,7 @@ impl Default for BorderConfig {
 #[serde(rename_all = "camelCase")]
 #[ts(export, export_to = "../../src/types/generated/")]
 pub struct BackgroundConfig {
-    /// Type of background (Solid, Gradient, Wallpaper, Image).
+    /// Type of background (Solid, Gradient, Wallpaper, Image, BlurredSource).
     pub bg_type: BackgroundType,
     /// Solid color (hex format, e.g., "#000000").
     pub solid_color: String,
@@ -828,23 +1438,26 @@ pub struct BackgroundConfig {
     /// Custom image path.
     #[serde(default)]
     pub image_path: Option<String>,
-    /// Background blur amount (0-100%).
+    /// Background blur amount (0-100%), used by the blurred source background.
     #[serde(default)]
     pub blur: f32,
+    /// Blur applied to the custom image background (0-100%).
+    #[serde(default)]
+    pub image_blur_amount: f32,
     /// Padding around video frame (0-200 pixels).
     #[serde(default)]
     pub padding: f32,
     /// Inset value (pixels).
     #[serde(default)]
     pub inset: u32,
-    /// Corner rounding radius (0-100 pixels).
-    #[serde(default)]
+    /// Corner rounding radius of the screen content (0-100 pixels).
+    #[serde(default, alias = "screenRounding")]
     pub rounding: f32,
     /// Corner rounding style (squircle or rounded).
     #[serde(default)]
     pub rounding_type: CornerStyle,
-    /// Shadow configuration.
-    #[serde(default)]
+    /// Drop shadow behind the screen content.
+    #[serde(default, alias = "screenShadow")]
     pub shadow: BackgroundShadowConfig,
     /// Border configuration.
     #[serde(default)]
@@ -862,6 +1475,7 @@ impl Default for BackgroundConfig {
             wallpaper: None,
             image_path: None,
             blur: 0.0,
+            image_blur_amount: 0.0,
             padding: 0.0,
             inset: 0,
             rounding: 0.0,
@@ -924,6 +1538,27 @@ impl Default for CompositionMode {
     }
 }
 
+/// Framing of screen content in a composition taller than the content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub enum PortraitFraming {
+    /// Fit the whole content, leaving background above and below.
+    Fit,
+    /// Crop the content to fill the frame, keeping the center.
+    Center,
+    /// Crop the content to fill the frame, panning with the active zoom.
+    FollowZoom,
+    /// Crop the content to fill the frame, panning with the cursor.
+    FollowCursor,
+}
+
+impl Default for PortraitFraming {
+    fn default() -> Self {
+        Self::Fit
+    }
+}
+
 /// Composition configuration for output canvas.
 /// Defines how the cropped video is placed within the output frame.
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -1131,6 +1766,29 @@ impl<T> XY<T> {
     }
 }
 
+/// Reveal animation for a text segment.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS)]
+#[serde(tag = "type", rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub enum TextAnimation {
+    /// Reveal the text one character at a time from the segment start.
+    Typewriter {
+        #[serde(rename = "charsPerSec")]
+        chars_per_sec: f64,
+    },
+}
+
+/// Vertical placement of text within its bounding box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub enum TextVerticalAlign {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+
 /// A text overlay segment.
 /// Matches Cap's TextSegment model for consistency.
 /// Supports backward compatibility with old format (startMs/endMs/text/x/y).
@@ -1162,6 +1820,26 @@ pub struct TextSegment {
     pub color: String,
     /// Fade duration in seconds (for fade in/out animation).
     pub fade_duration: f64,
+    /// Shrink the font size (never grow) until the text fits its bounding box.
+    pub auto_fit: bool,
+    /// Extra spacing between letters, in ems (0 = normal).
+    pub letter_spacing: f64,
+    /// Line height as a multiple of the font size.
+    pub line_height: f64,
+    /// Outline color (hex format), or None for no outline.
+    pub stroke_color: Option<String>,
+    /// Outline width in pixels (at 1080p reference).
+    pub stroke_width: f64,
+    /// Background box color (hex format), or None for no box.
+    pub background_color: Option<String>,
+    /// Background box opacity (0-1).
+    pub background_opacity: f64,
+    /// Padding around the text inside the box, in pixels (at 1080p reference).
+    pub background_padding: f64,
+    /// Reveal animation, or None to show the full text immediately.
+    pub animation: Option<TextAnimation>,
+    /// Vertical alignment of the text within its bounding box.
+    pub vertical_align: TextVerticalAlign,
 }
 
 /// Helper struct for deserializing both old and new TextSegment formats.
@@ -1201,6 +1879,26 @@ struct TextSegmentRaw {
     italic: Option<bool>,
     #[serde(default)]
     color: Option<String>,
+    #[serde(default)]
+    auto_fit: Option<bool>,
+    #[serde(default)]
+    letter_spacing: Option<f64>,
+    #[serde(default)]
+    line_height: Option<f64>,
+    #[serde(default)]
+    stroke_color: Option<String>,
+    #[serde(default)]
+    stroke_width: Option<f64>,
+    #[serde(default)]
+    background_color: Option<String>,
+    #[serde(default)]
+    background_opacity: Option<f64>,
+    #[serde(default)]
+    background_padding: Option<f64>,
+    #[serde(default)]
+    animation: Option<TextAnimation>,
+    #[serde(default)]
+    vertical_align: Option<TextVerticalAlign>,
 }
 
 impl<'de> Deserialize<'de> for TextSegment {
@@ -1255,6 +1953,16 @@ impl<'de> Deserialize<'de> for TextSegment {
             italic: raw.italic.unwrap_or(false),
             color: raw.color.unwrap_or_else(|| "#ffffff".to_string()),
             fade_duration,
+            auto_fit: raw.auto_fit.unwrap_or(false),
+            letter_spacing: raw.letter_spacing.unwrap_or(0.0),
+            line_height: raw.line_height.unwrap_or(1.2),
+            stroke_color: raw.stroke_color,
+            stroke_width: raw.stroke_width.unwrap_or(0.0),
+            background_color: raw.background_color,
+            background_opacity: raw.background_opacity.unwrap_or(1.0),
+            background_padding: raw.background_padding.unwrap_or(8.0),
+            animation: raw.animation,
+            vertical_align: raw.vertical_align.unwrap_or_default(),
         })
     }
 }
@@ -1274,6 +1982,16 @@ impl Default for TextSegment {
             italic: false,
             color: "#ffffff".to_string(),
             fade_duration: 0.15,
+            auto_fit: false,
+            letter_spacing: 0.0,
+            line_height: 1.2,
+            stroke_color: None,
+            stroke_width: 0.0,
+            background_color: None,
+            background_opacity: 1.0,
+            background_padding: 8.0,
+            animation: None,
+            vertical_align: TextVerticalAlign::Top,
         }
     }
 }
@@ -1295,6 +2013,106 @@ impl Default for TextConfig {
     }
 }
 
+// ============================================================================
+// Keystroke Configuration
+// ============================================================================
+
+/// Keystroke overlay rendered into exported frames.
+///
+/// Shows a keycap for each recent key press recorded in the `keystroke.json`
+/// sidecar; modifier combos share one cap (e.g. "Ctrl+C").
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct KeystrokeConfig {
+    /// Whether keystrokes are drawn in the export.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Frame corner the keycaps are anchored to.
+    #[serde(default)]
+    pub position: KeystrokePosition,
+    /// Distance from the corner in pixels (at 1080p reference).
+    #[serde(default = "KeystrokeConfig::default_margin")]
+    pub margin: f32,
+    /// Font size in pixels (at 1080p reference).
+    #[serde(default = "KeystrokeConfig::default_font_size")]
+    pub font_size: f32,
+    /// How long a key press stays on screen in milliseconds.
+    #[serde(default = "KeystrokeConfig::default_display_ms")]
+    #[ts(type = "number")]
+    pub display_ms: u64,
+    /// Maximum number of keycaps shown at once.
+    #[serde(default = "KeystrokeConfig::default_max_keys")]
+    pub max_keys: u32,
+    /// Text color (hex format, e.g., "#ffffff").
+    #[serde(default = "KeystrokeConfig::default_text_color")]
+    pub text_color: String,
+    /// Keycap background color (hex format, e.g., "#000000").
+    #[serde(default = "KeystrokeConfig::default_background_color")]
+    pub background_color: String,
+    /// Keycap background opacity (0.0-1.0).
+    #[serde(default = "KeystrokeConfig::default_background_opacity")]
+    pub background_opacity: f32,
+}
+
+impl KeystrokeConfig {
+    fn default_margin() -> f32 {
+        32.0
+    }
+
+    fn default_font_size() -> f32 {
+        28.0
+    }
+
+    fn default_display_ms() -> u64 {
+        1500
+    }
+
+    fn default_max_keys() -> u32 {
+        4
+    }
+
+    fn default_text_color() -> String {
+        "#ffffff".to_string()
+    }
+
+    fn default_background_color() -> String {
+        "#000000".to_string()
+    }
+
+    fn default_background_opacity() -> f32 {
+        0.8
+    }
+}
+
+impl Default for KeystrokeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            position: KeystrokePosition::default(),
+            margin: Self::default_margin(),
+            font_size: Self::default_font_size(),
+            display_ms: Self::default_display_ms(),
+            max_keys: Self::default_max_keys(),
+            text_color: Self::default_text_color(),
+            background_color: Self::default_background_color(),
+            background_opacity: Self::default_background_opacity(),
+        }
+    }
+}
+
+/// Frame corner for the keystroke overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub enum KeystrokePosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
 // ============================================================================
 // VideoProject Implementation
 // ============================================================================
@@ -1328,6 +2146,8 @@ impl VideoProject {
                 screen_video: screen_video_path.to_string(),
                 webcam_video: None,
                 cursor_data: None,
+                frame_timestamps: None,
+                keystrokes: None,
                 audio_file: None,
                 system_audio: None,
                 microphone_audio: None,
@@ -1342,6 +2162,9 @@ impl VideoProject {
                 in_point: 0,
                 out_point: duration_ms,
                 speed: 1.0,
+                segments: Vec::new(),
+                speed_keyframes: Vec::new(),
+                holds: Vec::new(),
             },
             zoom: ZoomConfig::default(),
             cursor: CursorConfig::default(),
@@ -1351,6 +2174,8 @@ impl VideoProject {
             scene: SceneConfig::default(),
             text: TextConfig::default(),
             mask: MaskConfig::default(),
+            cursor_smoothing_segments: Vec::new(),
+            keystrokes: KeystrokeConfig::default(),
         }
     }
 
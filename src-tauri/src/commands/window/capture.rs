@@ -68,6 +68,7 @@ pub fn trigger_capture(app: &AppHandle, capture_type: Option<&str>) -> Result<()
                 None,
                 None,
                 None,
+                None,
             )
             .await
             {
@@ -79,6 +80,8 @@ pub fn trigger_capture(app: &AppHandle, capture_type: Option<&str>) -> Result<()
                         height,
                         action,
                         window_id,
+                        regions,
+                        ..
                     } = result;
 
                     match action {
@@ -103,10 +106,24 @@ pub fn trigger_capture(app: &AppHandle, capture_type: Option<&str>) -> Result<()
                             );
                             close_all_capture_windows(&app_clone);
 
-                            // Use window capture if a window was selected, otherwise region capture
+                            // Use window capture if a window was selected, multi-region capture
+                            // for several regions, otherwise region capture
                             let capture_result = if let Some(hwnd) = window_id {
                                 println!("[SCREENSHOT] Using window capture for hwnd={} (0x{:X})", hwnd, hwnd);
                                 crate::commands::capture::capture_window_fast(hwnd).await
+                            } else if !regions.is_empty() {
+                                println!("[SCREENSHOT] Using multi-region capture: {} regions", regions.len());
+                                let selections = regions
+                                    .into_iter()
+                                    .map(|r| crate::commands::capture::ScreenRegionSelection {
+                                        x: r.x,
+                                        y: r.y,
+                                        width: r.width,
+                                        height: r.height,
+                                    })
+                                    .collect();
+                                crate::commands::capture::capture_screen_regions_fast(selections)
+                                    .await
                             } else {
                                 println!(
                                     "[SCREENSHOT] Using region capture: x={}, y={}, w={}, h={}",
@@ -148,6 +165,10 @@ pub fn trigger_capture(app: &AppHandle, capture_type: Option<&str>) -> Result<()
                                 },
                             }
                         },
+                        OverlayAction::PickColor => {
+                            // Only returned in color pick mode, which this flow never requests
+                            restore_main_if_visible(&app_clone);
+                        },
                         OverlayAction::Cancelled => {
                             // User cancelled - close recording border only (toolbar persists)
                             close_recording_border_window(&app_clone);
@@ -177,6 +198,34 @@ pub async fn show_overlay(app: AppHandle, capture_type: Option<String>) -> Resul
     trigger_capture(&app, capture_type.as_deref())
 }
 
+/// Capture the window under the cursor and open it in the editor.
+///
+/// One-keystroke alternative to the overlay: no selection and no clicks.
+/// Fails when there is no capturable window under the cursor (e.g. the desktop).
+#[command]
+pub async fn trigger_capture_window_under_cursor(app: AppHandle) -> Result<(), String> {
+    use windows::Win32::Foundation::{HWND, POINT};
+    use windows::Win32::UI::WindowsAndMessaging::GetCursorPos;
+
+    let mut cursor = POINT::default();
+    unsafe { GetCursorPos(&mut cursor) }
+        .map_err(|e| format!("Failed to get cursor position: {}", e))?;
+
+    let window =
+        crate::commands::capture_overlay::get_window_at_point(cursor.x, cursor.y, HWND::default())
+            .ok_or_else(|| "No window under the cursor".to_string())?;
+    let hwnd = window.hwnd.0 as isize;
+    log::info!(
+        "[trigger_capture] Window under cursor ({}, {}): hwnd=0x{:X}",
+        cursor.x,
+        cursor.y,
+        hwnd
+    );
+
+    let result = crate::commands::capture::capture_window_fast(hwnd).await?;
+    open_editor_fast(app, result.file_path, result.width, result.height).await
+}
+
 #[command]
 pub async fn hide_overlay(app: AppHandle, restore_main_window: Option<bool>) -> Result<(), String> {
     // Restore main window if it was visible before capture started
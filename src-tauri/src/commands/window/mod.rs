@@ -8,10 +8,12 @@
 //!   capture.rs  - Capture flow, overlay commands
 //!   toolbar.rs  - Capture toolbar and startup toolbar
 //!   recording.rs - Recording border and countdown windows
+//!   pin.rs      - Always-on-top pinned capture windows
 //! ```
 
 pub mod capture;
 pub mod image_editor;
+pub mod pin;
 pub mod recording;
 pub mod settings;
 pub mod toolbar;
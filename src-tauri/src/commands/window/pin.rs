@@ -0,0 +1,129 @@
+//! Pinned capture windows.
+//!
+//! A pin shows a capture in a small frameless, always-on-top window so it
+//! can be kept in view as a reference while working. Any number of pins can be
+//! open at once, including several of the same capture.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::{command, AppHandle, WebviewUrl, WebviewWindowBuilder};
+
+use super::{set_physical_position, set_physical_size};
+use crate::commands::storage::types::CaptureProject;
+use crate::commands::storage::{get_app_data_dir, resolve_original_image};
+
+/// Pin window label prefix
+const PIN_LABEL_PREFIX: &str = "pin-";
+
+/// Largest share of the monitor a pin may cover initially.
+const MAX_PIN_SCREEN_FRACTION: f64 = 0.8;
+
+/// Makes labels unique when the same capture is pinned more than once.
+static NEXT_PIN: AtomicU64 = AtomicU64::new(1);
+
+/// Pin a library capture to the screen in an always-on-top window.
+///
+/// The window opens at the image's pixel size (scaled down to fit the
+/// monitor) and is centered. Returns the window label.
+#[command]
+pub async fn pin_capture(app: AppHandle, project_id: String) -> Result<String, String> {
+    let base_dir = get_app_data_dir(&app)?;
+    let project_file = base_dir
+        .join("projects")
+        .join(&project_id)
+        .join("project.json");
+    if !project_file.exists() {
+        return Err("Project not found".to_string());
+    }
+
+    let content = std::fs::read_to_string(&project_file)
+        .map_err(|e| format!("Failed to read project: {}", e))?;
+    let project: CaptureProject =
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse project: {}", e))?;
+
+    let label = format!(
+        "{}{}-{}",
+        PIN_LABEL_PREFIX,
+        project_id,
+        NEXT_PIN.fetch_add(1, Ordering::Relaxed)
+    );
+
+    // Pass image path via URL query parameter for immediate availability
+    let image_path = resolve_original_image(&base_dir, &project.original_image);
+    let encoded_path = urlencoding::encode(&image_path.to_string_lossy());
+    let url = WebviewUrl::App(format!("windows/pin.html?path={}", encoded_path).into());
+
+    let window = WebviewWindowBuilder::new(&app, &label, url)
+        .title("Pinned Capture - SnapIt")
+        .resizable(true)
+        .decorations(false)
+        .always_on_top(true)
+        .skip_taskbar(true)
+        .shadow(true)
+        .visible(false) // Hidden until sized to the image
+        .build()
+        .map_err(|e| format!("Failed to create pin window: {}", e))?;
+
+    let (width, height) = (project.dimensions.width, project.dimensions.height);
+    let (width, height, x, y) = match window.current_monitor().ok().flatten() {
+        Some(monitor) => {
+            let area = monitor.size();
+            let (width, height) = fit_to_screen(width, height, area.width, area.height);
+            let origin = monitor.position();
+            (
+                width,
+                height,
+                origin.x + (area.width.saturating_sub(width) / 2) as i32,
+                origin.y + (area.height.saturating_sub(height) / 2) as i32,
+            )
+        },
+        None => (width, height, 0, 0),
+    };
+
+    set_physical_size(&window, width.max(1), height.max(1))?;
+    set_physical_position(&window, x, y)?;
+    window
+        .show()
+        .map_err(|e| format!("Failed to show pin window: {}", e))?;
+
+    log::info!(
+        "[PIN] Pinned {} as {} ({}x{})",
+        project_id,
+        label,
+        width,
+        height
+    );
+    Ok(label)
+}
+
+/// Size of a `width`x`height` image scaled down, keeping its aspect ratio, to
+/// cover at most `MAX_PIN_SCREEN_FRACTION` of a monitor on either side.
+fn fit_to_screen(width: u32, height: u32, screen_width: u32, screen_height: u32) -> (u32, u32) {
+    if width == 0 || height == 0 {
+        return (width, height);
+    }
+
+    let max_width = screen_width as f64 * MAX_PIN_SCREEN_FRACTION;
+    let max_height = screen_height as f64 * MAX_PIN_SCREEN_FRACTION;
+    let scale = (max_width / width as f64)
+        .min(max_height / height as f64)
+        .min(1.0);
+
+    (
+        ((width as f64 * scale).round() as u32).max(1),
+        ((height as f64 * scale).round() as u32).max(1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_to_screen() {
+        // Small captures open at their own size
+        assert_eq!(fit_to_screen(400, 300, 1920, 1080), (400, 300));
+        // Large captures shrink to the screen, keeping the aspect ratio
+        assert_eq!(fit_to_screen(3840, 2160, 1920, 1080), (1536, 864));
+        assert_eq!(fit_to_screen(500, 4000, 1920, 1080), (108, 864));
+    }
+}
@@ -46,6 +46,10 @@ pub struct RecordingConfig {
 
     /// Whether to hide desktop icons during recording.
     pub hide_desktop_icons: bool,
+
+    /// Microphone gain in dB (-20 to +20), applied to recordings and level meters.
+    #[serde(default)]
+    pub microphone_gain_db: f32,
 }
 
 impl Default for RecordingConfig {
@@ -61,6 +65,7 @@ impl Default for RecordingConfig {
             microphone_device_index: None,
             quick_capture: false,
             hide_desktop_icons: false,
+            microphone_gain_db: 0.0,
         }
     }
 }
@@ -71,6 +76,7 @@ impl RecordingConfig {
         self.countdown_secs = self.countdown_secs.clamp(0, 10);
         self.fps = self.fps.clamp(10, 60);
         self.quality = self.quality.clamp(1, 100);
+        self.microphone_gain_db = clamp_gain_db(self.microphone_gain_db);
     }
 
     /// Reset all settings to defaults.
@@ -79,6 +85,17 @@ impl RecordingConfig {
     }
 }
 
+/// Microphone gain range in dB.
+const MICROPHONE_GAIN_RANGE_DB: (f32, f32) = (-20.0, 20.0);
+
+fn clamp_gain_db(gain_db: f32) -> f32 {
+    if gain_db.is_finite() {
+        gain_db.clamp(MICROPHONE_GAIN_RANGE_DB.0, MICROPHONE_GAIN_RANGE_DB.1)
+    } else {
+        0.0
+    }
+}
+
 lazy_static! {
     /// Global recording configuration.
     ///
@@ -153,6 +170,11 @@ pub fn get_hide_desktop_icons() -> bool {
     RECORDING_CONFIG.read().hide_desktop_icons
 }
 
+/// Get the current microphone gain in dB.
+pub fn get_microphone_gain_db() -> f32 {
+    RECORDING_CONFIG.read().microphone_gain_db
+}
+
 /// Reset all recording settings to defaults.
 pub fn reset_recording_config() {
     let mut config = RECORDING_CONFIG.write();
@@ -252,6 +274,16 @@ pub fn set_recording_microphone_device(index: Option<u32>) {
     RECORDING_CONFIG.write().microphone_device_index = device_index;
 }
 
+/// Set the microphone gain in dB, clamped to -20..+20.
+///
+/// Takes effect immediately, including during a recording.
+#[tauri::command]
+pub fn set_recording_microphone_gain(gain_db: f32) {
+    let gain_db = clamp_gain_db(gain_db);
+    log::debug!("[CONFIG] set_recording_microphone_gain({})", gain_db);
+    RECORDING_CONFIG.write().microphone_gain_db = gain_db;
+}
+
 /// Set whether to hide desktop icons during recording.
 #[tauri::command]
 pub fn set_hide_desktop_icons(enabled: bool) {
@@ -288,4 +320,18 @@ mod tests {
         assert_eq!(config.quality, 1);
         assert_eq!(config.countdown_secs, 10);
     }
+
+    #[test]
+    fn test_microphone_gain_clamped() {
+        let mut config = RecordingConfig {
+            microphone_gain_db: 35.0,
+            ..Default::default()
+        };
+        config.validate();
+        assert_eq!(config.microphone_gain_db, 20.0);
+
+        config.microphone_gain_db = f32::NAN;
+        config.validate();
+        assert_eq!(config.microphone_gain_db, 0.0);
+    }
 }
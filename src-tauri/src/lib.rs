@@ -113,10 +113,12 @@ pub fn run() {
             commands::capture::capture_region_fast,
             commands::capture::capture_screen_region_fast,
             commands::capture::capture_fullscreen_fast,
+            commands::capture::capture_scrolling,
             commands::capture::read_rgba_file,
             commands::capture::cleanup_rgba_file,
             // Window commands - capture flow
             commands::window::capture::show_overlay,
+            commands::window::capture::trigger_capture_window_under_cursor,
             commands::window::capture::hide_overlay,
             commands::window::capture::open_editor_fast,
             commands::window::capture::restore_main_window,
@@ -149,24 +151,41 @@ pub fn run() {
             commands::window::image_editor::show_image_editor_window,
             commands::window::image_editor::close_image_editor_window,
             commands::window::image_editor::get_image_editor_capture_path,
+            // Window commands - pinned captures
+            commands::window::pin::pin_capture,
             // Image commands
             commands::image::copy_image_to_clipboard,
+            commands::image::copy_capture_as_file,
+            commands::upload::upload_capture,
+            commands::ocr::extract_text,
+            commands::qr::generate_qr,
             // Storage commands
             commands::storage::operations::save_capture,
             commands::storage::operations::save_capture_from_file,
             commands::storage::operations::update_project_annotations,
+            commands::storage::operations::undo_annotations,
+            commands::storage::operations::redo_annotations,
+            commands::storage::operations::apply_redactions,
             commands::storage::operations::update_project_metadata,
+            commands::storage::operations::update_project_name,
             commands::storage::operations::get_capture_list,
+            commands::storage::operations::search_captures,
             commands::storage::operations::get_project,
             commands::storage::operations::get_project_image,
             commands::storage::operations::delete_project,
             commands::storage::operations::delete_projects,
             commands::storage::operations::export_project,
+            commands::storage::operations::export_projects,
+            commands::storage::bundle::export_project_bundle,
+            commands::storage::bundle::import_project_bundle,
             commands::storage::operations::get_storage_stats,
             commands::storage::operations::get_library_folder,
             commands::storage::operations::startup_cleanup,
             commands::storage::operations::import_image_from_path,
+            commands::storage::operations::import_image_from_clipboard,
             commands::storage::operations::ensure_ffmpeg,
+            commands::storage::operations::verify_library,
+            commands::storage::operations::repair_library,
             // Settings commands
             commands::settings::set_autostart,
             commands::settings::is_autostart_enabled,
@@ -181,8 +200,10 @@ pub fn run() {
             config::app::set_app_config,
             // Font commands
             commands::fonts::get_system_fonts,
+            commands::fonts::get_system_font_families,
             commands::fonts::get_font_data,
             commands::fonts::get_font_weights,
+            commands::fonts::load_text_font,
             // Keyboard hook commands (Windows shortcut override)
             commands::keyboard_hook::register_shortcut_with_hook,
             commands::keyboard_hook::unregister_shortcut_hook,
@@ -209,6 +230,7 @@ pub fn run() {
             config::recording::set_recording_quick_capture,
             config::recording::set_recording_max_duration,
             config::recording::set_recording_microphone_device,
+            config::recording::set_recording_microphone_gain,
             config::recording::set_hide_desktop_icons,
             config::recording::reset_recording_config_cmd,
             config::recording::set_recording_config,
@@ -237,6 +259,7 @@ pub fn run() {
             commands::video_recording::get_webcam_preview_frame,
             commands::video_recording::get_webcam_preview_dimensions,
             commands::video_recording::exclude_webcam_from_capture,
+            commands::video_recording::is_webcam_excluded_from_capture,
             // Native webcam preview (Windows-only, GDI-based with circle mask)
             #[cfg(target_os = "windows")]
             commands::video_recording::start_native_webcam_preview,
@@ -269,19 +292,25 @@ pub fn run() {
             // Video editor commands
             commands::video_recording::load_video_project,
             commands::video_recording::save_video_project,
+            commands::video_recording::autosave_project,
+            commands::video_recording::find_video_project_autosave,
             commands::video_recording::load_cursor_recording_cmd,
             commands::video_recording::extract_frame,
             commands::video_recording::clear_video_frame_cache,
             commands::video_recording::extract_audio_waveform,
+            commands::video_recording::get_audio_peaks,
             commands::video_recording::generate_auto_zoom,
             commands::video_recording::export_video,
+            commands::video_recording::reexport_projects,
             commands::video_recording::check_nvenc_available,
+            commands::video_recording::get_encoder_capabilities,
             // GPU-accelerated video editor commands
             commands::video_recording::gpu_editor::create_editor_instance,
             commands::video_recording::gpu_editor::destroy_editor_instance,
             commands::video_recording::gpu_editor::editor_play,
             commands::video_recording::gpu_editor::editor_pause,
             commands::video_recording::gpu_editor::editor_seek,
+            commands::video_recording::gpu_editor::editor_step_frame,
             commands::video_recording::gpu_editor::editor_set_speed,
             commands::video_recording::gpu_editor::editor_get_state,
             commands::video_recording::gpu_editor::editor_render_frame,
@@ -292,6 +321,7 @@ pub fn run() {
             commands::preview::render_preview_frame,
             commands::preview::render_text_only_frame,
             commands::preview::render_text_overlay,
+            commands::preview::measure_text_segment,
             commands::preview::shutdown_preview,
             commands::preview::get_preview_ws_port,
             // Native text preview commands (zero-latency surface rendering)
@@ -316,6 +346,7 @@ pub fn run() {
             commands::capture_overlay::commands::capture_overlay_cancel,
             commands::capture_overlay::commands::capture_overlay_reselect,
             commands::capture_overlay::commands::capture_overlay_set_dimensions,
+            commands::capture_overlay::commands::capture_overlay_set_aspect_ratio,
             commands::capture_overlay::commands::capture_overlay_highlight_monitor,
             commands::capture_overlay::commands::capture_overlay_highlight_window,
             // Preview overlay for picker panels
@@ -376,6 +407,15 @@ pub fn run() {
                 let _ = window.hide();
             }
 
+            // Register the bundled emoji font for video text overlays
+            if let Ok(resource_dir) = app.path().resource_dir() {
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = rendering::fonts::load_bundled_emoji_font(resource_dir).await {
+                        log::warn!("Failed to load bundled emoji font: {}", e);
+                    }
+                });
+            }
+
             // Show floating startup toolbar on app launch
             let app_handle = app.handle().clone();
             tauri::async_runtime::spawn(async move {
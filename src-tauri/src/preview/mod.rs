@@ -16,10 +16,10 @@ pub use native_surface::{get_preview_instance, remove_preview_instance, NativeTe
 use crate::commands::video_recording::video_project::{VideoProject, XY};
 use crate::rendering::compositor::Compositor;
 use crate::rendering::renderer::Renderer;
-use crate::rendering::text::prepare_texts;
+use crate::rendering::text::{prepare_text, prepare_texts};
 use crate::rendering::types::{
     BackgroundStyle, BackgroundType, BorderStyle, CornerStyle, DecodedFrame, RenderOptions,
-    ShadowStyle, ZoomState,
+    ShadowStyle, TextMeasurement, ZoomState,
 };
 use log::info;
 use std::path::PathBuf;
@@ -250,6 +250,20 @@ impl PreviewRenderer {
         Ok(())
     }
 
+    /// Measure a text segment's laid-out size at the given output size.
+    ///
+    /// Shapes the text the same way rendering does, but never touches the GPU.
+    /// Measured as fully revealed, so typewriter segments report their final size.
+    pub async fn measure_text_segment(
+        &self,
+        width: u32,
+        height: u32,
+        segment: &crate::commands::video_recording::video_project::TextSegment,
+    ) -> TextMeasurement {
+        let prepared = prepare_text(XY::new(width, height), segment.end, segment);
+        self.compositor.lock().await.measure_text(&prepared)
+    }
+
     /// Build render options from project configuration.
     /// For preview, we render at video dimensions (no padding) - CSS handles frame styling.
     fn build_render_options(&self, project: &VideoProject) -> RenderOptions {
@@ -275,15 +289,17 @@ impl PreviewRenderer {
                 color: [0.0, 0.0, 0.0, 0.0],
                 opacity: 0.0,
             },
+            transparent: false,
         };
 
         RenderOptions {
             output_width,
             output_height,
             zoom: ZoomState::default(),
-            webcam: None,
+            webcams: Vec::new(),
             cursor: None,
             background,
+            fill_focus: None,
         }
     }
 }
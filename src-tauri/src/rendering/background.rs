@@ -1,6 +1,7 @@
 //! Background rendering layer for video compositing.
 //!
-//! Supports solid colors, gradients, and image backgrounds.
+//! Supports solid colors, gradients, image backgrounds, and a blurred copy of
+//! the screen frame.
 //! Adapted from Cap's rendering engine.
 
 use bytemuck::{Pod, Zeroable};
@@ -28,8 +29,10 @@ pub enum Background {
     },
     /// Built-in wallpaper preset (path relative to assets/backgrounds/).
     Wallpaper { path: String },
-    /// Custom image background from file path.
-    Image { path: String },
+    /// Custom image background from file path, blurred by `blur` (0-100%).
+    Image { path: String, blur: f32 },
+    /// Blurred copy of the current screen frame (`blur` 0-100%).
+    BlurredSource { blur: f32 },
 }
 
 impl Default for Background {
@@ -60,11 +63,18 @@ impl Background {
                 angle: *angle,
             },
             RenderBackgroundType::Wallpaper(path) => Self::Wallpaper { path: path.clone() },
-            RenderBackgroundType::Image(path) => Self::Image { path: path.clone() },
+            RenderBackgroundType::Image { path, blur } => Self::Image {
+                path: path.clone(),
+                blur: *blur,
+            },
+            RenderBackgroundType::BlurredSource { blur } => Self::BlurredSource { blur: *blur },
         }
     }
 }
 
+/// Blur radius at 100%, as a fraction of the output's shorter side.
+const MAX_BLUR_RADIUS_FRACTION: f32 = 0.08;
+
 /// Convert sRGB (0-255) to linear color space (0.0-1.0).
 pub fn srgb_to_linear(value: u8) -> f32 {
     let v = value as f32 / 255.0;
@@ -98,8 +108,11 @@ pub fn hex_to_linear_rgba(hex: &str) -> [f32; 4] {
 enum BackgroundInner {
     Image {
         path: String,
+        blur: f32,
         bind_group: wgpu::BindGroup,
     },
+    /// Rebuilt every frame since the source texture changes.
+    BlurredSource { bind_group: wgpu::BindGroup },
     ColorOrGradient {
         value: Background,
         #[allow(unused)]
@@ -128,6 +141,8 @@ impl BackgroundLayer {
     }
 
     /// Prepare the background for rendering.
+    ///
+    /// `source` is the current screen frame, used by `Background::BlurredSource`.
     pub async fn prepare(
         &mut self,
         device: &wgpu::Device,
@@ -135,17 +150,33 @@ impl BackgroundLayer {
         output_width: u32,
         output_height: u32,
         background: Background,
+        source: &wgpu::TextureView,
+        source_size: (u32, u32),
     ) -> Result<(), String> {
         match background {
             Background::None => {
                 self.inner = None;
             },
-            Background::Image { ref path } => {
+            Background::BlurredSource { blur } => {
+                self.inner = Some(BackgroundInner::BlurredSource {
+                    bind_group: self.image_bind_group(
+                        device,
+                        "Blurred Source Uniforms",
+                        source,
+                        (output_width, output_height),
+                        source_size,
+                        blur,
+                    ),
+                });
+            },
+            Background::Image { ref path, blur } => {
                 // Check if we already have this image loaded
                 match &self.inner {
                     Some(BackgroundInner::Image {
-                        path: current_path, ..
-                    }) if current_path == path => {
+                        path: current_path,
+                        blur: current_blur,
+                        ..
+                    }) if current_path == path && current_blur == &blur => {
                         // Already prepared
                         return Ok(());
                     },
@@ -220,46 +251,19 @@ impl BackgroundLayer {
                     },
                 };
 
-                // Calculate aspect ratio correction for cover scaling
-                let output_ar = output_height as f32 / output_width as f32;
-                let image_ar = texture.height() as f32 / texture.width() as f32;
-
-                let y_height = if output_ar < image_ar {
-                    ((image_ar - output_ar) / 2.0) / image_ar
-                } else {
-                    0.0
-                };
-
-                let x_width = if output_ar > image_ar {
-                    let output_ar_inv = 1.0 / output_ar;
-                    let image_ar_inv = 1.0 / image_ar;
-                    ((image_ar_inv - output_ar_inv) / 2.0) / image_ar_inv
-                } else {
-                    0.0
-                };
-
-                let image_uniforms = ImageBackgroundUniforms {
-                    output_size: [output_width as f32, output_height as f32],
-                    padding: 0.0,
-                    x_width,
-                    y_height,
-                    _padding: 0.0,
-                };
-
-                let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                    label: Some("Image Background Uniforms"),
-                    contents: bytemuck::cast_slice(&[image_uniforms]),
-                    usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
-                });
-
                 let texture_view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+                let image_size = (texture.width(), texture.height());
 
                 self.inner = Some(BackgroundInner::Image {
                     path: path.clone(),
-                    bind_group: self.image_pipeline.bind_group(
+                    blur,
+                    bind_group: self.image_bind_group(
                         device,
-                        &uniform_buffer,
+                        "Image Background Uniforms",
                         &texture_view,
+                        (output_width, output_height),
+                        image_size,
+                        blur,
                     ),
                 });
             },
@@ -313,8 +317,10 @@ impl BackgroundLayer {
                 // Check if we already have this wallpaper loaded
                 match &self.inner {
                     Some(BackgroundInner::Image {
-                        path: current_path, ..
-                    }) if current_path == path => {
+                        path: current_path,
+                        blur: current_blur,
+                        ..
+                    }) if current_path == path && *current_blur == 0.0 => {
                         return Ok(());
                     },
                     _ => {},
@@ -387,46 +393,19 @@ impl BackgroundLayer {
                     },
                 };
 
-                // Calculate aspect ratio correction for cover scaling
-                let output_ar = output_height as f32 / output_width as f32;
-                let image_ar = texture.height() as f32 / texture.width() as f32;
-
-                let y_height = if output_ar < image_ar {
-                    ((image_ar - output_ar) / 2.0) / image_ar
-                } else {
-                    0.0
-                };
-
-                let x_width = if output_ar > image_ar {
-                    let output_ar_inv = 1.0 / output_ar;
-                    let image_ar_inv = 1.0 / image_ar;
-                    ((image_ar_inv - output_ar_inv) / 2.0) / image_ar_inv
-                } else {
-                    0.0
-                };
-
-                let image_uniforms = ImageBackgroundUniforms {
-                    output_size: [output_width as f32, output_height as f32],
-                    padding: 0.0,
-                    x_width,
-                    y_height,
-                    _padding: 0.0,
-                };
-
-                let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                    label: Some("Wallpaper Uniforms"),
-                    contents: bytemuck::cast_slice(&[image_uniforms]),
-                    usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
-                });
-
                 let texture_view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+                let image_size = (texture.width(), texture.height());
 
                 self.inner = Some(BackgroundInner::Image {
                     path: path.clone(),
-                    bind_group: self.image_pipeline.bind_group(
+                    blur: 0.0,
+                    bind_group: self.image_bind_group(
                         device,
-                        &uniform_buffer,
+                        "Wallpaper Uniforms",
                         &texture_view,
+                        (output_width, output_height),
+                        image_size,
+                        0.0,
                     ),
                 });
             },
@@ -435,10 +414,57 @@ impl BackgroundLayer {
         Ok(())
     }
 
+    /// Bind a texture to the image pipeline, scaled to cover the output.
+    fn image_bind_group(
+        &self,
+        device: &wgpu::Device,
+        label: &str,
+        texture_view: &wgpu::TextureView,
+        (output_width, output_height): (u32, u32),
+        (image_width, image_height): (u32, u32),
+        blur: f32,
+    ) -> wgpu::BindGroup {
+        // Calculate aspect ratio correction for cover scaling
+        let output_ar = output_height as f32 / output_width as f32;
+        let image_ar = image_height as f32 / image_width as f32;
+
+        let y_height = if output_ar < image_ar {
+            ((image_ar - output_ar) / 2.0) / image_ar
+        } else {
+            0.0
+        };
+
+        let x_width = if output_ar > image_ar {
+            let output_ar_inv = 1.0 / output_ar;
+            let image_ar_inv = 1.0 / image_ar;
+            ((image_ar_inv - output_ar_inv) / 2.0) / image_ar_inv
+        } else {
+            0.0
+        };
+
+        let image_uniforms = ImageBackgroundUniforms {
+            output_size: [output_width as f32, output_height as f32],
+            padding: 0.0,
+            x_width,
+            y_height,
+            blur_radius: blur_radius_px(blur, output_width, output_height),
+        };
+
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some(label),
+            contents: bytemuck::cast_slice(&[image_uniforms]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        self.image_pipeline
+            .bind_group(device, &uniform_buffer, texture_view)
+    }
+
     /// Render the background to the given render pass.
     pub fn render(&self, pass: &mut wgpu::RenderPass<'_>) {
         match &self.inner {
-            Some(BackgroundInner::Image { bind_group, .. }) => {
+            Some(BackgroundInner::Image { bind_group, .. })
+            | Some(BackgroundInner::BlurredSource { bind_group }) => {
                 pass.set_pipeline(&self.image_pipeline.render_pipeline);
                 pass.set_bind_group(0, bind_group, &[]);
                 pass.draw(0..4, 0..1);
@@ -476,7 +502,14 @@ struct ImageBackgroundUniforms {
     padding: f32,
     x_width: f32,
     y_height: f32,
-    _padding: f32,
+    /// Blur radius in output pixels (0 = sharp).
+    blur_radius: f32,
+}
+
+/// Convert a blur amount (0-100%) to a radius in output pixels.
+fn blur_radius_px(blur: f32, output_width: u32, output_height: u32) -> f32 {
+    let shorter_side = output_width.min(output_height) as f32;
+    (blur.clamp(0.0, 100.0) / 100.0) * MAX_BLUR_RADIUS_FRACTION * shorter_side
 }
 
 impl ImageBackgroundPipeline {
@@ -15,9 +15,13 @@ use super::renderer::Renderer;
 use super::text::PreparedText;
 use super::text_layer::TextLayer;
 use super::types::{
-    BackgroundStyle, BackgroundType, CornerStyle, DecodedFrame, RenderOptions, WebcamShape,
+    BackgroundStyle, BackgroundType, CornerStyle, DecodedFrame, RenderOptions, TextMeasurement,
+    WebcamOverlay, WebcamShape,
 };
 
+/// `time_flags.y` value for passes that draw only an additional webcam overlay.
+const WEBCAM_ONLY_FLAG: f32 = 1.0;
+
 /// WGSL shader for video compositing with zoom, padding, rounding, shadow, border, and webcam overlay.
 /// Supports circle, squircle (superellipse), and rounded rectangle shapes with drop shadow.
 const COMPOSITOR_SHADER: &str = r#"
@@ -25,10 +29,10 @@ struct Uniforms {
     video_size: vec4<f32>,      // width, height, 0, 0
     output_size: vec4<f32>,     // width, height, 0, 0
     zoom: vec4<f32>,            // scale, center_x, center_y, 0
-    time_flags: vec4<f32>,      // time_ms, flags, 0, 0
+    time_flags: vec4<f32>,      // time_ms, flags (1 = webcam only), 0, 0
     webcam_rect: vec4<f32>,     // x, y, width, height (normalized 0-1)
     webcam_params: vec4<f32>,   // shape(0=none,1=circle,2=squircle,3=rounded), shadow, mirror, radius
-    webcam_shadow: vec4<f32>,   // shadow_size, shadow_opacity, shadow_blur, 0
+    webcam_shadow: vec4<f32>,   // shadow_size, shadow_opacity, shadow_blur, opacity
     webcam_tex_size: vec4<f32>, // texture width, height, aspect_ratio, 0
     // Video frame styling
     frame_bounds: vec4<f32>,    // x, y, width, height in pixels (padded frame area)
@@ -36,6 +40,12 @@ struct Uniforms {
     frame_shadow: vec4<f32>,    // enabled, size, opacity, blur
     frame_border: vec4<f32>,    // enabled, width, opacity, 0
     border_color: vec4<f32>,    // r, g, b, a (linear space)
+    source_window: vec4<f32>,   // x, y, width, height of the sampled source (normalized)
+    // Webcam border styling
+    webcam_border: vec4<f32>,           // width_px, gradient_angle, glow_radius_px, glow_opacity
+    webcam_border_color: vec4<f32>,     // r, g, b, a (linear space)
+    webcam_border_color_end: vec4<f32>, // r, g, b, a (linear space)
+    webcam_glow_color: vec4<f32>,       // r, g, b, a (linear space)
 }
 
 @group(0) @binding(0) var<uniform> uniforms: Uniforms;
@@ -149,12 +159,15 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     let rel_pos = pixel_pos - frame_center;
     let frame_dist = sdf_rounded_rect_styled(rel_pos, frame_half_size, rounding_px, rounding_type);
 
+    // Extra webcam passes draw over the finished frame
+    let draw_frame = uniforms.time_flags.y < 0.5;
+
     // Start with transparent (background shows through)
     var color = vec4<f32>(0.0, 0.0, 0.0, 0.0);
 
     // Render shadow behind video frame (matching webcam shadow formula)
     // Single slider controls both blur size and opacity
-    if (shadow_enabled && frame_dist > 0.0) {
+    if (draw_frame && shadow_enabled && frame_dist > 0.0) {
         let min_frame_size = min(frame_half_size.x, frame_half_size.y);
         let strength = shadow_value / 100.0;
 
@@ -175,7 +188,7 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     }
 
     // Render border around video frame
-    if (border_enabled && border_width > 0.0) {
+    if (draw_frame && border_enabled && border_width > 0.0) {
         let border_outer_dist = sdf_rounded_rect_styled(
             rel_pos,
             frame_half_size + vec2<f32>(border_width),
@@ -196,7 +209,7 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     }
 
     // Render video frame content
-    if (frame_dist <= 0.0) {
+    if (draw_frame && frame_dist <= 0.0) {
         let zoom_scale = uniforms.zoom.x;
         let zoom_center = vec2<f32>(uniforms.zoom.y, uniforms.zoom.z);
 
@@ -210,6 +223,9 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
         }
         video_uv = clamp(video_uv, vec2<f32>(0.0), vec2<f32>(1.0));
 
+        // Map into the visible part of the source (crop-to-fill framing)
+        video_uv = uniforms.source_window.xy + video_uv * uniforms.source_window.zw;
+
         // Sample video
         var video_color = textureSample(video_texture, video_sampler, video_uv);
 
@@ -260,6 +276,19 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
             }
         }
 
+        let border_width = uniforms.webcam_border.x / min_webcam_size;
+        let glow_radius = uniforms.webcam_border.z / min_webcam_size;
+
+        // Webcam glow (outside the edge, quadratic falloff)
+        if (glow_radius > 0.0 && webcam_dist > 0.0) {
+            let glow_falloff = 1.0 - clamp(webcam_dist / glow_radius, 0.0, 1.0);
+            let glow_alpha = glow_falloff * glow_falloff * uniforms.webcam_border.w * uniforms.webcam_glow_color.a;
+
+            if (glow_alpha > 0.001) {
+                color = mix(color, vec4<f32>(uniforms.webcam_glow_color.rgb, 1.0), glow_alpha);
+            }
+        }
+
         // Webcam content
         let webcam_aa_width = fwidth(webcam_dist) * 2.0;
         if (webcam_dist <= webcam_aa_width) {
@@ -282,7 +311,20 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
 
             let webcam_color = textureSample(webcam_texture, webcam_sampler, webcam_uv);
             let webcam_alpha = 1.0 - smoothstep(-webcam_aa_width, webcam_aa_width, webcam_dist);
-            color = mix(color, webcam_color, webcam_alpha * webcam_color.a);
+            color = mix(color, webcam_color, webcam_alpha * webcam_color.a * uniforms.webcam_shadow.w);
+
+            // Webcam border (inside the edge, over the content)
+            if (border_width > 0.0) {
+                let border_alpha = webcam_alpha * smoothstep(-border_width - webcam_aa_width, -border_width + webcam_aa_width, webcam_dist);
+
+                // CSS-like linear gradient across the webcam bounds
+                let angle_rad = radians(uniforms.webcam_border.y + 270.0);
+                let gradient_dir = vec2<f32>(cos(angle_rad), sin(angle_rad));
+                let gradient_t = clamp(dot(webcam_pixel_pos / webcam_pixel_half_size, gradient_dir) * 0.5 + 0.5, 0.0, 1.0);
+                let border_color = mix(uniforms.webcam_border_color, uniforms.webcam_border_color_end, gradient_t);
+
+                color = mix(color, vec4<f32>(border_color.rgb, 1.0), border_alpha * border_color.a);
+            }
         }
     }
 
@@ -300,7 +342,7 @@ pub struct ExtendedUniforms {
     pub time_flags: [f32; 4],
     pub webcam_rect: [f32; 4],
     pub webcam_params: [f32; 4], // shape, shadow_strength, mirror, corner_radius
-    pub webcam_shadow: [f32; 4], // shadow_size, shadow_opacity, shadow_blur, 0
+    pub webcam_shadow: [f32; 4], // shadow_size, shadow_opacity, shadow_blur, opacity
     pub webcam_tex_size: [f32; 4],
     // Video frame styling
     pub frame_bounds: [f32; 4],   // x, y, width, height in pixels
@@ -308,6 +350,114 @@ pub struct ExtendedUniforms {
     pub frame_shadow: [f32; 4],   // enabled, size, opacity, blur
     pub frame_border: [f32; 4],   // enabled, width, opacity, 0
     pub border_color: [f32; 4],   // r, g, b, a
+    pub source_window: [f32; 4],  // x, y, width, height (normalized)
+    // Webcam border styling
+    pub webcam_border: [f32; 4], // width_px, gradient_angle, glow_radius_px, glow_opacity
+    pub webcam_border_color: [f32; 4],
+    pub webcam_border_color_end: [f32; 4],
+    pub webcam_glow_color: [f32; 4],
+}
+
+impl ExtendedUniforms {
+    /// Fill the webcam fields for an overlay, or disable the webcam (shape 0) for `None`.
+    fn set_webcam(
+        &mut self,
+        webcam: Option<&WebcamOverlay>,
+        output_width: u32,
+        output_height: u32,
+    ) {
+        let Some(webcam) = webcam else {
+            self.webcam_rect = [0.0, 0.0, 0.0, 0.0];
+            self.webcam_params = [0.0, 0.0, 0.0, 0.0]; // shape=0 means no webcam
+            self.webcam_shadow = [0.0, 0.0, 0.0, 0.0]; // no shadow
+            self.webcam_tex_size = [1.0, 1.0, 1.0, 0.0]; // Default 1:1 aspect
+            self.webcam_border = [0.0, 0.0, 0.0, 0.0]; // no border or glow
+            self.webcam_border_color = [0.0, 0.0, 0.0, 0.0];
+            self.webcam_border_color_end = [0.0, 0.0, 0.0, 0.0];
+            self.webcam_glow_color = [0.0, 0.0, 0.0, 0.0];
+            return;
+        };
+
+        // Shape: 1=Circle, 2=Squircle, 3=RoundedRect
+        let shape = match webcam.shape {
+            WebcamShape::Circle => 1.0,
+            WebcamShape::Squircle => 2.0,
+            WebcamShape::Rectangle => 3.0, // Rectangle uses RoundedRect with radius=0
+            WebcamShape::RoundedRect { .. } => 3.0,
+        };
+        let radius = match webcam.shape {
+            WebcamShape::RoundedRect { radius } => radius as f32,
+            _ => 0.0,
+        };
+
+        // Calculate webcam texture aspect ratio for proper cropping
+        let webcam_aspect = webcam.frame.width as f32 / webcam.frame.height as f32;
+
+        // Calculate webcam overlay dimensions
+        let output_aspect = output_width as f32 / output_height as f32;
+        let (webcam_width_norm, webcam_height_norm) = if webcam.use_source_aspect {
+            // Source shape: preserve native webcam aspect ratio
+            // Like Cap: base size is the smaller dimension
+            if webcam_aspect >= 1.0 {
+                // Landscape webcam: width = size * aspect, height = size (in pixels)
+                (webcam.size * webcam_aspect, webcam.size * output_aspect)
+            } else {
+                // Portrait webcam: width = size, height = size / aspect (in pixels)
+                (webcam.size, webcam.size * output_aspect / webcam_aspect)
+            }
+        } else {
+            // Square/Circle/Rectangle: force 1:1 in PIXELS (not normalized coords)
+            (webcam.size, webcam.size * output_aspect)
+        };
+
+        self.webcam_rect = [webcam.x, webcam.y, webcam_width_norm, webcam_height_norm];
+        self.webcam_params = [
+            shape,
+            webcam.shadow, // shadow_strength
+            if webcam.mirror { 1.0 } else { 0.0 },
+            radius,
+        ];
+        self.webcam_shadow = [
+            webcam.shadow_size,
+            webcam.shadow_opacity,
+            webcam.shadow_blur,
+            webcam.opacity,
+        ];
+        self.webcam_tex_size = [
+            webcam.frame.width as f32,
+            webcam.frame.height as f32,
+            webcam_aspect,
+            0.0,
+        ];
+        self.webcam_border = [
+            webcam.border_width,
+            webcam.border_gradient_angle,
+            webcam.glow_radius,
+            webcam.glow_opacity,
+        ];
+        self.webcam_border_color = webcam.border_color;
+        self.webcam_border_color_end = webcam.border_color_end;
+        self.webcam_glow_color = webcam.glow_color;
+    }
+}
+
+/// Part of the source sampled into the frame, as normalized `[x, y, width, height]`.
+///
+/// With a `fill_focus`, content wider than the frame is cropped horizontally
+/// to fill it, centered on the focus point and clamped to the source edges.
+pub fn source_window(
+    video_aspect: f32,
+    frame_aspect: f32,
+    fill_focus: Option<[f32; 2]>,
+) -> [f32; 4] {
+    match fill_focus {
+        Some([focus_x, _]) if frame_aspect < video_aspect => {
+            let width = frame_aspect / video_aspect;
+            let x = (focus_x - width / 2.0).clamp(0.0, 1.0 - width);
+            [x, 0.0, width, 1.0]
+        },
+        _ => [0.0, 0.0, 1.0, 1.0],
+    }
 }
 
 /// Compositor for GPU-accelerated frame rendering.
@@ -518,7 +668,11 @@ impl Compositor {
                 angle: *angle,
             },
             BackgroundType::Wallpaper(path) => Background::Wallpaper { path: path.clone() },
-            BackgroundType::Image(path) => Background::Image { path: path.clone() },
+            BackgroundType::Image { path, blur } => Background::Image {
+                path: path.clone(),
+                blur: *blur,
+            },
+            BackgroundType::BlurredSource { blur } => Background::BlurredSource { blur: *blur },
         }
     }
 
@@ -550,10 +704,24 @@ impl Compositor {
                 Background::Wallpaper { path } => {
                     log::info!("[COMPOSITOR] Background: Wallpaper {}", path)
                 },
-                Background::Image { path } => log::info!("[COMPOSITOR] Background: Image {}", path),
+                Background::Image { path, blur } => {
+                    log::info!("[COMPOSITOR] Background: Image {} blur={}", path, blur)
+                },
+                Background::BlurredSource { blur } => {
+                    log::info!("[COMPOSITOR] Background: BlurredSource blur={}", blur)
+                },
             }
         }
 
+        // Create video texture (also the source of the blurred background)
+        let video_texture = renderer.create_texture_from_rgba(
+            &frame.data,
+            frame.width,
+            frame.height,
+            "Video Frame",
+        );
+        let video_view = video_texture.create_view(&wgpu::TextureViewDescriptor::default());
+
         if !matches!(background, Background::None) {
             if let Err(e) = self
                 .background_layer
@@ -563,94 +731,25 @@ impl Compositor {
                     options.output_width,
                     options.output_height,
                     background,
+                    &video_view,
+                    (frame.width, frame.height),
                 )
                 .await
             {
                 log::warn!("Failed to prepare background: {}", e);
             }
         }
-        // Create video texture
-        let video_texture = renderer.create_texture_from_rgba(
-            &frame.data,
-            frame.width,
-            frame.height,
-            "Video Frame",
-        );
-        let video_view = video_texture.create_view(&wgpu::TextureViewDescriptor::default());
 
-        // Create webcam texture if present
-        let webcam_texture_storage: Option<wgpu::Texture>;
-        let (webcam_rect, webcam_params, webcam_shadow, webcam_tex_size) =
-            if let Some(ref webcam) = options.webcam {
-                webcam_texture_storage = Some(renderer.create_texture_from_rgba(
-                    &webcam.frame.data,
-                    webcam.frame.width,
-                    webcam.frame.height,
-                    "Webcam Frame",
-                ));
-
-                // Shape: 1=Circle, 2=Squircle, 3=RoundedRect
-                let shape = match webcam.shape {
-                    WebcamShape::Circle => 1.0,
-                    WebcamShape::Squircle => 2.0,
-                    WebcamShape::Rectangle => 3.0, // Rectangle uses RoundedRect with radius=0
-                    WebcamShape::RoundedRect { .. } => 3.0,
-                };
-                let radius = match webcam.shape {
-                    WebcamShape::RoundedRect { radius } => radius as f32,
-                    _ => 0.0,
-                };
-
-                // Calculate webcam texture aspect ratio for proper cropping
-                let webcam_aspect = webcam.frame.width as f32 / webcam.frame.height as f32;
-
-                // Calculate webcam overlay dimensions
-                let output_aspect = options.output_width as f32 / options.output_height as f32;
-                let (webcam_width_norm, webcam_height_norm) = if webcam.use_source_aspect {
-                    // Source shape: preserve native webcam aspect ratio
-                    // Like Cap: base size is the smaller dimension
-                    if webcam_aspect >= 1.0 {
-                        // Landscape webcam: width = size * aspect, height = size (in pixels)
-                        (webcam.size * webcam_aspect, webcam.size * output_aspect)
-                    } else {
-                        // Portrait webcam: width = size, height = size / aspect (in pixels)
-                        (webcam.size, webcam.size * output_aspect / webcam_aspect)
-                    }
-                } else {
-                    // Square/Circle/Rectangle: force 1:1 in PIXELS (not normalized coords)
-                    (webcam.size, webcam.size * output_aspect)
-                };
-
-                (
-                    [webcam.x, webcam.y, webcam_width_norm, webcam_height_norm],
-                    [
-                        shape,
-                        webcam.shadow, // shadow_strength
-                        if webcam.mirror { 1.0 } else { 0.0 },
-                        radius,
-                    ],
-                    [
-                        webcam.shadow_size,
-                        webcam.shadow_opacity,
-                        webcam.shadow_blur,
-                        0.0,
-                    ],
-                    [
-                        webcam.frame.width as f32,
-                        webcam.frame.height as f32,
-                        webcam_aspect,
-                        0.0,
-                    ],
-                )
-            } else {
-                webcam_texture_storage = None;
-                (
-                    [0.0, 0.0, 0.0, 0.0],
-                    [0.0, 0.0, 0.0, 0.0], // shape=0 means no webcam
-                    [0.0, 0.0, 0.0, 0.0], // no shadow
-                    [1.0, 1.0, 1.0, 0.0], // Default 1:1 aspect
-                )
-            };
+        // Upload the first (bottom) overlay's frame; placements sharing it reuse the texture
+        let primary_webcam = options.webcams.first();
+        let webcam_texture_storage = primary_webcam.map(|webcam| {
+            renderer.create_texture_from_rgba(
+                &webcam.frame.data,
+                webcam.frame.width,
+                webcam.frame.height,
+                "Webcam Frame",
+            )
+        });
 
         let webcam_view = webcam_texture_storage
             .as_ref()
@@ -678,8 +777,14 @@ impl Compositor {
         let available_h = (out_h - padding * 2.0).max(1.0);
         let available_aspect = available_w / available_h;
 
+        // Crop-to-fill: a frame taller than the video shows only part of it
+        let source_window = source_window(video_aspect, available_aspect, options.fill_focus);
+        let cropped_to_fill = source_window[2] < 1.0;
+
         // Fit video into available space while maintaining aspect ratio
-        let (frame_w, frame_h) = if video_aspect > available_aspect {
+        let (frame_w, frame_h) = if cropped_to_fill {
+            (available_w, available_h)
+        } else if video_aspect > available_aspect {
             // Video is wider than available space - fit to width
             (available_w, available_w / video_aspect)
         } else {
@@ -724,26 +829,26 @@ impl Compositor {
         let border_color = options.background.border.color;
 
         // Update uniforms
-        let uniforms = ExtendedUniforms {
+        let mut uniforms = ExtendedUniforms {
             video_size: [frame.width as f32, frame.height as f32, 0.0, 0.0],
             output_size: [out_w, out_h, 0.0, 0.0],
             zoom: [
                 options.zoom.scale,
-                options.zoom.center_x,
+                // Zoom center is in source space; keep it fixed within the window
+                ((options.zoom.center_x - source_window[0]) / source_window[2]).clamp(0.0, 1.0),
                 options.zoom.center_y,
                 0.0,
             ],
             time_flags: [time_ms, 0.0, 0.0, 0.0],
-            webcam_rect,
-            webcam_params,
-            webcam_shadow,
-            webcam_tex_size,
             frame_bounds,
             frame_rounding,
             frame_shadow,
             frame_border,
             border_color,
+            source_window,
+            ..bytemuck::Zeroable::zeroed()
         };
+        uniforms.set_webcam(primary_webcam, options.output_width, options.output_height);
         self.queue
             .write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[uniforms]));
 
@@ -809,9 +914,12 @@ impl Compositor {
                     view: &output_view,
                     resolve_target: None,
                     ops: wgpu::Operations {
-                        // If we have a background, load it; otherwise clear to black
+                        // If we have a background, load it; otherwise clear to
+                        // black (or transparent for alpha exports)
                         load: if self.background_layer.has_background() {
                             wgpu::LoadOp::Load
+                        } else if options.background.transparent {
+                            wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT)
                         } else {
                             wgpu::LoadOp::Clear(wgpu::Color::BLACK)
                         },
@@ -830,9 +938,110 @@ impl Compositor {
 
         self.queue.submit(Some(encoder.finish()));
 
+        // Further webcam placements, each in its own pass on top. Submitted one
+        // at a time since every pass rewrites the shared uniform buffer.
+        for webcam in options.webcams.iter().skip(1) {
+            let shares_frame =
+                primary_webcam.is_some_and(|primary| Arc::ptr_eq(&primary.frame, &webcam.frame));
+            // Only a distinct frame needs its own upload
+            let own_texture = (!shares_frame).then(|| {
+                renderer.create_texture_from_rgba(
+                    &webcam.frame.data,
+                    webcam.frame.width,
+                    webcam.frame.height,
+                    "Webcam Frame",
+                )
+            });
+            let own_view = own_texture
+                .as_ref()
+                .map(|t| t.create_view(&wgpu::TextureViewDescriptor::default()));
+            self.composite_webcam(
+                &uniforms,
+                webcam,
+                own_view.as_ref().unwrap_or(&webcam_view),
+                &video_view,
+                &output_view,
+            );
+        }
+
         output_texture
     }
 
+    /// Draw one extra webcam overlay over an already composited frame.
+    fn composite_webcam(
+        &self,
+        frame_uniforms: &ExtendedUniforms,
+        webcam: &WebcamOverlay,
+        webcam_view: &wgpu::TextureView,
+        video_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+    ) {
+        let mut uniforms = *frame_uniforms;
+        uniforms.time_flags[1] = WEBCAM_ONLY_FLAG;
+        uniforms.set_webcam(
+            Some(webcam),
+            uniforms.output_size[0] as u32,
+            uniforms.output_size[1] as u32,
+        );
+        self.queue
+            .write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[uniforms]));
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Compositor Webcam Bind Group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: self.uniform_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(video_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Sampler(&self.sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::TextureView(webcam_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: wgpu::BindingResource::Sampler(&self.sampler),
+                },
+            ],
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Compositor Webcam Encoder"),
+            });
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Compositor Webcam Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: output_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_bind_group(0, &bind_group, &[]);
+            render_pass.draw(0..3, 0..1);
+        }
+
+        self.queue.submit(Some(encoder.finish()));
+    }
+
     /// Composite a frame with text overlays.
     ///
     /// This is the main entry point for rendering with text support.
@@ -893,6 +1102,11 @@ impl Compositor {
         output_texture
     }
 
+    /// Measure a prepared text with the compositor's font system (no GPU work).
+    pub fn measure_text(&mut self, text: &PreparedText) -> TextMeasurement {
+        self.text_layer.measure(text)
+    }
+
     /// Render only text overlays on a transparent background.
     ///
     /// This is used during playback when HTML video handles the video frame
@@ -995,3 +1209,33 @@ impl Compositor {
         output_texture
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_window_fit_without_focus() {
+        assert_eq!(
+            source_window(16.0 / 9.0, 9.0 / 16.0, None),
+            [0.0, 0.0, 1.0, 1.0]
+        );
+        // Frame wider than the video never crops
+        assert_eq!(
+            source_window(1.0, 16.0 / 9.0, Some([0.5, 0.5])),
+            [0.0, 0.0, 1.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn test_source_window_crops_to_fill_around_focus() {
+        let [x, _, w, h] = source_window(2.0, 0.5, Some([0.5, 0.5]));
+        assert!((w - 0.25).abs() < 1e-6);
+        assert!((x - 0.375).abs() < 1e-6);
+        assert_eq!(h, 1.0);
+
+        // Clamped to the source edges
+        assert_eq!(source_window(2.0, 0.5, Some([0.0, 0.5]))[0], 0.0);
+        assert!((source_window(2.0, 0.5, Some([1.0, 0.5]))[0] - 0.75).abs() < 1e-6);
+    }
+}
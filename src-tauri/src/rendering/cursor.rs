@@ -13,6 +13,7 @@ use super::zoom::InterpolatedZoom;
 use crate::commands::video_recording::cursor::events::{
     CursorEvent, CursorEventType, CursorImage, CursorRecording, WindowsCursorShape,
 };
+use crate::commands::video_recording::video_project::{CursorConfig, CursorSmoothingSegment};
 use std::collections::HashMap;
 
 // ============================================================================
@@ -35,20 +36,72 @@ const DEFAULT_SPRING: SpringConfig = SpringConfig {
 };
 
 /// Snappy profile - used within 160ms of a click (quick response).
-fn snappy_spring() -> SpringConfig {
+fn snappy_spring(base: SpringConfig) -> SpringConfig {
     SpringConfig {
-        tension: DEFAULT_SPRING.tension * 1.65,
-        mass: (DEFAULT_SPRING.mass * 0.65).max(0.1),
-        friction: DEFAULT_SPRING.friction * 1.25,
+        tension: base.tension * 1.65,
+        mass: (base.mass * 0.65).max(0.1),
+        friction: base.friction * 1.25,
     }
 }
 
 /// Drag profile - used when mouse button is held down (less bouncy).
-fn drag_spring() -> SpringConfig {
+fn drag_spring(base: SpringConfig) -> SpringConfig {
     SpringConfig {
-        tension: DEFAULT_SPRING.tension * 1.25,
-        mass: (DEFAULT_SPRING.mass * 0.85).max(0.1),
-        friction: DEFAULT_SPRING.friction * 1.1,
+        tension: base.tension * 1.25,
+        mass: (base.mass * 0.85).max(0.1),
+        friction: base.friction * 1.1,
+    }
+}
+
+/// Base spring constants over the timeline: the cursor config's springs,
+/// overridden by smoothing segments where they apply.
+#[derive(Debug, Clone)]
+pub struct CursorSprings {
+    default: SpringConfig,
+    /// (start_ms, end_ms, spring) overrides.
+    segments: Vec<(u64, u64, SpringConfig)>,
+}
+
+impl Default for CursorSprings {
+    fn default() -> Self {
+        Self {
+            default: DEFAULT_SPRING,
+            segments: Vec::new(),
+        }
+    }
+}
+
+impl CursorSprings {
+    /// Springs from the project's cursor config and smoothing segments.
+    pub fn from_project(config: &CursorConfig, segments: &[CursorSmoothingSegment]) -> Self {
+        Self {
+            default: SpringConfig {
+                tension: config.tension,
+                mass: config.mass,
+                friction: config.friction,
+            },
+            segments: segments
+                .iter()
+                .map(|s| {
+                    let spring = SpringConfig {
+                        tension: s.tension,
+                        mass: s.mass,
+                        friction: s.friction,
+                    };
+                    (s.start_ms, s.end_ms, spring)
+                })
+                .collect(),
+        }
+    }
+
+    /// Base spring at `time_ms`. Later segments win where segments overlap.
+    fn at(&self, time_ms: u64) -> SpringConfig {
+        self.segments
+            .iter()
+            .rev()
+            .find(|(start, end, _)| time_ms >= *start && time_ms < *end)
+            .map(|(_, _, spring)| *spring)
+            .unwrap_or(self.default)
     }
 }
 
@@ -323,6 +376,8 @@ struct SmoothedCursorEvent {
     target_position: XY,
     position: XY,
     velocity: XY,
+    /// Spring that moved the cursor from the previous event to this one.
+    spring: SpringConfig,
 }
 
 /// Interpolated cursor state at a point in time.
@@ -534,7 +589,12 @@ pub struct DecodedCursorImage {
 impl CursorInterpolator {
     /// Create a new cursor interpolator from a recording.
     pub fn new(recording: &CursorRecording) -> Self {
-        let smoothed_events = compute_smoothed_events(recording);
+        Self::with_springs(recording, &CursorSprings::default())
+    }
+
+    /// Create a cursor interpolator that smooths with `springs`.
+    pub fn with_springs(recording: &CursorRecording, springs: &CursorSprings) -> Self {
+        let smoothed_events = compute_smoothed_events(recording, springs);
 
         // Decode cursor images from base64 PNG
         let decoded_images = decode_cursor_images(&recording.cursor_images);
@@ -623,6 +683,52 @@ impl CursorInterpolator {
         cursor
     }
 
+    /// Smoothed positions over the `length_ms` before `time_ms`, oldest first.
+    ///
+    /// Samples every `interval_ms`, ending exactly at `time_ms`, and stops at
+    /// the start of the recording.
+    pub fn sample_trail(&self, time_ms: u64, length_ms: u64, interval_ms: u64) -> Vec<(f32, f32)> {
+        let interval_ms = interval_ms.max(1);
+        let start_ms = time_ms.saturating_sub(length_ms);
+
+        let mut points = Vec::with_capacity((length_ms / interval_ms) as usize + 2);
+        let mut t = start_ms;
+        while t < time_ms {
+            let cursor = interpolate_at_time(&self.smoothed_events, t, None);
+            points.push((cursor.x, cursor.y));
+            t += interval_ms;
+        }
+        let cursor = interpolate_at_time(&self.smoothed_events, time_ms, None);
+        points.push((cursor.x, cursor.y));
+
+        points
+    }
+
+    /// Most recent button press within `duration_ms` before `time_ms`.
+    ///
+    /// Returns the press position (normalized 0-1) and how far its animation
+    /// has progressed (0.0 at the press, 1.0 after `duration_ms`).
+    pub fn latest_click(&self, time_ms: u64, duration_ms: u64) -> Option<(f32, f32, f32)> {
+        let click = self.original_events.iter().rev().find(|e| {
+            e.timestamp_ms <= time_ms
+                && matches!(
+                    e.event_type,
+                    CursorEventType::LeftClick { pressed: true }
+                        | CursorEventType::RightClick { pressed: true }
+                        | CursorEventType::MiddleClick { pressed: true }
+                )
+        })?;
+        let elapsed = time_ms - click.timestamp_ms;
+        if duration_ms == 0 || elapsed > duration_ms {
+            return None;
+        }
+        Some((
+            click.x as f32,
+            click.y as f32,
+            elapsed as f32 / duration_ms as f32,
+        ))
+    }
+
     /// Get decoded cursor image by ID.
     pub fn get_cursor_image(&self, cursor_id: &str) -> Option<&DecodedCursorImage> {
         self.decoded_images.get(cursor_id)
@@ -730,6 +836,7 @@ fn get_spring_profile(
     time_ms: u64,
     clicks: &[&CursorEvent],
     is_primary_button_down: bool,
+    base: SpringConfig,
 ) -> SpringConfig {
     let recent_click = clicks.iter().find(|c| {
         let diff = time_ms.abs_diff(c.timestamp_ms);
@@ -737,18 +844,21 @@ fn get_spring_profile(
     });
 
     if recent_click.is_some() {
-        return snappy_spring();
+        return snappy_spring(base);
     }
 
     if is_primary_button_down {
-        return drag_spring();
+        return drag_spring(base);
     }
 
-    DEFAULT_SPRING
+    base
 }
 
 /// Pre-compute smoothed cursor events for the entire recording.
-fn compute_smoothed_events(recording: &CursorRecording) -> Vec<SmoothedCursorEvent> {
+fn compute_smoothed_events(
+    recording: &CursorRecording,
+    springs: &CursorSprings,
+) -> Vec<SmoothedCursorEvent> {
     let moves = densify_cursor_moves(&recording.events, recording);
     let clicks: Vec<_> = recording
         .events
@@ -767,7 +877,7 @@ fn compute_smoothed_events(recording: &CursorRecording) -> Vec<SmoothedCursorEve
         return Vec::new();
     }
 
-    let mut sim = SpringSimulation::new(DEFAULT_SPRING);
+    let mut sim = SpringSimulation::new(springs.at(0));
     let mut events: Vec<SmoothedCursorEvent> = Vec::with_capacity(moves.len() + 1);
 
     let mut primary_button_down = false;
@@ -787,6 +897,7 @@ fn compute_smoothed_events(recording: &CursorRecording) -> Vec<SmoothedCursorEve
             target_position: first_pos,
             position: first_pos,
             velocity: XY::default(),
+            spring: springs.at(0),
         });
     }
 
@@ -812,7 +923,12 @@ fn compute_smoothed_events(recording: &CursorRecording) -> Vec<SmoothedCursorEve
         }
 
         // Get appropriate spring profile
-        let profile = get_spring_profile(mov.timestamp_ms, &clicks, primary_button_down);
+        let profile = get_spring_profile(
+            mov.timestamp_ms,
+            &clicks,
+            primary_button_down,
+            springs.at(mov.timestamp_ms),
+        );
         sim.set_config(profile);
 
         // Run simulation
@@ -825,6 +941,7 @@ fn compute_smoothed_events(recording: &CursorRecording) -> Vec<SmoothedCursorEve
             target_position: next_target,
             position: sim.position,
             velocity: sim.velocity,
+            spring: profile,
         });
     }
 
@@ -989,7 +1106,7 @@ fn interpolate_at_time(
 
         if time_ms >= curr.time_ms && time_ms < next.time_ms {
             // Continue simulation from curr to exact time
-            let mut sim = SpringSimulation::new(DEFAULT_SPRING);
+            let mut sim = SpringSimulation::new(next.spring);
             sim.set_position(curr.position);
             sim.set_velocity(curr.velocity);
             sim.set_target_position(curr.target_position);
@@ -1359,4 +1476,93 @@ mod tests {
         assert_eq!(cursor.velocity_y, 0.0);
         assert!(cursor.cursor_id.is_none());
     }
+
+    fn move_event(timestamp_ms: u64, x: f64) -> CursorEvent {
+        CursorEvent {
+            timestamp_ms,
+            x,
+            y: 0.5,
+            event_type: CursorEventType::Move,
+            cursor_id: None,
+        }
+    }
+
+    fn smoothing_segment(start_ms: u64, end_ms: u64, tension: f32) -> CursorSmoothingSegment {
+        CursorSmoothingSegment {
+            start_ms,
+            end_ms,
+            tension,
+            mass: 1.0,
+            friction: 26.0,
+        }
+    }
+
+    #[test]
+    fn test_cursor_springs_segment_lookup() {
+        let config = CursorConfig::default();
+        let springs = CursorSprings::from_project(
+            &config,
+            &[
+                smoothing_segment(1000, 2000, 400.0),
+                smoothing_segment(1500, 3000, 50.0),
+            ],
+        );
+
+        assert_eq!(springs.at(500).tension, config.tension);
+        assert_eq!(springs.at(1200).tension, 400.0);
+        // Later segment wins on overlap
+        assert_eq!(springs.at(1800).tension, 50.0);
+        assert_eq!(springs.at(3000).tension, config.tension);
+    }
+
+    #[test]
+    fn test_smoothing_segment_changes_cursor_response() {
+        let recording = CursorRecording {
+            events: vec![
+                move_event(0, 0.2),
+                move_event(100, 0.8),
+                move_event(200, 0.8),
+            ],
+            ..Default::default()
+        };
+
+        let config = CursorConfig::default();
+        let loose = CursorInterpolator::with_springs(
+            &recording,
+            &CursorSprings::from_project(&config, &[smoothing_segment(0, 1000, 20.0)]),
+        );
+        let stiff = CursorInterpolator::with_springs(
+            &recording,
+            &CursorSprings::from_project(&config, &[smoothing_segment(0, 1000, 600.0)]),
+        );
+
+        let loose_x = loose.get_cursor_at(150).x;
+        let stiff_x = stiff.get_cursor_at(150).x;
+        assert!(
+            stiff_x > loose_x,
+            "stiff spring should track faster: {} vs {}",
+            stiff_x,
+            loose_x
+        );
+    }
+
+    #[test]
+    fn test_sample_trail_ends_at_current_position() {
+        let recording = CursorRecording {
+            events: vec![
+                move_event(0, 0.2),
+                move_event(500, 0.8),
+                move_event(1000, 0.8),
+            ],
+            ..Default::default()
+        };
+        let interpolator = CursorInterpolator::new(&recording);
+
+        let trail = interpolator.sample_trail(600, 200, 10);
+        assert_eq!(trail.len(), 21);
+        assert_eq!(trail.last().unwrap().0, interpolator.get_cursor_at(600).x);
+
+        // Clipped at the start of the recording
+        assert_eq!(interpolator.sample_trail(20, 200, 10).len(), 3);
+    }
 }
@@ -37,11 +37,18 @@ pub struct VideoDecoder {
 }
 
 struct FrameCache {
-    frames: Vec<Option<DecodedFrame>>,
+    frames: Vec<Option<CachedFrame>>,
     base_frame: u32,
     capacity: usize,
 }
 
+/// A cached frame and whether it was decoded with a frame-exact seek.
+#[derive(Clone)]
+struct CachedFrame {
+    decoded: DecodedFrame,
+    exact: bool,
+}
+
 impl FrameCache {
     fn new(capacity: usize) -> Self {
         Self {
@@ -52,6 +59,17 @@ impl FrameCache {
     }
 
     fn get(&self, frame: u32) -> Option<&DecodedFrame> {
+        self.get_entry(frame).map(|entry| &entry.decoded)
+    }
+
+    /// Get a frame only if it was decoded with a frame-exact seek.
+    fn get_exact(&self, frame: u32) -> Option<&DecodedFrame> {
+        self.get_entry(frame)
+            .filter(|entry| entry.exact)
+            .map(|entry| &entry.decoded)
+    }
+
+    fn get_entry(&self, frame: u32) -> Option<&CachedFrame> {
         if frame < self.base_frame {
             return None;
         }
@@ -62,7 +80,8 @@ impl FrameCache {
         self.frames[idx].as_ref()
     }
 
-    fn insert(&mut self, frame: u32, decoded: DecodedFrame) {
+    fn insert(&mut self, frame: u32, decoded: DecodedFrame, exact: bool) {
+        let decoded = CachedFrame { decoded, exact };
         if frame < self.base_frame {
             return;
         }
@@ -107,6 +126,8 @@ impl FrameCache {
 
 enum DecodeRequest {
     Seek(u32),
+    /// Decode forward from the preceding keyframe to exactly this frame.
+    SeekExact(u32),
     Prefetch(u32),
     Stop,
 }
@@ -185,21 +206,50 @@ impl VideoDecoder {
     }
 
     /// Seek to a specific frame and get it.
+    ///
+    /// Fast path for live scrubbing and playback; the decoded frame may be
+    /// off by one from the requested frame.
     pub async fn seek(&self, frame: u32) -> Result<DecodedFrame, String> {
-        // Check cache first
-        {
-            let cache = self.frame_cache.lock();
-            if let Some(decoded) = cache.get(frame) {
-                log::debug!("[DECODER] Frame {} found in cache", frame);
-                return Ok(decoded.clone());
+        self.request_frame(frame, false).await
+    }
+
+    /// Seek to exactly the requested frame and get it.
+    ///
+    /// Decodes forward from the preceding keyframe, so it is slower than
+    /// [`seek`](Self::seek). Use when paused or stepping frame-by-frame.
+    pub async fn seek_exact(&self, frame: u32) -> Result<DecodedFrame, String> {
+        self.request_frame(frame, true).await
+    }
+
+    async fn request_frame(&self, frame: u32, exact: bool) -> Result<DecodedFrame, String> {
+        let cached = |cache: &FrameCache| {
+            if exact {
+                cache.get_exact(frame).cloned()
+            } else {
+                cache.get(frame).cloned()
             }
+        };
+
+        // Check cache first
+        if let Some(decoded) = cached(&self.frame_cache.lock()) {
+            log::debug!("[DECODER] Frame {} found in cache", frame);
+            return Ok(decoded);
         }
 
-        log::debug!("[DECODER] Frame {} not in cache, requesting decode", frame);
+        log::debug!(
+            "[DECODER] Frame {} not in cache, requesting {} decode",
+            frame,
+            if exact { "exact" } else { "fast" }
+        );
 
         // Request decode
+        let request = if exact {
+            DecodeRequest::SeekExact(frame)
+        } else {
+            DecodeRequest::Seek(frame)
+        };
         if let Some(tx) = &self.decode_tx {
-            tx.send(DecodeRequest::Seek(frame))
+            tx.send(request)
                 .await
                 .map_err(|e| format!("Failed to send decode request: {}", e))?;
         } else {
@@ -209,10 +259,9 @@ impl VideoDecoder {
         // Wait for frame to be decoded (with timeout - 5 seconds for slow FFmpeg startup)
         for i in 0..250 {
             tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
-            let cache = self.frame_cache.lock();
-            if let Some(decoded) = cache.get(frame) {
+            if let Some(decoded) = cached(&self.frame_cache.lock()) {
                 log::debug!("[DECODER] Frame {} decoded after {}ms", frame, i * 20);
-                return Ok(decoded.clone());
+                return Ok(decoded);
             }
         }
 
@@ -241,6 +290,14 @@ impl VideoDecoder {
     pub fn frame_to_timestamp(&self, frame: u32) -> u64 {
         ((frame as f64 / self.fps) * 1000.0) as u64
     }
+
+    /// First whole millisecond at which `frame` is displayed.
+    ///
+    /// Unlike [`frame_to_timestamp`](Self::frame_to_timestamp) this rounds up,
+    /// so converting back with `timestamp_to_frame` yields the same frame.
+    pub fn frame_start_ms(&self, frame: u32) -> u64 {
+        ((frame as f64 * 1000.0) / self.fps).ceil() as u64
+    }
 }
 
 /// Background decoder task.
@@ -262,31 +319,11 @@ async fn decoder_task(
             },
             DecodeRequest::Seek(frame) => {
                 log::debug!("[DECODER] Seek request for frame {}", frame);
-                // Decode the requested frame using spawn_blocking to avoid blocking tokio runtime
-                let path_clone = path.clone();
-                let cache_clone = Arc::clone(&cache);
-                let result = tokio::task::spawn_blocking(move || {
-                    let path = Path::new(&path_clone);
-                    match decode_frame_ffmpeg(path, frame, width, height, fps) {
-                        Ok(decoded) => {
-                            let mut c = cache_clone.lock();
-                            c.insert(frame, decoded);
-                            Ok(())
-                        },
-                        Err(e) => Err(e),
-                    }
-                })
-                .await;
-
-                if let Err(e) = result {
-                    log::error!(
-                        "[DECODER] spawn_blocking failed for frame {}: {:?}",
-                        frame,
-                        e
-                    );
-                } else if let Ok(Err(e)) = result {
-                    log::error!("[DECODER] FFmpeg decode failed for frame {}: {}", frame, e);
-                }
+                decode_into_cache(&path, frame, width, height, fps, &cache, false).await;
+            },
+            DecodeRequest::SeekExact(frame) => {
+                log::debug!("[DECODER] Exact seek request for frame {}", frame);
+                decode_into_cache(&path, frame, width, height, fps, &cache, true).await;
             },
             DecodeRequest::Prefetch(from_frame) => {
                 log::debug!("[DECODER] Prefetch request from frame {}", from_frame);
@@ -306,10 +343,10 @@ async fn decoder_task(
                             }
                         }
 
-                        match decode_frame_ffmpeg(path, frame, width, height, fps) {
+                        match decode_frame_ffmpeg(path, frame, width, height, fps, false) {
                             Ok(decoded) => {
                                 let mut c = cache_clone.lock();
-                                c.insert(frame, decoded);
+                                c.insert(frame, decoded, false);
                             },
                             Err(e) => {
                                 log::warn!("[DECODER] Prefetch failed for frame {}: {}", frame, e);
@@ -325,19 +362,65 @@ async fn decoder_task(
     log::info!("[DECODER] Decoder task ended");
 }
 
+/// Decode one frame off the async runtime and store it in the cache.
+async fn decode_into_cache(
+    path: &str,
+    frame: u32,
+    width: u32,
+    height: u32,
+    fps: f64,
+    cache: &Arc<Mutex<FrameCache>>,
+    exact: bool,
+) {
+    // Decode the requested frame using spawn_blocking to avoid blocking tokio runtime
+    let path_clone = path.to_string();
+    let cache_clone = Arc::clone(cache);
+    let result = tokio::task::spawn_blocking(move || {
+        let path = Path::new(&path_clone);
+        match decode_frame_ffmpeg(path, frame, width, height, fps, exact) {
+            Ok(decoded) => {
+                let mut c = cache_clone.lock();
+                c.insert(frame, decoded, exact);
+                Ok(())
+            },
+            Err(e) => Err(e),
+        }
+    })
+    .await;
+
+    if let Err(e) = result {
+        log::error!(
+            "[DECODER] spawn_blocking failed for frame {}: {:?}",
+            frame,
+            e
+        );
+    } else if let Ok(Err(e)) = result {
+        log::error!("[DECODER] FFmpeg decode failed for frame {}: {}", frame, e);
+    }
+}
+
 /// Decode a single frame using FFmpeg CLI.
 ///
 /// This is a fallback approach. For better performance, we should use
 /// ffmpeg-next bindings directly, but this works for initial implementation.
+///
+/// With `exact`, the seek lands on the requested frame rather than whatever
+/// the millisecond-truncated timestamp rounds to.
 fn decode_frame_ffmpeg(
     path: &Path,
     frame: u32,
     width: u32,
     height: u32,
     fps: f64,
+    exact: bool,
 ) -> Result<DecodedFrame, String> {
     let timestamp_ms = ((frame as f64 / fps) * 1000.0) as u64;
     let timestamp_secs = timestamp_ms as f64 / 1000.0;
+    let seek_arg = if exact {
+        format!("{:.6}", exact_seek_secs(frame, fps))
+    } else {
+        format!("{:.3}", timestamp_secs)
+    };
 
     log::debug!(
         "[DECODER] Decoding frame {} at {:.3}s from {:?}",
@@ -352,9 +435,14 @@ fn decode_frame_ffmpeg(
 
     // Use FFmpeg to extract frame as raw RGBA with explicit scaling to target dimensions
     let output = crate::commands::storage::ffmpeg::create_hidden_command(&ffmpeg_path)
+        .args(if exact {
+            &["-accurate_seek"][..]
+        } else {
+            &[][..]
+        })
         .args([
             "-ss",
-            &format!("{:.3}", timestamp_secs),
+            &seek_arg,
             "-i",
             &path.to_string_lossy(),
             "-frames:v",
@@ -408,6 +496,15 @@ fn decode_frame_ffmpeg(
     })
 }
 
+/// Input seek position that resolves to exactly `frame`.
+///
+/// With accurate seeking FFmpeg decodes from the preceding keyframe and drops
+/// frames before the seek point. Seeking half a frame early keeps the previous
+/// frame out while leaving room for timestamp rounding in the container.
+fn exact_seek_secs(frame: u32, fps: f64) -> f64 {
+    ((frame as f64 - 0.5) / fps).max(0.0)
+}
+
 /// Video metadata from ffprobe.
 struct VideoMetadata {
     width: u32,
@@ -433,3 +530,38 @@ fn get_video_metadata(path: &Path) -> Result<VideoMetadata, String> {
         frame_count,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_seek_falls_between_frames() {
+        let fps = 30.0;
+        for frame in 1..120u32 {
+            let secs = exact_seek_secs(frame, fps);
+            assert!(secs > (frame - 1) as f64 / fps);
+            assert!(secs < frame as f64 / fps);
+        }
+        assert_eq!(exact_seek_secs(0, fps), 0.0);
+    }
+
+    #[test]
+    fn test_cache_exact_lookup() {
+        let decoded = DecodedFrame {
+            frame_number: 3,
+            timestamp_ms: 100,
+            data: Vec::new(),
+            width: 0,
+            height: 0,
+        };
+        let mut cache = FrameCache::new(4);
+
+        cache.insert(3, decoded.clone(), false);
+        assert!(cache.get(3).is_some());
+        assert!(cache.get_exact(3).is_none());
+
+        cache.insert(3, decoded, true);
+        assert!(cache.get_exact(3).is_some());
+    }
+}
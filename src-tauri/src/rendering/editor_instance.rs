@@ -28,6 +28,7 @@ enum PlaybackCommand {
     Pause,
     Stop,
     Seek(u64), // timestamp_ms
+    SeekFrame(u32),
     SetSpeed(f32),
 }
 
@@ -211,7 +212,14 @@ impl EditorInstance {
     }
 
     /// Seek to timestamp.
-    pub async fn seek(&self, timestamp_ms: u64) -> Result<(), String> {
+    ///
+    /// With `frame_exact`, the exact frame at `timestamp_ms` is decoded before
+    /// returning so the next render shows it instead of a nearby frame.
+    pub async fn seek(&self, timestamp_ms: u64, frame_exact: bool) -> Result<(), String> {
+        if frame_exact {
+            let frame = self.screen_decoder.timestamp_to_frame(timestamp_ms);
+            self.screen_decoder.seek_exact(frame).await?;
+        }
         if let Some(tx) = &self.command_tx {
             tx.send(PlaybackCommand::Seek(timestamp_ms))
                 .await
@@ -220,6 +228,27 @@ impl EditorInstance {
         Ok(())
     }
 
+    /// Step `delta` frames from the current frame, pausing playback.
+    ///
+    /// The target frame is decoded exactly. Returns the timestamp of the
+    /// new frame.
+    pub async fn step_frame(&self, delta: i32) -> Result<u64, String> {
+        let last_frame = self.screen_decoder.frame_count().saturating_sub(1);
+        let current = self.state.lock().current_frame;
+        let frame = (current as i64 + delta as i64).clamp(0, last_frame as i64) as u32;
+
+        self.screen_decoder.seek_exact(frame).await?;
+        if let Some(tx) = &self.command_tx {
+            tx.send(PlaybackCommand::Pause)
+                .await
+                .map_err(|e| e.to_string())?;
+            tx.send(PlaybackCommand::SeekFrame(frame))
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(self.screen_decoder.frame_start_ms(frame))
+    }
+
     /// Set playback speed.
     pub async fn set_speed(&self, speed: f32) -> Result<(), String> {
         if let Some(tx) = &self.command_tx {
@@ -242,6 +271,7 @@ impl EditorInstance {
         let background_style = BackgroundStyle::from_config(
             &self.project.export.background,
             self.resource_dir.as_deref(),
+            self.project.export.transparent_output(),
         );
 
         // Set up render options
@@ -249,9 +279,10 @@ impl EditorInstance {
             output_width: self.screen_decoder.width(),
             output_height: self.screen_decoder.height(),
             zoom: zoom_state,
-            webcam: None, // TODO: Add webcam overlay
-            cursor: None, // TODO: Add cursor overlay
+            webcams: Vec::new(), // TODO: Add webcam overlay
+            cursor: None,        // TODO: Add cursor overlay
             background: background_style,
+            fill_focus: None,
         };
 
         // Composite frame
@@ -341,6 +372,21 @@ async fn playback_loop(
                     };
                     let _ = app_handle.emit(&format!("playback:{}", instance_id), event);
                 },
+                PlaybackCommand::SeekFrame(frame) => {
+                    let mut s = state.lock();
+                    s.current_frame = frame.min(frame_count.saturating_sub(1));
+                    // Round up so the timestamp maps back to the same frame
+                    let timestamp_ms = ((s.current_frame as f64 * 1000.0) / fps).ceil() as u64;
+                    s.current_timestamp_ms = timestamp_ms.min(duration_ms);
+                    s.state = PlaybackState::Paused;
+
+                    let event = PlaybackEvent {
+                        frame: s.current_frame,
+                        timestamp_ms: s.current_timestamp_ms,
+                        state: s.state,
+                    };
+                    let _ = app_handle.emit(&format!("playback:{}", instance_id), event);
+                },
                 PlaybackCommand::SetSpeed(speed) => {
                     let mut s = state.lock();
                     s.speed = speed.clamp(0.1, 4.0);
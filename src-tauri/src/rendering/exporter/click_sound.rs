@@ -0,0 +1,213 @@
+//! Click sound track for exports (`cursor.clickHighlight.clickSound`).
+//!
+//! The sound is placed at every button press of the cursor recording and
+//! rendered to a temporary WAV, which FFmpeg then mixes like any other track.
+
+use std::path::{Path, PathBuf};
+
+use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
+
+use crate::commands::video_recording::cursor::events::{
+    load_cursor_recording, CursorEventType, CursorRecording,
+};
+use crate::commands::video_recording::video_project::VideoProject;
+
+/// Rendered click track, deleted when dropped.
+pub struct ClickTrack {
+    path: PathBuf,
+}
+
+impl ClickTrack {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for ClickTrack {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Timestamps (ms) of every mouse button press in the recording.
+fn click_timestamps(recording: &CursorRecording) -> Vec<u64> {
+    recording
+        .events
+        .iter()
+        .filter(|e| {
+            matches!(
+                e.event_type,
+                CursorEventType::LeftClick { pressed: true }
+                    | CursorEventType::RightClick { pressed: true }
+                    | CursorEventType::MiddleClick { pressed: true }
+            )
+        })
+        .map(|e| e.timestamp_ms)
+        .collect()
+}
+
+/// Read a WAV file as interleaved f32 samples.
+fn read_wav(path: &Path) -> Result<(WavSpec, Vec<f32>), String> {
+    let mut reader =
+        WavReader::open(path).map_err(|e| format!("Failed to open WAV {:?}: {}", path, e))?;
+    let spec = reader.spec();
+
+    let samples = match spec.sample_format {
+        SampleFormat::Float => reader.samples::<f32>().collect::<Result<Vec<_>, _>>(),
+        SampleFormat::Int => {
+            let full_scale = (1i64 << (spec.bits_per_sample.max(1) - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|s| s as f32 / full_scale))
+                .collect()
+        },
+    }
+    .map_err(|e| format!("Failed to read WAV {:?}: {}", path, e))?;
+
+    Ok((spec, samples))
+}
+
+/// Mix `sound` into a silent track at each of `clicks_ms`.
+///
+/// The track ends at `end_ms` (the end of the trimmed recording): later clicks
+/// are dropped and a sound playing at the end is cut off. Overlapping clicks
+/// add up and are clipped to full scale.
+fn mix_clicks(sound: &[f32], spec: &WavSpec, clicks_ms: &[u64], end_ms: u64) -> Vec<f32> {
+    let channels = spec.channels.max(1) as usize;
+    let start_sample = |ms: u64| (ms * spec.sample_rate as u64 / 1000) as usize * channels;
+    let max_len = start_sample(end_ms);
+
+    let len = clicks_ms
+        .iter()
+        .filter(|&&ms| ms < end_ms)
+        .map(|&ms| start_sample(ms) + sound.len())
+        .max()
+        .unwrap_or(0)
+        .min(max_len);
+    let mut track = vec![0.0f32; len];
+
+    for &ms in clicks_ms.iter().filter(|&&ms| ms < end_ms) {
+        let start = start_sample(ms);
+        let end = (start + sound.len()).min(len);
+        for (out, &sample) in track[start..end].iter_mut().zip(sound) {
+            *out = (*out + sample).clamp(-1.0, 1.0);
+        }
+    }
+
+    track
+}
+
+fn render_click_track(
+    sound_path: &Path,
+    recording: &CursorRecording,
+    end_ms: u64,
+    output_path: &Path,
+) -> Result<usize, String> {
+    let clicks: Vec<u64> = click_timestamps(recording)
+        .into_iter()
+        .filter(|&ms| ms < end_ms)
+        .collect();
+    let (sound_spec, sound) = read_wav(sound_path)?;
+    let track = mix_clicks(&sound, &sound_spec, &clicks, end_ms);
+
+    let spec = WavSpec {
+        bits_per_sample: 32,
+        sample_format: SampleFormat::Float,
+        ..sound_spec
+    };
+    let mut writer = WavWriter::create(output_path, spec)
+        .map_err(|e| format!("Failed to create click track: {}", e))?;
+    for sample in track {
+        writer
+            .write_sample(sample)
+            .map_err(|e| format!("Failed to write click track: {}", e))?;
+    }
+    writer
+        .finalize()
+        .map_err(|e| format!("Failed to finalize click track: {}", e))?;
+
+    Ok(clicks.len())
+}
+
+/// Render the click sound track for `project`, if it has one.
+///
+/// The click sound plays whether or not the click highlight itself is drawn.
+/// Returns None when no click sound is configured, the export has no audio
+/// (GIF, PNG sequence), the sound or cursor data is missing, or rendering fails, so the
+/// export goes on without it.
+pub fn prepare_click_track(project: &VideoProject) -> Option<ClickTrack> {
+    if !project.export.format.has_audio() {
+        return None;
+    }
+
+    let sound_path = Path::new(project.cursor.click_highlight.click_sound.as_deref()?);
+    if !sound_path.exists() {
+        log::debug!("[EXPORT] Click sound not found: {:?}", sound_path);
+        return None;
+    }
+
+    let cursor_data = Path::new(project.sources.cursor_data.as_deref()?);
+    let recording = load_cursor_recording(cursor_data).ok()?;
+
+    let track = ClickTrack {
+        path: std::env::temp_dir().join(format!(
+            "snapit_clicks_{}_{}.wav",
+            project.id,
+            std::process::id()
+        )),
+    };
+
+    // Nothing after the trimmed end is exported, so the track stops there
+    let end_ms = project
+        .timeline
+        .kept_ranges()
+        .last()
+        .map_or(0, |range| range.end_ms);
+
+    match render_click_track(sound_path, &recording, end_ms, track.path()) {
+        Ok(0) => None,
+        Ok(count) => {
+            log::info!("[EXPORT] Click sound track: {} clicks", count);
+            Some(track)
+        },
+        Err(e) => {
+            log::warn!("[EXPORT] Skipping click sound: {}", e);
+            None
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mix_clicks_places_sound_at_each_click() {
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 1000,
+            bits_per_sample: 32,
+            sample_format: SampleFormat::Float,
+        };
+
+        let track = mix_clicks(&[0.5, 0.25], &spec, &[2, 3], 100);
+
+        // Second click overlaps the tail of the first
+        assert_eq!(track, vec![0.0, 0.0, 0.5, 0.75, 0.25]);
+        assert!(mix_clicks(&[0.5], &spec, &[], 100).is_empty());
+    }
+
+    #[test]
+    fn test_mix_clicks_stops_at_trimmed_end() {
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 1000,
+            bits_per_sample: 32,
+            sample_format: SampleFormat::Float,
+        };
+
+        // The click at 3ms is cut off at 4ms; the one at 5ms is dropped
+        let track = mix_clicks(&[0.5, 0.25, 0.125], &spec, &[3, 5], 4);
+        assert_eq!(track, vec![0.0, 0.0, 0.0, 0.5]);
+    }
+}
@@ -2,21 +2,57 @@
 
 use std::path::PathBuf;
 use std::process::Stdio;
+use std::sync::OnceLock;
+
+use serde::Serialize;
+use ts_rs::TS;
+
+/// FFmpeg names of the H.264 encoders exports can use.
+const NVENC_CODEC: &str = "h264_nvenc";
+const QSV_CODEC: &str = "h264_qsv";
+const AMF_CODEC: &str = "h264_amf";
+const X264_CODEC: &str = "libx264";
+
+/// Hardware encoders in the order exports prefer them.
+const HARDWARE_PRIORITY: [EncoderType; 3] =
+    [EncoderType::Nvenc, EncoderType::Qsv, EncoderType::Amf];
+
+/// First usable hardware encoder, probed once per session since each probe
+/// spawns FFmpeg and resumable exports start an encoder per chunk.
+static HARDWARE_ENCODER: OnceLock<Option<EncoderType>> = OnceLock::new();
 
 /// Encoder type for video export.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EncoderType {
     /// NVIDIA NVENC hardware encoder (h264_nvenc).
     Nvenc,
+    /// Intel Quick Sync hardware encoder (h264_qsv).
+    Qsv,
+    /// AMD AMF hardware encoder (h264_amf).
+    Amf,
     /// Software x264 encoder (libx264).
     X264,
 }
 
+impl EncoderType {
+    /// FFmpeg encoder name.
+    pub fn codec(self) -> &'static str {
+        match self {
+            EncoderType::Nvenc => NVENC_CODEC,
+            EncoderType::Qsv => QSV_CODEC,
+            EncoderType::Amf => AMF_CODEC,
+            EncoderType::X264 => X264_CODEC,
+        }
+    }
+}
+
 /// Encoder configuration with codec-specific parameters.
 #[derive(Debug, Clone)]
 pub struct EncoderConfig {
     pub encoder_type: EncoderType,
     pub codec: String,
+    /// Option carrying the speed/quality preset (`-preset`, or `-quality` for AMF).
+    pub preset_param: String,
     pub preset: String,
     pub quality_param: String,
     pub quality_value: u8,
@@ -35,6 +71,26 @@ fn nvenc_preset_from_quality(quality: u32) -> &'static str {
     }
 }
 
+/// QSV preset mapping (veryfast..veryslow).
+fn qsv_preset_from_quality(quality: u32) -> &'static str {
+    match quality {
+        0..=25 => "veryfast",
+        26..=50 => "faster",
+        51..=75 => "medium",
+        76..=90 => "slow",
+        _ => "veryslow",
+    }
+}
+
+/// AMF quality preset mapping (speed, balanced, quality).
+fn amf_preset_from_quality(quality: u32) -> &'static str {
+    match quality {
+        0..=50 => "speed",
+        51..=90 => "balanced",
+        _ => "quality",
+    }
+}
+
 /// Convert quality percentage to NVENC CQ value.
 /// CQ range: 0 (highest quality) to 51 (lowest quality).
 /// Quality 100% -> CQ ~15, Quality 50% -> CQ ~25, Quality 0% -> CQ ~40.
@@ -43,9 +99,94 @@ fn quality_to_cq(quality: u32) -> u8 {
     (cq as u8).clamp(15, 40)
 }
 
+/// Hardware video encoders usable on this machine.
+#[derive(Debug, Clone, Default, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct EncoderCapabilities {
+    /// NVIDIA NVENC (h264_nvenc).
+    pub nvenc: bool,
+    /// Intel Quick Sync (h264_qsv).
+    pub qsv: bool,
+    /// AMD AMF (h264_amf).
+    pub amf: bool,
+    /// FFmpeg encoder exports use when hardware encoding is preferred.
+    pub export_encoder: String,
+}
+
+/// Detect hardware encoders.
+///
+/// Lists FFmpeg's compiled-in encoders first, then test-encodes with each
+/// hardware encoder present, since a build can include an encoder whose GPU
+/// or driver is missing.
+pub fn detect_encoder_capabilities(ffmpeg_path: &PathBuf) -> EncoderCapabilities {
+    let compiled = list_video_encoders(ffmpeg_path);
+    let usable = |codec: &str| {
+        compiled.iter().any(|name| name == codec) && is_encoder_usable(ffmpeg_path, codec)
+    };
+
+    let nvenc = usable(NVENC_CODEC);
+    let qsv = usable(QSV_CODEC);
+    let amf = usable(AMF_CODEC);
+    let export_encoder = HARDWARE_PRIORITY
+        .into_iter()
+        .find(|encoder| match encoder {
+            EncoderType::Nvenc => nvenc,
+            EncoderType::Qsv => qsv,
+            EncoderType::Amf => amf,
+            EncoderType::X264 => false,
+        })
+        .unwrap_or(EncoderType::X264);
+
+    EncoderCapabilities {
+        nvenc,
+        qsv,
+        amf,
+        export_encoder: export_encoder.codec().to_string(),
+    }
+}
+
+/// Names of the video encoders FFmpeg was built with (`ffmpeg -encoders`).
+fn list_video_encoders(ffmpeg_path: &PathBuf) -> Vec<String> {
+    let output = crate::commands::storage::ffmpeg::create_hidden_command(ffmpeg_path)
+        .args(["-hide_banner", "-encoders"])
+        .stderr(Stdio::null())
+        .output();
+
+    match output {
+        Ok(output) => parse_video_encoders(&String::from_utf8_lossy(&output.stdout)),
+        Err(e) => {
+            log::debug!("[ENCODER] Listing FFmpeg encoders failed: {}", e);
+            Vec::new()
+        },
+    }
+}
+
+/// Parse the encoder table printed by `ffmpeg -encoders`.
+///
+/// Entries follow a `------` separator as `<flags> <name> <description>`,
+/// where flags start with `V` for video encoders.
+fn parse_video_encoders(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .skip_while(|line| !line.trim_start().starts_with("---"))
+        .skip(1)
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let flags = fields.next()?;
+            let name = fields.next()?;
+            flags.starts_with('V').then(|| name.to_string())
+        })
+        .collect()
+}
+
 /// Check if NVENC is available by testing FFmpeg encoder.
 pub fn is_nvenc_available(ffmpeg_path: &PathBuf) -> bool {
-    // Run a quick encode test to verify NVENC works
+    is_encoder_usable(ffmpeg_path, NVENC_CODEC)
+}
+
+/// Check if an FFmpeg encoder works by running a tiny test encode.
+fn is_encoder_usable(ffmpeg_path: &PathBuf, codec: &str) -> bool {
     // Note: NVENC has minimum frame size requirements (~145x49), so we use 256x256
     let result = crate::commands::storage::ffmpeg::create_hidden_command(ffmpeg_path)
         .args([
@@ -54,7 +195,7 @@ pub fn is_nvenc_available(ffmpeg_path: &PathBuf) -> bool {
             "-i",
             "testsrc=duration=0.01:size=256x256:rate=1",
             "-c:v",
-            "h264_nvenc",
+            codec,
             "-f",
             "null",
             "-",
@@ -67,7 +208,8 @@ pub fn is_nvenc_available(ffmpeg_path: &PathBuf) -> bool {
         Ok(status) => {
             let available = status.success();
             log::info!(
-                "[ENCODER] NVENC availability check: {}",
+                "[ENCODER] {} availability check: {}",
+                codec,
                 if available {
                     "available"
                 } else {
@@ -77,37 +219,76 @@ pub fn is_nvenc_available(ffmpeg_path: &PathBuf) -> bool {
             available
         },
         Err(e) => {
-            log::debug!("[ENCODER] NVENC check failed: {}", e);
+            log::debug!("[ENCODER] {} check failed: {}", codec, e);
             false
         },
     }
 }
 
+/// First hardware encoder in priority order that passes a test encode.
+fn usable_hardware_encoder(ffmpeg_path: &PathBuf) -> Option<EncoderType> {
+    *HARDWARE_ENCODER.get_or_init(|| {
+        HARDWARE_PRIORITY
+            .into_iter()
+            .find(|encoder| is_encoder_usable(ffmpeg_path, encoder.codec()))
+    })
+}
+
+/// Encoder settings for a given encoder type and quality percentage.
+fn encoder_config(encoder_type: EncoderType, quality: u32) -> EncoderConfig {
+    let (preset_param, preset, quality_param, quality_value) = match encoder_type {
+        EncoderType::Nvenc => (
+            "-preset",
+            nvenc_preset_from_quality(quality),
+            "-cq",
+            quality_to_cq(quality),
+        ),
+        // ICQ mode; global_quality uses the same 1-51 scale as NVENC's CQ
+        EncoderType::Qsv => (
+            "-preset",
+            qsv_preset_from_quality(quality),
+            "-global_quality",
+            quality_to_cq(quality),
+        ),
+        // Constant QP; the P-frame QP is added alongside in the FFmpeg args
+        EncoderType::Amf => (
+            "-quality",
+            amf_preset_from_quality(quality),
+            "-qp_i",
+            quality_to_cq(quality),
+        ),
+        // "superfast" is ~2x faster than "fast" with minimal quality loss
+        // For balanced quality/speed when hardware encoding unavailable
+        EncoderType::X264 => (
+            "-preset",
+            "superfast",
+            "-crf",
+            super::ffmpeg::quality_to_crf(quality),
+        ),
+    };
+
+    EncoderConfig {
+        encoder_type,
+        codec: encoder_type.codec().to_string(),
+        preset_param: preset_param.to_string(),
+        preset: preset.to_string(),
+        quality_param: quality_param.to_string(),
+        quality_value,
+    }
+}
+
 /// Select the best available encoder based on hardware and preferences.
+///
+/// With hardware preferred, tries NVENC, then Quick Sync, then AMF, falling
+/// back to x264 when none of them can encode on this machine.
 pub fn select_encoder(ffmpeg_path: &PathBuf, quality: u32, prefer_hardware: bool) -> EncoderConfig {
-    let use_nvenc = prefer_hardware && is_nvenc_available(ffmpeg_path);
-
-    if use_nvenc {
-        log::info!("[ENCODER] Using NVENC hardware encoder");
-        EncoderConfig {
-            encoder_type: EncoderType::Nvenc,
-            codec: "h264_nvenc".to_string(),
-            preset: nvenc_preset_from_quality(quality).to_string(),
-            quality_param: "-cq".to_string(),
-            quality_value: quality_to_cq(quality),
-        }
-    } else {
-        log::info!("[ENCODER] Using x264 software encoder");
-        EncoderConfig {
-            encoder_type: EncoderType::X264,
-            codec: "libx264".to_string(),
-            // "superfast" is ~2x faster than "fast" with minimal quality loss
-            // For balanced quality/speed when hardware encoding unavailable
-            preset: "superfast".to_string(),
-            quality_param: "-crf".to_string(),
-            quality_value: super::ffmpeg::quality_to_crf(quality),
-        }
-    }
+    let encoder_type = prefer_hardware
+        .then(|| usable_hardware_encoder(ffmpeg_path))
+        .flatten()
+        .unwrap_or(EncoderType::X264);
+
+    log::info!("[ENCODER] Using {} encoder", encoder_type.codec());
+    encoder_config(encoder_type, quality)
 }
 
 #[cfg(test)]
@@ -122,6 +303,41 @@ mod tests {
         assert!(mid > 15 && mid < 40);
     }
 
+    #[test]
+    fn test_parse_video_encoders() {
+        let output = "Encoders:
+ V..... = Video
+ A..... = Audio
+ ------
+ V....D libx264              libx264 H.264 / AVC / MPEG-4 AVC (codec h264)
+ V....D h264_nvenc           NVIDIA NVENC H.264 encoder (codec h264)
+ V..... h264_qsv             H.264 / AVC / MPEG-4 AVC (Intel Quick Sync Video acceleration) (codec h264)
+ A....D aac                  AAC (Advanced Audio Coding)
+";
+        let encoders = parse_video_encoders(output);
+        assert_eq!(encoders, vec!["libx264", "h264_nvenc", "h264_qsv"]);
+        assert!(parse_video_encoders("").is_empty());
+    }
+
+    #[test]
+    fn test_encoder_config_per_encoder() {
+        let qsv = encoder_config(EncoderType::Qsv, 100);
+        assert_eq!(qsv.codec, "h264_qsv");
+        assert_eq!(qsv.preset, "veryslow");
+        assert_eq!(qsv.quality_param, "-global_quality");
+        assert_eq!(qsv.quality_value, 15);
+
+        let amf = encoder_config(EncoderType::Amf, 0);
+        assert_eq!(amf.codec, "h264_amf");
+        assert_eq!(amf.preset_param, "-quality");
+        assert_eq!(amf.preset, "speed");
+        assert_eq!(amf.quality_value, 40);
+
+        let x264 = encoder_config(EncoderType::X264, 80);
+        assert_eq!(x264.codec, "libx264");
+        assert_eq!(x264.quality_param, "-crf");
+    }
+
     #[test]
     fn test_nvenc_preset_selection() {
         assert_eq!(nvenc_preset_from_quality(100), "p7");
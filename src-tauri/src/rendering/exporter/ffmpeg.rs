@@ -5,24 +5,151 @@ use std::process::{Child, Stdio};
 
 use tauri::{AppHandle, Emitter};
 
-use crate::commands::video_recording::video_export::{ExportProgress, ExportStage};
-use crate::commands::video_recording::video_project::{ExportFormat, VideoProject};
+use crate::commands::video_recording::ffmpeg_gif_encoder::{GifQualityPreset, MAX_GIF_FPS};
+use crate::commands::video_recording::video_export::{
+    audio_track_chain, ExportProgress, ExportStage,
+};
+use crate::commands::video_recording::video_project::{
+    ExportFormat, HoldAudio, TempoPiece, VideoProject,
+};
 
 use super::encoder_selection::{select_encoder, EncoderType};
+use super::loudness::{loudnorm_filter, measure_loudness, LoudnessMeasurement};
 
 /// Audio input info for building ffmpeg filter.
 struct AudioInput {
     input_index: usize,
     volume: f32,
+    /// Track offset in ms (positive = delay).
+    offset_ms: i32,
+}
+
+/// Sample rate of the exported audio. `loudnorm` upsamples to 192kHz internally.
+const EXPORT_AUDIO_SAMPLE_RATE: u32 = 48000;
+
+/// Per-filter tempo range of `atempo`; larger changes are chained.
+const ATEMPO_MIN: f64 = 0.5;
+const ATEMPO_MAX: f64 = 2.0;
+
+/// How each audio track is edited to line up with the exported frames.
+struct TrackTiming {
+    /// Filter muting silenced holds, in source time.
+    mute: Option<String>,
+    retime: Retime,
+}
+
+/// How each audio track is retimed to line up with the exported frames.
+enum Retime {
+    /// Played as recorded.
+    Unchanged,
+    /// Filter applied after the track's offset (timeline cuts).
+    Filter(String),
+    /// Cut into source pieces, each time-stretched by its tempo, then rejoined.
+    Pieces(Vec<TempoPiece>),
+}
+
+/// Collect the unmuted audio tracks of `project`, plus the rendered click track.
+///
+/// Returns the `-i` arguments and the matching inputs, numbered from `first_index`.
+/// GIF exports have no audio, so they collect nothing.
+fn collect_audio_inputs(
+    project: &VideoProject,
+    first_index: usize,
+    click_track: Option<&Path>,
+) -> (Vec<String>, Vec<AudioInput>) {
+    let mut args = Vec::new();
+    let mut audio_inputs: Vec<AudioInput> = Vec::new();
+    let mut next_input_index = first_index;
+
+    if !project.export.format.has_audio() {
+        return (args, audio_inputs);
+    }
+
+    // Add system audio if available and not muted
+    if let Some(ref audio_path) = project.sources.system_audio {
+        if Path::new(audio_path).exists() && !project.audio.system_muted {
+            args.extend(["-i".to_string(), audio_path.clone()]);
+            audio_inputs.push(AudioInput {
+                input_index: next_input_index,
+                volume: project.audio.system_volume,
+                offset_ms: project.audio.system_offset_ms,
+            });
+            next_input_index += 1;
+        }
+    }
+
+    // Add microphone audio if available and not muted
+    if let Some(ref mic_path) = project.sources.microphone_audio {
+        if Path::new(mic_path).exists() && !project.audio.microphone_muted {
+            args.extend(["-i".to_string(), mic_path.clone()]);
+            audio_inputs.push(AudioInput {
+                input_index: next_input_index,
+                volume: project.audio.microphone_volume,
+                offset_ms: project.audio.mic_offset_ms,
+            });
+            next_input_index += 1;
+        }
+    }
+
+    // Add click sounds, already placed at the click times
+    if let Some(click_path) = click_track {
+        args.extend(["-i".to_string(), click_path.to_string_lossy().to_string()]);
+        audio_inputs.push(AudioInput {
+            input_index: next_input_index,
+            volume: project.audio.click_sound_volume,
+            offset_ms: 0,
+        });
+    }
+
+    (args, audio_inputs)
+}
+
+/// Measure the loudness of the export mix (pass 1 of loudness normalization).
+///
+/// Returns None when normalization is off or there is no audio to measure.
+pub fn measure_export_loudness(
+    project: &VideoProject,
+    click_track: Option<&Path>,
+) -> Result<Option<LoudnessMeasurement>, String> {
+    if !project.audio.normalize_output {
+        return Ok(None);
+    }
+
+    let (input_args, audio_inputs) = collect_audio_inputs(project, 0, click_track);
+    let analysis = loudnorm_filter(project.audio.loudness_target_lufs, None);
+    let timing = export_track_timing(project);
+    let Some(filter) = build_audio_filter(&audio_inputs, &timing, Some(&analysis)) else {
+        return Ok(None);
+    };
+
+    let ffmpeg_path = crate::commands::storage::find_ffmpeg().ok_or("FFmpeg not found")?;
+    let measured = measure_loudness(&ffmpeg_path, &input_args, &filter)?;
+    log::info!(
+        "[EXPORT] Loudness pass 1: integrated {} LUFS, true peak {} dBTP, LRA {} LU (target {} LUFS)",
+        measured.input_i,
+        measured.input_tp,
+        measured.input_lra,
+        project.audio.loudness_target_lufs
+    );
+
+    Ok(Some(measured))
 }
 
 /// Start FFmpeg process for encoding raw RGBA input.
+///
+/// `loudness` is the pass 1 measurement from `measure_export_loudness`; with
+/// normalization on and no measurement, `loudnorm` runs single-pass.
+/// `click_track` is the rendered click sound track, if any. With `video_only`
+/// the audio tracks are left out (resumable chunks get them in `mux_chunks`).
 pub fn start_ffmpeg_encoder(
     project: &VideoProject,
     output_path: &Path,
     width: u32,
     height: u32,
     fps: u32,
+    loudness: Option<&LoudnessMeasurement>,
+    click_track: Option<&Path>,
+    video_only: bool,
 ) -> Result<Child, String> {
     let ffmpeg_path = crate::commands::storage::find_ffmpeg().ok_or("FFmpeg not found")?;
 
@@ -43,40 +170,20 @@ pub fn start_ffmpeg_encoder(
 
     // Track audio inputs for filter graph
     // Input 0 is always video (stdin)
-    let mut audio_inputs: Vec<AudioInput> = Vec::new();
-    let mut next_input_index = 1;
-
-    // Add system audio if available and not muted
-    if let Some(ref audio_path) = project.sources.system_audio {
-        if Path::new(audio_path).exists() && !project.audio.system_muted {
-            args.extend(["-i".to_string(), audio_path.clone()]);
-            audio_inputs.push(AudioInput {
-                input_index: next_input_index,
-                volume: project.audio.system_volume,
-            });
-            next_input_index += 1;
-        }
-    }
-
-    // Add microphone audio if available and not muted
-    if let Some(ref mic_path) = project.sources.microphone_audio {
-        if Path::new(mic_path).exists() && !project.audio.microphone_muted {
-            args.extend(["-i".to_string(), mic_path.clone()]);
-            audio_inputs.push(AudioInput {
-                input_index: next_input_index,
-                volume: project.audio.microphone_volume,
-            });
-            // next_input_index += 1; // Uncomment when adding more audio sources
-        }
-    }
+    let (audio_args, audio_inputs) = if video_only {
+        (Vec::new(), Vec::new())
+    } else {
+        collect_audio_inputs(project, 1, click_track)
+    };
+    args.extend(audio_args);
 
     // Build audio filter graph if we have audio inputs
-    let audio_filter = build_audio_filter(&audio_inputs);
+    let audio_filter = export_audio_filter(project, &audio_inputs, loudness);
 
     // Output encoding based on format
     match project.export.format {
         ExportFormat::Mp4 => {
-            // Select encoder (NVENC if available and preferred, otherwise x264)
+            // Select encoder (first usable hardware encoder if preferred, otherwise x264)
             let prefer_hardware = project.export.prefer_hardware_encoding.unwrap_or(false);
             let encoder_config =
                 select_encoder(&ffmpeg_path, project.export.quality, prefer_hardware);
@@ -86,7 +193,7 @@ pub fn start_ffmpeg_encoder(
                 encoder_config.codec.clone(),
                 encoder_config.quality_param.clone(),
                 encoder_config.quality_value.to_string(),
-                "-preset".to_string(),
+                encoder_config.preset_param.clone(),
                 encoder_config.preset.clone(),
                 "-pix_fmt".to_string(),
                 "yuv420p".to_string(),
@@ -99,22 +206,38 @@ pub fn start_ffmpeg_encoder(
             ]);
 
             // Encoder-specific optimizations
-            if encoder_config.encoder_type == EncoderType::Nvenc {
-                // NVENC: add b-frames and lookahead for better quality
-                args.extend([
-                    "-bf".to_string(),
-                    "2".to_string(),
-                    "-rc-lookahead".to_string(),
-                    "20".to_string(),
-                ]);
-            } else {
-                // x264: enable multi-threaded encoding for better CPU utilization
-                args.extend([
-                    "-threads".to_string(),
-                    "0".to_string(), // Auto-detect CPU cores
-                    "-x264-params".to_string(),
-                    "threads=auto:lookahead_threads=auto".to_string(),
-                ]);
+            match encoder_config.encoder_type {
+                EncoderType::Nvenc => {
+                    // NVENC: add b-frames and lookahead for better quality
+                    args.extend([
+                        "-bf".to_string(),
+                        "2".to_string(),
+                        "-rc-lookahead".to_string(),
+                        "20".to_string(),
+                    ]);
+                },
+                EncoderType::Qsv => {
+                    // QSV: b-frames for better compression at the same quality
+                    args.extend(["-bf".to_string(), "2".to_string()]);
+                },
+                EncoderType::Amf => {
+                    // AMF: constant QP, same value for P-frames as I-frames
+                    args.extend([
+                        "-rc".to_string(),
+                        "cqp".to_string(),
+                        "-qp_p".to_string(),
+                        encoder_config.quality_value.to_string(),
+                    ]);
+                },
+                EncoderType::X264 => {
+                    // x264: enable multi-threaded encoding for better CPU utilization
+                    args.extend([
+                        "-threads".to_string(),
+                        "0".to_string(), // Auto-detect CPU cores
+                        "-x264-params".to_string(),
+                        "threads=auto:lookahead_threads=auto".to_string(),
+                    ]);
+                },
             }
 
             log::info!(
@@ -125,22 +248,14 @@ pub fn start_ffmpeg_encoder(
                 encoder_config.quality_value
             );
 
-            if !audio_inputs.is_empty() {
-                if let Some(ref filter) = audio_filter {
-                    args.extend(["-filter_complex".to_string(), filter.clone()]);
-                    args.extend(["-map".to_string(), "0:v".to_string()]);
-                    args.extend(["-map".to_string(), "[aout]".to_string()]);
-                }
-                args.extend([
-                    "-c:a".to_string(),
-                    "aac".to_string(),
-                    "-b:a".to_string(),
-                    "192k".to_string(),
-                    "-shortest".to_string(),
-                ]);
-            }
+            args.extend(audio_output_args(
+                project.export.format,
+                &audio_inputs,
+                audio_filter.as_deref(),
+            ));
         },
         ExportFormat::Webm => {
+            // Software libvpx-vp9 only (no hardware VP9 path); constant quality mode
             let crf = quality_to_crf(project.export.quality);
             args.extend([
                 "-c:v".to_string(),
@@ -153,32 +268,54 @@ pub fn start_ffmpeg_encoder(
                 "realtime".to_string(),
                 "-cpu-used".to_string(),
                 "4".to_string(),
+                // Row-based multithreading, since this is CPU-bound
+                "-row-mt".to_string(),
+                "1".to_string(),
                 // Keyframe every 1 second for precise seeking
                 "-g".to_string(),
                 fps.to_string(),
             ]);
-            if !audio_inputs.is_empty() {
-                if let Some(ref filter) = audio_filter {
-                    args.extend(["-filter_complex".to_string(), filter.clone()]);
-                    args.extend(["-map".to_string(), "0:v".to_string()]);
-                    args.extend(["-map".to_string(), "[aout]".to_string()]);
-                }
-                args.extend([
-                    "-c:a".to_string(),
-                    "libopus".to_string(),
-                    "-b:a".to_string(),
-                    "128k".to_string(),
-                ]);
-            }
+
+            log::info!("[EXPORT] Encoder: libvpx-vp9 (software, crf: {})", crf);
+
+            args.extend(audio_output_args(
+                project.export.format,
+                &audio_inputs,
+                audio_filter.as_deref(),
+            ));
         },
         ExportFormat::Gif => {
+            // Same palette pipeline as the GIF recorder
             args.extend([
                 "-vf".to_string(),
-                format!(
-                    "fps={},split[s0][s1];[s0]palettegen[p];[s1][p]paletteuse",
-                    fps.min(15)
-                ),
+                gif_filter(fps, project.export.gif_quality_preset),
+                "-loop".to_string(),
+                "0".to_string(),
+            ]);
+        },
+        ExportFormat::Mov => {
+            // ProRes 4444 keeps the RGBA input's alpha channel
+            args.extend([
+                "-c:v".to_string(),
+                "prores_ks".to_string(),
+                "-profile:v".to_string(),
+                "4444".to_string(),
+                "-pix_fmt".to_string(),
+                "yuva444p10le".to_string(),
+                "-vendor".to_string(),
+                "apl0".to_string(),
             ]);
+
+            log::info!("[EXPORT] Encoder: prores_ks 4444 (software, alpha)");
+
+            args.extend(audio_output_args(
+                project.export.format,
+                &audio_inputs,
+                audio_filter.as_deref(),
+            ));
+        },
+        ExportFormat::PngSequence => {
+            return Err("PNG sequences are written without FFmpeg".to_string());
         },
     }
 
@@ -195,39 +332,318 @@ pub fn start_ffmpeg_encoder(
         .map_err(|e| format!("Failed to start FFmpeg: {}", e))
 }
 
+/// FFmpeg video encoder used to export `project`, or `None` for PNG sequences.
+///
+/// MP4 goes through the same selection as the encoder itself; the hardware
+/// probe is cached, so this doesn't spawn FFmpeg again.
+pub fn export_video_encoder(project: &VideoProject) -> Option<String> {
+    match project.export.format {
+        ExportFormat::Mp4 => {
+            let ffmpeg_path = crate::commands::storage::find_ffmpeg()?;
+            let prefer_hardware = project.export.prefer_hardware_encoding.unwrap_or(false);
+            Some(select_encoder(&ffmpeg_path, project.export.quality, prefer_hardware).codec)
+        },
+        ExportFormat::Webm => Some("libvpx-vp9".to_string()),
+        ExportFormat::Gif => Some("gif".to_string()),
+        ExportFormat::Mov => Some("prores_ks".to_string()),
+        ExportFormat::PngSequence => None,
+    }
+}
+
+/// Mix the audio of a finished resumable export into its video chunks.
+///
+/// `concat_list` is an ffmpeg concat script of the chunks; video is copied,
+/// audio goes through the same mix, normalization and codec as a single-pass export.
+pub fn mux_chunks(
+    project: &VideoProject,
+    concat_list: &Path,
+    output_path: &Path,
+    loudness: Option<&LoudnessMeasurement>,
+    click_track: Option<&Path>,
+) -> Result<(), String> {
+    let ffmpeg_path = crate::commands::storage::find_ffmpeg().ok_or("FFmpeg not found")?;
+
+    let mut args = vec![
+        "-y".to_string(),
+        "-f".to_string(),
+        "concat".to_string(),
+        "-safe".to_string(),
+        "0".to_string(),
+        "-i".to_string(),
+        concat_list.to_string_lossy().to_string(),
+    ];
+
+    let (audio_args, audio_inputs) = collect_audio_inputs(project, 1, click_track);
+    args.extend(audio_args);
+    let audio_filter = export_audio_filter(project, &audio_inputs, loudness);
+
+    args.extend(["-c:v".to_string(), "copy".to_string()]);
+    args.extend(audio_output_args(
+        project.export.format,
+        &audio_inputs,
+        audio_filter.as_deref(),
+    ));
+    if project.export.format == ExportFormat::Mp4 {
+        args.extend(["-movflags".to_string(), "+faststart".to_string()]);
+    }
+    args.push(output_path.to_string_lossy().to_string());
+
+    log::info!("[EXPORT] Muxing chunks: ffmpeg {}", args.join(" "));
+
+    let output = crate::commands::storage::ffmpeg::create_hidden_command(&ffmpeg_path)
+        .args(&args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("Failed to start FFmpeg: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!(
+            "FFmpeg chunk mux failed: {}",
+            stderr.lines().last().unwrap_or("unknown error")
+        ));
+    }
+    Ok(())
+}
+
+/// Audio mix filter for the export, normalized to the loudness target when enabled.
+fn export_audio_filter(
+    project: &VideoProject,
+    audio_inputs: &[AudioInput],
+    loudness: Option<&LoudnessMeasurement>,
+) -> Option<String> {
+    // Normalize the mix to the loudness target (pass 2), resampling back
+    // down from loudnorm's internal rate
+    let normalization = project.audio.normalize_output.then(|| {
+        format!(
+            "{},aresample={}",
+            loudnorm_filter(project.audio.loudness_target_lufs, loudness),
+            EXPORT_AUDIO_SAMPLE_RATE
+        )
+    });
+
+    let timing = export_track_timing(project);
+    build_audio_filter(audio_inputs, &timing, normalization.as_deref())
+}
+
+/// Track retiming for the export: speed keyframes stretch the kept ranges
+/// piece by piece, otherwise cuts only drop the removed ranges.
+fn export_track_timing(project: &VideoProject) -> TrackTiming {
+    let mute = hold_mute_filter(project);
+
+    if project.timeline.has_speed_changes() {
+        let pieces = project.timeline.tempo_pieces();
+        if !pieces.is_empty() {
+            return TrackTiming {
+                mute,
+                retime: Retime::Pieces(pieces),
+            };
+        }
+    }
+
+    let retime = match timeline_cut_filter(project) {
+        Some(filter) => Retime::Filter(filter),
+        None => Retime::Unchanged,
+    };
+    TrackTiming { mute, retime }
+}
+
+/// Audio filter muting the holds set to silence. None when there are none.
+fn hold_mute_filter(project: &VideoProject) -> Option<String> {
+    let holds = project
+        .timeline
+        .holds
+        .iter()
+        .filter(|hold| hold.audio == HoldAudio::Silence && hold.duration_ms > 0)
+        .map(|hold| {
+            format!(
+                "between(t,{:.3},{:.3})",
+                hold.at_source_ms as f64 / 1000.0,
+                hold.at_source_ms.saturating_add(hold.duration_ms) as f64 / 1000.0
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if holds.is_empty() {
+        return None;
+    }
+    Some(format!("volume=0:enable='{}'", holds.join("+")))
+}
+
+/// Audio filter keeping only the timeline's kept ranges, joined back to back
+/// like the exported frames. None when the timeline isn't cut into segments.
+fn timeline_cut_filter(project: &VideoProject) -> Option<String> {
+    if project.timeline.segments.is_empty() {
+        return None;
+    }
+
+    let ranges = project
+        .timeline
+        .kept_ranges()
+        .iter()
+        .map(|range| {
+            format!(
+                "between(t,{:.3},{:.3})",
+                range.start_ms as f64 / 1000.0,
+                range.end_ms as f64 / 1000.0
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("+");
+    Some(format!("aselect='{}',asetpts=N/SR/TB", ranges))
+}
+
+/// Mapping and codec arguments for the mixed audio track (input 0 is the video).
+fn audio_output_args(
+    format: ExportFormat,
+    audio_inputs: &[AudioInput],
+    audio_filter: Option<&str>,
+) -> Vec<String> {
+    if audio_inputs.is_empty() {
+        return Vec::new();
+    }
+
+    let mut args = Vec::new();
+    if let Some(filter) = audio_filter {
+        args.extend(["-filter_complex".to_string(), filter.to_string()]);
+        args.extend(["-map".to_string(), "0:v".to_string()]);
+        args.extend(["-map".to_string(), "[aout]".to_string()]);
+    }
+    match format {
+        ExportFormat::Mp4 => args.extend([
+            "-c:a".to_string(),
+            "aac".to_string(),
+            "-b:a".to_string(),
+            "192k".to_string(),
+            "-shortest".to_string(),
+        ]),
+        ExportFormat::Webm => args.extend([
+            "-c:a".to_string(),
+            "libopus".to_string(),
+            "-b:a".to_string(),
+            "128k".to_string(),
+        ]),
+        ExportFormat::Mov => args.extend(["-c:a".to_string(), "pcm_s16le".to_string()]),
+        ExportFormat::Gif | ExportFormat::PngSequence => {},
+    }
+    args
+}
+
+/// Video filter for GIF output: fps capped like the GIF recorder, then the preset's palette.
+pub fn gif_filter(fps: u32, preset: GifQualityPreset) -> String {
+    format!("fps={},{}", fps.min(MAX_GIF_FPS), preset.to_filter())
+}
+
+/// `atempo` filters changing the tempo by `tempo`, split into factors
+/// `atempo` accepts.
+fn atempo_chain(tempo: f64) -> String {
+    let mut remaining = tempo;
+    let mut factors = Vec::new();
+    while remaining > ATEMPO_MAX {
+        factors.push(ATEMPO_MAX);
+        remaining /= ATEMPO_MAX;
+    }
+    while remaining < ATEMPO_MIN {
+        factors.push(ATEMPO_MIN);
+        remaining /= ATEMPO_MIN;
+    }
+    factors.push(remaining);
+
+    factors
+        .iter()
+        .map(|factor| format!("atempo={:.6}", factor))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Filter graph for one track: offset, volume and hold muting, retimed by
+/// `timing`, then `tail` (already comma-prefixed), ending at `[label]`.
+fn track_graph(input: &AudioInput, timing: &TrackTiming, tail: &str, label: &str) -> String {
+    let mut chain = audio_track_chain(input.offset_ms, input.volume);
+    if let Some(ref mute) = timing.mute {
+        chain = format!("{},{}", chain, mute);
+    }
+
+    match timing.retime {
+        Retime::Unchanged => {
+            format!("[{}:a]{}{}[{}]", input.input_index, chain, tail, label)
+        },
+        Retime::Filter(ref filter) => format!(
+            "[{}:a]{},{}{}[{}]",
+            input.input_index, chain, filter, tail, label
+        ),
+        Retime::Pieces(ref pieces) => {
+            let splits: String = (0..pieces.len())
+                .map(|k| format!("[{}s{}]", label, k))
+                .collect();
+            let mut parts = vec![format!(
+                "[{}:a]{},asplit={}{}",
+                input.input_index,
+                chain,
+                pieces.len(),
+                splits
+            )];
+
+            for (k, piece) in pieces.iter().enumerate() {
+                parts.push(format!(
+                    "[{label}s{k}]atrim=start={:.3}:end={:.3},asetpts=PTS-STARTPTS,{}[{label}p{k}]",
+                    piece.start_ms / 1000.0,
+                    piece.end_ms / 1000.0,
+                    atempo_chain(piece.tempo),
+                ));
+            }
+
+            let joined: String = (0..pieces.len())
+                .map(|k| format!("[{}p{}]", label, k))
+                .collect();
+            parts.push(format!(
+                "{}concat=n={}:v=0:a=1{}[{}]",
+                joined,
+                pieces.len(),
+                tail,
+                label
+            ));
+            parts.join(";")
+        },
+    }
+}
+
 /// Build audio filter graph for mixing multiple audio tracks with volume control.
+/// Each track is retimed by `timing` after its offset (timeline cuts, speed),
+/// `post_filter` is applied to the final mix (e.g. loudness normalization).
 /// Returns None if no audio inputs, otherwise returns the filter string.
-fn build_audio_filter(audio_inputs: &[AudioInput]) -> Option<String> {
+fn build_audio_filter(
+    audio_inputs: &[AudioInput],
+    timing: &TrackTiming,
+    post_filter: Option<&str>,
+) -> Option<String> {
     if audio_inputs.is_empty() {
         return None;
     }
 
+    let post = post_filter.map(|f| format!(",{}", f)).unwrap_or_default();
+
     if audio_inputs.len() == 1 {
-        // Single audio track - just apply volume
-        let input = &audio_inputs[0];
-        Some(format!(
-            "[{}:a]volume={:.2}[aout]",
-            input.input_index, input.volume
-        ))
+        // Single audio track - just apply offset and volume
+        Some(track_graph(&audio_inputs[0], timing, &post, "aout"))
     } else {
-        // Multiple audio tracks - apply volume to each, then mix
+        // Multiple audio tracks - apply offset and volume to each, then mix
         let mut filter_parts: Vec<String> = Vec::new();
         let mut mix_inputs: Vec<String> = Vec::new();
 
         for (i, input) in audio_inputs.iter().enumerate() {
             let label = format!("a{}", i);
-            filter_parts.push(format!(
-                "[{}:a]volume={:.2}[{}]",
-                input.input_index, input.volume, label
-            ));
+            filter_parts.push(track_graph(input, timing, "", &label));
             mix_inputs.push(format!("[{}]", label));
         }
 
         // Mix all audio streams together
         filter_parts.push(format!(
-            "{}amix=inputs={}:duration=longest[aout]",
+            "{}amix=inputs={}:duration=longest{}[aout]",
             mix_inputs.join(""),
-            audio_inputs.len()
+            audio_inputs.len(),
+            post
         ));
 
         Some(filter_parts.join(";"))
@@ -247,6 +663,28 @@ pub fn emit_progress(app: &AppHandle, progress: f32, stage: ExportStage, message
             progress,
             stage,
             message: message.to_string(),
+            fps: None,
+            eta_secs: None,
+        },
+    );
+}
+
+/// Emit an encoding progress event carrying render throughput and ETA.
+pub fn emit_render_progress(
+    app: &AppHandle,
+    progress: f32,
+    message: &str,
+    fps: Option<f32>,
+    eta_secs: Option<f32>,
+) {
+    let _ = app.emit(
+        "export-progress",
+        ExportProgress {
+            progress,
+            stage: ExportStage::Encoding,
+            message: message.to_string(),
+            fps,
+            eta_secs,
         },
     );
 }
@@ -5,6 +5,9 @@
 use super::super::types::DecodedFrame;
 use crate::commands::video_recording::video_project::SceneMode;
 
+/// Fraction of a click spotlight's radius over which its edge fades.
+const SPOTLIGHT_FEATHER: f32 = 0.25;
+
 /// Extract a cropped region from RGBA frame data.
 ///
 /// This is used for non-destructive crop - the frame is rendered at full size,
@@ -311,3 +314,154 @@ pub fn draw_cursor_circle(
         }
     }
 }
+
+/// Darken the whole frame except a soft-edged circle around a click.
+///
+/// `opacity` is how dark the area outside the circle gets (0 = untouched,
+/// 1 = black); the edge fades over the outer `SPOTLIGHT_FEATHER` of `radius`.
+pub fn draw_click_spotlight(
+    frame_data: &mut [u8],
+    frame_width: u32,
+    frame_height: u32,
+    center_x: f32, // normalized 0-1
+    center_y: f32, // normalized 0-1
+    radius: f32,
+    opacity: f32,
+) {
+    if opacity <= 0.0 || frame_width == 0 || frame_height == 0 {
+        return;
+    }
+
+    let center_x = center_x * frame_width as f32;
+    let center_y = center_y * frame_height as f32;
+    let radius = radius.max(1.0);
+    let inner_radius = radius * (1.0 - SPOTLIGHT_FEATHER);
+
+    for (i, pixel) in frame_data
+        .chunks_exact_mut(4)
+        .take((frame_width * frame_height) as usize)
+        .enumerate()
+    {
+        let dx = (i as u32 % frame_width) as f32 + 0.5 - center_x;
+        let dy = (i as u32 / frame_width) as f32 + 0.5 - center_y;
+        let dist = (dx * dx + dy * dy).sqrt();
+
+        // 0 inside the circle, 1 outside, smooth across the feathered edge
+        let outside = ((dist - inner_radius) / (radius - inner_radius)).clamp(0.0, 1.0);
+        let outside = outside * outside * (3.0 - 2.0 * outside);
+        if outside <= 0.0 {
+            continue;
+        }
+
+        let keep = 1.0 - opacity * outside;
+        pixel[0] = (pixel[0] as f32 * keep) as u8;
+        pixel[1] = (pixel[1] as f32 * keep) as u8;
+        pixel[2] = (pixel[2] as f32 * keep) as u8;
+    }
+}
+
+/// Draw a fading cursor trail through `points` (normalized 0-1, oldest first).
+///
+/// Opacity rises from 0 at the tail to `opacity` at the newest point along
+/// `progress^falloff`. Coverage is accumulated per pixel (max of all segments)
+/// and blended once, so segment joints don't double up.
+pub fn draw_cursor_trail(
+    frame_data: &mut [u8],
+    frame_width: u32,
+    frame_height: u32,
+    points: &[(f32, f32)],
+    color: [u8; 3],
+    opacity: f32,
+    falloff: f32,
+    line_width: f32,
+) {
+    if points.len() < 2 || opacity <= 0.0 || frame_width == 0 || frame_height == 0 {
+        return;
+    }
+
+    let half_width = (line_width / 2.0).max(0.5);
+    let pixels: Vec<(f32, f32)> = points
+        .iter()
+        .map(|&(x, y)| (x * frame_width as f32, y * frame_height as f32))
+        .collect();
+
+    // Bounding box of the whole trail, clamped to the frame
+    let (mut min_x, mut min_y, mut max_x, mut max_y) = (f32::MAX, f32::MAX, f32::MIN, f32::MIN);
+    for &(x, y) in &pixels {
+        min_x = min_x.min(x);
+        min_y = min_y.min(y);
+        max_x = max_x.max(x);
+        max_y = max_y.max(y);
+    }
+    let box_x0 = ((min_x - half_width - 1.0).floor() as i32).max(0);
+    let box_y0 = ((min_y - half_width - 1.0).floor() as i32).max(0);
+    let box_x1 = ((max_x + half_width + 1.0).ceil() as i32).min(frame_width as i32 - 1);
+    let box_y1 = ((max_y + half_width + 1.0).ceil() as i32).min(frame_height as i32 - 1);
+    if box_x0 > box_x1 || box_y0 > box_y1 {
+        return;
+    }
+
+    let box_w = (box_x1 - box_x0 + 1) as usize;
+    let box_h = (box_y1 - box_y0 + 1) as usize;
+    let mut coverage = vec![0.0f32; box_w * box_h];
+
+    let segments = pixels.len() - 1;
+    for (i, pair) in pixels.windows(2).enumerate() {
+        let (ax, ay) = pair[0];
+        let (bx, by) = pair[1];
+        let progress = (i + 1) as f32 / segments as f32;
+        let alpha = opacity * progress.powf(falloff.max(0.0));
+
+        let seg_x0 = ((ax.min(bx) - half_width - 1.0).floor() as i32).max(box_x0);
+        let seg_x1 = ((ax.max(bx) + half_width + 1.0).ceil() as i32).min(box_x1);
+        let seg_y0 = ((ay.min(by) - half_width - 1.0).floor() as i32).max(box_y0);
+        let seg_y1 = ((ay.max(by) + half_width + 1.0).ceil() as i32).min(box_y1);
+
+        let (dx, dy) = (bx - ax, by - ay);
+        let len_sq = dx * dx + dy * dy;
+
+        for y in seg_y0..=seg_y1 {
+            for x in seg_x0..=seg_x1 {
+                // Distance from the pixel center to the segment
+                let (px, py) = (x as f32 + 0.5 - ax, y as f32 + 0.5 - ay);
+                let t = if len_sq > 0.0 {
+                    ((px * dx + py * dy) / len_sq).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                let dist = ((px - dx * t).powi(2) + (py - dy * t).powi(2)).sqrt();
+
+                // Anti-aliased edge
+                let edge = (half_width + 0.5 - dist).clamp(0.0, 1.0);
+                if edge <= 0.0 {
+                    continue;
+                }
+
+                let cell = (y - box_y0) as usize * box_w + (x - box_x0) as usize;
+                coverage[cell] = coverage[cell].max(alpha * edge);
+            }
+        }
+    }
+
+    for row in 0..box_h {
+        for col in 0..box_w {
+            let alpha = coverage[row * box_w + col];
+            if alpha <= 0.0 {
+                continue;
+            }
+
+            let x = box_x0 as usize + col;
+            let y = box_y0 as usize + row;
+            let idx = (y * frame_width as usize + x) * 4;
+            if idx + 3 >= frame_data.len() {
+                continue;
+            }
+
+            let inv_alpha = 1.0 - alpha;
+            for (c, &channel) in color.iter().enumerate() {
+                frame_data[idx + c] =
+                    ((channel as f32 * alpha) + (frame_data[idx + c] as f32 * inv_alpha)) as u8;
+            }
+        }
+    }
+}
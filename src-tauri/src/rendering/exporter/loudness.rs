@@ -0,0 +1,136 @@
+//! Two-pass EBU R128 loudness normalization with FFmpeg's `loudnorm`.
+//!
+//! Pass 1 runs the audio mix through `loudnorm` with `print_format=json` and
+//! discards the output, which yields the measured loudness of the mix. Pass 2
+//! (the real encode) feeds those measurements back so `loudnorm` can apply a
+//! single linear gain that lands on the target, instead of the dynamic mode
+//! single-pass `loudnorm` falls back to.
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+/// True peak ceiling in dBTP.
+const TRUE_PEAK_DB: f32 = -1.5;
+
+/// Loudness range target in LU.
+const LOUDNESS_RANGE_LU: f32 = 11.0;
+
+/// Measurements printed by `loudnorm` with `print_format=json`.
+///
+/// FFmpeg prints every value as a JSON string.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LoudnessMeasurement {
+    pub input_i: String,
+    pub input_tp: String,
+    pub input_lra: String,
+    pub input_thresh: String,
+    #[serde(default)]
+    pub output_i: Option<String>,
+    pub target_offset: String,
+}
+
+/// `loudnorm` filter for `target_lufs`.
+///
+/// Without a measurement this is the analysis pass; with one it is the
+/// linear correction pass. Both print their stats as JSON on stderr.
+pub fn loudnorm_filter(target_lufs: f32, measured: Option<&LoudnessMeasurement>) -> String {
+    let base = format!(
+        "loudnorm=I={:.1}:TP={:.1}:LRA={:.1}",
+        target_lufs, TRUE_PEAK_DB, LOUDNESS_RANGE_LU
+    );
+
+    match measured {
+        Some(m) => format!(
+            "{}:measured_I={}:measured_TP={}:measured_LRA={}:measured_thresh={}:offset={}:linear=true:print_format=json",
+            base, m.input_i, m.input_tp, m.input_lra, m.input_thresh, m.target_offset
+        ),
+        None => format!("{}:print_format=json", base),
+    }
+}
+
+/// Extract the last `loudnorm` JSON block from FFmpeg's stderr.
+pub fn parse_loudnorm_output(stderr: &str) -> Option<LoudnessMeasurement> {
+    let start = stderr.rfind('{')?;
+    let end = stderr[start..].find('}')? + start;
+    serde_json::from_str(&stderr[start..=end]).ok()
+}
+
+/// Run the analysis pass over the audio inputs.
+///
+/// `input_args` are the `-i` arguments for the audio files (indexed from 0) and
+/// `filter` is the mix graph ending in the analysis `loudnorm` and `[aout]`.
+pub fn measure_loudness(
+    ffmpeg_path: &PathBuf,
+    input_args: &[String],
+    filter: &str,
+) -> Result<LoudnessMeasurement, String> {
+    let output = crate::commands::storage::ffmpeg::create_hidden_command(ffmpeg_path)
+        .args(["-hide_banner", "-nostats"])
+        .args(input_args)
+        .args([
+            "-filter_complex",
+            filter,
+            "-map",
+            "[aout]",
+            "-f",
+            "null",
+            "-",
+        ])
+        .output()
+        .map_err(|e| format!("Failed to run loudness analysis: {}", e))?;
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !output.status.success() {
+        return Err(format!("Loudness analysis failed: {}", stderr.trim()));
+    }
+
+    parse_loudnorm_output(&stderr)
+        .ok_or_else(|| "Loudness analysis produced no measurement".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_OUTPUT: &str = r#"
+[Parsed_loudnorm_0 @ 000001]
+{
+	"input_i" : "-27.61",
+	"input_tp" : "-4.47",
+	"input_lra" : "18.06",
+	"input_thresh" : "-39.20",
+	"output_i" : "-16.58",
+	"output_tp" : "-1.50",
+	"output_lra" : "14.78",
+	"output_thresh" : "-27.71",
+	"normalization_type" : "dynamic",
+	"target_offset" : "0.58"
+}
+"#;
+
+    #[test]
+    fn test_parse_loudnorm_output() {
+        let measured = parse_loudnorm_output(SAMPLE_OUTPUT).unwrap();
+        assert_eq!(measured.input_i, "-27.61");
+        assert_eq!(measured.input_thresh, "-39.20");
+        assert_eq!(measured.output_i.as_deref(), Some("-16.58"));
+        assert_eq!(measured.target_offset, "0.58");
+
+        assert!(parse_loudnorm_output("no json here").is_none());
+    }
+
+    #[test]
+    fn test_loudnorm_filter_passes() {
+        assert_eq!(
+            loudnorm_filter(-14.0, None),
+            "loudnorm=I=-14.0:TP=-1.5:LRA=11.0:print_format=json"
+        );
+
+        let measured = parse_loudnorm_output(SAMPLE_OUTPUT).unwrap();
+        assert_eq!(
+            loudnorm_filter(-16.0, Some(&measured)),
+            "loudnorm=I=-16.0:TP=-1.5:LRA=11.0:measured_I=-27.61:measured_TP=-4.47:measured_LRA=18.06:measured_thresh=-39.20:offset=0.58:linear=true:print_format=json"
+        );
+    }
+}
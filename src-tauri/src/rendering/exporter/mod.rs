@@ -5,36 +5,50 @@
 //! 2. Render on GPU with zoom/webcam effects
 //! 3. Pipe rendered RGBA frames to FFmpeg for encoding only
 
+mod click_sound;
 mod encoder_selection;
 mod ffmpeg;
 mod frame_ops;
+mod loudness;
 mod pipeline;
+mod png_sequence;
+mod resume;
 mod webcam;
 
-pub use encoder_selection::is_nvenc_available;
-use pipeline::{spawn_decode_task, spawn_encode_task};
+pub use encoder_selection::{detect_encoder_capabilities, is_nvenc_available, EncoderCapabilities};
+use pipeline::{spawn_decode_task, spawn_encode_task, ThroughputTracker};
+use png_sequence::{sequence_dir, sequence_size_bytes, spawn_png_sequence_task};
+use resume::{spawn_chunked_encode_task, ResumeState};
 
 #[cfg(test)]
 mod tests;
 
+use std::collections::VecDeque;
 use std::path::{Path, PathBuf};
+use std::process::Child;
+use std::sync::Arc;
 
 use tauri::{AppHandle, Manager};
+use tokio::sync::mpsc;
 
 use super::compositor::Compositor;
-use super::cursor::{composite_cursor, CursorInterpolator};
-use super::renderer::Renderer;
+use super::cursor::{composite_cursor, CursorInterpolator, CursorSprings};
+use super::keystroke::KeystrokeOverlay;
+use super::renderer::{PendingReadback, ReadbackRing, Renderer};
 use super::scene::SceneInterpolator;
-use super::stream_decoder::StreamDecoder;
+use super::stream_decoder::SegmentedDecoder;
 use super::svg_cursor::render_svg_cursor_to_height;
-use super::text::prepare_texts;
+use super::text::{parse_color, prepare_texts};
+use super::timecode::TimecodeOverlay;
 use super::types::{BackgroundStyle, RenderOptions};
-use super::zoom::ZoomInterpolator;
+use super::zoom::{CursorFollowZoom, ZoomInterpolator};
 use crate::commands::video_recording::cursor::events::load_cursor_recording;
+use crate::commands::video_recording::keystrokes::KeystrokeRecording;
 use crate::commands::video_recording::video_export::{ExportResult, ExportStage};
 use crate::commands::video_recording::video_project::XY;
 use crate::commands::video_recording::video_project::{
-    CompositionMode, CursorType, SceneMode, VideoProject,
+    ClickHighlightStyle, CompositionMode, CursorType, ExportFormat, PortraitFraming, SceneMode,
+    VideoProject,
 };
 
 // Re-export submodule functions used externally
@@ -42,9 +56,28 @@ pub use ffmpeg::emit_progress;
 pub use frame_ops::draw_cursor_circle;
 pub use webcam::build_webcam_overlay;
 
-use ffmpeg::start_ffmpeg_encoder;
-use frame_ops::{blend_frames_alpha, crop_decoded_frame, scale_frame_to_fill};
-use webcam::is_webcam_visible_at;
+use ffmpeg::{
+    emit_render_progress, export_video_encoder, measure_export_loudness, mux_chunks,
+    start_ffmpeg_encoder,
+};
+use frame_ops::{
+    blend_frames_alpha, crop_decoded_frame, draw_click_spotlight, draw_cursor_trail,
+    scale_frame_to_fill,
+};
+use webcam::build_webcam_overlays;
+
+/// Composition height that cursor sizes are specified against.
+const CURSOR_REFERENCE_HEIGHT: f32 = 720.0;
+
+/// Cursor trail sampling interval (ms), several samples per frame at 60fps.
+const CURSOR_TRAIL_SAMPLE_MS: u64 = 4;
+
+/// Shorter composition side that click highlight radii are specified against
+/// (matches the editor preview).
+const CLICK_HIGHLIGHT_REFERENCE_SIZE: f32 = 1080.0;
+
+/// How dark a click spotlight makes the rest of the frame at the moment of the click.
+const SPOTLIGHT_DIM_OPACITY: f32 = 0.6;
 
 /// Export a video project using GPU rendering.
 ///
@@ -77,16 +110,19 @@ pub async fn export_video_gpu(
     let fps = project.export.fps;
     let original_width = project.sources.original_width;
     let original_height = project.sources.original_height;
-    let in_point_ms = project.timeline.in_point;
-    let out_point_ms = project.timeline.out_point;
-    let duration_ms = out_point_ms - in_point_ms;
-    let duration_secs = duration_ms as f64 / 1000.0;
-    let total_frames = ((duration_ms as f64 / 1000.0) * fps as f64).ceil() as u32;
+    // Source ranges kept in the export (a single in/out range unless cut) and
+    // the source time of every exported frame along the speed curve
+    let kept_ranges = project.timeline.kept_ranges();
+    let in_point_ms = kept_ranges.first().map_or(0, |range| range.start_ms);
+    let frame_times = project.timeline.frame_source_times(fps);
+    let duration_secs = project.timeline.output_duration_ms() / 1000.0;
+    let total_frames = frame_times.len() as u32;
 
     // Clone configs to avoid borrow issues with project
     let crop = project.export.crop.clone();
     let composition = project.export.composition.clone();
     let padding = project.export.background.padding as u32;
+    let portrait_framing = project.export.portrait_framing;
 
     // Step 1: Determine video dimensions after crop
     let crop_enabled = crop.enabled && crop.width > 0 && crop.height > 0;
@@ -137,6 +173,12 @@ pub async fn export_video_gpu(
                     let h = video_h + padding * 2;
                     let w = (h as f32 * target_ratio) as u32;
                     (w, h)
+                } else if portrait_framing != PortraitFraming::Fit {
+                    // Composition is taller than video and the video is cropped to fill it -
+                    // video height determines composition height to keep source resolution
+                    let h = video_h + padding * 2;
+                    let w = (h as f32 * target_ratio) as u32;
+                    (w, h)
                 } else {
                     // Composition is taller than video - video width determines composition width
                     // Add padding to video, then calculate height from aspect ratio
@@ -179,19 +221,50 @@ pub async fn export_video_gpu(
     let out_w = composition_w;
     let out_h = composition_h;
 
-    // Initialize streaming decoders (ONE FFmpeg process each!)
+    // Resumable exports pick up after the last finished chunk
+    let resume = if project.export.resumable_output() {
+        let dir = resume::work_dir(&output_path);
+        let fresh = ResumeState::new(resume::project_hash(&project), out_w, out_h, fps);
+        Some((ResumeState::load_or_start(&dir, fresh)?, dir))
+    } else {
+        None
+    };
+    let first_frame = resume
+        .as_ref()
+        .map_or(0, |(state, _)| state.completed_frames().min(total_frames));
+    // Screen frames follow the timeline except where a hold freezes them
+    let screen_times: Vec<u64> = frame_times
+        .iter()
+        .map(|&source_ms| project.timeline.held_source_ms(source_ms))
+        .collect();
+    let decode_ranges = frame_times
+        .get(first_frame as usize)
+        .map_or_else(Vec::new, |&source_ms| {
+            project.timeline.ranges_from(source_ms)
+        });
+    let screen_ranges = screen_times
+        .get(first_frame as usize)
+        .map_or_else(Vec::new, |&source_ms| {
+            project.timeline.ranges_from(source_ms)
+        });
+    if kept_ranges.len() > 1 {
+        log::info!(
+            "[EXPORT] Timeline cut into {} kept ranges: {:?}",
+            kept_ranges.len(),
+            kept_ranges
+        );
+    }
+
+    // Initialize streaming decoders (one FFmpeg process per kept range)
     let screen_path = Path::new(&project.sources.screen_video);
-    let mut screen_decoder = StreamDecoder::new(screen_path, in_point_ms, out_point_ms)?;
-    screen_decoder.start(screen_path)?;
+    let screen_decoder = SegmentedDecoder::new(screen_path, screen_ranges);
 
     // Webcam decoder if enabled
     let webcam_decoder = if project.webcam.enabled {
         if let Some(ref path) = project.sources.webcam_video {
             let webcam_path = Path::new(path);
             if webcam_path.exists() {
-                let mut decoder = StreamDecoder::new(webcam_path, in_point_ms, out_point_ms)?;
-                decoder.start(webcam_path)?;
-                Some(decoder)
+                Some(SegmentedDecoder::new(webcam_path, decode_ranges))
             } else {
                 None
             }
@@ -205,8 +278,13 @@ pub async fn export_video_gpu(
     let has_webcam = webcam_decoder.is_some();
 
     // Spawn decode task for pipeline parallelism
-    let (mut decode_rx, decode_handle) =
-        spawn_decode_task(screen_decoder, webcam_decoder, total_frames);
+    let (mut decode_rx, decode_handle) = spawn_decode_task(
+        screen_decoder,
+        webcam_decoder,
+        first_frame,
+        frame_times[first_frame as usize..].to_vec(),
+        screen_times[first_frame as usize..].to_vec(),
+    );
 
     log::info!(
         "[EXPORT] GPU export (streaming): {}x{} @ {}fps, {} frames, webcam={}",
@@ -241,14 +319,90 @@ pub async fn export_video_gpu(
         project.zoom.regions.len()
     );
 
-    emit_progress(&app, 0.05, ExportStage::Encoding, "Starting encoder...");
+    // Render the click sound track (removed again when this export ends)
+    let click_track = click_sound::prepare_click_track(&project);
+    let click_track_path = click_track.as_ref().map(|track| track.path().to_path_buf());
+
+    // Measure the audio mix first so loudness normalization can run two-pass
+    let loudness = if project.audio.normalize_output && project.export.format.has_audio() {
+        emit_progress(&app, 0.04, ExportStage::Preparing, "Measuring loudness...");
+        let measure_project = project.clone();
+        let measure_click_track = click_track_path.clone();
+        match tokio::task::spawn_blocking(move || {
+            measure_export_loudness(&measure_project, measure_click_track.as_deref())
+        })
+        .await
+        {
+            Ok(Ok(measured)) => measured,
+            Ok(Err(e)) => {
+                log::warn!("[EXPORT] {}, normalizing in a single pass", e);
+                None
+            },
+            Err(e) => {
+                log::warn!("[EXPORT] Loudness analysis task failed: {:?}", e);
+                None
+            },
+        }
+    } else {
+        None
+    };
 
-    // Start FFmpeg encoder (takes raw RGBA from stdin)
-    let mut ffmpeg = start_ffmpeg_encoder(&project, &output_path, out_w, out_h, fps)?;
-    let stdin = ffmpeg.stdin.take().ok_or("Failed to get FFmpeg stdin")?;
+    emit_progress(&app, 0.05, ExportStage::Encoding, "Starting encoder...");
 
-    // Spawn encode task for pipeline parallelism
-    let (encode_tx, encode_handle) = spawn_encode_task(stdin);
+    // PNG sequences and resumable exports swap the single FFmpeg encoder for
+    // another sink; the render pipeline feeding it is the same
+    let (sink, output_path, encode_tx, encode_handle) = if let Some((state, dir)) = resume {
+        log::info!("[EXPORT] Encoding resumable chunks in {}", dir.display());
+        let (encode_tx, encode_handle) =
+            spawn_chunked_encode_task(project.clone(), dir.clone(), state);
+        (
+            FrameSink::Chunks { dir },
+            output_path,
+            encode_tx,
+            encode_handle,
+        )
+    } else if project.export.format == ExportFormat::PngSequence {
+        let frames_dir = sequence_dir(&output_path);
+        log::info!("[EXPORT] Writing PNG sequence to {}", frames_dir.display());
+        let (encode_tx, encode_handle) =
+            spawn_png_sequence_task(frames_dir.clone(), out_w, out_h, fps);
+        (FrameSink::PngSequence, frames_dir, encode_tx, encode_handle)
+    } else {
+        // Start FFmpeg encoder (takes raw RGBA from stdin)
+        let mut process = start_ffmpeg_encoder(
+            &project,
+            &output_path,
+            out_w,
+            out_h,
+            fps,
+            loudness.as_ref(),
+            click_track_path.as_deref(),
+            false,
+        )?;
+        let stdin = process.stdin.take().ok_or("Failed to get FFmpeg stdin")?;
+
+        // Drain stderr so FFmpeg never blocks on a full pipe; kept for the
+        // loudness report once encoding finishes
+        let stderr_reader = process.stderr.take().map(|mut stderr| {
+            std::thread::spawn(move || {
+                let mut output = String::new();
+                let _ = std::io::Read::read_to_string(&mut stderr, &mut output);
+                output
+            })
+        });
+
+        // Spawn encode task for pipeline parallelism
+        let (encode_tx, encode_handle) = spawn_encode_task(stdin);
+        (
+            FrameSink::Ffmpeg {
+                process,
+                stderr_reader,
+            },
+            output_path,
+            encode_tx,
+            encode_handle,
+        )
+    };
 
     // NOTE: Auto zoom generation is disabled. Users must explicitly add zoom regions.
     // The zoom mode in project.zoom.mode is used to control how existing regions behave,
@@ -257,12 +411,40 @@ pub async fn export_video_gpu(
 
     // Create zoom interpolator
     let zoom_interpolator = ZoomInterpolator::new(&project.zoom);
+    let mut cursor_follow = CursorFollowZoom::from_config(&project.zoom);
 
     // Create scene interpolator for smooth scene transitions
     let scene_interpolator = SceneInterpolator::new(project.scene.segments.clone());
 
-    // Load cursor recording and create interpolator if cursor is visible
-    let cursor_interpolator = if project.cursor.visible {
+    // Burned-in timecode (off unless configured)
+    let timecode_overlay = project
+        .export
+        .timecode
+        .as_ref()
+        .map(|config| TimecodeOverlay::new(config, &project));
+
+    // Recorded key presses (off unless enabled and the sidecar exists)
+    let keystroke_overlay = project
+        .sources
+        .keystrokes
+        .as_ref()
+        .filter(|_| project.keystrokes.enabled)
+        .and_then(|path| match KeystrokeRecording::load(Path::new(path)) {
+            Ok(recording) => {
+                log::info!("[EXPORT] Loaded {} key presses", recording.events.len());
+                Some(KeystrokeOverlay::new(&project.keystrokes, recording))
+            },
+            Err(e) => {
+                log::warn!("[EXPORT] {}, skipping keystroke overlay", e);
+                None
+            },
+        });
+
+    // Load cursor recording and create interpolator if cursor is visible or zoom/framing follows it
+    let cursor_interpolator = if project.cursor.visible
+        || cursor_follow.is_some()
+        || portrait_framing == PortraitFraming::FollowCursor
+    {
         if let Some(ref cursor_data_path) = project.sources.cursor_data {
             let cursor_path = std::path::Path::new(cursor_data_path);
             if cursor_path.exists() {
@@ -283,7 +465,13 @@ pub async fn export_video_gpu(
                                 img.height
                             );
                         }
-                        Some(CursorInterpolator::new(&recording))
+                        Some(CursorInterpolator::with_springs(
+                            &recording,
+                            &CursorSprings::from_project(
+                                &project.cursor,
+                                &project.cursor_smoothing_segments,
+                            ),
+                        ))
                     },
                     Err(e) => {
                         log::warn!("[EXPORT] Failed to load cursor recording: {}", e);
@@ -302,8 +490,19 @@ pub async fn export_video_gpu(
         None
     };
 
+    // Webcam placements composited over each frame
+    let webcam_instances = project.webcam.overlay_instances();
+
     emit_progress(&app, 0.08, ExportStage::Encoding, "Rendering frames...");
 
+    // Output read-backs in flight, oldest first
+    let mut readback_ring =
+        renderer.create_readback_ring(composition_w, composition_h, READBACK_RING_SIZE);
+    let mut in_flight: VecDeque<InFlightFrame> = VecDeque::with_capacity(READBACK_RING_SIZE);
+
+    let render_start = std::time::Instant::now();
+    let mut throughput = ThroughputTracker::new(first_frame, total_frames);
+
     // Render frames from decode pipeline, send to encode pipeline
     while let Some(bundle) = decode_rx.recv().await {
         let frame_idx = bundle.frame_idx;
@@ -311,25 +510,32 @@ pub async fn export_video_gpu(
 
         // Apply video crop to screen frame BEFORE composition
         let screen_frame = if crop_enabled {
-            crop_decoded_frame(
+            Arc::new(crop_decoded_frame(
                 &bundle.screen_frame,
                 crop.x,
                 crop.y,
                 crop.width,
                 crop.height,
-            )
+            ))
         } else {
             bundle.screen_frame
         };
 
-        // Calculate relative timestamp (position in trimmed video = what timeline shows)
-        // Scene segments, zoom regions, and visibility all use timeline-relative time
-        let relative_time_ms = ((frame_idx as f64 / fps as f64) * 1000.0) as u64;
+        let FrameTimes {
+            output_time_ms,
+            relative_time_ms,
+            screen_time_ms,
+        } = FrameTimes::at(frame_idx, fps, &frame_times, &screen_times, in_point_ms);
 
         // Scene segments and zoom regions use RELATIVE time (timeline position)
-        let zoom_state = zoom_interpolator.get_zoom_at(relative_time_ms);
+        let zoom_state = match (cursor_follow.as_mut(), cursor_interpolator.as_ref()) {
+            (Some(follow), Some(cursor_interp)) => {
+                let cursor = cursor_interp.get_cursor_at(screen_time_ms);
+                follow.update(relative_time_ms, (cursor.x as f64, cursor.y as f64))
+            },
+            _ => zoom_interpolator.get_zoom_at(relative_time_ms),
+        };
         let interpolated_scene = scene_interpolator.get_scene_at(relative_time_ms);
-        let webcam_visible = is_webcam_visible_at(&project, relative_time_ms);
 
         // Log first few frames for debugging
         if frame_idx < 3 || (6000..=6200).contains(&relative_time_ms) {
@@ -360,14 +566,14 @@ pub async fn export_video_gpu(
         // Note: Camera-only blending uses video dimensions (not output dimensions with padding)
         // because screen_frame comes from decoder at video dimensions. The compositor will
         // add background/padding around the blended result.
-        let (frame_to_render, webcam_overlay) = if camera_only_opacity > 0.99 {
+        let (frame_to_render, webcam_overlays) = if camera_only_opacity > 0.99 {
             // Fully in cameraOnly mode - just show fullscreen webcam
             // Scale to video dimensions since compositor will add padding
             if let Some(ref webcam_frame) = current_webcam_frame {
                 let scaled_frame = scale_frame_to_fill(webcam_frame, video_w, video_h);
-                (scaled_frame, None)
+                (Arc::new(scaled_frame), Vec::new())
             } else {
-                (screen_frame.clone(), None)
+                (Arc::clone(&screen_frame), Vec::new())
             }
         } else if camera_only_opacity > 0.01 {
             // In cameraOnly transition - blend screen and fullscreen webcam
@@ -376,9 +582,9 @@ pub async fn export_video_gpu(
                 let mut blended_frame = if interpolated_scene.screen_blur > 0.01 {
                     // Note: GPU blur would be better, but for now we skip CPU blur
                     // The screen will still fade out via opacity blending
-                    screen_frame.clone()
+                    (*screen_frame).clone()
                 } else {
-                    screen_frame.clone()
+                    (*screen_frame).clone()
                 };
 
                 // Scale webcam to fill video area (matches screen_frame dimensions)
@@ -391,58 +597,69 @@ pub async fn export_video_gpu(
                     camera_only_opacity as f32,
                 );
 
-                // Regular webcam overlay during transition (fades at 1.5x speed)
-                let overlay = if regular_camera_opacity > 0.01 && webcam_visible {
-                    let mut overlay = build_webcam_overlay(
+                // Regular webcam overlays during transition (fades at 1.5x speed)
+                let mut overlays = if regular_camera_opacity > 0.01 {
+                    build_webcam_overlays(
                         &project,
-                        webcam_frame.clone(),
+                        &webcam_instances,
+                        webcam_frame,
+                        relative_time_ms,
                         composition_w,
                         composition_h,
-                    );
-                    // Apply the transition opacity to the overlay
-                    overlay.shadow_opacity *= regular_camera_opacity as f32;
-                    Some(overlay)
+                    )
                 } else {
-                    None
+                    Vec::new()
                 };
+                // Apply the transition opacity to the overlays
+                for overlay in &mut overlays {
+                    overlay.shadow_opacity *= regular_camera_opacity as f32;
+                }
 
-                (blended_frame, overlay)
+                (Arc::new(blended_frame), overlays)
             } else {
                 // No webcam available
-                (screen_frame.clone(), None)
+                (Arc::clone(&screen_frame), Vec::new())
             }
         } else {
             // Not in cameraOnly transition - normal rendering
             match interpolated_scene.scene_mode {
                 SceneMode::ScreenOnly => {
                     // Screen only - no webcam overlay
-                    (screen_frame.clone(), None)
+                    (Arc::clone(&screen_frame), Vec::new())
                 },
                 _ => {
-                    // Default mode - screen with webcam overlay (if visible)
-                    let overlay = if webcam_visible && regular_camera_opacity > 0.01 {
-                        current_webcam_frame.as_ref().map(|frame| {
-                            build_webcam_overlay(
-                                &project,
-                                frame.clone(),
-                                composition_w,
-                                composition_h,
-                            )
-                        })
-                    } else {
-                        None
+                    // Default mode - screen with webcam overlays (if visible)
+                    let overlays = match current_webcam_frame {
+                        Some(ref frame) if regular_camera_opacity > 0.01 => build_webcam_overlays(
+                            &project,
+                            &webcam_instances,
+                            frame,
+                            relative_time_ms,
+                            composition_w,
+                            composition_h,
+                        ),
+                        _ => Vec::new(),
                     };
-                    (screen_frame.clone(), overlay)
+                    (Arc::clone(&screen_frame), overlays)
                 },
             }
         };
 
         // Convert background config to rendering style
-        let background_style =
-            BackgroundStyle::from_config(&project.export.background, resource_dir.as_deref());
+        let background_style = BackgroundStyle::from_config(
+            &project.export.background,
+            resource_dir.as_deref(),
+            project.export.transparent_output(),
+        );
 
         // Log background config on first frame
         if frame_idx == 0 {
+            if project.export.transparent_background && !project.export.transparent_output() {
+                log::warn!(
+                    "[EXPORT] Transparent background needs an alpha format (MOV, PNG sequence), {:?} is opaque",
+                    project.export.format
+                );
+            }
             log::info!(
                 "[EXPORT] Background: type={:?}, padding={}, rounding={}",
                 background_style.background_type,
@@ -451,23 +668,55 @@ pub async fn export_video_gpu(
             );
         }
 
+        // Point kept in view when the screen content is cropped to fill a portrait frame
+        let fill_focus = match portrait_framing {
+            PortraitFraming::Fit => None,
+            PortraitFraming::Center => Some([0.5, 0.5]),
+            PortraitFraming::FollowZoom => Some([zoom_state.center_x, zoom_state.center_y]),
+            PortraitFraming::FollowCursor => Some(
+                cursor_interpolator
+                    .as_ref()
+                    .map(|interp| {
+                        let cursor = interp.get_cursor_at(screen_time_ms);
+                        [cursor.x, cursor.y]
+                    })
+                    .unwrap_or([0.5, 0.5]),
+            ),
+        };
+
         let render_options = RenderOptions {
             output_width: composition_w,
             output_height: composition_h,
             zoom: zoom_state,
-            webcam: webcam_overlay,
+            webcams: webcam_overlays,
             cursor: None,
             background: background_style,
+            fill_focus,
         };
 
         // Prepare text overlays for this frame
         // Time is in seconds, output_size uses XY struct
         let frame_time_secs = relative_time_ms as f64 / 1000.0;
-        let prepared_texts = prepare_texts(
+        let mut prepared_texts = prepare_texts(
             XY::new(composition_w, composition_h),
             frame_time_secs,
             &project.text.segments,
         );
+        if let Some(ref timecode) = timecode_overlay {
+            prepared_texts.push(timecode.prepare(
+                XY::new(composition_w, composition_h),
+                padding as f32,
+                output_time_ms,
+                relative_time_ms,
+            ));
+        }
+        if let Some(ref keystrokes) = keystroke_overlay {
+            prepared_texts.extend(keystrokes.prepare(
+                XY::new(composition_w, composition_h),
+                padding as f32,
+                screen_time_ms,
+            ));
+        }
 
         // Render frame on GPU (with text overlays)
         let output_texture = compositor
@@ -480,112 +729,64 @@ pub async fn export_video_gpu(
             )
             .await;
 
-        // Read rendered frame back to CPU (at composition size, before crop)
-        let mut rgba_data = renderer
-            .read_texture(&output_texture, composition_w, composition_h)
-            .await;
-
-        // Composite cursor onto frame (CPU-based) if cursor is visible and not in cameraOnly mode
-        if let Some(ref cursor_interp) = cursor_interpolator {
-            // Only show cursor when screen is visible (not in cameraOnly mode)
-            if camera_only_opacity < 0.99 {
-                let cursor = cursor_interp.get_cursor_at(relative_time_ms);
-
-                // Get cursor image based on cursor type
-                if project.cursor.cursor_type == CursorType::Circle {
-                    // Draw circle indicator instead of actual cursor
-                    draw_cursor_circle(
-                        &mut rgba_data,
-                        composition_w,
-                        composition_h,
-                        cursor.x,
-                        cursor.y,
-                        project.cursor.scale,
-                    );
-                } else {
-                    // Priority: SVG cursor (if shape detected) > Bitmap cursor (fallback)
-                    // This matches Cap's approach for consistent, resolution-independent cursors.
-                    let mut rendered = false;
-
-                    // Calculate cursor scale relative to composition size
-                    // Base cursor is 24px (same as editor DEFAULT_CURSOR_SIZE)
-                    // Scale relative to 720p reference so cursor looks proportional
-                    let base_cursor_height = 24.0;
-                    let reference_height = 720.0;
-                    let size_scale = composition_h as f32 / reference_height;
-                    let final_cursor_height =
-                        base_cursor_height * size_scale * project.cursor.scale;
-                    let final_cursor_height = final_cursor_height.clamp(16.0, 256.0);
-
-                    // Try SVG cursor first (if shape is detected)
-                    if let Some(shape) = cursor.cursor_shape {
-                        // Render SVG at final cursor height (handles any original SVG size)
-                        let target_height = final_cursor_height.round() as u32;
-
-                        if let Some(svg_cursor) = render_svg_cursor_to_height(shape, target_height)
-                        {
-                            let svg_decoded = super::cursor::DecodedCursorImage {
-                                width: svg_cursor.width,
-                                height: svg_cursor.height,
-                                hotspot_x: svg_cursor.hotspot_x,
-                                hotspot_y: svg_cursor.hotspot_y,
-                                data: svg_cursor.data,
-                            };
-                            // Pass 1.0 as base_scale since SVG is already at final size
-                            // cursor.scale (click animation) is applied internally
-                            composite_cursor(
-                                &mut rgba_data,
-                                composition_w,
-                                composition_h,
-                                &cursor,
-                                &svg_decoded,
-                                1.0,
-                            );
-                            rendered = true;
-                        }
-                    }
-
-                    // Fall back to bitmap cursor if SVG not available
-                    if !rendered {
-                        if let Some(ref cursor_id) = cursor.cursor_id {
-                            if let Some(cursor_image) = cursor_interp.get_cursor_image(cursor_id) {
-                                // For bitmap, apply the full scale factor
-                                let bitmap_scale = final_cursor_height / cursor_image.height as f32;
-                                composite_cursor(
-                                    &mut rgba_data,
-                                    composition_w,
-                                    composition_h,
-                                    &cursor,
-                                    cursor_image,
-                                    bitmap_scale,
-                                );
-                            }
-                        }
-                    }
+        // Read the frame back through the ring: once it is full, finish the
+        // oldest read-back (frames stay in order) before queueing this one, so
+        // copies overlap rendering of the following frames
+        if in_flight.len() == READBACK_RING_SIZE {
+            if let Some(frame) = in_flight.pop_front() {
+                if !finish_readback(
+                    &renderer,
+                    &readback_ring,
+                    frame,
+                    &project,
+                    cursor_interpolator.as_ref(),
+                    (composition_w, composition_h),
+                    &encode_tx,
+                )
+                .await
+                {
+                    break;
                 }
             }
         }
-
-        // Send to encode pipeline (async, with backpressure)
-        // Note: Video crop is now applied to input frames, not extracted from output
-        if encode_tx.send(rgba_data).await.is_err() {
-            log::error!("[EXPORT] Encode channel closed unexpectedly");
-            break;
-        }
+        in_flight.push_back(InFlightFrame {
+            readback: readback_ring.start(&renderer, &output_texture),
+            cursor_time_ms: screen_time_ms,
+            camera_only_opacity,
+        });
 
         // Progress update (every 10 frames)
         if frame_idx.is_multiple_of(10) {
             let progress = (frame_idx + 1) as f32 / total_frames as f32;
             let stage_progress = 0.08 + progress * 0.87;
-            emit_progress(
+            let rate = throughput.update(frame_idx + 1, render_start.elapsed().as_secs_f64());
+            emit_render_progress(
                 &app,
                 stage_progress,
-                ExportStage::Encoding,
                 &format!("Rendering: {:.0}%", progress * 100.0),
+                rate.map(|(fps, _)| fps),
+                rate.map(|(_, eta)| eta),
             );
         }
     }
 
+    // Flush the read-backs still in flight
+    while let Some(frame) = in_flight.pop_front() {
+        if !finish_readback(
+            &renderer,
+            &readback_ring,
+            frame,
+            &project,
+            cursor_interpolator.as_ref(),
+            (composition_w, composition_h),
+            &encode_tx,
+        )
+        .await
+        {
+            break;
+        }
+    }
+
     // Signal end of render loop and wait for encode to finish
     drop(encode_tx);
 
@@ -595,37 +796,336 @@ pub async fn export_video_gpu(
     if let Err(e) = decode_handle.await {
         log::warn!("[EXPORT] Decode task join error: {:?}", e);
     }
-    if let Err(e) = encode_handle.await {
-        log::warn!("[EXPORT] Encode task join error: {:?}", e);
-    }
+    let encoded = encode_handle.await;
+
+    let file_size_bytes = match sink {
+        FrameSink::Ffmpeg {
+            mut process,
+            stderr_reader,
+        } => {
+            if let Err(e) = encoded {
+                log::warn!("[EXPORT] Encode task join error: {:?}", e);
+            }
 
-    // Wait for FFmpeg encoder to finish
-    let status = ffmpeg
-        .wait()
-        .map_err(|e| format!("FFmpeg wait failed: {}", e))?;
-    if !status.success() {
-        return Err(format!(
-            "FFmpeg encoding failed with status: {:?}",
-            status.code()
-        ));
-    }
+            // Wait for FFmpeg encoder to finish
+            let status = process
+                .wait()
+                .map_err(|e| format!("FFmpeg wait failed: {}", e))?;
+
+            let encoder_stderr = stderr_reader
+                .and_then(|handle| handle.join().ok())
+                .unwrap_or_default();
+            if project.audio.normalize_output && project.export.format.has_audio() {
+                match loudness::parse_loudnorm_output(&encoder_stderr) {
+                    Some(report) => log::info!(
+                        "[EXPORT] Loudness pass 2: {} LUFS in, {} LUFS out (target {} LUFS)",
+                        report.input_i,
+                        report.output_i.as_deref().unwrap_or("?"),
+                        project.audio.loudness_target_lufs
+                    ),
+                    None => log::warn!("[EXPORT] No loudnorm report in encoder output"),
+                }
+            }
+            if !status.success() {
+                return Err(format!(
+                    "FFmpeg encoding failed with status: {:?}",
+                    status.code()
+                ));
+            }
+
+            std::fs::metadata(&output_path)
+                .map_err(|e| format!("Failed to read output file: {}", e))?
+                .len()
+        },
+        FrameSink::PngSequence => {
+            // Without FFmpeg there is no exit status to report a failed write
+            encoded.map_err(|e| format!("Encode task join error: {:?}", e))??;
 
-    // Get output file info
-    let metadata = std::fs::metadata(&output_path)
-        .map_err(|e| format!("Failed to read output file: {}", e))?;
+            // A sequence reports the size of its directory
+            sequence_size_bytes(&output_path)?
+        },
+        FrameSink::Chunks { dir } => {
+            encoded.map_err(|e| format!("Encode task join error: {:?}", e))??;
+
+            let state = ResumeState::load(&dir).ok_or("Failed to read export progress")?;
+            if state.completed_frames() < total_frames {
+                return Err(format!(
+                    "Export stopped at frame {} of {}; export again to resume",
+                    state.completed_frames(),
+                    total_frames
+                ));
+            }
+
+            // Mix the audio into the finished chunks
+            let concat_list = state.write_concat_list(&dir)?;
+            let mux_project = project.clone();
+            let mux_output = output_path.clone();
+            tokio::task::spawn_blocking(move || {
+                mux_chunks(
+                    &mux_project,
+                    &concat_list,
+                    &mux_output,
+                    loudness.as_ref(),
+                    click_track_path.as_deref(),
+                )
+            })
+            .await
+            .map_err(|e| format!("Chunk mux task failed: {:?}", e))??;
+
+            if let Err(e) = std::fs::remove_dir_all(&dir) {
+                log::warn!("[EXPORT] Failed to remove export work directory: {}", e);
+            }
+
+            std::fs::metadata(&output_path)
+                .map_err(|e| format!("Failed to read output file: {}", e))?
+                .len()
+        },
+    };
 
     emit_progress(&app, 1.0, ExportStage::Complete, "Export complete!");
 
     log::info!(
         "[EXPORT] Complete in {:.1}s: {} bytes",
         start_time.elapsed().as_secs_f32(),
-        metadata.len()
+        file_size_bytes
     );
 
     Ok(ExportResult {
         output_path: output_path.to_string_lossy().to_string(),
         duration_secs,
-        file_size_bytes: metadata.len(),
+        file_size_bytes,
         format: project.export.format,
+        encoder: export_video_encoder(&project),
     })
 }
+
+/// Where rendered frames go once read back.
+enum FrameSink {
+    /// One FFmpeg process encoding the whole export.
+    Ffmpeg {
+        process: Child,
+        stderr_reader: Option<std::thread::JoinHandle<String>>,
+    },
+    /// Numbered PNGs written without FFmpeg.
+    PngSequence,
+    /// Resumable chunks in `dir`, muxed with the audio at the end.
+    Chunks { dir: PathBuf },
+}
+
+/// Number of output frames whose GPU read-back may be in flight at once.
+const READBACK_RING_SIZE: usize = 3;
+
+/// Times of one exported frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FrameTimes {
+    /// Position in the output video.
+    output_time_ms: u64,
+    /// Timeline time (source time after the in-point). Scene segments, zoom
+    /// regions, and visibility use it, so it skips over cut ranges and follows
+    /// speed ramps and effects stay on their source frames.
+    relative_time_ms: u64,
+    /// Timeline time of the screen frame shown. Cursor and keystroke overlays
+    /// belong to the screen frame, so they share this time and freeze with holds.
+    screen_time_ms: u64,
+}
+
+impl FrameTimes {
+    /// Times of output frame `frame_idx`, given the source time of every
+    /// exported frame and of the screen frame it shows.
+    fn at(
+        frame_idx: u32,
+        fps: u32,
+        frame_times: &[u64],
+        screen_times: &[u64],
+        in_point_ms: u64,
+    ) -> Self {
+        let output_time_ms = ((frame_idx as f64 / fps as f64) * 1000.0) as u64;
+        let relative_time_ms = frame_times
+            .get(frame_idx as usize)
+            .map_or(output_time_ms, |&source_ms| {
+                source_ms.saturating_sub(in_point_ms)
+            });
+        let screen_time_ms = screen_times
+            .get(frame_idx as usize)
+            .map_or(relative_time_ms, |&source_ms| {
+                source_ms.saturating_sub(in_point_ms)
+            });
+
+        Self {
+            output_time_ms,
+            relative_time_ms,
+            screen_time_ms,
+        }
+    }
+}
+
+/// Rendered frame waiting for its read-back, with the state its CPU passes need.
+struct InFlightFrame {
+    readback: PendingReadback,
+    cursor_time_ms: u64,
+    camera_only_opacity: f64,
+}
+
+/// Finish a frame's read-back, draw the cursor on it and send it to the encoder.
+///
+/// Returns false when the encoder has gone away.
+async fn finish_readback(
+    renderer: &Renderer,
+    ring: &ReadbackRing,
+    frame: InFlightFrame,
+    project: &VideoProject,
+    cursor_interpolator: Option<&CursorInterpolator>,
+    (composition_w, composition_h): (u32, u32),
+    encode_tx: &mpsc::Sender<Vec<u8>>,
+) -> bool {
+    // Read rendered frame back to CPU (at composition size, before crop)
+    let mut rgba_data = ring.finish(renderer, frame.readback).await;
+    draw_cursor_overlay(
+        &mut rgba_data,
+        project,
+        cursor_interpolator,
+        (composition_w, composition_h),
+        frame.cursor_time_ms,
+        frame.camera_only_opacity,
+    );
+
+    // Send to encode pipeline (async, with backpressure)
+    // Note: Video crop is now applied to input frames, not extracted from output
+    if encode_tx.send(rgba_data).await.is_err() {
+        log::error!("[EXPORT] Encode channel closed unexpectedly");
+        return false;
+    }
+    true
+}
+
+/// Composite the cursor trail and cursor onto a read-back frame (CPU-based).
+fn draw_cursor_overlay(
+    rgba_data: &mut [u8],
+    project: &VideoProject,
+    cursor_interpolator: Option<&CursorInterpolator>,
+    (composition_w, composition_h): (u32, u32),
+    cursor_time_ms: u64,
+    camera_only_opacity: f64,
+) {
+    // Composite cursor onto frame (CPU-based) if cursor is visible and not in cameraOnly mode
+    if let Some(cursor_interp) = cursor_interpolator.filter(|_| project.cursor.visible) {
+        // Only show cursor when screen is visible (not in cameraOnly mode)
+        if camera_only_opacity < 0.99 {
+            // Spotlight dims everything but the click, so it goes under the trail and cursor
+            let highlight = &project.cursor.click_highlight;
+            if highlight.enabled && highlight.style == ClickHighlightStyle::Spotlight {
+                if let Some((x, y, progress)) =
+                    cursor_interp.latest_click(cursor_time_ms, highlight.duration_ms as u64)
+                {
+                    let radius = highlight.radius as f32 * composition_w.min(composition_h) as f32
+                        / CLICK_HIGHLIGHT_REFERENCE_SIZE;
+                    // Fades out over the click, and with the screen during a cameraOnly transition
+                    let opacity = SPOTLIGHT_DIM_OPACITY
+                        * (1.0 - progress)
+                        * (1.0 - camera_only_opacity as f32);
+                    draw_click_spotlight(
+                        rgba_data,
+                        composition_w,
+                        composition_h,
+                        x,
+                        y,
+                        radius,
+                        opacity,
+                    );
+                }
+            }
+
+            // Trail goes under the cursor
+            if let Some(ref trail) = project.cursor.cursor_trail {
+                let points = cursor_interp.sample_trail(
+                    cursor_time_ms,
+                    trail.length_ms as u64,
+                    CURSOR_TRAIL_SAMPLE_MS,
+                );
+                let [r, g, b, _] = parse_color(&trail.color);
+                draw_cursor_trail(
+                    rgba_data,
+                    composition_w,
+                    composition_h,
+                    &points,
+                    [(r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8],
+                    trail.opacity,
+                    trail.falloff,
+                    trail.width * composition_h as f32 / CURSOR_REFERENCE_HEIGHT,
+                );
+            }
+
+            let cursor = cursor_interp.get_cursor_at(cursor_time_ms);
+
+            // Get cursor image based on cursor type
+            if project.cursor.cursor_type == CursorType::Circle {
+                // Draw circle indicator instead of actual cursor
+                draw_cursor_circle(
+                    rgba_data,
+                    composition_w,
+                    composition_h,
+                    cursor.x,
+                    cursor.y,
+                    project.cursor.scale,
+                );
+            } else {
+                // Priority: SVG cursor (if shape detected) > Bitmap cursor (fallback)
+                // This matches Cap's approach for consistent, resolution-independent cursors.
+                let mut rendered = false;
+
+                // Calculate cursor scale relative to composition size
+                // Base cursor is 24px (same as editor DEFAULT_CURSOR_SIZE)
+                // Scale relative to 720p reference so cursor looks proportional
+                let base_cursor_height = 24.0;
+                let size_scale = composition_h as f32 / CURSOR_REFERENCE_HEIGHT;
+                let final_cursor_height = base_cursor_height * size_scale * project.cursor.scale;
+                let final_cursor_height = final_cursor_height.clamp(16.0, 256.0);
+
+                // Try SVG cursor first (if shape is detected)
+                if let Some(shape) = cursor.cursor_shape {
+                    // Render SVG at final cursor height (handles any original SVG size)
+                    let target_height = final_cursor_height.round() as u32;
+
+                    if let Some(svg_cursor) = render_svg_cursor_to_height(shape, target_height) {
+                        let svg_decoded = super::cursor::DecodedCursorImage {
+                            width: svg_cursor.width,
+                            height: svg_cursor.height,
+                            hotspot_x: svg_cursor.hotspot_x,
+                            hotspot_y: svg_cursor.hotspot_y,
+                            data: svg_cursor.data,
+                        };
+                        // Pass 1.0 as base_scale since SVG is already at final size
+                        // cursor.scale (click animation) is applied internally
+                        composite_cursor(
+                            rgba_data,
+                            composition_w,
+                            composition_h,
+                            &cursor,
+                            &svg_decoded,
+                            1.0,
+                        );
+                        rendered = true;
+                    }
+                }
+
+                // Fall back to bitmap cursor if SVG not available
+                if !rendered {
+                    if let Some(ref cursor_id) = cursor.cursor_id {
+                        if let Some(cursor_image) = cursor_interp.get_cursor_image(cursor_id) {
+                            // For bitmap, apply the full scale factor
+                            let bitmap_scale = final_cursor_height / cursor_image.height as f32;
+                            composite_cursor(
+                                rgba_data,
+                                composition_w,
+                                composition_h,
+                                &cursor,
+                                cursor_image,
+                                bitmap_scale,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -5,11 +5,12 @@
 
 use std::io::Write;
 use std::process::ChildStdin;
+use std::sync::Arc;
 
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 
-use crate::rendering::stream_decoder::StreamDecoder;
+use crate::rendering::stream_decoder::SegmentedDecoder;
 use crate::rendering::types::DecodedFrame;
 
 /// Buffer size for decode and encode channels.
@@ -17,27 +18,79 @@ use crate::rendering::types::DecodedFrame;
 /// At 1080p RGBA (~8MB/frame), this uses ~32MB per channel.
 pub const PIPELINE_BUFFER_SIZE: usize = 4;
 
+/// Smoothing factor for the render throughput moving average.
+/// Lower values react slower but hide per-frame jitter (e.g. zoom-heavy sections).
+const THROUGHPUT_EMA_ALPHA: f64 = 0.2;
+
+/// Tracks render throughput to estimate fps and time remaining.
+///
+/// Counts frames from `first_frame`, so a resumed export measures only
+/// what it renders.
+pub struct ThroughputTracker {
+    total_frames: u32,
+    last_frames: u32,
+    last_secs: f64,
+    fps_ema: Option<f64>,
+}
+
+impl ThroughputTracker {
+    pub fn new(first_frame: u32, total_frames: u32) -> Self {
+        Self {
+            total_frames,
+            last_frames: first_frame,
+            last_secs: 0.0,
+            fps_ema: None,
+        }
+    }
+
+    /// Record that `frames_done` frames were rendered `elapsed_secs` after
+    /// the render loop started. Returns the smoothed fps and the ETA in seconds.
+    pub fn update(&mut self, frames_done: u32, elapsed_secs: f64) -> Option<(f32, f32)> {
+        let frames = frames_done.saturating_sub(self.last_frames);
+        let secs = elapsed_secs - self.last_secs;
+        if frames == 0 || secs <= 0.0 {
+            return None;
+        }
+        self.last_frames = frames_done;
+        self.last_secs = elapsed_secs;
+
+        let sample = frames as f64 / secs;
+        let fps = match self.fps_ema {
+            Some(ema) => ema + THROUGHPUT_EMA_ALPHA * (sample - ema),
+            None => sample,
+        };
+        self.fps_ema = Some(fps);
+
+        let remaining = self.total_frames.saturating_sub(frames_done);
+        Some((fps as f32, (remaining as f64 / fps) as f32))
+    }
+}
+
 /// Bundle of decoded frames for a single frame index.
 pub struct DecodedFrameBundle {
     /// Frame index (0-indexed from start of export).
     pub frame_idx: u32,
     /// Decoded screen frame.
-    pub screen_frame: DecodedFrame,
+    pub screen_frame: Arc<DecodedFrame>,
     /// Decoded webcam frame (if webcam enabled).
-    pub webcam_frame: Option<DecodedFrame>,
+    pub webcam_frame: Option<Arc<DecodedFrame>>,
 }
 
 /// Spawns a decode task that pre-fetches frames into a bounded channel.
 ///
-/// The task reads frames from the screen and webcam decoders and sends
+/// The task reads the frames at `frame_times` (source ms of each export frame
+/// from `first_frame` on) from the webcam decoder and at `screen_times` (the
+/// same, with held frames repeating) from the screen decoder, and sends
 /// bundles to the returned receiver. Backpressure is automatic via the
 /// bounded channel.
 ///
 /// Returns the receiver and task handle for cleanup.
 pub fn spawn_decode_task(
-    mut screen_decoder: StreamDecoder,
-    mut webcam_decoder: Option<StreamDecoder>,
-    total_frames: u32,
+    mut screen_decoder: SegmentedDecoder,
+    mut webcam_decoder: Option<SegmentedDecoder>,
+    first_frame: u32,
+    frame_times: Vec<u64>,
+    screen_times: Vec<u64>,
 ) -> (
     mpsc::Receiver<DecodedFrameBundle>,
     JoinHandle<Result<(), String>>,
@@ -45,12 +98,12 @@ pub fn spawn_decode_task(
     let (tx, rx) = mpsc::channel(PIPELINE_BUFFER_SIZE);
 
     let handle = tokio::spawn(async move {
-        let mut frame_idx = 0u32;
-        let mut last_webcam_frame: Option<DecodedFrame> = None;
+        let mut frame_idx = first_frame;
+        let mut last_webcam_frame: Option<Arc<DecodedFrame>> = None;
 
-        loop {
-            // Read screen frame
-            let screen_frame = match screen_decoder.next_frame().await {
+        for (source_ms, screen_ms) in frame_times.into_iter().zip(screen_times) {
+            // Read screen frame (the same one again while a hold covers it)
+            let screen_frame = match screen_decoder.frame_at(screen_ms).await {
                 Ok(Some(frame)) => frame,
                 Ok(None) => break, // End of stream
                 Err(e) => {
@@ -59,11 +112,12 @@ pub fn spawn_decode_task(
                 },
             };
 
-            // Read webcam frame (always consume to stay in sync)
+            // Read webcam frame at the timeline's source time, so it keeps
+            // playing through holds
             let webcam_frame = if let Some(ref mut decoder) = webcam_decoder {
-                match decoder.next_frame().await {
+                match decoder.frame_at(source_ms).await {
                     Ok(Some(frame)) => {
-                        last_webcam_frame = Some(frame.clone());
+                        last_webcam_frame = Some(Arc::clone(&frame));
                         Some(frame)
                     },
                     _ => last_webcam_frame.clone(),
@@ -86,9 +140,6 @@ pub fn spawn_decode_task(
             }
 
             frame_idx += 1;
-            if frame_idx >= total_frames {
-                break;
-            }
         }
 
         log::debug!("[PIPELINE] Decode task complete: {} frames", frame_idx);
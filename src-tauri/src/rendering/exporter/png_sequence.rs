@@ -0,0 +1,100 @@
+//! PNG image-sequence export sink.
+//!
+//! Replaces the FFmpeg encoder at the end of the render pipeline: each
+//! rendered RGBA frame is written as a numbered PNG (alpha preserved) for
+//! post-processing in compositing tools.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use super::pipeline::PIPELINE_BUFFER_SIZE;
+
+/// Name of the ffmpeg concat sidecar written next to the frames.
+pub const SEQUENCE_SIDECAR_NAME: &str = "sequence.ffconcat";
+
+/// Directory the frames are written to.
+///
+/// A `.png` output path (as picked in a save dialog) names the directory,
+/// e.g. `Recording.png` -> `Recording/`; any other path is used as-is.
+pub fn sequence_dir(output_path: &Path) -> PathBuf {
+    match output_path.extension() {
+        Some(ext) if ext.eq_ignore_ascii_case("png") => output_path.with_extension(""),
+        _ => output_path.to_path_buf(),
+    }
+}
+
+/// File name of a 0-indexed frame (`frame_00001.png` for the first).
+pub fn frame_file_name(frame_idx: u32) -> String {
+    format!("frame_{:05}.png", frame_idx + 1)
+}
+
+/// ffmpeg concat script replaying `frame_count` frames at `fps`.
+///
+/// `ffmpeg -f concat -i sequence.ffconcat ...` re-encodes the sequence at
+/// the exported frame rate.
+pub fn ffconcat_script(frame_count: u32, fps: u32) -> String {
+    let duration = 1.0 / fps.max(1) as f64;
+    let mut script = String::from("ffconcat version 1.0\n");
+    for idx in 0..frame_count {
+        let _ = writeln!(script, "file {}", frame_file_name(idx));
+        let _ = writeln!(script, "duration {:.6}", duration);
+    }
+    script
+}
+
+/// Spawns a task that writes rendered frames as a numbered PNG sequence.
+///
+/// Takes the place of `spawn_encode_task`; frames arrive in order on the
+/// returned sender. The sidecar is written once the sender is dropped.
+pub fn spawn_png_sequence_task(
+    dir: PathBuf,
+    width: u32,
+    height: u32,
+    fps: u32,
+) -> (mpsc::Sender<Vec<u8>>, JoinHandle<Result<(), String>>) {
+    let (tx, mut rx) = mpsc::channel::<Vec<u8>>(PIPELINE_BUFFER_SIZE);
+
+    let handle = tokio::task::spawn_blocking(move || {
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create sequence directory: {}", e))?;
+
+        let mut frame_count = 0u32;
+        while let Some(rgba_data) = rx.blocking_recv() {
+            let path = dir.join(frame_file_name(frame_count));
+            image::save_buffer(
+                &path,
+                &rgba_data,
+                width,
+                height,
+                image::ExtendedColorType::Rgba8,
+            )
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+            frame_count += 1;
+        }
+
+        std::fs::write(
+            dir.join(SEQUENCE_SIDECAR_NAME),
+            ffconcat_script(frame_count, fps),
+        )
+        .map_err(|e| format!("Failed to write sequence sidecar: {}", e))?;
+
+        log::debug!("[PIPELINE] PNG sequence complete: {} frames", frame_count);
+        Ok(())
+    });
+
+    (tx, handle)
+}
+
+/// Total size in bytes of the files in a sequence directory.
+pub fn sequence_size_bytes(dir: &Path) -> Result<u64, String> {
+    let entries =
+        std::fs::read_dir(dir).map_err(|e| format!("Failed to read output directory: {}", e))?;
+    Ok(entries
+        .filter_map(|entry| entry.ok()?.metadata().ok())
+        .filter(|metadata| metadata.is_file())
+        .map(|metadata| metadata.len())
+        .sum())
+}
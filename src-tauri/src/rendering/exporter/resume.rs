@@ -0,0 +1,277 @@
+//! Resumable (chunked) export.
+//!
+//! With `ExportConfig::resumable`, rendered frames are encoded into short
+//! video-only chunks in a work directory next to the output. A sidecar
+//! records the project hash and how many frames are safely encoded, so a
+//! later export of the same project to the same path picks up after the last
+//! finished chunk. The chunks are muxed with the audio once all frames exist.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use super::ffmpeg::start_ffmpeg_encoder;
+use super::pipeline::PIPELINE_BUFFER_SIZE;
+use crate::commands::video_recording::video_project::VideoProject;
+
+/// Length of one chunk. Shorter chunks lose less work on a crash but add
+/// more FFmpeg restarts.
+pub const CHUNK_SECS: u32 = 10;
+
+/// Progress sidecar inside the work directory.
+const SIDECAR_NAME: &str = "progress.json";
+
+/// ffmpeg concat script listing the finished chunks.
+const CONCAT_LIST_NAME: &str = "chunks.ffconcat";
+
+/// Work directory holding the chunks of `output_path` (`video.mp4.partial`).
+pub fn work_dir(output_path: &Path) -> PathBuf {
+    let mut name = output_path.file_name().unwrap_or_default().to_os_string();
+    name.push(".partial");
+    output_path.with_file_name(name)
+}
+
+/// Hash of everything that affects the rendered frames.
+pub fn project_hash(project: &VideoProject) -> String {
+    let json = serde_json::to_vec(project).unwrap_or_default();
+    let mut hasher = Sha256::new();
+    hasher.update(&json);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Progress of a resumable export, persisted as the sidecar.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeState {
+    project_hash: String,
+    width: u32,
+    height: u32,
+    fps: u32,
+    /// Finished chunk files, in order.
+    chunks: Vec<String>,
+    /// Frames encoded into `chunks`, i.e. the index of the next frame to render.
+    completed_frames: u32,
+}
+
+impl ResumeState {
+    pub fn new(project_hash: String, width: u32, height: u32, fps: u32) -> Self {
+        Self {
+            project_hash,
+            width,
+            height,
+            fps,
+            chunks: Vec::new(),
+            completed_frames: 0,
+        }
+    }
+
+    /// Read the sidecar in `dir`, if there is a readable one.
+    pub fn load(dir: &Path) -> Option<Self> {
+        let json = std::fs::read_to_string(dir.join(SIDECAR_NAME)).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    /// Resume from `dir` if its sidecar belongs to this export (same project
+    /// hash and output format), otherwise start over in a cleared directory.
+    pub fn load_or_start(dir: &Path, fresh: Self) -> Result<Self, String> {
+        match Self::load(dir) {
+            Some(state) if state.is_resumable_as(&fresh) => {
+                // Drop the chunk that was being written when the export stopped
+                let entries = std::fs::read_dir(dir)
+                    .map_err(|e| format!("Failed to read export work directory: {}", e))?;
+                for entry in entries.flatten() {
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    if name != SIDECAR_NAME && !state.chunks.contains(&name) {
+                        let _ = std::fs::remove_file(entry.path());
+                    }
+                }
+                log::info!(
+                    "[EXPORT] Resuming at frame {} ({} chunks done)",
+                    state.completed_frames,
+                    state.chunks.len()
+                );
+                Ok(state)
+            },
+            existing => {
+                if existing.is_some() {
+                    log::info!(
+                        "[EXPORT] Project changed since the interrupted export, starting over"
+                    );
+                }
+                if dir.exists() {
+                    std::fs::remove_dir_all(dir)
+                        .map_err(|e| format!("Failed to clear export work directory: {}", e))?;
+                }
+                std::fs::create_dir_all(dir)
+                    .map_err(|e| format!("Failed to create export work directory: {}", e))?;
+                fresh.save(dir)?;
+                Ok(fresh)
+            },
+        }
+    }
+
+    fn is_resumable_as(&self, fresh: &Self) -> bool {
+        self.project_hash == fresh.project_hash
+            && self.width == fresh.width
+            && self.height == fresh.height
+            && self.fps == fresh.fps
+    }
+
+    /// Index of the next frame to render.
+    pub fn completed_frames(&self) -> u32 {
+        self.completed_frames
+    }
+
+    /// Write the sidecar atomically (a crash leaves the previous one intact).
+    fn save(&self, dir: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize export progress: {}", e))?;
+        let tmp = dir.join(format!("{}.tmp", SIDECAR_NAME));
+        std::fs::write(&tmp, json)
+            .map_err(|e| format!("Failed to write export progress: {}", e))?;
+        std::fs::rename(&tmp, dir.join(SIDECAR_NAME))
+            .map_err(|e| format!("Failed to write export progress: {}", e))
+    }
+
+    /// Write the concat script of the finished chunks, returning its path.
+    pub fn write_concat_list(&self, dir: &Path) -> Result<PathBuf, String> {
+        let path = dir.join(CONCAT_LIST_NAME);
+        std::fs::write(&path, concat_script(&self.chunks))
+            .map_err(|e| format!("Failed to write chunk list: {}", e))?;
+        Ok(path)
+    }
+}
+
+/// ffmpeg concat script for chunk files in the same directory.
+pub fn concat_script(chunks: &[String]) -> String {
+    let mut script = String::from("ffconcat version 1.0\n");
+    for chunk in chunks {
+        script.push_str(&format!("file '{}'\n", chunk));
+    }
+    script
+}
+
+/// Chunk currently being encoded.
+struct ChunkEncoder {
+    name: String,
+    process: Child,
+    stdin: ChildStdin,
+    frames: u32,
+}
+
+/// Spawns an encode task that writes frames into resumable chunks.
+///
+/// Takes the place of `spawn_encode_task`: every `CHUNK_SECS` of frames the
+/// chunk's FFmpeg process is closed and the sidecar updated. A trailing
+/// partial chunk is kept too, since its frames rendered fine.
+pub fn spawn_chunked_encode_task(
+    project: VideoProject,
+    dir: PathBuf,
+    mut state: ResumeState,
+) -> (mpsc::Sender<Vec<u8>>, JoinHandle<Result<(), String>>) {
+    let (tx, mut rx) = mpsc::channel::<Vec<u8>>(PIPELINE_BUFFER_SIZE);
+
+    let handle = tokio::task::spawn_blocking(move || {
+        let chunk_frames = CHUNK_SECS * state.fps;
+        let mut current: Option<ChunkEncoder> = None;
+
+        while let Some(rgba_data) = rx.blocking_recv() {
+            let mut chunk = match current.take() {
+                Some(chunk) => chunk,
+                None => start_chunk(&project, &dir, &state)?,
+            };
+            chunk
+                .stdin
+                .write_all(&rgba_data)
+                .map_err(|e| format!("FFmpeg write failed: {}", e))?;
+            chunk.frames += 1;
+
+            if chunk.frames >= chunk_frames {
+                finish_chunk(chunk, &mut state, &dir)?;
+            } else {
+                current = Some(chunk);
+            }
+        }
+
+        if let Some(chunk) = current.take() {
+            finish_chunk(chunk, &mut state, &dir)?;
+        }
+
+        log::debug!(
+            "[PIPELINE] Chunked encode complete: {} frames in {} chunks",
+            state.completed_frames,
+            state.chunks.len()
+        );
+        Ok(())
+    });
+
+    (tx, handle)
+}
+
+fn start_chunk(
+    project: &VideoProject,
+    dir: &Path,
+    state: &ResumeState,
+) -> Result<ChunkEncoder, String> {
+    let name = format!(
+        "chunk_{:05}.{}",
+        state.chunks.len(),
+        project.export.format.extension()
+    );
+    let mut process = start_ffmpeg_encoder(
+        project,
+        &dir.join(&name),
+        state.width,
+        state.height,
+        state.fps,
+        None,
+        None,
+        true,
+    )?;
+    let stdin = process.stdin.take().ok_or("Failed to get FFmpeg stdin")?;
+
+    // Drain stderr so FFmpeg never blocks on a full pipe
+    if let Some(mut stderr) = process.stderr.take() {
+        std::thread::spawn(move || {
+            let _ = std::io::copy(&mut stderr, &mut std::io::sink());
+        });
+    }
+
+    Ok(ChunkEncoder {
+        name,
+        process,
+        stdin,
+        frames: 0,
+    })
+}
+
+fn finish_chunk(chunk: ChunkEncoder, state: &mut ResumeState, dir: &Path) -> Result<(), String> {
+    let ChunkEncoder {
+        name,
+        mut process,
+        stdin,
+        frames,
+    } = chunk;
+
+    // Close stdin to signal EOF to FFmpeg
+    drop(stdin);
+    let status = process
+        .wait()
+        .map_err(|e| format!("FFmpeg wait failed: {}", e))?;
+    if !status.success() {
+        return Err(format!(
+            "FFmpeg encoding of {} failed with status: {:?}",
+            name,
+            status.code()
+        ));
+    }
+
+    state.chunks.push(name);
+    state.completed_frames += frames;
+    state.save(dir)
+}
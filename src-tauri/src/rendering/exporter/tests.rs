@@ -2,13 +2,15 @@
 
 #![cfg(test)]
 
-use super::super::types::DecodedFrame;
+use super::super::types::{DecodedFrame, WebcamOverlay};
 use super::frame_ops::*;
 use super::webcam::*;
 use crate::commands::video_recording::video_project::{
-    AudioTrackSettings, CornerStyle, CursorConfig, ExportConfig, MaskConfig, SceneConfig,
-    ShadowConfig, TextConfig, TimelineState, VideoProject, VideoSources, WebcamBorder,
-    WebcamConfig, WebcamOverlayPosition, WebcamOverlayShape, ZoomConfig,
+    AudioTrackSettings, ChromaKeyConfig, CornerStyle, CursorConfig, EasingFunction, ExportConfig,
+    GlowConfig, KeystrokeConfig, MaskConfig, SceneConfig, ShadowConfig, TextConfig,
+    TimelineSegment, TimelineState, VideoProject, VideoSources, VisibilitySegment, WebcamBorder,
+    WebcamConfig, WebcamOverlayPosition, WebcamOverlayShape, WebcamPositionKeyframe,
+    WebcamTransition, WebcamTransitionKind, ZoomConfig, XY,
 };
 
 /// Create a minimal VideoProject for testing webcam positioning
@@ -27,6 +29,8 @@ fn make_test_project(
             screen_video: "/tmp/test.mp4".to_string(),
             webcam_video: Some("/tmp/webcam.mp4".to_string()),
             cursor_data: None,
+            frame_timestamps: None,
+            keystrokes: None,
             audio_file: None,
             system_audio: None,
             microphone_audio: None,
@@ -55,14 +59,20 @@ fn make_test_project(
                 enabled: false,
                 width: 0,
                 color: "#ffffff".to_string(),
+                ..Default::default()
             },
             visibility_segments: vec![],
+            chroma_key: None,
+            position_keyframes: vec![],
+            instances: vec![],
         },
         audio: AudioTrackSettings::default(),
         export: ExportConfig::default(),
         scene: SceneConfig::default(),
         text: TextConfig::default(),
         mask: MaskConfig::default(),
+        cursor_smoothing_segments: vec![],
+        keystrokes: KeystrokeConfig::default(),
     }
 }
 
@@ -285,6 +295,44 @@ fn test_webcam_custom_position_edge_snapping() {
     );
 }
 
+#[test]
+fn test_webcam_border_gradient_and_glow() {
+    let (out_w, out_h) = (1920, 1080);
+
+    // Disabled border draws nothing, even with glow configured
+    let mut project = make_test_project(WebcamOverlayPosition::BottomRight, 0.20, 0.0, 0.0);
+    project.webcam.border.glow = Some(GlowConfig::default());
+    let overlay = build_webcam_overlay(&project, make_test_frame(), out_w, out_h);
+    assert_eq!(overlay.border_width, 0.0);
+    assert_eq!(overlay.glow_radius, 0.0);
+
+    // Solid border: gradient end falls back to the border color
+    project.webcam.border = WebcamBorder {
+        enabled: true,
+        width: 4,
+        color: "#ff0000".to_string(),
+        ..Default::default()
+    };
+    let overlay = build_webcam_overlay(&project, make_test_frame(), out_w, out_h);
+    assert_eq!(overlay.border_width, 4.0);
+    assert_eq!(overlay.border_color_end, overlay.border_color);
+    assert_eq!(overlay.glow_radius, 0.0);
+
+    // Gradient border with glow
+    project.webcam.border.gradient_end = Some("#0000ff".to_string());
+    project.webcam.border.gradient_angle = 90.0;
+    project.webcam.border.glow = Some(GlowConfig {
+        color: "#00ff00".to_string(),
+        radius: 12.0,
+        opacity: 1.5,
+    });
+    let overlay = build_webcam_overlay(&project, make_test_frame(), out_w, out_h);
+    assert_ne!(overlay.border_color_end, overlay.border_color);
+    assert_eq!(overlay.border_gradient_angle, 90.0);
+    assert_eq!(overlay.glow_radius, 12.0);
+    assert_eq!(overlay.glow_opacity, 1.0, "Glow opacity should be clamped");
+}
+
 #[test]
 fn test_webcam_size_consistency() {
     // The webcam overlay size should be stored as a fraction of width
@@ -526,9 +574,10 @@ fn test_gpu_webcam_pixel_position_bottom_right() {
         output_width: out_w,
         output_height: out_h,
         zoom: crate::rendering::types::ZoomState::identity(),
-        webcam: Some(overlay),
+        webcams: vec![overlay],
         cursor: None,
         background: Default::default(),
+        fill_focus: None,
     };
 
     // Render frame through GPU
@@ -636,9 +685,10 @@ fn test_gpu_webcam_circle_not_oval() {
         output_width: out_w,
         output_height: out_h,
         zoom: crate::rendering::types::ZoomState::identity(),
-        webcam: Some(overlay),
+        webcams: vec![overlay],
         cursor: None,
         background: Default::default(),
+        fill_focus: None,
     };
 
     let output_texture = compositor.composite(&renderer, &screen_frame, &render_options, 0.0);
@@ -731,9 +781,10 @@ fn test_gpu_webcam_all_corners() {
             output_width: out_w,
             output_height: out_h,
             zoom: crate::rendering::types::ZoomState::identity(),
-            webcam: Some(overlay),
+            webcams: vec![overlay],
             cursor: None,
             background: Default::default(),
+            fill_focus: None,
         };
 
         let output_texture = compositor.composite(&renderer, &screen_frame, &render_options, 0.0);
@@ -778,3 +829,410 @@ fn test_gpu_webcam_all_corners() {
 
     eprintln!("[GPU TEST] PASSED: All corner positions verified!");
 }
+
+#[test]
+fn test_cursor_trail_fades_toward_tail() {
+    let (w, h) = (100u32, 10u32);
+    let mut frame = vec![0u8; (w * h * 4) as usize];
+
+    // Horizontal trail across the middle row, tail on the left
+    draw_cursor_trail(
+        &mut frame,
+        w,
+        h,
+        &[(0.1, 0.55), (0.5, 0.55), (0.9, 0.55)],
+        [255, 255, 255],
+        1.0,
+        1.0,
+        4.0,
+    );
+
+    let red_at = |x: u32| frame[((5 * w + x) * 4) as usize];
+    assert!(red_at(70) > red_at(30), "head should be brighter than tail");
+    assert!(red_at(70) > 200);
+    assert_eq!(red_at(97), 0, "nothing drawn past the head");
+    // Rows far from the line stay untouched
+    assert_eq!(frame[(4 * 4) as usize], 0);
+}
+
+#[test]
+fn test_click_spotlight_dims_outside_circle() {
+    let (w, h) = (100u32, 50u32);
+    let mut frame = vec![200u8; (w * h * 4) as usize];
+
+    draw_click_spotlight(&mut frame, w, h, 0.5, 0.5, 10.0, 0.5);
+
+    let pixel = |x: u32, y: u32| {
+        let i = ((y * w + x) * 4) as usize;
+        frame[i..i + 4].to_vec()
+    };
+    // Click point untouched, far corner dimmed by half, alpha kept
+    assert_eq!(pixel(50, 25), [200, 200, 200, 200]);
+    assert_eq!(pixel(0, 0), [100, 100, 100, 200]);
+    // Feathered edge sits between the two
+    let edge = pixel(59, 25)[0];
+    assert!(edge > 100 && edge < 200);
+
+    // Fully faded spotlight leaves the frame alone
+    let mut untouched = vec![200u8; (w * h * 4) as usize];
+    draw_click_spotlight(&mut untouched, w, h, 0.5, 0.5, 10.0, 0.0);
+    assert!(untouched.iter().all(|&v| v == 200));
+}
+
+#[test]
+fn test_gif_filter_caps_fps_and_uses_preset() {
+    use super::ffmpeg::gif_filter;
+    use crate::commands::video_recording::GifQualityPreset;
+
+    let filter = gif_filter(60, GifQualityPreset::High);
+    assert!(filter.starts_with("fps=30,"));
+    assert!(filter.contains("dither=floyd_steinberg"));
+
+    assert!(gif_filter(15, GifQualityPreset::Fast).starts_with("fps=15,"));
+}
+
+#[test]
+fn test_throughput_tracker_fps_and_eta() {
+    use super::pipeline::ThroughputTracker;
+
+    let mut tracker = ThroughputTracker::new(0, 300);
+    assert!(tracker.update(0, 0.0).is_none());
+
+    // 30 frames in 1s -> 30 fps, 270 frames left -> 9s
+    let (fps, eta) = tracker.update(30, 1.0).unwrap();
+    assert!((fps - 30.0).abs() < 1e-3);
+    assert!((eta - 9.0).abs() < 1e-3);
+
+    // A slower sample pulls the average down without replacing it
+    let (fps, _) = tracker.update(40, 2.0).unwrap();
+    assert!(fps < 30.0 && fps > 10.0);
+
+    let (_, eta) = tracker.update(300, 10.0).unwrap();
+    assert_eq!(eta, 0.0);
+}
+
+#[test]
+fn test_png_sequence_paths_and_sidecar() {
+    use super::png_sequence::{ffconcat_script, frame_file_name, sequence_dir};
+    use std::path::{Path, PathBuf};
+
+    assert_eq!(
+        sequence_dir(Path::new("/tmp/Recording.png")),
+        PathBuf::from("/tmp/Recording")
+    );
+    assert_eq!(
+        sequence_dir(Path::new("/tmp/frames")),
+        PathBuf::from("/tmp/frames")
+    );
+    assert_eq!(frame_file_name(0), "frame_00001.png");
+    assert_eq!(frame_file_name(41), "frame_00042.png");
+
+    let script = ffconcat_script(2, 25);
+    assert_eq!(
+        script,
+        "ffconcat version 1.0\nfile frame_00001.png\nduration 0.040000\nfile frame_00002.png\nduration 0.040000\n"
+    );
+}
+
+#[test]
+fn test_resume_state_resumes_only_matching_exports() {
+    use super::resume::{concat_script, work_dir, ResumeState};
+    use std::path::{Path, PathBuf};
+
+    assert_eq!(
+        work_dir(Path::new("/tmp/out/video.mp4")),
+        PathBuf::from("/tmp/out/video.mp4.partial")
+    );
+    assert_eq!(
+        concat_script(&["chunk_00000.mp4".to_string()]),
+        "ffconcat version 1.0\nfile 'chunk_00000.mp4'\n"
+    );
+
+    let dir = std::env::temp_dir().join(format!("snapit_resume_test_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    // First run starts from frame 0 and writes the sidecar
+    let fresh = ResumeState::new("hash-a".to_string(), 1920, 1080, 30);
+    let state = ResumeState::load_or_start(&dir, fresh.clone()).unwrap();
+    assert_eq!(state.completed_frames(), 0);
+    assert_eq!(ResumeState::load(&dir), Some(fresh));
+
+    // Unfinished chunk files are dropped when resuming
+    std::fs::write(dir.join("chunk_00000.mp4"), b"partial").unwrap();
+    let resumed =
+        ResumeState::load_or_start(&dir, ResumeState::new("hash-a".to_string(), 1920, 1080, 30))
+            .unwrap();
+    assert_eq!(resumed.completed_frames(), 0);
+    assert!(!dir.join("chunk_00000.mp4").exists());
+
+    // A different project starts over
+    std::fs::write(dir.join("chunk_00000.mp4"), b"stale").unwrap();
+    let restarted =
+        ResumeState::load_or_start(&dir, ResumeState::new("hash-b".to_string(), 1920, 1080, 30))
+            .unwrap();
+    assert_eq!(restarted.completed_frames(), 0);
+    assert_eq!(ResumeState::load(&dir).unwrap(), restarted);
+    assert!(!dir.join("chunk_00000.mp4").exists());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_chroma_key_removes_green_keeps_subject() {
+    let green = [0u8, 255, 0, 255];
+    let near_green = [40u8, 200, 50, 255];
+    let skin = [220u8, 170, 140, 255];
+    let mut data = Vec::new();
+    for pixel in [green, near_green, skin] {
+        data.extend_from_slice(&pixel);
+    }
+    let mut frame = DecodedFrame::new(0, 0, data, 3, 1);
+
+    apply_chroma_key(&mut frame, &ChromaKeyConfig::default());
+
+    assert_eq!(frame.data[3], 0, "Key color should be fully transparent");
+    assert_eq!(frame.data[7], 0, "Shade of the key should be transparent");
+    assert_eq!(frame.data[11], 255, "Subject should stay opaque");
+    assert_eq!(
+        &frame.data[8..11],
+        &skin[..3],
+        "Color channels are untouched"
+    );
+}
+
+#[test]
+fn test_chroma_key_applied_by_overlay_builder() {
+    let mut project = make_test_project(WebcamOverlayPosition::BottomRight, 0.20, 0.0, 0.0);
+    let frame = DecodedFrame::new(0, 0, vec![0, 255, 0, 255], 1, 1);
+
+    let overlay = build_webcam_overlay(&project, frame.clone(), 1920, 1080);
+    assert_eq!(overlay.frame.data[3], 255, "No keying without config");
+
+    project.webcam.chroma_key = Some(ChromaKeyConfig::default());
+    let overlay = build_webcam_overlay(&project, frame, 1920, 1080);
+    assert_eq!(overlay.frame.data[3], 0);
+}
+
+fn make_transition_project(visible: bool, kind: WebcamTransitionKind) -> VideoProject {
+    let mut project = make_test_project(WebcamOverlayPosition::BottomRight, 0.20, 0.0, 0.0);
+    project.webcam.visibility_segments = vec![VisibilitySegment {
+        start_ms: 1000,
+        end_ms: 5000,
+        visible,
+        transition: Some(WebcamTransition {
+            kind,
+            duration_ms: 400,
+        }),
+    }];
+    project
+}
+
+#[test]
+fn test_webcam_transition_at_visible_segment_boundaries() {
+    let project = make_transition_project(true, WebcamTransitionKind::Fade);
+    let instance = project.webcam.base_instance();
+
+    // Entrance ramps up after the start, exit ramps down before the end
+    let entering = webcam_transition_at(&project, &instance, 1100).expect("entering");
+    let exiting = webcam_transition_at(&project, &instance, 4900).expect("exiting");
+    assert!(entering.progress > 0.0 && entering.progress < 1.0);
+    assert!((entering.progress - exiting.progress).abs() < 0.001);
+    assert!(
+        webcam_transition_at(&project, &instance, 1300)
+            .unwrap()
+            .progress
+            > entering.progress
+    );
+
+    // Fully shown mid-segment, hidden outside
+    assert_eq!(webcam_transition_at(&project, &instance, 3000), None);
+    assert_eq!(webcam_transition_at(&project, &instance, 6000), None);
+
+    // Segments without a transition pop in
+    let mut instance = instance;
+    instance.visibility_segments[0].transition = None;
+    assert_eq!(webcam_transition_at(&project, &instance, 1100), None);
+}
+
+#[test]
+fn test_webcam_transition_at_hidden_segment_boundaries() {
+    let project = make_transition_project(false, WebcamTransitionKind::Fade);
+    let instance = project.webcam.base_instance();
+
+    // Animates out before the hidden range and back in after it
+    assert!(webcam_transition_at(&project, &instance, 900).is_some());
+    assert!(webcam_transition_at(&project, &instance, 5100).is_some());
+    assert_eq!(webcam_transition_at(&project, &instance, 3000), None);
+    assert_eq!(webcam_transition_at(&project, &instance, 500), None);
+}
+
+#[test]
+fn test_apply_webcam_transition() {
+    let (out_w, out_h) = (1920, 1080);
+    let project = make_test_project(WebcamOverlayPosition::BottomRight, 0.20, 0.0, 0.0);
+    let opaque = DecodedFrame::new(0, 0, vec![255; 4 * 4], 2, 2);
+    let base = build_webcam_overlay(&project, opaque, out_w, out_h);
+    let halfway = |kind| WebcamTransitionState {
+        kind,
+        progress: 0.5,
+    };
+
+    let mut faded = base.clone();
+    apply_webcam_transition(
+        &mut faded,
+        halfway(WebcamTransitionKind::Fade),
+        out_w,
+        out_h,
+    );
+    assert_eq!(faded.opacity, 0.5);
+    assert_eq!(
+        faded.frame.data[3], 255,
+        "Fade leaves the shared frame untouched"
+    );
+    assert_eq!(faded.x, base.x);
+
+    // Bottom-right overlay slides in from the right edge
+    let mut slid = base.clone();
+    apply_webcam_transition(
+        &mut slid,
+        halfway(WebcamTransitionKind::SlideFromEdge),
+        out_w,
+        out_h,
+    );
+    assert!(slid.x > base.x && slid.x < 1.0);
+    assert_eq!(slid.size, base.size);
+
+    // Scale keeps the center fixed
+    let mut scaled = base.clone();
+    apply_webcam_transition(
+        &mut scaled,
+        halfway(WebcamTransitionKind::ScaleIn),
+        out_w,
+        out_h,
+    );
+    assert!((scaled.size - base.size * 0.5).abs() < 0.001);
+    let center = |o: &WebcamOverlay| o.x + o.size / 2.0;
+    assert!((center(&scaled) - center(&base)).abs() < 0.001);
+}
+
+#[test]
+fn test_webcam_instances_default_to_single_config() {
+    let mut project = make_test_project(WebcamOverlayPosition::TopLeft, 0.20, 0.0, 0.0);
+    let frame = DecodedFrame::new(0, 0, vec![255; 4 * 4], 2, 2);
+
+    // No instances: the top-level placement is the only overlay
+    let instances = project.webcam.overlay_instances();
+    assert_eq!(instances.len(), 1);
+    let overlays = build_webcam_overlays(&project, &instances, &frame, 0, 1920, 1080);
+    assert_eq!(overlays.len(), 1);
+    assert!((overlays[0].x * 1920.0 - 16.0).abs() < 1.0);
+
+    // Instances replace it, each with its own placement and visibility
+    let mut second = project.webcam.base_instance();
+    second.position = WebcamOverlayPosition::BottomRight;
+    second.size = 0.10;
+    second.visibility_segments = vec![VisibilitySegment {
+        start_ms: 0,
+        end_ms: 1000,
+        visible: false,
+        transition: None,
+    }];
+    project.webcam.instances = vec![project.webcam.base_instance(), second];
+
+    let instances = project.webcam.overlay_instances();
+    let overlays = build_webcam_overlays(&project, &instances, &frame, 2000, 1920, 1080);
+    assert_eq!(overlays.len(), 2);
+    assert!(overlays[1].x > overlays[0].x);
+    // Both placements draw from one uploaded frame
+    assert!(std::sync::Arc::ptr_eq(
+        &overlays[0].frame,
+        &overlays[1].frame
+    ));
+    assert!((overlays[1].size - 0.10).abs() < 0.001);
+
+    // Second instance is hidden during its hidden segment
+    let overlays = build_webcam_overlays(&project, &instances, &frame, 500, 1920, 1080);
+    assert_eq!(overlays.len(), 1);
+}
+
+#[test]
+fn test_webcam_position_keyframes_interpolate() {
+    let (out_w, out_h) = (1920, 1080);
+    let mut project = make_test_project(WebcamOverlayPosition::BottomRight, 0.20, 0.0, 0.0);
+    let keyframe = |time_ms, x, y, size| WebcamPositionKeyframe {
+        time_ms,
+        center: XY::new(x, y),
+        size,
+        easing: EasingFunction::Linear,
+    };
+    // Deliberately out of order
+    project.webcam.position_keyframes = vec![
+        keyframe(2000, 0.8, 0.8, 0.10),
+        keyframe(1000, 0.2, 0.2, 0.20),
+    ];
+    let instances = project.webcam.overlay_instances();
+    let frame = make_test_frame();
+    let center_x = |o: &WebcamOverlay| o.x + o.size / 2.0;
+
+    // Holds the first keyframe before it
+    let before = build_webcam_overlays(&project, &instances, &frame, 0, out_w, out_h);
+    assert!((center_x(&before[0]) - 0.2).abs() < 0.001);
+    assert!((before[0].size - 0.20).abs() < 0.001);
+
+    // Halfway between keyframes
+    let mid = build_webcam_overlays(&project, &instances, &frame, 1500, out_w, out_h);
+    assert!((center_x(&mid[0]) - 0.5).abs() < 0.001);
+    assert!((mid[0].size - 0.15).abs() < 0.001);
+
+    // Holds the last keyframe after it
+    let after = build_webcam_overlays(&project, &instances, &frame, 9000, out_w, out_h);
+    assert!((center_x(&after[0]) - 0.8).abs() < 0.001);
+
+    // No keyframes: static preset position
+    project.webcam.position_keyframes.clear();
+    let instances = project.webcam.overlay_instances();
+    let static_overlay = build_webcam_overlays(&project, &instances, &frame, 1500, out_w, out_h);
+    let expected_x = (out_w as f32 - out_w as f32 * 0.20 - 16.0) / out_w as f32;
+    assert!((static_overlay[0].x - expected_x).abs() < 0.001);
+}
+
+#[test]
+fn test_frame_times_with_in_point() {
+    use super::super::keystroke::KeystrokeOverlay;
+    use super::FrameTimes;
+    use crate::commands::video_recording::keystrokes::{KeystrokeEvent, KeystrokeRecording};
+
+    // Trimmed to start at 5s, 2 fps, the last frame holds the screen
+    let frame_times = [5000, 5500, 6000];
+    let screen_times = [5000, 5500, 5500];
+    let times = |frame_idx| FrameTimes::at(frame_idx, 2, &frame_times, &screen_times, 5000);
+
+    assert_eq!(times(0).screen_time_ms, 0);
+    assert_eq!(times(2).relative_time_ms, 1000);
+    assert_eq!(times(2).screen_time_ms, 500);
+
+    // Keystrokes use the cursor's time base, so a press 400ms after the
+    // in-point shows on the frames after it
+    let keystrokes = KeystrokeOverlay::new(
+        &KeystrokeConfig::default(),
+        KeystrokeRecording {
+            events: vec![KeystrokeEvent {
+                timestamp_ms: 400,
+                key: "A".to_string(),
+                modifiers: Vec::new(),
+            }],
+        },
+    );
+    let size = XY::new(1920, 1080);
+    assert!(keystrokes
+        .prepare(size, 0.0, times(0).screen_time_ms)
+        .is_empty());
+    assert_eq!(
+        keystrokes.prepare(size, 0.0, times(1).screen_time_ms).len(),
+        1
+    );
+    assert_eq!(
+        keystrokes.prepare(size, 0.0, times(2).screen_time_ms).len(),
+        1
+    );
+}
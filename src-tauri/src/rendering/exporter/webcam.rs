@@ -1,15 +1,135 @@
 //! Webcam overlay building and visibility helpers.
 
+use std::sync::Arc;
+
+use super::super::background::hex_to_linear_rgba;
 use super::super::types::{DecodedFrame, WebcamOverlay, WebcamShape};
 use crate::commands::video_recording::video_project::{
-    VideoProject, WebcamOverlayPosition, WebcamOverlayShape,
+    ChromaKeyConfig, VideoProject, WebcamInstance, WebcamOverlayPosition, WebcamOverlayShape,
+    WebcamPositionKeyframe, WebcamTransitionKind, XY,
 };
 
+/// Smallest scale used by the scale-in transition (keeps the shader's
+/// size-normalized distances finite).
+const MIN_TRANSITION_SCALE: f32 = 0.01;
+
+/// Webcam entrance/exit animation state at a timestamp.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WebcamTransitionState {
+    /// Animation style.
+    pub kind: WebcamTransitionKind,
+    /// Eased progress (0.0 = fully hidden, 1.0 = fully shown).
+    pub progress: f32,
+}
+
 /// Build webcam overlay from frame and project settings.
 /// Positioning logic matches WebcamOverlay.tsx exactly for WYSIWYG export.
+///
+/// Keyframed positions are taken at the start of the timeline.
 pub fn build_webcam_overlay(
     project: &VideoProject,
-    frame: DecodedFrame,
+    mut frame: DecodedFrame,
+    out_w: u32,
+    out_h: u32,
+) -> WebcamOverlay {
+    key_webcam_frame(project, &mut frame);
+    build_webcam_instance_overlay(
+        project,
+        &project.webcam.base_instance(),
+        Arc::new(frame),
+        0,
+        out_w,
+        out_h,
+    )
+}
+
+/// Webcam center and size on a keyframed path. `keyframes` must be sorted and non-empty.
+///
+/// Eases between the keyframes bracketing `timestamp_ms` with the later
+/// keyframe's easing.
+fn keyframe_placement_at(
+    keyframes: &[WebcamPositionKeyframe],
+    timestamp_ms: u64,
+) -> (XY<f64>, f32) {
+    let next_idx = keyframes.partition_point(|k| k.time_ms <= timestamp_ms);
+
+    match (next_idx.checked_sub(1), keyframes.get(next_idx)) {
+        (Some(prev_idx), Some(next)) => {
+            let prev = &keyframes[prev_idx];
+            let span_ms = (next.time_ms - prev.time_ms) as f64;
+            let t = next
+                .easing
+                .apply((timestamp_ms - prev.time_ms) as f64 / span_ms);
+
+            let center = XY::new(
+                prev.center.x + (next.center.x - prev.center.x) * t,
+                prev.center.y + (next.center.y - prev.center.y) * t,
+            );
+            let size = prev.size + (next.size - prev.size) * t as f32;
+            (center, size.max(0.0))
+        },
+        (Some(prev_idx), None) => (keyframes[prev_idx].center, keyframes[prev_idx].size),
+        (None, _) => (keyframes[0].center, keyframes[0].size),
+    }
+}
+
+/// Key out the webcam background before compositing over the screen.
+fn key_webcam_frame(project: &VideoProject, frame: &mut DecodedFrame) {
+    if let Some(ref chroma_key) = project.webcam.chroma_key {
+        apply_chroma_key(frame, chroma_key);
+    }
+}
+
+/// Overlays for every webcam placement visible at a timestamp, bottom to top.
+///
+/// The frame is keyed once and shared by all placements.
+pub fn build_webcam_overlays(
+    project: &VideoProject,
+    instances: &[WebcamInstance],
+    frame: &DecodedFrame,
+    timestamp_ms: u64,
+    out_w: u32,
+    out_h: u32,
+) -> Vec<WebcamOverlay> {
+    let visible: Vec<&WebcamInstance> = instances
+        .iter()
+        .filter(|instance| is_webcam_visible_at(project, instance, timestamp_ms))
+        .collect();
+    if visible.is_empty() {
+        return Vec::new();
+    }
+
+    let mut keyed = frame.clone();
+    key_webcam_frame(project, &mut keyed);
+    let keyed = Arc::new(keyed);
+
+    visible
+        .into_iter()
+        .map(|instance| {
+            let mut overlay = build_webcam_instance_overlay(
+                project,
+                instance,
+                Arc::clone(&keyed),
+                timestamp_ms,
+                out_w,
+                out_h,
+            );
+            if let Some(transition) = webcam_transition_at(project, instance, timestamp_ms) {
+                apply_webcam_transition(&mut overlay, transition, out_w, out_h);
+            }
+            overlay
+        })
+        .collect()
+}
+
+/// Build the overlay for one webcam placement at a timeline position.
+///
+/// `frame` must already be chroma keyed.
+pub fn build_webcam_instance_overlay(
+    project: &VideoProject,
+    instance: &WebcamInstance,
+    frame: Arc<DecodedFrame>,
+    timestamp_ms: u64,
     out_w: u32,
     out_h: u32,
 ) -> WebcamOverlay {
@@ -20,10 +140,15 @@ pub fn build_webcam_overlay(
     let webcam_aspect = frame.width as f32 / frame.height as f32;
 
     // Determine if we should use source aspect ratio (native webcam dimensions)
-    let use_source_aspect = matches!(project.webcam.shape, WebcamOverlayShape::Source);
+    let use_source_aspect = matches!(instance.shape, WebcamOverlayShape::Source);
+
+    // Keyframed center and size replace the static position
+    let keyframed = (!instance.position_keyframes.is_empty())
+        .then(|| keyframe_placement_at(&instance.position_keyframes, timestamp_ms));
+    let size = keyframed.map_or(instance.size, |(_, size)| size);
 
     // Base size (used as height for consistent sizing across shapes)
-    let base_size_px = out_w as f32 * project.webcam.size;
+    let base_size_px = out_w as f32 * size;
 
     // Calculate webcam dimensions based on shape
     // For Source: preserve native aspect ratio (like Cap)
@@ -38,7 +163,7 @@ pub fn build_webcam_overlay(
             // Portrait webcam: width = base, height = base / aspect
             (base_size_px, base_size_px / webcam_aspect)
         }
-    } else if matches!(project.webcam.shape, WebcamOverlayShape::Rectangle) {
+    } else if matches!(instance.shape, WebcamOverlayShape::Rectangle) {
         // Rectangle: force 16:9 aspect ratio
         (base_size_px * (16.0 / 9.0), base_size_px)
     } else {
@@ -47,39 +172,49 @@ pub fn build_webcam_overlay(
     };
 
     // Calculate position in PIXELS first (matching WebcamOverlay.tsx getPositionStyle)
-    let (left_px, top_px) = match project.webcam.position {
-        WebcamOverlayPosition::TopLeft => (MARGIN_PX, MARGIN_PX),
-        WebcamOverlayPosition::TopRight => (out_w as f32 - webcam_width_px - MARGIN_PX, MARGIN_PX),
-        WebcamOverlayPosition::BottomLeft => {
-            (MARGIN_PX, out_h as f32 - webcam_height_px - MARGIN_PX)
-        },
-        WebcamOverlayPosition::BottomRight => (
-            out_w as f32 - webcam_width_px - MARGIN_PX,
-            out_h as f32 - webcam_height_px - MARGIN_PX,
-        ),
-        WebcamOverlayPosition::Custom => {
-            // Custom positioning matches preview logic
-            let custom_x = project.webcam.custom_x;
-            let custom_y = project.webcam.custom_y;
-
-            let left = if custom_x <= 0.1 {
-                MARGIN_PX
-            } else if custom_x >= 0.9 {
-                out_w as f32 - webcam_width_px - MARGIN_PX
-            } else {
-                custom_x * out_w as f32 - webcam_width_px / 2.0
-            };
+    let (left_px, top_px) = if let Some((center, _)) = keyframed {
+        // Follow the path freely (no edge snapping, so motion stays smooth)
+        (
+            center.x as f32 * out_w as f32 - webcam_width_px / 2.0,
+            center.y as f32 * out_h as f32 - webcam_height_px / 2.0,
+        )
+    } else {
+        match instance.position {
+            WebcamOverlayPosition::TopLeft => (MARGIN_PX, MARGIN_PX),
+            WebcamOverlayPosition::TopRight => {
+                (out_w as f32 - webcam_width_px - MARGIN_PX, MARGIN_PX)
+            },
+            WebcamOverlayPosition::BottomLeft => {
+                (MARGIN_PX, out_h as f32 - webcam_height_px - MARGIN_PX)
+            },
+            WebcamOverlayPosition::BottomRight => (
+                out_w as f32 - webcam_width_px - MARGIN_PX,
+                out_h as f32 - webcam_height_px - MARGIN_PX,
+            ),
+            WebcamOverlayPosition::Custom => {
+                // Custom positioning matches preview logic
+                let custom_x = instance.custom_x;
+                let custom_y = instance.custom_y;
 
-            let top = if custom_y <= 0.1 {
-                MARGIN_PX
-            } else if custom_y >= 0.9 {
-                out_h as f32 - webcam_height_px - MARGIN_PX
-            } else {
-                custom_y * out_h as f32 - webcam_height_px / 2.0
-            };
+                let left = if custom_x <= 0.1 {
+                    MARGIN_PX
+                } else if custom_x >= 0.9 {
+                    out_w as f32 - webcam_width_px - MARGIN_PX
+                } else {
+                    custom_x * out_w as f32 - webcam_width_px / 2.0
+                };
 
-            (left, top)
-        },
+                let top = if custom_y <= 0.1 {
+                    MARGIN_PX
+                } else if custom_y >= 0.9 {
+                    out_h as f32 - webcam_height_px - MARGIN_PX
+                } else {
+                    custom_y * out_h as f32 - webcam_height_px / 2.0
+                };
+
+                (left, top)
+            },
+        }
     };
 
     // Convert to normalized coordinates (0-1)
@@ -101,7 +236,7 @@ pub fn build_webcam_overlay(
         use_source_aspect
     );
 
-    let shape = match project.webcam.shape {
+    let shape = match instance.shape {
         WebcamOverlayShape::Circle => WebcamShape::Circle,
         WebcamOverlayShape::Rectangle => WebcamShape::Rectangle,
         // Use Squircle for both RoundedRectangle and Source (Source = native aspect + squircle)
@@ -117,11 +252,33 @@ pub fn build_webcam_overlay(
     let shadow_opacity = strength * 0.5; // Matches preview: max 50% opacity
     let shadow_blur = 0.15; // Same as size for consistent falloff
 
+    // Border: solid by default, gradient when an end color is set
+    let border = &project.webcam.border;
+    let border_width = if border.enabled {
+        border.width as f32
+    } else {
+        0.0
+    };
+    let border_color = hex_to_linear_rgba(&border.color);
+    let border_color_end = border
+        .gradient_end
+        .as_deref()
+        .map(hex_to_linear_rgba)
+        .unwrap_or(border_color);
+    let (glow_color, glow_radius, glow_opacity) = match &border.glow {
+        Some(glow) if border.enabled => (
+            hex_to_linear_rgba(&glow.color),
+            glow.radius.max(0.0),
+            glow.opacity.clamp(0.0, 1.0),
+        ),
+        _ => ([0.0; 4], 0.0, 0.0),
+    };
+
     WebcamOverlay {
         frame,
         x: x_norm,
         y: y_norm,
-        size: project.webcam.size,
+        size,
         shape,
         mirror: project.webcam.mirror,
         use_source_aspect,
@@ -129,24 +286,74 @@ pub fn build_webcam_overlay(
         shadow_size,
         shadow_opacity,
         shadow_blur,
+        border_width,
+        border_color,
+        border_color_end,
+        border_gradient_angle: border.gradient_angle,
+        glow_color,
+        glow_radius,
+        glow_opacity,
+        opacity: 1.0,
+    }
+}
+
+/// Make pixels near the key color transparent.
+///
+/// Compares chroma (Cb/Cr) only, so shadows and highlights on the backdrop
+/// are keyed the same as its mid tones. Pixels within `similarity` of the key
+/// are removed; alpha ramps back up over the next `smoothness`.
+pub fn apply_chroma_key(frame: &mut DecodedFrame, config: &ChromaKeyConfig) {
+    let [key_r, key_g, key_b] = hex_to_rgb(&config.key_color);
+    let (key_cb, key_cr) = chroma(key_r, key_g, key_b);
+    let similarity = config.similarity.clamp(0.0, 1.0);
+    let smoothness = config.smoothness.clamp(0.0, 1.0).max(f32::EPSILON);
+
+    for pixel in frame.data.chunks_exact_mut(4) {
+        let (cb, cr) = chroma(pixel[0], pixel[1], pixel[2]);
+        let dist = ((cb - key_cb).powi(2) + (cr - key_cr).powi(2)).sqrt();
+        let alpha = ((dist - similarity) / smoothness).clamp(0.0, 1.0);
+        pixel[3] = (pixel[3] as f32 * alpha).round() as u8;
     }
 }
 
-/// Check if webcam should be visible at a specific timestamp.
-pub fn is_webcam_visible_at(project: &VideoProject, timestamp_ms: u64) -> bool {
+/// BT.601 chroma of an sRGB color, each component in -0.5..0.5.
+fn chroma(r: u8, g: u8, b: u8) -> (f32, f32) {
+    let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+    let cb = -0.168_736 * r - 0.331_264 * g + 0.5 * b;
+    let cr = 0.5 * r - 0.418_688 * g - 0.081_312 * b;
+    (cb, cr)
+}
+
+/// Parse a hex color string to sRGB bytes.
+fn hex_to_rgb(hex: &str) -> [u8; 3] {
+    let hex = hex.trim_start_matches('#');
+    let channel = |i: usize| {
+        hex.get(i..i + 2)
+            .and_then(|c| u8::from_str_radix(c, 16).ok())
+            .unwrap_or(0)
+    };
+    [channel(0), channel(2), channel(4)]
+}
+
+/// Check if a webcam placement should be visible at a specific timestamp.
+pub fn is_webcam_visible_at(
+    project: &VideoProject,
+    instance: &WebcamInstance,
+    timestamp_ms: u64,
+) -> bool {
     // If webcam is disabled globally, it's not visible
     if !project.webcam.enabled {
         return false;
     }
 
     // If no visibility segments defined, webcam is always visible
-    if project.webcam.visibility_segments.is_empty() {
+    if instance.visibility_segments.is_empty() {
         return true;
     }
 
     // Check visibility segments - find the last segment that starts before this timestamp
     let mut is_visible = true; // Default to visible
-    for segment in &project.webcam.visibility_segments {
+    for segment in &instance.visibility_segments {
         if timestamp_ms >= segment.start_ms && timestamp_ms < segment.end_ms {
             is_visible = segment.visible;
         }
@@ -154,3 +361,103 @@ pub fn is_webcam_visible_at(project: &VideoProject, timestamp_ms: u64) -> bool {
 
     is_visible
 }
+
+/// Transition a webcam placement is in the middle of at a timestamp, if any.
+///
+/// Visible segments animate in after their start and out before their end.
+/// Around hidden segments the webcam animates out before the segment and
+/// back in after it, so the hidden range itself stays empty.
+pub fn webcam_transition_at(
+    project: &VideoProject,
+    instance: &WebcamInstance,
+    timestamp_ms: u64,
+) -> Option<WebcamTransitionState> {
+    if !is_webcam_visible_at(project, instance, timestamp_ms) {
+        return None;
+    }
+
+    instance
+        .visibility_segments
+        .iter()
+        .filter_map(|segment| {
+            let transition = segment.transition?;
+
+            // Time to the nearest boundary, measured on the visible side
+            let distance_ms = if segment.visible {
+                if timestamp_ms < segment.start_ms || timestamp_ms >= segment.end_ms {
+                    return None;
+                }
+                (timestamp_ms - segment.start_ms).min(segment.end_ms - timestamp_ms)
+            } else if timestamp_ms < segment.start_ms {
+                segment.start_ms - timestamp_ms
+            } else if timestamp_ms >= segment.end_ms {
+                timestamp_ms - segment.end_ms
+            } else {
+                return None;
+            };
+
+            let t = distance_ms as f32 / transition.duration_ms.max(1) as f32;
+            (t < 1.0).then(|| WebcamTransitionState {
+                kind: transition.kind,
+                progress: ease_out_cubic(t),
+            })
+        })
+        .min_by(|a, b| a.progress.total_cmp(&b.progress))
+}
+
+/// Animate a built overlay for an entrance/exit transition.
+pub fn apply_webcam_transition(
+    overlay: &mut WebcamOverlay,
+    state: WebcamTransitionState,
+    out_w: u32,
+    out_h: u32,
+) {
+    let progress = state.progress.clamp(0.0, 1.0);
+    let (width, height) = overlay_extent(overlay, out_w, out_h);
+
+    match state.kind {
+        WebcamTransitionKind::Fade => {
+            overlay.opacity *= progress;
+            overlay.shadow_opacity *= progress;
+            overlay.glow_opacity *= progress;
+            overlay.border_color[3] *= progress;
+            overlay.border_color_end[3] *= progress;
+        },
+        WebcamTransitionKind::SlideFromEdge => {
+            // Start fully past the nearest horizontal edge
+            let offscreen_x = if overlay.x + width / 2.0 < 0.5 {
+                -width
+            } else {
+                1.0
+            };
+            overlay.x = offscreen_x + (overlay.x - offscreen_x) * progress;
+        },
+        WebcamTransitionKind::ScaleIn => {
+            let scale = progress.max(MIN_TRANSITION_SCALE);
+            // Scale about the center
+            overlay.x += width * (1.0 - scale) / 2.0;
+            overlay.y += height * (1.0 - scale) / 2.0;
+            overlay.size *= scale;
+            overlay.border_width *= scale;
+            overlay.glow_radius *= scale;
+        },
+    }
+}
+
+/// Normalized overlay width and height, matching the compositor's webcam rect.
+fn overlay_extent(overlay: &WebcamOverlay, out_w: u32, out_h: u32) -> (f32, f32) {
+    let output_aspect = out_w as f32 / out_h as f32;
+    let webcam_aspect = overlay.frame.width as f32 / overlay.frame.height as f32;
+
+    if !overlay.use_source_aspect {
+        (overlay.size, overlay.size * output_aspect)
+    } else if webcam_aspect >= 1.0 {
+        (overlay.size * webcam_aspect, overlay.size * output_aspect)
+    } else {
+        (overlay.size, overlay.size * output_aspect / webcam_aspect)
+    }
+}
+
+fn ease_out_cubic(t: f32) -> f32 {
+    1.0 - (1.0 - t.clamp(0.0, 1.0)).powi(3)
+}
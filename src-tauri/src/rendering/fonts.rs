@@ -0,0 +1,117 @@
+//! Custom fonts for text overlays.
+//!
+//! Fonts loaded at runtime go into a process-wide registry so every `TextLayer`
+//! (preview, native preview, export) can resolve them by family name. Layers pick
+//! up new fonts on their next prepare; until a font has loaded, segments using
+//! its family render with the fallback font instead of waiting.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+
+use parking_lot::Mutex;
+
+/// Family the color emoji font is registered under. Text layers use it for
+/// emoji in place of the system emoji font, so emoji look the same everywhere.
+pub const EMOJI_FAMILY: &str = "emoji";
+
+/// Bundled color emoji font (Noto Color Emoji), relative to the resource directory.
+const BUNDLED_EMOJI_FONT: &str = "assets/fonts/NotoColorEmoji.ttf";
+
+/// A font loaded at runtime.
+#[derive(Clone)]
+pub struct CustomFont {
+    /// Family name segments use to refer to this font.
+    pub family: String,
+    /// Raw font file data.
+    pub data: Arc<Vec<u8>>,
+}
+
+#[derive(Default)]
+struct FontRegistry {
+    /// Loaded fonts, in load order.
+    fonts: Vec<CustomFont>,
+    /// Sources that are loaded or still loading.
+    sources: HashSet<String>,
+}
+
+fn registry() -> &'static Mutex<FontRegistry> {
+    static REGISTRY: OnceLock<Mutex<FontRegistry>> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+/// Load a font file and register it under `family`.
+///
+/// `source` is a file path or `file://` URL. Each source is read at most once;
+/// repeated requests (including ones made while it is still loading) return
+/// immediately. A failed load can be retried.
+pub async fn load_font(family: String, source: String) -> Result<(), String> {
+    if !registry().lock().sources.insert(source.clone()) {
+        return Ok(());
+    }
+
+    let result = read_font(&source).await;
+
+    let mut registry = registry().lock();
+    match result {
+        Ok(data) => {
+            log::info!(
+                "[Fonts] Loaded '{}' from {} ({} bytes)",
+                family,
+                source,
+                data.len()
+            );
+            registry.fonts.push(CustomFont {
+                family,
+                data: Arc::new(data),
+            });
+            Ok(())
+        },
+        Err(e) => {
+            registry.sources.remove(&source);
+            Err(e)
+        },
+    }
+}
+
+/// Register the bundled color emoji font from `resource_dir` as [`EMOJI_FAMILY`].
+pub async fn load_bundled_emoji_font(resource_dir: PathBuf) -> Result<(), String> {
+    let source = resource_dir.join(BUNDLED_EMOJI_FONT);
+    load_font(
+        EMOJI_FAMILY.to_string(),
+        source.to_string_lossy().into_owned(),
+    )
+    .await
+}
+
+/// Fonts loaded after the first `loaded` ones.
+///
+/// Text layers keep a count of fonts they have already added to their font system.
+pub fn fonts_since(loaded: usize) -> Vec<CustomFont> {
+    registry()
+        .lock()
+        .fonts
+        .get(loaded..)
+        .map(<[CustomFont]>::to_vec)
+        .unwrap_or_default()
+}
+
+async fn read_font(source: &str) -> Result<Vec<u8>, String> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        return Err(format!(
+            "Remote font URLs are not supported, download the font first: {}",
+            source
+        ));
+    }
+
+    let path = PathBuf::from(source.strip_prefix("file://").unwrap_or(source));
+    let data = tokio::fs::read(&path)
+        .await
+        .map_err(|e| format!("Failed to read font {:?}: {}", path, e))?;
+
+    if data.is_empty() {
+        return Err(format!("Font file is empty: {:?}", path));
+    }
+
+    Ok(data)
+}
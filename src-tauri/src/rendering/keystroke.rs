@@ -0,0 +1,223 @@
+//! Keystroke overlay for export.
+//!
+//! Lays out the most recent key presses from the `keystroke.json` sidecar as
+//! boxed PreparedText keycaps stacked from a frame corner, newest nearest the
+//! corner, so they are drawn by the same glyphon pipeline as text overlays.
+
+use glyphon::cosmic_text::Align;
+
+use super::text::{parse_color, PreparedText, MAX_FONT_SIZE_PX};
+use super::text_layer::LINE_HEIGHT_FACTOR;
+use crate::commands::video_recording::keystrokes::{KeystrokeEvent, KeystrokeRecording};
+use crate::commands::video_recording::video_project::{
+    KeystrokeConfig, KeystrokePosition, TextVerticalAlign, XY,
+};
+
+/// Keycap font family.
+const FONT_FAMILY: &str = "sans-serif";
+
+/// Keycap font weight.
+const FONT_WEIGHT: f32 = 600.0;
+
+/// Keycap box padding as a fraction of the font size.
+const CAP_PADDING_FACTOR: f32 = 0.4;
+
+/// Gap between stacked keycaps as a fraction of the font size.
+const CAP_GAP_FACTOR: f32 = 0.3;
+
+/// Keycaps fade out over the last part of their display time.
+const FADE_OUT_MS: u64 = 250;
+
+/// Keystroke overlay state for one export.
+pub struct KeystrokeOverlay {
+    config: KeystrokeConfig,
+    /// Recorded presses sorted by timestamp.
+    events: Vec<KeystrokeEvent>,
+}
+
+impl KeystrokeOverlay {
+    /// Create the overlay from the project config and recorded presses.
+    pub fn new(config: &KeystrokeConfig, recording: KeystrokeRecording) -> Self {
+        let mut events = recording.events;
+        events.sort_by_key(|event| event.timestamp_ms);
+
+        Self {
+            config: config.clone(),
+            events,
+        }
+    }
+
+    /// Prepare the keycaps visible at `time_ms` (recording clock).
+    ///
+    /// `padding` is the background padding in output pixels; the keycaps sit
+    /// inside it so they overlay the video rather than the canvas edge.
+    pub fn prepare(&self, output_size: XY<u32>, padding: f32, time_ms: u64) -> Vec<PreparedText> {
+        let display_ms = self.config.display_ms.max(1);
+        let end = self
+            .events
+            .partition_point(|event| event.timestamp_ms <= time_ms);
+        let start = self.events[..end]
+            .partition_point(|event| time_ms - event.timestamp_ms >= display_ms)
+            .max(end.saturating_sub(self.config.max_keys as usize));
+
+        // Newest first, so index 0 sits in the corner
+        self.events[start..end]
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(slot, event)| {
+                let remaining_ms = display_ms - (time_ms - event.timestamp_ms);
+                let opacity = (remaining_ms as f32 / FADE_OUT_MS as f32).min(1.0);
+                layout_keycap(
+                    &self.config,
+                    event.label(),
+                    slot,
+                    opacity,
+                    output_size,
+                    padding,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Place one keycap `slot` caps away from the configured corner.
+fn layout_keycap(
+    config: &KeystrokeConfig,
+    content: String,
+    slot: usize,
+    opacity: f32,
+    output_size: XY<u32>,
+    padding: f32,
+) -> PreparedText {
+    let width = output_size.x as f32;
+    let height = output_size.y as f32;
+    let height_scale = if output_size.y == 0 {
+        1.0
+    } else {
+        height / 1080.0
+    };
+
+    let font_size = (config.font_size.max(1.0) * height_scale).min(MAX_FONT_SIZE_PX);
+    let line_height = font_size * LINE_HEIGHT_FACTOR;
+    let cap_padding = font_size * CAP_PADDING_FACTOR;
+    let margin = config.margin.max(0.0) * height_scale;
+
+    // The box extends past the text by its padding; keep it inside the canvas
+    let inset = padding.max(0.0) + margin + cap_padding;
+    let inset_x = inset.min(((width - 1.0) / 2.0).max(0.0));
+    let inset_y = inset.min((height - line_height).max(0.0));
+    let step = line_height + 2.0 * cap_padding + font_size * CAP_GAP_FACTOR;
+    let offset = slot as f32 * step;
+
+    let (top, align) = match config.position {
+        KeystrokePosition::TopLeft => (inset_y + offset, Align::Left),
+        KeystrokePosition::TopRight => (inset_y + offset, Align::Right),
+        KeystrokePosition::BottomLeft => (height - inset_y - line_height - offset, Align::Left),
+        KeystrokePosition::BottomRight => (height - inset_y - line_height - offset, Align::Right),
+    };
+    let top = top.clamp(0.0, (height - line_height).max(0.0));
+
+    let background_opacity = config.background_opacity.clamp(0.0, 1.0);
+    let background_color = (background_opacity > 0.0).then(|| {
+        let [r, g, b, _] = parse_color(&config.background_color);
+        [r, g, b, background_opacity]
+    });
+
+    PreparedText {
+        content,
+        bounds: [
+            inset_x,
+            top,
+            width - inset_x,
+            (top + line_height).min(height),
+        ],
+        color: parse_color(&config.text_color),
+        font_family: FONT_FAMILY.to_string(),
+        font_size,
+        font_weight: FONT_WEIGHT,
+        italic: false,
+        opacity: opacity.clamp(0.0, 1.0),
+        align,
+        vertical_align: TextVerticalAlign::Top,
+        auto_fit: false,
+        letter_spacing: 0.0,
+        line_height: LINE_HEIGHT_FACTOR,
+        stroke_color: None,
+        stroke_width: 0.0,
+        background_color,
+        background_padding: cap_padding,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::video_recording::keystrokes::KeyModifier;
+
+    fn press(timestamp_ms: u64, key: &str, modifiers: Vec<KeyModifier>) -> KeystrokeEvent {
+        KeystrokeEvent {
+            timestamp_ms,
+            key: key.to_string(),
+            modifiers,
+        }
+    }
+
+    fn overlay(config: KeystrokeConfig) -> KeystrokeOverlay {
+        KeystrokeOverlay::new(
+            &config,
+            KeystrokeRecording {
+                events: vec![
+                    press(1000, "C", vec![KeyModifier::Ctrl]),
+                    press(1200, "V", vec![KeyModifier::Ctrl]),
+                    press(1400, "Enter", vec![]),
+                ],
+            },
+        )
+    }
+
+    #[test]
+    fn test_recent_presses_newest_in_corner() {
+        let keystrokes = overlay(KeystrokeConfig {
+            display_ms: 1000,
+            ..KeystrokeConfig::default()
+        });
+        let size = XY::new(1920, 1080);
+
+        assert!(keystrokes.prepare(size, 0.0, 900).is_empty());
+
+        let caps = keystrokes.prepare(size, 0.0, 1500);
+        let labels: Vec<_> = caps.iter().map(|cap| cap.content.as_str()).collect();
+        assert_eq!(labels, ["Enter", "Ctrl+V", "Ctrl+C"]);
+        // Bottom-right stacks upwards from the corner
+        assert!(caps[0].bounds[1] > caps[1].bounds[1]);
+        assert!(caps[1].bounds[1] > caps[2].bounds[1]);
+        assert_eq!(caps[0].align, Align::Right);
+        assert!(caps[0].background_color.is_some());
+
+        // Ctrl+C expires after display_ms and fades out just before
+        let caps = keystrokes.prepare(size, 0.0, 1900);
+        assert_eq!(caps.len(), 3);
+        assert!(caps[2].opacity < 1.0);
+        assert_eq!(keystrokes.prepare(size, 0.0, 2000).len(), 2);
+    }
+
+    #[test]
+    fn test_max_keys_and_top_left_layout() {
+        let keystrokes = overlay(KeystrokeConfig {
+            position: KeystrokePosition::TopLeft,
+            max_keys: 2,
+            ..KeystrokeConfig::default()
+        });
+        let caps = keystrokes.prepare(XY::new(1920, 1080), 40.0, 1400);
+
+        assert_eq!(caps.len(), 2);
+        assert_eq!(caps[0].content, "Enter");
+        assert_eq!(caps[0].align, Align::Left);
+        // Padding + margin + keycap padding at 1080p
+        let inset = 40.0 + 32.0 + 28.0 * CAP_PADDING_FACTOR;
+        assert!((caps[0].bounds[0] - inset).abs() < 0.01);
+        assert!((caps[0].bounds[1] - inset).abs() < 0.01);
+        assert!(caps[1].bounds[1] > caps[0].bounds[1]);
+    }
+}
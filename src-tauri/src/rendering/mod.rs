@@ -10,6 +10,8 @@
 //! - `compositor`: Frame compositing pipeline
 //! - `background`: Background rendering (solid colors, gradients, images)
 //! - `zoom`: Zoom interpolation with bezier easing
+//! - `timecode`: Burned-in timecode overlay for export
+//! - `fonts`: Runtime-loaded custom fonts for text overlays
 //! - `editor_instance`: Playback state management
 
 pub mod background;
@@ -19,6 +21,8 @@ pub mod cursor;
 pub mod decoder;
 pub mod editor_instance;
 pub mod exporter;
+pub mod fonts;
+pub mod keystroke;
 pub mod renderer;
 pub mod renderer_state;
 pub mod scene;
@@ -26,6 +30,7 @@ pub mod stream_decoder;
 pub mod svg_cursor;
 pub mod text;
 pub mod text_layer;
+pub mod timecode;
 pub mod types;
 pub mod zoom;
 
@@ -42,12 +47,14 @@ pub use cursor::{
 pub use decoder::VideoDecoder;
 pub use editor_instance::EditorInstance;
 pub use exporter::export_video_gpu;
+pub use keystroke::KeystrokeOverlay;
 pub use renderer::Renderer;
 pub use renderer_state::RendererState;
 pub use scene::{InterpolatedScene, SceneInterpolator};
 pub use stream_decoder::StreamDecoder;
 pub use svg_cursor::{get_svg_cursor, render_svg_cursor, RenderedSvgCursor};
-pub use text::{parse_color, prepare_texts, PreparedText};
+pub use text::{parse_color, prepare_text, prepare_texts, PreparedText};
 pub use text_layer::TextLayer;
+pub use timecode::TimecodeOverlay;
 pub use types::*;
 pub use zoom::ZoomInterpolator;
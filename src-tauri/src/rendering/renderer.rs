@@ -188,18 +188,29 @@ impl Renderer {
         let _ = rx.await;
 
         let data = buffer_slice.get_mapped_range();
+        unpad_rows(&data, bytes_per_row, padded_bytes_per_row, height)
+    }
+
+    /// Create a ring of `size` read-back buffers for `width`x`height` frames.
+    pub fn create_readback_ring(&self, width: u32, height: u32, size: usize) -> ReadbackRing {
+        let padded_bytes_per_row = (4 * width + 255) & !255;
+        let buffers = (0..size)
+            .map(|_| {
+                self.device.create_buffer(&wgpu::BufferDescriptor {
+                    label: Some("Readback Ring Buffer"),
+                    size: (padded_bytes_per_row * height) as u64,
+                    usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+                    mapped_at_creation: false,
+                })
+            })
+            .collect();
 
-        // Remove padding if present
-        if padded_bytes_per_row != bytes_per_row {
-            let mut result = Vec::with_capacity((bytes_per_row * height) as usize);
-            for row in 0..height {
-                let start = (row * padded_bytes_per_row) as usize;
-                let end = start + bytes_per_row as usize;
-                result.extend_from_slice(&data[start..end]);
-            }
-            result
-        } else {
-            data.to_vec()
+        ReadbackRing {
+            buffers,
+            next: 0,
+            width,
+            height,
+            padded_bytes_per_row,
         }
     }
 
@@ -212,3 +223,112 @@ impl Renderer {
             })
     }
 }
+
+/// Strip the 256-byte row alignment from read-back data.
+fn unpad_rows(data: &[u8], bytes_per_row: u32, padded_bytes_per_row: u32, height: u32) -> Vec<u8> {
+    if padded_bytes_per_row == bytes_per_row {
+        return data.to_vec();
+    }
+
+    let mut result = Vec::with_capacity((bytes_per_row * height) as usize);
+    for row in 0..height {
+        let start = (row * padded_bytes_per_row) as usize;
+        let end = start + bytes_per_row as usize;
+        result.extend_from_slice(&data[start..end]);
+    }
+    result
+}
+
+/// Ring of map buffers for pipelined texture read-back.
+///
+/// `start` queues the copy of a frame and returns without waiting, so the GPU
+/// renders the next frames while earlier ones are mapped. At most one pending
+/// read-back per buffer may be outstanding: finish the oldest before starting
+/// more than the ring size.
+pub struct ReadbackRing {
+    buffers: Vec<wgpu::Buffer>,
+    next: usize,
+    width: u32,
+    height: u32,
+    padded_bytes_per_row: u32,
+}
+
+/// Read-back started by `ReadbackRing::start`.
+pub struct PendingReadback {
+    slot: usize,
+    submission: wgpu::SubmissionIndex,
+    mapped: tokio::sync::oneshot::Receiver<Result<(), wgpu::BufferAsyncError>>,
+}
+
+impl ReadbackRing {
+    /// Copy `texture` into the next buffer and begin mapping it.
+    pub fn start(&mut self, renderer: &Renderer, texture: &wgpu::Texture) -> PendingReadback {
+        let slot = self.next;
+        self.next = (self.next + 1) % self.buffers.len();
+        let buffer = &self.buffers[slot];
+
+        let mut encoder = renderer
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Readback Ring Encoder"),
+            });
+
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(self.padded_bytes_per_row),
+                    rows_per_image: Some(self.height),
+                },
+            },
+            wgpu::Extent3d {
+                width: self.width,
+                height: self.height,
+                depth_or_array_layers: 1,
+            },
+        );
+
+        let submission = renderer.queue.submit(Some(encoder.finish()));
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        buffer
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                let _ = tx.send(result);
+            });
+
+        PendingReadback {
+            slot,
+            submission,
+            mapped: rx,
+        }
+    }
+
+    /// Wait for `pending` (and only the GPU work submitted before it) and return its RGBA data.
+    pub async fn finish(&self, renderer: &Renderer, pending: PendingReadback) -> Vec<u8> {
+        let _ = renderer
+            .device
+            .poll(wgpu::PollType::WaitForSubmissionIndex(pending.submission));
+        let _ = pending.mapped.await;
+
+        let buffer = &self.buffers[pending.slot];
+        let result = {
+            let data = buffer.slice(..).get_mapped_range();
+            unpad_rows(
+                &data,
+                4 * self.width,
+                self.padded_bytes_per_row,
+                self.height,
+            )
+        };
+        buffer.unmap();
+        result
+    }
+}
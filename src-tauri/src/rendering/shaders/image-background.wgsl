@@ -6,9 +6,13 @@ struct Uniforms {
     padding: f32,
     x_width: f32,
     y_height: f32,
-    _padding: f32,  // For 16-byte alignment
+    blur_radius: f32,  // In output pixels, 0 = no blur
 }
 
+// Vogel disk sampling for the background blur
+const BLUR_SAMPLES: i32 = 64;
+const GOLDEN_ANGLE: f32 = 2.39996323;
+
 @group(0) @binding(0) var<uniform> u: Uniforms;
 @group(0) @binding(1) var t_image: texture_2d<f32>;
 @group(0) @binding(2) var s_image: sampler;
@@ -40,5 +44,24 @@ fn fs_main(@location(0) tex_coords: vec2<f32>) -> @location(0) vec4<f32> {
         u.y_height + y_height * tex_coords.y
     );
 
-    return textureSample(t_image, s_image, sample_coords);
+    if (u.blur_radius < 0.5) {
+        return textureSample(t_image, s_image, sample_coords);
+    }
+
+    // Blur radius converted from output pixels to image UV space
+    let radius_uv = u.blur_radius / u.output_size * vec2<f32>(x_width, y_height);
+
+    var color = vec4<f32>(0.0);
+    var total_weight = 0.0;
+    for (var i = 0; i < BLUR_SAMPLES; i++) {
+        let r = sqrt((f32(i) + 0.5) / f32(BLUR_SAMPLES));
+        let theta = f32(i) * GOLDEN_ANGLE;
+        let offset = vec2<f32>(cos(theta), sin(theta)) * r * radius_uv;
+        // Gaussian-like falloff towards the edge of the disk
+        let weight = exp(-2.0 * r * r);
+        color += textureSample(t_image, s_image, sample_coords + offset) * weight;
+        total_weight += weight;
+    }
+
+    return color / total_weight;
 }
@@ -0,0 +1,54 @@
+// Shader for the rounded background boxes drawn behind text overlays.
+// One instance per box; the quad is generated from the vertex index.
+
+struct InstanceInput {
+    // Box as [left, top, right, bottom] in clip space
+    @location(0) clip_rect: vec4<f32>,
+    // Box size in pixels (xy) and corner radius in pixels (z)
+    @location(1) size_radius: vec4<f32>,
+    // Linear, premultiplied color
+    @location(2) color: vec4<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) local: vec2<f32>,
+    @location(1) size_radius: vec4<f32>,
+    @location(2) color: vec4<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) in_vertex_index: u32, instance: InstanceInput) -> VertexOutput {
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(0.0, 0.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(1.0, 1.0),
+    );
+    let corner = corners[in_vertex_index];
+
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(
+        mix(instance.clip_rect.x, instance.clip_rect.z, corner.x),
+        mix(instance.clip_rect.y, instance.clip_rect.w, corner.y),
+        0.0,
+        1.0,
+    );
+    out.local = corner * instance.size_radius.xy;
+    out.size_radius = instance.size_radius;
+    out.color = instance.color;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    // Signed distance to the rounded rectangle, anti-aliased over one pixel
+    let half_size = in.size_radius.xy * 0.5;
+    let radius = min(in.size_radius.z, min(half_size.x, half_size.y));
+    let q = abs(in.local - half_size) - half_size + vec2<f32>(radius);
+    let dist = length(max(q, vec2<f32>(0.0))) + min(max(q.x, q.y), 0.0) - radius;
+    let coverage = clamp(0.5 - dist, 0.0, 1.0);
+    return in.color * coverage;
+}
@@ -1,13 +1,17 @@
 //! Streaming video decoder - single FFmpeg process for all frames.
 //!
 //! Uses tokio async I/O for non-blocking reads from FFmpeg stdout.
+//! `SegmentedDecoder` chains one stream per kept timeline range.
 
-use std::path::Path;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use std::sync::Arc;
 use tokio::io::AsyncReadExt;
 use tokio::process::{Child, Command};
 
 use super::types::DecodedFrame;
+use crate::commands::video_recording::video_project::TimelineSegment;
 
 /// Streaming video decoder using a single FFmpeg process.
 pub struct StreamDecoder {
@@ -205,6 +209,92 @@ impl Drop for StreamDecoder {
     }
 }
 
+/// Serves frames by source time from several ranges, one FFmpeg process per range.
+///
+/// Requests must not go back in time. Frames are skipped when requests move
+/// faster than the source (sped-up sections) and repeated when slower.
+pub struct SegmentedDecoder {
+    path: PathBuf,
+    /// Ranges not yet started.
+    ranges: VecDeque<TimelineSegment>,
+    /// Decoder of the current range, with the range.
+    current: Option<(StreamDecoder, TimelineSegment)>,
+    /// Latest frame due, with its source time. Shared with callers, since
+    /// slowed-down sections serve it repeatedly.
+    last: Option<(u64, Arc<DecodedFrame>)>,
+    /// Frame read ahead of `last` that isn't due yet.
+    next: Option<(u64, DecodedFrame)>,
+}
+
+impl SegmentedDecoder {
+    /// Create a decoder over `ranges` (source ms, in playback order).
+    ///
+    /// FFmpeg is started lazily, one range at a time.
+    pub fn new(path: &Path, ranges: Vec<TimelineSegment>) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            ranges: ranges.into(),
+            current: None,
+            last: None,
+            next: None,
+        }
+    }
+
+    /// The frame showing at `source_ms`, or None past the last range.
+    pub async fn frame_at(&mut self, source_ms: u64) -> Result<Option<Arc<DecodedFrame>>, String> {
+        let in_current = self
+            .current
+            .as_ref()
+            .is_some_and(|(_, range)| source_ms < range.end_ms);
+        if !in_current && !self.start_range_at(source_ms).await? {
+            return Ok(None);
+        }
+        let Some((decoder, range)) = self.current.as_mut() else {
+            return Ok(None);
+        };
+
+        loop {
+            if self.next.is_none() {
+                self.next = decoder
+                    .next_frame()
+                    .await?
+                    .map(|frame| (range.start_ms + frame.timestamp_ms, frame));
+            }
+            match self.next {
+                Some((time_ms, _)) if time_ms <= source_ms || self.last.is_none() => {
+                    self.last = self
+                        .next
+                        .take()
+                        .map(|(time_ms, frame)| (time_ms, Arc::new(frame)));
+                },
+                _ => break,
+            }
+        }
+
+        Ok(self.last.as_ref().map(|(_, frame)| Arc::clone(frame)))
+    }
+
+    /// Start decoding the range containing `source_ms`, dropping earlier ones.
+    /// Returns false when no range is left.
+    async fn start_range_at(&mut self, source_ms: u64) -> Result<bool, String> {
+        if let Some((mut decoder, _)) = self.current.take() {
+            decoder.stop().await;
+        }
+        self.last = None;
+        self.next = None;
+
+        while let Some(range) = self.ranges.pop_front() {
+            if range.end_ms > source_ms {
+                let mut decoder = StreamDecoder::new(&self.path, range.start_ms, range.end_ms)?;
+                decoder.start(&self.path)?;
+                self.current = Some((decoder, range));
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
 /// Video metadata from ffprobe.
 struct VideoMetadata {
     width: u32,
@@ -5,13 +5,24 @@
 //!
 //! Based on Cap's text rendering implementation.
 
-use crate::commands::video_recording::video_project::{TextSegment, XY};
+use glyphon::cosmic_text::Align;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::commands::video_recording::video_project::{
+    TextAnimation, TextSegment, TextVerticalAlign, XY,
+};
 
 /// Base text height used for size scaling calculations.
 const BASE_TEXT_HEIGHT: f64 = 0.2;
 
 /// Maximum font size in pixels to prevent performance issues.
-const MAX_FONT_SIZE_PX: f32 = 256.0;
+pub(super) const MAX_FONT_SIZE_PX: f32 = 256.0;
+
+/// Maximum outline width in pixels.
+const MAX_STROKE_PX: f32 = 32.0;
+
+/// Maximum background box padding in pixels.
+const MAX_BACKGROUND_PADDING_PX: f32 = 128.0;
 
 /// Prepared text segment ready for GPU rendering.
 #[derive(Debug, Clone)]
@@ -32,6 +43,24 @@ pub struct PreparedText {
     pub italic: bool,
     /// Opacity (0.0-1.0), used for fade animations.
     pub opacity: f32,
+    /// Horizontal alignment within the bounds.
+    pub align: Align,
+    /// Vertical alignment within the bounds.
+    pub vertical_align: TextVerticalAlign,
+    /// Shrink the font size until the text fits the bounds.
+    pub auto_fit: bool,
+    /// Extra spacing between letters, in ems.
+    pub letter_spacing: f32,
+    /// Line height as a multiple of the font size.
+    pub line_height: f32,
+    /// Outline color as RGBA (0.0-1.0), if outlined.
+    pub stroke_color: Option<[f32; 4]>,
+    /// Outline width in pixels.
+    pub stroke_width: f32,
+    /// Background box color as RGBA (0.0-1.0), if boxed. Alpha is the box opacity.
+    pub background_color: Option<[f32; 4]>,
+    /// Padding between the text and the box edge in pixels (also the corner radius).
+    pub background_padding: f32,
 }
 
 /// Parse a hex color string to RGBA values.
@@ -50,6 +79,118 @@ pub fn parse_color(hex: &str) -> [f32; 4] {
     [1.0, 1.0, 1.0, 1.0]
 }
 
+/// Portion of the segment's content visible at `frame_time`.
+///
+/// Typewriter reveals whole grapheme clusters, so multi-codepoint emoji never
+/// appear half-drawn. Once fully revealed the text stays.
+fn visible_content(segment: &TextSegment, frame_time: f64) -> &str {
+    let Some(TextAnimation::Typewriter { chars_per_sec }) = segment.animation else {
+        return &segment.content;
+    };
+    if !chars_per_sec.is_finite() || chars_per_sec <= 0.0 {
+        return &segment.content;
+    }
+
+    let revealed = ((frame_time - segment.start).max(0.0) * chars_per_sec).floor() as usize;
+    match segment.content.grapheme_indices(true).nth(revealed) {
+        Some((end, _)) => &segment.content[..end],
+        None => &segment.content,
+    }
+}
+
+/// Split `content` into runs of emoji and other text, as `(run, is_emoji)`.
+///
+/// Emoji runs are shaped with a color emoji font so they don't pick up
+/// monochrome glyphs from the text font (or its symbol fallbacks).
+pub(super) fn emoji_runs(content: &str) -> Vec<(&str, bool)> {
+    let mut runs: Vec<(&str, bool)> = Vec::new();
+    let mut run_start = 0;
+    let mut run_emoji = false;
+
+    for (index, grapheme) in content.grapheme_indices(true) {
+        let emoji = is_emoji(grapheme);
+        if emoji != run_emoji && index > run_start {
+            runs.push((&content[run_start..index], run_emoji));
+            run_start = index;
+        }
+        run_emoji = emoji;
+    }
+    if run_start < content.len() {
+        runs.push((&content[run_start..], run_emoji));
+    }
+    runs
+}
+
+/// Whether a grapheme cluster is shown as an emoji: a character that defaults
+/// to emoji presentation, or one followed by VS16 or a keycap mark. VS15
+/// asks for text presentation.
+fn is_emoji(grapheme: &str) -> bool {
+    if grapheme.contains('\u{FE0E}') {
+        return false;
+    }
+    if grapheme.contains('\u{FE0F}') || grapheme.contains('\u{20E3}') {
+        return true;
+    }
+    grapheme.chars().next().is_some_and(is_emoji_presentation)
+}
+
+/// Characters with default emoji presentation (the pictograph blocks are
+/// taken whole).
+fn is_emoji_presentation(c: char) -> bool {
+    matches!(
+        c,
+        '\u{231A}'..='\u{231B}'
+            | '\u{23E9}'..='\u{23EC}'
+            | '\u{23F0}'
+            | '\u{23F3}'
+            | '\u{25FD}'..='\u{25FE}'
+            | '\u{2614}'..='\u{2615}'
+            | '\u{2648}'..='\u{2653}'
+            | '\u{267F}'
+            | '\u{2693}'
+            | '\u{26A1}'
+            | '\u{26AA}'..='\u{26AB}'
+            | '\u{26BD}'..='\u{26BE}'
+            | '\u{26C4}'..='\u{26C5}'
+            | '\u{26CE}'
+            | '\u{26D4}'
+            | '\u{26EA}'
+            | '\u{26F2}'..='\u{26F3}'
+            | '\u{26F5}'
+            | '\u{26FA}'
+            | '\u{26FD}'
+            | '\u{2705}'
+            | '\u{270A}'..='\u{270B}'
+            | '\u{2728}'
+            | '\u{274C}'
+            | '\u{274E}'
+            | '\u{2753}'..='\u{2755}'
+            | '\u{2757}'
+            | '\u{2795}'..='\u{2797}'
+            | '\u{27B0}'
+            | '\u{27BF}'
+            | '\u{2B1B}'..='\u{2B1C}'
+            | '\u{2B50}'
+            | '\u{2B55}'
+            | '\u{1F004}'
+            | '\u{1F0CF}'
+            | '\u{1F18E}'
+            | '\u{1F191}'..='\u{1F19A}'
+            | '\u{1F1E6}'..='\u{1F1FF}'
+            | '\u{1F201}'
+            | '\u{1F21A}'
+            | '\u{1F22F}'
+            | '\u{1F232}'..='\u{1F236}'
+            | '\u{1F238}'..='\u{1F23A}'
+            | '\u{1F250}'..='\u{1F251}'
+            | '\u{1F300}'..='\u{1F64F}'
+            | '\u{1F680}'..='\u{1F6FF}'
+            | '\u{1F7E0}'..='\u{1F7EB}'
+            | '\u{1F90C}'..='\u{1F9FF}'
+            | '\u{1FA70}'..='\u{1FAFF}'
+    )
+}
+
 /// Prepare text segments for rendering at a specific frame time.
 ///
 /// Filters segments by time, calculates positions/sizes, and applies fade animations.
@@ -58,78 +199,103 @@ pub fn prepare_texts(
     frame_time: f64,
     segments: &[TextSegment],
 ) -> Vec<PreparedText> {
-    let mut prepared = Vec::new();
+    segments
+        .iter()
+        .filter(|segment| {
+            segment.enabled && frame_time >= segment.start && frame_time <= segment.end
+        })
+        .map(|segment| prepare_text(output_size, frame_time, segment))
+        .collect()
+}
+
+/// Prepare a single segment at `frame_time`, regardless of whether it is visible then.
+pub fn prepare_text(output_size: XY<u32>, frame_time: f64, segment: &TextSegment) -> PreparedText {
     let height_scale = if output_size.y == 0 {
         1.0
     } else {
         output_size.y as f32 / 1080.0
     };
 
-    for segment in segments {
-        if !segment.enabled {
-            continue;
-        }
+    let center = XY::new(
+        segment.center.x.clamp(0.0, 1.0),
+        segment.center.y.clamp(0.0, 1.0),
+    );
+    let size = XY::new(
+        segment.size.x.clamp(0.01, 2.0),
+        segment.size.y.clamp(0.01, 2.0),
+    );
+    let size_scale = (size.y / BASE_TEXT_HEIGHT).clamp(0.25, 4.0) as f32;
 
-        if frame_time < segment.start || frame_time > segment.end {
-            continue;
-        }
+    let width = (size.x * output_size.x as f64).max(1.0) as f32;
+    let height = (size.y * output_size.y as f64).max(1.0) as f32;
+    let half_w = width / 2.0;
+    let half_h = height / 2.0;
 
-        let center = XY::new(
-            segment.center.x.clamp(0.0, 1.0),
-            segment.center.y.clamp(0.0, 1.0),
-        );
-        let size = XY::new(
-            segment.size.x.clamp(0.01, 2.0),
-            segment.size.y.clamp(0.01, 2.0),
-        );
-        let size_scale = (size.y / BASE_TEXT_HEIGHT).clamp(0.25, 4.0) as f32;
-
-        let width = (size.x * output_size.x as f64).max(1.0) as f32;
-        let height = (size.y * output_size.y as f64).max(1.0) as f32;
-        let half_w = width / 2.0;
-        let half_h = height / 2.0;
-
-        let left = (center.x as f32 * output_size.x as f32 - half_w).max(0.0);
-        let top = (center.y as f32 * output_size.y as f32 - half_h).max(0.0);
-        let right = (left + width).min(output_size.x as f32);
-        let bottom = (top + height).min(output_size.y as f32);
-
-        let fade_duration = segment.fade_duration.max(0.0);
-        let opacity = if fade_duration > 0.0 {
-            let time_since_start = frame_time - segment.start;
-            let time_until_end = segment.end - frame_time;
-            let segment_duration = segment.end - segment.start;
-
-            // Fade in at start
-            if time_since_start < fade_duration {
-                (time_since_start / fade_duration).clamp(0.0, 1.0) as f32
-            }
-            // Fade out at end (only if segment long enough for both fades)
-            else if time_until_end < fade_duration && segment_duration > fade_duration * 2.0 {
-                (time_until_end / fade_duration).clamp(0.0, 1.0) as f32
-            }
-            // Hold at full opacity
-            else {
-                1.0
-            }
-        } else {
+    let left = (center.x as f32 * output_size.x as f32 - half_w).max(0.0);
+    let top = (center.y as f32 * output_size.y as f32 - half_h).max(0.0);
+    let right = (left + width).min(output_size.x as f32);
+    let bottom = (top + height).min(output_size.y as f32);
+
+    let fade_duration = segment.fade_duration.max(0.0);
+    let opacity = if fade_duration > 0.0 {
+        let time_since_start = frame_time - segment.start;
+        let time_until_end = segment.end - frame_time;
+        let segment_duration = segment.end - segment.start;
+
+        // Fade in at start
+        if time_since_start < fade_duration {
+            (time_since_start / fade_duration).clamp(0.0, 1.0) as f32
+        }
+        // Fade out at end (only if segment long enough for both fades)
+        else if time_until_end < fade_duration && segment_duration > fade_duration * 2.0 {
+            (time_until_end / fade_duration).clamp(0.0, 1.0) as f32
+        }
+        // Hold at full opacity
+        else {
             1.0
-        };
+        }
+    } else {
+        1.0
+    };
 
-        prepared.push(PreparedText {
-            content: segment.content.clone(),
-            bounds: [left, top, right, bottom],
-            color: parse_color(&segment.color),
-            font_family: segment.font_family.clone(),
-            font_size: ((segment.font_size * size_scale).max(1.0) * height_scale)
-                .min(MAX_FONT_SIZE_PX),
-            font_weight: segment.font_weight,
-            italic: segment.italic,
-            opacity,
+    let text_scale = size_scale * height_scale;
+    let stroke_width = (segment.stroke_width.max(0.0) as f32 * text_scale).min(MAX_STROKE_PX);
+    let stroke_color = segment
+        .stroke_color
+        .as_deref()
+        .filter(|_| stroke_width > 0.0)
+        .map(parse_color);
+    let background_padding =
+        (segment.background_padding.max(0.0) as f32 * text_scale).min(MAX_BACKGROUND_PADDING_PX);
+    let background_opacity = segment.background_opacity.clamp(0.0, 1.0) as f32;
+    let background_color = segment
+        .background_color
+        .as_deref()
+        .filter(|_| background_opacity > 0.0)
+        .map(|hex| {
+            let [r, g, b, _] = parse_color(hex);
+            [r, g, b, background_opacity]
         });
-    }
 
-    prepared
+    PreparedText {
+        content: visible_content(segment, frame_time).to_string(),
+        bounds: [left, top, right, bottom],
+        color: parse_color(&segment.color),
+        font_family: segment.font_family.clone(),
+        font_size: ((segment.font_size * size_scale).max(1.0) * height_scale).min(MAX_FONT_SIZE_PX),
+        font_weight: segment.font_weight,
+        italic: segment.italic,
+        opacity,
+        align: Align::Center,
+        vertical_align: segment.vertical_align,
+        auto_fit: segment.auto_fit,
+        letter_spacing: segment.letter_spacing.clamp(-0.5, 2.0) as f32,
+        line_height: segment.line_height.clamp(0.5, 3.0) as f32,
+        stroke_color,
+        stroke_width,
+        background_color,
+        background_padding,
+    }
 }
 
 #[cfg(test)]
@@ -153,4 +319,76 @@ mod tests {
         assert!((color[2] - 1.0).abs() < 0.01);
         assert!((color[3] - 1.0).abs() < 0.01);
     }
+
+    #[test]
+    fn test_prepare_texts_stroke() {
+        let outlined = TextSegment {
+            stroke_color: Some("#000000".to_string()),
+            stroke_width: 3.0,
+            ..TextSegment::default()
+        };
+        let unstroked = TextSegment {
+            stroke_width: 0.0,
+            ..outlined.clone()
+        };
+
+        let prepared = prepare_texts(XY::new(1920, 1080), 1.0, &[outlined, unstroked]);
+        assert_eq!(prepared[0].stroke_color, Some([0.0, 0.0, 0.0, 1.0]));
+        assert!((prepared[0].stroke_width - 3.0).abs() < 0.01);
+        assert_eq!(prepared[1].stroke_color, None);
+    }
+
+    #[test]
+    fn test_prepare_texts_background() {
+        let boxed = TextSegment {
+            background_color: Some("#000000".to_string()),
+            background_opacity: 0.6,
+            background_padding: 12.0,
+            ..TextSegment::default()
+        };
+        let transparent = TextSegment {
+            background_opacity: 0.0,
+            ..boxed.clone()
+        };
+
+        let prepared = prepare_texts(XY::new(1920, 1080), 1.0, &[boxed, transparent]);
+        assert_eq!(prepared[0].background_color, Some([0.0, 0.0, 0.0, 0.6]));
+        assert!((prepared[0].background_padding - 12.0).abs() < 0.01);
+        assert_eq!(prepared[1].background_color, None);
+    }
+
+    #[test]
+    fn test_typewriter_reveals_graphemes() {
+        let segment = TextSegment {
+            start: 1.0,
+            end: 10.0,
+            content: "Hi 👍🏽!".to_string(),
+            animation: Some(TextAnimation::Typewriter { chars_per_sec: 2.0 }),
+            ..TextSegment::default()
+        };
+
+        assert_eq!(visible_content(&segment, 1.0), "");
+        assert_eq!(visible_content(&segment, 2.0), "Hi");
+        // The skin-tone modifier is part of the same cluster
+        assert_eq!(visible_content(&segment, 3.0), "Hi 👍🏽");
+        assert_eq!(visible_content(&segment, 9.0), "Hi 👍🏽!");
+    }
+
+    #[test]
+    fn test_emoji_runs() {
+        assert_eq!(emoji_runs("Hi"), vec![("Hi", false)]);
+        assert_eq!(
+            emoji_runs("Ship it 🚀👍🏽 now ❤️"),
+            vec![
+                ("Ship it ", false),
+                ("🚀👍🏽", true),
+                (" now ", false),
+                ("❤️", true),
+            ]
+        );
+        // Keycaps and flags are emoji, text-presentation symbols are not
+        assert_eq!(emoji_runs("1️⃣🇯🇵"), vec![("1️⃣🇯🇵", true)]);
+        assert_eq!(emoji_runs("★ ❤︎"), vec![("★ ❤︎", false)]);
+        assert_eq!(emoji_runs(""), vec![]);
+    }
 }
@@ -3,15 +3,49 @@
 //! Renders text overlays onto video frames using GPU-accelerated text rasterization.
 //! Based on Cap's text rendering implementation.
 
-use glyphon::cosmic_text::Align;
+use std::collections::HashMap;
+
+use bytemuck::{Pod, Zeroable};
+use glyphon::cosmic_text::fontdb;
 use glyphon::{
-    Attrs, Buffer, Cache, Color, Family, FontSystem, Metrics, Resolution, Shaping, Style,
-    SwashCache, TextArea, TextAtlas, TextBounds, TextRenderer, Viewport, Weight,
+    Attrs, Buffer, Cache, CacheKey, Color, ContentType, CustomGlyph, CustomGlyphId, Family,
+    FontSystem, Metrics, RasterizeCustomGlyphRequest, RasterizedCustomGlyph, Resolution, Shaping,
+    Style, SwashCache, SwashContent, TextArea, TextAtlas, TextBounds, TextRenderer, Viewport,
+    Weight,
 };
 use log::warn;
 use wgpu::{Device, Queue};
 
-use crate::rendering::text::PreparedText;
+use crate::commands::video_recording::video_project::TextVerticalAlign;
+use crate::rendering::fonts::{self, EMOJI_FAMILY};
+use crate::rendering::text::{emoji_runs, PreparedText};
+use crate::rendering::types::TextMeasurement;
+
+/// Default line height relative to font size.
+pub const LINE_HEIGHT_FACTOR: f32 = 1.2;
+
+/// Smallest font size auto-fit will shrink text to.
+const MIN_AUTO_FIT_FONT_SIZE: f32 = 4.0;
+
+/// Binary search steps for auto-fit (~0.4% of the configured size).
+const AUTO_FIT_ITERATIONS: u32 = 8;
+
+/// Color emoji font the platform ships, used until the bundled one has loaded.
+#[cfg(target_os = "windows")]
+const SYSTEM_EMOJI_FAMILY: &str = "Segoe UI Emoji";
+#[cfg(target_os = "macos")]
+const SYSTEM_EMOJI_FAMILY: &str = "Apple Color Emoji";
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const SYSTEM_EMOJI_FAMILY: &str = "Noto Color Emoji";
+
+/// Glyph masks kept for building outlines before the cache is cleared.
+const MAX_GLYPH_MASKS: usize = 4096;
+
+/// Outlines kept before the atlas is rebuilt.
+///
+/// The atlas caches outlines by custom glyph id, so ids are only reused with a
+/// fresh atlas.
+const MAX_OUTLINES: usize = 64;
 
 /// GPU text rendering layer.
 pub struct TextLayer {
@@ -20,7 +54,16 @@ pub struct TextLayer {
     text_atlas: TextAtlas,
     text_renderer: TextRenderer,
     viewport: Viewport,
+    cache: Cache,
     buffers: Vec<Buffer>,
+    /// Empty buffer for the text areas that only draw outline glyphs.
+    outline_buffer: Buffer,
+    outlines: Outlines,
+    background_pipeline: TextBackgroundPipeline,
+    /// Number of registry fonts already added to `font_system`.
+    custom_fonts_loaded: usize,
+    /// Lowercase custom family name -> family name inside the font file.
+    custom_families: HashMap<String, String>,
 }
 
 impl TextLayer {
@@ -30,14 +73,7 @@ impl TextLayer {
         let swash_cache = SwashCache::new();
         let cache = Cache::new(device);
         let viewport = Viewport::new(device, &cache);
-        let mut text_atlas =
-            TextAtlas::new(device, queue, &cache, wgpu::TextureFormat::Rgba8UnormSrgb);
-        let text_renderer = TextRenderer::new(
-            &mut text_atlas,
-            device,
-            wgpu::MultisampleState::default(),
-            None,
-        );
+        let (text_atlas, text_renderer) = new_atlas(device, queue, &cache);
 
         Self {
             font_system,
@@ -45,7 +81,36 @@ impl TextLayer {
             text_atlas,
             text_renderer,
             viewport,
+            cache,
             buffers: Vec::new(),
+            outline_buffer: Buffer::new_empty(Metrics::new(1.0, 1.0)),
+            outlines: Outlines::default(),
+            background_pipeline: TextBackgroundPipeline::new(device),
+            custom_fonts_loaded: 0,
+            custom_families: HashMap::new(),
+        }
+    }
+
+    /// Add fonts loaded since the last call to the font system.
+    fn sync_custom_fonts(&mut self) {
+        for font in fonts::fonts_since(self.custom_fonts_loaded) {
+            self.custom_fonts_loaded += 1;
+
+            let db = self.font_system.db_mut();
+            let ids = db.load_font_source(fontdb::Source::Binary(font.data));
+            let face_family = ids
+                .first()
+                .and_then(|id| db.face(*id))
+                .and_then(|face| face.families.first())
+                .map(|(name, _)| name.clone());
+
+            match face_family {
+                Some(face_family) => {
+                    self.custom_families
+                        .insert(font.family.to_ascii_lowercase(), face_family);
+                },
+                None => warn!("No usable font faces in custom font '{}'", font.family),
+            }
         }
     }
 
@@ -59,61 +124,31 @@ impl TextLayer {
         output_size: (u32, u32),
         texts: &[PreparedText],
     ) {
+        self.sync_custom_fonts();
+        if self.outlines.masks.len() >= MAX_OUTLINES {
+            self.outlines.clear_atlas_entries();
+            (self.text_atlas, self.text_renderer) = new_atlas(device, queue, &self.cache);
+        }
         self.buffers.clear();
         self.buffers.reserve(texts.len());
         let mut text_area_data = Vec::with_capacity(texts.len());
+        let mut outline_glyphs = Vec::new();
+        let mut backgrounds = Vec::new();
 
         for text in texts {
-            let alpha = text.color[3].clamp(0.0, 1.0) * text.opacity.clamp(0.0, 1.0);
-            let color = Color::rgba(
-                (text.color[0].clamp(0.0, 1.0) * 255.0) as u8,
-                (text.color[1].clamp(0.0, 1.0) * 255.0) as u8,
-                (text.color[2].clamp(0.0, 1.0) * 255.0) as u8,
-                (alpha * 255.0) as u8,
-            );
+            let color = to_glyphon_color(text.color, text.opacity);
 
             let width = (text.bounds[2] - text.bounds[0]).max(1.0);
             let height = (text.bounds[3] - text.bounds[1]).max(1.0);
 
-            let metrics = Metrics::new(text.font_size, text.font_size * 1.2);
-            let mut buffer = Buffer::new(&mut self.font_system, metrics);
-            buffer.set_size(&mut self.font_system, Some(width), Some(height));
-            buffer.set_wrap(&mut self.font_system, glyphon::Wrap::Word);
-
-            let family = match text.font_family.trim() {
-                "" => Family::SansSerif,
-                name => match name.to_ascii_lowercase().as_str() {
-                    "sans" | "sans-serif" | "system sans" | "system sans-serif" => {
-                        Family::SansSerif
-                    },
-                    "serif" | "system serif" => Family::Serif,
-                    "mono" | "monospace" | "system mono" | "system monospace" => Family::Monospace,
-                    _ => Family::Name(name),
-                },
-            };
-            let weight = Weight(text.font_weight.round().clamp(100.0, 900.0) as u16);
-            let attrs = Attrs::new()
-                .family(family)
-                .color(color)
-                .weight(weight)
-                .style(if text.italic {
-                    Style::Italic
-                } else {
-                    Style::Normal
-                });
-
-            buffer.set_text(
+            let buffer = shape_text(
                 &mut self.font_system,
-                &text.content,
-                &attrs,
-                Shaping::Advanced,
+                &self.custom_families,
+                text,
+                width,
+                height,
             );
-
-            for line in buffer.lines.iter_mut() {
-                line.set_align(Some(Align::Center));
-            }
-
-            buffer.shape_until_scroll(&mut self.font_system, false);
+            let top = text.bounds[1] + vertical_offset(&buffer, height, text.vertical_align);
 
             let bounds = TextBounds {
                 left: text.bounds[0].floor() as i32,
@@ -122,25 +157,90 @@ impl TextLayer {
                 bottom: (text.bounds[1] + height).ceil() as i32,
             };
 
+            if let Some(background_color) = text.background_color {
+                if let Some([box_left, box_top, box_right, box_bottom]) =
+                    text_extent(&buffer, width, height)
+                {
+                    let padding = text.background_padding;
+                    backgrounds.push(BackgroundInstance::new(
+                        [
+                            text.bounds[0] + box_left - padding,
+                            top + box_top - padding,
+                            text.bounds[0] + box_right + padding,
+                            top + box_bottom + padding,
+                        ],
+                        padding,
+                        background_color,
+                        text.opacity,
+                        output_size,
+                    ));
+                }
+            }
+
+            // Outline: the glyph coverage grown by the stroke width, drawn first so
+            // the fill sits on top. It is a single mask, so overlapping strokes and
+            // the text opacity don't stack.
+            if let Some(stroke_color) = text.stroke_color.filter(|_| text.stroke_width > 0.0) {
+                let width = text.stroke_width;
+                let stroke_bounds = TextBounds {
+                    left: bounds.left - width.ceil() as i32,
+                    top: bounds.top - width.ceil() as i32,
+                    right: bounds.right + width.ceil() as i32,
+                    bottom: bounds.bottom + width.ceil() as i32,
+                };
+                if let Some(outline) = self.outlines.outline(
+                    &mut self.font_system,
+                    &mut self.swash_cache,
+                    &buffer,
+                    (text.bounds[0], top),
+                    width,
+                ) {
+                    text_area_data.push((
+                        AreaContent::Outline(outline_glyphs.len()),
+                        stroke_bounds,
+                        0.0,
+                        0.0,
+                        to_glyphon_color(stroke_color, text.opacity),
+                    ));
+                    outline_glyphs.push(outline);
+                }
+            }
+
+            text_area_data.push((
+                AreaContent::Text(self.buffers.len()),
+                bounds,
+                text.bounds[0],
+                top,
+                color,
+            ));
             self.buffers.push(buffer);
-            text_area_data.push((bounds, text.bounds[0], text.bounds[1], color));
         }
 
-        let text_areas = self
-            .buffers
-            .iter()
-            .zip(text_area_data)
-            .map(|(buffer, (bounds, left, top, color))| TextArea {
-                buffer,
-                left,
-                top,
-                scale: 1.0,
-                bounds,
-                default_color: color,
-                custom_glyphs: &[],
+        let text_areas = text_area_data
+            .into_iter()
+            .map(|(content, bounds, left, top, color)| {
+                let (buffer, custom_glyphs) = match content {
+                    AreaContent::Text(index) => (&self.buffers[index], &[][..]),
+                    AreaContent::Outline(index) => (
+                        &self.outline_buffer,
+                        std::slice::from_ref(&outline_glyphs[index]),
+                    ),
+                };
+                TextArea {
+                    buffer,
+                    left,
+                    top,
+                    scale: 1.0,
+                    bounds,
+                    default_color: color,
+                    custom_glyphs,
+                }
             })
             .collect::<Vec<_>>();
 
+        self.background_pipeline
+            .prepare(device, queue, &backgrounds);
+
         self.viewport.update(
             queue,
             Resolution {
@@ -149,7 +249,8 @@ impl TextLayer {
             },
         );
 
-        if let Err(error) = self.text_renderer.prepare(
+        let outlines = &self.outlines;
+        if let Err(error) = self.text_renderer.prepare_with_custom(
             device,
             queue,
             &mut self.font_system,
@@ -157,13 +258,18 @@ impl TextLayer {
             &self.viewport,
             text_areas,
             &mut self.swash_cache,
+            |request| outlines.rasterize(request),
         ) {
             warn!("Failed to prepare text: {error:?}");
         }
     }
 
     /// Render text to the given render pass.
+    ///
+    /// Background boxes are drawn first so the glyphs sit on top.
     pub fn render<'a>(&'a self, pass: &mut wgpu::RenderPass<'a>) {
+        self.background_pipeline.render(pass);
+
         if let Err(error) = self
             .text_renderer
             .render(&self.text_atlas, &self.viewport, pass)
@@ -172,8 +278,682 @@ impl TextLayer {
         }
     }
 
+    /// Measure the laid-out text without preparing or submitting any GPU work.
+    ///
+    /// Uses the same shaping as `prepare()`, including auto-fit, so the result
+    /// matches what gets rendered.
+    pub fn measure(&mut self, text: &PreparedText) -> TextMeasurement {
+        let width = (text.bounds[2] - text.bounds[0]).max(1.0);
+        let height = (text.bounds[3] - text.bounds[1]).max(1.0);
+        self.sync_custom_fonts();
+        let buffer = shape_text(
+            &mut self.font_system,
+            &self.custom_families,
+            text,
+            width,
+            height,
+        );
+
+        let (text_width, text_height) = text_extent(&buffer, width, height)
+            .map(|[left, top, right, bottom]| (right - left, bottom - top))
+            .unwrap_or((0.0, 0.0));
+
+        TextMeasurement {
+            width: text_width,
+            height: text_height,
+            line_count: buffer.layout_runs().count() as u32,
+        }
+    }
+
     /// Check if there are any texts to render.
     pub fn has_texts(&self) -> bool {
         !self.buffers.is_empty()
     }
 }
+
+/// What a prepared text area draws.
+enum AreaContent {
+    /// The shaped text at this index in `buffers`.
+    Text(usize),
+    /// The outline glyph at this index.
+    Outline(usize),
+}
+
+/// Coverage mask, placed relative to an origin.
+struct Mask {
+    left: i32,
+    top: i32,
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+/// Glyphs of an outline relative to its top-left corner, and the stroke width bits.
+type OutlineKey = (Vec<(CacheKey, i32, i32)>, u32);
+
+/// Text outlines, drawn as glyphon custom glyphs in the text area color.
+///
+/// An outline is the union of a text's glyph masks grown by the stroke width,
+/// built on the CPU and cached by the glyphs it covers. Color glyphs (emoji)
+/// have no mask, so they get no outline.
+#[derive(Default)]
+struct Outlines {
+    /// Glyph masks, `None` for color glyphs and empty glyphs like spaces.
+    glyph_masks: HashMap<CacheKey, Option<Mask>>,
+    /// Outline -> custom glyph id.
+    ids: HashMap<OutlineKey, CustomGlyphId>,
+    /// Outline masks by custom glyph id.
+    masks: Vec<Mask>,
+}
+
+impl Outlines {
+    /// Forget the outlines uploaded to the atlas, for use with a fresh atlas.
+    fn clear_atlas_entries(&mut self) {
+        self.ids.clear();
+        self.masks.clear();
+    }
+
+    /// The outline of `buffer` drawn at `origin`, or `None` if no glyph has a mask.
+    fn outline(
+        &mut self,
+        font_system: &mut FontSystem,
+        swash_cache: &mut SwashCache,
+        buffer: &Buffer,
+        origin: (f32, f32),
+        stroke_width: f32,
+    ) -> Option<CustomGlyph> {
+        if self.glyph_masks.len() >= MAX_GLYPH_MASKS {
+            self.glyph_masks.clear();
+        }
+
+        // Glyph masks at the pixel positions glyphon draws the glyphs at
+        let mut placed = Vec::new();
+        for run in buffer.layout_runs() {
+            for glyph in run.glyphs {
+                let physical = glyph.physical(origin, 1.0);
+                let cache_key = physical.cache_key;
+                let mask = self
+                    .glyph_masks
+                    .entry(cache_key)
+                    .or_insert_with(|| glyph_mask(font_system, swash_cache, cache_key));
+                if let Some(mask) = mask {
+                    let left = physical.x + mask.left;
+                    let top = run.line_y.round() as i32 + physical.y - mask.top;
+                    placed.push((cache_key, left, top));
+                }
+            }
+        }
+
+        let pad = stroke_width.ceil() as i32 + 1;
+        let left = placed.iter().map(|&(_, x, _)| x).min()? - pad;
+        let top = placed.iter().map(|&(_, _, y)| y).min()? - pad;
+        let glyph_masks = &self.glyph_masks;
+        let mask_of =
+            move |key: &CacheKey| glyph_masks[key].as_ref().expect("placed glyphs have masks");
+        let right = placed
+            .iter()
+            .map(|(key, x, _)| x + mask_of(key).width as i32)
+            .max()?
+            + pad;
+        let bottom = placed
+            .iter()
+            .map(|(key, _, y)| y + mask_of(key).height as i32)
+            .max()?
+            + pad;
+        let (width, height) = ((right - left) as u32, (bottom - top) as u32);
+
+        let key = (
+            placed
+                .iter()
+                .map(|&(cache_key, x, y)| (cache_key, x - left, y - top))
+                .collect::<Vec<_>>(),
+            stroke_width.to_bits(),
+        );
+        let id = match self.ids.get(&key) {
+            Some(&id) => id,
+            None => {
+                let id = CustomGlyphId::try_from(self.masks.len()).ok()?;
+                let mut coverage = vec![0u8; (width * height) as usize];
+                for (cache_key, x, y) in &key.0 {
+                    max_blit(&mut coverage, width, mask_of(cache_key), *x, *y);
+                }
+                self.masks.push(Mask {
+                    left: 0,
+                    top: 0,
+                    width,
+                    height,
+                    data: dilate(&coverage, width, height, stroke_width),
+                });
+                self.ids.insert(key, id);
+                id
+            },
+        };
+
+        Some(CustomGlyph {
+            id,
+            left: left as f32,
+            top: top as f32,
+            width: width as f32,
+            height: height as f32,
+            color: None,
+            snap_to_physical_pixel: true,
+            metadata: 0,
+        })
+    }
+
+    /// Hand glyphon the mask for an outline.
+    fn rasterize(&self, request: RasterizeCustomGlyphRequest) -> Option<RasterizedCustomGlyph> {
+        let mask = self.masks.get(usize::from(request.id))?;
+        // Outlines are drawn unscaled, at their mask size
+        (mask.width == u32::from(request.width) && mask.height == u32::from(request.height)).then(
+            || RasterizedCustomGlyph {
+                data: mask.data.clone(),
+                content_type: ContentType::Mask,
+            },
+        )
+    }
+}
+
+/// Coverage mask of a glyph, `None` for color glyphs and empty glyphs.
+fn glyph_mask(
+    font_system: &mut FontSystem,
+    swash_cache: &mut SwashCache,
+    cache_key: CacheKey,
+) -> Option<Mask> {
+    let image = swash_cache.get_image_uncached(font_system, cache_key)?;
+    let placement = image.placement;
+    if image.content != SwashContent::Mask || placement.width == 0 || placement.height == 0 {
+        return None;
+    }
+
+    Some(Mask {
+        left: placement.left,
+        top: placement.top,
+        width: placement.width,
+        height: placement.height,
+        data: image.data,
+    })
+}
+
+/// Combine `mask` into `coverage` (`width` wide) at (`x`, `y`), keeping the
+/// higher coverage where glyphs overlap.
+fn max_blit(coverage: &mut [u8], width: u32, mask: &Mask, x: i32, y: i32) {
+    for row in 0..mask.height as usize {
+        let src = &mask.data[row * mask.width as usize..][..mask.width as usize];
+        let start = (y as usize + row) * width as usize + x as usize;
+        for (dst, &value) in coverage[start..start + src.len()].iter_mut().zip(src) {
+            *dst = (*dst).max(value);
+        }
+    }
+}
+
+/// Grow `coverage` by `radius` pixels with a round, anti-aliased pen.
+///
+/// Each pixel takes the highest coverage within `radius` of it, faded over the
+/// last pixel of the radius so the outline edge stays smooth.
+fn dilate(coverage: &[u8], width: u32, height: u32, radius: f32) -> Vec<u8> {
+    let reach = radius.ceil() as i32 + 1;
+    let pen: Vec<(i32, i32, f32)> = (-reach..=reach)
+        .flat_map(|dy| (-reach..=reach).map(move |dx| (dx, dy)))
+        .filter_map(|(dx, dy)| {
+            let distance = ((dx * dx + dy * dy) as f32).sqrt();
+            let weight = (radius + 0.5 - distance).clamp(0.0, 1.0);
+            (weight > 0.0).then_some((dx, dy, weight))
+        })
+        .collect();
+
+    let (width, height) = (width as i32, height as i32);
+    let mut grown = vec![0u8; coverage.len()];
+    // Spread each covered pixel, as text leaves most of its box empty
+    for y in 0..height {
+        for x in 0..width {
+            let value = coverage[(y * width + x) as usize];
+            if value == 0 {
+                continue;
+            }
+            for &(dx, dy, weight) in &pen {
+                let (px, py) = (x + dx, y + dy);
+                if px < 0 || py < 0 || px >= width || py >= height {
+                    continue;
+                }
+                let dst = &mut grown[(py * width + px) as usize];
+                *dst = (*dst).max((value as f32 * weight).round() as u8);
+            }
+        }
+    }
+    grown
+}
+
+/// A text atlas and the renderer drawing from it.
+fn new_atlas(device: &Device, queue: &Queue, cache: &Cache) -> (TextAtlas, TextRenderer) {
+    let mut text_atlas = TextAtlas::new(device, queue, cache, wgpu::TextureFormat::Rgba8UnormSrgb);
+    let text_renderer = TextRenderer::new(
+        &mut text_atlas,
+        device,
+        wgpu::MultisampleState::default(),
+        None,
+    );
+    (text_atlas, text_renderer)
+}
+
+/// Convert an RGBA (0.0-1.0) color to glyphon, applying `opacity` to alpha.
+fn to_glyphon_color(rgba: [f32; 4], opacity: f32) -> Color {
+    let alpha = rgba[3].clamp(0.0, 1.0) * opacity.clamp(0.0, 1.0);
+    Color::rgba(
+        (rgba[0].clamp(0.0, 1.0) * 255.0) as u8,
+        (rgba[1].clamp(0.0, 1.0) * 255.0) as u8,
+        (rgba[2].clamp(0.0, 1.0) * 255.0) as u8,
+        (alpha * 255.0) as u8,
+    )
+}
+
+/// Shape `text` into a buffer wrapped to a `width` x `height` box.
+///
+/// Shared by rendering and measurement so both see the same layout.
+/// `custom_families` maps runtime-loaded family names to their face names.
+///
+/// Emoji are shaped with the color emoji font (the usual font fallback applies
+/// if it isn't installed). glyphon draws color glyphs in their own colors, so
+/// they ignore the text color and opacity, and they get no outline.
+fn shape_text(
+    font_system: &mut FontSystem,
+    custom_families: &HashMap<String, String>,
+    text: &PreparedText,
+    width: f32,
+    height: f32,
+) -> Buffer {
+    let family = match text.font_family.trim() {
+        "" => Family::SansSerif,
+        name => {
+            let key = name.to_ascii_lowercase();
+            match key.as_str() {
+                "sans" | "sans-serif" | "system sans" | "system sans-serif" => Family::SansSerif,
+                "serif" | "system serif" => Family::Serif,
+                "mono" | "monospace" | "system mono" | "system monospace" => Family::Monospace,
+                _ => Family::Name(custom_families.get(&key).map_or(name, String::as_str)),
+            }
+        },
+    };
+    let weight = Weight(text.font_weight.round().clamp(100.0, 900.0) as u16);
+    // No per-glyph color, so the fill is drawn in the text area default
+    let attrs = Attrs::new()
+        .family(family)
+        .weight(weight)
+        .style(if text.italic {
+            Style::Italic
+        } else {
+            Style::Normal
+        })
+        .letter_spacing(text.letter_spacing);
+    let emoji_family = custom_families
+        .get(EMOJI_FAMILY)
+        .map_or(SYSTEM_EMOJI_FAMILY, String::as_str);
+    let spans = styled_spans(
+        &text.content,
+        &attrs,
+        &attrs.clone().family(Family::Name(emoji_family)),
+    );
+
+    let font_size = if text.auto_fit {
+        fit_font_size(
+            font_system,
+            &spans,
+            &attrs,
+            text.font_size,
+            text.line_height,
+            width,
+            height,
+        )
+    } else {
+        text.font_size
+    };
+
+    let metrics = Metrics::new(font_size, font_size * text.line_height);
+    let mut buffer = Buffer::new(font_system, metrics);
+    buffer.set_size(font_system, Some(width), Some(height));
+    buffer.set_wrap(font_system, glyphon::Wrap::Word);
+
+    buffer.set_rich_text(
+        font_system,
+        spans.iter().cloned(),
+        &attrs,
+        Shaping::Advanced,
+        None,
+    );
+
+    for line in buffer.lines.iter_mut() {
+        line.set_align(Some(text.align));
+    }
+
+    buffer.shape_until_scroll(font_system, false);
+    buffer
+}
+
+/// `content` split into spans, with emoji runs in `emoji_attrs`.
+fn styled_spans<'s, 'a>(
+    content: &'s str,
+    attrs: &Attrs<'a>,
+    emoji_attrs: &Attrs<'a>,
+) -> Vec<(&'s str, Attrs<'a>)> {
+    emoji_runs(content)
+        .into_iter()
+        .map(|(run, emoji)| (run, if emoji { emoji_attrs } else { attrs }.clone()))
+        .collect()
+}
+
+/// Offset from the top of a `height` box that places the laid-out lines per `align`.
+fn vertical_offset(buffer: &Buffer, height: f32, align: TextVerticalAlign) -> f32 {
+    let text_height = buffer
+        .layout_runs()
+        .fold(0.0f32, |h, run| h.max(run.line_top + run.line_height));
+    let slack = (height - text_height).max(0.0);
+
+    match align {
+        TextVerticalAlign::Top => 0.0,
+        TextVerticalAlign::Middle => slack / 2.0,
+        TextVerticalAlign::Bottom => slack,
+    }
+}
+
+/// Extent of the laid-out glyphs as [left, top, right, bottom], relative to the
+/// buffer origin and clipped to its `width` x `height` box. None if there are no glyphs.
+fn text_extent(buffer: &Buffer, width: f32, height: f32) -> Option<[f32; 4]> {
+    let mut extent: Option<[f32; 4]> = None;
+    for run in buffer.layout_runs() {
+        // Glyph positions already include the line alignment offset
+        let Some(first) = run.glyphs.first() else {
+            continue;
+        };
+        let (left, right) = run.glyphs.iter().fold((first.x, first.x), |(l, r), glyph| {
+            (l.min(glyph.x), r.max(glyph.x + glyph.w))
+        });
+        let top = run.line_top;
+        let bottom = run.line_top + run.line_height;
+
+        extent = Some(match extent {
+            Some([l, t, r, b]) => [l.min(left), t.min(top), r.max(right), b.max(bottom)],
+            None => [left, top, right, bottom],
+        });
+    }
+
+    extent.map(|[l, t, r, b]| [l.max(0.0), t.max(0.0), r.min(width), b.min(height)])
+}
+
+/// Measure shaped text as (widest line, total height) when wrapped to `max_width`.
+fn measure_text(
+    font_system: &mut FontSystem,
+    spans: &[(&str, Attrs)],
+    attrs: &Attrs,
+    font_size: f32,
+    line_height: f32,
+    max_width: f32,
+) -> (f32, f32) {
+    let metrics = Metrics::new(font_size, font_size * line_height);
+    let mut buffer = Buffer::new(font_system, metrics);
+    buffer.set_size(font_system, Some(max_width), None);
+    buffer.set_wrap(font_system, glyphon::Wrap::Word);
+    buffer.set_rich_text(
+        font_system,
+        spans.iter().cloned(),
+        attrs,
+        Shaping::Advanced,
+        None,
+    );
+    buffer.shape_until_scroll(font_system, false);
+
+    buffer.layout_runs().fold((0.0f32, 0.0f32), |(w, h), run| {
+        (w.max(run.line_w), h.max(run.line_top + run.line_height))
+    })
+}
+
+/// Find the largest font size (up to `max_size`) at which the text fits the box.
+///
+/// Binary searches on the shaped layout, so wrapping is taken into account.
+fn fit_font_size(
+    font_system: &mut FontSystem,
+    spans: &[(&str, Attrs)],
+    attrs: &Attrs,
+    max_size: f32,
+    line_height: f32,
+    width: f32,
+    height: f32,
+) -> f32 {
+    let fits = |font_system: &mut FontSystem, size: f32| {
+        let (text_w, text_h) = measure_text(font_system, spans, attrs, size, line_height, width);
+        text_w <= width + 0.5 && text_h <= height + 0.5
+    };
+
+    if fits(font_system, max_size) {
+        return max_size;
+    }
+
+    let mut low = MIN_AUTO_FIT_FONT_SIZE.min(max_size);
+    let mut high = max_size;
+    for _ in 0..AUTO_FIT_ITERATIONS {
+        let mid = (low + high) / 2.0;
+        if fits(font_system, mid) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    low
+}
+
+// =============================================================================
+// Background Box Pipeline
+// =============================================================================
+
+/// Initial instance capacity of the background box buffer.
+const INITIAL_BACKGROUND_CAPACITY: u64 = 4;
+
+/// A rounded background box behind one text segment.
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+struct BackgroundInstance {
+    /// Box as [left, top, right, bottom] in clip space.
+    clip_rect: [f32; 4],
+    /// Box size in pixels, corner radius in pixels, unused.
+    size_radius: [f32; 4],
+    /// Linear, premultiplied color.
+    color: [f32; 4],
+}
+
+impl BackgroundInstance {
+    /// Build an instance from a pixel rect, converting the sRGB `rgba` color
+    /// to linear so it blends like glyphon's text on the sRGB target.
+    fn new(
+        rect: [f32; 4],
+        corner_radius: f32,
+        rgba: [f32; 4],
+        opacity: f32,
+        output_size: (u32, u32),
+    ) -> Self {
+        let output_w = output_size.0.max(1) as f32;
+        let output_h = output_size.1.max(1) as f32;
+        let alpha = rgba[3].clamp(0.0, 1.0) * opacity.clamp(0.0, 1.0);
+        let linear = |c: f32| {
+            let c = c.clamp(0.0, 1.0);
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        Self {
+            clip_rect: [
+                rect[0] / output_w * 2.0 - 1.0,
+                1.0 - rect[1] / output_h * 2.0,
+                rect[2] / output_w * 2.0 - 1.0,
+                1.0 - rect[3] / output_h * 2.0,
+            ],
+            size_radius: [
+                (rect[2] - rect[0]).max(0.0),
+                (rect[3] - rect[1]).max(0.0),
+                corner_radius.max(0.0),
+                0.0,
+            ],
+            color: [
+                linear(rgba[0]) * alpha,
+                linear(rgba[1]) * alpha,
+                linear(rgba[2]) * alpha,
+                alpha,
+            ],
+        }
+    }
+}
+
+/// Instanced quad pipeline for text background boxes (glyphon only draws glyphs).
+struct TextBackgroundPipeline {
+    render_pipeline: wgpu::RenderPipeline,
+    instance_buffer: wgpu::Buffer,
+    capacity: u64,
+    count: u32,
+}
+
+impl TextBackgroundPipeline {
+    fn new(device: &Device) -> Self {
+        let shader_source = include_str!("shaders/text-background.wgsl");
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Text Background Shader"),
+            source: wgpu::ShaderSource::Wgsl(shader_source.into()),
+        });
+
+        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("TextBackgroundPipeline"),
+            layout: Some(
+                &device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                    label: Some("TextBackgroundPipelineLayout"),
+                    bind_group_layouts: &[],
+                    push_constant_ranges: &[],
+                }),
+            ),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: std::mem::size_of::<BackgroundInstance>() as wgpu::BufferAddress,
+                    step_mode: wgpu::VertexStepMode::Instance,
+                    attributes: &wgpu::vertex_attr_array![
+                        0 => Float32x4,
+                        1 => Float32x4,
+                        2 => Float32x4,
+                    ],
+                }],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                    blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
+        Self {
+            render_pipeline,
+            instance_buffer: Self::create_instance_buffer(device, INITIAL_BACKGROUND_CAPACITY),
+            capacity: INITIAL_BACKGROUND_CAPACITY,
+            count: 0,
+        }
+    }
+
+    fn create_instance_buffer(device: &Device, capacity: u64) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("TextBackground Instance Buffer"),
+            size: capacity * std::mem::size_of::<BackgroundInstance>() as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+
+    /// Upload this frame's boxes, growing the instance buffer if needed.
+    fn prepare(&mut self, device: &Device, queue: &Queue, instances: &[BackgroundInstance]) {
+        self.count = instances.len() as u32;
+        if instances.is_empty() {
+            return;
+        }
+
+        if instances.len() as u64 > self.capacity {
+            self.capacity = (instances.len() as u64).next_power_of_two();
+            self.instance_buffer = Self::create_instance_buffer(device, self.capacity);
+        }
+
+        queue.write_buffer(&self.instance_buffer, 0, bytemuck::cast_slice(instances));
+    }
+
+    fn render<'a>(&'a self, pass: &mut wgpu::RenderPass<'a>) {
+        if self.count == 0 {
+            return;
+        }
+
+        pass.set_pipeline(&self.render_pipeline);
+        pass.set_vertex_buffer(0, self.instance_buffer.slice(..));
+        pass.draw(0..6, 0..self.count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dilate_grows_coverage_once() {
+        // A single covered pixel in the middle of a 9x9 box
+        let mut coverage = vec![0u8; 81];
+        coverage[4 * 9 + 4] = 255;
+
+        let grown = dilate(&coverage, 9, 9, 2.0);
+        let at = |x: usize, y: usize| grown[y * 9 + x];
+
+        assert_eq!(at(4, 4), 255);
+        assert_eq!(at(5, 4), 255);
+        assert_eq!(at(4, 3), 255);
+        // Faded at the edge of the radius, empty past it
+        assert_eq!(at(6, 4), 128);
+        assert_eq!(at(7, 4), 0);
+        assert_eq!(at(6, 6), 0);
+        // Partial coverage isn't amplified
+        let half = dilate(
+            &coverage.iter().map(|v| v / 2).collect::<Vec<_>>(),
+            9,
+            9,
+            2.0,
+        );
+        assert_eq!(half[4 * 9 + 5], 127);
+    }
+
+    #[test]
+    fn test_max_blit_keeps_highest_coverage() {
+        let mask = Mask {
+            left: 0,
+            top: 0,
+            width: 2,
+            height: 1,
+            data: vec![100, 200],
+        };
+        let mut coverage = vec![150u8, 0, 0, 0, 0, 0];
+        max_blit(&mut coverage, 3, &mask, 0, 0);
+        max_blit(&mut coverage, 3, &mask, 1, 1);
+        assert_eq!(coverage, vec![150, 200, 0, 0, 100, 200]);
+    }
+}
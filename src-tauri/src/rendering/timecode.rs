@@ -0,0 +1,248 @@
+//! Burned-in timecode overlay for export.
+//!
+//! Formats the elapsed or wall-clock time of each exported frame and lays it
+//! out as a PreparedText anchored to a frame corner, so it is drawn by the
+//! same glyphon pipeline as regular text overlays.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, Duration, Local, NaiveTime};
+use glyphon::cosmic_text::Align;
+
+use super::text::{parse_color, PreparedText, MAX_FONT_SIZE_PX};
+use super::text_layer::LINE_HEIGHT_FACTOR;
+use crate::commands::video_recording::video_project::{
+    TextVerticalAlign, TimecodeConfig, TimecodeMode, TimecodePosition, VideoProject, XY,
+};
+
+/// Format used when the configured format string is invalid.
+const FALLBACK_FORMAT: &str = "%H:%M:%S";
+
+/// Timecode overlay state for one export.
+pub struct TimecodeOverlay {
+    config: TimecodeConfig,
+    /// Wall-clock time of the first exported frame (WallClock mode only).
+    clip_start: Option<DateTime<Local>>,
+}
+
+impl TimecodeOverlay {
+    /// Create the overlay for a project.
+    ///
+    /// In WallClock mode the clock starts at the recording start plus the
+    /// timeline in-point (the start of the first kept range). Falls back to
+    /// elapsed time if the recording start can't be determined.
+    pub fn new(config: &TimecodeConfig, project: &VideoProject) -> Self {
+        let clip_start = match config.mode {
+            TimecodeMode::Elapsed => None,
+            TimecodeMode::WallClock => {
+                let in_point_ms = project
+                    .timeline
+                    .kept_ranges()
+                    .first()
+                    .map_or(0, |range| range.start_ms);
+                let start = recording_start(project)
+                    .map(|start| start + Duration::milliseconds(in_point_ms as i64));
+                if start.is_none() {
+                    log::warn!(
+                        "[EXPORT] Unknown recording start time ({}), using elapsed timecode",
+                        project.created_at
+                    );
+                }
+                start
+            },
+        };
+
+        Self {
+            config: config.clone(),
+            clip_start,
+        }
+    }
+
+    /// Prepare the timecode text for a frame.
+    ///
+    /// `padding` is the background padding in output pixels; the timecode sits
+    /// inside it so it overlays the video rather than the canvas edge.
+    /// Elapsed time counts `output_time_ms` (running time of the export), the
+    /// wall clock follows `relative_time_ms` (source time past the in-point),
+    /// so it jumps over timeline cuts.
+    pub fn prepare(
+        &self,
+        output_size: XY<u32>,
+        padding: f32,
+        output_time_ms: u64,
+        relative_time_ms: u64,
+    ) -> PreparedText {
+        let time_ms = match self.clip_start {
+            Some(_) => relative_time_ms,
+            None => output_time_ms,
+        };
+        let content = format_timecode(&self.config.format, self.clip_start, time_ms);
+        layout_timecode(&self.config, content, output_size, padding)
+    }
+}
+
+/// Estimate the wall-clock time at which recording started.
+///
+/// The project is created when recording stops, so this is the creation
+/// time minus the recorded duration.
+pub fn recording_start(project: &VideoProject) -> Option<DateTime<Local>> {
+    let created = DateTime::parse_from_rfc3339(&project.created_at).ok()?;
+    Some(created.with_timezone(&Local) - Duration::milliseconds(project.sources.duration_ms as i64))
+}
+
+/// Format the timecode for a frame.
+///
+/// With `clip_start` the wall-clock time is shown, otherwise the elapsed time.
+/// Invalid format strings fall back to `HH:MM:SS`.
+pub fn format_timecode(
+    format: &str,
+    clip_start: Option<DateTime<Local>>,
+    relative_time_ms: u64,
+) -> String {
+    let offset = Duration::milliseconds(relative_time_ms as i64);
+
+    let render = |fmt: &str| -> Option<String> {
+        let mut out = String::new();
+        let written = match clip_start {
+            Some(start) => write!(out, "{}", (start + offset).format(fmt)),
+            None => write!(out, "{}", (NaiveTime::MIN + offset).format(fmt)),
+        };
+        written.ok().map(|_| out)
+    };
+
+    render(format)
+        .or_else(|| render(FALLBACK_FORMAT))
+        .unwrap_or_default()
+}
+
+/// Anchor the timecode text to the configured corner.
+fn layout_timecode(
+    config: &TimecodeConfig,
+    content: String,
+    output_size: XY<u32>,
+    padding: f32,
+) -> PreparedText {
+    let width = output_size.x as f32;
+    let height = output_size.y as f32;
+    let height_scale = if output_size.y == 0 {
+        1.0
+    } else {
+        height / 1080.0
+    };
+
+    let font_size = (config.font_size.max(1.0) * height_scale).min(MAX_FONT_SIZE_PX);
+    let line_height = font_size * LINE_HEIGHT_FACTOR;
+    let margin = config.margin.max(0.0) * height_scale;
+
+    // Keep the text box fully inside the canvas even with large padding/margins
+    let inset_x = (padding.max(0.0) + margin).min(((width - 1.0) / 2.0).max(0.0));
+    let inset_y = (padding.max(0.0) + margin).min((height - line_height).max(0.0));
+
+    let (top, align) = match config.position {
+        TimecodePosition::TopLeft => (inset_y, Align::Left),
+        TimecodePosition::TopRight => (inset_y, Align::Right),
+        TimecodePosition::BottomLeft => ((height - inset_y - line_height).max(0.0), Align::Left),
+        TimecodePosition::BottomRight => ((height - inset_y - line_height).max(0.0), Align::Right),
+    };
+
+    PreparedText {
+        content,
+        bounds: [
+            inset_x,
+            top,
+            width - inset_x,
+            (top + line_height).min(height),
+        ],
+        color: parse_color(&config.color),
+        font_family: config.font_family.clone(),
+        font_size,
+        font_weight: config.font_weight,
+        italic: false,
+        opacity: 1.0,
+        align,
+        vertical_align: TextVerticalAlign::Top,
+        auto_fit: false,
+        letter_spacing: 0.0,
+        line_height: LINE_HEIGHT_FACTOR,
+        stroke_color: None,
+        stroke_width: 0.0,
+        background_color: None,
+        background_padding: 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_timecode("%H:%M:%S", None, 3_723_000), "01:02:03");
+        assert_eq!(format_timecode("%M:%S%.3f", None, 61_250), "01:01.250");
+    }
+
+    #[test]
+    fn test_format_wall_clock() {
+        let start = Local.with_ymd_and_hms(2024, 5, 1, 9, 59, 58).unwrap();
+        assert_eq!(
+            format_timecode("%Y-%m-%d %H:%M:%S", Some(start), 3_000),
+            "2024-05-01 10:00:01"
+        );
+    }
+
+    #[test]
+    fn test_invalid_format_falls_back() {
+        // Date fields are invalid for elapsed time
+        assert_eq!(format_timecode("%Y-%m-%d", None, 5_000), "00:00:05");
+    }
+
+    #[test]
+    fn test_layout_corners_respect_padding() {
+        let config = TimecodeConfig {
+            margin: 10.0,
+            font_size: 20.0,
+            ..TimecodeConfig::default()
+        };
+        let size = XY::new(1920, 1080);
+
+        let top_left = layout_timecode(
+            &TimecodeConfig {
+                position: TimecodePosition::TopLeft,
+                ..config.clone()
+            },
+            "x".to_string(),
+            size,
+            40.0,
+        );
+        assert_eq!(top_left.bounds[0], 50.0);
+        assert_eq!(top_left.bounds[1], 50.0);
+        assert_eq!(top_left.align, Align::Left);
+
+        let bottom_right = layout_timecode(
+            &TimecodeConfig {
+                position: TimecodePosition::BottomRight,
+                ..config
+            },
+            "x".to_string(),
+            size,
+            40.0,
+        );
+        assert_eq!(bottom_right.bounds[2], 1870.0);
+        assert!((bottom_right.bounds[3] - 1030.0).abs() < 0.01);
+        assert_eq!(bottom_right.align, Align::Right);
+    }
+
+    #[test]
+    fn test_layout_stays_inside_small_canvas() {
+        let config = TimecodeConfig {
+            position: TimecodePosition::BottomLeft,
+            ..TimecodeConfig::default()
+        };
+        let text = layout_timecode(&config, "x".to_string(), XY::new(100, 40), 500.0);
+
+        assert!(text.bounds[0] >= 0.0 && text.bounds[0] < text.bounds[2]);
+        assert!(text.bounds[1] >= 0.0);
+        assert!(text.bounds[3] <= 40.0);
+    }
+}
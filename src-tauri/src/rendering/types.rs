@@ -1,6 +1,7 @@
 //! Core types for GPU-accelerated video rendering.
 
 use std::path::Path;
+use std::sync::Arc;
 
 use super::background::hex_to_linear_rgba;
 use super::coord::{Coord, FrameSpace, Size};
@@ -64,12 +65,15 @@ pub struct RenderOptions {
     pub output_height: u32,
     /// Current zoom state.
     pub zoom: ZoomState,
-    /// Webcam overlay options (if enabled).
-    pub webcam: Option<WebcamOverlay>,
+    /// Webcam overlays, bottom to top (empty if disabled).
+    pub webcams: Vec<WebcamOverlay>,
     /// Cursor rendering options (if enabled).
     pub cursor: Option<CursorOverlay>,
     /// Background padding/styling.
     pub background: BackgroundStyle,
+    /// Normalized source point to keep in view when the screen content is
+    /// cropped to fill a frame taller than it. `None` fits the whole content.
+    pub fill_focus: Option<[f32; 2]>,
 }
 
 impl Default for RenderOptions {
@@ -78,9 +82,10 @@ impl Default for RenderOptions {
             output_width: 1920,
             output_height: 1080,
             zoom: ZoomState::default(),
-            webcam: None,
+            webcams: Vec::new(),
             cursor: None,
             background: BackgroundStyle::default(),
+            fill_focus: None,
         }
     }
 }
@@ -113,8 +118,8 @@ impl ZoomState {
 /// Webcam overlay configuration for rendering.
 #[derive(Debug, Clone)]
 pub struct WebcamOverlay {
-    /// Webcam frame data.
-    pub frame: DecodedFrame,
+    /// Webcam frame data, shared by every placement of the same frame.
+    pub frame: Arc<DecodedFrame>,
     /// Position X (0.0-1.0, normalized).
     pub x: f32,
     /// Position Y (0.0-1.0, normalized).
@@ -135,6 +140,22 @@ pub struct WebcamOverlay {
     pub shadow_opacity: f32,
     /// Shadow blur amount (0.0-1.0).
     pub shadow_blur: f32,
+    /// Border width in output pixels (0.0 = no border).
+    pub border_width: f32,
+    /// Border color (linear RGBA).
+    pub border_color: [f32; 4],
+    /// Border gradient end color (linear RGBA). Same as `border_color` for a solid border.
+    pub border_color_end: [f32; 4],
+    /// Border gradient angle in degrees.
+    pub border_gradient_angle: f32,
+    /// Glow color (linear RGBA).
+    pub glow_color: [f32; 4],
+    /// Glow radius in output pixels (0.0 = no glow).
+    pub glow_radius: f32,
+    /// Glow opacity (0.0-1.0).
+    pub glow_opacity: f32,
+    /// Opacity of the webcam content (0.0-1.0).
+    pub opacity: f32,
 }
 
 /// Shape of webcam overlay.
@@ -300,6 +321,8 @@ pub struct BackgroundStyle {
     pub shadow: ShadowStyle,
     /// Border configuration.
     pub border: BorderStyle,
+    /// Leave uncovered pixels transparent instead of black.
+    pub transparent: bool,
 }
 
 impl Default for BackgroundStyle {
@@ -313,6 +336,7 @@ impl Default for BackgroundStyle {
             rounding_type: CornerStyle::default(),
             shadow: ShadowStyle::default(),
             border: BorderStyle::default(),
+            transparent: false,
         }
     }
 }
@@ -320,8 +344,56 @@ impl Default for BackgroundStyle {
 impl BackgroundStyle {
     /// Create a BackgroundStyle from a project BackgroundConfig.
     /// `resource_dir` is used to resolve wallpaper paths (assets/backgrounds/).
-    pub fn from_config(config: &BackgroundConfig, resource_dir: Option<&Path>) -> Self {
-        let background_type = match config.bg_type {
+    /// With `transparent`, the configured background is not drawn; padding and
+    /// frame styling still apply.
+    pub fn from_config(
+        config: &BackgroundConfig,
+        resource_dir: Option<&Path>,
+        transparent: bool,
+    ) -> Self {
+        let background_type = if transparent {
+            BackgroundType::None
+        } else {
+            Self::background_type(config, resource_dir)
+        };
+
+        let rounding_type = match config.rounding_type {
+            ProjectCornerStyle::Squircle => CornerStyle::Squircle,
+            ProjectCornerStyle::Rounded => CornerStyle::Rounded,
+        };
+
+        let shadow = ShadowStyle {
+            enabled: config.shadow.enabled,
+            shadow: config.shadow.shadow,
+        };
+
+        // Bake border opacity into color's alpha channel (matching Cap's approach)
+        let mut border_color = hex_to_linear_rgba(&config.border.color);
+        border_color[3] *= config.border.opacity / 100.0; // Apply opacity to alpha
+
+        let border = BorderStyle {
+            enabled: config.border.enabled,
+            width: config.border.width,
+            color: border_color,
+            opacity: 1.0, // Opacity is now baked into color alpha
+        };
+
+        Self {
+            background_type,
+            blur: config.blur,
+            padding: config.padding,
+            inset: config.inset,
+            rounding: config.rounding,
+            rounding_type,
+            shadow,
+            border,
+            transparent,
+        }
+    }
+
+    /// Background to draw for a project BackgroundConfig.
+    fn background_type(config: &BackgroundConfig, resource_dir: Option<&Path>) -> BackgroundType {
+        match config.bg_type {
             ProjectBackgroundType::Solid => {
                 BackgroundType::Solid(hex_to_linear_rgba(&config.solid_color))
             },
@@ -360,44 +432,18 @@ impl BackgroundStyle {
             },
             ProjectBackgroundType::Image => {
                 if let Some(ref image_path) = config.image_path {
-                    BackgroundType::Image(image_path.clone())
+                    BackgroundType::Image {
+                        path: image_path.clone(),
+                        blur: config.image_blur_amount,
+                    }
                 } else {
                     // Fallback to solid color if no image specified
                     BackgroundType::Solid(hex_to_linear_rgba(&config.solid_color))
                 }
             },
-        };
-
-        let rounding_type = match config.rounding_type {
-            ProjectCornerStyle::Squircle => CornerStyle::Squircle,
-            ProjectCornerStyle::Rounded => CornerStyle::Rounded,
-        };
-
-        let shadow = ShadowStyle {
-            enabled: config.shadow.enabled,
-            shadow: config.shadow.shadow,
-        };
-
-        // Bake border opacity into color's alpha channel (matching Cap's approach)
-        let mut border_color = hex_to_linear_rgba(&config.border.color);
-        border_color[3] *= config.border.opacity / 100.0; // Apply opacity to alpha
-
-        let border = BorderStyle {
-            enabled: config.border.enabled,
-            width: config.border.width,
-            color: border_color,
-            opacity: 1.0, // Opacity is now baked into color alpha
-        };
-
-        Self {
-            background_type,
-            blur: config.blur,
-            padding: config.padding,
-            inset: config.inset,
-            rounding: config.rounding,
-            rounding_type,
-            shadow,
-            border,
+            ProjectBackgroundType::BlurredSource => {
+                BackgroundType::BlurredSource { blur: config.blur }
+            },
         }
     }
 }
@@ -418,8 +464,10 @@ pub enum BackgroundType {
     },
     /// Built-in wallpaper preset (path relative to assets/backgrounds/).
     Wallpaper(String),
-    /// Custom image file path.
-    Image(String),
+    /// Custom image file path with blur amount (0-100%).
+    Image { path: String, blur: f32 },
+    /// Blurred copy of the screen frame with blur amount (0-100%).
+    BlurredSource { blur: f32 },
 }
 
 /// Uniforms passed to the compositor shader.
@@ -502,6 +550,19 @@ pub struct RenderedFrame {
     pub height: u32,
 }
 
+/// Measured size of a text segment's laid-out glyphs.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "../../src/types/generated/")]
+pub struct TextMeasurement {
+    /// Width of the widest line in pixels.
+    pub width: f32,
+    /// Height of the laid-out lines in pixels.
+    pub height: f32,
+    /// Number of lines after wrapping.
+    pub line_count: u32,
+}
+
 /// Result of creating an editor instance.
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase")]
@@ -7,11 +7,19 @@
 #![allow(dead_code)]
 
 use super::types::ZoomState;
-use crate::commands::video_recording::video_project::{ZoomConfig, ZoomRegion, ZoomRegionMode};
+use crate::commands::video_recording::video_project::{
+    EasingFunction, ZoomConfig, ZoomKeyframe, ZoomMode, ZoomRegion, ZoomRegionMode,
+};
 
 /// Fixed zoom transition duration in seconds (matches Cap).
 pub const ZOOM_DURATION: f64 = 1.0;
 
+/// Frame duration that follow-cursor `smoothing` is defined against (60fps).
+const FOLLOW_REFERENCE_FRAME_MS: f64 = 1000.0 / 60.0;
+
+/// Upper bound for follow-cursor smoothing so the view always catches up.
+const MAX_FOLLOW_SMOOTHING: f64 = 0.99;
+
 /// XY coordinate for bounds calculations.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct XY {
@@ -76,6 +84,9 @@ impl SegmentBounds {
     /// The key insight: for a zoom centered at (cx, cy) with scale `amount`:
     /// - The zoomed viewport is `amount` times larger
     /// - We offset to keep the center point at the same screen position
+    ///
+    /// The center is first made edge-aware via [`focus_anchor`], so targets near
+    /// the frame edges stay `focus_padding` away from the output edges.
     pub fn from_region(region: &ZoomRegion, cursor_pos: Option<(f64, f64)>) -> Self {
         // Get position - either from cursor (Auto mode) or fixed target
         let position = match region.mode {
@@ -86,6 +97,11 @@ impl SegmentBounds {
         };
 
         let amount = region.scale as f64;
+        let padding = region.focus_padding as f64;
+        let position = (
+            focus_anchor(position.0, amount, padding),
+            focus_anchor(position.1, amount, padding),
+        );
 
         // Cap's calculation: scale the center, then offset to maintain position
         let scaled_center = [position.0 * amount, position.1 * amount];
@@ -97,11 +113,29 @@ impl SegmentBounds {
         )
     }
 
+    /// Create bounds for a zoom of `amount` around the fixed point `center`.
+    pub fn from_center(center: XY, amount: f64) -> Self {
+        let top_left = center * -(amount - 1.0);
+        SegmentBounds::new(top_left, top_left + XY::new(amount, amount))
+    }
+
     /// Get the zoom amount (width of the viewport).
     pub fn zoom_amount(&self) -> f64 {
         (self.bottom_right - self.top_left).x
     }
 
+    /// Keep the viewport covering the whole frame: at least 1x, with no edge
+    /// pulled inside the frame. Overshooting easings can otherwise do both.
+    pub fn clamp_to_frame(&self) -> SegmentBounds {
+        let amount = self.zoom_amount().max(1.0);
+        let top_left = XY::new(
+            self.top_left.x.clamp(1.0 - amount, 0.0),
+            self.top_left.y.clamp(1.0 - amount, 0.0),
+        );
+
+        SegmentBounds::new(top_left, top_left + XY::new(amount, amount))
+    }
+
     /// Interpolate between two bounds.
     pub fn lerp(&self, other: &SegmentBounds, t: f64) -> SegmentBounds {
         SegmentBounds::new(
@@ -111,6 +145,24 @@ impl SegmentBounds {
     }
 }
 
+/// Compute the zoom center (fixed point) for one axis so the target stays visible.
+///
+/// The compositor zooms around a fixed point `c`: a source point `t` lands on
+/// screen at `c + (t - c) * scale`. Using the target itself as `c` pins edge
+/// targets to the output edge, so instead we solve for the `c` that places the
+/// target `padding` away from the edges, then clamp `c` to 0-1 so the zoomed
+/// viewport never extends past the frame.
+pub fn focus_anchor(target: f64, scale: f64, padding: f64) -> f64 {
+    if scale <= 1.0 + f64::EPSILON {
+        return target.clamp(0.0, 1.0);
+    }
+
+    let padding = padding.clamp(0.0, 0.5);
+    let desired_screen_pos = target.clamp(padding, 1.0 - padding);
+
+    ((scale * target - desired_screen_pos) / (scale - 1.0)).clamp(0.0, 1.0)
+}
+
 /// Cursor for tracking position within zoom segments.
 #[derive(Debug, Clone, Copy)]
 pub struct SegmentsCursor<'a> {
@@ -187,29 +239,30 @@ impl InterpolatedZoom {
     ) -> Self {
         let default = SegmentBounds::default();
 
-        // Helper to apply easing
-        let apply_ease_in = |t: f32| -> f32 {
+        // Helpers to apply the region's transition easing
+        let apply_ease_in = |region: &ZoomRegion, t: f64| -> f64 {
             if use_bezier {
-                bezier_easing::bezier_easing(0.1, 0.0, 0.3, 1.0).unwrap()(t)
+                ease_transition(region.transition.easing, t, true)
             } else {
                 t
             }
         };
-        let apply_ease_out = |t: f32| -> f32 {
+        let apply_ease_out = |region: &ZoomRegion, t: f64| -> f64 {
             if use_bezier {
-                bezier_easing::bezier_easing(0.5, 0.0, 0.5, 1.0).unwrap()(t)
+                ease_transition(region.transition.easing, t, false)
             } else {
                 t
             }
         };
 
-        match (cursor.prev_segment, cursor.segment) {
+        let interpolated = match (cursor.prev_segment, cursor.segment) {
             // Case 1: After a segment, zooming out
             (Some(prev_segment), None) => {
                 let prev_end_s = prev_segment.end_ms as f64 / 1000.0;
-                let zoom_t =
-                    apply_ease_out(t_clamp((cursor.time - prev_end_s) / ZOOM_DURATION) as f32)
-                        as f64;
+                let zoom_t = apply_ease_out(
+                    prev_segment,
+                    t_clamp((cursor.time - prev_end_s) / ZOOM_DURATION),
+                );
 
                 let prev_bounds = SegmentBounds::from_region(prev_segment, cursor_pos);
 
@@ -222,8 +275,7 @@ impl InterpolatedZoom {
             // Case 2: In first segment, zooming in
             (None, Some(segment)) => {
                 let start_s = segment.start_ms as f64 / 1000.0;
-                let t =
-                    apply_ease_in(t_clamp((cursor.time - start_s) / ZOOM_DURATION) as f32) as f64;
+                let t = apply_ease_in(segment, t_clamp((cursor.time - start_s) / ZOOM_DURATION));
 
                 let segment_bounds = SegmentBounds::from_region(segment, cursor_pos);
 
@@ -240,9 +292,10 @@ impl InterpolatedZoom {
                 let segment_start_s = segment.start_ms as f64 / 1000.0;
                 let prev_end_s = prev_segment.end_ms as f64 / 1000.0;
 
-                let zoom_t =
-                    apply_ease_in(t_clamp((cursor.time - segment_start_s) / ZOOM_DURATION) as f32)
-                        as f64;
+                let zoom_t = apply_ease_in(
+                    segment,
+                    t_clamp((cursor.time - segment_start_s) / ZOOM_DURATION),
+                );
 
                 // No gap: direct transition between segments
                 if (segment.start_ms as i64 - prev_segment.end_ms as i64).abs() < 10 {
@@ -279,6 +332,13 @@ impl InterpolatedZoom {
                 t: 0.0,
                 bounds: default,
             },
+        };
+
+        // Overshooting easings may carry past either end of a transition;
+        // never let that show area outside the frame
+        Self {
+            t: t_clamp(interpolated.t),
+            bounds: interpolated.bounds.clamp_to_frame(),
         }
     }
 
@@ -290,7 +350,14 @@ impl InterpolatedZoom {
 
     /// Convert to ZoomState for rendering.
     pub fn to_zoom_state(&self) -> ZoomState {
-        let scale = self.bounds.zoom_amount();
+        self.bounds.to_zoom_state()
+    }
+}
+
+impl SegmentBounds {
+    /// Convert to ZoomState for rendering.
+    pub fn to_zoom_state(&self) -> ZoomState {
+        let scale = self.zoom_amount();
 
         // No zoom (scale ~= 1.0)
         if (scale - 1.0).abs() < 0.001 {
@@ -303,8 +370,8 @@ impl InterpolatedZoom {
         //   where centerDiff = target * (scale - 1)
         // So: topLeft = -target * (scale - 1)
         // Therefore: target = -topLeft / (scale - 1)
-        let center_x = (-self.bounds.top_left.x / (scale - 1.0)) as f32;
-        let center_y = (-self.bounds.top_left.y / (scale - 1.0)) as f32;
+        let center_x = (-self.top_left.x / (scale - 1.0)) as f32;
+        let center_y = (-self.top_left.y / (scale - 1.0)) as f32;
 
         ZoomState {
             scale: scale as f32,
@@ -314,6 +381,52 @@ impl InterpolatedZoom {
     }
 }
 
+/// Zoom bounds of a keyframe.
+fn keyframe_bounds(keyframe: &ZoomKeyframe) -> SegmentBounds {
+    SegmentBounds::from_center(
+        XY::new(keyframe.center.x, keyframe.center.y),
+        (keyframe.scale as f64).max(1.0),
+    )
+}
+
+/// Zoom on a keyframed curve. `keyframes` must be sorted and non-empty.
+///
+/// Finds the keyframes bracketing `timestamp_ms` and eases between their
+/// bounds with the later keyframe's easing.
+fn keyframe_zoom_at(keyframes: &[ZoomKeyframe], timestamp_ms: u64) -> ZoomState {
+    let next_idx = keyframes.partition_point(|k| k.time_ms <= timestamp_ms);
+
+    let bounds = match (next_idx.checked_sub(1), keyframes.get(next_idx)) {
+        (Some(prev_idx), Some(next)) => {
+            let prev = &keyframes[prev_idx];
+            let span_ms = (next.time_ms - prev.time_ms) as f64;
+            let t = (timestamp_ms - prev.time_ms) as f64 / span_ms;
+
+            keyframe_bounds(prev).lerp(&keyframe_bounds(next), next.easing.apply(t))
+        },
+        (Some(prev_idx), None) => keyframe_bounds(&keyframes[prev_idx]),
+        (None, _) => keyframe_bounds(&keyframes[0]),
+    };
+
+    bounds.clamp_to_frame().to_zoom_state()
+}
+
+/// Ease a zoom transition with the region's easing function.
+///
+/// `EaseInOut` is the default for every region and keeps Cap's bezier curves,
+/// which differ for zooming in and out; other easings use the same curve both ways.
+fn ease_transition(easing: EasingFunction, t: f64, zooming_in: bool) -> f64 {
+    match easing {
+        EasingFunction::EaseInOut if zooming_in => {
+            bezier_easing::bezier_easing(0.1, 0.0, 0.3, 1.0).unwrap()(t as f32) as f64
+        },
+        EasingFunction::EaseInOut => {
+            bezier_easing::bezier_easing(0.5, 0.0, 0.5, 1.0).unwrap()(t as f32) as f64
+        },
+        other => other.apply(t),
+    }
+}
+
 /// Clamp value to 0-1 range.
 fn t_clamp(v: f64) -> f64 {
     v.clamp(0.0, 1.0)
@@ -323,6 +436,8 @@ fn t_clamp(v: f64) -> f64 {
 pub struct ZoomInterpolator {
     /// Sorted zoom regions.
     regions: Vec<ZoomRegion>,
+    /// Sorted keyframes; when present they replace the regions.
+    keyframes: Vec<ZoomKeyframe>,
 }
 
 impl ZoomInterpolator {
@@ -332,7 +447,13 @@ impl ZoomInterpolator {
         // Sort by start time
         regions.sort_by_key(|r| r.start_ms);
 
-        Self { regions }
+        let mut keyframes = match &config.mode {
+            ZoomMode::Keyframed { keyframes } => keyframes.clone(),
+            _ => Vec::new(),
+        };
+        keyframes.sort_by_key(|k| k.time_ms);
+
+        Self { regions, keyframes }
     }
 
     /// Get the zoom state at a specific timestamp.
@@ -346,6 +467,10 @@ impl ZoomInterpolator {
         timestamp_ms: u64,
         cursor_pos: Option<(f64, f64)>,
     ) -> ZoomState {
+        if !self.keyframes.is_empty() {
+            return keyframe_zoom_at(&self.keyframes, timestamp_ms);
+        }
+
         if self.regions.is_empty() {
             return ZoomState::identity();
         }
@@ -364,6 +489,68 @@ impl ZoomInterpolator {
     }
 }
 
+/// Follow-cursor zoom ([`ZoomMode::FollowCursor`]) for sequential rendering.
+///
+/// Keeps a smoothed camera position that is eased toward the cursor on every
+/// frame, so it must see frames in timeline order.
+pub struct CursorFollowZoom {
+    scale: f64,
+    smoothing: f64,
+    /// Smoothed cursor position the view is centered on.
+    focus: Option<(f64, f64)>,
+    last_timestamp_ms: u64,
+}
+
+impl CursorFollowZoom {
+    /// Create a follower if the zoom config is in follow-cursor mode.
+    pub fn from_config(config: &ZoomConfig) -> Option<Self> {
+        match config.mode {
+            ZoomMode::FollowCursor { scale, smoothing } => Some(Self {
+                scale: (scale as f64).max(1.0),
+                smoothing: (smoothing as f64).clamp(0.0, MAX_FOLLOW_SMOOTHING),
+                focus: None,
+                last_timestamp_ms: 0,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Advance to `timestamp_ms` and return the zoom that centers the cursor.
+    ///
+    /// Near the edges the view stops at the frame instead of centering, so
+    /// the zoomed viewport never leaves the composition.
+    pub fn update(&mut self, timestamp_ms: u64, cursor_pos: (f64, f64)) -> ZoomState {
+        let focus = match self.focus {
+            Some((x, y)) => {
+                let elapsed_ms = timestamp_ms.saturating_sub(self.last_timestamp_ms) as f64;
+                let t = 1.0 - self.smoothing.powf(elapsed_ms / FOLLOW_REFERENCE_FRAME_MS);
+                (x + (cursor_pos.0 - x) * t, y + (cursor_pos.1 - y) * t)
+            },
+            None => cursor_pos,
+        };
+        self.focus = Some(focus);
+        self.last_timestamp_ms = timestamp_ms;
+
+        if self.scale < 1.001 {
+            return ZoomState::identity();
+        }
+
+        ZoomState {
+            scale: self.scale as f32,
+            center_x: centered_zoom_anchor(focus.0, self.scale) as f32,
+            center_y: centered_zoom_anchor(focus.1, self.scale) as f32,
+        }
+    }
+}
+
+/// Zoom center for one axis that puts `target` in the middle of the output.
+///
+/// A source point `t` lands on screen at `c + (t - c) * scale`; solving for
+/// `t -> 0.5` gives `c`. Clamping `c` to 0-1 keeps the viewport in the frame.
+fn centered_zoom_anchor(target: f64, scale: f64) -> f64 {
+    t_clamp((target * scale - 0.5) / (scale - 1.0))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -384,6 +571,7 @@ mod tests {
                 duration_out_ms: 300,
                 easing: EasingFunction::EaseInOut,
             },
+            focus_padding: ZoomRegion::default_focus_padding(),
         }
     }
 
@@ -539,4 +727,212 @@ mod tests {
             state3.center_y
         );
     }
+
+    #[test]
+    fn test_focus_anchor_interior_target_unchanged() {
+        assert!((focus_anchor(0.5, 2.0, 0.05) - 0.5).abs() < 1e-9);
+        assert!((focus_anchor(0.3, 3.0, 0.05) - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_focus_anchor_moves_edge_target_inward() {
+        // Target at 0.98 with 4x zoom and 5% padding should land at 0.95 on screen
+        let c = focus_anchor(0.98, 4.0, 0.05);
+        let screen = c + (0.98 - c) * 4.0;
+        assert!((screen - 0.95).abs() < 1e-9, "screen pos {}", screen);
+    }
+
+    #[test]
+    fn test_focus_anchor_keeps_viewport_in_frame() {
+        // Large padding can't be honored at 2x - anchor clamps to the frame edge
+        let c = focus_anchor(0.98, 2.0, 0.2);
+        assert!((c - 1.0).abs() < 1e-9);
+
+        // Out-of-frame targets (e.g. cursor off-screen) are clamped too
+        assert!((focus_anchor(-0.2, 2.0, 0.05) - 0.0).abs() < 1e-9);
+        assert!((focus_anchor(1.3, 2.0, 0.05) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_segment_bounds_top_right_corner_padding() {
+        // Zoom toward a close button in the top-right corner
+        let mut region = make_region(0, 1000, 2.0, 0.99, 0.01);
+        region.focus_padding = 0.1;
+        let bounds = SegmentBounds::from_region(&region, None);
+
+        // Viewport must stay inside the frame
+        assert!(bounds.top_left.x <= 1e-9 && bounds.top_left.y <= 1e-9);
+        assert!(bounds.bottom_right.x >= 1.0 - 1e-9 && bounds.bottom_right.y >= 1.0 - 1e-9);
+
+        // Target is moved inward from the output edges
+        let state = InterpolatedZoom { t: 1.0, bounds }.to_zoom_state();
+        let screen_x = state.center_x + (0.99 - state.center_x) * state.scale;
+        let screen_y = state.center_y + (0.01 - state.center_y) * state.scale;
+        assert!(screen_x < 0.99, "screen_x {}", screen_x);
+        assert!(screen_y > 0.01, "screen_y {}", screen_y);
+    }
+
+    #[test]
+    fn test_easing_curves_sampled() {
+        let cases = [
+            (EasingFunction::EaseInOutCubic, 0.5),
+            (EasingFunction::EaseOutBack, 1.0877),
+            (EasingFunction::CubicBezier(0.25, 0.1, 0.25, 1.0), 0.8024),
+        ];
+
+        for (easing, mid) in cases {
+            assert!(easing.apply(0.0).abs() < 1e-3, "{:?} at 0", easing);
+            assert!(
+                (easing.apply(0.5) - mid).abs() < 1e-3,
+                "{:?} at 0.5 = {}",
+                easing,
+                easing.apply(0.5)
+            );
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-3, "{:?} at 1", easing);
+        }
+    }
+
+    #[test]
+    fn test_default_easing_keeps_cap_curves() {
+        let zoom_in = bezier_easing::bezier_easing(0.1, 0.0, 0.3, 1.0).unwrap()(0.3) as f64;
+        let zoom_out = bezier_easing::bezier_easing(0.5, 0.0, 0.5, 1.0).unwrap()(0.3) as f64;
+
+        assert!((ease_transition(EasingFunction::EaseInOut, 0.3, true) - zoom_in).abs() < 1e-9);
+        assert!((ease_transition(EasingFunction::EaseInOut, 0.3, false) - zoom_out).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_overshoot_stays_in_frame() {
+        // Corner target with an overshooting easing, in and out
+        let mut region = make_region(1000, 3000, 3.0, 1.0, 1.0);
+        region.transition.easing = EasingFunction::EaseOutBack;
+        let regions = vec![region];
+
+        for ms in (1000..=4500).step_by(25) {
+            let cursor = SegmentsCursor::new(ms as f64 / 1000.0, &regions);
+            let zoom = InterpolatedZoom::new(cursor, None);
+
+            assert!((0.0..=1.0).contains(&zoom.t), "t {} at {}ms", zoom.t, ms);
+            assert!(zoom.bounds.zoom_amount() >= 1.0 - 1e-9, "scale at {}ms", ms);
+            assert!(
+                zoom.bounds.top_left.x <= 1e-9 && zoom.bounds.top_left.y <= 1e-9,
+                "top-left inside frame at {}ms",
+                ms
+            );
+            assert!(
+                zoom.bounds.bottom_right.x >= 1.0 - 1e-9
+                    && zoom.bounds.bottom_right.y >= 1.0 - 1e-9,
+                "bottom-right inside frame at {}ms",
+                ms
+            );
+        }
+    }
+
+    fn follow_config(scale: f32, smoothing: f32) -> ZoomConfig {
+        ZoomConfig {
+            mode: ZoomMode::FollowCursor { scale, smoothing },
+            auto_zoom_scale: 2.0,
+            regions: vec![],
+        }
+    }
+
+    #[test]
+    fn test_follow_cursor_centers_pointer() {
+        let mut follow = CursorFollowZoom::from_config(&follow_config(2.0, 0.0)).unwrap();
+
+        // Cursor at (0.4, 0.6) should land in the middle of the output
+        let state = follow.update(0, (0.4, 0.6));
+        let screen_x = state.center_x + (0.4 - state.center_x) * state.scale;
+        let screen_y = state.center_y + (0.6 - state.center_y) * state.scale;
+        assert!((screen_x - 0.5).abs() < 1e-4, "x on screen: {}", screen_x);
+        assert!((screen_y - 0.5).abs() < 1e-4, "y on screen: {}", screen_y);
+
+        assert!(CursorFollowZoom::from_config(&ZoomConfig::default()).is_none());
+    }
+
+    #[test]
+    fn test_follow_cursor_clamps_to_frame() {
+        let mut follow = CursorFollowZoom::from_config(&follow_config(2.0, 0.0)).unwrap();
+
+        let state = follow.update(0, (0.0, 1.0));
+        assert_eq!((state.center_x, state.center_y), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_follow_cursor_smoothing_lags_behind() {
+        let mut follow = CursorFollowZoom::from_config(&follow_config(2.0, 0.9)).unwrap();
+
+        let start = follow.update(0, (0.5, 0.5));
+        let next = follow.update(17, (0.75, 0.5));
+        assert!(next.center_x > start.center_x);
+        assert!(
+            next.center_x < 0.99,
+            "should not snap, got {}",
+            next.center_x
+        );
+
+        // Eventually catches up
+        let mut state = next;
+        for frame in 2..300 {
+            state = follow.update(frame * 17, (0.75, 0.5));
+        }
+        assert!((state.center_x - 1.0).abs() < 1e-3);
+    }
+
+    fn keyframe(time_ms: u64, x: f64, y: f64, scale: f32) -> ZoomKeyframe {
+        ZoomKeyframe {
+            time_ms,
+            center: crate::commands::video_recording::video_project::XY { x, y },
+            scale,
+            easing: EasingFunction::Linear,
+        }
+    }
+
+    #[test]
+    fn test_keyframes_interpolate_between_brackets() {
+        let config = ZoomConfig {
+            mode: ZoomMode::Keyframed {
+                keyframes: vec![
+                    keyframe(5000, 0.3, 0.4, 1.0),
+                    keyframe(2000, 0.3, 0.4, 2.0),
+                    keyframe(0, 0.5, 0.5, 1.0),
+                ],
+            },
+            auto_zoom_scale: 2.0,
+            regions: vec![make_region(0, 10000, 3.0, 0.5, 0.5)],
+        };
+        let interpolator = ZoomInterpolator::new(&config);
+
+        // Keyframes replace the regions
+        assert!((interpolator.get_zoom_at(0).scale - 1.0).abs() < 0.001);
+
+        // Halfway from 1x to 2x
+        let state = interpolator.get_zoom_at(1000);
+        assert!((state.scale - 1.5).abs() < 0.001);
+
+        // On a keyframe
+        let state = interpolator.get_zoom_at(2000);
+        assert!((state.scale - 2.0).abs() < 0.001);
+        assert!((state.center_x - 0.3).abs() < 0.001);
+        assert!((state.center_y - 0.4).abs() < 0.001);
+
+        // Holds the last keyframe
+        assert!((interpolator.get_zoom_at(8000).scale - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_keyframes_hold_first_before_start() {
+        let config = ZoomConfig {
+            mode: ZoomMode::Keyframed {
+                keyframes: vec![keyframe(2000, 0.3, 0.4, 2.0)],
+            },
+            auto_zoom_scale: 2.0,
+            regions: vec![],
+        };
+        let interpolator = ZoomInterpolator::new(&config);
+
+        let state = interpolator.get_zoom_at(500);
+        assert!((state.scale - 2.0).abs() < 0.001);
+        assert!((state.center_x - 0.3).abs() < 0.001);
+    }
 }
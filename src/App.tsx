@@ -32,13 +32,19 @@ function App() {
   const [showShortcuts, setShowShortcuts] = useState(false);
 
   // Capture actions for shortcuts
-  const { triggerNewCapture, triggerFullscreenCapture, triggerAllMonitorsCapture } = useCaptureActions();
+  const {
+    triggerNewCapture,
+    triggerFullscreenCapture,
+    triggerAllMonitorsCapture,
+    triggerWindowUnderCursorCapture,
+  } = useCaptureActions();
 
   // App initialization (settings, shortcuts, cleanup)
   useAppInitialization({
     triggerNewCapture,
     triggerFullscreenCapture,
     triggerAllMonitorsCapture,
+    triggerWindowUnderCursorCapture,
   });
 
   // Consolidated event listener callbacks
@@ -21,6 +21,7 @@ import { SettingsPopover } from './SettingsPopover';
 import { AudioLevelMeter } from './AudioLevelMeter';
 import { useCaptureSettingsStore } from '@/stores/captureSettingsStore';
 import { useRustAudioLevels } from '@/hooks/useRustAudioLevels';
+import { formatFileSize } from '@/stores/videoRecordingStore';
 
 export type ToolbarMode = 'selection' | 'starting' | 'recording' | 'paused' | 'processing' | 'error';
 
@@ -61,6 +62,8 @@ interface CaptureToolbarProps {
   format?: RecordingFormat;
   /** Elapsed recording time in seconds */
   elapsedTime?: number;
+  /** Output size written so far in bytes */
+  currentSizeBytes?: number;
   /** GIF encoding progress (0-1) */
   progress?: number;
   /** Error message */
@@ -75,10 +78,15 @@ interface CaptureToolbarProps {
   countdownSeconds?: number;
   /** Callback when user changes dimensions via input */
   onDimensionChange?: (width: number, height: number) => void;
+  /** Called when the user picks the aspect ratio Shift-drag locks to */
+  onAspectRatioChange?: (width: number, height: number) => void;
   /** Open settings modal */
   onOpenSettings?: () => void;
 }
 
+/** GIF sizes are estimated until encoding finishes. */
+const ESTIMATED_SIZE_PREFIX = '~';
+
 function formatTime(seconds: number): string {
   const mins = Math.floor(seconds / 60);
   const secs = Math.floor(seconds % 60);
@@ -103,12 +111,14 @@ export const CaptureToolbar: React.FC<CaptureToolbarProps> = ({
   onCancel,
   format = 'mp4',
   elapsedTime = 0,
+  currentSizeBytes = 0,
   errorMessage,
   onPause,
   onResume,
   onStop,
   countdownSeconds,
   onDimensionChange,
+  onAspectRatioChange,
   onOpenSettings,
 }) => {
   const isGif = captureType === 'gif' || format === 'gif';
@@ -131,6 +141,7 @@ export const CaptureToolbar: React.FC<CaptureToolbarProps> = ({
   const { micLevel, systemLevel } = useRustAudioLevels({
     micDeviceIndex: isMicEnabled ? micDeviceIndex : null,
     monitorSystemAudio: isSystemAudioEnabled,
+    noiseGateThresholdDb: settings.video.noiseGate ? settings.video.noiseGateThresholdDb : null,
     enabled: isVideoMode && !isBusy,
   });
 
@@ -168,6 +179,11 @@ export const CaptureToolbar: React.FC<CaptureToolbarProps> = ({
             <span className="glass-text glass-text--mono text-sm font-medium">
               {formatTime(elapsedTime)}
             </span>
+            {currentSizeBytes > 0 && (
+              <span className="glass-text--muted glass-text--mono text-xs select-none">
+                {isGif ? ESTIMATED_SIZE_PREFIX : ''}{formatFileSize(currentSizeBytes)}
+              </span>
+            )}
             <div className={`glass-badge px-2 py-0.5 text-[9px] uppercase tracking-wider select-none ${
               isGif ? 'glass-badge--purple' : 'glass-badge--blue'
             }`}>
@@ -214,8 +230,8 @@ export const CaptureToolbar: React.FC<CaptureToolbarProps> = ({
 
         {/* Controls */}
         <div className="glass-controls-section">
-          {/* Pause/Resume button (not for GIF, not during starting/processing/error) */}
-          {isRecording && !isGif && (
+          {/* Pause/Resume button (not during starting/processing/error) */}
+          {isRecording && (
             <button
               type="button"
               onClick={handlePauseResume}
@@ -289,6 +305,7 @@ export const CaptureToolbar: React.FC<CaptureToolbarProps> = ({
               width={width}
               height={height}
               onDimensionChange={onDimensionChange}
+              onAspectRatioChange={onAspectRatioChange}
               onBack={onRedo}
               disabled={isBusy}
             />
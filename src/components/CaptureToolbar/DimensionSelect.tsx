@@ -2,7 +2,8 @@
  * DimensionSelect - Compact dimension inputs matching source selector style.
  *
  * Shows editable W × H inputs plus a button that opens a native OS menu
- * for preset selection. Styled to match glass-source-group buttons.
+ * for preset selection and the aspect ratio Shift-drag locks to.
+ * Styled to match glass-source-group buttons.
  */
 
 import React, { useState, useEffect, useCallback, useRef } from 'react';
@@ -21,10 +22,18 @@ const DIMENSION_PRESETS = [
   { label: 'Story', width: 1080, height: 1920 },
 ] as const;
 
+// Ratios a Shift-drag in the overlay can lock to
+const ASPECT_RATIO_PRESETS = [
+  { label: '16:9', width: 16, height: 9 },
+  { label: '4:3', width: 4, height: 3 },
+  { label: '1:1', width: 1, height: 1 },
+] as const;
+
 interface DimensionSelectProps {
   width: number;
   height: number;
   onDimensionChange?: (width: number, height: number) => void;
+  onAspectRatioChange?: (width: number, height: number) => void;
   onBack?: () => void;
   disabled?: boolean;
 }
@@ -33,6 +42,7 @@ export const DimensionSelect: React.FC<DimensionSelectProps> = ({
   width,
   height,
   onDimensionChange,
+  onAspectRatioChange,
   onBack,
   disabled = false,
 }) => {
@@ -97,6 +107,19 @@ export const DimensionSelect: React.FC<DimensionSelectProps> = ({
             action: () => handlePresetSelect(preset),
           })
         ),
+        PredefinedMenuItem.new({ item: 'Separator' }),
+        MenuItem.new({
+          id: 'aspect-header',
+          text: 'Shift-drag ratio',
+          enabled: false,
+        }),
+        ...ASPECT_RATIO_PRESETS.map((ratio) =>
+          MenuItem.new({
+            id: `aspect-${ratio.label}`,
+            text: ratio.label,
+            action: () => onAspectRatioChange?.(ratio.width, ratio.height),
+          })
+        ),
       ]);
 
       const menu = await Menu.new({ items });
@@ -110,7 +133,7 @@ export const DimensionSelect: React.FC<DimensionSelectProps> = ({
     } catch (error) {
       captureLogger.error('Failed to open preset menu:', error);
     }
-  }, [disabled, handlePresetSelect]);
+  }, [disabled, handlePresetSelect, onAspectRatioChange]);
 
   return (
     <div className={`glass-source-group ${disabled ? 'opacity-50 pointer-events-none' : ''}`}>
@@ -7,11 +7,31 @@
 
 import React, { useEffect, useRef, useCallback } from 'react';
 import { Mic, MicOff, ChevronDown } from 'lucide-react';
-import { Menu, MenuItem, PredefinedMenuItem, CheckMenuItem } from '@tauri-apps/api/menu';
+import { Menu, MenuItem, PredefinedMenuItem, CheckMenuItem, Submenu } from '@tauri-apps/api/menu';
 import { LogicalPosition } from '@tauri-apps/api/dpi';
+import { invoke } from '@tauri-apps/api/core';
 import { useAudioInputStore } from '@/stores/audioInputStore';
 import { useCaptureSettingsStore } from '@/stores/captureSettingsStore';
 import { audioLogger } from '@/utils/logger';
+import type { MicChannelMode } from '@/types';
+
+/** Gain steps offered in the menu (dB). The backend clamps to -20..+20. */
+const MIC_GAIN_OPTIONS_DB = [-10, -5, 0, 5, 10, 15, 20];
+
+/** Noise gate thresholds offered in the menu (dBFS). Lower lets quieter speech through. */
+const NOISE_GATE_THRESHOLD_OPTIONS_DB = [-60, -50, -45, -40, -30];
+
+/** Channel handling offered in the menu. Mono modes fix interfaces that deliver on one side only. */
+const MIC_CHANNEL_MODE_OPTIONS: { mode: MicChannelMode; label: string }[] = [
+  { mode: 'monoMix', label: 'Mono (Mix Both)' },
+  { mode: 'monoLeft', label: 'Mono (Left Only)' },
+  { mode: 'monoRight', label: 'Mono (Right Only)' },
+  { mode: 'stereo', label: 'Stereo' },
+];
+
+function formatGain(db: number): string {
+  return db > 0 ? `+${db} dB` : `${db} dB`;
+}
 
 interface MicrophonePopoverProps {
   disabled?: boolean;
@@ -24,6 +44,15 @@ export const MicrophonePopover: React.FC<MicrophonePopoverProps> = ({ disabled =
 
   const selectedDeviceIndex = settings.video.microphoneDeviceIndex;
   const isEnabled = selectedDeviceIndex !== null;
+  const gainDb = settings.video.microphoneGainDb;
+  const { noiseGate, noiseGateThresholdDb, micChannelMode } = settings.video;
+
+  // Keep the backend gain in sync so level meters show the boosted signal
+  useEffect(() => {
+    invoke('set_recording_microphone_gain', { gainDb }).catch((error) => {
+      audioLogger.error('Failed to set microphone gain:', error);
+    });
+  }, [gainDb]);
 
   // Load devices on mount
   useEffect(() => {
@@ -46,6 +75,22 @@ export const MicrophonePopover: React.FC<MicrophonePopoverProps> = ({ disabled =
     updateVideoSettings({ microphoneDeviceIndex: deviceIndex });
   }, [updateVideoSettings]);
 
+  const handleSelectGain = useCallback((db: number) => {
+    updateVideoSettings({ microphoneGainDb: db });
+  }, [updateVideoSettings]);
+
+  const handleSelectChannelMode = useCallback((mode: MicChannelMode) => {
+    updateVideoSettings({ micChannelMode: mode });
+  }, [updateVideoSettings]);
+
+  const handleSelectNoiseGate = useCallback((thresholdDb: number | null) => {
+    if (thresholdDb === null) {
+      updateVideoSettings({ noiseGate: false });
+    } else {
+      updateVideoSettings({ noiseGate: true, noiseGateThresholdDb: thresholdDb });
+    }
+  }, [updateVideoSettings]);
+
   // Open native menu
   const openMenu = useCallback(async () => {
     if (disabled) return;
@@ -81,6 +126,64 @@ export const MicrophonePopover: React.FC<MicrophonePopoverProps> = ({ disabled =
             action: () => handleSelectDevice(device.index),
           })
         ),
+        // Input gain for quiet mics
+        PredefinedMenuItem.new({ item: 'Separator' }),
+        Promise.all(
+          MIC_GAIN_OPTIONS_DB.map((db) =>
+            CheckMenuItem.new({
+              id: `gain-${db}`,
+              text: formatGain(db),
+              checked: gainDb === db,
+              action: () => handleSelectGain(db),
+            })
+          )
+        ).then((gainItems) =>
+          Submenu.new({
+            id: 'gain-submenu',
+            text: `Gain: ${formatGain(gainDb)}`,
+            items: gainItems,
+          })
+        ),
+        // Channel handling for one-sided interface inputs
+        Promise.all(
+          MIC_CHANNEL_MODE_OPTIONS.map(({ mode, label }) =>
+            CheckMenuItem.new({
+              id: `channels-${mode}`,
+              text: label,
+              checked: micChannelMode === mode,
+              action: () => handleSelectChannelMode(mode),
+            })
+          )
+        ).then((channelItems) =>
+          Submenu.new({
+            id: 'channels-submenu',
+            text: `Channels: ${MIC_CHANNEL_MODE_OPTIONS.find((o) => o.mode === micChannelMode)?.label ?? 'Stereo'}`,
+            items: channelItems,
+          })
+        ),
+        // Noise gate silences background hiss between words
+        Promise.all([
+          CheckMenuItem.new({
+            id: 'noise-gate-off',
+            text: 'Off',
+            checked: !noiseGate,
+            action: () => handleSelectNoiseGate(null),
+          }),
+          ...NOISE_GATE_THRESHOLD_OPTIONS_DB.map((db) =>
+            CheckMenuItem.new({
+              id: `noise-gate-${db}`,
+              text: `Below ${db} dB`,
+              checked: noiseGate && noiseGateThresholdDb === db,
+              action: () => handleSelectNoiseGate(db),
+            })
+          ),
+        ]).then((gateItems) =>
+          Submenu.new({
+            id: 'noise-gate-submenu',
+            text: noiseGate ? `Noise Gate: ${noiseGateThresholdDb} dB` : 'Noise Gate: Off',
+            items: gateItems,
+          })
+        ),
         // Refresh option
         PredefinedMenuItem.new({ item: 'Separator' }),
         MenuItem.new({
@@ -101,7 +204,7 @@ export const MicrophonePopover: React.FC<MicrophonePopoverProps> = ({ disabled =
     } catch (error) {
       audioLogger.error('Failed to open microphone menu:', error);
     }
-  }, [disabled, devices, isEnabled, selectedDeviceIndex, loadDevices, handleSelectDevice]);
+  }, [disabled, devices, isEnabled, selectedDeviceIndex, gainDb, noiseGate, noiseGateThresholdDb, micChannelMode, loadDevices, handleSelectDevice, handleSelectGain, handleSelectChannelMode, handleSelectNoiseGate]);
 
   return (
     <button
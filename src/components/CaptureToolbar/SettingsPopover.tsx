@@ -11,7 +11,18 @@ import { Menu, MenuItem, PredefinedMenuItem, CheckMenuItem, Submenu } from '@tau
 import { LogicalPosition } from '@tauri-apps/api/dpi';
 import { useCaptureSettingsStore } from '@/stores/captureSettingsStore';
 import { settingsLogger } from '@/utils/logger';
-import type { CaptureType } from '@/types';
+import type { CaptureType, RateControl } from '@/types';
+
+/** Peak bitrate for variable-bitrate recordings (bits per second). */
+const VBR_MAX_BITRATE = 40_000_000;
+
+/** Keyframe intervals offered in the menu (seconds). */
+const KEYFRAME_INTERVAL_OPTIONS = [0.5, 1, 2, 5];
+
+/** Segment lengths offered in the menu (minutes). */
+const SEGMENT_DURATION_OPTIONS_MINS = [10, 30, 60];
+
+const SECONDS_PER_MINUTE = 60;
 
 interface SettingsPopoverProps {
   mode: CaptureType;
@@ -137,6 +148,78 @@ export const SettingsPopover: React.FC<SettingsPopoverProps> = ({
             text: `Quality: ${settings.video.quality}%`,
             items: qualityItems,
           }));
+
+          // Rate control submenu
+          const rateControlLabels: Record<RateControl['mode'], string> = {
+            cbr: 'Constant',
+            vbr: 'Variable (Smaller)',
+            quality: 'Constant Quality',
+          };
+          const rateControlOptions: Record<RateControl['mode'], RateControl> = {
+            cbr: { mode: 'cbr' },
+            vbr: { mode: 'vbr', maxBitrate: VBR_MAX_BITRATE },
+            quality: { mode: 'quality', crf: 0 }, // CRF is derived from quality by the backend
+          };
+          const rateControlItems = await Promise.all(
+            (['cbr', 'vbr', 'quality'] as const).map(rateMode =>
+              CheckMenuItem.new({
+                id: `rate-${rateMode}`,
+                text: rateControlLabels[rateMode],
+                checked: settings.video.rateControl.mode === rateMode,
+                action: () => updateVideoSettings({ rateControl: rateControlOptions[rateMode] }),
+              })
+            )
+          );
+          menuItems.push(await Submenu.new({
+            id: 'rate-control-submenu',
+            text: `Bitrate: ${rateControlLabels[settings.video.rateControl.mode]}`,
+            items: rateControlItems,
+          }));
+
+          // Keyframe interval submenu - shorter intervals scrub faster in the editor
+          const keyframeItems = await Promise.all(
+            KEYFRAME_INTERVAL_OPTIONS.map(secs =>
+              CheckMenuItem.new({
+                id: `keyframe-${secs}`,
+                text: `Every ${secs}s`,
+                checked: settings.video.keyframeIntervalSecs === secs,
+                action: () => updateVideoSettings({ keyframeIntervalSecs: secs }),
+              })
+            )
+          );
+          menuItems.push(await Submenu.new({
+            id: 'keyframe-submenu',
+            text: `Keyframes: Every ${settings.video.keyframeIntervalSecs}s`,
+            items: keyframeItems,
+          }));
+
+          // Segment submenu - long recordings are written in parts and joined on stop
+          const segmentSecs = settings.video.segmentDurationSecs;
+          const segmentItems = [
+            await CheckMenuItem.new({
+              id: 'segment-off',
+              text: 'Off',
+              checked: segmentSecs === null,
+              action: () => updateVideoSettings({ segmentDurationSecs: null }),
+            }),
+            ...(await Promise.all(
+              SEGMENT_DURATION_OPTIONS_MINS.map(mins =>
+                CheckMenuItem.new({
+                  id: `segment-${mins}`,
+                  text: `Every ${mins} min`,
+                  checked: segmentSecs === mins * SECONDS_PER_MINUTE,
+                  action: () => updateVideoSettings({ segmentDurationSecs: mins * SECONDS_PER_MINUTE }),
+                })
+              )
+            )),
+          ];
+          menuItems.push(await Submenu.new({
+            id: 'segment-submenu',
+            text: segmentSecs === null
+              ? 'Split: Off'
+              : `Split: Every ${segmentSecs / SECONDS_PER_MINUTE} min`,
+            items: segmentItems,
+          }));
         } else {
           const presetLabels: Record<string, string> = { fast: 'Fast', balanced: 'Balanced', high: 'High' };
           const presetItems = await Promise.all(
@@ -187,6 +270,12 @@ export const SettingsPopover: React.FC<SettingsPopoverProps> = ({
           checked: settings.video.quickCapture,
           action: () => updateVideoSettings({ quickCapture: !settings.video.quickCapture }),
         }));
+        menuItems.push(await CheckMenuItem.new({
+          id: 'hevc',
+          text: 'HEVC / H.265 (Smaller Files)',
+          checked: settings.video.codec === 'h265',
+          action: () => updateVideoSettings({ codec: settings.video.codec === 'h265' ? 'h264' : 'h265' }),
+        }));
       }
 
       // Cursor capture toggle
@@ -205,6 +294,14 @@ export const SettingsPopover: React.FC<SettingsPopoverProps> = ({
           checked: settings.video.hideDesktopIcons,
           action: () => updateVideoSettings({ hideDesktopIcons: !settings.video.hideDesktopIcons }),
         }));
+        // Records key presses for the export keystroke overlay (editor flow only)
+        menuItems.push(await CheckMenuItem.new({
+          id: 'keystrokes',
+          text: 'Show Keystrokes',
+          checked: settings.video.captureKeystrokes,
+          enabled: !settings.video.quickCapture,
+          action: () => updateVideoSettings({ captureKeystrokes: !settings.video.captureKeystrokes }),
+        }));
       }
 
       // Link to full settings
@@ -28,6 +28,7 @@ import {
   Blinds,
   ListOrdered,
   Pen,
+  QrCode,
   Palette,
   Copy,
   Save,
@@ -85,6 +86,7 @@ const TOOL_ICONS: Record<Tool, React.ReactNode> = {
   blur: <Blinds className="h-4 w-4" />,
   steps: <ListOrdered className="h-4 w-4" />,
   pen: <Pen className="h-4 w-4" />,
+  qr: <QrCode className="h-4 w-4" />,
   background: <Palette className="h-4 w-4" />,
 };
 
@@ -100,6 +102,7 @@ const TOOL_SHORTCUTS: Partial<Record<Tool, string>> = {
   blur: 'B',
   steps: 'S',
   pen: 'P',
+  qr: 'Q',
   background: 'G',
 };
 
@@ -115,6 +118,7 @@ const TOOL_LABELS: Record<Tool, string> = {
   blur: 'Blur',
   steps: 'Steps',
   pen: 'Pen',
+  qr: 'QR Code',
   background: 'Background',
 };
 
@@ -122,7 +126,7 @@ const TOOL_LABELS: Record<Tool, string> = {
 const TOOL_CATEGORIES: { name: string; tools: Tool[] }[] = [
   { name: 'Selection', tools: ['select', 'crop'] },
   { name: 'Shapes', tools: ['rect', 'circle', 'arrow', 'line'] },
-  { name: 'Annotation', tools: ['text', 'steps', 'highlight', 'blur', 'pen'] },
+  { name: 'Annotation', tools: ['text', 'steps', 'highlight', 'blur', 'pen', 'qr'] },
   { name: 'Effects', tools: ['background'] },
 ];
 
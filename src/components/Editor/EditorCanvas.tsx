@@ -245,7 +245,7 @@ export const EditorCanvas = forwardRef<EditorCanvasRef, EditorCanvasProps>(({
   const hasProportionalShape = useMemo(() => {
     return selectedIds.some((id) => {
       const shape = shapes.find((s) => s.id === id);
-      return shape?.type === 'step';
+      return shape?.type === 'step' || shape?.type === 'qr';
     });
   }, [selectedIds, shapes]);
 
@@ -12,11 +12,13 @@ import {
   Grid3X3,
   Hash,
   Pencil,
+  QrCode,
   Crop,
 } from 'lucide-react';
 import { useEditorStore } from '../../stores/editorStore';
 import { useEditorHistory } from '../../hooks/useEditorHistory';
 import { type Tool } from '../../types';
+import { Input } from '@/components/ui/input';
 import { Label } from '@/components/ui/label';
 import { Switch } from '@/components/ui/switch';
 import { Separator } from '@/components/ui/separator';
@@ -60,6 +62,7 @@ const TOOL_INFO: Record<Tool, { icon: React.ElementType; label: string }> = {
   blur: { icon: Grid3X3, label: 'Blur' },
   steps: { icon: Hash, label: 'Steps' },
   pen: { icon: Pencil, label: 'Pen' },
+  qr: { icon: QrCode, label: 'QR Code' },
   background: { icon: Sparkles, label: 'Background' },
 };
 
@@ -94,7 +97,7 @@ export const PropertiesPanel: React.FC<PropertiesPanelProps> = ({
     updateShape,
   } = useEditorStore();
 
-  const { recordAction } = useEditorHistory();
+  const { takeSnapshot, commitSnapshot, recordAction } = useEditorHistory();
 
   // Get selected shapes
   const selectedShapes = shapes.filter(s => selectedIds.includes(s.id));
@@ -301,6 +304,7 @@ export const PropertiesPanel: React.FC<PropertiesPanelProps> = ({
       blur: 'blur',
       step: 'steps',
       pen: 'pen',
+      qr: 'qr',
     };
     return mapping[shapeType] || 'select';
   };
@@ -333,6 +337,27 @@ export const PropertiesPanel: React.FC<PropertiesPanelProps> = ({
           </div>
         )}
 
+        {/* QR Code - data of the selected code */}
+        {effectiveTool === 'qr' && (
+          singleSelection?.type === 'qr' ? (
+            <div className="space-y-3">
+              <Label className="text-xs text-[var(--ink-muted)] uppercase tracking-wide font-medium">QR Data</Label>
+              <Input
+                value={singleSelection.text ?? ''}
+                placeholder="https://example.com"
+                onFocus={takeSnapshot}
+                onChange={(e) => updateShape(singleSelection.id, { text: e.target.value })}
+                onBlur={commitSnapshot}
+                className="text-sm bg-[var(--card)]"
+              />
+            </div>
+          ) : (
+            <div className="text-xs text-[var(--ink-muted)] leading-relaxed">
+              Click to place a QR code, then enter the link or text it encodes.
+            </div>
+          )
+        )}
+
         {/* Quick Styles - Arrow, Line, Pen (stroke-only tools) */}
         {(effectiveTool === 'arrow' || effectiveTool === 'line' || effectiveTool === 'pen') && (
           <div className="space-y-3">
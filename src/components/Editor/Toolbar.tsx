@@ -17,6 +17,7 @@ import {
   Crop,
   Loader2,
   Pencil,
+  QrCode,
   FileImage,
   Save,
   Trash2,
@@ -65,6 +66,7 @@ const toolDefs: { id: Tool; Icon: typeof MousePointer2; label: string; shortcut:
   { id: 'blur', Icon: Droplet, label: 'Blur', shortcut: 'B' },
   { id: 'steps', Icon: Hash, label: 'Steps', shortcut: 'S' },
   { id: 'pen', Icon: Pencil, label: 'Pen', shortcut: 'P' },
+  { id: 'qr', Icon: QrCode, label: 'QR Code', shortcut: 'Q' },
   { id: 'background', Icon: Sparkles, label: 'Background', shortcut: 'G' },
 ];
 
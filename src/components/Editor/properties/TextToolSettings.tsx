@@ -1,4 +1,4 @@
-import React, { useState, useEffect } from 'react';
+import React, { useState, useEffect, useMemo } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import {
   Check,
@@ -13,6 +13,7 @@ import {
 import { useEditorStore } from '../../../stores/editorStore';
 import { useEditorHistory } from '../../../hooks/useEditorHistory';
 import { DEFAULT_FONT_FAMILIES, type CanvasShape } from '../../../types';
+import type { FontVariant, SystemFontFamily } from '@/types/generated';
 import { editorLogger } from '@/utils/logger';
 import { Slider } from '@/components/ui/slider';
 import { Label } from '@/components/ui/label';
@@ -27,6 +28,9 @@ const COLOR_PRESETS = [
   '#3B82F6', '#8B5CF6', '#EC4899', '#FFFFFF', '#1A1A1A',
 ];
 
+// Lightest weight browsers render bold text with without faking it
+const BOLD_MIN_WEIGHT = 600;
+
 interface TextToolSettingsProps {
   textShape: CanvasShape | null;
   strokeColor: string;
@@ -47,14 +51,16 @@ export const TextToolSettings: React.FC<TextToolSettingsProps> = ({
 
   // System fonts state
   const [systemFonts, setSystemFonts] = useState<string[]>([...DEFAULT_FONT_FAMILIES]);
+  const [fontVariants, setFontVariants] = useState<Map<string, FontVariant[]>>(new Map());
   const [fontComboboxOpen, setFontComboboxOpen] = useState(false);
 
   // Fetch system fonts on mount
   useEffect(() => {
-    invoke<string[]>('get_system_fonts')
-      .then((fonts) => {
-        if (fonts && fonts.length > 0) {
-          setSystemFonts(fonts);
+    invoke<SystemFontFamily[]>('get_system_font_families')
+      .then((families) => {
+        if (families && families.length > 0) {
+          setSystemFonts(families.map((f) => f.family));
+          setFontVariants(new Map(families.map((f) => [f.family, f.variants])));
         }
       })
       .catch((err) => {
@@ -75,6 +81,16 @@ export const TextToolSettings: React.FC<TextToolSettingsProps> = ({
   const isItalic = currentFontStyle.includes('italic');
   const isUnderline = currentTextDecoration === 'underline';
 
+  // Gray out styles the font isn't installed in (unknown fonts allow everything)
+  const { hasBold, hasItalic } = useMemo(() => {
+    const variants = fontVariants.get(currentFontFamily);
+    if (!variants) return { hasBold: true, hasItalic: true };
+    return {
+      hasBold: variants.some((v) => v.weight >= BOLD_MIN_WEIGHT && v.italic === isItalic),
+      hasItalic: variants.some((v) => v.italic && (v.weight >= BOLD_MIN_WEIGHT) === isBold),
+    };
+  }, [fontVariants, currentFontFamily, isBold, isItalic]);
+
   const toggleBold = () => {
     let newStyle = currentFontStyle;
     if (isBold) {
@@ -198,7 +214,8 @@ export const TextToolSettings: React.FC<TextToolSettingsProps> = ({
         <div className="flex gap-2">
           <button
             onClick={toggleBold}
-            disabled={!textShape}
+            disabled={!textShape || (!isBold && !hasBold)}
+            title={hasBold ? undefined : `${currentFontFamily} has no bold style`}
             className={`flex-1 h-9 rounded-lg flex items-center justify-center transition-all border ${
               isBold
                 ? 'bg-[var(--coral-50)] text-[var(--coral-500)] border-[var(--coral-200)]'
@@ -209,7 +226,8 @@ export const TextToolSettings: React.FC<TextToolSettingsProps> = ({
           </button>
           <button
             onClick={toggleItalic}
-            disabled={!textShape}
+            disabled={!textShape || (!isItalic && !hasItalic)}
+            title={hasItalic ? undefined : `${currentFontFamily} has no italic style`}
             className={`flex-1 h-9 rounded-lg flex items-center justify-center transition-all border ${
               isItalic
                 ? 'bg-[var(--coral-50)] text-[var(--coral-500)] border-[var(--coral-200)]'
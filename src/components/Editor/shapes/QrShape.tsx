@@ -0,0 +1,95 @@
+import React, { useEffect, useState } from 'react';
+import { Image, Rect } from 'react-konva';
+import { invoke } from '@tauri-apps/api/core';
+import type { BaseShapeProps } from '../../../types';
+import { useShapeCursor } from '../../../hooks/useShapeCursor';
+import { editorLogger } from '../../../utils/logger';
+
+/** Pixel size codes are generated at - the shape scales the image */
+const QR_RENDER_SIZE = 512;
+
+/** Shown while a code is generated, or when there is no data to encode */
+const QR_PLACEHOLDER_FILL = 'rgba(255, 255, 255, 0.8)';
+
+/** Generated codes by encoded data, shared by all QR shapes */
+const qrImageCache = new Map<string, Promise<HTMLImageElement>>();
+
+function loadQrImage(data: string): Promise<HTMLImageElement> {
+  let image = qrImageCache.get(data);
+  if (!image) {
+    image = invoke<number[]>('generate_qr', { data, size: QR_RENDER_SIZE }).then(
+      (png) =>
+        new Promise<HTMLImageElement>((resolve, reject) => {
+          const url = URL.createObjectURL(new Blob([new Uint8Array(png)], { type: 'image/png' }));
+          const img = new window.Image();
+          img.onload = () => {
+            URL.revokeObjectURL(url);
+            resolve(img);
+          };
+          img.onerror = reject;
+          img.src = url;
+        })
+    );
+    image.catch(() => qrImageCache.delete(data));
+    qrImageCache.set(data, image);
+  }
+  return image;
+}
+
+export const QrShape: React.FC<BaseShapeProps> = React.memo(({
+  shape,
+  isDraggable,
+  onClick,
+  onSelect,
+  onDragStart,
+  onDragEnd,
+  onTransformStart,
+  onTransformEnd,
+}) => {
+  const cursorHandlers = useShapeCursor(isDraggable);
+  const [image, setImage] = useState<HTMLImageElement | undefined>();
+  const data = shape.text ?? '';
+
+  useEffect(() => {
+    if (!data) {
+      setImage(undefined);
+      return;
+    }
+
+    // Keep showing the previous code until the new one is ready
+    let cancelled = false;
+    loadQrImage(data)
+      .then((img) => {
+        if (!cancelled) setImage(img);
+      })
+      .catch((error) => editorLogger.error('Failed to generate QR code:', error));
+    return () => {
+      cancelled = true;
+    };
+  }, [data]);
+
+  const commonProps = {
+    id: shape.id,
+    x: shape.x,
+    y: shape.y,
+    width: shape.width,
+    height: shape.height,
+    rotation: shape.rotation,
+    draggable: isDraggable,
+    onClick,
+    onTap: onSelect,
+    onDragStart,
+    onDragEnd,
+    onTransformStart,
+    onTransformEnd,
+    ...cursorHandlers,
+  };
+
+  if (!image) {
+    return <Rect {...commonProps} fill={QR_PLACEHOLDER_FILL} />;
+  }
+
+  return <Image {...commonProps} image={image} imageSmoothingEnabled={false} />;
+});
+
+QrShape.displayName = 'QrShape';
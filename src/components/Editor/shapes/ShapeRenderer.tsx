@@ -10,6 +10,7 @@ import { StepShape } from './StepShape';
 import { ArrowShape } from './ArrowShape';
 import { LineShape } from './LineShape';
 import { BlurShape } from './BlurShape';
+import { QrShape } from './QrShape';
 
 interface ShapeRendererProps {
   shapes: CanvasShape[];
@@ -190,6 +191,8 @@ const MemoizedShape = React.memo<{
       return <StepShape {...commonProps} />;
     case 'pen':
       return <PenShape {...commonProps} />;
+    case 'qr':
+      return <QrShape {...commonProps} />;
     default:
       return null;
   }
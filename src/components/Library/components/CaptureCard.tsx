@@ -6,7 +6,7 @@ import { TagChip } from './TagChip';
 import { TagPopover } from './TagPopover';
 import { useInViewAnimation, getCachedThumbnailUrl } from '../hooks';
 import type { CaptureCardProps } from './types';
-import { capturePropsAreEqual } from './types';
+import { capturePropsAreEqual, formatDuration } from './types';
 
 // Check if capture is a video or gif recording
 const isVideoOrGif = (captureType: string) => captureType === 'video' || captureType === 'gif';
@@ -178,6 +178,11 @@ export const CaptureCard: React.FC<CaptureCardProps> = memo(
             {/* Card Footer */}
             <div className="card-footer flex items-center justify-between">
               <div className="flex flex-col gap-1">
+                {!isPlaceholder && capture.name && (
+                  <span className="text-xs font-medium text-[var(--ink-black)] truncate">
+                    {capture.name}
+                  </span>
+                )}
                 <span className="text-[11px] text-[var(--ink-subtle)]">
                   {isPlaceholder ? 'Saving...' : formatDate(capture.created_at)}
                 </span>
@@ -189,6 +194,11 @@ export const CaptureCard: React.FC<CaptureCardProps> = memo(
                         ? capture.capture_type.toUpperCase()
                         : `${capture.dimensions.width} × ${capture.dimensions.height}`}
                   </span>
+                  {!isPlaceholder && capture.duration_ms !== null && (
+                    <span className="pill font-mono text-[10px]">
+                      {formatDuration(capture.duration_ms)}
+                    </span>
+                  )}
                   {/* Display up to 2 tags */}
                   {!isPlaceholder && capture.tags.slice(0, 2).map(tag => (
                     <TagChip key={tag} tag={tag} size="sm" />
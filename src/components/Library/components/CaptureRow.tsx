@@ -12,7 +12,7 @@ import { TagChip } from './TagChip';
 import { TagPopover } from './TagPopover';
 import { useInViewAnimation, getCachedThumbnailUrl } from '../hooks';
 import type { CaptureCardProps } from './types';
-import { capturePropsAreEqual } from './types';
+import { capturePropsAreEqual, formatDuration } from './types';
 
 // Check if capture is a video or gif recording
 const isVideoOrGif = (captureType: string) => captureType === 'video' || captureType === 'gif';
@@ -145,9 +145,15 @@ export const CaptureRow: React.FC<CaptureCardProps> = memo(
             {/* Info */}
             <div className="flex-1 min-w-0">
               <div className="flex items-center gap-2 mb-1">
-                <span className={`text-sm font-medium capitalize ${isMissing ? 'text-[var(--ink-subtle)]' : 'text-[var(--ink-black)]'}`}>
-                  {capture.capture_type} capture
-                </span>
+                {capture.name ? (
+                  <span className={`text-sm font-medium truncate ${isMissing ? 'text-[var(--ink-subtle)]' : 'text-[var(--ink-black)]'}`}>
+                    {capture.name}
+                  </span>
+                ) : (
+                  <span className={`text-sm font-medium capitalize ${isMissing ? 'text-[var(--ink-subtle)]' : 'text-[var(--ink-black)]'}`}>
+                    {capture.capture_type} capture
+                  </span>
+                )}
                 {isMissing && (
                   <Badge className="bg-amber-100 text-amber-700 text-[10px] px-2 py-0.5">Missing</Badge>
                 )}
@@ -168,6 +174,12 @@ export const CaptureRow: React.FC<CaptureCardProps> = memo(
                 {isMedia && capture.dimensions.width === 0
                   ? capture.capture_type.toUpperCase()
                   : `${capture.dimensions.width} × ${capture.dimensions.height}`}
+                {capture.duration_ms !== null && (
+                  <>
+                    <span className="mx-2 text-[var(--polar-frost)]">·</span>
+                    {formatDuration(capture.duration_ms)}
+                  </>
+                )}
                 <span className="mx-2 text-[var(--polar-frost)]">·</span>
                 {formatDate(capture.created_at)}
               </div>
@@ -17,6 +17,17 @@ export interface CaptureCardProps {
   formatDate: (date: string) => string;
 }
 
+const MS_PER_SECOND = 1000;
+const SECONDS_PER_MINUTE = 60;
+
+// Format a media duration as m:ss (e.g. 0:42, 12:05)
+export const formatDuration = (durationMs: number): string => {
+  const totalSeconds = Math.round(durationMs / MS_PER_SECOND);
+  const minutes = Math.floor(totalSeconds / SECONDS_PER_MINUTE);
+  const seconds = totalSeconds % SECONDS_PER_MINUTE;
+  return `${minutes}:${seconds.toString().padStart(2, '0')}`;
+};
+
 // Helper to compare tags arrays
 const tagsEqual = (a: string[], b: string[]): boolean => {
   if (a.length !== b.length) return false;
@@ -31,7 +42,10 @@ export const capturePropsAreEqual = (
   return (
     prev.capture.id === next.capture.id &&
     prev.capture.favorite === next.capture.favorite &&
+    prev.capture.name === next.capture.name &&
     prev.capture.thumbnail_path === next.capture.thumbnail_path &&
+    prev.capture.dimensions.width === next.capture.dimensions.width &&
+    prev.capture.duration_ms === next.capture.duration_ms &&
     tagsEqual(prev.capture.tags, next.capture.tags) &&
     prev.selected === next.selected &&
     prev.isLoading === next.isLoading
@@ -6,9 +6,27 @@ import { Button } from '@/components/ui/button';
 import { Input } from '@/components/ui/input';
 import { Switch } from '@/components/ui/switch';
 import { useSettingsStore } from '@/stores/settingsStore';
-import type { Theme } from '@/types';
+import type { S3UploadSettings, Theme } from '@/types';
 import { settingsLogger } from '@/utils/logger';
 
+const FILENAME_TEMPLATE_PLACEHOLDER = '{date}_{source}_{n}';
+
+interface S3UploadField {
+  key: keyof S3UploadSettings;
+  label: string;
+  placeholder: string;
+  secret?: boolean;
+}
+
+const S3_UPLOAD_FIELDS: S3UploadField[] = [
+  { key: 'endpoint', label: 'Endpoint', placeholder: 'https://s3.us-east-1.amazonaws.com' },
+  { key: 'region', label: 'Region', placeholder: 'us-east-1' },
+  { key: 'bucket', label: 'Bucket', placeholder: 'my-screenshots' },
+  { key: 'accessKeyId', label: 'Access key ID', placeholder: '' },
+  { key: 'secretAccessKey', label: 'Secret access key', placeholder: '', secret: true },
+  { key: 'publicUrl', label: 'Public URL (optional)', placeholder: 'https://cdn.example.com' },
+];
+
 export const GeneralTab: React.FC = () => {
   const { settings, updateGeneralSettings } = useSettingsStore();
   const { general } = settings;
@@ -222,6 +240,72 @@ export const GeneralTab: React.FC = () => {
               )}
             </div>
           </div>
+
+          {/* Filename Template */}
+          <div>
+            <label className="text-sm text-[var(--ink-black)] mb-2 block">
+              Filename template
+            </label>
+            <Input
+              value={general.filenameTemplate}
+              placeholder={FILENAME_TEMPLATE_PLACEHOLDER}
+              onChange={(e) => updateGeneralSettings({ filenameTemplate: e.target.value })}
+              className="text-sm bg-[var(--card)]"
+            />
+            <p className="text-xs text-[var(--ink-muted)] mt-1">
+              Use {'{date}'}, {'{time}'}, {'{source}'} and {'{n}'}. Leave empty for default names.
+            </p>
+          </div>
+
+          <label className="flex items-center justify-between cursor-pointer">
+            <div>
+              <p className="text-sm text-[var(--ink-black)]">
+                Animated video thumbnails
+              </p>
+              <p className="text-xs text-[var(--ink-muted)] mt-0.5">
+                Show a short looping preview of recordings in the library
+              </p>
+            </div>
+            <Switch
+              checked={general.animatedThumbnails}
+              onCheckedChange={(checked) => {
+                updateGeneralSettings({ animatedThumbnails: checked });
+              }}
+            />
+          </label>
+        </div>
+      </section>
+
+      {/* Sharing Section */}
+      <section>
+        <h3 className="text-xs font-semibold uppercase tracking-wider text-[var(--coral-400)] mb-3">
+          Sharing
+        </h3>
+        <div className="p-4 rounded-lg bg-[var(--polar-ice)] border border-[var(--polar-frost)] space-y-3">
+          <p className="text-xs text-[var(--ink-muted)]">
+            Upload captures to Amazon S3 or any S3-compatible storage. The shared link is copied to the clipboard.
+          </p>
+          {S3_UPLOAD_FIELDS.map(({ key, label, placeholder, secret }) => (
+            <div key={key}>
+              <label className="text-sm text-[var(--ink-black)] mb-1 block">
+                {label}
+              </label>
+              <Input
+                type={secret ? 'password' : 'text'}
+                value={general.s3Upload[key]}
+                placeholder={placeholder}
+                onChange={(e) =>
+                  updateGeneralSettings({
+                    s3Upload: { ...general.s3Upload, [key]: e.target.value },
+                  })
+                }
+                className="text-sm bg-[var(--card)]"
+              />
+            </div>
+          ))}
+          <p className="text-xs text-[var(--ink-muted)]">
+            The secret access key is saved unencrypted in the settings file. Use a key that can only write to this bucket.
+          </p>
         </div>
       </section>
 
@@ -3,7 +3,7 @@ import { resolveResource } from '@tauri-apps/api/path';
 import { convertFileSrc } from '@tauri-apps/api/core';
 import { Slider } from '@/components/ui/slider';
 import { ColorPicker } from '@/components/ui/color-picker';
-import { Check, Upload, X, Loader2 } from 'lucide-react';
+import { Check, Upload, X, Loader2, Droplet } from 'lucide-react';
 import type { BackgroundConfig, VideoBackgroundType } from '@/types';
 import { videoEditorLogger } from '@/utils/logger';
 import {
@@ -19,6 +19,21 @@ interface BackgroundSettingsProps {
   onUpdate: (updates: Partial<BackgroundConfig>) => void;
 }
 
+const BACKGROUND_TYPES: VideoBackgroundType[] = ['wallpaper', 'image', 'blurredSource', 'solid', 'gradient'];
+
+const BACKGROUND_TYPE_LABELS: Record<VideoBackgroundType, string> = {
+  wallpaper: 'Wallpaper',
+  image: 'Image',
+  blurredSource: 'Blur',
+  solid: 'Solid',
+  gradient: 'Gradient',
+};
+
+/** Blur applied when first switching to the blurred source background (0-100%). */
+const DEFAULT_SOURCE_BLUR = 60;
+const DEFAULT_BACKGROUND_PADDING = 40;
+const DEFAULT_BACKGROUND_ROUNDING = 12;
+
 interface LoadedWallpaper {
   id: string;
   url: string;
@@ -71,16 +86,19 @@ export function BackgroundSettings({ background, onUpdate }: BackgroundSettingsP
   }, [wallpaperTheme]);
 
   const handleTypeChange = useCallback((type: VideoBackgroundType) => {
-    // Auto-add padding when switching to wallpaper/image
-    const needsPadding = (type === 'wallpaper' || type === 'image') && background.padding === 0;
+    // Auto-add padding when switching to wallpaper/image/blurred source
+    const needsPadding = (type === 'wallpaper' || type === 'image' || type === 'blurredSource')
+      && background.padding === 0;
     const needsRounding = needsPadding && background.rounding === 0;
+    const needsBlur = type === 'blurredSource' && background.blur === 0;
 
     onUpdate({
       bgType: type,
-      ...(needsPadding && { padding: 40 }),
-      ...(needsRounding && { rounding: 12 }),
+      ...(needsPadding && { padding: DEFAULT_BACKGROUND_PADDING }),
+      ...(needsRounding && { rounding: DEFAULT_BACKGROUND_ROUNDING }),
+      ...(needsBlur && { blur: DEFAULT_SOURCE_BLUR }),
     });
-  }, [background.padding, background.rounding, onUpdate]);
+  }, [background.padding, background.rounding, background.blur, onUpdate]);
 
   const handleWallpaperSelect = useCallback((wallpaperId: string) => {
     // Store just the wallpaper ID - Rust will resolve the full path during export
@@ -120,8 +138,8 @@ export function BackgroundSettings({ background, onUpdate }: BackgroundSettingsP
       {/* Background Type Tabs */}
       <div>
         <span className="text-xs text-[var(--ink-muted)] block mb-2">Background Type</span>
-        <div className="grid grid-cols-4 gap-1.5">
-          {(['wallpaper', 'image', 'solid', 'gradient'] as VideoBackgroundType[]).map((type) => (
+        <div className="grid grid-cols-5 gap-1.5">
+          {BACKGROUND_TYPES.map((type) => (
             <button
               key={type}
               onClick={() => handleTypeChange(type)}
@@ -155,7 +173,10 @@ export function BackgroundSettings({ background, onUpdate }: BackgroundSettingsP
                     <Upload className="w-2 h-2" />
                   </div>
                 )}
-                <span className="capitalize">{type}</span>
+                {type === 'blurredSource' && (
+                  <Droplet className="w-3 h-3" />
+                )}
+                <span>{BACKGROUND_TYPE_LABELS[type]}</span>
               </div>
             </button>
           ))}
@@ -254,6 +275,40 @@ export function BackgroundSettings({ background, onUpdate }: BackgroundSettingsP
               />
             </label>
           )}
+
+          {/* Image blur */}
+          <div className="flex items-center gap-2">
+            <span className="text-[11px] text-[var(--ink-subtle)]">Blur</span>
+            <Slider
+              value={[background.imageBlurAmount]}
+              onValueChange={(values) => onUpdate({ imageBlurAmount: values[0] })}
+              min={0}
+              max={100}
+              step={1}
+              className="flex-1"
+            />
+            <span className="text-[11px] text-[var(--ink-faint)] w-8 text-right">
+              {background.imageBlurAmount}%
+            </span>
+          </div>
+        </div>
+      )}
+
+      {/* Blurred Source Tab Content */}
+      {background.bgType === 'blurredSource' && (
+        <div className="flex items-center gap-2">
+          <span className="text-[11px] text-[var(--ink-subtle)]">Blur</span>
+          <Slider
+            value={[background.blur]}
+            onValueChange={(values) => onUpdate({ blur: values[0] })}
+            min={0}
+            max={100}
+            step={1}
+            className="flex-1"
+          />
+          <span className="text-[11px] text-[var(--ink-faint)] w-8 text-right">
+            {background.blur}%
+          </span>
         </div>
       )}
 
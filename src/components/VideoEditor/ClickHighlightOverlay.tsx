@@ -24,6 +24,11 @@ interface ClickHighlightOverlayProps {
   zoomRegions?: ZoomRegion[];
 }
 
+/** How dark a spotlight makes the rest of the frame at the moment of the click. */
+const SPOTLIGHT_DIM_OPACITY = 0.6;
+/** Fraction of the spotlight radius over which its edge fades. */
+const SPOTLIGHT_FEATHER = 0.25;
+
 /**
  * Calculate the actual video bounds within a container using object-contain.
  * Returns the offset and dimensions of the video area.
@@ -169,34 +174,34 @@ function renderRipple(
 }
 
 /**
- * Render spotlight effect - static glow that fades out.
+ * Render spotlight effect - dims everything except a circle around the click,
+ * fading out. Matches the exporter's spotlight.
  */
 function renderSpotlight(
   ctx: CanvasRenderingContext2D,
   cx: number,
   cy: number,
   progress: number,
-  radius: number,
-  r: number,
-  g: number,
-  b: number,
-  baseAlpha: number
+  radius: number
 ) {
-  // Spotlight stays same size but fades out
-  const alpha = baseAlpha * (1 - progress);
-  
-  if (alpha <= 0) return;
-  
-  // Draw gaussian-like glow using radial gradient
-  const gradient = ctx.createRadialGradient(cx, cy, 0, cx, cy, radius);
-  gradient.addColorStop(0, `rgba(${r}, ${g}, ${b}, ${alpha})`);
-  gradient.addColorStop(0.5, `rgba(${r}, ${g}, ${b}, ${alpha * 0.5})`);
-  gradient.addColorStop(1, `rgba(${r}, ${g}, ${b}, 0)`);
-  
+  const alpha = SPOTLIGHT_DIM_OPACITY * (1 - progress);
+
+  if (alpha <= 0 || radius <= 0) return;
+
+  ctx.save();
+  ctx.fillStyle = `rgba(0, 0, 0, ${alpha})`;
+  ctx.fillRect(0, 0, ctx.canvas.width, ctx.canvas.height);
+
+  // Cut a soft-edged hole around the click
+  const gradient = ctx.createRadialGradient(cx, cy, radius * (1 - SPOTLIGHT_FEATHER), cx, cy, radius);
+  gradient.addColorStop(0, 'rgba(0, 0, 0, 1)');
+  gradient.addColorStop(1, 'rgba(0, 0, 0, 0)');
+  ctx.globalCompositeOperation = 'destination-out';
   ctx.beginPath();
   ctx.arc(cx, cy, radius, 0, Math.PI * 2);
   ctx.fillStyle = gradient;
   ctx.fill();
+  ctx.restore();
 }
 
 /**
@@ -295,7 +300,10 @@ export const ClickHighlightOverlay = memo(function ClickHighlightOverlay({
       ? cursorRecording.width / cursorRecording.height
       : videoAspectRatio;
 
-    for (const click of activeClicks) {
+    // Spotlights darken the whole frame, so only the latest click gets one
+    const clicksToRender = style === 'spotlight' ? activeClicks.slice(-1) : activeClicks;
+
+    for (const click of clicksToRender) {
       // Convert normalized coordinates to pixel coordinates
       // Account for object-contain letterboxing when video aspect ratio differs from container
       let pixelX: number;
@@ -320,8 +328,7 @@ export const ClickHighlightOverlay = memo(function ClickHighlightOverlay({
             parsedColor.r, parsedColor.g, parsedColor.b, parsedColor.a);
           break;
         case 'spotlight':
-          renderSpotlight(ctx, pixelX, pixelY, click.progress, scaledRadius,
-            parsedColor.r, parsedColor.g, parsedColor.b, parsedColor.a);
+          renderSpotlight(ctx, pixelX, pixelY, click.progress, scaledRadius);
           break;
         case 'ring':
           renderRing(ctx, pixelX, pixelY, click.progress, scaledRadius,
@@ -22,6 +22,9 @@ import {
 import type { SceneSegment, SceneMode, WebcamConfig, ZoomRegion, CursorRecording, CursorConfig, MaskSegment, TextSegment, CropConfig } from '../../types';
 
 // Selectors to prevent re-renders from unrelated store changes
+/** Blur radius at 100%, as a fraction of the shorter side (matches the Rust background layer). */
+const MAX_BLUR_RADIUS_FRACTION = 0.08;
+
 const selectProject = (s: ReturnType<typeof useVideoEditorStore.getState>) => s.project;
 const selectIsPlaying = (s: ReturnType<typeof useVideoEditorStore.getState>) => s.isPlaying;
 const selectPreviewTimeMs = (s: ReturnType<typeof useVideoEditorStore.getState>) => s.previewTimeMs;
@@ -574,6 +577,9 @@ export function GPUVideoPreview() {
               willChange: 'transform',
               transform: 'translateZ(0)',
               zIndex: 0,
+              filter: backgroundConfig.imageBlurAmount > 0 && containedSize
+                ? `blur(${(backgroundConfig.imageBlurAmount / 100) * MAX_BLUR_RADIUS_FRACTION * Math.min(containedSize.width, containedSize.height)}px)`
+                : undefined,
             }}
           />
         )}
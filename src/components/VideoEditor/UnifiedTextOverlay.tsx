@@ -55,7 +55,20 @@ function calculateOpacity(segment: TextSegment, time: number): number {
 }
 
 /**
- * Compute visibility key - changes when visible text OR opacity changes.
+ * Number of characters revealed by a typewriter animation (capped at the
+ * content length so the key settles once fully revealed).
+ */
+function calculateRevealCount(segment: TextSegment, time: number): number {
+  const length = [...segment.content].length;
+  if (segment.animation?.type !== 'typewriter' || segment.animation.charsPerSec <= 0) {
+    return length;
+  }
+  const revealed = Math.floor(Math.max(0, time - segment.start) * segment.animation.charsPerSec);
+  return Math.min(revealed, length);
+}
+
+/**
+ * Compute visibility key - changes when visible text, reveal progress, or opacity changes.
  */
 function computeVisibilityKey(segments: TextSegment[], time: number): string {
   const visible: string[] = [];
@@ -64,7 +77,7 @@ function computeVisibilityKey(segments: TextSegment[], time: number): string {
     if (s.enabled && time >= s.start && time <= s.end) {
       const opacity = Math.round(calculateOpacity(s, time) * 100) / 100;
       visible.push(
-        `${i}:${s.content}:${s.fontFamily}:${s.fontWeight}:${s.italic}:${s.fontSize}:${s.color}:${s.center.x.toFixed(3)}:${s.center.y.toFixed(3)}:${opacity}`
+        `${i}:${s.content}:${s.fontFamily}:${s.fontWeight}:${s.italic}:${s.fontSize}:${s.autoFit}:${s.letterSpacing}:${s.lineHeight}:${s.verticalAlign}:${s.color}:${s.strokeColor}:${s.strokeWidth}:${s.backgroundColor}:${s.backgroundOpacity}:${s.backgroundPadding}:${calculateRevealCount(s, time)}:${s.center.x.toFixed(3)}:${s.center.y.toFixed(3)}:${s.size.x.toFixed(3)}:${s.size.y.toFixed(3)}:${opacity}`
       );
     }
   }
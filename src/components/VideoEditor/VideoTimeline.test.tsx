@@ -82,6 +82,18 @@ const createMockProject = (overrides: Partial<VideoProject> = {}): VideoProject
   mask: {
     segments: [],
   },
+  cursorSmoothingSegments: [],
+  keystrokes: {
+    enabled: false,
+    position: 'bottomRight',
+    margin: 32,
+    fontSize: 28,
+    displayMs: 1500,
+    maxKeys: 4,
+    textColor: '#ffffff',
+    backgroundColor: '#000000',
+    backgroundOpacity: 0.8,
+  },
   ...overrides,
 });
 
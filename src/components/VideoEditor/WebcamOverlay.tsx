@@ -413,9 +413,19 @@ export const WebcamOverlay = memo(function WebcamOverlay({
 
   // Border style
   const borderStyle = useMemo((): React.CSSProperties => {
-    if (!config.border.enabled) return {};
+    const { enabled, width, color, gradientEnd, gradientAngle, glow } = config.border;
+    if (!enabled) return {};
     return {
-      border: `${config.border.width}px solid ${config.border.color}`,
+      // Gradient shows through a transparent border; the video covers the padding box
+      border: gradientEnd
+        ? `${width}px solid transparent`
+        : `${width}px solid ${color}`,
+      background: gradientEnd
+        ? `linear-gradient(${gradientAngle}deg, ${color}, ${gradientEnd}) border-box`
+        : undefined,
+      boxShadow: glow
+        ? `0 0 ${glow.radius}px color-mix(in srgb, ${glow.color} ${glow.opacity * 100}%, transparent)`
+        : undefined,
     };
   }, [config.border]);
 
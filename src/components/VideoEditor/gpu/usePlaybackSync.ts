@@ -13,6 +13,14 @@ import { usePlaybackControls, initPlaybackEngine, startPlaybackLoop, stopPlaybac
 import { videoEditorLogger } from '../../../utils/logger';
 import type { AudioTrackSettings } from '../../../types';
 
+/**
+ * Position in an audio track for a given video time, shifted by the track's
+ * offset (positive offsets delay the track).
+ */
+function trackTime(videoTimeSecs: number, offsetMs: number | undefined): number {
+  return Math.max(0, videoTimeSecs - (offsetMs ?? 0) / 1000);
+}
+
 interface PlaybackSyncOptions {
   /** Main video element ref */
   videoRef: React.RefObject<HTMLVideoElement | null>;
@@ -206,13 +214,13 @@ export function usePlaybackSync(options: PlaybackSyncOptions): PlaybackSyncResul
       const syncAudio = () => {
         const videoTime = video.currentTime;
         if (systemAudio) {
-          systemAudio.currentTime = videoTime;
+          systemAudio.currentTime = trackTime(videoTime, audioConfig?.systemOffsetMs);
           systemAudio.play().catch(e => {
             videoEditorLogger.warn('System audio play failed:', e);
           });
         }
         if (micAudio) {
-          micAudio.currentTime = videoTime;
+          micAudio.currentTime = trackTime(videoTime, audioConfig?.micOffsetMs);
           micAudio.play().catch(e => {
             videoEditorLogger.warn('Mic audio play failed:', e);
           });
@@ -235,19 +243,23 @@ export function usePlaybackSync(options: PlaybackSyncOptions): PlaybackSyncResul
   useEffect(() => {
     const targetTime = (previewTimeMs !== null ? previewTimeMs : currentTimeMs) / 1000;
 
+    const systemTarget = trackTime(targetTime, audioConfig?.systemOffsetMs);
+    const micTarget = trackTime(targetTime, audioConfig?.micOffsetMs);
+
     if (isPlaying) {
-      const audioTime = systemAudioRef.current?.currentTime ?? micAudioRef.current?.currentTime ?? 0;
-      const timeDiff = Math.abs(targetTime - audioTime);
+      const timeDiff = systemAudioRef.current
+        ? Math.abs(systemTarget - systemAudioRef.current.currentTime)
+        : Math.abs(micTarget - (micAudioRef.current?.currentTime ?? 0));
       if (timeDiff < 0.5) return;
     }
 
     if (systemAudioRef.current) {
-      systemAudioRef.current.currentTime = targetTime;
+      systemAudioRef.current.currentTime = systemTarget;
     }
     if (micAudioRef.current) {
-      micAudioRef.current.currentTime = targetTime;
+      micAudioRef.current.currentTime = micTarget;
     }
-  }, [previewTimeMs, currentTimeMs, isPlaying]);
+  }, [previewTimeMs, currentTimeMs, isPlaying, audioConfig?.systemOffsetMs, audioConfig?.micOffsetMs]);
 
   // Seek video when preview time or current time changes
   useEffect(() => {
@@ -377,6 +377,16 @@ export const TextTrackContent = memo(function TextTrackContent({
       italic: false,
       color: '#ffffff',
       fadeDuration: 0.15,
+      autoFit: false,
+      letterSpacing: 0,
+      lineHeight: 1.2,
+      strokeColor: null,
+      strokeWidth: 0,
+      backgroundColor: null,
+      backgroundOpacity: 1,
+      backgroundPadding: 8,
+      animation: null,
+      verticalAlign: 'top',
     };
 
     // addTextSegment handles selection internally after sorting
@@ -180,6 +180,7 @@ export const ZoomTrackContent = memo(function ZoomTrackContent({
       mode: 'auto',
       isAuto: false,
       transition: defaultTransition,
+      focusPadding: 0.05,
     };
 
     addZoomRegion(newRegion);
@@ -103,6 +103,7 @@ export function useVideoEditorActions() {
       gpuPlay: state.gpuPlay,
       gpuPause: state.gpuPause,
       gpuSeek: state.gpuSeek,
+      gpuStepFrame: state.gpuStepFrame,
       selectZoomRegion: state.selectZoomRegion,
       addZoomRegion: state.addZoomRegion,
       updateZoomRegion: state.updateZoomRegion,
@@ -26,6 +26,8 @@ interface UseAppInitializationProps {
   triggerFullscreenCapture: () => Promise<void>;
   /** Handler for all monitors capture shortcut */
   triggerAllMonitorsCapture: () => Promise<void>;
+  /** Handler for window under cursor capture shortcut */
+  triggerWindowUnderCursorCapture: () => Promise<void>;
 }
 
 /**
@@ -36,6 +38,7 @@ export function useAppInitialization({
   triggerNewCapture,
   triggerFullscreenCapture,
   triggerAllMonitorsCapture,
+  triggerWindowUnderCursorCapture,
 }: UseAppInitializationProps) {
   const { loadCaptures, restoreEditorSession } = useCaptureStore();
 
@@ -69,6 +72,7 @@ export function useAppInitialization({
     setShortcutHandler('new_capture', triggerNewCapture);
     setShortcutHandler('fullscreen_capture', triggerFullscreenCapture);
     setShortcutHandler('all_monitors_capture', triggerAllMonitorsCapture);
+    setShortcutHandler('window_under_cursor_capture', triggerWindowUnderCursorCapture);
 
     // Defer heavy initialization to after first paint for responsive UI
     const initSettings = async () => {
@@ -94,7 +98,12 @@ export function useAppInitialization({
     } else {
       setTimeout(initSettings, 0);
     }
-  }, [triggerNewCapture, triggerFullscreenCapture, triggerAllMonitorsCapture]);
+  }, [
+    triggerNewCapture,
+    triggerFullscreenCapture,
+    triggerAllMonitorsCapture,
+    triggerWindowUnderCursorCapture,
+  ]);
 
   // Sync recording state with backend on window focus
   // This handles edge cases where frontend/backend state may drift
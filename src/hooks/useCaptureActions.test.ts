@@ -74,6 +74,18 @@ describe('useCaptureActions', () => {
     expect(mockCaptureAllMonitorsToEditor).toHaveBeenCalledTimes(1);
   });
 
+  it('should capture the window under the cursor', async () => {
+    mockInvoke.mockResolvedValue(undefined);
+
+    const { result } = renderHook(() => useCaptureActions());
+
+    await act(async () => {
+      await result.current.triggerWindowUnderCursorCapture();
+    });
+
+    expect(mockInvoke).toHaveBeenCalledWith('trigger_capture_window_under_cursor');
+  });
+
   it('should return stable function references', () => {
     const { result, rerender } = renderHook(() => useCaptureActions());
 
@@ -37,9 +37,17 @@ export function useCaptureActions() {
     await CaptureService.captureAllMonitorsToEditor();
   }, []);
 
+  /**
+   * Capture the window under the cursor and open it in editor.
+   */
+  const triggerWindowUnderCursorCapture = useCallback(async () => {
+    await invoke('trigger_capture_window_under_cursor');
+  }, []);
+
   return {
     triggerNewCapture,
     triggerFullscreenCapture,
     triggerAllMonitorsCapture,
+    triggerWindowUnderCursorCapture,
   };
 }
@@ -16,6 +16,7 @@ const TOOL_SHORTCUTS: Record<string, Tool> = {
   b: 'blur',
   s: 'steps',
   p: 'pen',
+  q: 'qr',
 };
 
 interface UseEditorKeyboardShortcutsProps {
@@ -43,7 +44,7 @@ interface UseEditorKeyboardShortcutsReturn {
  * Hook for editor keyboard shortcuts.
  * Consolidates all keyboard handling from App.tsx:
  * - Command palette (Ctrl+K) - works in all views
- * - Tool shortcuts (V, C, A, L, R, E, T, H, B, S, P, G) - editor only
+ * - Tool shortcuts (V, C, A, L, R, E, T, H, B, S, P, Q, G) - editor only
  * - Undo/Redo (Ctrl+Z, Ctrl+Y, Ctrl+Shift+Z) - editor only
  * - Escape handling (deselect → select tool) - editor only
  * - Fit to center (F) - editor only
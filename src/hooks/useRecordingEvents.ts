@@ -9,6 +9,7 @@ import { useEffect, useState, useRef, useCallback } from 'react';
 import { listen, type UnlistenFn } from '@tauri-apps/api/event';
 import { invoke } from '@tauri-apps/api/core';
 import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+import { toast } from 'sonner';
 import type { RecordingState, RecordingFormat } from '../types';
 import type { ToolbarMode } from '../components/CaptureToolbar/CaptureToolbar';
 import { createErrorHandler } from '../utils/errorReporting';
@@ -24,6 +25,8 @@ interface UseRecordingEventsReturn {
   format: RecordingFormat;
   /** Elapsed recording time in seconds */
   elapsedTime: number;
+  /** Output size written so far in bytes (estimated for GIF) */
+  currentSizeBytes: number;
   /** Processing progress (0-1) */
   progress: number;
   /** Error message if in error state */
@@ -42,6 +45,7 @@ export function useRecordingEvents(): UseRecordingEventsReturn {
   const [mode, setModeState] = useState<ToolbarMode>('selection');
   const [format, setFormat] = useState<RecordingFormat>('mp4');
   const [elapsedTime, setElapsedTime] = useState(0);
+  const [currentSizeBytes, setCurrentSizeBytes] = useState(0);
   const [progress, setProgress] = useState(0);
   const [errorMessage, setErrorMessage] = useState<string | undefined>();
   const [countdownSeconds, setCountdownSeconds] = useState<number | undefined>();
@@ -120,6 +124,7 @@ export function useRecordingEvents(): UseRecordingEventsReturn {
             isRecordingActiveRef.current = false;
             setMode('starting');
             setElapsedTime(0);
+            setCurrentSizeBytes(0);
             setProgress(0);
             setErrorMessage(undefined);
             setCountdownSeconds(state.secondsRemaining);
@@ -135,6 +140,7 @@ export function useRecordingEvents(): UseRecordingEventsReturn {
             // Timer will interpolate between backend updates for smooth display
             lastBackendSyncRef.current = { backendTime: state.elapsedSecs, localTime: Date.now() };
             setElapsedTime(state.elapsedSecs);
+            setCurrentSizeBytes(state.currentSizeBytes);
             setMode('recording');
             break;
 
@@ -227,6 +233,12 @@ export function useRecordingEvents(): UseRecordingEventsReturn {
               });
             }, 3000);
             break;
+
+          case 'warning':
+            // Non-fatal (e.g. HEVC fell back to H.264) - keep the current mode
+            recordingLogger.warn(state.message);
+            toast.warning(state.message);
+            break;
         }
       });
 
@@ -269,6 +281,7 @@ export function useRecordingEvents(): UseRecordingEventsReturn {
     setMode,
     format,
     elapsedTime,
+    currentSizeBytes,
     progress,
     errorMessage,
     countdownSeconds,
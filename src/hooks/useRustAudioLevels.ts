@@ -20,6 +20,8 @@ interface UseRustAudioLevelsOptions {
   micDeviceIndex?: number | null;
   /** Whether to monitor system audio */
   monitorSystemAudio?: boolean;
+  /** Meter the mic through a noise gate at this threshold in dBFS (null = no gate) */
+  noiseGateThresholdDb?: number | null;
   /** Whether monitoring is enabled at all */
   enabled?: boolean;
 }
@@ -42,6 +44,7 @@ interface UseRustAudioLevelsResult {
 export function useRustAudioLevels({
   micDeviceIndex = null,
   monitorSystemAudio = false,
+  noiseGateThresholdDb = null,
   enabled = true,
 }: UseRustAudioLevelsOptions = {}): UseRustAudioLevelsResult {
   const [micLevel, setMicLevel] = useState(0);
@@ -82,7 +85,7 @@ export function useRustAudioLevels({
       setIsStarting(true);
       setError(null);
 
-      audioLogger.debug('Starting monitoring:', { micDeviceIndex, monitorSystemAudio });
+      audioLogger.debug('Starting monitoring:', { micDeviceIndex, monitorSystemAudio, noiseGateThresholdDb });
 
       try {
         // Set up event listener first
@@ -108,6 +111,7 @@ export function useRustAudioLevels({
         await invoke('start_audio_monitoring', {
           micDeviceIndex: micDeviceIndex,
           monitorSystemAudio: monitorSystemAudio,
+          noiseGateThresholdDb: noiseGateThresholdDb,
         });
 
         audioLogger.debug('Rust monitoring started successfully');
@@ -138,7 +142,7 @@ export function useRustAudioLevels({
         unlistenRef.current = null;
       }
     };
-  }, [enabled, micDeviceIndex, monitorSystemAudio]);
+  }, [enabled, micDeviceIndex, monitorSystemAudio, noiseGateThresholdDb]);
 
   return {
     micLevel,
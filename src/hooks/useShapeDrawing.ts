@@ -5,6 +5,10 @@ import type { EditorHistoryActions } from './useEditorHistory';
 
 const MIN_SHAPE_SIZE = 5;
 
+// Size and placeholder data of a newly placed QR code
+const DEFAULT_QR_SIZE = 120;
+const DEFAULT_QR_DATA = 'https://';
+
 // Tools that stay in draw mode after completing a shape
 const TOOLS_RETAIN_MODE: Set<Tool> = new Set(['pen', 'steps']);
 
@@ -233,6 +237,24 @@ export const useShapeDrawing = ({
         return true;
       }
 
+      // QR codes are placed at a fixed size, then resized and given data
+      if (selectedTool === 'qr') {
+        const id = `shape_${Date.now()}`;
+        const newShape: CanvasShape = {
+          id,
+          type: 'qr',
+          x: pos.x,
+          y: pos.y,
+          width: DEFAULT_QR_SIZE,
+          height: DEFAULT_QR_SIZE,
+          text: DEFAULT_QR_DATA,
+        };
+        recordAction(() => onShapesChange([...shapes, newShape]));
+        setSelectedIds([id]);
+        onToolChange('select');
+        return true;
+      }
+
       // Crop tool is handled elsewhere
       if (selectedTool === 'crop') return false;
 
@@ -253,6 +275,7 @@ export const useShapeDrawing = ({
       stageRef,
       getCanvasPosition,
       setSelectedIds,
+      onToolChange,
     ]
   );
 
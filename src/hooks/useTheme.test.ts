@@ -18,6 +18,16 @@ describe('useTheme', () => {
           jpgQuality: 85,
           allowOverride: false,
           theme: 'light',
+          animatedThumbnails: false,
+          filenameTemplate: '',
+          s3Upload: {
+            endpoint: '',
+            region: '',
+            bucket: '',
+            accessKeyId: '',
+            secretAccessKey: '',
+            publicUrl: '',
+          },
         },
       },
     });
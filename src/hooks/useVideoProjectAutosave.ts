@@ -0,0 +1,38 @@
+/**
+ * Video Project Autosave Hook
+ *
+ * Sends every edit of the open video project to the backend autosave, which
+ * writes it to a `.autosave.snapit` sidecar once edits pause, so an editor
+ * crash doesn't lose unsaved work. The project as loaded isn't autosaved:
+ * opening a project without editing it leaves nothing to recover.
+ */
+
+import { useEffect, useRef } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { useVideoEditorStore, sanitizeProjectForSave } from '../stores/videoEditor';
+import { videoEditorLogger } from '../utils/logger';
+
+/**
+ * Hook that autosaves the video editor's project on every change.
+ */
+export function useVideoProjectAutosave() {
+  const project = useVideoEditorStore((state) => state.project);
+  const loadedProjectIdRef = useRef<string | null>(null);
+
+  useEffect(() => {
+    if (!project) {
+      loadedProjectIdRef.current = null;
+      return;
+    }
+
+    // The first state of each project is the one just loaded
+    if (loadedProjectIdRef.current !== project.id) {
+      loadedProjectIdRef.current = project.id;
+      return;
+    }
+
+    invoke('autosave_project', { project: sanitizeProjectForSave(project) }).catch((error) =>
+      videoEditorLogger.warn('Failed to autosave project:', error)
+    );
+  }, [project]);
+}
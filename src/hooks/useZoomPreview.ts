@@ -13,7 +13,7 @@
  */
 
 import { useMemo } from 'react';
-import type { ZoomRegion, CursorRecording } from '../types';
+import type { ZoomRegion, CursorRecording, EasingFunction } from '../types';
 import { useCursorInterpolation, type InterpolatedCursor } from './useCursorInterpolation';
 
 /** Fixed zoom transition duration in seconds (matches Cap) */
@@ -96,6 +96,54 @@ function easeOut(t: number): number {
   return easeOutCurve(t);
 }
 
+/** Back-easing overshoot constant (matches Rust's `EasingFunction::apply`). */
+const BACK_OVERSHOOT = 1.70158;
+
+/**
+ * Map linear progress (0-1) to eased progress. Mirrors `EasingFunction::apply`
+ * in Rust so the preview matches export. Overshooting curves may leave 0-1.
+ */
+function applyEasing(easing: EasingFunction, t: number): number {
+  t = clamp01(t);
+  if (typeof easing === 'object') {
+    const [x1, y1, x2, y2] = easing.cubicBezier;
+    return cubicBezier(clamp01(x1), y1, clamp01(x2), y2)(t);
+  }
+  switch (easing) {
+    case 'linear':
+      return t;
+    case 'easeIn':
+      return t * t;
+    case 'easeOut':
+      return 1 - (1 - t) ** 2;
+    case 'easeInOut':
+      return t < 0.5 ? 2 * t * t : 1 - (-2 * t + 2) ** 2 / 2;
+    case 'smooth':
+      return t * t * (3 - 2 * t);
+    case 'snappy':
+      return 1 - (1 - t) ** 3;
+    case 'easeInOutCubic':
+      return t < 0.5 ? 4 * t * t * t : 1 - (-2 * t + 2) ** 3 / 2;
+    case 'bouncy':
+    case 'easeOutBack': {
+      const c3 = BACK_OVERSHOOT + 1;
+      return 1 + c3 * (t - 1) ** 3 + BACK_OVERSHOOT * (t - 1) ** 2;
+    }
+  }
+}
+
+/**
+ * Ease a zoom transition with the region's easing. The default `easeInOut`
+ * keeps Cap's separate zoom-in/zoom-out curves.
+ */
+function easeTransition(region: ZoomRegion, t: number, zoomingIn: boolean): number {
+  const easing = region.transition?.easing ?? 'easeInOut';
+  if (easing === 'easeInOut') {
+    return zoomingIn ? easeIn(t) : easeOut(t);
+  }
+  return applyEasing(easing, t);
+}
+
 // ============================================================================
 // Bounds-based Zoom (Cap's approach)
 // ============================================================================
@@ -160,6 +208,20 @@ function clamp01(v: number): number {
   return Math.max(0, Math.min(1, v));
 }
 
+/**
+ * Keep the viewport covering the whole frame (at least 1x, no edge inside the
+ * frame). Overshooting easings can otherwise do both.
+ */
+function clampBoundsToFrame(bounds: SegmentBounds): SegmentBounds {
+  const amount = Math.max(1, boundsWidth(bounds));
+  const clampEdge = (v: number) => Math.min(0, Math.max(1 - amount, v));
+  const topLeft = { x: clampEdge(bounds.topLeft.x), y: clampEdge(bounds.topLeft.y) };
+  return {
+    topLeft,
+    bottomRight: { x: topLeft.x + amount, y: topLeft.y + amount },
+  };
+}
+
 // ============================================================================
 // Segments Cursor (tracks position in zoom timeline)
 // ============================================================================
@@ -214,6 +276,14 @@ interface InterpolatedZoom {
 function interpolateZoom(
   cursor: SegmentsCursor,
   cursorPos: XY | null
+): InterpolatedZoom {
+  const zoom = interpolateZoomUnclamped(cursor, cursorPos);
+  return { t: clamp01(zoom.t), bounds: clampBoundsToFrame(zoom.bounds) };
+}
+
+function interpolateZoomUnclamped(
+  cursor: SegmentsCursor,
+  cursorPos: XY | null
 ): InterpolatedZoom {
   const defaultB = defaultBounds();
   const { prevSegment, segment, timeS, segments } = cursor;
@@ -221,7 +291,7 @@ function interpolateZoom(
   // Case 1: After a segment, zooming out
   if (prevSegment && !segment) {
     const prevEndS = prevSegment.endMs / 1000;
-    const zoomT = easeOut(clamp01((timeS - prevEndS) / ZOOM_DURATION_S));
+    const zoomT = easeTransition(prevSegment, clamp01((timeS - prevEndS) / ZOOM_DURATION_S), false);
     const prevBounds = boundsFromRegion(prevSegment, cursorPos);
 
     return {
@@ -233,7 +303,7 @@ function interpolateZoom(
   // Case 2: In first segment, zooming in
   if (!prevSegment && segment) {
     const startS = segment.startMs / 1000;
-    const t = easeIn(clamp01((timeS - startS) / ZOOM_DURATION_S));
+    const t = easeTransition(segment, clamp01((timeS - startS) / ZOOM_DURATION_S), true);
     const segmentBounds = boundsFromRegion(segment, cursorPos);
 
     return {
@@ -249,7 +319,7 @@ function interpolateZoom(
     const segmentStartS = segment.startMs / 1000;
     const prevEndS = prevSegment.endMs / 1000;
 
-    const zoomT = easeIn(clamp01((timeS - segmentStartS) / ZOOM_DURATION_S));
+    const zoomT = easeTransition(segment, clamp01((timeS - segmentStartS) / ZOOM_DURATION_S), true);
 
     // No gap: direct transition between segments
     if (Math.abs(segment.startMs - prevSegment.endMs) < 10) {
@@ -45,6 +45,15 @@ const DEFAULT_VIDEO_SETTINGS: VideoSettings = {
   countdownSecs: 3,
   hideDesktopIcons: false,
   quickCapture: false, // Default to editor flow
+  codec: 'h264',
+  rateControl: { mode: 'cbr' },
+  keyframeIntervalSecs: 2,
+  segmentDurationSecs: null,
+  microphoneGainDb: 0,
+  noiseGate: false,
+  noiseGateThresholdDb: -45,
+  micChannelMode: 'monoMix',
+  captureKeystrokes: false,
 };
 
 const DEFAULT_GIF_SETTINGS: GifSettings = {
@@ -22,6 +22,8 @@ function createTestCapture(overrides: Partial<CaptureListItem> = {}): CaptureLis
     tags: [],
     favorite: false,
     is_missing: false,
+    duration_ms: null,
+    name: null,
     ...overrides,
   };
 }
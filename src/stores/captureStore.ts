@@ -157,6 +157,7 @@ interface CaptureState {
   updateAnnotations: (annotations: Annotation[]) => Promise<void>;
   toggleFavorite: (id: string) => Promise<void>;
   updateTags: (id: string, tags: string[]) => Promise<void>;
+  updateName: (id: string, name: string | null) => Promise<void>;
   bulkAddTags: (ids: string[], tagsToAdd: string[]) => Promise<void>;
   deleteCapture: (id: string) => Promise<void>;
   deleteCaptures: (ids: string[]) => Promise<void>;
@@ -200,6 +201,8 @@ function createPlaceholderCapture(
     tags: [],
     favorite: false,
     is_missing: false,
+    duration_ms: null,
+    name: null,
   };
 }
 
@@ -217,6 +220,8 @@ function createCaptureFromResponse(result: SaveCaptureResponse): CaptureListItem
     tags: result.project.tags,
     favorite: result.project.favorite,
     is_missing: false,
+    duration_ms: null,
+    name: result.project.name ?? null,
   };
 }
 
@@ -539,6 +544,32 @@ export const useCaptureStore = create<CaptureState>()(
     }
   },
 
+  updateName: async (id: string, name: string | null) => {
+    const captures = get().captures;
+    const captureIndex = captures.findIndex((c) => c.id === id);
+    if (captureIndex === -1) return;
+
+    const capture = captures[captureIndex];
+    const trimmed = name?.trim() || null;
+
+    // Optimistically update local state first
+    const updatedCaptures = [...captures];
+    updatedCaptures[captureIndex] = { ...capture, name: trimmed };
+    set({ captures: updatedCaptures });
+
+    try {
+      await invoke('update_project_name', {
+        projectId: id,
+        name: trimmed,
+      });
+      // Update cache on success
+      saveToCache(updatedCaptures);
+    } catch (error) {
+      // Revert on error
+      set({ captures, error: String(error) });
+    }
+  },
+
   bulkAddTags: async (ids: string[], tagsToAdd: string[]) => {
     if (ids.length === 0 || tagsToAdd.length === 0) return;
 
@@ -727,6 +758,9 @@ export const useFilteredCaptures = () => {
         const matchesType = capture.capture_type.toLowerCase().includes(queryLower);
         if (matchesType) return true;
 
+        const matchesName = capture.name?.toLowerCase().includes(queryLower) ?? false;
+        if (matchesName) return true;
+
         const matchesTags = capture.tags.some((tag) =>
           tag.toLowerCase().includes(queryLower)
         );
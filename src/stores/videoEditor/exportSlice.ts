@@ -1,5 +1,5 @@
 import { invoke } from '@tauri-apps/api/core';
-import type { SliceCreator, ExportProgress, ExportResult, ExportConfig, AutoZoomConfig, VideoProject } from './types';
+import type { SliceCreator, ExportProgress, ExportResult, ExportConfig, ExportFormat, AutoZoomConfig, VideoProject } from './types';
 import { videoEditorLogger } from '../../utils/logger';
 import { sanitizeProjectForSave } from './projectSlice';
 
@@ -57,10 +57,13 @@ export const createExportSlice: SliceCreator<ExportSlice> = (set, get) => ({
 
     // Infer format from file extension to ensure consistency
     const ext = outputPath.split('.').pop()?.toLowerCase();
-    const formatMap: Record<string, 'mp4' | 'webm' | 'gif'> = {
+    // A .png path names the directory the frame sequence is written to
+    const formatMap: Record<string, ExportFormat> = {
       mp4: 'mp4',
       webm: 'webm',
       gif: 'gif',
+      png: 'pngSequence',
+      mov: 'mov',
     };
     const selectedFormat = formatMap[ext ?? 'mp4'] ?? 'mp4';
 
@@ -18,7 +18,8 @@ export interface GPUEditorSlice {
   renderFrame: (timestampMs: number) => Promise<RenderedFrame | null>;
   gpuPlay: () => Promise<void>;
   gpuPause: () => Promise<void>;
-  gpuSeek: (timestampMs: number) => Promise<void>;
+  gpuSeek: (timestampMs: number, frameExact?: boolean) => Promise<void>;
+  gpuStepFrame: (delta: number) => Promise<void>;
 }
 
 export const createGPUEditorSlice: SliceCreator<GPUEditorSlice> = (set, get) => ({
@@ -116,14 +117,34 @@ export const createGPUEditorSlice: SliceCreator<GPUEditorSlice> = (set, get) =>
     set({ isPlaying: false });
   },
 
-  gpuSeek: async (timestampMs) => {
+  gpuSeek: async (timestampMs, frameExact = false) => {
     const { editorInstanceId, project } = get();
     if (!editorInstanceId || !project) return;
 
     // Clamp to valid range
     const clampedTime = Math.max(0, Math.min(timestampMs, project.timeline.durationMs));
 
-    await invoke('editor_seek', { instanceId: editorInstanceId, timestampMs: clampedTime });
+    // Frame-exact seeks are slower; use them when paused, not for live scrubbing
+    await invoke('editor_seek', {
+      instanceId: editorInstanceId,
+      timestampMs: clampedTime,
+      frameExact,
+    });
     set({ currentTimeMs: clampedTime });
   },
+
+  gpuStepFrame: async (delta) => {
+    const { editorInstanceId } = get();
+    if (!editorInstanceId) return;
+
+    try {
+      const timestampMs = await invoke<number>('editor_step_frame', {
+        instanceId: editorInstanceId,
+        delta,
+      });
+      set({ currentTimeMs: timestampMs, isPlaying: false });
+    } catch (error) {
+      videoEditorLogger.error('Failed to step frame:', error);
+    }
+  },
 });
@@ -22,6 +22,9 @@ function createTestProject(overrides: Partial<VideoProject> = {}): VideoProject
       inPoint: 0,
       outPoint: 10000,
       speed: 1.0,
+      segments: [],
+      speedKeyframes: [],
+      holds: [],
     },
     zoom: {
       enabled: false,
@@ -71,6 +74,18 @@ function createTestProject(overrides: Partial<VideoProject> = {}): VideoProject
     mask: {
       segments: [],
     },
+    cursorSmoothingSegments: [],
+    keystrokes: {
+      enabled: false,
+      position: 'bottomRight',
+      margin: 32,
+      fontSize: 28,
+      displayMs: 1500,
+      maxKeys: 4,
+      textColor: '#ffffff',
+      backgroundColor: '#000000',
+      backgroundOpacity: 0.8,
+    },
     ...overrides,
   };
 }
@@ -167,6 +182,9 @@ describe('playbackSlice', () => {
           inPoint: 0,
           outPoint: 10000,
           speed: 1.0,
+          segments: [],
+          speedKeyframes: [],
+          holds: [],
         },
       });
       useVideoEditorStore.setState({ project });
@@ -182,6 +200,9 @@ describe('playbackSlice', () => {
           inPoint: 0,
           outPoint: 5000,
           speed: 1.0,
+          segments: [],
+          speedKeyframes: [],
+          holds: [],
         },
       });
       useVideoEditorStore.setState({ project });
@@ -248,6 +269,9 @@ describe('playbackSlice', () => {
           inPoint: 0,
           outPoint: 5000,
           speed: 1.0,
+          segments: [],
+          speedKeyframes: [],
+          holds: [],
         },
       });
       useVideoEditorStore.setState({ project: shortProject });
@@ -262,6 +286,9 @@ describe('playbackSlice', () => {
           inPoint: 0,
           outPoint: 20000,
           speed: 1.0,
+          segments: [],
+          speedKeyframes: [],
+          holds: [],
         },
       });
       useVideoEditorStore.setState({ project: longProject });
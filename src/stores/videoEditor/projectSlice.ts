@@ -20,6 +20,19 @@ export function sanitizeProjectForSave(project: VideoProject): VideoProject {
       durationMs: Math.round(project.timeline.durationMs),
       inPoint: Math.round(project.timeline.inPoint),
       outPoint: Math.round(project.timeline.outPoint),
+      segments: (project.timeline.segments ?? []).map((segment) => ({
+        startMs: Math.round(segment.startMs),
+        endMs: Math.round(segment.endMs),
+      })),
+      speedKeyframes: (project.timeline.speedKeyframes ?? []).map((keyframe) => ({
+        ...keyframe,
+        timeMs: Math.round(keyframe.timeMs),
+      })),
+      holds: (project.timeline.holds ?? []).map((hold) => ({
+        ...hold,
+        atSourceMs: Math.round(hold.atSourceMs),
+        durationMs: Math.round(hold.durationMs),
+      })),
     },
     zoom: {
       ...project.zoom,
@@ -53,6 +66,11 @@ export function sanitizeProjectForSave(project: VideoProject): VideoProject {
         endMs: Math.round(segment.endMs),
       })),
     },
+    cursorSmoothingSegments: (project.cursorSmoothingSegments ?? []).map((segment) => ({
+      ...segment,
+      startMs: Math.round(segment.startMs),
+      endMs: Math.round(segment.endMs),
+    })),
     // Note: text.segments uses start/end in seconds (f32), not ms
   };
 }
@@ -22,6 +22,9 @@ function createTestProject(overrides: Partial<VideoProject> = {}): VideoProject
       inPoint: 0,
       outPoint: 60000,
       speed: 1.0,
+      segments: [],
+      speedKeyframes: [],
+      holds: [],
     },
     zoom: {
       enabled: false,
@@ -71,6 +74,18 @@ function createTestProject(overrides: Partial<VideoProject> = {}): VideoProject
     mask: {
       segments: [],
     },
+    cursorSmoothingSegments: [],
+    keystrokes: {
+      enabled: false,
+      position: 'bottomRight',
+      margin: 32,
+      fontSize: 28,
+      displayMs: 1500,
+      maxKeys: 4,
+      textColor: '#ffffff',
+      backgroundColor: '#000000',
+      backgroundOpacity: 0.8,
+    },
     ...overrides,
   };
 }
@@ -244,6 +259,9 @@ describe('timelineSlice', () => {
           inPoint: 0,
           outPoint: 60000,
           speed: 1.0,
+          segments: [],
+          speedKeyframes: [],
+          holds: [],
         },
       });
       useVideoEditorStore.setState({
@@ -12,6 +12,7 @@ import type {
   RenderedFrame,
   WebcamConfig,
   ExportConfig,
+  ExportFormat,
   CursorConfig,
   SceneSegment,
   TextSegment,
@@ -33,6 +34,7 @@ export type {
   RenderedFrame,
   WebcamConfig,
   ExportConfig,
+  ExportFormat,
   CursorConfig,
   SceneSegment,
   TextSegment,
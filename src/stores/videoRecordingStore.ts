@@ -65,11 +65,19 @@ const defaultSettings: RecordingSettings = {
   audio: {
     captureSystemAudio: true,
     microphoneDeviceIndex: null,
+    noiseGate: false,
+    noiseGateThresholdDb: -45,
+    micChannelMode: 'monoMix',
   },
   quality: 80,
   gifQualityPreset: 'balanced',
   countdownSecs: 3,
   quickCapture: false,
+  videoCodec: 'h264',
+  rateControl: { mode: 'cbr' },
+  keyframeIntervalSecs: 2,
+  segmentDurationSecs: null,
+  captureKeystrokes: false,
 };
 
 // Event listener cleanup function
@@ -91,6 +99,8 @@ export const useVideoRecordingStore = create<VideoRecordingStore>((set, get) =>
     // Set up event listener for recording state changes
     unlistenFn = await listen<RecordingState>('recording-state-changed', (event) => {
       const newState = event.payload;
+      // Warnings don't change the recording state
+      if (newState.status === 'warning') return;
       set({ recordingState: newState });
       
       // Auto-reset to idle after terminal states (completed/error) so user can start a new recording
@@ -287,20 +297,15 @@ export const useVideoRecordingStore = create<VideoRecordingStore>((set, get) =>
     }
   },
 
-  // Pause recording (MP4 only)
+  // Pause recording
   pauseRecording: async () => {
-    const { recordingState, settings } = get();
+    const { recordingState } = get();
 
     if (recordingState.status !== 'recording') {
       recordingLogger.warn('Cannot pause: not recording');
       return;
     }
 
-    if (settings.format === 'gif') {
-      recordingLogger.warn('Cannot pause GIF recording');
-      return;
-    }
-
     try {
       await invoke('pause_recording');
     } catch (error) {
@@ -36,6 +36,9 @@ function createTestProject(overrides: Partial<VideoProject> = {}): VideoProject
       inPoint: 0,
       outPoint: durationMs,
       speed: 1.0,
+      segments: [],
+      speedKeyframes: [],
+      holds: [],
     },
     zoom: {
       mode: 'manual',
@@ -84,8 +87,11 @@ function createTestProject(overrides: Partial<VideoProject> = {}): VideoProject
         enabled: false,
         width: 2,
         color: '#ffffff',
+        gradientAngle: 135,
       },
       visibilitySegments: [],
+      positionKeyframes: [],
+      instances: [],
     },
     audio: {
       systemVolume: 1.0,
@@ -94,9 +100,13 @@ function createTestProject(overrides: Partial<VideoProject> = {}): VideoProject
       musicFadeInSecs: 2.0,
       musicFadeOutSecs: 2.0,
       normalizeOutput: true,
+      loudnessTargetLufs: -16,
       systemMuted: false,
       microphoneMuted: false,
       musicMuted: false,
+      micOffsetMs: 0,
+      systemOffsetMs: 0,
+      clickSoundVolume: 0.5,
     },
     export: {
       preset: 'social',
@@ -144,6 +154,18 @@ function createTestProject(overrides: Partial<VideoProject> = {}): VideoProject
     mask: {
       segments: [],
     },
+    cursorSmoothingSegments: [],
+    keystrokes: {
+      enabled: false,
+      position: 'bottomRight',
+      margin: 32,
+      fontSize: 28,
+      displayMs: 1500,
+      maxKeys: 4,
+      textColor: '#ffffff',
+      backgroundColor: '#000000',
+      backgroundOpacity: 0.8,
+    },
     ...overrides,
   };
 }
@@ -168,6 +190,7 @@ describe('Video Export Flow Integration', () => {
         durationSecs: 10.5,
         fileSizeBytes: 5242880, // 5MB
         format: 'mp4',
+        encoder: 'libx264',
       };
 
       setInvokeResponse('export_video', mockExportResult);
@@ -258,6 +281,7 @@ describe('Video Export Flow Integration', () => {
           durationSecs: 5,
           fileSizeBytes: 1000000,
           format,
+          encoder: null,
         };
 
         setInvokeResponse('export_video', mockResult);
@@ -328,6 +352,7 @@ describe('Video Export Flow Integration', () => {
         durationSecs: 10,
         fileSizeBytes: 5000000,
         format: 'mp4',
+        encoder: 'libx264',
       };
 
       setInvokeResponse('export_video', mockResult);
@@ -544,6 +569,9 @@ describe('Video Export Flow Integration', () => {
         transitionOutMs: 300,
         easing: 'ease-in',
         leftClicksOnly: true,
+        mergeWindowMs: 1000,
+        mergeRadius: 0.1,
+        minClickSpacingMs: 400,
       };
 
       const updatedProject: VideoProject = {
@@ -667,6 +695,9 @@ describe('Video Export with Timeline Trim', () => {
         inPoint: 10000, // Start at 10s
         outPoint: 30000, // End at 30s
         speed: 1.0,
+        segments: [],
+        speedKeyframes: [],
+        holds: [],
       },
     });
 
@@ -675,6 +706,7 @@ describe('Video Export with Timeline Trim', () => {
       durationSecs: 20, // 20 seconds (30s - 10s)
       fileSizeBytes: 10000000,
       format: 'mp4',
+      encoder: 'libx264',
     };
 
     setInvokeResponse('export_video', mockResult);
@@ -718,6 +750,7 @@ describe('Video Export with Zoom Regions', () => {
       durationSecs: 10,
       fileSizeBytes: 8000000,
       format: 'mp4',
+      encoder: 'libx264',
     };
 
     setInvokeResponse('export_video', mockResult);
@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MicChannelMode } from "./MicChannelMode";
 
 /**
  * Audio capture settings.
@@ -11,4 +12,16 @@ captureSystemAudio: boolean,
 /**
  * Selected microphone device index. None = no microphone.
  */
-microphoneDeviceIndex: number | null, };
+microphoneDeviceIndex: number | null, 
+/**
+ * Silence the microphone while its level is below `noise_gate_threshold_db`.
+ */
+noiseGate: boolean, 
+/**
+ * RMS level (dBFS) the microphone must reach to open the noise gate.
+ */
+noiseGateThresholdDb: number, 
+/**
+ * How microphone channels are mixed before writing the track.
+ */
+micChannelMode: MicChannelMode, };
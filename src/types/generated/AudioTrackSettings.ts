@@ -25,9 +25,13 @@ musicFadeInSecs: number,
  */
 musicFadeOutSecs: number, 
 /**
- * Normalize output audio to -16 LUFS.
+ * Normalize output audio to `loudness_target_lufs`.
  */
 normalizeOutput: boolean, 
+/**
+ * Integrated loudness target for normalization (e.g. -14 streaming, -16 podcasts).
+ */
+loudnessTargetLufs: number, 
 /**
  * Mute system audio track.
  */
@@ -39,4 +43,16 @@ microphoneMuted: boolean,
 /**
  * Mute background music.
  */
-musicMuted: boolean, };
+musicMuted: boolean, 
+/**
+ * Microphone track offset in ms. Positive delays the track, negative advances it.
+ */
+micOffsetMs: number, 
+/**
+ * System audio track offset in ms. Positive delays the track, negative advances it.
+ */
+systemOffsetMs: number, 
+/**
+ * Click sound volume (0.0 - 1.0).
+ */
+clickSoundVolume: number, };
@@ -32,4 +32,17 @@ easing: EasingFunction,
 /**
  * Only include left clicks (ignore right/middle clicks).
  */
-leftClicksOnly: boolean, };
+leftClicksOnly: boolean, 
+/**
+ * Clicks within this long of the previous click in the same spot are
+ * merged into one zoom centered on their centroid (ms).
+ */
+mergeWindowMs: number, 
+/**
+ * How close (normalized, 0-1) a click must be to the group's centroid to merge.
+ */
+mergeRadius: number, 
+/**
+ * Clicks elsewhere sooner than this after the previous click are skipped (ms).
+ */
+minClickSpacingMs: number, };
@@ -9,7 +9,7 @@ import type { CornerStyle } from "./CornerStyle";
  */
 export type BackgroundConfig = {
   /**
-   * Type of background (Solid, Gradient, Wallpaper, Image).
+   * Type of background (Solid, Gradient, Wallpaper, Image, BlurredSource).
    */
   bgType: BackgroundType;
   /**
@@ -37,9 +37,13 @@ export type BackgroundConfig = {
    */
   imagePath: string | null;
   /**
-   * Background blur amount (0-100%).
+   * Background blur amount (0-100%), used by the blurred source background.
    */
   blur: number;
+  /**
+   * Blur applied to the custom image background (0-100%).
+   */
+  imageBlurAmount: number;
   /**
    * Padding around video frame (0-200 pixels).
    */
@@ -49,7 +53,7 @@ export type BackgroundConfig = {
    */
   inset: number;
   /**
-   * Corner rounding radius (0-100 pixels).
+   * Corner rounding radius of the screen content (0-100 pixels).
    */
   rounding: number;
   /**
@@ -57,7 +61,7 @@ export type BackgroundConfig = {
    */
   roundingType: CornerStyle;
   /**
-   * Shadow configuration.
+   * Drop shadow behind the screen content.
    */
   shadow: BackgroundShadowConfig;
   /**
@@ -8,4 +8,13 @@ export type CaptureListItem = { id: string, created_at: string, updated_at: stri
 /**
  * True if the original image file is missing from disk
  */
-is_missing: boolean, };
+is_missing: boolean, 
+/**
+ * Duration of video and GIF captures in milliseconds.
+ * None for images, and for media whose metadata hasn't been probed yet.
+ */
+duration_ms: number | null, 
+/**
+ * User-chosen title (video projects always have one).
+ */
+name: string | null, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Chroma key settings for removing a solid-color webcam background.
+ */
+export type ChromaKeyConfig = { 
+/**
+ * Key color (hex format, e.g., "#00FF00").
+ */
+keyColor: string, 
+/**
+ * Chroma distance from the key color that is fully removed (0-1).
+ */
+similarity: number, 
+/**
+ * Width of the partially transparent band beyond `similarity` (0-1).
+ */
+smoothness: number, };
@@ -24,4 +24,9 @@ durationMs: number,
 /**
  * Highlight style.
  */
-style: ClickHighlightStyle, };
+style: ClickHighlightStyle, 
+/**
+ * Short WAV file played at every click in exports, or None for silent clicks.
+ * Independent of `enabled`: the sound plays even with the highlight hidden.
+ */
+clickSound: string | null, };
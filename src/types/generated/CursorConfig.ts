@@ -1,6 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { ClickHighlightConfig } from "./ClickHighlightConfig";
 import type { CursorAnimationStyle } from "./CursorAnimationStyle";
+import type { CursorTrailConfig } from "./CursorTrailConfig";
 import type { CursorType } from "./CursorType";
 
 /**
@@ -47,6 +48,10 @@ motionBlur: number,
  * Click highlight settings.
  */
 clickHighlight: ClickHighlightConfig, 
+/**
+ * Fading trail behind the cursor, or None for no trail.
+ */
+cursorTrail: CursorTrailConfig | null, 
 /**
  * Hide cursor when idle.
  */
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Cursor spring constants for a time range, overriding the ones in [`CursorConfig`].
+ */
+export type CursorSmoothingSegment = { 
+/**
+ * Start time in milliseconds.
+ */
+startMs: number, 
+/**
+ * End time in milliseconds.
+ */
+endMs: number, 
+/**
+ * Spring tension (higher = snappier).
+ */
+tension: number, 
+/**
+ * Mass (higher = more momentum).
+ */
+mass: number, 
+/**
+ * Friction (higher = more damping).
+ */
+friction: number, };
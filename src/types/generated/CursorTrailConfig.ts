@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Fading motion trail drawn behind the cursor.
+ */
+export type CursorTrailConfig = { 
+/**
+ * How far back the trail reaches (ms).
+ */
+lengthMs: number, 
+/**
+ * Trail color (hex format, e.g., "#ffffff").
+ */
+color: string, 
+/**
+ * Opacity at the cursor (0-1); the trail fades to transparent at its tail.
+ */
+opacity: number, 
+/**
+ * Fade curve exponent (1.0 = linear, higher = fades out sooner).
+ */
+falloff: number, 
+/**
+ * Line width in pixels (at 720p reference).
+ */
+width: number, };
@@ -3,4 +3,4 @@
 /**
  * Easing function for animations.
  */
-export type EasingFunction = "linear" | "easeIn" | "easeOut" | "easeInOut" | "smooth" | "snappy" | "bouncy";
+export type EasingFunction = "linear" | "easeIn" | "easeOut" | "easeInOut" | "smooth" | "snappy" | "bouncy" | "easeInOutCubic" | "easeOutBack" | { "cubicBezier": [number, number, number, number] };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Hardware video encoders usable on this machine.
+ */
+export type EncoderCapabilities = { 
+/**
+ * NVIDIA NVENC (h264_nvenc).
+ */
+nvenc: boolean, 
+/**
+ * Intel Quick Sync (h264_qsv).
+ */
+qsv: boolean, 
+/**
+ * AMD AMF (h264_amf).
+ */
+amf: boolean, 
+/**
+ * FFmpeg encoder exports use when hardware encoding is preferred.
+ */
+exportEncoder: string, };
@@ -6,6 +6,9 @@ import type { CropConfig } from "./CropConfig";
 import type { ExportFormat } from "./ExportFormat";
 import type { ExportPreset } from "./ExportPreset";
 import type { ExportResolution } from "./ExportResolution";
+import type { GifQualityPreset } from "./GifQualityPreset";
+import type { PortraitFraming } from "./PortraitFraming";
+import type { TimecodeConfig } from "./TimecodeConfig";
 
 /**
  * Export settings for the final video.
@@ -47,7 +50,29 @@ crop: CropConfig,
  * Composition configuration (output canvas size/aspect).
  */
 composition: CompositionConfig,
+/**
+ * How screen content fills a composition taller than it (e.g. 9:16).
+ */
+portraitFraming: PortraitFraming,
+/**
+ * Palette quality used when exporting to GIF.
+ */
+gifQualityPreset: GifQualityPreset,
+/**
+ * Leave the background transparent instead of drawing it (alpha-capable
+ * formats only: MOV/ProRes 4444 and PNG sequences).
+ */
+transparentBackground: boolean,
+/**
+ * Encode in chunks next to the output so a failed export of the same
+ * project resumes where it stopped (MP4, WebM and MOV).
+ */
+resumable: boolean,
 /**
  * Prefer hardware encoding (NVENC) when available.
  */
-preferHardwareEncoding?: boolean, };
+preferHardwareEncoding?: boolean,
+/**
+ * Burned-in timecode/clock overlay. Off when `None`.
+ */
+timecode?: TimecodeConfig, };
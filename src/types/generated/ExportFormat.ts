@@ -3,4 +3,4 @@
 /**
  * Export format.
  */
-export type ExportFormat = "mp4" | "webm" | "gif";
+export type ExportFormat = "mp4" | "webm" | "gif" | "pngSequence" | "mov";
@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExportFormat } from "./ExportFormat";
+import type { ExportPreset } from "./ExportPreset";
+import type { ExportResolution } from "./ExportResolution";
+
+/**
+ * Export settings applied on top of each project's saved `ExportConfig`
+ * when re-exporting several projects at once. `None` keeps the project's value.
+ */
+export type ExportOverrides = { 
+/**
+ * Export preset.
+ */
+preset: ExportPreset | null, 
+/**
+ * Output format.
+ */
+format: ExportFormat | null, 
+/**
+ * Output resolution.
+ */
+resolution: ExportResolution | null, 
+/**
+ * Quality (1-100).
+ */
+quality: number | null, 
+/**
+ * Frames per second.
+ */
+fps: number | null, 
+/**
+ * Prefer hardware encoding when available.
+ */
+preferHardwareEncoding: boolean | null, };
@@ -16,4 +16,12 @@ stage: ExportStage,
 /**
  * Human-readable status message.
  */
-message: string, };
+message: string, 
+/**
+ * Render throughput in frames per second (while rendering frames).
+ */
+fps?: number, 
+/**
+ * Estimated seconds until rendering finishes (while rendering frames).
+ */
+etaSecs?: number, };
@@ -20,4 +20,8 @@ fileSizeBytes: number,
 /**
  * Output format.
  */
-format: ExportFormat, };
+format: ExportFormat, 
+/**
+ * FFmpeg video encoder used (e.g. `h264_nvenc`), `None` for PNG sequences.
+ */
+encoder: string | null, };
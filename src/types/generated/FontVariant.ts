@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A weight/style a font family is installed in.
+ */
+export type FontVariant = { 
+/**
+ * CSS weight, rounded to the nearest 100.
+ */
+weight: number, italic: boolean, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Soft glow drawn outside the webcam border.
+ */
+export type GlowConfig = { 
+/**
+ * Glow color (CSS color string).
+ */
+color: string, 
+/**
+ * Glow radius in pixels.
+ */
+radius: number, 
+/**
+ * Glow opacity (0-1).
+ */
+opacity: number, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HoldAudio } from "./HoldAudio";
+
+/**
+ * Freeze-frame on the source timeline.
+ */
+export type HoldSegment = { 
+/**
+ * Source time of the frame to hold, in ms.
+ */
+atSourceMs: number, 
+/**
+ * How long the frame is held, in ms of source time.
+ */
+durationMs: number, 
+/**
+ * What the audio does during the hold.
+ */
+audio: HoldAudio, };
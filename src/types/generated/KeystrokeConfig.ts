@@ -0,0 +1,46 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { KeystrokePosition } from "./KeystrokePosition";
+
+/**
+ * Keystroke overlay rendered into exported frames.
+ *
+ * Shows a keycap for each recent key press recorded in the `keystroke.json`
+ * sidecar; modifier combos share one cap (e.g. "Ctrl+C").
+ */
+export type KeystrokeConfig = { 
+/**
+ * Whether keystrokes are drawn in the export.
+ */
+enabled: boolean, 
+/**
+ * Frame corner the keycaps are anchored to.
+ */
+position: KeystrokePosition, 
+/**
+ * Distance from the corner in pixels (at 1080p reference).
+ */
+margin: number, 
+/**
+ * Font size in pixels (at 1080p reference).
+ */
+fontSize: number, 
+/**
+ * How long a key press stays on screen in milliseconds.
+ */
+displayMs: number, 
+/**
+ * Maximum number of keycaps shown at once.
+ */
+maxKeys: number, 
+/**
+ * Text color (hex format, e.g., "#ffffff").
+ */
+textColor: string, 
+/**
+ * Keycap background color (hex format, e.g., "#000000").
+ */
+backgroundColor: string, 
+/**
+ * Keycap background opacity (0.0-1.0).
+ */
+backgroundOpacity: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of `repair_library`.
+ */
+export type LibraryRepairResult = { thumbnails_removed: number, projects_restored: number, temp_files_removed: number, thumbnails_regenerated: number, };
@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Library health report from `verify_library`.
+ */
+export type LibraryReport = { 
+/**
+ * Number of projects and media files scanned.
+ */
+items_scanned: number, 
+/**
+ * Thumbnail files with no matching project or media file.
+ */
+orphaned_thumbnails: Array<string>, 
+/**
+ * IDs of screenshot projects whose original image is missing.
+ */
+missing_originals: Array<string>, 
+/**
+ * project.json files that could not be read or parsed.
+ */
+unparseable_projects: Array<string>, 
+/**
+ * Video project folders that have a project.json but no screen.mp4.
+ */
+missing_screen_videos: Array<string>, 
+/**
+ * Leftover project.json.tmp files from interrupted writes.
+ */
+temp_project_files: Array<string>, 
+/**
+ * IDs of library items without a thumbnail.
+ */
+missing_thumbnails: Array<string>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Which microphone channels end up in the recorded track.
+ */
+export type MicChannelMode = "stereo" | "monoLeft" | "monoRight" | "monoMix";
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Framing of screen content in a composition taller than the content.
+ */
+export type PortraitFraming = "fit" | "center" | "followZoom" | "followCursor";
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How the MP4 encoder spends its bits.
+ */
+export type RateControl = { "mode": "cbr" } | { "mode": "vbr", maxBitrate: number, } | { "mode": "quality", crf: number, };
@@ -3,4 +3,4 @@
 /**
  * What to capture.
  */
-export type RecordingMode = { "type": "region", x: number, y: number, width: number, height: number, } | { "type": "window", windowId: number, } | { "type": "monitor", monitorIndex: number, } | { "type": "allMonitors" };
+export type RecordingMode = { "type": "region", x: number, y: number, width: number, height: number, } | { "type": "window", windowId: number, } | { "type": "monitor", monitorIndex: number, } | { "type": "allMonitors" } | { "type": "windowRegion", windowId: number, x: number, y: number, width: number, height: number, };
@@ -1,8 +1,10 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { AudioSettings } from "./AudioSettings";
 import type { GifQualityPreset } from "./GifQualityPreset";
+import type { RateControl } from "./RateControl";
 import type { RecordingFormat } from "./RecordingFormat";
 import type { RecordingMode } from "./RecordingMode";
+import type { VideoCodec } from "./VideoCodec";
 
 /**
  * Settings for a recording session.
@@ -49,4 +51,26 @@ countdownSecs: number,
  * When true, cursor is baked into video based on include_cursor setting.
  * When false, cursor is captured separately for editor flexibility.
  */
-quickCapture: boolean, };
+quickCapture: boolean, 
+/**
+ * Video codec for MP4 recordings. Ignored for GIF.
+ */
+videoCodec: VideoCodec, 
+/**
+ * Encoder rate control for MP4 recordings. Ignored for GIF.
+ */
+rateControl: RateControl, 
+/**
+ * Seconds between keyframes (0.5-10). Shorter intervals make seeking in the
+ * editor faster at the cost of file size.
+ */
+keyframeIntervalSecs: number, 
+/**
+ * Split the screen recording into `recording_partNN.mp4` files of this many
+ * seconds, joined when recording stops. Editor-flow MP4 only.
+ */
+segmentDurationSecs: number | null, 
+/**
+ * Record key presses for the exported keystroke overlay. Editor-flow MP4 only.
+ */
+captureKeystrokes: boolean, };
@@ -6,4 +6,8 @@
  * NOTE: ts-rs generates TypeScript types from Rust - single source of truth.
  * The serde attributes ensure JSON serialization matches the generated TS types.
  */
-export type RecordingState = { "status": "idle" } | { "status": "countdown", secondsRemaining: number, } | { "status": "recording", startedAt: string, elapsedSecs: number, frameCount: number, } | { "status": "paused", elapsedSecs: number, frameCount: number, } | { "status": "processing", progress: number, } | { "status": "completed", outputPath: string, durationSecs: number, fileSizeBytes: number, } | { "status": "error", message: string, };
+export type RecordingState = { "status": "idle" } | { "status": "countdown", secondsRemaining: number, } | { "status": "recording", startedAt: string, elapsedSecs: number, frameCount: number, 
+/**
+ * Bytes written to disk so far (estimated for GIF, which encodes at the end).
+ */
+currentSizeBytes: number, } | { "status": "paused", elapsedSecs: number, frameCount: number, } | { "status": "processing", progress: number, } | { "status": "completed", outputPath: string, durationSecs: number, fileSizeBytes: number, } | { "status": "error", message: string, } | { "status": "warning", message: string, };
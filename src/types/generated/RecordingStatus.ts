@@ -5,4 +5,9 @@ import type { RecordingState } from "./RecordingState";
 /**
  * Full status of the recording system.
  */
-export type RecordingStatus = { state: RecordingState, settings: RecordingSettings | null, };
+export type RecordingStatus = { state: RecordingState, settings: RecordingSettings | null, 
+/**
+ * Segment being written (1-based, matching `recording_partNN.mp4`), or None
+ * when the recording isn't split into segments.
+ */
+currentSegment: number | null, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Aggregate progress event sent during a batch re-export.
+ */
+export type ReexportProgress = { 
+/**
+ * Project currently being exported.
+ */
+projectId: string, 
+/**
+ * Number of projects finished so far (success or failure).
+ */
+completed: number, 
+/**
+ * Number of failed projects so far.
+ */
+failed: number, 
+/**
+ * Total number of projects in the batch.
+ */
+total: number, };
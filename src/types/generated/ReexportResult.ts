@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExportResult } from "./ExportResult";
+
+/**
+ * Outcome of re-exporting one project in a batch.
+ */
+export type ReexportResult = { 
+/**
+ * Library ID of the project.
+ */
+projectId: string, 
+/**
+ * Export result when the project exported successfully.
+ */
+result: ExportResult | null, 
+/**
+ * Error message when the project failed to export.
+ */
+error: string | null, };
@@ -4,4 +4,8 @@ import type { CaptureSource } from "./CaptureSource";
 /**
  * Request to save a new capture.
  */
-export type SaveCaptureRequest = { image_data: string, capture_type: string, source: CaptureSource, };
+export type SaveCaptureRequest = { image_data: string, capture_type: string, source: CaptureSource, 
+/**
+ * Save even if a project with identical image content already exists.
+ */
+allow_duplicates?: boolean, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Export playback speed at a point of the source timeline.
+ */
+export type SpeedKeyframe = { 
+/**
+ * Source time in ms.
+ */
+timeMs: number, 
+/**
+ * Speed multiplier (0.25-8, 2.0 = twice as fast).
+ */
+speed: number, };
@@ -1,6 +1,11 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TypeUsage } from "./TypeUsage";
 
 /**
  * Storage statistics.
  */
-export type StorageStats = { total_size_bytes: number, total_size_mb: number, capture_count: number, storage_path: string, };
+export type StorageStats = { total_size_bytes: number, total_size_mb: number, capture_count: number, storage_path: string, 
+/**
+ * Disk usage per capture type, largest first.
+ */
+by_type: Array<TypeUsage>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FontVariant } from "./FontVariant";
+
+/**
+ * An installed font family and the variants it has.
+ */
+export type SystemFontFamily = { family: string, 
+/**
+ * Sorted by weight, upright before italic.
+ */
+variants: Array<FontVariant>, };
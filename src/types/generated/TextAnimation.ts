@@ -1,6 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
 /**
- * Text animation style.
+ * Reveal animation for a text segment.
  */
-export type TextAnimation = "none" | "fadeIn" | "fadeOut" | "fadeInOut";
+export type TextAnimation = { "type": "typewriter", charsPerSec: number, };
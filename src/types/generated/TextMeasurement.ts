@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Measured size of a text segment's laid-out glyphs.
+ */
+export type TextMeasurement = { 
+/**
+ * Width of the widest line in pixels.
+ */
+width: number, 
+/**
+ * Height of the laid-out lines in pixels.
+ */
+height: number, 
+/**
+ * Number of lines after wrapping.
+ */
+lineCount: number, };
@@ -1,4 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TextAnimation } from "./TextAnimation";
+import type { TextVerticalAlign } from "./TextVerticalAlign";
 import type { XY } from "./XY";
 
 /**
@@ -54,4 +56,44 @@ export type TextSegment = {
    * Fade duration in seconds (for fade in/out animation).
    */
   fadeDuration: number;
+  /**
+   * Shrink the font size (never grow) until the text fits its bounding box.
+   */
+  autoFit: boolean;
+  /**
+   * Extra spacing between letters, in ems (0 = normal).
+   */
+  letterSpacing: number;
+  /**
+   * Line height as a multiple of the font size.
+   */
+  lineHeight: number;
+  /**
+   * Outline color (hex format), or None for no outline.
+   */
+  strokeColor: string | null;
+  /**
+   * Outline width in pixels (at 1080p reference).
+   */
+  strokeWidth: number;
+  /**
+   * Background box color (hex format), or None for no box.
+   */
+  backgroundColor: string | null;
+  /**
+   * Background box opacity (0-1).
+   */
+  backgroundOpacity: number;
+  /**
+   * Padding around the text inside the box, in pixels (at 1080p reference).
+   */
+  backgroundPadding: number;
+  /**
+   * Reveal animation, or None to show the full text immediately.
+   */
+  animation: TextAnimation | null;
+  /**
+   * Vertical alignment of the text within its bounding box.
+   */
+  verticalAlign: TextVerticalAlign;
 };
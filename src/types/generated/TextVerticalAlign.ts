@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Vertical placement of text within its bounding box.
+ */
+export type TextVerticalAlign = "top" | "middle" | "bottom";
@@ -0,0 +1,40 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TimecodeMode } from "./TimecodeMode";
+import type { TimecodePosition } from "./TimecodePosition";
+
+/**
+ * Burned-in timecode overlay rendered into exported frames.
+ */
+export type TimecodeConfig = { 
+/**
+ * What time the overlay shows.
+ */
+mode: TimecodeMode, 
+/**
+ * strftime-style format string (e.g. "%H:%M:%S%.3f").
+ */
+format: string, 
+/**
+ * Frame corner the timecode is anchored to.
+ */
+position: TimecodePosition, 
+/**
+ * Distance from the corner in pixels (at 1080p reference).
+ */
+margin: number, 
+/**
+ * Font family.
+ */
+fontFamily: string, 
+/**
+ * Font size in pixels (at 1080p reference).
+ */
+fontSize: number, 
+/**
+ * Font weight (100-900).
+ */
+fontWeight: number, 
+/**
+ * Text color (hex format, e.g., "#ffffff").
+ */
+color: string, };
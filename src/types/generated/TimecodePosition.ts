@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Frame corner for the burned-in timecode.
+ */
+export type TimecodePosition = "topLeft" | "topRight" | "bottomLeft" | "bottomRight";
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A kept range of the source recording.
+ */
+export type TimelineSegment = { 
+/**
+ * Start of the range in source ms.
+ */
+startMs: number, 
+/**
+ * End of the range in source ms (exclusive).
+ */
+endMs: number, };
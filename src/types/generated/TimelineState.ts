@@ -1,4 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HoldSegment } from "./HoldSegment";
+import type { SpeedKeyframe } from "./SpeedKeyframe";
+import type { TimelineSegment } from "./TimelineSegment";
 
 /**
  * Timeline editing state.
@@ -19,4 +22,19 @@ outPoint: number,
 /**
  * Playback speed multiplier (1.0 = normal).
  */
-speed: number, };
+speed: number, 
+/**
+ * Source ranges kept in the export, in order. Everything between them is
+ * cut. Empty = keep the single `in_point`..`out_point` range.
+ */
+segments: Array<TimelineSegment>, 
+/**
+ * Export speed curve over source time. Speed ramps linearly between
+ * keyframes and holds before the first and after the last. Empty = 1x.
+ */
+speedKeyframes: Array<SpeedKeyframe>, 
+/**
+ * Freeze-frames: the screen holds on one frame while the timeline
+ * (audio, zoom, text) keeps running underneath.
+ */
+holds: Array<HoldSegment>, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Disk usage of one capture type.
+ */
+export type TypeUsage = { 
+/**
+ * Capture type as shown in the library (region, window, video, gif, ...).
+ */
+capture_type: string, count: number, size_bytes: number, };
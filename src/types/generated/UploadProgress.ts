@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Progress event sent while a capture uploads.
+ */
+export type UploadProgress = { projectId: string, uploadedBytes: number, totalBytes: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Video codec for MP4 recordings.
+ */
+export type VideoCodec = "h264" | "h265";
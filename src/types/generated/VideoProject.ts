@@ -1,7 +1,9 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { AudioTrackSettings } from "./AudioTrackSettings";
 import type { CursorConfig } from "./CursorConfig";
+import type { CursorSmoothingSegment } from "./CursorSmoothingSegment";
 import type { ExportConfig } from "./ExportConfig";
+import type { KeystrokeConfig } from "./KeystrokeConfig";
 import type { MaskConfig } from "./MaskConfig";
 import type { SceneConfig } from "./SceneConfig";
 import type { TextConfig } from "./TextConfig";
@@ -69,4 +71,12 @@ text: TextConfig,
 /**
  * Mask/blur region configuration.
  */
-mask: MaskConfig, };
+mask: MaskConfig, 
+/**
+ * Cursor spring overrides for parts of the timeline.
+ */
+cursorSmoothingSegments: Array<CursorSmoothingSegment>, 
+/**
+ * Keystroke overlay configuration.
+ */
+keystrokes: KeystrokeConfig, };
@@ -1,4 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MicChannelMode } from "./MicChannelMode";
+import type { RateControl } from "./RateControl";
+import type { VideoCodec } from "./VideoCodec";
 import type { VideoFormat } from "./VideoFormat";
 
 /**
@@ -50,4 +53,40 @@ hideDesktopIcons: boolean,
  * When true, cursor is baked into video based on include_cursor setting.
  * When false, cursor is captured separately for editor flexibility.
  */
-quickCapture: boolean, };
+quickCapture: boolean, 
+/**
+ * Video codec. H.265 falls back to H.264 without a hardware HEVC encoder.
+ */
+codec: VideoCodec, 
+/**
+ * Encoder rate control (constant bitrate, variable bitrate or constant quality).
+ */
+rateControl: RateControl, 
+/**
+ * Seconds between keyframes (0.5-10). Shorter seeks faster in the editor.
+ */
+keyframeIntervalSecs: number, 
+/**
+ * Split long recordings into parts of this many seconds (None = single file).
+ */
+segmentDurationSecs: number | null, 
+/**
+ * Microphone gain in dB (-20 to +20).
+ */
+microphoneGainDb: number, 
+/**
+ * Silence the microphone below `noise_gate_threshold_db`.
+ */
+noiseGate: boolean, 
+/**
+ * Noise gate threshold in dBFS.
+ */
+noiseGateThresholdDb: number, 
+/**
+ * How microphone channels are mixed into the recorded track.
+ */
+micChannelMode: MicChannelMode, 
+/**
+ * Record key presses for the exported keystroke overlay.
+ */
+captureKeystrokes: boolean, };
@@ -16,6 +16,14 @@ webcamVideo: string | null,
  * Path to cursor events JSON file.
  */
 cursorData: string | null, 
+/**
+ * Path to the per-frame capture timestamps sidecar (`frames.json`).
+ */
+frameTimestamps?: string, 
+/**
+ * Path to the recorded key presses sidecar (`keystroke.json`).
+ */
+keystrokes?: string, 
 /**
  * Path to audio file if recorded separately (legacy, use system_audio/microphone_audio instead).
  */
@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebcamTransition } from "./WebcamTransition";
 
 /**
  * A segment defining visibility state over time.
@@ -15,4 +16,8 @@ endMs: number,
 /**
  * Whether visible during this segment.
  */
-visible: boolean, };
+visible: boolean, 
+/**
+ * Animation at the segment's boundaries. Instant when `None`.
+ */
+transition?: WebcamTransition, };
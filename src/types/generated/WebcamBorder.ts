@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GlowConfig } from "./GlowConfig";
 
 /**
  * Webcam border settings.
@@ -15,4 +16,16 @@ width: number,
 /**
  * Border color (CSS color string).
  */
-color: string, };
+color: string, 
+/**
+ * Gradient end color. Solid `color` border when `None`.
+ */
+gradientEnd?: string, 
+/**
+ * Gradient angle in degrees (0-360).
+ */
+gradientAngle: number, 
+/**
+ * Glow around the border. No glow when `None`.
+ */
+glow?: GlowConfig, };
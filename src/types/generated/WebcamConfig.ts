@@ -1,10 +1,13 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChromaKeyConfig } from "./ChromaKeyConfig";
 import type { CornerStyle } from "./CornerStyle";
 import type { ShadowConfig } from "./ShadowConfig";
 import type { VisibilitySegment } from "./VisibilitySegment";
 import type { WebcamBorder } from "./WebcamBorder";
+import type { WebcamInstance } from "./WebcamInstance";
 import type { WebcamOverlayPosition } from "./WebcamOverlayPosition";
 import type { WebcamOverlayShape } from "./WebcamOverlayShape";
+import type { WebcamPositionKeyframe } from "./WebcamPositionKeyframe";
 
 /**
  * Webcam overlay configuration.
@@ -57,4 +60,17 @@ border: WebcamBorder,
 /**
  * Visibility segments (for toggling on/off during video).
  */
-visibilitySegments: Array<VisibilitySegment>, };
+visibilitySegments: Array<VisibilitySegment>, 
+/**
+ * Green-screen keying applied on export. Off when `None`.
+ */
+chromaKey?: ChromaKeyConfig, 
+/**
+ * Animated position/size path. When empty, the static position is used.
+ */
+positionKeyframes: Array<WebcamPositionKeyframe>, 
+/**
+ * Overlay placements (e.g., picture-in-picture at several positions).
+ * When empty, the position/size/shape/visibility above are the only one.
+ */
+instances: Array<WebcamInstance>, };
@@ -0,0 +1,37 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VisibilitySegment } from "./VisibilitySegment";
+import type { WebcamOverlayPosition } from "./WebcamOverlayPosition";
+import type { WebcamOverlayShape } from "./WebcamOverlayShape";
+import type { WebcamPositionKeyframe } from "./WebcamPositionKeyframe";
+
+/**
+ * One on-screen placement of the webcam.
+ *
+ * Shares the source and styling (border, shadow, mirror, keying) of its
+ * `WebcamConfig`; only placement and timing are per instance.
+ */
+export type WebcamInstance = { 
+/**
+ * Position preset.
+ */
+position: WebcamOverlayPosition, 
+/**
+ * Custom position (used when position is Custom).
+ */
+customX: number, customY: number, 
+/**
+ * Size as percentage of video width (e.g., 0.2 = 20%).
+ */
+size: number, 
+/**
+ * Shape of webcam overlay.
+ */
+shape: WebcamOverlayShape, 
+/**
+ * Visibility segments (for toggling on/off during video).
+ */
+visibilitySegments: Array<VisibilitySegment>, 
+/**
+ * Animated position/size path. When empty, the static position is used.
+ */
+positionKeyframes: Array<WebcamPositionKeyframe>, };
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EasingFunction } from "./EasingFunction";
+import type { XY } from "./XY";
+
+/**
+ * A point on an animated webcam path.
+ *
+ * The webcam holds the first keyframe before it and the last one after it.
+ */
+export type WebcamPositionKeyframe = { 
+/**
+ * Timeline position in milliseconds.
+ */
+timeMs: number, 
+/**
+ * Webcam center (0-1 normalized).
+ */
+center: XY, 
+/**
+ * Size as percentage of video width (e.g., 0.2 = 20%).
+ */
+size: number, 
+/**
+ * Easing of the transition from the previous keyframe into this one.
+ */
+easing: EasingFunction, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebcamTransitionKind } from "./WebcamTransitionKind";
+
+/**
+ * Webcam entrance/exit transition.
+ */
+export type WebcamTransition = { 
+/**
+ * Animation style.
+ */
+kind: WebcamTransitionKind, 
+/**
+ * Duration of each of the entrance and exit in milliseconds.
+ */
+durationMs: number, };
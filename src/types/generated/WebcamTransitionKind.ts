@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How the webcam animates in and out at a visibility boundary.
+ */
+export type WebcamTransitionKind = "fade" | "slideFromEdge" | "scaleIn";
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EasingFunction } from "./EasingFunction";
+import type { XY } from "./XY";
+
+/**
+ * A point on a keyframed zoom curve ([`ZoomMode::Keyframed`]).
+ *
+ * The zoom holds the first keyframe before it and the last one after it.
+ */
+export type ZoomKeyframe = { 
+/**
+ * Timeline position in milliseconds.
+ */
+timeMs: number, 
+/**
+ * Zoom center (0-1 normalized).
+ */
+center: XY, 
+/**
+ * Zoom scale (1.0 = no zoom).
+ */
+scale: number, 
+/**
+ * Easing of the transition from the previous keyframe into this one.
+ */
+easing: EasingFunction, };
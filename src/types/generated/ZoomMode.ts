@@ -1,6 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ZoomKeyframe } from "./ZoomKeyframe";
 
 /**
  * Zoom mode - controls how zooms are applied.
  */
-export type ZoomMode = "off" | "auto" | "manual" | "both";
+export type ZoomMode = "off" | "auto" | "manual" | "both" | { "followCursor": { scale: number, smoothing: number, } } | { "keyframed": { keyframes: Array<ZoomKeyframe>, } };
@@ -43,4 +43,9 @@ isAuto: boolean,
 /**
  * Transition settings.
  */
-transition: ZoomTransition, };
+transition: ZoomTransition, 
+/**
+ * Minimum distance (normalized 0-0.5) kept between the zoom target and the
+ * output edges, so targets near a corner aren't clipped by the viewport.
+ */
+focusPadding: number, };
@@ -5,6 +5,8 @@ export type { AudioInputDevice } from './AudioInputDevice';
 export type { AudioLevels } from './AudioLevels';
 export type { AudioSettings } from './AudioSettings';
 export type { GifQualityPreset } from './GifQualityPreset';
+export type { MicChannelMode } from './MicChannelMode';
+export type { RateControl } from './RateControl';
 export type { RecordingFormat } from './RecordingFormat';
 export type { RecordingMode } from './RecordingMode';
 export type { RecordingSettings } from './RecordingSettings';
@@ -12,6 +14,7 @@ export type { RecordingState } from './RecordingState';
 export type { RecordingStatus } from './RecordingStatus';
 export type { StartRecordingResult } from './StartRecordingResult';
 export type { StopRecordingResult } from './StopRecordingResult';
+export type { VideoCodec } from './VideoCodec';
 
 // Capture settings types
 export type { CaptureSettings } from './CaptureSettings';
@@ -26,8 +29,21 @@ export type { CaptureListItem } from './CaptureListItem';
 export type { CaptureSource } from './CaptureSource';
 export type { Dimensions } from './Dimensions';
 export type { Region } from './Region';
+export type { RedactRegion } from './RedactRegion';
+export type { RedactMode } from './RedactMode';
 export type { SaveCaptureRequest } from './SaveCaptureRequest';
 export type { StorageStats } from './StorageStats';
+export type { TypeUsage } from './TypeUsage';
+export type { LibraryReport } from './LibraryReport';
+export type { LibraryRepairResult } from './LibraryRepairResult';
+
+// Font types
+export type { FontVariant } from './FontVariant';
+export type { SystemFontFamily } from './SystemFontFamily';
+
+// Upload types
+export type { UploadProvider } from './UploadProvider';
+export type { UploadProgress } from './UploadProgress';
 
 // Webcam types
 export type { SupportedResolutions } from './SupportedResolutions';
@@ -48,11 +64,16 @@ export type { WindowsCursorShape } from './WindowsCursorShape';
 export type { VideoProject } from './VideoProject';
 export type { VideoSources } from './VideoSources';
 export type { TimelineState } from './TimelineState';
+export type { TimelineSegment } from './TimelineSegment';
+export type { SpeedKeyframe } from './SpeedKeyframe';
+export type { HoldSegment } from './HoldSegment';
+export type { HoldAudio } from './HoldAudio';
 
 // Video editor - Zoom types
 export type { AutoZoomConfig } from './AutoZoomConfig';
 export type { ZoomConfig } from './ZoomConfig';
 export type { ZoomMode } from './ZoomMode';
+export type { ZoomKeyframe } from './ZoomKeyframe';
 export type { ZoomRegion } from './ZoomRegion';
 export type { ZoomRegionMode } from './ZoomRegionMode';
 export type { ZoomTransition } from './ZoomTransition';
@@ -60,6 +81,8 @@ export type { EasingFunction } from './EasingFunction';
 
 // Video editor - Cursor config types
 export type { CursorConfig } from './CursorConfig';
+export type { CursorSmoothingSegment } from './CursorSmoothingSegment';
+export type { CursorTrailConfig } from './CursorTrailConfig';
 export type { CursorType } from './CursorType';
 export type { CursorAnimationStyle } from './CursorAnimationStyle';
 export type { ClickHighlightConfig } from './ClickHighlightConfig';
@@ -67,10 +90,16 @@ export type { ClickHighlightStyle } from './ClickHighlightStyle';
 
 // Video editor - Webcam config types
 export type { WebcamConfig } from './WebcamConfig';
+export type { WebcamInstance } from './WebcamInstance';
+export type { WebcamPositionKeyframe } from './WebcamPositionKeyframe';
 export type { WebcamOverlayPosition } from './WebcamOverlayPosition';
 export type { WebcamOverlayShape } from './WebcamOverlayShape';
 export type { WebcamBorder } from './WebcamBorder';
+export type { GlowConfig } from './GlowConfig';
+export type { ChromaKeyConfig } from './ChromaKeyConfig';
 export type { VisibilitySegment } from './VisibilitySegment';
+export type { WebcamTransition } from './WebcamTransition';
+export type { WebcamTransitionKind } from './WebcamTransitionKind';
 export type { CornerStyle } from './CornerStyle';
 export type { ShadowConfig } from './ShadowConfig';
 
@@ -86,6 +115,8 @@ export type { SceneConfig } from './SceneConfig';
 // Video editor - Text overlay types (Cap's model)
 export type { XY } from './XY';
 export type { TextSegment } from './TextSegment';
+export type { TextAnimation } from './TextAnimation';
+export type { TextVerticalAlign } from './TextVerticalAlign';
 export type { TextConfig } from './TextConfig';
 
 // Video editor - Mask types
@@ -93,11 +124,18 @@ export type { MaskType } from './MaskType';
 export type { MaskSegment } from './MaskSegment';
 export type { MaskConfig } from './MaskConfig';
 
+// Video editor - Keystroke overlay types
+export type { KeystrokeConfig } from './KeystrokeConfig';
+export type { KeystrokePosition } from './KeystrokePosition';
+
 // Video editor - Export types
 export type { ExportConfig } from './ExportConfig';
 export type { ExportFormat } from './ExportFormat';
 export type { ExportResolution } from './ExportResolution';
 export type { ExportPreset } from './ExportPreset';
+export type { TimecodeConfig } from './TimecodeConfig';
+export type { TimecodeMode } from './TimecodeMode';
+export type { TimecodePosition } from './TimecodePosition';
 export type { AspectRatio } from './AspectRatio';
 export type { BackgroundType } from './BackgroundType';
 export type { BackgroundConfig } from './BackgroundConfig';
@@ -106,12 +144,18 @@ export type { BorderConfig } from './BorderConfig';
 export type { CropConfig } from './CropConfig';
 export type { CompositionMode } from './CompositionMode';
 export type { CompositionConfig } from './CompositionConfig';
+export type { PortraitFraming } from './PortraitFraming';
 export type { ExportProgress } from './ExportProgress';
 export type { ExportResult } from './ExportResult';
 export type { ExportStage } from './ExportStage';
+export type { EncoderCapabilities } from './EncoderCapabilities';
+export type { ExportOverrides } from './ExportOverrides';
+export type { ReexportProgress } from './ReexportProgress';
+export type { ReexportResult } from './ReexportResult';
 
 // GPU Video Editor types (wgpu-accelerated rendering)
 export type { EditorInstanceInfo } from './EditorInstanceInfo';
 export type { PlaybackEvent } from './PlaybackEvent';
 export type { PlaybackState } from './PlaybackState';
 export type { RenderedFrame } from './RenderedFrame';
+export type { TextMeasurement } from './TextMeasurement';
@@ -87,6 +87,8 @@ export interface CaptureProject {
   annotations: Annotation[];
   tags: string[];
   favorite: boolean;
+  content_hash?: string;
+  name?: string;
 }
 
 export interface CaptureListItem {
@@ -102,6 +104,10 @@ export interface CaptureListItem {
   favorite: boolean;
   /** True if the original image file is missing from disk */
   is_missing: boolean;
+  /** Duration of video and GIF captures in ms (null for images or not yet probed) */
+  duration_ms: number | null;
+  /** User-chosen title (video projects always have one) */
+  name: string | null;
 }
 
 export interface CaptureResult {
@@ -153,9 +159,16 @@ export interface StorageStats {
   total_size_mb: number;
   capture_count: number;
   storage_path: string;
+  by_type: TypeUsage[];
 }
 
-export type Tool = 'select' | 'arrow' | 'line' | 'rect' | 'circle' | 'text' | 'blur' | 'highlight' | 'steps' | 'crop' | 'pen' | 'background';
+export interface TypeUsage {
+  capture_type: string;
+  count: number;
+  size_bytes: number;
+}
+
+export type Tool = 'select' | 'arrow' | 'line' | 'rect' | 'circle' | 'text' | 'blur' | 'highlight' | 'steps' | 'crop' | 'pen' | 'qr' | 'background';
 
 export interface CanvasShape {
   id: string;
@@ -193,6 +206,7 @@ export interface SaveCaptureRequest {
   image_data: string;
   capture_type: string;
   source: CaptureSource;
+  allow_duplicates?: boolean;
 }
 
 export interface SaveCaptureResponse {
@@ -287,6 +301,16 @@ export type ImageFormat = 'png' | 'jpg' | 'webp' | 'gif' | 'bmp';
 // Theme options
 export type Theme = 'light' | 'dark' | 'system';
 
+// S3-compatible upload target (Amazon S3, R2, MinIO, ...)
+export interface S3UploadSettings {
+  endpoint: string; // e.g. 'https://s3.us-east-1.amazonaws.com'
+  region: string; // Empty uses 'us-east-1'
+  bucket: string;
+  accessKeyId: string;
+  secretAccessKey: string; // Stored in plain text in settings.json
+  publicUrl: string; // Base URL shared links use (CDN/custom domain); empty uses the endpoint
+}
+
 // General application settings
 export interface GeneralSettings {
   startWithWindows: boolean;
@@ -297,6 +321,9 @@ export interface GeneralSettings {
   jpgQuality: number; // 0-100
   allowOverride: boolean; // Allow SnapIt to override shortcuts registered by other apps
   theme: Theme; // App color theme
+  animatedThumbnails: boolean; // Animated WebP previews for videos in the library (slower to generate)
+  filenameTemplate: string; // e.g. '{date}_{source}_{n}'; empty uses the built-in names
+  s3Upload: S3UploadSettings; // Target of upload_capture with the 's3' provider
 }
 
 // Complete application settings
@@ -334,6 +361,15 @@ export const DEFAULT_SHORTCUTS: Record<string, ShortcutConfig> = {
     status: 'pending',
     useHook: true,
   },
+  window_under_cursor_capture: {
+    id: 'window_under_cursor_capture',
+    name: 'Window Under Cursor',
+    description: 'Capture the window under the mouse pointer',
+    defaultShortcut: 'Alt+PrintScreen',
+    currentShortcut: 'Alt+PrintScreen',
+    status: 'pending',
+    useHook: true,
+  },
 };
 
 // Default general settings
@@ -346,6 +382,16 @@ export const DEFAULT_GENERAL_SETTINGS: GeneralSettings = {
   jpgQuality: 85,
   allowOverride: true, // Override shortcuts from other apps by default
   theme: 'system', // Follow OS preference by default
+  animatedThumbnails: false, // Static thumbnails keep library loading fast
+  filenameTemplate: '',
+  s3Upload: {
+    endpoint: '',
+    region: '',
+    bucket: '',
+    accessKeyId: '',
+    secretAccessKey: '',
+    publicUrl: '',
+  },
 };
 
 // Default complete settings
@@ -368,6 +414,8 @@ export type CaptureType = 'screenshot' | 'video' | 'gif';
 // Re-export generated types - single source of truth from Rust
 export type {
   AudioSettings,
+  MicChannelMode,
+  RateControl,
   RecordingFormat,
   RecordingMode,
   RecordingSettings,
@@ -375,6 +423,7 @@ export type {
   RecordingStatus,
   StartRecordingResult,
   StopRecordingResult,
+  VideoCodec,
   VideoFormat,
 } from './generated';
 
@@ -397,37 +446,59 @@ export type {
   VideoProject,
   VideoSources,
   TimelineState,
+  TimelineSegment,
+  SpeedKeyframe,
+  HoldSegment,
+  HoldAudio,
   ZoomConfig,
   ZoomMode,
+  ZoomKeyframe,
   ZoomRegion,
   ZoomRegionMode,
   ZoomTransition,
   EasingFunction,
   CursorConfig,
+  CursorSmoothingSegment,
+  CursorTrailConfig,
   CursorType,
   CursorAnimationStyle,
   ClickHighlightConfig,
   ClickHighlightStyle,
   WebcamConfig,
+  WebcamInstance,
+  WebcamPositionKeyframe,
   WebcamOverlayPosition,
   WebcamOverlayShape,
   WebcamBorder,
+  GlowConfig,
+  ChromaKeyConfig,
   VisibilitySegment,
+  WebcamTransition,
+  WebcamTransitionKind,
   CornerStyle,
   ShadowConfig,
   ExportConfig,
   ExportFormat,
   ExportResolution,
   ExportPreset,
+  TimecodeConfig,
+  TimecodeMode,
+  TimecodePosition,
   AspectRatio,
   BackgroundType as VideoBackgroundType,
   BackgroundConfig,
   CropConfig,
   CompositionMode,
   CompositionConfig,
+  PortraitFraming,
+  GifQualityPreset,
   ExportProgress,
   ExportResult,
   ExportStage,
+  EncoderCapabilities,
+  ExportOverrides,
+  ReexportProgress,
+  ReexportResult,
   AudioTrackSettings,
   AudioWaveform,
   SceneMode,
@@ -435,10 +506,14 @@ export type {
   SceneConfig,
   XY,
   TextSegment,
+  TextAnimation,
+  TextVerticalAlign,
   TextConfig,
   MaskType,
   MaskSegment,
   MaskConfig,
+  KeystrokeConfig,
+  KeystrokePosition,
 } from './generated';
 
 // GPU Video Editor types (wgpu-accelerated rendering)
@@ -447,6 +522,7 @@ export type {
   PlaybackEvent,
   PlaybackState,
   RenderedFrame,
+  TextMeasurement,
 } from './generated';
 
 // Import Rust type for extension
@@ -471,11 +547,19 @@ export const DEFAULT_RECORDING_SETTINGS: RecordingSettings = {
   audio: {
     captureSystemAudio: true,
     microphoneDeviceIndex: null,
+    noiseGate: false,
+    noiseGateThresholdDb: -45,
+    micChannelMode: 'monoMix',
   },
   quality: 80,
   gifQualityPreset: 'balanced',
   countdownSecs: 3,
   quickCapture: false,
+  videoCodec: 'h264',
+  rateControl: { mode: 'cbr' },
+  keyframeIntervalSecs: 2,
+  segmentDurationSecs: null,
+  captureKeystrokes: false,
 };
 
 // ============================================
@@ -7,7 +7,8 @@ import { invoke } from '@tauri-apps/api/core';
 import { Italic } from 'lucide-react';
 import { videoEditorLogger } from '@/utils/logger';
 import { Slider } from '../../components/ui/slider';
-import type { TextSegment } from '../../types';
+import { Switch } from '../../components/ui/switch';
+import type { TextSegment, TextVerticalAlign } from '../../types';
 
 export interface TextSegmentConfigProps {
   segment: TextSegment;
@@ -23,6 +24,22 @@ const DEFAULT_FONT_FAMILIES = [
   'monospace',
 ];
 
+// Outline color used until the user picks one
+const DEFAULT_STROKE_COLOR = '#000000';
+
+// Background box color used when the box is first enabled
+const DEFAULT_BACKGROUND_COLOR = '#000000';
+
+// Typewriter reveal speed used when the animation is first enabled
+const DEFAULT_TYPEWRITER_CHARS_PER_SEC = 20;
+
+// Vertical alignment options, top to bottom
+const VERTICAL_ALIGN_OPTIONS: { value: TextVerticalAlign; label: string }[] = [
+  { value: 'top', label: 'Top' },
+  { value: 'middle', label: 'Middle' },
+  { value: 'bottom', label: 'Bottom' },
+];
+
 // Weight labels for display
 const WEIGHT_LABELS: Record<number, string> = {
   100: 'Thin',
@@ -153,6 +170,43 @@ export function TextSegmentConfig({ segment, onUpdate, onDelete, onDone }: TextS
           step={2}
           onValueChange={(values) => onUpdate({ fontSize: values[0] })}
         />
+        <div className="flex items-center justify-between mt-2">
+          <span className="text-xs text-[var(--ink-muted)]">Shrink to fit</span>
+          <Switch
+            checked={segment.autoFit}
+            onCheckedChange={(checked) => onUpdate({ autoFit: checked })}
+          />
+        </div>
+      </div>
+
+      {/* Letter Spacing */}
+      <div>
+        <div className="flex items-center justify-between mb-2">
+          <span className="text-xs text-[var(--ink-muted)]">Letter spacing</span>
+          <span className="text-xs text-[var(--ink-dark)] font-mono">{segment.letterSpacing.toFixed(2)}em</span>
+        </div>
+        <Slider
+          value={[segment.letterSpacing]}
+          min={-0.2}
+          max={1}
+          step={0.01}
+          onValueChange={(values) => onUpdate({ letterSpacing: values[0] })}
+        />
+      </div>
+
+      {/* Line Height */}
+      <div>
+        <div className="flex items-center justify-between mb-2">
+          <span className="text-xs text-[var(--ink-muted)]">Line height</span>
+          <span className="text-xs text-[var(--ink-dark)] font-mono">{segment.lineHeight.toFixed(2)}×</span>
+        </div>
+        <Slider
+          value={[segment.lineHeight]}
+          min={0.8}
+          max={3}
+          step={0.05}
+          onValueChange={(values) => onUpdate({ lineHeight: values[0] })}
+        />
       </div>
 
       {/* Font Style Row */}
@@ -189,6 +243,26 @@ export function TextSegmentConfig({ segment, onUpdate, onDelete, onDone }: TextS
         </div>
       </div>
 
+      {/* Vertical Alignment */}
+      <div>
+        <span className="text-xs text-[var(--ink-muted)] block mb-2">Vertical Align</span>
+        <div className="flex gap-1">
+          {VERTICAL_ALIGN_OPTIONS.map((option) => (
+            <button
+              key={option.value}
+              onClick={() => onUpdate({ verticalAlign: option.value })}
+              className={`flex-1 h-8 rounded-md border text-xs transition-colors ${
+                segment.verticalAlign === option.value
+                  ? 'bg-[var(--coral-100)] border-[var(--coral-300)] text-[var(--coral-500)]'
+                  : 'bg-[var(--polar-mist)] border-[var(--glass-border)] text-[var(--ink-muted)]'
+              }`}
+            >
+              {option.label}
+            </button>
+          ))}
+        </div>
+      </div>
+
       {/* Text Color */}
       <div className="flex items-center justify-between">
         <span className="text-xs text-[var(--ink-muted)]">Text Color</span>
@@ -200,6 +274,120 @@ export function TextSegmentConfig({ segment, onUpdate, onDelete, onDone }: TextS
         />
       </div>
 
+      {/* Outline */}
+      <div>
+        <div className="flex items-center justify-between mb-2">
+          <span className="text-xs text-[var(--ink-muted)]">Outline</span>
+          <div className="flex items-center gap-2">
+            <span className="text-xs text-[var(--ink-dark)] font-mono">{segment.strokeWidth}px</span>
+            <input
+              type="color"
+              value={segment.strokeColor ?? DEFAULT_STROKE_COLOR}
+              onChange={(e) => onUpdate({ strokeColor: e.target.value })}
+              className="w-8 h-6 rounded border border-[var(--glass-border)] cursor-pointer bg-transparent"
+            />
+          </div>
+        </div>
+        <Slider
+          value={[segment.strokeWidth]}
+          min={0}
+          max={12}
+          step={1}
+          onValueChange={(values) =>
+            onUpdate({
+              strokeWidth: values[0],
+              strokeColor: segment.strokeColor ?? DEFAULT_STROKE_COLOR,
+            })
+          }
+        />
+      </div>
+
+      {/* Background Box */}
+      <div>
+        <div className="flex items-center justify-between mb-2">
+          <span className="text-xs text-[var(--ink-muted)]">Background</span>
+          <div className="flex items-center gap-2">
+            {segment.backgroundColor !== null && (
+              <input
+                type="color"
+                value={segment.backgroundColor}
+                onChange={(e) => onUpdate({ backgroundColor: e.target.value })}
+                className="w-8 h-6 rounded border border-[var(--glass-border)] cursor-pointer bg-transparent"
+              />
+            )}
+            <Switch
+              checked={segment.backgroundColor !== null}
+              onCheckedChange={(checked) =>
+                onUpdate({ backgroundColor: checked ? DEFAULT_BACKGROUND_COLOR : null })
+              }
+            />
+          </div>
+        </div>
+        {segment.backgroundColor !== null && (
+          <div className="space-y-3">
+            <div>
+              <div className="flex items-center justify-between mb-2">
+                <span className="text-xs text-[var(--ink-muted)]">Opacity</span>
+                <span className="text-xs text-[var(--ink-dark)] font-mono">{Math.round(segment.backgroundOpacity * 100)}%</span>
+              </div>
+              <Slider
+                value={[segment.backgroundOpacity * 100]}
+                min={0}
+                max={100}
+                step={5}
+                onValueChange={(values) => onUpdate({ backgroundOpacity: values[0] / 100 })}
+              />
+            </div>
+            <div>
+              <div className="flex items-center justify-between mb-2">
+                <span className="text-xs text-[var(--ink-muted)]">Padding</span>
+                <span className="text-xs text-[var(--ink-dark)] font-mono">{segment.backgroundPadding}px</span>
+              </div>
+              <Slider
+                value={[segment.backgroundPadding]}
+                min={0}
+                max={48}
+                step={1}
+                onValueChange={(values) => onUpdate({ backgroundPadding: values[0] })}
+              />
+            </div>
+          </div>
+        )}
+      </div>
+
+      {/* Typewriter */}
+      <div>
+        <div className="flex items-center justify-between mb-2">
+          <span className="text-xs text-[var(--ink-muted)]">Typewriter</span>
+          <div className="flex items-center gap-2">
+            {segment.animation && (
+              <span className="text-xs text-[var(--ink-dark)] font-mono">{segment.animation.charsPerSec} chars/s</span>
+            )}
+            <Switch
+              checked={segment.animation !== null}
+              onCheckedChange={(checked) =>
+                onUpdate({
+                  animation: checked
+                    ? { type: 'typewriter', charsPerSec: DEFAULT_TYPEWRITER_CHARS_PER_SEC }
+                    : null,
+                })
+              }
+            />
+          </div>
+        </div>
+        {segment.animation && (
+          <Slider
+            value={[segment.animation.charsPerSec]}
+            min={1}
+            max={60}
+            step={1}
+            onValueChange={(values) =>
+              onUpdate({ animation: { type: 'typewriter', charsPerSec: values[0] } })
+            }
+          />
+        )}
+      </div>
+
       {/* Fade Duration */}
       <div>
         <div className="flex items-center justify-between mb-2">
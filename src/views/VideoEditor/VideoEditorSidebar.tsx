@@ -2,18 +2,64 @@
  * VideoEditorSidebar - Right sidebar with tabbed properties panel.
  * Contains Project, Cursor, Webcam, Style, and Export tabs.
  */
-import { useState } from 'react';
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { open } from '@tauri-apps/plugin-dialog';
 import { Circle, Square, Monitor, Crop } from 'lucide-react';
 import { useVideoEditorStore } from '../../stores/videoEditorStore';
 import { BackgroundSettings } from '../../components/VideoEditor/BackgroundSettings';
 import { Button } from '../../components/ui/button';
 import { Slider } from '../../components/ui/slider';
+import { Switch } from '../../components/ui/switch';
 import { ToggleGroup, ToggleGroupItem } from '../../components/ui/toggle-group';
 import { PositionGrid } from './PositionGrid';
 import { ZoomRegionConfig } from './ZoomRegionConfig';
 import { MaskSegmentConfig } from './MaskSegmentConfig';
 import { TextSegmentConfig } from './TextSegmentConfig';
-import type { WebcamOverlayShape, AspectRatio, ExportPreset, SceneMode, VideoProject } from '../../types';
+import { videoEditorLogger } from '../../utils/logger';
+import type { CursorTrailConfig, EncoderCapabilities, WebcamOverlayShape, AspectRatio, ExportPreset, GifQualityPreset, PortraitFraming, SceneMode, VideoProject } from '../../types';
+
+/** Audio track offset slider range (±ms) and step. */
+const AUDIO_OFFSET_RANGE_MS = 500;
+const AUDIO_OFFSET_STEP_MS = 10;
+
+/** Loudness normalization targets offered on export (LUFS). */
+const LOUDNESS_TARGET_OPTIONS = [
+  { lufs: -14, label: 'Streaming' },
+  { lufs: -16, label: 'Podcast' },
+  { lufs: -23, label: 'Broadcast' },
+];
+const NORMALIZE_OFF = 'off';
+
+/** Cursor trail used when the trail is switched on (matches the Rust default). */
+const DEFAULT_CURSOR_TRAIL: CursorTrailConfig = {
+  lengthMs: 300,
+  color: '#ffffff',
+  opacity: 0.6,
+  falloff: 1.5,
+  width: 4,
+};
+
+/** GIF palette presets (same as the GIF recorder). */
+const GIF_QUALITY_OPTIONS: { value: GifQualityPreset; label: string }[] = [
+  { value: 'fast', label: 'Fast (128 colors)' },
+  { value: 'balanced', label: 'Balanced' },
+  { value: 'high', label: 'High (best dithering)' },
+];
+
+/** Display names of the hardware encoders reported by the backend. */
+const HARDWARE_ENCODER_LABELS: { key: 'nvenc' | 'qsv' | 'amf'; codec: string; label: string }[] = [
+  { key: 'nvenc', codec: 'h264_nvenc', label: 'NVENC' },
+  { key: 'qsv', codec: 'h264_qsv', label: 'Quick Sync' },
+  { key: 'amf', codec: 'h264_amf', label: 'AMF' },
+];
+
+/** File types accepted as a click sound (decoded as WAV on export). */
+const CLICK_SOUND_EXTENSIONS = ['wav'];
+
+function formatOffset(ms: number): string {
+  return ms > 0 ? `+${ms} ms` : `${ms} ms`;
+}
 
 export interface VideoEditorSidebarProps {
   project: VideoProject | null;
@@ -53,6 +99,37 @@ export function VideoEditorSidebar({ project, onOpenCropDialog }: VideoEditorSid
   // Properties panel tab state
   const [activeTab, setActiveTab] = useState<PropertiesTab>('project');
 
+  // Hardware encoders, probed once the Export tab is first opened
+  const [encoderCapabilities, setEncoderCapabilities] = useState<EncoderCapabilities | null>(null);
+  const needsEncoderProbe = activeTab === 'export' && encoderCapabilities === null;
+  useEffect(() => {
+    if (!needsEncoderProbe) return;
+    invoke<EncoderCapabilities>('get_encoder_capabilities')
+      .then(setEncoderCapabilities)
+      .catch((error) => videoEditorLogger.error('Failed to detect encoders:', error));
+  }, [needsEncoderProbe]);
+  const exportEncoderLabel = HARDWARE_ENCODER_LABELS.find(
+    ({ codec }) => codec === encoderCapabilities?.exportEncoder
+  )?.label;
+
+  const handleBrowseClickSound = async () => {
+    if (!project) return;
+    try {
+      const selected = await open({
+        multiple: false,
+        title: 'Select Click Sound',
+        filters: [{ name: 'WAV Audio', extensions: CLICK_SOUND_EXTENSIONS }],
+      });
+      if (selected && typeof selected === 'string') {
+        updateCursorConfig({
+          clickHighlight: { ...project.cursor.clickHighlight, clickSound: selected },
+        });
+      }
+    } catch (error) {
+      videoEditorLogger.error('Failed to open click sound picker:', error);
+    }
+  };
+
   return (
     <div className="w-92 compositor-sidebar flex flex-col">
       {/* Tab Bar - scrollable to prevent clipping */}
@@ -286,6 +363,38 @@ export function VideoEditorSidebar({ project, onOpenCropDialog }: VideoEditorSid
                     </button>
                   </div>
 
+                  {/* Loudness normalization target */}
+                  <div className="space-y-1.5">
+                    <span className="text-xs text-[var(--ink-muted)]">Normalize Loudness</span>
+                    <ToggleGroup
+                      type="single"
+                      value={project.audio.normalizeOutput ? String(project.audio.loudnessTargetLufs) : NORMALIZE_OFF}
+                      onValueChange={(value) => {
+                        if (!value) return;
+                        if (value === NORMALIZE_OFF) {
+                          updateAudioConfig({ normalizeOutput: false });
+                        } else {
+                          updateAudioConfig({ normalizeOutput: true, loudnessTargetLufs: Number(value) });
+                        }
+                      }}
+                      className="justify-start"
+                    >
+                      <ToggleGroupItem value={NORMALIZE_OFF} className="text-xs h-7 px-2.5 data-[state=on]:bg-[var(--polar-frost)]">
+                        Off
+                      </ToggleGroupItem>
+                      {LOUDNESS_TARGET_OPTIONS.map(({ lufs, label }) => (
+                        <ToggleGroupItem
+                          key={lufs}
+                          value={String(lufs)}
+                          title={`${lufs} LUFS`}
+                          className="text-xs h-7 px-2.5 data-[state=on]:bg-[var(--polar-frost)]"
+                        >
+                          {label}
+                        </ToggleGroupItem>
+                      ))}
+                    </ToggleGroup>
+                  </div>
+
                   {/* Microphone Volume - only show when separate mic audio exists */}
                   {project.sources.microphoneAudio && (
                     <div className="space-y-1.5">
@@ -310,6 +419,20 @@ export function VideoEditorSidebar({ project, onOpenCropDialog }: VideoEditorSid
                         max={100}
                         step={1}
                       />
+                      {/* Sync offset - positive delays the mic, negative plays it earlier */}
+                      <div className="flex items-center justify-between pt-1">
+                        <span className="text-xs text-[var(--ink-muted)]">Mic Sync</span>
+                        <span className="text-xs text-[var(--ink-dark)] font-mono">
+                          {formatOffset(project.audio.micOffsetMs)}
+                        </span>
+                      </div>
+                      <Slider
+                        value={[project.audio.micOffsetMs]}
+                        onValueChange={(values) => updateAudioConfig({ micOffsetMs: values[0] })}
+                        min={-AUDIO_OFFSET_RANGE_MS}
+                        max={AUDIO_OFFSET_RANGE_MS}
+                        step={AUDIO_OFFSET_STEP_MS}
+                      />
                     </div>
                   )}
 
@@ -342,6 +465,24 @@ export function VideoEditorSidebar({ project, onOpenCropDialog }: VideoEditorSid
                       max={100}
                       step={1}
                     />
+                    {/* Sync offset only applies to the separate system audio track */}
+                    {project.sources.systemAudio && (
+                      <>
+                        <div className="flex items-center justify-between pt-1">
+                          <span className="text-xs text-[var(--ink-muted)]">System Sync</span>
+                          <span className="text-xs text-[var(--ink-dark)] font-mono">
+                            {formatOffset(project.audio.systemOffsetMs)}
+                          </span>
+                        </div>
+                        <Slider
+                          value={[project.audio.systemOffsetMs]}
+                          onValueChange={(values) => updateAudioConfig({ systemOffsetMs: values[0] })}
+                          min={-AUDIO_OFFSET_RANGE_MS}
+                          max={AUDIO_OFFSET_RANGE_MS}
+                          step={AUDIO_OFFSET_STEP_MS}
+                        />
+                      </>
+                    )}
                   </div>
                 </div>
               </>
@@ -569,6 +710,77 @@ export function VideoEditorSidebar({ project, onOpenCropDialog }: VideoEditorSid
               />
             </div>
 
+            {/* Cursor Trail Section */}
+            <div className="pt-3 border-t border-[var(--glass-border)]">
+              <div className="flex items-center justify-between mb-3">
+                <span className="text-xs text-[var(--ink-muted)]">Motion Trail</span>
+                <button
+                  onClick={() => updateCursorConfig({
+                    cursorTrail: project.cursor.cursorTrail ? null : DEFAULT_CURSOR_TRAIL
+                  })}
+                  className={`relative w-10 h-5 rounded-full transition-colors ${
+                    project.cursor.cursorTrail ? 'bg-[var(--coral-400)]' : 'bg-[var(--polar-frost)]'
+                  }`}
+                >
+                  <span
+                    className={`absolute top-0.5 left-0.5 w-4 h-4 rounded-full bg-white shadow transition-transform ${
+                      project.cursor.cursorTrail ? 'translate-x-5' : 'translate-x-0'
+                    }`}
+                  />
+                </button>
+              </div>
+
+              {project.cursor.cursorTrail && (
+                <div className="space-y-3">
+                  <div className="flex items-center justify-between">
+                    <span className="text-[11px] text-[var(--ink-subtle)]">Color</span>
+                    <input
+                      type="color"
+                      value={project.cursor.cursorTrail.color}
+                      onChange={(e) => project.cursor.cursorTrail && updateCursorConfig({
+                        cursorTrail: { ...project.cursor.cursorTrail, color: e.target.value }
+                      })}
+                      className="w-8 h-6 rounded border border-[var(--glass-border)] cursor-pointer bg-transparent"
+                    />
+                  </div>
+
+                  <div>
+                    <div className="flex items-center justify-between mb-1">
+                      <span className="text-[11px] text-[var(--ink-subtle)]">Length</span>
+                      <span className="text-[11px] text-[var(--ink-muted)] font-mono">{project.cursor.cursorTrail.lengthMs}ms</span>
+                    </div>
+                    <Slider
+                      value={[project.cursor.cursorTrail.lengthMs]}
+                      onValueChange={(values) => project.cursor.cursorTrail && updateCursorConfig({
+                        cursorTrail: { ...project.cursor.cursorTrail, lengthMs: values[0] }
+                      })}
+                      min={50}
+                      max={1000}
+                      step={50}
+                    />
+                  </div>
+
+                  <div>
+                    <div className="flex items-center justify-between mb-1">
+                      <span className="text-[11px] text-[var(--ink-subtle)]">Opacity</span>
+                      <span className="text-[11px] text-[var(--ink-muted)] font-mono">
+                        {Math.round(project.cursor.cursorTrail.opacity * 100)}%
+                      </span>
+                    </div>
+                    <Slider
+                      value={[project.cursor.cursorTrail.opacity * 100]}
+                      onValueChange={(values) => project.cursor.cursorTrail && updateCursorConfig({
+                        cursorTrail: { ...project.cursor.cursorTrail, opacity: values[0] / 100 }
+                      })}
+                      min={5}
+                      max={100}
+                      step={5}
+                    />
+                  </div>
+                </div>
+              )}
+            </div>
+
             {/* Click Highlight Section */}
             <div className="pt-3 border-t border-[var(--glass-border)]">
               <div className="flex items-center justify-between mb-3">
@@ -662,8 +874,50 @@ export function VideoEditorSidebar({ project, onOpenCropDialog }: VideoEditorSid
                       step={50}
                     />
                   </div>
+
                 </div>
               )}
+
+              {/* Click Sound */}
+              <div className="mt-3">
+                <div className="flex items-center justify-between mb-1">
+                  <span className="text-[11px] text-[var(--ink-subtle)]">Click Sound</span>
+                  <div className="flex gap-1">
+                    <Button variant="ghost" size="sm" className="h-6 px-2 text-[11px]" onClick={handleBrowseClickSound}>
+                      {project.cursor.clickHighlight.clickSound ? 'Change' : 'Choose...'}
+                    </Button>
+                    {project.cursor.clickHighlight.clickSound && (
+                      <Button
+                        variant="ghost"
+                        size="sm"
+                        className="h-6 px-2 text-[11px]"
+                        onClick={() => updateCursorConfig({
+                          clickHighlight: { ...project.cursor.clickHighlight, clickSound: null }
+                        })}
+                      >
+                        Remove
+                      </Button>
+                    )}
+                  </div>
+                </div>
+                {project.cursor.clickHighlight.clickSound && (
+                  <>
+                    <div className="flex items-center justify-between mb-1">
+                      <span className="text-[11px] text-[var(--ink-subtle)]">Volume</span>
+                      <span className="text-[11px] text-[var(--ink-muted)] font-mono">
+                        {Math.round(project.audio.clickSoundVolume * 100)}%
+                      </span>
+                    </div>
+                    <Slider
+                      value={[project.audio.clickSoundVolume * 100]}
+                      onValueChange={(values) => updateAudioConfig({ clickSoundVolume: values[0] / 100 })}
+                      min={0}
+                      max={100}
+                      step={5}
+                    />
+                  </>
+                )}
+              </div>
             </div>
           </div>
         )}
@@ -830,6 +1084,86 @@ export function VideoEditorSidebar({ project, onOpenCropDialog }: VideoEditorSid
               </select>
             </div>
 
+            {/* Portrait Framing */}
+            <div>
+              <span className="text-xs text-[var(--ink-muted)] block mb-2">Portrait Framing</span>
+              <select
+                value={project.export.portraitFraming}
+                onChange={(e) => updateExportConfig({ portraitFraming: e.target.value as PortraitFraming })}
+                className="w-full h-8 bg-[var(--polar-mist)] border border-[var(--glass-border)] rounded-md text-sm text-[var(--ink-dark)] px-2"
+              >
+                <option value="fit">Fit (show everything)</option>
+                <option value="center">Fill, centered</option>
+                <option value="followZoom">Fill, follow zoom</option>
+                <option value="followCursor">Fill, follow cursor</option>
+              </select>
+              <p className="text-[10px] text-[var(--ink-subtle)] mt-1.5">
+                Applies when the composition is taller than the video, e.g. 9:16.
+              </p>
+            </div>
+
+            {/* GIF Quality */}
+            <div>
+              <span className="text-xs text-[var(--ink-muted)] block mb-2">GIF Quality</span>
+              <select
+                value={project.export.gifQualityPreset}
+                onChange={(e) => updateExportConfig({ gifQualityPreset: e.target.value as GifQualityPreset })}
+                className="w-full h-8 bg-[var(--polar-mist)] border border-[var(--glass-border)] rounded-md text-sm text-[var(--ink-dark)] px-2"
+              >
+                {GIF_QUALITY_OPTIONS.map(({ value, label }) => (
+                  <option key={value} value={value}>{label}</option>
+                ))}
+              </select>
+              <p className="text-[10px] text-[var(--ink-subtle)] mt-1.5">
+                Used when exporting to .gif (max 30 fps, no audio).
+              </p>
+            </div>
+
+            {/* Transparent Background */}
+            <div>
+              <div className="flex items-center justify-between">
+                <span className="text-xs text-[var(--ink-muted)]">Transparent Background</span>
+                <Switch
+                  checked={project.export.transparentBackground}
+                  onCheckedChange={(checked) => updateExportConfig({ transparentBackground: checked })}
+                />
+              </div>
+              <p className="text-[10px] text-[var(--ink-subtle)] mt-1.5">
+                Skips the background when exporting to .mov (ProRes 4444) or a PNG sequence.
+              </p>
+            </div>
+
+            {/* Resumable Export */}
+            <div>
+              <div className="flex items-center justify-between">
+                <span className="text-xs text-[var(--ink-muted)]">Resumable Export</span>
+                <Switch
+                  checked={project.export.resumable}
+                  onCheckedChange={(checked) => updateExportConfig({ resumable: checked })}
+                />
+              </div>
+              <p className="text-[10px] text-[var(--ink-subtle)] mt-1.5">
+                Saves progress in chunks so a failed export continues where it stopped when exported again to the same file.
+              </p>
+            </div>
+
+            {/* Hardware Acceleration */}
+            <div>
+              <div className="flex items-center justify-between">
+                <span className="text-xs text-[var(--ink-muted)]">Hardware Acceleration</span>
+                <span className="text-xs text-[var(--ink-dark)]">
+                  {encoderCapabilities === null
+                    ? 'Detecting...'
+                    : (exportEncoderLabel ?? 'None (CPU)')}
+                </span>
+              </div>
+              {encoderCapabilities && !exportEncoderLabel && (
+                <p className="text-[10px] text-[var(--ink-subtle)] mt-1.5">
+                  Exports encode on the CPU and may be slow.
+                </p>
+              )}
+            </div>
+
             {/* Crop Video */}
             <div className="pt-3 border-t border-[var(--glass-border)]">
               <div className="flex items-center justify-between mb-2">
@@ -6,7 +6,15 @@ import { useRef, useEffect } from 'react';
 import { useWebCodecsPreview } from '../../hooks/useWebCodecsPreview';
 import { usePreviewOrPlaybackTime } from '../../hooks/usePlaybackEngine';
 import { Slider } from '../../components/ui/slider';
-import type { ZoomRegion } from '../../types';
+import type { ZoomRegion, EasingFunction } from '../../types';
+
+/** Transition feels offered for a zoom region. */
+const ZOOM_EASING_OPTIONS: { easing: EasingFunction; label: string }[] = [
+  { easing: 'easeInOut', label: 'Default' },
+  { easing: 'easeInOutCubic', label: 'Smooth' },
+  { easing: 'easeOutBack', label: 'Snap' },
+  { easing: 'linear', label: 'Linear' },
+];
 
 export interface ZoomRegionConfigProps {
   region: ZoomRegion;
@@ -120,6 +128,26 @@ export function ZoomRegionConfig({ region, videoSrc, canUseAuto, onUpdate, onDel
         />
       </div>
 
+      {/* Transition Easing */}
+      <div>
+        <span className="text-xs text-[var(--ink-muted)] block mb-2">Transition</span>
+        <div className="flex gap-1">
+          {ZOOM_EASING_OPTIONS.map(({ easing, label }) => (
+            <button
+              key={label}
+              onClick={() => onUpdate({ transition: { ...region.transition, easing } })}
+              className={`flex-1 h-7 text-xs rounded-md border border-[var(--glass-border)] transition-colors ${
+                region.transition.easing === easing
+                  ? 'bg-[var(--polar-frost)] text-[var(--ink-black)]'
+                  : 'text-[var(--ink-subtle)] hover:text-[var(--ink-dark)]'
+              }`}
+            >
+              {label}
+            </button>
+          ))}
+        </div>
+      </div>
+
       {/* Zoom Mode Toggle */}
       <div>
         <span className="text-xs text-[var(--ink-muted)] block mb-2">Zoom Mode</span>
@@ -16,17 +16,38 @@ import { save } from '@tauri-apps/plugin-dialog';
 import { useCaptureStore } from '../../stores/captureStore';
 import { useVideoEditorStore } from '../../stores/videoEditorStore';
 import { useVideoEditorShortcuts } from '../../hooks/useVideoEditorShortcuts';
+import { useVideoProjectAutosave } from '../../hooks/useVideoProjectAutosave';
 import { Button } from '../../components/ui/button';
 import { VideoEditorToolbar } from './VideoEditorToolbar';
 import { VideoEditorSidebar } from './VideoEditorSidebar';
 import { VideoEditorPreview } from './VideoEditorPreview';
 import { VideoEditorTimeline } from './VideoEditorTimeline';
-import type { ExportProgress, CropConfig, CompositionConfig } from '../../types';
+import type { ExportFormat, ExportProgress, CropConfig, CompositionConfig } from '../../types';
 import { videoEditorLogger } from '../../utils/logger';
 
 // Lazy load CropDialog - only needed when crop tool is opened (861 lines)
 const CropDialog = lazy(() => import('../../components/VideoEditor/CropDialog').then(m => ({ default: m.CropDialog })));
 
+const SECONDS_PER_MINUTE = 60;
+
+const EXPORT_FORMAT_LABELS: Record<ExportFormat, string> = {
+  mp4: 'MP4',
+  webm: 'WEBM',
+  gif: 'GIF',
+  pngSequence: 'PNG sequence',
+  mov: 'MOV',
+};
+
+/** Format render ETA/throughput as "~2m 15s remaining · 48 fps". */
+function formatExportRate(progress: ExportProgress | null): string | null {
+  if (progress?.etaSecs == null || progress.fps == null) return null;
+  const totalSecs = Math.ceil(progress.etaSecs);
+  const minutes = Math.floor(totalSecs / SECONDS_PER_MINUTE);
+  const seconds = totalSecs % SECONDS_PER_MINUTE;
+  const eta = minutes > 0 ? `${minutes}m ${seconds}s` : `${seconds}s`;
+  return `~${eta} remaining · ${Math.round(progress.fps)} fps`;
+}
+
 /**
  * Imperative API exposed by VideoEditorView
  */
@@ -167,6 +188,9 @@ export const VideoEditorView = forwardRef<VideoEditorViewRef, VideoEditorViewPro
     }
   }, [project, isSaving, saveProject]);
 
+  // Autosave edits for crash recovery
+  useVideoProjectAutosave();
+
   // Use keyboard shortcuts
   useVideoEditorShortcuts({
     enabled: !!project && !isExporting,
@@ -236,6 +260,8 @@ export const VideoEditorView = forwardRef<VideoEditorViewRef, VideoEditorViewPro
           { name: 'MP4 Video', extensions: ['mp4'] },
           { name: 'WebM Video', extensions: ['webm'] },
           { name: 'GIF Animation', extensions: ['gif'] },
+          { name: 'PNG Sequence (folder of frames)', extensions: ['png'] },
+          { name: 'QuickTime ProRes 4444 (alpha)', extensions: ['mov'] },
         ],
       });
 
@@ -250,7 +276,7 @@ export const VideoEditorView = forwardRef<VideoEditorViewRef, VideoEditorViewPro
       // Show success toast with file info
       const sizeMB = (result.fileSizeBytes / (1024 * 1024)).toFixed(1);
       toast.success(`Exported successfully`, {
-        description: `${sizeMB} MB - ${result.format.toUpperCase()}`,
+        description: `${sizeMB} MB - ${EXPORT_FORMAT_LABELS[result.format]}`,
       });
     } catch (error) {
       videoEditorLogger.error('Export failed:', error);
@@ -361,6 +387,13 @@ export const VideoEditorView = forwardRef<VideoEditorViewRef, VideoEditorViewPro
               <span>{Math.round((exportProgress?.progress ?? 0) * 100)}%</span>
             </div>
 
+            {/* ETA and render throughput */}
+            {formatExportRate(exportProgress) && (
+              <p className="text-xs text-[var(--ink-muted)] mt-1 tabular-nums">
+                {formatExportRate(exportProgress)}
+              </p>
+            )}
+
             {/* Status message */}
             {exportProgress?.message && (
               <p className="text-xs text-[var(--ink-subtle)] mt-2 truncate">
@@ -84,6 +84,7 @@ const CaptureToolbarWindow: React.FC = () => {
     setMode,
     format,
     elapsedTime,
+    currentSizeBytes,
     progress,
     errorMessage,
     countdownSeconds,
@@ -223,6 +224,7 @@ const CaptureToolbarWindow: React.FC = () => {
 
         if (captureType === 'video') {
           await invoke('set_hide_desktop_icons', { enabled: settings.video.hideDesktopIcons });
+          await invoke('set_recording_microphone_gain', { gainDb: settings.video.microphoneGainDb });
           // Webcam only for editor flow, not quick capture
           await invoke('set_webcam_enabled', { enabled: !quickCapture && webcamSettings.enabled });
         } else {
@@ -286,11 +288,19 @@ const CaptureToolbarWindow: React.FC = () => {
           audio: {
             captureSystemAudio: systemAudioEnabled,
             microphoneDeviceIndex: microphoneDeviceIndex ?? null,
+            noiseGate: settings.video.noiseGate,
+            noiseGateThresholdDb: settings.video.noiseGateThresholdDb,
+            micChannelMode: settings.video.micChannelMode,
           },
           quality,
           gifQualityPreset,
           countdownSecs,
           quickCapture,
+          videoCodec: captureType === 'video' ? settings.video.codec : 'h264',
+          rateControl: captureType === 'video' ? settings.video.rateControl : { mode: 'cbr' },
+          keyframeIntervalSecs: settings.video.keyframeIntervalSecs,
+          segmentDurationSecs: captureType === 'video' ? settings.video.segmentDurationSecs : null,
+          captureKeystrokes: captureType === 'video' && settings.video.captureKeystrokes,
         };
 
         await invoke('start_recording', { settings: recordingSettings });
@@ -362,6 +372,14 @@ const CaptureToolbarWindow: React.FC = () => {
     }
   }, []);
 
+  const handleAspectRatioChange = useCallback(async (width: number, height: number) => {
+    try {
+      await invoke('capture_overlay_set_aspect_ratio', { width, height });
+    } catch (e) {
+      toolbarLogger.error('Failed to set aspect ratio:', e);
+    }
+  }, []);
+
   const handleCaptureSourceChange = useCallback(async (source: CaptureSource) => {
     setCaptureSource(source);
 
@@ -462,6 +480,7 @@ const CaptureToolbarWindow: React.FC = () => {
               onCancel={handleCancel}
               format={format}
               elapsedTime={elapsedTime}
+              currentSizeBytes={currentSizeBytes}
               progress={progress}
               errorMessage={errorMessage}
               onPause={handlePause}
@@ -469,6 +488,7 @@ const CaptureToolbarWindow: React.FC = () => {
               onStop={handleStop}
               countdownSeconds={countdownSeconds}
               onDimensionChange={handleDimensionChange}
+              onAspectRatioChange={handleAspectRatioChange}
               onOpenSettings={handleOpenSettings}
             />
           </div>
@@ -43,6 +43,47 @@ const TOOL_DEFAULT_COLORS: Partial<Record<Tool, string>> = {
 };
 const DEFAULT_STROKE_COLOR = '#ef4444';
 
+/**
+ * Apply a project's saved annotations (shapes, crop bounds, compositor settings)
+ * to the editor store.
+ */
+function applyProjectAnnotations(store: EditorStore, annotations: Annotation[]) {
+  // Separate special annotations from shape annotations
+  const cropBoundsAnn = annotations.find(isCropBoundsAnnotation);
+  const compositorAnn = annotations.find(isCompositorSettingsAnnotation);
+  const shapeAnnotations = annotations.filter(
+    (ann: Annotation) => !isCropBoundsAnnotation(ann) && !isCompositorSettingsAnnotation(ann)
+  );
+
+  // Load crop bounds (none = uncropped)
+  store.getState().setCanvasBounds(
+    cropBoundsAnn
+      ? {
+          width: cropBoundsAnn.width,
+          height: cropBoundsAnn.height,
+          imageOffsetX: cropBoundsAnn.imageOffsetX,
+          imageOffsetY: cropBoundsAnn.imageOffsetY,
+        }
+      : null
+  );
+
+  // Load compositor settings if present (spread defaults, then saved values)
+  if (compositorAnn) {
+    store.getState().setCompositorSettings({
+      ...DEFAULT_COMPOSITOR_SETTINGS,
+      ...compositorAnn,
+    });
+  }
+
+  // Load shapes
+  const projectShapes: CanvasShape[] = shapeAnnotations.map((ann: Annotation) => ({
+    ...ann,
+    id: ann.id,
+    type: ann.type,
+  } as CanvasShape));
+  store.getState().setShapes(projectShapes);
+}
+
 /**
  * Inner component that uses the editor store context
  */
@@ -111,14 +152,32 @@ const ImageEditorContent: React.FC<{
     setShapes(newShapes);
   }, [setShapes]);
 
-  // Undo/Redo handlers - use store methods directly for window context
+  // Undo/Redo handlers - use store methods directly for window context.
+  // Once the session's history runs out, step through the project's saved
+  // annotation history, so undo keeps working after reopening a capture.
+  const stepSavedHistory = useCallback(async (command: 'undo_annotations' | 'redo_annotations') => {
+    if (!projectId) return;
+    try {
+      const annotations = await invoke<Annotation[] | null>(command, { projectId });
+      if (annotations) {
+        applyProjectAnnotations(store, annotations);
+      }
+    } catch (error) {
+      editorLogger.warn(`Failed to ${command}:`, error);
+    }
+  }, [projectId, store]);
+
   const handleUndo = useCallback(() => {
-    store.getState()._undo();
-  }, [store]);
+    if (!store.getState()._undo()) {
+      stepSavedHistory('undo_annotations');
+    }
+  }, [store, stepSavedHistory]);
 
   const handleRedo = useCallback(() => {
-    store.getState()._redo();
-  }, [store]);
+    if (!store.getState()._redo()) {
+      stepSavedHistory('redo_annotations');
+    }
+  }, [store, stepSavedHistory]);
 
   // Delete handlers
   const handleRequestDelete = useCallback(() => {
@@ -281,31 +340,6 @@ const ImageEditorWindow: React.FC = () => {
         try {
           const project = await invoke<{ annotations?: Annotation[]; dimensions?: { width: number; height: number } }>('get_project', { projectId: capture.id });
           if (project.annotations && project.annotations.length > 0) {
-            // Separate special annotations from shape annotations
-            const cropBoundsAnn = project.annotations.find(isCropBoundsAnnotation);
-            const compositorAnn = project.annotations.find(isCompositorSettingsAnnotation);
-            const shapeAnnotations = project.annotations.filter(
-              (ann: Annotation) => !isCropBoundsAnnotation(ann) && !isCompositorSettingsAnnotation(ann)
-            );
-
-            // Load crop bounds if present
-            if (cropBoundsAnn) {
-              store.getState().setCanvasBounds({
-                width: cropBoundsAnn.width,
-                height: cropBoundsAnn.height,
-                imageOffsetX: cropBoundsAnn.imageOffsetX,
-                imageOffsetY: cropBoundsAnn.imageOffsetY,
-              });
-            }
-
-            // Load compositor settings if present (spread defaults, then saved values)
-            if (compositorAnn) {
-              store.getState().setCompositorSettings({
-                ...DEFAULT_COMPOSITOR_SETTINGS,
-                ...compositorAnn,
-              });
-            }
-
             // Set original image size for reset functionality
             if (project.dimensions) {
               store.getState().setOriginalImageSize({
@@ -314,13 +348,7 @@ const ImageEditorWindow: React.FC = () => {
               });
             }
 
-            // Load shapes
-            const projectShapes: CanvasShape[] = shapeAnnotations.map((ann: Annotation) => ({
-              ...ann,
-              id: ann.id,
-              type: ann.type,
-            } as CanvasShape));
-            store.getState().setShapes(projectShapes);
+            applyProjectAnnotations(store, project.annotations);
           }
         } catch (err) {
           editorLogger.warn('Failed to load project annotations:', err);
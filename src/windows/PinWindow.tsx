@@ -0,0 +1,57 @@
+/**
+ * PinWindow - A capture pinned on top of other windows.
+ *
+ * The image fills the frameless window and doubles as its drag region.
+ * Close with the hover button or Escape.
+ */
+
+import React, { useEffect } from 'react';
+import { convertFileSrc } from '@tauri-apps/api/core';
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+import { X } from 'lucide-react';
+
+const PinWindow: React.FC = () => {
+  // Image path is passed by pin_capture via URL query parameter
+  const imagePath = new URLSearchParams(window.location.search).get('path');
+
+  const closePin = () => {
+    getCurrentWebviewWindow().close();
+  };
+
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') {
+        e.preventDefault();
+        closePin();
+      }
+    };
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, []);
+
+  if (!imagePath) {
+    return null;
+  }
+
+  return (
+    <div className="group relative w-full h-full">
+      <img
+        src={convertFileSrc(imagePath)}
+        alt="Pinned capture"
+        className="w-full h-full object-contain select-none"
+        draggable={false}
+        data-tauri-drag-region
+      />
+      <button
+        type="button"
+        onClick={closePin}
+        title="Close"
+        className="absolute top-1.5 right-1.5 p-1 rounded-md bg-black/60 text-white opacity-0 group-hover:opacity-100 transition-opacity"
+      >
+        <X className="w-3.5 h-3.5" />
+      </button>
+    </div>
+  );
+};
+
+export default PinWindow;
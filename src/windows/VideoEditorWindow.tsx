@@ -9,6 +9,7 @@ import React, { useEffect, useState, useCallback, useRef } from 'react';
 import { listen } from '@tauri-apps/api/event';
 import { invoke } from '@tauri-apps/api/core';
 import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+import { ask } from '@tauri-apps/plugin-dialog';
 import { Loader2 } from 'lucide-react';
 import { Titlebar } from '@/components/Titlebar/Titlebar';
 import { VideoEditorView } from '@/views/VideoEditorView';
@@ -59,8 +60,21 @@ const VideoEditorWindow: React.FC = () => {
 
     try {
       videoEditorLogger.info('Loading video project:', path);
+
+      // Offer to recover edits autosaved since the last save (e.g. after a crash)
+      const autosavedAt = await invoke<string | null>('find_video_project_autosave', {
+        videoPath: path,
+      });
+      const recoverAutosave = autosavedAt
+        ? await ask(
+            `This project has unsaved changes from ${new Date(autosavedAt).toLocaleString()}. Recover them?`,
+            { title: 'Recover Unsaved Changes', kind: 'warning', okLabel: 'Recover', cancelLabel: 'Discard' }
+          )
+        : undefined;
+
       const videoProject = await invoke<VideoProject>('load_video_project', {
         videoPath: path,
+        recoverAutosave,
       });
 
       setProject(videoProject);